@@ -11,9 +11,13 @@ mod debug;
 mod helper;
 
 use boa_engine::{
+    ast::{
+        codegen::{generate_module, generate_script, CodegenMode},
+        estree::{module_to_estree, script_to_estree},
+    },
     builtins::promise::PromiseState,
     context::ContextBuilder,
-    job::{Job, JobExecutor, NativeAsyncJob, PromiseJob},
+    job::{GenericJob, Job, JobExecutor, NativeAsyncJob, PromiseJob},
     module::{Module, SimpleModuleLoader},
     optimizer::OptimizerOptions,
     script::Script,
@@ -164,6 +168,18 @@ enum DumpFormat {
 
     /// This is a pretty printed json format.
     JsonPretty,
+
+    /// This is a minified `ESTree` json format.
+    Estree,
+
+    /// This is a pretty printed `ESTree` json format.
+    EstreePretty,
+
+    /// This is the JavaScript source code generated from the AST.
+    Js,
+
+    /// This is the minified JavaScript source code generated from the AST.
+    JsMinified,
 }
 
 /// Represents the format of the instruction flowgraph.
@@ -204,6 +220,17 @@ fn dump<R: ReadChar>(src: Source<'_, R>, args: &Opt, context: &mut Context) -> R
                         .expect("could not convert AST to a JSON string"),
                     DumpFormat::JsonPretty => serde_json::to_string_pretty(&module)
                         .expect("could not convert AST to a pretty JSON string"),
+                    DumpFormat::Estree => module_to_estree(&module, context.interner()).to_string(),
+                    DumpFormat::EstreePretty => {
+                        serde_json::to_string_pretty(&module_to_estree(&module, context.interner()))
+                            .expect("could not convert ESTree to a pretty JSON string")
+                    }
+                    DumpFormat::Js => {
+                        generate_module(&module, context.interner(), CodegenMode::Pretty)
+                    }
+                    DumpFormat::JsMinified => {
+                        generate_module(&module, context.interner(), CodegenMode::Minified)
+                    }
                     DumpFormat::Debug => format!("{module:#?}"),
                 }
             } else {
@@ -221,6 +248,17 @@ fn dump<R: ReadChar>(src: Source<'_, R>, args: &Opt, context: &mut Context) -> R
                         .expect("could not convert AST to a JSON string"),
                     DumpFormat::JsonPretty => serde_json::to_string_pretty(&script)
                         .expect("could not convert AST to a pretty JSON string"),
+                    DumpFormat::Estree => script_to_estree(&script, context.interner()).to_string(),
+                    DumpFormat::EstreePretty => {
+                        serde_json::to_string_pretty(&script_to_estree(&script, context.interner()))
+                            .expect("could not convert ESTree to a pretty JSON string")
+                    }
+                    DumpFormat::Js => {
+                        generate_script(&script, context.interner(), CodegenMode::Pretty)
+                    }
+                    DumpFormat::JsMinified => {
+                        generate_script(&script, context.interner(), CodegenMode::Minified)
+                    }
                     DumpFormat::Debug => format!("{script:#?}"),
                 }
             };
@@ -304,7 +342,7 @@ fn evaluate_file(
 
     match context.eval(Source::from_filepath(file)?) {
         Ok(v) => println!("{}", v.display()),
-        Err(v) => eprintln!("Uncaught {v}"),
+        Err(v) => eprintln!("Uncaught {}", display_error(&v)),
     }
     context
         .run_jobs()
@@ -418,7 +456,7 @@ fn main() -> Result<()> {
                             println!("{}", v.display());
                         }
                         Err(v) => {
-                            eprintln!("{}: {}", "Uncaught".red(), v.to_string().red());
+                            eprintln!("{}: {}", "Uncaught".red(), display_error(&v).red());
                         }
                     }
                     if let Err(err) = context.run_jobs() {
@@ -444,6 +482,16 @@ fn main() -> Result<()> {
     Ok(())
 }
 
+/// Formats an uncaught error, appending the backtrace captured when it was thrown if it's known.
+fn display_error(err: &JsError) -> String {
+    let mut output = err.to_string();
+    for frame in err.backtrace().unwrap_or_default() {
+        output.push_str("\n    ");
+        output.push_str(&frame.to_string());
+    }
+    output
+}
+
 /// Adds the CLI runtime to the context with default options.
 fn add_runtime(context: &mut Context) {
     boa_runtime::register(context, boa_runtime::RegisterOptions::new())
@@ -454,6 +502,7 @@ fn add_runtime(context: &mut Context) {
 struct Executor {
     promise_jobs: RefCell<VecDeque<PromiseJob>>,
     async_jobs: RefCell<VecDeque<NativeAsyncJob>>,
+    generic_jobs: RefCell<VecDeque<GenericJob>>,
 }
 
 impl JobExecutor for Executor {
@@ -461,35 +510,46 @@ impl JobExecutor for Executor {
         match job {
             Job::PromiseJob(job) => self.promise_jobs.borrow_mut().push_back(job),
             Job::AsyncJob(job) => self.async_jobs.borrow_mut().push_back(job),
+            Job::GenericJob(job) => self.generic_jobs.borrow_mut().push_back(job),
             job => eprintln!("unsupported job type {job:?}"),
         }
     }
 
     fn run_jobs(&self, context: &mut Context) -> JsResult<()> {
         loop {
-            if self.promise_jobs.borrow().is_empty() && self.async_jobs.borrow().is_empty() {
+            if self.promise_jobs.borrow().is_empty()
+                && self.async_jobs.borrow().is_empty()
+                && self.generic_jobs.borrow().is_empty()
+            {
                 return Ok(());
             }
 
             let jobs = std::mem::take(&mut *self.promise_jobs.borrow_mut());
             for job in jobs {
                 if let Err(e) = job.call(context) {
-                    eprintln!("Uncaught {e}");
+                    eprintln!("Uncaught {}", display_error(&e));
                 }
             }
 
             let async_jobs = std::mem::take(&mut *self.async_jobs.borrow_mut());
             for async_job in async_jobs {
                 if let Err(err) = pollster::block_on(async_job.call(&RefCell::new(context))) {
-                    eprintln!("Uncaught {err}");
+                    eprintln!("Uncaught {}", display_error(&err));
                 }
                 let jobs = std::mem::take(&mut *self.promise_jobs.borrow_mut());
                 for job in jobs {
                     if let Err(e) = job.call(context) {
-                        eprintln!("Uncaught {e}");
+                        eprintln!("Uncaught {}", display_error(&e));
                     }
                 }
             }
+
+            let generic_jobs = std::mem::take(&mut *self.generic_jobs.borrow_mut());
+            for job in generic_jobs {
+                if let Err(e) = job.call(context) {
+                    eprintln!("Uncaught {}", display_error(&e));
+                }
+            }
         }
     }
 }
@@ -0,0 +1,2094 @@
+//! Generation of the source code of the AST nodes.
+
+use std::fmt::Write;
+
+use boa_interner::{Interner, Sym};
+use cow_utils::CowUtils;
+use num_bigint::Sign;
+use rustc_hash::FxHashSet;
+
+use super::{
+    precedence::Precedence,
+    printer::Printer,
+    renamer::{has_dynamic_scope, Renamer},
+    CodegenMode,
+};
+use crate::{
+    comment::{Comment, CommentKind},
+    declaration::{
+        Binding, ExportDeclaration, ExportSpecifier, ImportAttribute, ImportDeclaration,
+        ImportKind, LexicalDeclaration, ReExportKind, VarDeclaration, VariableList,
+    },
+    expression::{
+        access::{PropertyAccess, PropertyAccessField},
+        literal::{ArrayLiteral, LiteralKind, ObjectLiteral, PropertyDefinition, TemplateElement},
+        operator::{
+            assign::AssignTarget,
+            binary::{ArithmeticOp, BinaryOp, LogicalOp, RelationalOp},
+            update::{UpdateOp, UpdateTarget},
+            Binary,
+        },
+        Expression, Identifier, Optional, OptionalOperationKind, TaggedTemplate,
+    },
+    function::{
+        ClassDeclaration, ClassElement, ClassElementName, ClassExpression, Decorator,
+        FormalParameterList, FunctionBody, FunctionExpression, PrivateName,
+    },
+    pattern::{ArrayPatternElement, ObjectPatternElement, Pattern},
+    property::{MethodDefinitionKind, PropertyName},
+    scope::{FunctionScopes, Scope},
+    statement::{
+        iteration::{ForLoopInitializer, IterableLoopInitializer},
+        Block, LabelledItem,
+    },
+    Declaration, Module, ModuleItem, Script, Span, Spanned, Statement, StatementListItem,
+    ToJsString,
+};
+
+/// The kind of a function declaration or expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FunctionKind {
+    Ordinary,
+    Generator,
+    Async,
+    AsyncGenerator,
+}
+
+/// A position where some expressions can't appear unparenthesized, since the code would be read
+/// as something else.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Start {
+    /// The start of an expression statement, where `{`, `function`, `class` and `let [` start
+    /// other statements.
+    Statement,
+
+    /// The concise body of an arrow function, where `{` starts a function body.
+    ArrowBody,
+
+    /// The expression of an `export default`, where `function` and `class` start declarations.
+    ExportDefault,
+
+    /// The initializer of a `for` loop, where `let` starts a declaration.
+    ForInit,
+}
+
+/// Generates the source code of the AST nodes.
+pub(super) struct Generator<'a> {
+    interner: &'a Interner,
+    minify: bool,
+    printer: Printer,
+    renamer: Option<Renamer>,
+
+    /// The scope of the code that is being generated, used to resolve identifiers.
+    scope: Option<Scope>,
+
+    comments: &'a [Comment],
+    next_comment: usize,
+
+    /// Whether an `in` operator must be parenthesized, as in the initializer of a `for` loop.
+    no_in: bool,
+}
+
+impl<'a> Generator<'a> {
+    pub(super) fn script(
+        script: &'a Script,
+        interner: &'a Interner,
+        mode: CodegenMode,
+        indentation: usize,
+    ) -> String {
+        Self::run(
+            interner,
+            mode,
+            indentation,
+            script.comments(),
+            None,
+            has_dynamic_scope(script),
+            |generator| generator.statements(script.statements().statements()),
+        )
+    }
+
+    pub(super) fn module(
+        module: &'a Module,
+        interner: &'a Interner,
+        mode: CodegenMode,
+        indentation: usize,
+    ) -> String {
+        Self::run(
+            interner,
+            mode,
+            indentation,
+            module.comments(),
+            Some(module.scope().clone()),
+            has_dynamic_scope(module),
+            |generator| {
+                for (i, item) in module.items().items().iter().enumerate() {
+                    if i > 0 {
+                        generator.printer.newline();
+                    }
+                    generator.module_item(item);
+                }
+            },
+        )
+    }
+
+    fn run(
+        interner: &'a Interner,
+        mode: CodegenMode,
+        indentation: usize,
+        comments: &'a [Comment],
+        scope: Option<Scope>,
+        dynamic_scope: bool,
+        generate: impl Fn(&mut Self),
+    ) -> String {
+        let minify = mode == CodegenMode::Minified;
+
+        let mut renamer = None;
+        if minify && !dynamic_scope {
+            // A first pass finds the names that resolve to bindings that are not renamed, which
+            // the renamed bindings must not shadow.
+            let mut generator = Self::new(
+                interner,
+                minify,
+                indentation,
+                &[],
+                scope.clone(),
+                Some(Renamer::new(scope.clone(), FxHashSet::default())),
+            );
+            generate(&mut generator);
+            let reserved = generator
+                .renamer
+                .map(Renamer::into_free_names)
+                .unwrap_or_default();
+            renamer = Some(Renamer::new(scope.clone(), reserved));
+        }
+
+        let comments = if minify {
+            // Only the hashbang is kept, since it affects how the file is run.
+            let hashbang = comments
+                .first()
+                .is_some_and(|comment| comment.kind() == CommentKind::Hashbang);
+            &comments[..usize::from(hashbang)]
+        } else {
+            comments
+        };
+
+        let mut generator = Self::new(interner, minify, indentation, comments, scope, renamer);
+        if let Some(hashbang) = comments
+            .first()
+            .filter(|comment| comment.kind() == CommentKind::Hashbang)
+        {
+            generator.printer.comment(&hashbang.to_string());
+            generator.next_comment = 1;
+        }
+        generate(&mut generator);
+        generator.remaining_comments();
+        generator.printer.finish()
+    }
+
+    fn new(
+        interner: &'a Interner,
+        minify: bool,
+        indentation: usize,
+        comments: &'a [Comment],
+        scope: Option<Scope>,
+        renamer: Option<Renamer>,
+    ) -> Self {
+        Self {
+            interner,
+            minify,
+            printer: Printer::new(minify, indentation),
+            renamer,
+            scope,
+            comments,
+            next_comment: 0,
+            no_in: false,
+        }
+    }
+
+    /// Writes the comments that end before the start of `span`.
+    fn comments_before(&mut self, span: Option<Span>) {
+        let Some(span) = span else {
+            return;
+        };
+        while let Some(comment) = self.comments.get(self.next_comment) {
+            if comment.span().end() > span.start() {
+                break;
+            }
+            self.next_comment += 1;
+            self.printer.comment(&comment.to_string());
+        }
+    }
+
+    fn remaining_comments(&mut self) {
+        for comment in &self.comments[self.next_comment..] {
+            self.printer.comment(&comment.to_string());
+        }
+        self.next_comment = self.comments.len();
+    }
+
+    /// Runs `f` with `scope` as the current scope, if there is one.
+    fn with_scope(&mut self, scope: Option<&Scope>, f: impl FnOnce(&mut Self)) {
+        let Some(scope) = scope else {
+            f(self);
+            return;
+        };
+        let outer = self.scope.replace(scope.clone());
+        f(self);
+        self.scope = outer;
+    }
+
+    /// Runs `f` in a position where the `in` operator doesn't need parentheses.
+    fn allow_in(&mut self, f: impl FnOnce(&mut Self)) {
+        let no_in = std::mem::replace(&mut self.no_in, false);
+        f(self);
+        self.no_in = no_in;
+    }
+
+    /// Writes a keyword, followed by a space in pretty mode.
+    fn keyword(&mut self, keyword: &str) {
+        self.printer.token(keyword);
+        self.printer.space();
+    }
+
+    fn comma_separated<T>(&mut self, items: &[T], mut f: impl FnMut(&mut Self, &T)) {
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                self.printer.token(",");
+                self.printer.space();
+            }
+            f(self, item);
+        }
+    }
+
+    fn resolve(&self, sym: Sym) -> String {
+        self.interner.resolve_expect(sym).to_string()
+    }
+
+    fn sym(&mut self, sym: Sym) {
+        let name = self.resolve(sym);
+        self.printer.token(&name);
+    }
+
+    /// Writes a name, as an identifier if possible, and as a string literal otherwise.
+    fn name_or_string(&mut self, sym: Sym) {
+        let name = self.resolve(sym);
+        if is_identifier_name(&name) {
+            self.printer.token(&name);
+        } else {
+            self.string(sym);
+        }
+    }
+
+    /// Gets the name of `identifier` in the generated code, which differs from the original one
+    /// if the binding it resolves to is renamed.
+    fn identifier_name(&mut self, identifier: Identifier) -> String {
+        if let (Some(renamer), Some(scope)) = (&mut self.renamer, &self.scope) {
+            if let Some(name) = renamer.resolve(scope, &identifier.to_js_string(self.interner)) {
+                return name.into();
+            }
+        }
+        self.resolve(identifier.sym())
+    }
+
+    fn identifier(&mut self, identifier: Identifier) {
+        let name = self.identifier_name(identifier);
+        self.printer.token(&name);
+    }
+
+    fn private_name(&mut self, name: PrivateName) {
+        let name = format!("#{}", self.resolve(name.description()));
+        self.printer.token(&name);
+    }
+
+    fn string(&mut self, sym: Sym) {
+        let units = self.interner.resolve_expect(sym).utf16();
+        let quote = if self.minify {
+            let count = |quote: u8| units.iter().filter(|&&u| u == u16::from(quote)).count();
+            if count(b'\'') < count(b'"') {
+                '\''
+            } else {
+                '"'
+            }
+        } else {
+            '"'
+        };
+        self.printer.token(&quote_string(units, quote));
+    }
+
+    fn number(&mut self, value: f64) {
+        if value.is_sign_negative() && !value.is_nan() {
+            self.printer.token("-");
+        }
+        self.printer
+            .number(&number_to_string(value.abs(), self.minify));
+    }
+
+    fn statements(&mut self, items: &[StatementListItem]) {
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                self.printer.newline();
+            }
+            self.statement_list_item(item);
+        }
+    }
+
+    /// Writes a list of statements enclosed in braces.
+    fn block_statements(&mut self, items: &[StatementListItem]) {
+        self.printer.token("{");
+        if !items.is_empty() {
+            self.printer.indent();
+            for item in items {
+                self.printer.newline();
+                self.statement_list_item(item);
+            }
+            self.printer.dedent();
+            self.printer.newline();
+        }
+        self.printer.token("}");
+    }
+
+    fn statement_list_item(&mut self, item: &StatementListItem) {
+        self.comments_before(item.span());
+        match item {
+            StatementListItem::Statement(statement) => self.statement(statement),
+            StatementListItem::Declaration(declaration) => self.declaration(declaration),
+        }
+    }
+
+    fn block(&mut self, block: &Block) {
+        self.with_scope(block.scope(), |generator| {
+            generator.block_statements(block.statement_list().statements());
+        });
+    }
+
+    /// Writes the body of a compound statement.
+    fn body(&mut self, body: &Statement) {
+        self.printer.space();
+        self.statement(body);
+    }
+
+    fn statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Block(block) => self.block(block),
+            Statement::Var(var) => {
+                self.var_declaration(var);
+                self.printer.semicolon();
+            }
+            Statement::Empty(_) | Statement::Error(_) => self.printer.token(";"),
+            Statement::Expression(expression) => {
+                self.expression_at(expression, Precedence::Lowest, Start::Statement);
+                self.printer.semicolon();
+            }
+            Statement::If(r#if) => {
+                self.keyword("if");
+                self.condition(r#if.cond());
+                match r#if.else_node() {
+                    Some(else_node) => {
+                        // An `else` after an `if` without one would belong to the inner `if`.
+                        if ends_with_if_without_else(r#if.body()) {
+                            self.printer.space();
+                            self.braced(r#if.body());
+                        } else {
+                            self.body(r#if.body());
+                        }
+                        self.printer.space();
+                        self.printer.token("else");
+                        self.body(else_node);
+                    }
+                    None => self.body(r#if.body()),
+                }
+            }
+            Statement::DoWhileLoop(do_while) => {
+                self.printer.token("do");
+                self.body(do_while.body());
+                self.printer.space();
+                self.keyword("while");
+                self.condition(do_while.cond());
+                self.printer.semicolon();
+            }
+            Statement::WhileLoop(while_loop) => {
+                self.keyword("while");
+                self.condition(while_loop.condition());
+                self.body(while_loop.body());
+            }
+            Statement::ForLoop(for_loop) => {
+                let scope = match for_loop.init() {
+                    Some(ForLoopInitializer::Lexical(lexical)) => Some(lexical.scope()),
+                    _ => None,
+                };
+                self.keyword("for");
+                self.printer.token("(");
+                self.with_scope(scope, |generator| {
+                    if let Some(init) = for_loop.init() {
+                        let no_in = std::mem::replace(&mut generator.no_in, true);
+                        match init {
+                            ForLoopInitializer::Expression(expression) => {
+                                generator.expression_at(
+                                    expression,
+                                    Precedence::Lowest,
+                                    Start::ForInit,
+                                );
+                            }
+                            ForLoopInitializer::Var(var) => generator.var_declaration(var),
+                            ForLoopInitializer::Lexical(lexical) => {
+                                generator.lexical_declaration(lexical.declaration());
+                            }
+                        }
+                        generator.no_in = no_in;
+                    }
+                    generator.printer.token(";");
+                    if let Some(condition) = for_loop.condition() {
+                        generator.printer.space();
+                        generator.expression(condition, Precedence::Lowest);
+                    }
+                    generator.printer.token(";");
+                    if let Some(final_expr) = for_loop.final_expr() {
+                        generator.printer.space();
+                        generator.expression(final_expr, Precedence::Lowest);
+                    }
+                    generator.printer.token(")");
+                    generator.body(for_loop.body());
+                });
+            }
+            Statement::ForInLoop(for_in) => {
+                self.keyword("for");
+                self.printer.token("(");
+                self.with_scope(for_in.scope(), |generator| {
+                    generator.iterable_loop_initializer(for_in.initializer(), false);
+                });
+                self.printer.space();
+                self.printer.token("in");
+                self.printer.space();
+                self.with_scope(for_in.target_scope(), |generator| {
+                    generator.expression(for_in.target(), Precedence::Lowest);
+                });
+                self.printer.token(")");
+                self.with_scope(for_in.scope(), |generator| generator.body(for_in.body()));
+            }
+            Statement::ForOfLoop(for_of) => {
+                self.keyword("for");
+                if for_of.r#await() {
+                    self.keyword("await");
+                }
+                self.printer.token("(");
+                self.with_scope(for_of.scope(), |generator| {
+                    generator.iterable_loop_initializer(for_of.initializer(), true);
+                });
+                self.printer.space();
+                self.printer.token("of");
+                self.printer.space();
+                self.with_scope(for_of.iterable_scope(), |generator| {
+                    generator.expression(for_of.iterable(), Precedence::Yield);
+                });
+                self.printer.token(")");
+                self.with_scope(for_of.scope(), |generator| generator.body(for_of.body()));
+            }
+            Statement::Switch(switch) => {
+                self.keyword("switch");
+                self.condition(switch.val());
+                self.printer.space();
+                self.printer.token("{");
+                if !switch.cases().is_empty() {
+                    self.with_scope(switch.scope(), |generator| {
+                        for case in switch.cases() {
+                            generator.printer.newline();
+                            match case.condition() {
+                                Some(condition) => {
+                                    generator.keyword("case");
+                                    generator.expression(condition, Precedence::Lowest);
+                                }
+                                None => generator.printer.token("default"),
+                            }
+                            generator.printer.token(":");
+                            generator.printer.indent();
+                            for item in case.body().statements() {
+                                generator.printer.newline();
+                                generator.statement_list_item(item);
+                            }
+                            generator.printer.dedent();
+                        }
+                    });
+                    self.printer.newline();
+                }
+                self.printer.token("}");
+            }
+            Statement::Continue(cont) => {
+                self.printer.token("continue");
+                if let Some(label) = cont.label() {
+                    self.printer.space();
+                    self.sym(label);
+                }
+                self.printer.semicolon();
+            }
+            Statement::Break(brk) => {
+                self.printer.token("break");
+                if let Some(label) = brk.label() {
+                    self.printer.space();
+                    self.sym(label);
+                }
+                self.printer.semicolon();
+            }
+            Statement::Return(ret) => {
+                self.printer.token("return");
+                if let Some(target) = ret.target() {
+                    self.printer.space();
+                    self.expression(target, Precedence::Lowest);
+                }
+                self.printer.semicolon();
+            }
+            Statement::Labelled(labelled) => {
+                self.sym(labelled.label());
+                self.printer.token(":");
+                self.printer.space();
+                match labelled.item() {
+                    LabelledItem::FunctionDeclaration(f) => self.function(
+                        FunctionKind::Ordinary,
+                        Some(f.name()),
+                        f.parameters(),
+                        f.body(),
+                        f.scopes(),
+                    ),
+                    LabelledItem::Statement(statement) => self.statement(statement),
+                }
+            }
+            Statement::Throw(throw) => {
+                self.keyword("throw");
+                self.expression(throw.target(), Precedence::Lowest);
+                self.printer.semicolon();
+            }
+            Statement::Try(r#try) => {
+                self.keyword("try");
+                self.block(r#try.block());
+                if let Some(catch) = r#try.catch() {
+                    self.printer.space();
+                    self.keyword("catch");
+                    self.with_scope(Some(catch.scope()), |generator| {
+                        if let Some(parameter) = catch.parameter() {
+                            generator.printer.token("(");
+                            generator.binding(parameter);
+                            generator.printer.token(")");
+                            generator.printer.space();
+                        }
+                        generator.block(catch.block());
+                    });
+                }
+                if let Some(finally) = r#try.finally() {
+                    self.printer.space();
+                    self.keyword("finally");
+                    self.block(finally.block());
+                }
+            }
+            Statement::With(with) => {
+                self.keyword("with");
+                self.condition(with.expression());
+                self.with_scope(Some(with.scope()), |generator| {
+                    generator.body(with.statement());
+                });
+            }
+        }
+    }
+
+    /// Writes the parenthesized condition of a compound statement.
+    fn condition(&mut self, condition: &Expression) {
+        self.printer.token("(");
+        self.expression(condition, Precedence::Lowest);
+        self.printer.token(")");
+    }
+
+    /// Writes a statement enclosed in a block.
+    fn braced(&mut self, statement: &Statement) {
+        self.printer.token("{");
+        self.printer.indent();
+        self.printer.newline();
+        self.statement(statement);
+        self.printer.dedent();
+        self.printer.newline();
+        self.printer.token("}");
+    }
+
+    fn iterable_loop_initializer(&mut self, initializer: &IterableLoopInitializer, of: bool) {
+        let (kind, binding) = match initializer {
+            IterableLoopInitializer::Identifier(ident) => {
+                // `for (async of` and `for (let of` would start other statements.
+                if of && matches!(ident.sym(), Sym::ASYNC | Sym::LET) {
+                    self.printer.token("(");
+                    self.identifier(*ident);
+                    self.printer.token(")");
+                } else {
+                    self.identifier(*ident);
+                }
+                return;
+            }
+            IterableLoopInitializer::Access(access) => {
+                self.property_access(access);
+                return;
+            }
+            IterableLoopInitializer::Pattern(pattern) => {
+                self.pattern(pattern);
+                return;
+            }
+            IterableLoopInitializer::Var(variable) => {
+                self.keyword("var");
+                self.binding(variable.binding());
+                if let Some(init) = variable.init() {
+                    let no_in = std::mem::replace(&mut self.no_in, true);
+                    self.initializer(Some(init));
+                    self.no_in = no_in;
+                }
+                return;
+            }
+            IterableLoopInitializer::Let(binding) => ("let", binding),
+            IterableLoopInitializer::Const(binding) => ("const", binding),
+            IterableLoopInitializer::Using(binding) => ("using", binding),
+            IterableLoopInitializer::AwaitUsing(binding) => ("await using", binding),
+        };
+        self.keyword(kind);
+        self.binding(binding);
+    }
+
+    fn declaration(&mut self, declaration: &Declaration) {
+        match declaration {
+            Declaration::FunctionDeclaration(f) => self.function(
+                FunctionKind::Ordinary,
+                Some(f.name()),
+                f.parameters(),
+                f.body(),
+                f.scopes(),
+            ),
+            Declaration::GeneratorDeclaration(f) => self.function(
+                FunctionKind::Generator,
+                Some(f.name()),
+                f.parameters(),
+                f.body(),
+                f.scopes(),
+            ),
+            Declaration::AsyncFunctionDeclaration(f) => self.function(
+                FunctionKind::Async,
+                Some(f.name()),
+                f.parameters(),
+                f.body(),
+                f.scopes(),
+            ),
+            Declaration::AsyncGeneratorDeclaration(f) => self.function(
+                FunctionKind::AsyncGenerator,
+                Some(f.name()),
+                f.parameters(),
+                f.body(),
+                f.scopes(),
+            ),
+            Declaration::ClassDeclaration(class) => {
+                self.class_declaration(class, Some(class.name()));
+            }
+            Declaration::Lexical(lexical) => {
+                self.lexical_declaration(lexical);
+                self.printer.semicolon();
+            }
+        }
+    }
+
+    fn var_declaration(&mut self, var: &VarDeclaration) {
+        self.keyword("var");
+        self.variable_list(&var.0);
+    }
+
+    fn lexical_declaration(&mut self, declaration: &LexicalDeclaration) {
+        let kind = match declaration {
+            LexicalDeclaration::Const(_) => "const",
+            LexicalDeclaration::Let(_) => "let",
+            LexicalDeclaration::Using(_) => "using",
+            LexicalDeclaration::AwaitUsing(_) => "await using",
+        };
+        self.keyword(kind);
+        self.variable_list(declaration.variable_list());
+    }
+
+    fn variable_list(&mut self, list: &VariableList) {
+        self.comma_separated(list.as_ref(), |generator, variable| {
+            generator.binding(variable.binding());
+            generator.initializer(variable.init());
+        });
+    }
+
+    /// Writes the initializer of a binding or parameter, if there is one.
+    fn initializer(&mut self, init: Option<&Expression>) {
+        if let Some(init) = init {
+            self.printer.space();
+            self.printer.token("=");
+            self.printer.space();
+            self.expression(init, Precedence::Yield);
+        }
+    }
+
+    fn binding(&mut self, binding: &Binding) {
+        match binding {
+            Binding::Identifier(ident) => self.identifier(*ident),
+            Binding::Pattern(pattern) => self.pattern(pattern),
+        }
+    }
+
+    fn pattern(&mut self, pattern: &Pattern) {
+        match pattern {
+            Pattern::Object(object) => {
+                self.printer.token("{");
+                if !object.bindings().is_empty() {
+                    self.printer.space();
+                    self.comma_separated(object.bindings(), Self::object_pattern_element);
+                    self.printer.space();
+                }
+                self.printer.token("}");
+            }
+            Pattern::Array(array) => {
+                self.printer.token("[");
+                self.comma_separated(array.bindings(), Self::array_pattern_element);
+                if matches!(array.bindings().last(), Some(ArrayPatternElement::Elision)) {
+                    self.printer.token(",");
+                }
+                self.printer.token("]");
+            }
+        }
+    }
+
+    fn object_pattern_element(&mut self, element: &ObjectPatternElement) {
+        match element {
+            ObjectPatternElement::SingleName {
+                name,
+                ident,
+                default_init,
+            } => {
+                let binding = self.identifier_name(*ident);
+                let shorthand =
+                    name.literal() == Some(ident.sym()) && binding == self.resolve(ident.sym());
+                if !shorthand {
+                    self.property_name(name);
+                    self.printer.token(":");
+                    self.printer.space();
+                }
+                self.printer.token(&binding);
+                self.initializer(default_init.as_ref());
+            }
+            ObjectPatternElement::RestProperty { ident } => {
+                self.printer.token("...");
+                self.identifier(*ident);
+            }
+            ObjectPatternElement::AssignmentPropertyAccess {
+                name,
+                access,
+                default_init,
+            } => {
+                self.property_name(name);
+                self.printer.token(":");
+                self.printer.space();
+                self.property_access(access);
+                self.initializer(default_init.as_ref());
+            }
+            ObjectPatternElement::AssignmentRestPropertyAccess { access } => {
+                self.printer.token("...");
+                self.property_access(access);
+            }
+            ObjectPatternElement::Pattern {
+                name,
+                pattern,
+                default_init,
+            } => {
+                self.property_name(name);
+                self.printer.token(":");
+                self.printer.space();
+                self.pattern(pattern);
+                self.initializer(default_init.as_ref());
+            }
+        }
+    }
+
+    fn array_pattern_element(&mut self, element: &ArrayPatternElement) {
+        match element {
+            ArrayPatternElement::Elision => {}
+            ArrayPatternElement::SingleName {
+                ident,
+                default_init,
+            } => {
+                self.identifier(*ident);
+                self.initializer(default_init.as_ref());
+            }
+            ArrayPatternElement::PropertyAccess {
+                access,
+                default_init,
+            } => {
+                self.property_access(access);
+                self.initializer(default_init.as_ref());
+            }
+            ArrayPatternElement::Pattern {
+                pattern,
+                default_init,
+            } => {
+                self.pattern(pattern);
+                self.initializer(default_init.as_ref());
+            }
+            ArrayPatternElement::SingleNameRest { ident } => {
+                self.printer.token("...");
+                self.identifier(*ident);
+            }
+            ArrayPatternElement::PropertyAccessRest { access } => {
+                self.printer.token("...");
+                self.property_access(access);
+            }
+            ArrayPatternElement::PatternRest { pattern } => {
+                self.printer.token("...");
+                self.pattern(pattern);
+            }
+        }
+    }
+
+    fn parameters(&mut self, parameters: &FormalParameterList) {
+        self.printer.token("(");
+        self.comma_separated(parameters.as_ref(), |generator, parameter| {
+            if parameter.is_rest_param() {
+                generator.printer.token("...");
+            }
+            generator.binding(parameter.variable().binding());
+            generator.initializer(parameter.init());
+        });
+        self.printer.token(")");
+    }
+
+    /// Writes a function declaration or expression.
+    ///
+    /// The name is resolved from the current scope, so the name scope of function expressions
+    /// must be entered before.
+    fn function(
+        &mut self,
+        kind: FunctionKind,
+        name: Option<Identifier>,
+        parameters: &FormalParameterList,
+        body: &FunctionBody,
+        scopes: &FunctionScopes,
+    ) {
+        if matches!(kind, FunctionKind::Async | FunctionKind::AsyncGenerator) {
+            self.printer.token("async");
+        }
+        self.printer.token("function");
+        if matches!(kind, FunctionKind::Generator | FunctionKind::AsyncGenerator) {
+            self.printer.token("*");
+            if name.is_some() {
+                self.printer.space();
+            }
+        }
+        if let Some(name) = name {
+            self.identifier(name);
+        }
+        self.function_tail(parameters, body, scopes);
+    }
+
+    /// Writes the parameters and the body of a function.
+    fn function_tail(
+        &mut self,
+        parameters: &FormalParameterList,
+        body: &FunctionBody,
+        scopes: &FunctionScopes,
+    ) {
+        self.allow_in(|generator| {
+            generator.with_scope(Some(&scopes.parameter_scope()), |generator| {
+                generator.parameters(parameters);
+            });
+            generator.printer.space();
+            generator.with_scope(Some(&scopes.body_scope()), |generator| {
+                generator.block_statements(body.statements());
+            });
+        });
+    }
+
+    fn arrow_function(
+        &mut self,
+        r#async: bool,
+        parameters: &FormalParameterList,
+        body: &FunctionBody,
+        scopes: &FunctionScopes,
+    ) {
+        if r#async {
+            self.keyword("async");
+        }
+        self.allow_in(|generator| {
+            generator.with_scope(
+                Some(&scopes.parameter_scope()),
+                |generator| match parameters.as_ref() {
+                    [parameter]
+                        if generator.minify
+                            && !parameter.is_rest_param()
+                            && parameter.init().is_none() =>
+                    {
+                        match parameter.variable().binding() {
+                            Binding::Identifier(ident) => generator.identifier(*ident),
+                            Binding::Pattern(_) => generator.parameters(parameters),
+                        }
+                    }
+                    _ => generator.parameters(parameters),
+                },
+            );
+        });
+        self.printer.space();
+        self.printer.token("=>");
+        self.printer.space();
+
+        // Concise bodies are parsed as a single `return` statement without a span of its own.
+        let concise = match body.statements() {
+            [StatementListItem::Statement(Statement::Return(ret))] if ret.span().is_none() => {
+                ret.target()
+            }
+            _ => None,
+        };
+        self.with_scope(Some(&scopes.body_scope()), |generator| match concise {
+            Some(expression) => {
+                generator.expression_at(expression, Precedence::Yield, Start::ArrowBody);
+            }
+            None => generator.allow_in(|generator| generator.block_statements(body.statements())),
+        });
+    }
+
+    fn decorators(&mut self, decorators: &[Decorator]) {
+        for decorator in decorators {
+            self.printer.token("@");
+            let expression = decorator.expression();
+            if self.is_decorator_expression(expression)
+                || (!self.minify && matches!(expression, Expression::Parenthesized(_)))
+            {
+                self.expression(expression, Precedence::Lowest);
+            } else {
+                self.parenthesized(expression);
+            }
+            self.printer.space();
+        }
+    }
+
+    /// Checks if `expression` can be written as a decorator without parentheses.
+    fn is_decorator_expression(&self, expression: &Expression) -> bool {
+        match expression {
+            Expression::Call(call) => self.is_decorator_member(call.function()),
+            _ => self.is_decorator_member(expression),
+        }
+    }
+
+    fn is_decorator_member(&self, expression: &Expression) -> bool {
+        match expression {
+            Expression::Identifier(_) => true,
+            Expression::PropertyAccess(PropertyAccess::Simple(access)) => {
+                matches!(
+                    access.field(),
+                    PropertyAccessField::Const(sym) if is_identifier_name(&self.resolve(*sym))
+                ) && self.is_decorator_member(access.target())
+            }
+            Expression::PropertyAccess(PropertyAccess::Private(access)) => {
+                self.is_decorator_member(access.target())
+            }
+            _ => false,
+        }
+    }
+
+    fn class_declaration(&mut self, class: &ClassDeclaration, name: Option<Identifier>) {
+        self.decorators(class.decorators());
+        self.printer.token("class");
+        self.with_scope(Some(class.name_scope()), |generator| {
+            if let Some(name) = name {
+                generator.identifier(name);
+            }
+            generator.class_tail(class.super_ref(), class.constructor(), class.elements());
+        });
+    }
+
+    fn class_expression(&mut self, class: &ClassExpression) {
+        self.decorators(class.decorators());
+        self.printer.token("class");
+        // Only classes with a binding identifier have a name scope, anonymous classes can still
+        // have a name inferred from their context.
+        let name = class.name().filter(|_| class.name_scope().is_some());
+        self.with_scope(class.name_scope(), |generator| {
+            if let Some(name) = name {
+                generator.identifier(name);
+            }
+            generator.class_tail(class.super_ref(), class.constructor(), class.elements());
+        });
+    }
+
+    fn class_tail(
+        &mut self,
+        super_ref: Option<&Expression>,
+        constructor: Option<&FunctionExpression>,
+        elements: &[ClassElement],
+    ) {
+        self.allow_in(|generator| {
+            if let Some(super_ref) = super_ref {
+                generator.printer.space();
+                generator.keyword("extends");
+                generator.expression(super_ref, Precedence::Optional);
+            }
+            generator.printer.space();
+            generator.printer.token("{");
+            if constructor.is_none() && elements.is_empty() {
+                generator.printer.token("}");
+                return;
+            }
+            generator.printer.indent();
+            if let Some(constructor) = constructor {
+                generator.printer.newline();
+                generator.printer.token("constructor");
+                generator.function_tail(
+                    constructor.parameters(),
+                    constructor.body(),
+                    constructor.scopes(),
+                );
+            }
+            for element in elements {
+                generator.printer.newline();
+                generator.class_element(element);
+            }
+            generator.printer.dedent();
+            generator.printer.newline();
+            generator.printer.token("}");
+        });
+    }
+
+    fn class_element(&mut self, element: &ClassElement) {
+        self.decorators(element.decorators());
+        match element {
+            ClassElement::MethodDefinition(method) => {
+                if method.is_static() {
+                    self.keyword("static");
+                }
+                self.method_prefix(method.kind());
+                match method.name() {
+                    ClassElementName::PropertyName(name) => self.property_name(name),
+                    ClassElementName::PrivateName(name) => self.private_name(*name),
+                }
+                self.function_tail(method.parameters(), method.body(), method.scopes());
+            }
+            ClassElement::FieldDefinition(field) | ClassElement::StaticFieldDefinition(field) => {
+                if matches!(element, ClassElement::StaticFieldDefinition(_)) {
+                    self.keyword("static");
+                }
+                if field.is_accessor() {
+                    self.keyword("accessor");
+                }
+                self.property_name(field.name());
+                self.with_scope(Some(field.scope()), |generator| {
+                    generator.initializer(field.initializer());
+                });
+                self.printer.semicolon();
+            }
+            ClassElement::PrivateFieldDefinition(field)
+            | ClassElement::PrivateStaticFieldDefinition(field) => {
+                if matches!(element, ClassElement::PrivateStaticFieldDefinition(_)) {
+                    self.keyword("static");
+                }
+                if field.is_accessor() {
+                    self.keyword("accessor");
+                }
+                self.private_name(*field.name());
+                self.with_scope(Some(field.scope()), |generator| {
+                    generator.initializer(field.initializer());
+                });
+                self.printer.semicolon();
+            }
+            ClassElement::StaticBlock(block) => {
+                self.keyword("static");
+                self.with_scope(Some(&block.scopes().body_scope()), |generator| {
+                    generator.block_statements(block.statements().statements());
+                });
+            }
+        }
+    }
+
+    /// Writes the keywords that precede the name of a method.
+    fn method_prefix(&mut self, kind: MethodDefinitionKind) {
+        match kind {
+            MethodDefinitionKind::Get => self.keyword("get"),
+            MethodDefinitionKind::Set => self.keyword("set"),
+            MethodDefinitionKind::Ordinary => {}
+            MethodDefinitionKind::Generator => self.printer.token("*"),
+            MethodDefinitionKind::Async => self.keyword("async"),
+            MethodDefinitionKind::AsyncGenerator => {
+                self.keyword("async");
+                self.printer.token("*");
+            }
+        }
+    }
+
+    fn property_name(&mut self, name: &PropertyName) {
+        match name {
+            PropertyName::Literal(sym) => self.name_or_string(*sym),
+            // Numeric keys are parsed as computed literals.
+            PropertyName::Computed(expression @ Expression::Literal(literal))
+                if is_non_negative_number(literal.kind()) =>
+            {
+                self.expression(expression, Precedence::Member);
+            }
+            PropertyName::Computed(expression) => {
+                self.printer.token("[");
+                self.allow_in(|generator| generator.expression(expression, Precedence::Yield));
+                self.printer.token("]");
+            }
+        }
+    }
+
+    fn object_literal(&mut self, object: &ObjectLiteral) {
+        let properties = object.properties();
+        if properties.is_empty() {
+            self.printer.token("{");
+            self.printer.token("}");
+            return;
+        }
+        self.allow_in(|generator| {
+            generator.printer.token("{");
+            generator.printer.indent();
+            for (i, property) in properties.iter().enumerate() {
+                if generator.minify && i > 0 {
+                    generator.printer.token(",");
+                }
+                generator.printer.newline();
+                generator.property_definition(property);
+                if !generator.minify {
+                    generator.printer.token(",");
+                }
+            }
+            generator.printer.dedent();
+            generator.printer.newline();
+            generator.printer.token("}");
+        });
+    }
+
+    fn property_definition(&mut self, definition: &PropertyDefinition) {
+        match definition {
+            PropertyDefinition::IdentifierReference(ident) => {
+                let value = self.identifier_name(*ident);
+                let name = self.resolve(ident.sym());
+                if value != name {
+                    // A `__proto__: value` property would set the prototype of the object.
+                    if name == "__proto__" {
+                        self.printer.token("[");
+                        self.string(ident.sym());
+                        self.printer.token("]");
+                    } else {
+                        self.printer.token(&name);
+                    }
+                    self.printer.token(":");
+                    self.printer.space();
+                }
+                self.printer.token(&value);
+            }
+            PropertyDefinition::Property(name, value) => {
+                self.property_name(name);
+                self.printer.token(":");
+                self.printer.space();
+                self.expression(value, Precedence::Yield);
+            }
+            PropertyDefinition::MethodDefinition(method) => {
+                self.method_prefix(method.kind());
+                self.property_name(method.name());
+                self.function_tail(method.parameters(), method.body(), method.scopes());
+            }
+            PropertyDefinition::SpreadObject(target) => {
+                self.printer.token("...");
+                self.expression(target, Precedence::Yield);
+            }
+            PropertyDefinition::CoverInitializedName(ident, init) => {
+                self.identifier(*ident);
+                self.initializer(Some(init));
+            }
+        }
+    }
+
+    fn array_literal(&mut self, array: &ArrayLiteral) {
+        let elements = array.as_ref();
+        self.allow_in(|generator| {
+            generator.printer.token("[");
+            generator.comma_separated(elements, |generator, element| {
+                if let Some(element) = element {
+                    generator.expression(element, Precedence::Yield);
+                }
+            });
+            // A trailing elision needs its own comma, since the last one is ignored.
+            let trailing_comma = match elements.last() {
+                Some(None) => true,
+                Some(Some(_)) => array.has_trailing_comma_spread(),
+                None => false,
+            };
+            if trailing_comma {
+                generator.printer.token(",");
+            }
+            generator.printer.token("]");
+        });
+    }
+
+    fn arguments(&mut self, arguments: &[Expression]) {
+        self.printer.token("(");
+        self.allow_in(|generator| {
+            generator.comma_separated(arguments, |generator, argument| {
+                generator.expression(argument, Precedence::Yield);
+            });
+        });
+        self.printer.token(")");
+    }
+
+    fn property_access(&mut self, access: &PropertyAccess) {
+        match access {
+            PropertyAccess::Simple(access) => {
+                self.expression(access.target(), Precedence::Call);
+                self.property_access_field(access.field(), false);
+            }
+            PropertyAccess::Private(access) => {
+                self.expression(access.target(), Precedence::Call);
+                self.printer.token(".");
+                self.private_name(access.field());
+            }
+            PropertyAccess::Super(access) => {
+                self.printer.token("super");
+                self.property_access_field(access.field(), false);
+            }
+        }
+    }
+
+    fn property_access_field(&mut self, field: &PropertyAccessField, optional: bool) {
+        match field {
+            PropertyAccessField::Const(sym) => {
+                let name = self.resolve(*sym);
+                if is_identifier_name(&name) {
+                    self.printer.token(if optional { "?." } else { "." });
+                    self.printer.token(&name);
+                } else {
+                    if optional {
+                        self.printer.token("?.");
+                    }
+                    self.printer.token("[");
+                    self.string(*sym);
+                    self.printer.token("]");
+                }
+            }
+            PropertyAccessField::Expr(expression) => {
+                if optional {
+                    self.printer.token("?.");
+                }
+                self.printer.token("[");
+                self.allow_in(|generator| generator.expression(expression, Precedence::Lowest));
+                self.printer.token("]");
+            }
+        }
+    }
+
+    fn optional(&mut self, optional: &Optional) {
+        self.expression(optional.target(), Precedence::Call);
+        for operation in optional.chain() {
+            let shorted = operation.shorted();
+            match operation.kind() {
+                OptionalOperationKind::SimplePropertyAccess { field } => {
+                    self.property_access_field(field, shorted);
+                }
+                OptionalOperationKind::PrivatePropertyAccess { field } => {
+                    self.printer.token(if shorted { "?." } else { "." });
+                    self.private_name(*field);
+                }
+                OptionalOperationKind::Call { args } => {
+                    if shorted {
+                        self.printer.token("?.");
+                    }
+                    self.arguments(args);
+                }
+            }
+        }
+    }
+
+    fn template_literal(&mut self, elements: &[TemplateElement]) {
+        let mut text = String::from("`");
+        for element in elements {
+            match element {
+                TemplateElement::String(sym) => {
+                    text.push_str(&template_string(self.interner.resolve_expect(*sym).utf16()));
+                }
+                TemplateElement::Expr(expression) => {
+                    text.push_str("${");
+                    self.printer.token(&text);
+                    self.allow_in(|generator| {
+                        generator.expression(expression, Precedence::Lowest);
+                    });
+                    text = String::from("}");
+                }
+            }
+        }
+        text.push('`');
+        self.printer.token(&text);
+    }
+
+    fn tagged_template(&mut self, template: &TaggedTemplate) {
+        self.expression(template.tag(), Precedence::Call);
+        let mut text = String::from("`");
+        for (i, raw) in template.raws().iter().enumerate() {
+            text.push_str(&self.resolve(*raw));
+            if let Some(expression) = template.exprs().get(i) {
+                text.push_str("${");
+                self.printer.token(&text);
+                self.allow_in(|generator| {
+                    generator.expression(expression, Precedence::Lowest);
+                });
+                text = String::from("}");
+            }
+        }
+        text.push('`');
+        self.printer.token(&text);
+    }
+
+    fn literal(&mut self, kind: &LiteralKind) {
+        match kind {
+            LiteralKind::String(sym) => self.string(*sym),
+            LiteralKind::Num(value) => self.number(*value),
+            LiteralKind::Int(value) => self.number(f64::from(*value)),
+            LiteralKind::BigInt(value) => self.printer.token(&format!("{value}n")),
+            LiteralKind::Bool(true) => self.printer.token("true"),
+            LiteralKind::Bool(false) => self.printer.token("false"),
+            LiteralKind::Null => self.printer.token("null"),
+            LiteralKind::Undefined => self.printer.token("undefined"),
+        }
+    }
+
+    /// Gets the precedence of `expression`.
+    fn precedence(&self, expression: &Expression) -> Precedence {
+        match expression {
+            Expression::Parenthesized(parenthesized) if self.minify => {
+                self.precedence(parenthesized.expression())
+            }
+            Expression::Literal(literal) => match literal.kind() {
+                LiteralKind::Num(value) if value.is_sign_negative() && !value.is_nan() => {
+                    Precedence::Prefix
+                }
+                LiteralKind::Int(value) if *value < 0 => Precedence::Prefix,
+                LiteralKind::BigInt(value) if value.sign() == Sign::Minus => Precedence::Prefix,
+                _ => Precedence::Member,
+            },
+            Expression::Spread(_) | Expression::Yield(_) => Precedence::Yield,
+            Expression::ArrowFunction(_)
+            | Expression::AsyncArrowFunction(_)
+            | Expression::Assign(_) => Precedence::Assign,
+            Expression::Conditional(_) => Precedence::Conditional,
+            Expression::Binary(binary) => Precedence::of(binary.op()),
+            Expression::BinaryInPrivate(_) => Precedence::Relational,
+            Expression::Unary(_) | Expression::Await(_) => Precedence::Prefix,
+            Expression::Update(_) => Precedence::Postfix,
+            Expression::Optional(_) => Precedence::Optional,
+            Expression::Call(_) | Expression::SuperCall(_) | Expression::ImportCall(_) => {
+                Precedence::Call
+            }
+            _ => Precedence::Member,
+        }
+    }
+
+    /// Checks if `expression` is an `in` expression.
+    fn is_in(&self, expression: &Expression) -> bool {
+        match expression {
+            Expression::Binary(binary) => binary.op() == BinaryOp::Relational(RelationalOp::In),
+            Expression::BinaryInPrivate(_) => true,
+            Expression::Parenthesized(parenthesized) if self.minify => {
+                self.is_in(parenthesized.expression())
+            }
+            _ => false,
+        }
+    }
+
+    /// Writes an expression, parenthesized if its precedence is lower than `level`.
+    fn expression(&mut self, expression: &Expression, level: Precedence) {
+        if self.precedence(expression) < level || (self.no_in && self.is_in(expression)) {
+            self.parenthesized(expression);
+        } else {
+            self.expression_unparenthesized(expression);
+        }
+    }
+
+    /// Writes an expression at a position where some expressions must be parenthesized.
+    fn expression_at(&mut self, expression: &Expression, level: Precedence, start: Start) {
+        if self.starts_ambiguously(expression, start) {
+            self.parenthesized(expression);
+        } else {
+            self.expression(expression, level);
+        }
+    }
+
+    fn parenthesized(&mut self, expression: &Expression) {
+        self.printer.token("(");
+        self.allow_in(|generator| generator.expression(expression, Precedence::Lowest));
+        self.printer.token(")");
+    }
+
+    /// Checks if the code of `expression` starts with something that would be read differently
+    /// at `start`.
+    fn starts_ambiguously(&self, expression: &Expression, start: Start) -> bool {
+        let is_let = |ident: Identifier| {
+            ident.sym() == Sym::LET && matches!(start, Start::Statement | Start::ForInit)
+        };
+        match expression {
+            Expression::ObjectLiteral(_) => matches!(start, Start::Statement | Start::ArrowBody),
+            Expression::FunctionExpression(_)
+            | Expression::GeneratorExpression(_)
+            | Expression::AsyncFunctionExpression(_)
+            | Expression::AsyncGeneratorExpression(_)
+            | Expression::ClassExpression(_) => {
+                matches!(start, Start::Statement | Start::ExportDefault)
+            }
+            Expression::Identifier(ident) => is_let(*ident),
+            Expression::Assign(assign) => match assign.lhs() {
+                AssignTarget::Identifier(ident) => is_let(*ident),
+                AssignTarget::Access(access) => self.access_starts_ambiguously(access, start),
+                AssignTarget::Pattern(Pattern::Object(_)) => {
+                    matches!(start, Start::Statement | Start::ArrowBody)
+                }
+                AssignTarget::Pattern(Pattern::Array(_)) => false,
+            },
+            Expression::Update(update)
+                if matches!(
+                    update.op(),
+                    UpdateOp::IncrementPost | UpdateOp::DecrementPost
+                ) =>
+            {
+                match update.target() {
+                    UpdateTarget::Identifier(ident) => is_let(*ident),
+                    UpdateTarget::PropertyAccess(access) => {
+                        self.access_starts_ambiguously(access, start)
+                    }
+                }
+            }
+            Expression::PropertyAccess(access) => self.access_starts_ambiguously(access, start),
+            Expression::Binary(binary) => self.starts_ambiguously(binary.lhs(), start),
+            Expression::Conditional(conditional) => {
+                self.starts_ambiguously(conditional.condition(), start)
+            }
+            Expression::Call(call) => self.starts_ambiguously(call.function(), start),
+            Expression::Optional(optional) => self.starts_ambiguously(optional.target(), start),
+            Expression::TaggedTemplate(template) => self.starts_ambiguously(template.tag(), start),
+            Expression::Parenthesized(parenthesized) => {
+                self.minify && self.starts_ambiguously(parenthesized.expression(), start)
+            }
+            _ => false,
+        }
+    }
+
+    fn access_starts_ambiguously(&self, access: &PropertyAccess, start: Start) -> bool {
+        match access {
+            PropertyAccess::Simple(access) => self.starts_ambiguously(access.target(), start),
+            PropertyAccess::Private(access) => self.starts_ambiguously(access.target(), start),
+            PropertyAccess::Super(_) => false,
+        }
+    }
+
+    /// Checks if `expression` contains a call that isn't parenthesized, which would take the
+    /// arguments of a `new` expression that it is the constructor of.
+    fn has_call(&self, expression: &Expression) -> bool {
+        match expression {
+            Expression::Call(_) | Expression::SuperCall(_) | Expression::ImportCall(_) => true,
+            Expression::PropertyAccess(PropertyAccess::Simple(access)) => {
+                self.has_call(access.target())
+            }
+            Expression::PropertyAccess(PropertyAccess::Private(access)) => {
+                self.has_call(access.target())
+            }
+            Expression::TaggedTemplate(template) => self.has_call(template.tag()),
+            Expression::Parenthesized(parenthesized) if self.minify => {
+                self.has_call(parenthesized.expression())
+            }
+            _ => false,
+        }
+    }
+
+    /// Checks if `expression` is a `&&` or `||` expression, which can't be mixed with `??`
+    /// without parentheses.
+    fn is_and_or(&self, expression: &Expression) -> bool {
+        match expression {
+            Expression::Binary(binary) => matches!(
+                binary.op(),
+                BinaryOp::Logical(LogicalOp::And | LogicalOp::Or)
+            ),
+            Expression::Parenthesized(parenthesized) if self.minify => {
+                self.is_and_or(parenthesized.expression())
+            }
+            _ => false,
+        }
+    }
+
+    fn binary(&mut self, binary: &Binary) {
+        let op = binary.op();
+        let precedence = Precedence::of(op);
+        let (lhs, rhs) = match op {
+            BinaryOp::Comma => (Precedence::Comma, Precedence::Comma),
+            // `**` is right-associative, and its left operand can't be a unary expression.
+            BinaryOp::Arithmetic(ArithmeticOp::Exp) => {
+                (Precedence::Postfix, Precedence::Exponentiation)
+            }
+            BinaryOp::Logical(LogicalOp::Coalesce) => {
+                let level = |expression: &Expression| {
+                    if self.is_and_or(expression) {
+                        Precedence::Member
+                    } else {
+                        precedence
+                    }
+                };
+                (
+                    level(binary.lhs()),
+                    level(binary.rhs()).max(precedence.next()),
+                )
+            }
+            _ => (precedence, precedence.next()),
+        };
+
+        self.expression(binary.lhs(), lhs);
+        if op != BinaryOp::Comma {
+            self.printer.space();
+        }
+        self.printer.token(&op.to_string());
+        self.printer.space();
+        self.expression(binary.rhs(), rhs);
+    }
+
+    fn update_target(&mut self, target: &UpdateTarget) {
+        match target {
+            UpdateTarget::Identifier(ident) => self.identifier(*ident),
+            UpdateTarget::PropertyAccess(access) => self.property_access(access),
+        }
+    }
+
+    fn expression_unparenthesized(&mut self, expression: &Expression) {
+        match expression {
+            Expression::This(_) => self.printer.token("this"),
+            Expression::Identifier(ident) => self.identifier(*ident),
+            Expression::Literal(literal) => self.literal(literal.kind()),
+            Expression::RegExpLiteral(regexp) => {
+                let regexp = format!(
+                    "/{}/{}",
+                    self.resolve(regexp.pattern()),
+                    self.resolve(regexp.flags())
+                );
+                self.printer.token(&regexp);
+            }
+            Expression::ArrayLiteral(array) => self.array_literal(array),
+            Expression::ObjectLiteral(object) => self.object_literal(object),
+            Expression::Spread(spread) => {
+                self.printer.token("...");
+                self.expression(spread.target(), Precedence::Yield);
+            }
+            Expression::FunctionExpression(f) => {
+                let name = f.name().filter(|_| f.has_binding_identifier());
+                self.with_scope(f.name_scope(), |generator| {
+                    generator.function(
+                        FunctionKind::Ordinary,
+                        name,
+                        f.parameters(),
+                        f.body(),
+                        f.scopes(),
+                    );
+                });
+            }
+            Expression::GeneratorExpression(f) => {
+                let name = f.name().filter(|_| f.has_binding_identifier());
+                self.with_scope(f.name_scope(), |generator| {
+                    generator.function(
+                        FunctionKind::Generator,
+                        name,
+                        f.parameters(),
+                        f.body(),
+                        f.scopes(),
+                    );
+                });
+            }
+            Expression::AsyncFunctionExpression(f) => {
+                let name = f.name().filter(|_| f.has_binding_identifier());
+                self.with_scope(f.name_scope(), |generator| {
+                    generator.function(
+                        FunctionKind::Async,
+                        name,
+                        f.parameters(),
+                        f.body(),
+                        f.scopes(),
+                    );
+                });
+            }
+            Expression::AsyncGeneratorExpression(f) => {
+                let name = f.name().filter(|_| f.has_binding_identifier());
+                self.with_scope(f.name_scope(), |generator| {
+                    generator.function(
+                        FunctionKind::AsyncGenerator,
+                        name,
+                        f.parameters(),
+                        f.body(),
+                        f.scopes(),
+                    );
+                });
+            }
+            Expression::ArrowFunction(f) => {
+                self.arrow_function(false, f.parameters(), f.body(), f.scopes());
+            }
+            Expression::AsyncArrowFunction(f) => {
+                self.arrow_function(true, f.parameters(), f.body(), f.scopes());
+            }
+            Expression::ClassExpression(class) => self.class_expression(class),
+            Expression::TemplateLiteral(template) => self.template_literal(template.elements()),
+            Expression::PropertyAccess(access) => self.property_access(access),
+            Expression::New(new) => {
+                self.printer.token("new");
+                let constructor = new.constructor();
+                if self.has_call(constructor) {
+                    self.parenthesized(constructor);
+                } else {
+                    self.expression(constructor, Precedence::Member);
+                }
+                self.arguments(new.arguments());
+            }
+            Expression::Call(call) => {
+                self.expression(call.function(), Precedence::Call);
+                self.arguments(call.args());
+            }
+            Expression::SuperCall(call) => {
+                self.printer.token("super");
+                self.arguments(call.arguments());
+            }
+            Expression::ImportCall(call) => {
+                self.printer.token("import");
+                self.printer.token("(");
+                self.allow_in(|generator| {
+                    generator.expression(call.argument(), Precedence::Yield);
+                    if let Some(options) = call.options() {
+                        generator.printer.token(",");
+                        generator.printer.space();
+                        generator.expression(options, Precedence::Yield);
+                    }
+                });
+                self.printer.token(")");
+            }
+            Expression::Optional(optional) => self.optional(optional),
+            Expression::TaggedTemplate(template) => self.tagged_template(template),
+            Expression::NewTarget(_) => {
+                self.printer.token("new");
+                self.printer.token(".");
+                self.printer.token("target");
+            }
+            Expression::ImportMeta(_) => {
+                self.printer.token("import");
+                self.printer.token(".");
+                self.printer.token("meta");
+            }
+            Expression::Assign(assign) => {
+                match assign.lhs() {
+                    AssignTarget::Identifier(ident) => self.identifier(*ident),
+                    AssignTarget::Access(access) => self.property_access(access),
+                    AssignTarget::Pattern(pattern) => self.pattern(pattern),
+                }
+                self.printer.space();
+                self.printer.token(&assign.op().to_string());
+                self.printer.space();
+                self.expression(assign.rhs(), Precedence::Yield);
+            }
+            Expression::Unary(unary) => {
+                self.printer.token(&unary.op().to_string());
+                self.expression(unary.target(), Precedence::Prefix);
+            }
+            Expression::Update(update) => match update.op() {
+                UpdateOp::IncrementPre | UpdateOp::DecrementPre => {
+                    self.printer.token(&update.op().to_string());
+                    self.update_target(update.target());
+                }
+                UpdateOp::IncrementPost | UpdateOp::DecrementPost => {
+                    self.update_target(update.target());
+                    self.printer.token(&update.op().to_string());
+                }
+            },
+            Expression::Binary(binary) => self.binary(binary),
+            Expression::BinaryInPrivate(binary) => {
+                self.private_name(*binary.lhs());
+                self.printer.space();
+                self.printer.token("in");
+                self.printer.space();
+                self.expression(binary.rhs(), Precedence::Shift);
+            }
+            Expression::Conditional(conditional) => {
+                self.expression(conditional.condition(), Precedence::Coalesce);
+                self.printer.space();
+                self.printer.token("?");
+                self.printer.space();
+                self.allow_in(|generator| {
+                    generator.expression(conditional.if_true(), Precedence::Yield);
+                });
+                self.printer.space();
+                self.printer.token(":");
+                self.printer.space();
+                self.expression(conditional.if_false(), Precedence::Yield);
+            }
+            Expression::Await(expression) => {
+                self.printer.token("await");
+                self.printer.space();
+                self.expression(expression.target(), Precedence::Prefix);
+            }
+            Expression::Yield(expression) => {
+                self.printer.token("yield");
+                if expression.delegate() {
+                    self.printer.token("*");
+                }
+                if let Some(target) = expression.target() {
+                    self.printer.space();
+                    self.expression(target, Precedence::Yield);
+                }
+            }
+            Expression::Parenthesized(parenthesized) => {
+                if self.minify {
+                    self.expression_unparenthesized(parenthesized.expression());
+                } else {
+                    self.parenthesized(parenthesized.expression());
+                }
+            }
+            Expression::FormalParameterList(parameters) => self.parameters(parameters),
+            Expression::Debugger(_) => self.printer.token("debugger"),
+        }
+    }
+
+    fn module_item(&mut self, item: &ModuleItem) {
+        match item {
+            ModuleItem::ImportDeclaration(import) => {
+                self.comments_before(import.span());
+                self.import_declaration(import);
+            }
+            ModuleItem::ExportDeclaration(export) => {
+                let span = match export {
+                    ExportDeclaration::VarStatement(var) => var.span(),
+                    ExportDeclaration::Declaration(declaration) => declaration.span(),
+                    ExportDeclaration::DefaultFunctionDeclaration(f) => f.span(),
+                    ExportDeclaration::DefaultGeneratorDeclaration(f) => f.span(),
+                    ExportDeclaration::DefaultAsyncFunctionDeclaration(f) => f.span(),
+                    ExportDeclaration::DefaultAsyncGeneratorDeclaration(f) => f.span(),
+                    ExportDeclaration::DefaultClassDeclaration(class) => class.span(),
+                    ExportDeclaration::DefaultAssignmentExpression(expression) => expression.span(),
+                    ExportDeclaration::ReExport { .. } | ExportDeclaration::List(_) => None,
+                };
+                self.comments_before(span);
+                self.export_declaration(export);
+            }
+            ModuleItem::StatementListItem(item) => self.statement_list_item(item),
+        }
+    }
+
+    fn import_declaration(&mut self, import: &ImportDeclaration) {
+        self.printer.token("import");
+        self.printer.space();
+        let mut specifiers = false;
+        if let Some(default) = import.default() {
+            self.identifier(default);
+            specifiers = true;
+        }
+        match import.kind() {
+            ImportKind::DefaultOrUnnamed => {}
+            ImportKind::Namespaced { binding } => {
+                if specifiers {
+                    self.printer.token(",");
+                    self.printer.space();
+                }
+                self.keyword("*");
+                self.keyword("as");
+                self.identifier(*binding);
+                specifiers = true;
+            }
+            ImportKind::Named { names } => {
+                if specifiers {
+                    self.printer.token(",");
+                    self.printer.space();
+                }
+                self.printer.token("{");
+                if !names.is_empty() {
+                    self.printer.space();
+                    self.comma_separated(names, |generator, specifier| {
+                        if specifier.export_name() != specifier.binding().sym() {
+                            generator.name_or_string(specifier.export_name());
+                            generator.printer.space();
+                            generator.keyword("as");
+                        }
+                        generator.identifier(specifier.binding());
+                    });
+                    self.printer.space();
+                }
+                self.printer.token("}");
+                specifiers = true;
+            }
+        }
+        if specifiers {
+            self.printer.space();
+            self.keyword("from");
+        }
+        self.string(import.specifier().sym());
+        self.import_attributes(import.attributes());
+        self.printer.semicolon();
+    }
+
+    fn import_attributes(&mut self, attributes: &[ImportAttribute]) {
+        if attributes.is_empty() {
+            return;
+        }
+        self.printer.space();
+        self.keyword("with");
+        self.printer.token("{");
+        self.printer.space();
+        self.comma_separated(attributes, |generator, attribute| {
+            generator.name_or_string(attribute.key());
+            generator.printer.token(":");
+            generator.printer.space();
+            generator.string(attribute.value());
+        });
+        self.printer.space();
+        self.printer.token("}");
+    }
+
+    fn export_specifiers(&mut self, specifiers: &[ExportSpecifier]) {
+        self.printer.token("{");
+        if !specifiers.is_empty() {
+            self.printer.space();
+            self.comma_separated(specifiers, |generator, specifier| {
+                if specifier.string_literal() {
+                    generator.string(specifier.private_name());
+                } else {
+                    generator.sym(specifier.private_name());
+                }
+                if specifier.alias() != specifier.private_name() {
+                    generator.printer.space();
+                    generator.keyword("as");
+                    generator.name_or_string(specifier.alias());
+                }
+            });
+            self.printer.space();
+        }
+        self.printer.token("}");
+    }
+
+    fn export_declaration(&mut self, export: &ExportDeclaration) {
+        self.keyword("export");
+        match export {
+            ExportDeclaration::ReExport {
+                kind,
+                specifier,
+                attributes,
+            } => {
+                match kind {
+                    ReExportKind::Namespaced { name } => {
+                        self.printer.token("*");
+                        if let Some(name) = name {
+                            self.printer.space();
+                            self.keyword("as");
+                            self.name_or_string(*name);
+                        }
+                    }
+                    ReExportKind::Named { names } => self.export_specifiers(names),
+                }
+                self.printer.space();
+                self.keyword("from");
+                self.string(specifier.sym());
+                self.import_attributes(attributes);
+                self.printer.semicolon();
+            }
+            ExportDeclaration::List(names) => {
+                self.export_specifiers(names);
+                self.printer.semicolon();
+            }
+            ExportDeclaration::VarStatement(var) => {
+                self.var_declaration(var);
+                self.printer.semicolon();
+            }
+            ExportDeclaration::Declaration(declaration) => self.declaration(declaration),
+            ExportDeclaration::DefaultFunctionDeclaration(f) => {
+                self.keyword("default");
+                self.function(
+                    FunctionKind::Ordinary,
+                    default_name(f.name()),
+                    f.parameters(),
+                    f.body(),
+                    f.scopes(),
+                );
+            }
+            ExportDeclaration::DefaultGeneratorDeclaration(f) => {
+                self.keyword("default");
+                self.function(
+                    FunctionKind::Generator,
+                    default_name(f.name()),
+                    f.parameters(),
+                    f.body(),
+                    f.scopes(),
+                );
+            }
+            ExportDeclaration::DefaultAsyncFunctionDeclaration(f) => {
+                self.keyword("default");
+                self.function(
+                    FunctionKind::Async,
+                    default_name(f.name()),
+                    f.parameters(),
+                    f.body(),
+                    f.scopes(),
+                );
+            }
+            ExportDeclaration::DefaultAsyncGeneratorDeclaration(f) => {
+                self.keyword("default");
+                self.function(
+                    FunctionKind::AsyncGenerator,
+                    default_name(f.name()),
+                    f.parameters(),
+                    f.body(),
+                    f.scopes(),
+                );
+            }
+            ExportDeclaration::DefaultClassDeclaration(class) => {
+                self.keyword("default");
+                self.class_declaration(class, default_name(class.name()));
+            }
+            ExportDeclaration::DefaultAssignmentExpression(expression) => {
+                self.keyword("default");
+                self.expression_at(expression, Precedence::Yield, Start::ExportDefault);
+                self.printer.semicolon();
+            }
+        }
+    }
+}
+
+/// Checks if an `else` after `statement` would belong to an inner `if` statement.
+fn ends_with_if_without_else(statement: &Statement) -> bool {
+    match statement {
+        Statement::If(r#if) => r#if.else_node().is_none_or(ends_with_if_without_else),
+        Statement::Labelled(labelled) => match labelled.item() {
+            LabelledItem::Statement(statement) => ends_with_if_without_else(statement),
+            LabelledItem::FunctionDeclaration(_) => false,
+        },
+        Statement::WhileLoop(while_loop) => ends_with_if_without_else(while_loop.body()),
+        Statement::ForLoop(for_loop) => ends_with_if_without_else(for_loop.body()),
+        Statement::ForInLoop(for_in) => ends_with_if_without_else(for_in.body()),
+        Statement::ForOfLoop(for_of) => ends_with_if_without_else(for_of.body()),
+        Statement::With(with) => ends_with_if_without_else(with.statement()),
+        _ => false,
+    }
+}
+
+/// Anonymous default exports are parsed with the `default` name.
+fn default_name(name: Identifier) -> Option<Identifier> {
+    (name.sym() != Sym::DEFAULT).then_some(name)
+}
+
+fn is_non_negative_number(kind: &LiteralKind) -> bool {
+    match kind {
+        LiteralKind::Num(value) => value.is_sign_positive() && !value.is_nan(),
+        LiteralKind::Int(value) => *value >= 0,
+        LiteralKind::BigInt(value) => value.sign() != Sign::Minus,
+        _ => false,
+    }
+}
+
+/// Checks if `name` can be written as an identifier.
+fn is_identifier_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Writes a string literal with the given `quote`, escaping the characters that can't appear in
+/// it literally.
+fn quote_string(units: &[u16], quote: char) -> String {
+    let mut string = String::with_capacity(units.len() + 2);
+    string.push(quote);
+    let mut chars = char::decode_utf16(units.iter().copied()).peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            Ok('\\') => string.push_str("\\\\"),
+            Ok(c) if c == quote => {
+                string.push('\\');
+                string.push(c);
+            }
+            Ok('\n') => string.push_str("\\n"),
+            Ok('\r') => string.push_str("\\r"),
+            Ok('\t') => string.push_str("\\t"),
+            // `\0` can't be followed by a digit, since it would be read as an octal escape.
+            Ok('\0') if matches!(chars.peek(), Some(Ok(next)) if next.is_ascii_digit()) => {
+                string.push_str("\\x00");
+            }
+            Ok('\0') => string.push_str("\\0"),
+            Ok(c @ ('\u{2028}' | '\u{2029}')) => {
+                let _ = write!(string, "\\u{:04X}", u32::from(c));
+            }
+            Ok(c) if c.is_ascii_control() => {
+                let _ = write!(string, "\\x{:02X}", u32::from(c));
+            }
+            Ok(c) => string.push(c),
+            Err(e) => {
+                let _ = write!(string, "\\u{:04X}", e.unpaired_surrogate());
+            }
+        }
+    }
+    string.push(quote);
+    string
+}
+
+/// Escapes the cooked string of a template literal.
+fn template_string(units: &[u16]) -> String {
+    let mut string = String::with_capacity(units.len());
+    let mut chars = char::decode_utf16(units.iter().copied()).peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            Ok('\\') => string.push_str("\\\\"),
+            Ok('`') => string.push_str("\\`"),
+            Ok('$') if matches!(chars.peek(), Some(Ok('{'))) => string.push_str("\\$"),
+            // Line terminators in templates are normalized to `\n`.
+            Ok('\r') => string.push_str("\\r"),
+            Ok(c) => string.push(c),
+            Err(e) => {
+                let _ = write!(string, "\\u{:04X}", e.unpaired_surrogate());
+            }
+        }
+    }
+    string
+}
+
+/// Converts a non-negative number to its source code.
+///
+/// The pretty output uses the format of `Number.prototype.toString`, while the minified output
+/// uses the shortest equivalent literal.
+fn number_to_string(value: f64, minify: bool) -> String {
+    if value.is_nan() {
+        return "NaN".to_owned();
+    }
+    if value.is_infinite() {
+        return "1e999".to_owned();
+    }
+
+    // The shortest digits that round trip, and the exponent of the first one.
+    let scientific = format!("{value:e}");
+    let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+    let digits = mantissa.cow_replace('.', "");
+    let length = i64::try_from(digits.len()).unwrap_or(i64::MAX);
+    let point = exponent.parse::<i64>().unwrap_or_default() + 1;
+    let zeros = |count: i64| "0".repeat(usize::try_from(count).unwrap_or_default());
+
+    let decimal = if length <= point && point <= 21 {
+        format!("{digits}{}", zeros(point - length))
+    } else if 0 < point && point <= 21 {
+        let (integer, fraction) = digits.split_at(usize::try_from(point).unwrap_or_default());
+        format!("{integer}.{fraction}")
+    } else if -6 < point && point <= 0 {
+        format!("0.{}{digits}", zeros(-point))
+    } else {
+        let (first, rest) = digits.split_at(1);
+        let sign = if point > 0 { '+' } else { '-' };
+        let exponent = (point - 1).abs();
+        if rest.is_empty() {
+            format!("{first}e{sign}{exponent}")
+        } else {
+            format!("{first}.{rest}e{sign}{exponent}")
+        }
+    };
+    if !minify {
+        return decimal;
+    }
+
+    let mut shortest = match decimal.strip_prefix("0.") {
+        Some(fraction) => format!(".{fraction}"),
+        None => decimal,
+    };
+    let shift = point - length;
+    if shift != 0 {
+        let exponential = format!("{digits}e{shift}");
+        if exponential.len() < shortest.len() {
+            shortest = exponential;
+        }
+    }
+    shortest
+}
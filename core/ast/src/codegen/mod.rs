@@ -0,0 +1,78 @@
+//! Generation of JavaScript source code from Boa's AST.
+//!
+//! Unlike the [`ToInternedString`] and [`ToIndentedString`] implementations of the AST nodes,
+//! which are meant for debugging, the code generator guarantees that its output parses back to
+//! the same AST. Expressions are only parenthesized where the precedence and associativity of
+//! their operators require it, or where the code would otherwise be read as something else (e.g.
+//! an object literal at the start of an expression statement), and every statement is terminated
+//! explicitly, so the output never relies on automatic semicolon insertion.
+//!
+//! The [`Minified`][CodegenMode::Minified] mode removes all optional whitespace, parentheses and
+//! semicolons, and uses the scopes computed by the [`scope_analyzer`] to give short names to the
+//! local bindings. The scopes of the AST must have been analyzed for this, which the parser does.
+//!
+//! [`ToInternedString`]: boa_interner::ToInternedString
+//! [`ToIndentedString`]: boa_interner::ToIndentedString
+//! [`scope_analyzer`]: crate::scope_analyzer
+
+mod generator;
+mod precedence;
+mod printer;
+mod renamer;
+
+use boa_interner::Interner;
+
+use crate::{Module, Script};
+
+use self::generator::Generator;
+
+/// The style of the generated code.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CodegenMode {
+    /// Indented code with one statement per line.
+    ///
+    /// The recorded comments of the source code and its parenthesized expressions are kept.
+    #[default]
+    Pretty,
+
+    /// The shortest code that the generator can produce.
+    ///
+    /// Local bindings are renamed, unless the program contains a `with` statement or a direct
+    /// `eval` call, whose bindings can only be resolved at runtime. Renaming changes the `name`
+    /// of functions and classes that take it from their binding, and the result of
+    /// `Function.prototype.toString`. Comments are dropped, except for a hashbang.
+    Minified,
+}
+
+/// Generates the source code of a [`Script`].
+#[must_use]
+pub fn generate_script(script: &Script, interner: &Interner, mode: CodegenMode) -> String {
+    Generator::script(script, interner, mode, 0)
+}
+
+/// Generates the source code of a [`Module`].
+///
+/// The bindings of the top-level scope of the module are never renamed, so that its exports and
+/// the code in other modules can keep referring to them.
+#[must_use]
+pub fn generate_module(module: &Module, interner: &Interner, mode: CodegenMode) -> String {
+    Generator::module(module, interner, mode, 0)
+}
+
+/// Generates the pretty source code of a [`Script`], starting at the given indentation.
+pub(crate) fn generate_indented_script(
+    script: &Script,
+    interner: &Interner,
+    indentation: usize,
+) -> String {
+    Generator::script(script, interner, CodegenMode::Pretty, indentation)
+}
+
+/// Generates the pretty source code of a [`Module`], starting at the given indentation.
+pub(crate) fn generate_indented_module(
+    module: &Module,
+    interner: &Interner,
+    indentation: usize,
+) -> String {
+    Generator::module(module, interner, CodegenMode::Pretty, indentation)
+}
@@ -0,0 +1,89 @@
+//! Operator precedence, used to decide where the generated code needs parentheses.
+
+use crate::expression::operator::binary::{
+    ArithmeticOp, BinaryOp, BitwiseOp, LogicalOp, RelationalOp,
+};
+
+/// The precedence of an expression, from the loosest to the tightest binding.
+///
+/// An expression must be parenthesized when its precedence is lower than the one required by
+/// the position it is written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) enum Precedence {
+    Lowest,
+    Comma,
+    Yield,
+    Assign,
+    Conditional,
+    Coalesce,
+    LogicalOr,
+    LogicalAnd,
+    BitwiseOr,
+    BitwiseXor,
+    BitwiseAnd,
+    Equality,
+    Relational,
+    Shift,
+    Additive,
+    Multiplicative,
+    Exponentiation,
+    Prefix,
+    Postfix,
+    Optional,
+    Call,
+    Member,
+}
+
+impl Precedence {
+    /// Gets the precedence of a binary operator.
+    pub(super) const fn of(op: BinaryOp) -> Self {
+        match op {
+            BinaryOp::Comma => Self::Comma,
+            BinaryOp::Logical(LogicalOp::Coalesce) => Self::Coalesce,
+            BinaryOp::Logical(LogicalOp::Or) => Self::LogicalOr,
+            BinaryOp::Logical(LogicalOp::And) => Self::LogicalAnd,
+            BinaryOp::Bitwise(BitwiseOp::Or) => Self::BitwiseOr,
+            BinaryOp::Bitwise(BitwiseOp::Xor) => Self::BitwiseXor,
+            BinaryOp::Bitwise(BitwiseOp::And) => Self::BitwiseAnd,
+            BinaryOp::Bitwise(BitwiseOp::Shl | BitwiseOp::Shr | BitwiseOp::UShr) => Self::Shift,
+            BinaryOp::Relational(
+                RelationalOp::Equal
+                | RelationalOp::NotEqual
+                | RelationalOp::StrictEqual
+                | RelationalOp::StrictNotEqual,
+            ) => Self::Equality,
+            BinaryOp::Relational(_) => Self::Relational,
+            BinaryOp::Arithmetic(ArithmeticOp::Add | ArithmeticOp::Sub) => Self::Additive,
+            BinaryOp::Arithmetic(ArithmeticOp::Exp) => Self::Exponentiation,
+            BinaryOp::Arithmetic(_) => Self::Multiplicative,
+        }
+    }
+
+    /// Gets the next tighter precedence, required by the right operand of left-associative
+    /// operators.
+    pub(super) const fn next(self) -> Self {
+        match self {
+            Self::Lowest => Self::Comma,
+            Self::Comma => Self::Yield,
+            Self::Yield => Self::Assign,
+            Self::Assign => Self::Conditional,
+            Self::Conditional => Self::Coalesce,
+            Self::Coalesce => Self::LogicalOr,
+            Self::LogicalOr => Self::LogicalAnd,
+            Self::LogicalAnd => Self::BitwiseOr,
+            Self::BitwiseOr => Self::BitwiseXor,
+            Self::BitwiseXor => Self::BitwiseAnd,
+            Self::BitwiseAnd => Self::Equality,
+            Self::Equality => Self::Relational,
+            Self::Relational => Self::Shift,
+            Self::Shift => Self::Additive,
+            Self::Additive => Self::Multiplicative,
+            Self::Multiplicative => Self::Exponentiation,
+            Self::Exponentiation => Self::Prefix,
+            Self::Prefix => Self::Postfix,
+            Self::Postfix => Self::Optional,
+            Self::Optional => Self::Call,
+            Self::Call | Self::Member => Self::Member,
+        }
+    }
+}
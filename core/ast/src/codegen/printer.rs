@@ -0,0 +1,133 @@
+//! The output buffer of the code generator.
+
+/// Writes the tokens of the generated code, separating them only where needed.
+///
+/// In minified mode, the semicolon that terminates a statement is delayed until the next token,
+/// so that it can be dropped before a closing brace.
+#[derive(Debug)]
+pub(super) struct Printer {
+    buf: String,
+    minify: bool,
+    indentation: usize,
+    line_start: bool,
+    pending_semicolon: bool,
+
+    /// The end of the last written number, if it only contains decimal digits.
+    ///
+    /// A `.` right after such a number would be read as its decimal point.
+    integer_end: Option<usize>,
+}
+
+impl Printer {
+    /// Creates a new `Printer`, whose lines start at the given indentation.
+    pub(super) const fn new(minify: bool, indentation: usize) -> Self {
+        Self {
+            buf: String::new(),
+            minify,
+            indentation,
+            line_start: true,
+            pending_semicolon: false,
+            integer_end: None,
+        }
+    }
+
+    /// Finishes the output, returning the generated code.
+    pub(super) fn finish(mut self) -> String {
+        if self.pending_semicolon {
+            self.buf.push(';');
+        }
+        if !self.minify && !self.buf.is_empty() && !self.buf.ends_with('\n') {
+            self.buf.push('\n');
+        }
+        self.buf
+    }
+
+    /// Writes a token, separating it from the previous one if they would merge otherwise.
+    pub(super) fn token(&mut self, token: &str) {
+        let Some(first) = token.chars().next() else {
+            return;
+        };
+        if self.pending_semicolon {
+            self.pending_semicolon = false;
+            if first != '}' {
+                self.buf.push(';');
+            }
+        }
+        if self.line_start {
+            self.line_start = false;
+            for _ in 0..self.indentation {
+                self.buf.push_str("    ");
+            }
+        }
+        if let Some(last) = self.buf.chars().next_back() {
+            let separate = (is_word_char(last) && is_word_char(first))
+                || matches!(
+                    (last, first),
+                    ('+', '+') | ('-', '-' | '>') | ('/', '/' | '*') | ('<', '!') | ('?', '.')
+                )
+                || (first == '.' && self.integer_end == Some(self.buf.len()));
+            if separate {
+                self.buf.push(' ');
+            }
+        }
+        self.buf.push_str(token);
+    }
+
+    /// Writes a numeric literal.
+    pub(super) fn number(&mut self, number: &str) {
+        self.token(number);
+        if number.bytes().all(|b| b.is_ascii_digit()) {
+            self.integer_end = Some(self.buf.len());
+        }
+    }
+
+    /// Writes a comment on its own line.
+    pub(super) fn comment(&mut self, comment: &str) {
+        if !self.line_start && !self.buf.is_empty() {
+            self.buf.push('\n');
+            self.line_start = true;
+        }
+        self.token(comment);
+        self.buf.push('\n');
+        self.line_start = true;
+    }
+
+    /// Writes a space, which is omitted in minified mode.
+    pub(super) fn space(&mut self) {
+        if !self.minify {
+            self.buf.push(' ');
+        }
+    }
+
+    /// Starts a new line, which is omitted in minified mode.
+    pub(super) fn newline(&mut self) {
+        if !self.minify {
+            self.buf.push('\n');
+            self.line_start = true;
+        }
+    }
+
+    /// Terminates a statement.
+    pub(super) fn semicolon(&mut self) {
+        if self.minify {
+            self.pending_semicolon = true;
+        } else {
+            self.token(";");
+        }
+    }
+
+    pub(super) fn indent(&mut self) {
+        self.indentation += 1;
+    }
+
+    pub(super) fn dedent(&mut self) {
+        self.indentation = self.indentation.saturating_sub(1);
+    }
+}
+
+/// Checks if `c` can be part of an identifier, keyword or number.
+///
+/// Non-ASCII characters are conservatively treated as identifier characters.
+const fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '$' | '\\') || !c.is_ascii()
+}
@@ -0,0 +1,253 @@
+//! Renaming of the local bindings for the minified output.
+
+use core::ops::ControlFlow;
+
+use boa_interner::Sym;
+use boa_string::JsString;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    expression::Call,
+    scope::Scope,
+    statement::With,
+    visitor::{VisitWith, Visitor},
+    Expression,
+};
+
+/// Names that renamed bindings can never take.
+const RESERVED: &[&str] = &[
+    "do",
+    "if",
+    "in",
+    "for",
+    "let",
+    "new",
+    "try",
+    "var",
+    "NaN",
+    "case",
+    "else",
+    "enum",
+    "eval",
+    "null",
+    "this",
+    "true",
+    "void",
+    "with",
+    "await",
+    "break",
+    "catch",
+    "class",
+    "const",
+    "false",
+    "super",
+    "throw",
+    "while",
+    "yield",
+    "delete",
+    "export",
+    "import",
+    "public",
+    "return",
+    "static",
+    "switch",
+    "typeof",
+    "default",
+    "extends",
+    "finally",
+    "package",
+    "private",
+    "continue",
+    "debugger",
+    "function",
+    "Infinity",
+    "arguments",
+    "interface",
+    "protected",
+    "undefined",
+    "implements",
+    "instanceof",
+];
+
+/// Assigns short names to the bindings of the local scopes of a program.
+///
+/// The bindings of the global scope and of the top-level scope of a module are never renamed,
+/// since other scripts and modules can access them by name. The bindings of any other scope take
+/// the shortest names that are not used by the scopes enclosing it, skipping the names of
+/// bindings that aren't renamed.
+#[derive(Debug)]
+pub(super) struct Renamer {
+    top_level: Option<Scope>,
+    reserved: FxHashSet<String>,
+    free: FxHashSet<String>,
+    frames: FxHashMap<*const (), Frame>,
+}
+
+/// The new names of the bindings of a scope.
+#[derive(Debug, Default)]
+struct Frame {
+    names: FxHashMap<JsString, Box<str>>,
+
+    /// The index of the next name that inner scopes can take.
+    next: usize,
+}
+
+impl Renamer {
+    /// Creates a new `Renamer`, which never renames the bindings of `top_level`, and never uses
+    /// the `reserved` names.
+    pub(super) fn new(top_level: Option<Scope>, reserved: FxHashSet<String>) -> Self {
+        Self {
+            top_level,
+            reserved,
+            free: FxHashSet::default(),
+            frames: FxHashMap::default(),
+        }
+    }
+
+    /// Returns the names that were resolved to a binding that is not renamed, or to no binding
+    /// at all.
+    ///
+    /// Renaming the bindings of the program again without using these names guarantees that no
+    /// renamed binding shadows them.
+    pub(super) fn into_free_names(self) -> FxHashSet<String> {
+        self.free
+    }
+
+    /// Gets the new name of the binding that `name` resolves to from `scope`, if it is renamed.
+    pub(super) fn resolve(&mut self, scope: &Scope, name: &JsString) -> Option<Box<str>> {
+        let mut current = Some(scope.clone());
+        let renamed = loop {
+            let Some(scope) = current else {
+                break None;
+            };
+            if scope.has_binding(name) {
+                break self.frame(&scope).names.get(name).cloned();
+            }
+            current = scope.outer();
+        };
+        if renamed.is_none() {
+            self.free.insert(name.to_std_string_escaped());
+        }
+        renamed
+    }
+
+    fn frame(&mut self, scope: &Scope) -> &Frame {
+        let addr = scope.addr();
+        if !self.frames.contains_key(&addr) {
+            let frame = self.new_frame(scope);
+            self.frames.insert(addr, frame);
+        }
+        &self.frames[&addr]
+    }
+
+    fn new_frame(&mut self, scope: &Scope) -> Frame {
+        let Some(outer) = scope.outer() else {
+            return Frame::default();
+        };
+        if self
+            .top_level
+            .as_ref()
+            .is_some_and(|top_level| top_level.addr() == scope.addr())
+        {
+            return Frame::default();
+        }
+
+        let mut next = self.frame(&outer).next;
+        let mut names = FxHashMap::default();
+        for binding in scope.bindings() {
+            // The arguments object is bound implicitly.
+            if binding.name == "arguments" {
+                continue;
+            }
+            // Some bindings must keep the name of an enclosing binding of the same function, e.g.
+            // the inner binding of a class name or the var binding of a block-level function.
+            let inherited = if scope.is_function() {
+                None
+            } else {
+                self.inherited(&outer, &binding.name)
+            };
+            let name = match inherited {
+                Some(name) => name,
+                None => Some(self.fresh_name(&mut next)),
+            };
+            if let Some(name) = name {
+                names.insert(binding.name, name);
+            }
+        }
+        Frame { names, next }
+    }
+
+    /// Gets the new name of the binding `name` of `scope` or its enclosing scopes, up to the
+    /// enclosing function scope.
+    ///
+    /// Returns `None` if no such binding exists, and `Some(None)` if the binding is not renamed.
+    #[allow(clippy::option_option)]
+    fn inherited(&mut self, scope: &Scope, name: &JsString) -> Option<Option<Box<str>>> {
+        let mut scope = scope.clone();
+        loop {
+            if scope.has_binding(name) {
+                return Some(self.frame(&scope).names.get(name).cloned());
+            }
+            // The var bindings of a script are not part of the global scope, so a binding of a
+            // top-level block could be one of them, e.g. a block-level function.
+            if scope.is_global() {
+                return Some(None);
+            }
+            if scope.is_function() {
+                return None;
+            }
+            scope = scope.outer()?;
+        }
+    }
+
+    fn fresh_name(&self, next: &mut usize) -> Box<str> {
+        loop {
+            let name = short_name(*next);
+            *next += 1;
+            if !RESERVED.contains(&name.as_str()) && !self.reserved.contains(&name) {
+                return name.into_boxed_str();
+            }
+        }
+    }
+}
+
+/// Gets the `index`-th shortest identifier name.
+fn short_name(mut index: usize) -> String {
+    const FIRST: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ_$";
+    const REST: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ_$0123456789";
+
+    let mut name = String::from(char::from(FIRST[index % FIRST.len()]));
+    index /= FIRST.len();
+    while index > 0 {
+        index -= 1;
+        name.push(char::from(REST[index % REST.len()]));
+        index /= REST.len();
+    }
+    name
+}
+
+/// Checks if `node` contains a `with` statement or a direct `eval` call, whose bindings can only
+/// be resolved at runtime.
+pub(super) fn has_dynamic_scope<N: VisitWith>(node: &N) -> bool {
+    node.visit_with(&mut DynamicScopeFinder).is_break()
+}
+
+#[derive(Debug)]
+struct DynamicScopeFinder;
+
+impl<'ast> Visitor<'ast> for DynamicScopeFinder {
+    type BreakTy = ();
+
+    fn visit_with(&mut self, _node: &'ast With) -> ControlFlow<Self::BreakTy> {
+        ControlFlow::Break(())
+    }
+
+    fn visit_call(&mut self, node: &'ast Call) -> ControlFlow<Self::BreakTy> {
+        if let Expression::Identifier(ident) = node.function().flatten() {
+            if ident.sym() == Sym::EVAL {
+                return ControlFlow::Break(());
+            }
+        }
+        node.visit_with(self)
+    }
+}
@@ -0,0 +1,110 @@
+//! Comments of the source code.
+//!
+//! Comments are not part of the AST, since they don't affect the execution of the code. The
+//! parser can optionally record them in a side table attached to the parsed [`Script`] or
+//! [`Module`], which tools like formatters and documentation extractors can query by [`Span`].
+//!
+//! [`Script`]: crate::Script
+//! [`Module`]: crate::Module
+
+use std::fmt;
+
+use crate::{Position, Span};
+
+/// The kind of a [`Comment`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CommentKind {
+    /// A single line comment (`// comment`).
+    ///
+    /// HTML-like comments (`<!-- comment` and `--> comment`) are recorded as single line
+    /// comments too.
+    SingleLine,
+
+    /// A multi-line comment (`/* comment */`).
+    MultiLine,
+
+    /// A hashbang comment (`#!/usr/bin/env node`), which can only appear at the start of the
+    /// source.
+    Hashbang,
+}
+
+/// A comment of the source code.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Comment {
+    kind: CommentKind,
+    text: Box<str>,
+    span: Span,
+}
+
+impl Comment {
+    /// Creates a new `Comment`.
+    ///
+    /// `text` is the content of the comment, without its delimiters.
+    #[inline]
+    #[must_use]
+    pub fn new<T: Into<Box<str>>>(kind: CommentKind, text: T, span: Span) -> Self {
+        Self {
+            kind,
+            text: text.into(),
+            span,
+        }
+    }
+
+    /// Gets the kind of the comment.
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> CommentKind {
+        self.kind
+    }
+
+    /// Gets the content of the comment, without its delimiters.
+    #[inline]
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Gets the span of the comment, including its delimiters.
+    #[inline]
+    #[must_use]
+    pub const fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Checks if this is a documentation comment (`/** comment */`).
+    #[inline]
+    #[must_use]
+    pub fn is_doc(&self) -> bool {
+        self.kind == CommentKind::MultiLine && self.text.starts_with('*') && &*self.text != "*"
+    }
+}
+
+impl fmt::Display for Comment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            CommentKind::SingleLine => write!(f, "//{}", self.text),
+            CommentKind::MultiLine => write!(f, "/*{}*/", self.text),
+            CommentKind::Hashbang => write!(f, "#!{}", self.text),
+        }
+    }
+}
+
+/// Gets the comments of `comments` that end before `position`.
+///
+/// `comments` must be sorted by position, as they are when recorded by the parser.
+#[must_use]
+pub fn comments_before(comments: &[Comment], position: Position) -> &[Comment] {
+    &comments[..comments.partition_point(|comment| comment.span.end() <= position)]
+}
+
+/// Gets the comments of `comments` that are contained in `span`.
+///
+/// `comments` must be sorted by position, as they are when recorded by the parser.
+#[must_use]
+pub fn comments_within(comments: &[Comment], span: Span) -> &[Comment] {
+    let start = comments.partition_point(|comment| comment.span.start() < span.start());
+    let end = comments.partition_point(|comment| comment.span.end() <= span.end());
+    &comments[start..end.max(start)]
+}
@@ -13,11 +13,13 @@ use std::ops::ControlFlow;
 
 use crate::{
     expression::Identifier,
+    position::impl_spanned,
     visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
 };
 use boa_interner::Sym;
 
-use super::ModuleSpecifier;
+use super::{ModuleRequest, ModuleSpecifier};
 
 /// The kind of import in an [`ImportDeclaration`].
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -87,6 +89,9 @@ pub struct ImportDeclaration {
     kind: ImportKind,
     /// Module specifier.
     specifier: ModuleSpecifier,
+    /// Import attributes of the `with` clause.
+    attributes: Box<[ImportAttribute]>,
+    span: SpanIgnoreEq,
 }
 
 impl ImportDeclaration {
@@ -97,11 +102,14 @@ impl ImportDeclaration {
         default: Option<Identifier>,
         kind: ImportKind,
         specifier: ModuleSpecifier,
+        attributes: Box<[ImportAttribute]>,
     ) -> Self {
         Self {
             default,
             kind,
             specifier,
+            attributes,
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -125,8 +133,17 @@ impl ImportDeclaration {
     pub const fn kind(&self) -> &ImportKind {
         &self.kind
     }
+
+    /// Gets the import attributes of the import declaration.
+    #[inline]
+    #[must_use]
+    pub const fn attributes(&self) -> &[ImportAttribute] {
+        &self.attributes
+    }
 }
 
+impl_spanned!(ImportDeclaration);
+
 impl VisitWith for ImportDeclaration {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
@@ -136,7 +153,11 @@ impl VisitWith for ImportDeclaration {
             visitor.visit_identifier(default)?;
         }
         visitor.visit_import_kind(&self.kind)?;
-        visitor.visit_module_specifier(&self.specifier)
+        visitor.visit_module_specifier(&self.specifier)?;
+        for attribute in &*self.attributes {
+            visitor.visit_import_attribute(attribute)?;
+        }
+        ControlFlow::Continue(())
     }
 
     fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
@@ -147,7 +168,11 @@ impl VisitWith for ImportDeclaration {
             visitor.visit_identifier_mut(default)?;
         }
         visitor.visit_import_kind_mut(&mut self.kind)?;
-        visitor.visit_module_specifier_mut(&mut self.specifier)
+        visitor.visit_module_specifier_mut(&mut self.specifier)?;
+        for attribute in &mut *self.attributes {
+            visitor.visit_import_attribute_mut(attribute)?;
+        }
+        ControlFlow::Continue(())
     }
 }
 
@@ -209,6 +234,64 @@ impl VisitWith for ImportSpecifier {
     }
 }
 
+/// Import attribute.
+///
+/// An entry of the `with` clause of an import declaration or a re-export, such as
+/// `type: "json"`.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-AttributeEntry
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ImportAttribute {
+    key: Sym,
+    value: Sym,
+}
+
+impl ImportAttribute {
+    /// Creates a new [`ImportAttribute`].
+    #[inline]
+    #[must_use]
+    pub const fn new(key: Sym, value: Sym) -> Self {
+        Self { key, value }
+    }
+
+    /// Gets the key of the import attribute.
+    #[inline]
+    #[must_use]
+    pub const fn key(self) -> Sym {
+        self.key
+    }
+
+    /// Gets the value of the import attribute.
+    #[inline]
+    #[must_use]
+    pub const fn value(self) -> Sym {
+        self.value
+    }
+}
+
+impl VisitWith for ImportAttribute {
+    fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: Visitor<'a>,
+    {
+        visitor.visit_sym(&self.key)?;
+        visitor.visit_sym(&self.value)
+    }
+
+    fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: VisitorMut<'a>,
+    {
+        visitor.visit_sym_mut(&mut self.key)?;
+        visitor.visit_sym_mut(&mut self.value)
+    }
+}
+
 /// The name under which the imported binding is exported by a module.
 #[derive(Debug, Clone, Copy)]
 pub enum ImportName {
@@ -221,9 +304,9 @@ pub enum ImportName {
 /// [`ImportEntry`][spec] record.
 ///
 /// [spec]: https://tc39.es/ecma262/#table-importentry-record-fields
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct ImportEntry {
-    module_request: Sym,
+    module_request: ModuleRequest,
     import_name: ImportName,
     local_name: Identifier,
 }
@@ -231,7 +314,11 @@ pub struct ImportEntry {
 impl ImportEntry {
     /// Creates a new `ImportEntry`.
     #[must_use]
-    pub const fn new(module_request: Sym, import_name: ImportName, local_name: Identifier) -> Self {
+    pub const fn new(
+        module_request: ModuleRequest,
+        import_name: ImportName,
+        local_name: Identifier,
+    ) -> Self {
         Self {
             module_request,
             import_name,
@@ -241,8 +328,8 @@ impl ImportEntry {
 
     /// Gets the module from where the binding must be imported.
     #[must_use]
-    pub const fn module_request(&self) -> Sym {
-        self.module_request
+    pub const fn module_request(&self) -> &ModuleRequest {
+        &self.module_request
     }
 
     /// Gets the import name of the imported binding.
@@ -20,6 +20,7 @@ use super::function::{
 use crate::{
     function::ClassDeclaration,
     visitor::{VisitWith, Visitor, VisitorMut},
+    Span, Spanned,
 };
 use boa_interner::{Interner, Sym, ToIndentedString, ToInternedString};
 use core::ops::ControlFlow;
@@ -75,6 +76,30 @@ impl ToIndentedString for Declaration {
     }
 }
 
+impl Spanned for Declaration {
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::FunctionDeclaration(f) => f.span(),
+            Self::GeneratorDeclaration(g) => g.span(),
+            Self::AsyncFunctionDeclaration(af) => af.span(),
+            Self::AsyncGeneratorDeclaration(ag) => ag.span(),
+            Self::ClassDeclaration(c) => c.span(),
+            Self::Lexical(l) => l.span(),
+        }
+    }
+
+    fn set_span(&mut self, span: Span) {
+        match self {
+            Self::FunctionDeclaration(f) => f.set_span(span),
+            Self::GeneratorDeclaration(g) => g.set_span(span),
+            Self::AsyncFunctionDeclaration(af) => af.set_span(span),
+            Self::AsyncGeneratorDeclaration(ag) => ag.set_span(span),
+            Self::ClassDeclaration(c) => c.set_span(span),
+            Self::Lexical(l) => l.set_span(span),
+        }
+    }
+}
+
 impl VisitWith for Declaration {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
@@ -156,3 +181,45 @@ impl VisitWith for ModuleSpecifier {
         visitor.visit_sym_mut(&mut self.module)
     }
 }
+
+/// A module request.
+///
+/// This is equivalent to the [`ModuleRequest`][spec] record, which pairs the specifier of a module
+/// with the import attributes it was requested with.
+///
+/// [spec]: https://tc39.es/ecma262/#modulerequest-record
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ModuleRequest {
+    specifier: Sym,
+    attributes: Box<[ImportAttribute]>,
+}
+
+impl ModuleRequest {
+    /// Creates a new `ModuleRequest` from a specifier and its import attributes.
+    ///
+    /// The attributes are sorted by key, which makes two requests with the same set of
+    /// attributes compare equal.
+    #[must_use]
+    pub fn new(specifier: Sym, attributes: &[ImportAttribute]) -> Self {
+        let mut attributes: Box<[ImportAttribute]> = attributes.into();
+        attributes.sort_unstable_by_key(|attribute| attribute.key());
+        Self {
+            specifier,
+            attributes,
+        }
+    }
+
+    /// Gets the module specifier of the request.
+    #[inline]
+    #[must_use]
+    pub const fn specifier(&self) -> Sym {
+        self.specifier
+    }
+
+    /// Gets the import attributes of the request.
+    #[inline]
+    #[must_use]
+    pub const fn attributes(&self) -> &[ImportAttribute] {
+        &self.attributes
+    }
+}
@@ -5,8 +5,9 @@ use crate::{
     expression::{Expression, Identifier},
     join_nodes,
     pattern::Pattern,
+    position::impl_spanned,
     visitor::{VisitWith, Visitor, VisitorMut},
-    Statement,
+    Span, SpanIgnoreEq, Spanned, Statement,
 };
 use boa_interner::{Interner, ToInternedString};
 use core::{convert::TryFrom, fmt::Write as _, ops::ControlFlow};
@@ -45,6 +46,18 @@ use core::{convert::TryFrom, fmt::Write as _, ops::ControlFlow};
 #[derive(Clone, Debug, PartialEq)]
 pub struct VarDeclaration(pub VariableList);
 
+impl Spanned for VarDeclaration {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.0.span()
+    }
+
+    #[inline]
+    fn set_span(&mut self, span: Span) {
+        self.0.set_span(span);
+    }
+}
+
 impl From<VarDeclaration> for Statement {
     fn from(var: VarDeclaration) -> Self {
         Self::Var(var)
@@ -101,6 +114,21 @@ pub enum LexicalDeclaration {
     ///
     /// [let]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/let
     Let(VariableList),
+
+    /// A <code>[using]</code> declaration creates a constant binding whose value is disposed,
+    /// by calling its `Symbol.dispose` method, when the enclosing scope is exited.
+    ///
+    /// Only binding identifiers can be declared, and an initializer is required outside of
+    /// `for...of` loop heads.
+    ///
+    /// [using]: https://tc39.es/proposal-explicit-resource-management/#prod-UsingDeclaration
+    Using(VariableList),
+
+    /// An <code>[await using]</code> declaration behaves like a `using` declaration, but its
+    /// value is disposed asynchronously, by awaiting its `Symbol.asyncDispose` method.
+    ///
+    /// [await using]: https://tc39.es/proposal-explicit-resource-management/#prod-AwaitUsingDeclaration
+    AwaitUsing(VariableList),
 }
 
 impl LexicalDeclaration {
@@ -108,14 +136,46 @@ impl LexicalDeclaration {
     #[must_use]
     pub const fn variable_list(&self) -> &VariableList {
         match self {
-            Self::Const(list) | Self::Let(list) => list,
+            Self::Const(list) | Self::Let(list) | Self::Using(list) | Self::AwaitUsing(list) => {
+                list
+            }
         }
     }
 
-    /// Returns `true` if the declaration is a `const` declaration.
+    /// Returns `true` if the declaration creates immutable bindings.
+    ///
+    /// This is the case for `const`, `using` and `await using` declarations.
     #[must_use]
     pub const fn is_const(&self) -> bool {
-        matches!(self, Self::Const(_))
+        matches!(self, Self::Const(_) | Self::Using(_) | Self::AwaitUsing(_))
+    }
+
+    /// Returns `true` if the declaration is a `using` or `await using` declaration.
+    #[must_use]
+    pub const fn is_using(&self) -> bool {
+        matches!(self, Self::Using(_) | Self::AwaitUsing(_))
+    }
+
+    /// Returns `true` if the declaration is an `await using` declaration.
+    #[must_use]
+    pub const fn is_await_using(&self) -> bool {
+        matches!(self, Self::AwaitUsing(_))
+    }
+}
+
+impl Spanned for LexicalDeclaration {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.variable_list().span()
+    }
+
+    #[inline]
+    fn set_span(&mut self, span: Span) {
+        match self {
+            Self::Const(list) | Self::Let(list) | Self::Using(list) | Self::AwaitUsing(list) => {
+                list.set_span(span);
+            }
+        }
     }
 }
 
@@ -132,6 +192,8 @@ impl ToInternedString for LexicalDeclaration {
             match &self {
                 Self::Let(_) => "let",
                 Self::Const(_) => "const",
+                Self::Using(_) => "using",
+                Self::AwaitUsing(_) => "await using",
             },
             self.variable_list().to_interned_string(interner)
         )
@@ -144,7 +206,9 @@ impl VisitWith for LexicalDeclaration {
         V: Visitor<'a>,
     {
         match self {
-            Self::Const(vars) | Self::Let(vars) => visitor.visit_variable_list(vars),
+            Self::Const(vars) | Self::Let(vars) | Self::Using(vars) | Self::AwaitUsing(vars) => {
+                visitor.visit_variable_list(vars)
+            }
         }
     }
 
@@ -153,7 +217,9 @@ impl VisitWith for LexicalDeclaration {
         V: VisitorMut<'a>,
     {
         match self {
-            Self::Const(vars) | Self::Let(vars) => visitor.visit_variable_list_mut(vars),
+            Self::Const(vars) | Self::Let(vars) | Self::Using(vars) | Self::AwaitUsing(vars) => {
+                visitor.visit_variable_list_mut(vars)
+            }
         }
     }
 }
@@ -164,6 +230,7 @@ impl VisitWith for LexicalDeclaration {
 #[derive(Clone, Debug, PartialEq)]
 pub struct VariableList {
     list: Box<[Variable]>,
+    span: SpanIgnoreEq,
 }
 
 impl VariableList {
@@ -174,10 +241,15 @@ impl VariableList {
             return None;
         }
 
-        Some(Self { list })
+        Some(Self {
+            list,
+            span: SpanIgnoreEq(None),
+        })
     }
 }
 
+impl_spanned!(VariableList);
+
 impl AsRef<[Variable]> for VariableList {
     fn as_ref(&self) -> &[Variable] {
         &self.list
@@ -0,0 +1,121 @@
+//! Conversion between Boa's AST and the [ESTree][estree] JSON format.
+//!
+//! [ESTree][estree] is the AST format understood by most JavaScript tooling (`ESLint`, `Prettier`,
+//! `acorn`, `babel` with the `estree` plugin, ...). This module can export a [`Script`] or a
+//! [`Module`] as an `ESTree` `Program` and build them back from one, which makes it possible to
+//! feed Boa-parsed code to external tools, and to run code parsed by other tools on Boa.
+//!
+//! Nodes are exported with a `loc` property when their [`Span`] is known. Following `ESTree`,
+//! lines are 1-based and columns are 0-based. Constructs that `ESTree` does not cover are exported
+//! using the representation of the corresponding Stage 3 proposals, as most tools do:
+//!
+//! - decorators are stored in a `decorators` array on classes and class elements;
+//! - `using` declarations are `VariableDeclaration`s of kind `"using"` or `"await using"`;
+//! - `accessor` fields are `AccessorProperty` nodes;
+//! - import attributes are stored in an `attributes` array.
+//!
+//! `ESTree` does not represent parentheses, so [`Parenthesized`] nodes are exported as their inner
+//! expression. Statements that failed to parse in a recovering parse are exported as
+//! `EmptyStatement`s.
+//!
+//! An imported AST is not checked for early errors: the `ESTree` tree is expected to come from a
+//! conforming parser. Nodes built from `ESTree` have no source text, so their [`LinearSpan`]s are
+//! empty.
+//!
+//! [estree]: https://github.com/estree/estree
+//! [`Parenthesized`]: crate::expression::Parenthesized
+//! [`LinearSpan`]: crate::LinearSpan
+
+mod reader;
+mod writer;
+
+#[cfg(test)]
+mod tests;
+
+use std::fmt;
+
+use boa_interner::Interner;
+use serde_json::Value;
+
+use crate::{Module, Position, Script};
+
+use self::{reader::Reader, writer::Writer};
+
+/// Converts a [`Script`] into an `ESTree` `Program` node.
+#[must_use]
+pub fn script_to_estree(script: &Script, interner: &Interner) -> Value {
+    Writer::new(interner).script(script)
+}
+
+/// Converts a [`Module`] into an `ESTree` `Program` node.
+#[must_use]
+pub fn module_to_estree(module: &Module, interner: &Interner) -> Value {
+    Writer::new(interner).module(module)
+}
+
+/// Builds a [`Script`] from an `ESTree` `Program` node.
+///
+/// The returned script still needs its scopes analyzed with [`Script::analyze_scope`] before it
+/// can be compiled.
+///
+/// # Errors
+///
+/// Returns an error if `program` is not a valid `ESTree` `Program`, or if it contains nodes that
+/// cannot appear in a script.
+pub fn script_from_estree(program: &Value, interner: &mut Interner) -> Result<Script, EstreeError> {
+    Reader::new(interner).script(program)
+}
+
+/// Builds a [`Module`] from an `ESTree` `Program` node.
+///
+/// The returned module still needs its scopes analyzed with [`Module::analyze_scope`] before it
+/// can be compiled.
+///
+/// # Errors
+///
+/// Returns an error if `program` is not a valid `ESTree` `Program`.
+pub fn module_from_estree(program: &Value, interner: &mut Interner) -> Result<Module, EstreeError> {
+    Reader::new(interner).module(program)
+}
+
+/// The error returned when an `ESTree` tree cannot be converted to Boa's AST.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EstreeError {
+    message: Box<str>,
+    position: Option<Position>,
+}
+
+impl EstreeError {
+    /// Creates a new error, located at the start of `node` if it has a `loc` property.
+    pub(crate) fn new(message: impl Into<Box<str>>, node: &Value) -> Self {
+        Self {
+            message: message.into(),
+            position: reader::span(node).map(crate::Span::start),
+        }
+    }
+
+    /// Gets the message of the error.
+    #[inline]
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Gets the start position of the node that caused the error, if known.
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        self.position
+    }
+}
+
+impl fmt::Display for EstreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.position {
+            Some(position) => write!(f, "{} at {position}", self.message),
+            None => self.message.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for EstreeError {}
@@ -0,0 +1,1506 @@
+//! Conversion from `ESTree` nodes to Boa's AST.
+
+use boa_interner::{Interner, Sym};
+use num_bigint::BigInt;
+use serde_json::Value;
+
+use super::EstreeError;
+use crate::{
+    declaration::{
+        Binding, ExportDeclaration, ExportSpecifier, ImportAttribute, ImportDeclaration,
+        ImportKind, ImportSpecifier, LexicalDeclaration, ModuleSpecifier, ReExportKind,
+        VarDeclaration, Variable, VariableList,
+    },
+    expression::{
+        access::{
+            PrivatePropertyAccess, PropertyAccess, PropertyAccessField, SimplePropertyAccess,
+            SuperPropertyAccess,
+        },
+        literal::{
+            ArrayLiteral, Literal, LiteralKind, ObjectLiteral, ObjectMethodDefinition,
+            PropertyDefinition, TemplateElement, TemplateLiteral,
+        },
+        operator::{
+            assign::{AssignOp, AssignTarget},
+            binary::{ArithmeticOp, BinaryOp, BitwiseOp, LogicalOp, RelationalOp},
+            unary::UnaryOp,
+            update::{UpdateOp, UpdateTarget},
+            Assign, Binary, BinaryInPrivate, Conditional, Unary, Update,
+        },
+        Await, Call, Expression, Identifier, ImportCall, New, Optional, OptionalOperation,
+        OptionalOperationKind, Parenthesized, RegExpLiteral, Spread, SuperCall, TaggedTemplate,
+        Yield,
+    },
+    function::{
+        ArrowFunction, AsyncArrowFunction, AsyncFunctionDeclaration, AsyncFunctionExpression,
+        AsyncGeneratorDeclaration, AsyncGeneratorExpression, ClassDeclaration, ClassElement,
+        ClassElementName, ClassExpression, ClassFieldDefinition, ClassMethodDefinition, Decorator,
+        FormalParameter, FormalParameterList, FunctionBody, FunctionDeclaration,
+        FunctionExpression, GeneratorDeclaration, GeneratorExpression, PrivateFieldDefinition,
+        PrivateName, StaticBlockBody,
+    },
+    pattern::{ArrayPattern, ArrayPatternElement, ObjectPattern, ObjectPatternElement, Pattern},
+    property::{MethodDefinitionKind, PropertyName},
+    statement::{
+        iteration::{ForLoopInitializer, IterableLoopInitializer},
+        Block, Break, Case, Catch, Continue, DoWhileLoop, ErrorHandler, Finally, ForInLoop,
+        ForLoop, ForOfLoop, If, Labelled, LabelledItem, Return, Switch, Throw, Try, WhileLoop,
+        With,
+    },
+    Declaration, LinearPosition, LinearSpan, Module, ModuleItem, ModuleItemList, Position, Script,
+    Span, SpanIgnoreEq, Spanned, Statement, StatementList, StatementListItem,
+};
+
+type Result<T> = std::result::Result<T, EstreeError>;
+
+/// Nodes built from `ESTree` have no source text to point to.
+const NO_POSITION: LinearPosition = LinearPosition::new(0);
+const NO_SOURCE: LinearSpan = LinearSpan::new(NO_POSITION, NO_POSITION);
+
+/// The parts shared by class declarations and expressions.
+struct ClassParts {
+    super_ref: Option<Expression>,
+    constructor: Option<FunctionExpression>,
+    elements: Box<[ClassElement]>,
+    decorators: Box<[Decorator]>,
+}
+
+/// Converts `ESTree` nodes to Boa's AST nodes.
+pub(super) struct Reader<'i> {
+    interner: &'i mut Interner,
+    strict: bool,
+    tagged_templates: u32,
+}
+
+impl<'i> Reader<'i> {
+    pub(super) fn new(interner: &'i mut Interner) -> Self {
+        Self {
+            interner,
+            strict: false,
+            tagged_templates: 0,
+        }
+    }
+
+    pub(super) fn script(&mut self, program: &Value) -> Result<Script> {
+        let body = program_body(program)?;
+        let strict = has_use_strict(body);
+        self.strict = strict;
+        let items = self.statement_items(body)?;
+        Ok(Script::new(StatementList::new(items, NO_POSITION, strict)))
+    }
+
+    pub(super) fn module(&mut self, program: &Value) -> Result<Module> {
+        let body = program_body(program)?;
+        self.strict = true;
+        let items = body
+            .iter()
+            .map(|item| self.module_item(item))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Module::new(ModuleItemList::from(items)))
+    }
+
+    /// Runs `f` in strict mode if `strict` is true or if the current code is already strict.
+    fn with_strict<T>(&mut self, strict: bool, f: impl FnOnce(&mut Self) -> T) -> T {
+        let outer = self.strict;
+        self.strict = outer || strict;
+        let result = f(self);
+        self.strict = outer;
+        result
+    }
+
+    fn sym(&mut self, string: &str) -> Sym {
+        self.interner.get_or_intern(string)
+    }
+
+    fn name(&mut self, node: &Value) -> Result<Sym> {
+        if kind(node)? != "Identifier" {
+            return Err(unexpected(node, "identifier position"));
+        }
+        Ok(self.sym(string_field(node, "name")?))
+    }
+
+    fn identifier(&mut self, node: &Value) -> Result<Identifier> {
+        Ok(spanned(node, Identifier::new(self.name(node)?)))
+    }
+
+    fn private_name(&mut self, node: &Value) -> Result<PrivateName> {
+        if kind(node)? != "PrivateIdentifier" {
+            return Err(unexpected(node, "private name position"));
+        }
+        Ok(PrivateName::new(self.sym(string_field(node, "name")?)))
+    }
+
+    fn string_literal(&mut self, node: &Value) -> Result<Sym> {
+        match node.get("value") {
+            Some(Value::String(value)) if kind(node)? == "Literal" => Ok(self.sym(value)),
+            _ => Err(EstreeError::new("expected a string literal", node)),
+        }
+    }
+
+    fn name_or_string(&mut self, node: &Value) -> Result<Sym> {
+        if kind(node)? == "Identifier" {
+            self.name(node)
+        } else {
+            self.string_literal(node)
+        }
+    }
+
+    fn optional_expression(&mut self, node: &Value, name: &str) -> Result<Option<Expression>> {
+        optional_field(node, name)
+            .map(|expression| self.expression(expression))
+            .transpose()
+    }
+
+    fn expressions(&mut self, nodes: &[Value]) -> Result<Box<[Expression]>> {
+        nodes
+            .iter()
+            .map(|node| self.expression(node))
+            .collect::<Result<Vec<_>>>()
+            .map(Vec::into_boxed_slice)
+    }
+
+    fn statement_items(&mut self, nodes: &[Value]) -> Result<Vec<StatementListItem>> {
+        nodes
+            .iter()
+            .map(|node| self.statement_list_item(node))
+            .collect()
+    }
+
+    fn statement_list(&mut self, nodes: &[Value]) -> Result<StatementList> {
+        let items = self.statement_items(nodes)?;
+        Ok(StatementList::new(items, NO_POSITION, self.strict))
+    }
+
+    fn statement_list_item(&mut self, node: &Value) -> Result<StatementListItem> {
+        match kind(node)? {
+            "FunctionDeclaration" | "ClassDeclaration" => Ok(self.declaration(node)?.into()),
+            "VariableDeclaration" if string_field(node, "kind")? != "var" => {
+                Ok(self.declaration(node)?.into())
+            }
+            _ => Ok(self.statement(node)?.into()),
+        }
+    }
+
+    fn block(&mut self, node: &Value) -> Result<Block> {
+        if kind(node)? != "BlockStatement" {
+            return Err(unexpected(node, "block position"));
+        }
+        let statements = self.statement_list(array(node, "body")?)?;
+        Ok(spanned(node, Block::from(statements)))
+    }
+
+    fn statement(&mut self, node: &Value) -> Result<Statement> {
+        let mut statement = match kind(node)? {
+            "ExpressionStatement" => {
+                Statement::Expression(self.expression(field(node, "expression")?)?)
+            }
+            "BlockStatement" => Statement::Block(self.block(node)?),
+            "EmptyStatement" => Statement::Empty(SpanIgnoreEq::default()),
+            "DebuggerStatement" => {
+                Statement::Expression(Expression::Debugger(SpanIgnoreEq::default()))
+            }
+            "WithStatement" => {
+                let object = self.expression(field(node, "object")?)?;
+                Statement::With(With::new(object, self.statement(field(node, "body")?)?))
+            }
+            "ReturnStatement" => {
+                Statement::Return(Return::new(self.optional_expression(node, "argument")?))
+            }
+            "LabeledStatement" => {
+                let label = self.name(field(node, "label")?)?;
+                let body = field(node, "body")?;
+                let item = if kind(body)? == "FunctionDeclaration" {
+                    match self.function_declaration(body, false)? {
+                        Declaration::FunctionDeclaration(f) => LabelledItem::FunctionDeclaration(f),
+                        _ => return Err(unexpected(body, "labelled statement")),
+                    }
+                } else {
+                    LabelledItem::Statement(self.statement(body)?)
+                };
+                Statement::Labelled(Labelled::new(item, label))
+            }
+            "BreakStatement" => Statement::Break(Break::new(
+                optional_field(node, "label")
+                    .map(|label| self.name(label))
+                    .transpose()?,
+            )),
+            "ContinueStatement" => Statement::Continue(Continue::new(
+                optional_field(node, "label")
+                    .map(|label| self.name(label))
+                    .transpose()?,
+            )),
+            "IfStatement" => {
+                let test = self.expression(field(node, "test")?)?;
+                let consequent = self.statement(field(node, "consequent")?)?;
+                let alternate = optional_field(node, "alternate")
+                    .map(|alternate| self.statement(alternate))
+                    .transpose()?;
+                Statement::If(If::new(test, consequent, alternate))
+            }
+            "SwitchStatement" => {
+                let discriminant = self.expression(field(node, "discriminant")?)?;
+                let cases = array(node, "cases")?
+                    .iter()
+                    .map(|case| {
+                        let test = self.optional_expression(case, "test")?;
+                        let body = self.statement_list(array(case, "consequent")?)?;
+                        Ok(match test {
+                            Some(test) => Case::new(test, body),
+                            None => Case::default(body),
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Statement::Switch(Switch::new(discriminant, cases.into_boxed_slice()))
+            }
+            "ThrowStatement" => {
+                Statement::Throw(Throw::new(self.expression(field(node, "argument")?)?))
+            }
+            "TryStatement" => {
+                let block = self.block(field(node, "block")?)?;
+                let catch = match optional_field(node, "handler") {
+                    Some(handler) => {
+                        let parameter = optional_field(handler, "param")
+                            .map(|param| self.binding(param))
+                            .transpose()?;
+                        Some(Catch::new(parameter, self.block(field(handler, "body")?)?))
+                    }
+                    None => None,
+                };
+                let finally = match optional_field(node, "finalizer") {
+                    Some(finalizer) => Some(Finally::from(self.block(finalizer)?)),
+                    None => None,
+                };
+                let handler = match (catch, finally) {
+                    (Some(catch), Some(finally)) => ErrorHandler::Full(catch, finally),
+                    (Some(catch), None) => ErrorHandler::Catch(catch),
+                    (None, Some(finally)) => ErrorHandler::Finally(finally),
+                    (None, None) => {
+                        return Err(EstreeError::new(
+                            "try statements must have a handler or a finalizer",
+                            node,
+                        ))
+                    }
+                };
+                Statement::Try(Try::new(block, handler))
+            }
+            "WhileStatement" => {
+                let test = self.expression(field(node, "test")?)?;
+                Statement::WhileLoop(WhileLoop::new(test, self.statement(field(node, "body")?)?))
+            }
+            "DoWhileStatement" => {
+                let body = self.statement(field(node, "body")?)?;
+                let test = self.expression(field(node, "test")?)?;
+                Statement::DoWhileLoop(DoWhileLoop::new(body, test))
+            }
+            "ForStatement" => {
+                let init = match optional_field(node, "init") {
+                    Some(init) if kind(init)? == "VariableDeclaration" => {
+                        Some(if string_field(init, "kind")? == "var" {
+                            ForLoopInitializer::from(spanned(
+                                init,
+                                VarDeclaration(self.variable_list(init)?),
+                            ))
+                        } else {
+                            ForLoopInitializer::from(self.lexical_declaration(init)?)
+                        })
+                    }
+                    Some(init) => Some(ForLoopInitializer::from(self.expression(init)?)),
+                    None => None,
+                };
+                let test = self.optional_expression(node, "test")?;
+                let update = self.optional_expression(node, "update")?;
+                let body = self.statement(field(node, "body")?)?;
+                Statement::ForLoop(ForLoop::new(init, test, update, body))
+            }
+            "ForInStatement" => {
+                let left = self.iterable_loop_initializer(field(node, "left")?)?;
+                let right = self.expression(field(node, "right")?)?;
+                let body = self.statement(field(node, "body")?)?;
+                Statement::ForInLoop(ForInLoop::new(left, right, body))
+            }
+            "ForOfStatement" => {
+                let left = self.iterable_loop_initializer(field(node, "left")?)?;
+                let right = self.expression(field(node, "right")?)?;
+                let body = self.statement(field(node, "body")?)?;
+                Statement::ForOfLoop(ForOfLoop::new(left, right, body, flag(node, "await")))
+            }
+            "VariableDeclaration" if string_field(node, "kind")? == "var" => {
+                Statement::Var(VarDeclaration(self.variable_list(node)?))
+            }
+            _ => return Err(unexpected(node, "statement position")),
+        };
+        // Expression statements keep the span of their expression.
+        if statement.span().is_none() {
+            if let Some(span) = span(node) {
+                statement.set_span(span);
+            }
+        }
+        Ok(statement)
+    }
+
+    fn declaration(&mut self, node: &Value) -> Result<Declaration> {
+        let declaration = match kind(node)? {
+            "FunctionDeclaration" => self.function_declaration(node, false)?,
+            "ClassDeclaration" => {
+                Declaration::ClassDeclaration(self.class_declaration(node, false)?)
+            }
+            "VariableDeclaration" => Declaration::Lexical(self.lexical_declaration(node)?),
+            _ => return Err(unexpected(node, "declaration position")),
+        };
+        Ok(spanned(node, declaration))
+    }
+
+    /// Gets the name of a declaration, which is `default` for anonymous default exports.
+    fn declaration_name(&mut self, node: &Value, default: bool) -> Result<Identifier> {
+        match optional_field(node, "id") {
+            Some(id) => self.identifier(id),
+            None if default => Ok(Sym::DEFAULT.into()),
+            None => Err(EstreeError::new("declarations must have a name", node)),
+        }
+    }
+
+    fn function_declaration(&mut self, node: &Value, default: bool) -> Result<Declaration> {
+        let name = self.declaration_name(node, default)?;
+        let (parameters, body) = self.function_parts(node)?;
+        let declaration = match (flag(node, "generator"), flag(node, "async")) {
+            (false, false) => Declaration::FunctionDeclaration(FunctionDeclaration::new(
+                name, parameters, body, NO_SOURCE,
+            )),
+            (true, false) => Declaration::GeneratorDeclaration(GeneratorDeclaration::new(
+                name, parameters, body, NO_SOURCE,
+            )),
+            (false, true) => Declaration::AsyncFunctionDeclaration(AsyncFunctionDeclaration::new(
+                name, parameters, body, NO_SOURCE,
+            )),
+            (true, true) => Declaration::AsyncGeneratorDeclaration(AsyncGeneratorDeclaration::new(
+                name, parameters, body, NO_SOURCE,
+            )),
+        };
+        Ok(spanned(node, declaration))
+    }
+
+    fn function_expression(&mut self, node: &Value) -> Result<Expression> {
+        let name = optional_field(node, "id")
+            .map(|id| self.identifier(id))
+            .transpose()?;
+        let (parameters, body) = self.function_parts(node)?;
+        let has_binding_identifier = name.is_some();
+        Ok(match (flag(node, "generator"), flag(node, "async")) {
+            (false, false) => Expression::FunctionExpression(FunctionExpression::new(
+                name,
+                parameters,
+                body,
+                Some(NO_SOURCE),
+                has_binding_identifier,
+            )),
+            (true, false) => Expression::GeneratorExpression(GeneratorExpression::new(
+                name,
+                parameters,
+                body,
+                NO_SOURCE,
+                has_binding_identifier,
+            )),
+            (false, true) => Expression::AsyncFunctionExpression(AsyncFunctionExpression::new(
+                name,
+                parameters,
+                body,
+                NO_SOURCE,
+                has_binding_identifier,
+            )),
+            (true, true) => Expression::AsyncGeneratorExpression(AsyncGeneratorExpression::new(
+                name,
+                parameters,
+                body,
+                NO_SOURCE,
+                has_binding_identifier,
+            )),
+        })
+    }
+
+    fn arrow_function(&mut self, node: &Value) -> Result<Expression> {
+        let body = field(node, "body")?;
+        let (parameters, body) = if kind(body)? == "BlockStatement" {
+            self.function_parts(node)?
+        } else {
+            let parameters = self.parameters(array(node, "params")?)?;
+            let expression = self.expression(body)?;
+            let items = vec![StatementListItem::from(Statement::Return(Return::new(
+                Some(expression),
+            )))];
+            (
+                parameters,
+                FunctionBody::new(items, NO_POSITION, self.strict),
+            )
+        };
+        Ok(if flag(node, "async") {
+            Expression::AsyncArrowFunction(AsyncArrowFunction::new(
+                None, parameters, body, NO_SOURCE,
+            ))
+        } else {
+            Expression::ArrowFunction(ArrowFunction::new(None, parameters, body, NO_SOURCE))
+        })
+    }
+
+    /// Converts the parameters and the block body of a function node.
+    fn function_parts(&mut self, node: &Value) -> Result<(FormalParameterList, FunctionBody)> {
+        let statements = array(field(node, "body")?, "body")?;
+        self.with_strict(has_use_strict(statements), |this| {
+            let parameters = this.parameters(array(node, "params")?)?;
+            let items = this.statement_items(statements)?;
+            Ok((
+                parameters,
+                FunctionBody::new(items, NO_POSITION, this.strict),
+            ))
+        })
+    }
+
+    fn parameters(&mut self, nodes: &[Value]) -> Result<FormalParameterList> {
+        let parameters = nodes
+            .iter()
+            .map(|node| {
+                let (target, is_rest) = if kind(node)? == "RestElement" {
+                    (field(node, "argument")?, true)
+                } else {
+                    (node, false)
+                };
+                let (target, init) = split_default(target)?;
+                let binding = self.binding(target)?;
+                let init = init.map(|init| self.expression(init)).transpose()?;
+                Ok(FormalParameter::new(variable(binding, init), is_rest))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(FormalParameterList::from_parameters(parameters))
+    }
+
+    fn variable_list(&mut self, node: &Value) -> Result<VariableList> {
+        let variables = array(node, "declarations")?
+            .iter()
+            .map(|declarator| {
+                let binding = self.binding(field(declarator, "id")?)?;
+                let init = self.optional_expression(declarator, "init")?;
+                Ok(variable(binding, init))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        VariableList::try_from(variables).map_err(|err| EstreeError::new(err.to_string(), node))
+    }
+
+    fn lexical_declaration(&mut self, node: &Value) -> Result<LexicalDeclaration> {
+        let list = self.variable_list(node)?;
+        let declaration = match string_field(node, "kind")? {
+            "let" => LexicalDeclaration::Let(list),
+            "const" => LexicalDeclaration::Const(list),
+            "using" => LexicalDeclaration::Using(list),
+            "await using" => LexicalDeclaration::AwaitUsing(list),
+            _ => return Err(EstreeError::new("invalid lexical declaration kind", node)),
+        };
+        Ok(spanned(node, declaration))
+    }
+
+    fn iterable_loop_initializer(&mut self, node: &Value) -> Result<IterableLoopInitializer> {
+        match kind(node)? {
+            "VariableDeclaration" => {
+                let [declarator] = array(node, "declarations")? else {
+                    return Err(EstreeError::new(
+                        "for-in and for-of declarations must have a single binding",
+                        node,
+                    ));
+                };
+                let binding = self.binding(field(declarator, "id")?)?;
+                Ok(match string_field(node, "kind")? {
+                    "var" => {
+                        let init = self.optional_expression(declarator, "init")?;
+                        IterableLoopInitializer::Var(variable(binding, init))
+                    }
+                    "let" => IterableLoopInitializer::Let(binding),
+                    "const" => IterableLoopInitializer::Const(binding),
+                    "using" => IterableLoopInitializer::Using(binding),
+                    "await using" => IterableLoopInitializer::AwaitUsing(binding),
+                    _ => return Err(EstreeError::new("invalid declaration kind", node)),
+                })
+            }
+            "Identifier" => Ok(IterableLoopInitializer::Identifier(self.identifier(node)?)),
+            "ObjectPattern" | "ArrayPattern" => {
+                Ok(IterableLoopInitializer::Pattern(self.pattern(node)?))
+            }
+            _ => Ok(IterableLoopInitializer::Access(self.property_access(node)?)),
+        }
+    }
+
+    fn binding(&mut self, node: &Value) -> Result<Binding> {
+        match kind(node)? {
+            "Identifier" => Ok(Binding::Identifier(self.identifier(node)?)),
+            _ => Ok(Binding::Pattern(self.pattern(node)?)),
+        }
+    }
+
+    fn pattern(&mut self, node: &Value) -> Result<Pattern> {
+        match kind(node)? {
+            "ObjectPattern" => {
+                let elements = array(node, "properties")?
+                    .iter()
+                    .map(|element| self.object_pattern_element(element))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Pattern::Object(ObjectPattern::new(
+                    elements.into_boxed_slice(),
+                )))
+            }
+            "ArrayPattern" => {
+                let elements = array(node, "elements")?
+                    .iter()
+                    .map(|element| self.array_pattern_element(element))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Pattern::Array(ArrayPattern::new(
+                    elements.into_boxed_slice(),
+                )))
+            }
+            _ => Err(unexpected(node, "pattern position")),
+        }
+    }
+
+    fn object_pattern_element(&mut self, node: &Value) -> Result<ObjectPatternElement> {
+        if kind(node)? == "RestElement" {
+            let argument = field(node, "argument")?;
+            return Ok(if kind(argument)? == "Identifier" {
+                ObjectPatternElement::RestProperty {
+                    ident: self.identifier(argument)?,
+                }
+            } else {
+                ObjectPatternElement::AssignmentRestPropertyAccess {
+                    access: self.property_access(argument)?,
+                }
+            });
+        }
+        if kind(node)? != "Property" {
+            return Err(unexpected(node, "object pattern"));
+        }
+        let name = self.property_key(node)?;
+        let (target, default_init) = split_default(field(node, "value")?)?;
+        let default_init = default_init.map(|init| self.expression(init)).transpose()?;
+        Ok(match kind(target)? {
+            "Identifier" => ObjectPatternElement::SingleName {
+                name,
+                ident: self.identifier(target)?,
+                default_init,
+            },
+            "ObjectPattern" | "ArrayPattern" => ObjectPatternElement::Pattern {
+                name,
+                pattern: self.pattern(target)?,
+                default_init,
+            },
+            _ => ObjectPatternElement::AssignmentPropertyAccess {
+                name,
+                access: self.property_access(target)?,
+                default_init,
+            },
+        })
+    }
+
+    fn array_pattern_element(&mut self, node: &Value) -> Result<ArrayPatternElement> {
+        if node.is_null() {
+            return Ok(ArrayPatternElement::Elision);
+        }
+        if kind(node)? == "RestElement" {
+            let argument = field(node, "argument")?;
+            return Ok(match kind(argument)? {
+                "Identifier" => ArrayPatternElement::SingleNameRest {
+                    ident: self.identifier(argument)?,
+                },
+                "ObjectPattern" | "ArrayPattern" => ArrayPatternElement::PatternRest {
+                    pattern: self.pattern(argument)?,
+                },
+                _ => ArrayPatternElement::PropertyAccessRest {
+                    access: self.property_access(argument)?,
+                },
+            });
+        }
+        let (target, default_init) = split_default(node)?;
+        let default_init = default_init.map(|init| self.expression(init)).transpose()?;
+        Ok(match kind(target)? {
+            "Identifier" => ArrayPatternElement::SingleName {
+                ident: self.identifier(target)?,
+                default_init,
+            },
+            "ObjectPattern" | "ArrayPattern" => ArrayPatternElement::Pattern {
+                pattern: self.pattern(target)?,
+                default_init,
+            },
+            _ => ArrayPatternElement::PropertyAccess {
+                access: self.property_access(target)?,
+                default_init,
+            },
+        })
+    }
+
+    /// Converts the `key` of a property, method or field node.
+    fn property_key(&mut self, node: &Value) -> Result<PropertyName> {
+        let key = field(node, "key")?;
+        if flag(node, "computed") {
+            return Ok(PropertyName::Computed(self.expression(key)?));
+        }
+        match kind(key)? {
+            "Identifier" => Ok(PropertyName::Literal(self.name(key)?)),
+            "Literal" => match key.get("value") {
+                Some(Value::String(value)) => Ok(PropertyName::Literal(self.sym(value))),
+                // Numeric keys are represented as computed literals.
+                _ => Ok(PropertyName::Computed(self.expression(key)?)),
+            },
+            _ => Err(unexpected(key, "property key position")),
+        }
+    }
+
+    fn property_definition(&mut self, node: &Value) -> Result<PropertyDefinition> {
+        match kind(node)? {
+            "SpreadElement" => Ok(PropertyDefinition::SpreadObject(
+                self.expression(field(node, "argument")?)?,
+            )),
+            "Property" => {
+                let value = field(node, "value")?;
+                let method_kind = match string_field(node, "kind")? {
+                    "get" => Some(MethodDefinitionKind::Get),
+                    "set" => Some(MethodDefinitionKind::Set),
+                    _ if flag(node, "method") => Some(method_kind(value)),
+                    _ => None,
+                };
+                if let Some(method_kind) = method_kind {
+                    let name = self.property_key(node)?;
+                    let (parameters, body) = self.function_parts(value)?;
+                    return Ok(PropertyDefinition::MethodDefinition(
+                        ObjectMethodDefinition::new(
+                            name,
+                            parameters,
+                            body,
+                            method_kind,
+                            NO_POSITION,
+                        ),
+                    ));
+                }
+                if flag(node, "shorthand") {
+                    let (target, init) = split_default(value)?;
+                    let ident = self.identifier(target)?;
+                    return Ok(match init {
+                        Some(init) => {
+                            PropertyDefinition::CoverInitializedName(ident, self.expression(init)?)
+                        }
+                        None => PropertyDefinition::IdentifierReference(ident),
+                    });
+                }
+                let name = self.property_key(node)?;
+                Ok(PropertyDefinition::Property(name, self.expression(value)?))
+            }
+            _ => Err(unexpected(node, "object literal")),
+        }
+    }
+
+    fn decorators(&mut self, node: &Value) -> Result<Box<[Decorator]>> {
+        optional_array(node, "decorators")
+            .iter()
+            .map(|decorator| {
+                Ok(Decorator::new(
+                    self.expression(field(decorator, "expression")?)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(Vec::into_boxed_slice)
+    }
+
+    fn class_parts(&mut self, node: &Value, name: Option<Identifier>) -> Result<ClassParts> {
+        let decorators = self.decorators(node)?;
+        // All parts of a class are strict mode code.
+        self.with_strict(true, |this| {
+            let super_ref = this.optional_expression(node, "superClass")?;
+            let mut constructor = None;
+            let mut elements = Vec::new();
+            for element in array(field(node, "body")?, "body")? {
+                if kind(element)? == "MethodDefinition"
+                    && string_field(element, "kind")? == "constructor"
+                {
+                    let (parameters, body) = this.function_parts(field(element, "value")?)?;
+                    constructor = Some(FunctionExpression::new(
+                        name,
+                        parameters,
+                        body,
+                        Some(NO_SOURCE),
+                        false,
+                    ));
+                } else {
+                    elements.push(this.class_element(element)?);
+                }
+            }
+            Ok(ClassParts {
+                super_ref,
+                constructor,
+                elements: elements.into_boxed_slice(),
+                decorators,
+            })
+        })
+    }
+
+    fn class_declaration(&mut self, node: &Value, default: bool) -> Result<ClassDeclaration> {
+        let name = self.declaration_name(node, default)?;
+        let parts = self.class_parts(node, Some(name))?;
+        let class = ClassDeclaration::new(name, parts.super_ref, parts.constructor, parts.elements)
+            .with_decorators(parts.decorators);
+        Ok(spanned(node, class))
+    }
+
+    fn class_expression(&mut self, node: &Value) -> Result<ClassExpression> {
+        let name = optional_field(node, "id")
+            .map(|id| self.identifier(id))
+            .transpose()?;
+        let parts = self.class_parts(node, name)?;
+        Ok(ClassExpression::new(
+            name,
+            parts.super_ref,
+            parts.constructor,
+            parts.elements,
+            name.is_some(),
+        )
+        .with_decorators(parts.decorators))
+    }
+
+    fn class_element_name(&mut self, node: &Value) -> Result<ClassElementName> {
+        let key = field(node, "key")?;
+        if kind(key)? == "PrivateIdentifier" {
+            Ok(ClassElementName::PrivateName(self.private_name(key)?))
+        } else {
+            Ok(ClassElementName::PropertyName(self.property_key(node)?))
+        }
+    }
+
+    fn class_element(&mut self, node: &Value) -> Result<ClassElement> {
+        let decorators = self.decorators(node)?;
+        let is_static = flag(node, "static");
+        let element = match kind(node)? {
+            "MethodDefinition" => {
+                let value = field(node, "value")?;
+                let name = self.class_element_name(node)?;
+                let kind = match string_field(node, "kind")? {
+                    "get" => MethodDefinitionKind::Get,
+                    "set" => MethodDefinitionKind::Set,
+                    _ => method_kind(value),
+                };
+                let (parameters, body) = self.function_parts(value)?;
+                ClassElement::MethodDefinition(
+                    ClassMethodDefinition::new(
+                        name,
+                        parameters,
+                        body,
+                        kind,
+                        is_static,
+                        NO_POSITION,
+                    )
+                    .with_decorators(decorators),
+                )
+            }
+            element_kind @ ("PropertyDefinition" | "AccessorProperty") => {
+                let accessor = element_kind == "AccessorProperty";
+                let name = self.class_element_name(node)?;
+                let initializer = self.optional_expression(node, "value")?;
+                match name {
+                    ClassElementName::PropertyName(name) => {
+                        let field = if accessor {
+                            ClassFieldDefinition::new_accessor(name, initializer)
+                        } else {
+                            ClassFieldDefinition::new(name, initializer)
+                        }
+                        .with_decorators(decorators);
+                        if is_static {
+                            ClassElement::StaticFieldDefinition(field)
+                        } else {
+                            ClassElement::FieldDefinition(field)
+                        }
+                    }
+                    ClassElementName::PrivateName(name) => {
+                        let field = if accessor {
+                            PrivateFieldDefinition::new_accessor(name, initializer)
+                        } else {
+                            PrivateFieldDefinition::new(name, initializer)
+                        }
+                        .with_decorators(decorators);
+                        if is_static {
+                            ClassElement::PrivateStaticFieldDefinition(field)
+                        } else {
+                            ClassElement::PrivateFieldDefinition(field)
+                        }
+                    }
+                }
+            }
+            "StaticBlock" => {
+                let items = self.statement_items(array(node, "body")?)?;
+                ClassElement::StaticBlock(StaticBlockBody::new(FunctionBody::new(
+                    items,
+                    NO_POSITION,
+                    true,
+                )))
+            }
+            _ => return Err(unexpected(node, "class body")),
+        };
+        Ok(element)
+    }
+
+    fn member_field(&mut self, node: &Value, property: &Value) -> Result<PropertyAccessField> {
+        if flag(node, "computed") {
+            Ok(self.expression(property)?.into())
+        } else {
+            Ok(self.name(property)?.into())
+        }
+    }
+
+    fn property_access(&mut self, node: &Value) -> Result<PropertyAccess> {
+        if kind(node)? != "MemberExpression" {
+            return Err(unexpected(node, "member expression position"));
+        }
+        if flag(node, "optional") {
+            return Err(EstreeError::new(
+                "optional member expressions must be inside a chain expression",
+                node,
+            ));
+        }
+        let object = field(node, "object")?;
+        let property = field(node, "property")?;
+        let access = if kind(property)? == "PrivateIdentifier" {
+            let target = self.expression(object)?;
+            PropertyAccess::Private(PrivatePropertyAccess::new(
+                target,
+                self.private_name(property)?,
+            ))
+        } else if kind(object)? == "Super" {
+            PropertyAccess::Super(SuperPropertyAccess::new(self.member_field(node, property)?))
+        } else {
+            let target = self.expression(object)?;
+            PropertyAccess::Simple(SimplePropertyAccess::new(
+                target,
+                self.member_field(node, property)?,
+            ))
+        };
+        Ok(spanned(node, access))
+    }
+
+    fn arguments(&mut self, node: &Value) -> Result<Box<[Expression]>> {
+        self.expressions(array(node, "arguments")?)
+    }
+
+    fn chain(&mut self, node: &Value) -> Result<Expression> {
+        // Collect the member and call expressions of the chain, from the outermost one.
+        let mut links = Vec::new();
+        let mut current = field(node, "expression")?;
+        loop {
+            match kind(current)? {
+                "MemberExpression" => {
+                    links.push(current);
+                    current = field(current, "object")?;
+                }
+                "CallExpression" => {
+                    links.push(current);
+                    current = field(current, "callee")?;
+                }
+                _ => break,
+            }
+        }
+
+        // Everything before the first optional operation is the target of the chain.
+        let Some(first) = links.iter().rposition(|link| flag(link, "optional")) else {
+            return Err(EstreeError::new(
+                "chain expressions must contain an optional operation",
+                node,
+            ));
+        };
+        let target = if kind(links[first])? == "MemberExpression" {
+            field(links[first], "object")?
+        } else {
+            field(links[first], "callee")?
+        };
+        let target = self.expression(target)?;
+
+        let chain = links[..=first]
+            .iter()
+            .rev()
+            .map(|link| {
+                let operation = if kind(link)? == "MemberExpression" {
+                    let property = field(link, "property")?;
+                    if kind(property)? == "PrivateIdentifier" {
+                        OptionalOperationKind::PrivatePropertyAccess {
+                            field: self.private_name(property)?,
+                        }
+                    } else {
+                        OptionalOperationKind::SimplePropertyAccess {
+                            field: self.member_field(link, property)?,
+                        }
+                    }
+                } else {
+                    OptionalOperationKind::Call {
+                        args: self.arguments(link)?,
+                    }
+                };
+                Ok(OptionalOperation::new(operation, flag(link, "optional")))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Expression::Optional(Optional::new(
+            target,
+            chain.into_boxed_slice(),
+        )))
+    }
+
+    fn literal(&mut self, node: &Value) -> Result<Expression> {
+        if let Some(regex) = optional_field(node, "regex") {
+            let pattern = self.sym(string_field(regex, "pattern")?);
+            let flags = self.sym(string_field(regex, "flags")?);
+            return Ok(Expression::RegExpLiteral(RegExpLiteral::new(
+                pattern, flags,
+            )));
+        }
+        if let Some(bigint) = optional_field(node, "bigint") {
+            let value = bigint
+                .as_str()
+                .and_then(|digits| digits.parse::<BigInt>().ok())
+                .ok_or_else(|| EstreeError::new("invalid BigInt literal", node))?;
+            return Ok(Literal::new(LiteralKind::BigInt(Box::new(value))).into());
+        }
+        let kind = match node.get("value") {
+            Some(Value::String(value)) => LiteralKind::String(self.sym(value)),
+            Some(Value::Bool(value)) => LiteralKind::Bool(*value),
+            Some(Value::Number(value)) => match value.as_i64().map(i32::try_from) {
+                Some(Ok(value)) => LiteralKind::Int(value),
+                _ => LiteralKind::Num(value.as_f64().unwrap_or(f64::NAN)),
+            },
+            // Numbers that JSON cannot represent have a `null` value and keep their `raw` text.
+            Some(Value::Null) | None => match node.get("raw").and_then(Value::as_str) {
+                Some(raw) if raw != "null" => LiteralKind::Num(
+                    raw.parse()
+                        .map_err(|_| EstreeError::new("invalid numeric literal", node))?,
+                ),
+                _ => LiteralKind::Null,
+            },
+            Some(_) => return Err(EstreeError::new("invalid literal value", node)),
+        };
+        Ok(Literal::new(kind).into())
+    }
+
+    fn template_literal(&mut self, node: &Value) -> Result<Expression> {
+        let quasis = array(node, "quasis")?;
+        let expressions = array(node, "expressions")?;
+        let mut elements = Vec::with_capacity(quasis.len() + expressions.len());
+        for (i, quasi) in quasis.iter().enumerate() {
+            let cooked = optional_field(field(quasi, "value")?, "cooked")
+                .and_then(Value::as_str)
+                .ok_or_else(|| {
+                    EstreeError::new("untagged templates cannot have invalid escapes", quasi)
+                })?;
+            elements.push(TemplateElement::String(self.sym(cooked)));
+            if let Some(expression) = expressions.get(i) {
+                elements.push(TemplateElement::Expr(self.expression(expression)?));
+            }
+        }
+        Ok(Expression::TemplateLiteral(TemplateLiteral::new(
+            elements.into_boxed_slice(),
+        )))
+    }
+
+    fn tagged_template(&mut self, node: &Value) -> Result<Expression> {
+        let tag = self.expression(field(node, "tag")?)?;
+        let quasi = field(node, "quasi")?;
+        let mut raws = Vec::new();
+        let mut cookeds = Vec::new();
+        for element in array(quasi, "quasis")? {
+            let value = field(element, "value")?;
+            raws.push(self.sym(string_field(value, "raw")?));
+            cookeds.push(
+                optional_field(value, "cooked")
+                    .and_then(Value::as_str)
+                    .map(|cooked| self.sym(cooked)),
+            );
+        }
+        let exprs = self.expressions(array(quasi, "expressions")?)?;
+
+        // Mirrors the site identifiers given by the parser to scripts without an identifier.
+        self.tagged_templates += 1;
+        let identifier = u64::from(self.tagged_templates) << 32;
+
+        Ok(Expression::TaggedTemplate(TaggedTemplate::new(
+            tag,
+            raws.into_boxed_slice(),
+            cookeds.into_boxed_slice(),
+            exprs,
+            identifier,
+        )))
+    }
+
+    fn assignment(&mut self, node: &Value) -> Result<Expression> {
+        let op = assign_op(string_field(node, "operator")?)
+            .ok_or_else(|| EstreeError::new("invalid assignment operator", node))?;
+        let left = field(node, "left")?;
+        let target = match kind(left)? {
+            "Identifier" => AssignTarget::Identifier(self.identifier(left)?),
+            "ObjectPattern" | "ArrayPattern" => AssignTarget::Pattern(self.pattern(left)?),
+            _ => AssignTarget::Access(self.property_access(left)?),
+        };
+        let value = self.expression(field(node, "right")?)?;
+        Ok(Expression::Assign(Assign::new(op, target, value)))
+    }
+
+    fn binary(&mut self, node: &Value) -> Result<Expression> {
+        let operator = string_field(node, "operator")?;
+        let left = field(node, "left")?;
+        if kind(left)? == "PrivateIdentifier" && operator == "in" {
+            let name = self.private_name(left)?;
+            let right = self.expression(field(node, "right")?)?;
+            return Ok(Expression::BinaryInPrivate(BinaryInPrivate::new(
+                name, right,
+            )));
+        }
+        let op =
+            binary_op(operator).ok_or_else(|| EstreeError::new("invalid binary operator", node))?;
+        let left = self.expression(left)?;
+        let right = self.expression(field(node, "right")?)?;
+        Ok(Expression::Binary(Binary::new(op, left, right)))
+    }
+
+    fn expression(&mut self, node: &Value) -> Result<Expression> {
+        let expression = match kind(node)? {
+            "Identifier" => Expression::Identifier(self.identifier(node)?),
+            "Literal" => self.literal(node)?,
+            "ThisExpression" => Expression::This(SpanIgnoreEq::default()),
+            "ArrayExpression" => {
+                let elements = array(node, "elements")?
+                    .iter()
+                    .map(|element| {
+                        if element.is_null() {
+                            Ok(None)
+                        } else {
+                            self.expression(element).map(Some)
+                        }
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Expression::ArrayLiteral(ArrayLiteral::new(elements, false))
+            }
+            "ObjectExpression" => {
+                let properties = array(node, "properties")?
+                    .iter()
+                    .map(|property| self.property_definition(property))
+                    .collect::<Result<Vec<_>>>()?;
+                Expression::ObjectLiteral(ObjectLiteral::from(properties))
+            }
+            "FunctionExpression" => self.function_expression(node)?,
+            "ArrowFunctionExpression" => self.arrow_function(node)?,
+            "ClassExpression" => {
+                Expression::ClassExpression(Box::new(self.class_expression(node)?))
+            }
+            "TemplateLiteral" => self.template_literal(node)?,
+            "TaggedTemplateExpression" => self.tagged_template(node)?,
+            "MemberExpression" => Expression::PropertyAccess(self.property_access(node)?),
+            "CallExpression" => {
+                if flag(node, "optional") {
+                    return Err(EstreeError::new(
+                        "optional calls must be inside a chain expression",
+                        node,
+                    ));
+                }
+                let callee = field(node, "callee")?;
+                if kind(callee)? == "Super" {
+                    Expression::SuperCall(SuperCall::new(self.arguments(node)?))
+                } else {
+                    let function = self.expression(callee)?;
+                    Expression::Call(Call::new(function, self.arguments(node)?))
+                }
+            }
+            "ChainExpression" => self.chain(node)?,
+            "NewExpression" => {
+                let callee = self.expression(field(node, "callee")?)?;
+                Expression::New(New::from(Call::new(callee, self.arguments(node)?)))
+            }
+            "ImportExpression" => {
+                let source = self.expression(field(node, "source")?)?;
+                let options = self.optional_expression(node, "options")?;
+                Expression::ImportCall(ImportCall::new(source, options))
+            }
+            "MetaProperty" => {
+                let meta = string_field(field(node, "meta")?, "name")?;
+                let property = string_field(field(node, "property")?, "name")?;
+                match (meta, property) {
+                    ("new", "target") => Expression::NewTarget(SpanIgnoreEq::default()),
+                    ("import", "meta") => Expression::ImportMeta(SpanIgnoreEq::default()),
+                    _ => return Err(EstreeError::new("unknown meta property", node)),
+                }
+            }
+            "SpreadElement" => {
+                Expression::Spread(Spread::new(self.expression(field(node, "argument")?)?))
+            }
+            "AssignmentExpression" => self.assignment(node)?,
+            "UnaryExpression" => {
+                let op = unary_op(string_field(node, "operator")?)
+                    .ok_or_else(|| EstreeError::new("invalid unary operator", node))?;
+                Expression::Unary(Unary::new(op, self.expression(field(node, "argument")?)?))
+            }
+            "UpdateExpression" => {
+                let op = match (string_field(node, "operator")?, flag(node, "prefix")) {
+                    ("++", true) => UpdateOp::IncrementPre,
+                    ("++", false) => UpdateOp::IncrementPost,
+                    ("--", true) => UpdateOp::DecrementPre,
+                    ("--", false) => UpdateOp::DecrementPost,
+                    _ => return Err(EstreeError::new("invalid update operator", node)),
+                };
+                let argument = field(node, "argument")?;
+                let target = if kind(argument)? == "Identifier" {
+                    UpdateTarget::Identifier(self.identifier(argument)?)
+                } else {
+                    UpdateTarget::PropertyAccess(self.property_access(argument)?)
+                };
+                Expression::Update(Update::new(op, target))
+            }
+            "BinaryExpression" | "LogicalExpression" => self.binary(node)?,
+            "SequenceExpression" => {
+                let mut expressions = array(node, "expressions")?.iter();
+                let first = expressions.next().ok_or_else(|| {
+                    EstreeError::new("sequence expressions cannot be empty", node)
+                })?;
+                let mut sequence = self.expression(first)?;
+                for expression in expressions {
+                    let next = self.expression(expression)?;
+                    sequence = Expression::Binary(Binary::new(BinaryOp::Comma, sequence, next));
+                }
+                sequence
+            }
+            "ConditionalExpression" => {
+                let test = self.expression(field(node, "test")?)?;
+                let consequent = self.expression(field(node, "consequent")?)?;
+                let alternate = self.expression(field(node, "alternate")?)?;
+                Expression::Conditional(Conditional::new(test, consequent, alternate))
+            }
+            "AwaitExpression" => {
+                Expression::Await(Await::from(self.expression(field(node, "argument")?)?))
+            }
+            "YieldExpression" => {
+                let argument = self.optional_expression(node, "argument")?;
+                Expression::Yield(Yield::new(argument, flag(node, "delegate")))
+            }
+            "ParenthesizedExpression" => Expression::Parenthesized(Parenthesized::new(
+                self.expression(field(node, "expression")?)?,
+            )),
+            _ => return Err(unexpected(node, "expression position")),
+        };
+        Ok(spanned(node, expression))
+    }
+
+    fn module_item(&mut self, node: &Value) -> Result<ModuleItem> {
+        match kind(node)? {
+            "ImportDeclaration" => Ok(ModuleItem::ImportDeclaration(
+                self.import_declaration(node)?,
+            )),
+            "ExportNamedDeclaration" | "ExportDefaultDeclaration" | "ExportAllDeclaration" => Ok(
+                ModuleItem::ExportDeclaration(self.export_declaration(node)?),
+            ),
+            _ => Ok(ModuleItem::StatementListItem(
+                self.statement_list_item(node)?,
+            )),
+        }
+    }
+
+    fn module_specifier(&mut self, node: &Value) -> Result<ModuleSpecifier> {
+        Ok(ModuleSpecifier::new(
+            self.string_literal(field(node, "source")?)?,
+        ))
+    }
+
+    fn import_attributes(&mut self, node: &Value) -> Result<Box<[ImportAttribute]>> {
+        // Older versions of the proposal called them import assertions.
+        let attributes = match optional_field(node, "attributes") {
+            Some(_) => optional_array(node, "attributes"),
+            None => optional_array(node, "assertions"),
+        };
+        attributes
+            .iter()
+            .map(|attribute| {
+                let key = self.name_or_string(field(attribute, "key")?)?;
+                let value = self.string_literal(field(attribute, "value")?)?;
+                Ok(ImportAttribute::new(key, value))
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(Vec::into_boxed_slice)
+    }
+
+    fn import_declaration(&mut self, node: &Value) -> Result<ImportDeclaration> {
+        let mut default = None;
+        let mut namespace = None;
+        let mut names = Vec::new();
+        for specifier in array(node, "specifiers")? {
+            let local = self.identifier(field(specifier, "local")?)?;
+            match kind(specifier)? {
+                "ImportDefaultSpecifier" => default = Some(local),
+                "ImportNamespaceSpecifier" => namespace = Some(local),
+                "ImportSpecifier" => {
+                    let imported = self.name_or_string(field(specifier, "imported")?)?;
+                    names.push(ImportSpecifier::new(local, imported));
+                }
+                _ => return Err(unexpected(specifier, "import declaration")),
+            }
+        }
+        let kind = match namespace {
+            Some(binding) => ImportKind::Namespaced { binding },
+            None if names.is_empty() => ImportKind::DefaultOrUnnamed,
+            None => ImportKind::Named {
+                names: names.into_boxed_slice(),
+            },
+        };
+        let specifier = self.module_specifier(node)?;
+        let attributes = self.import_attributes(node)?;
+        Ok(spanned(
+            node,
+            ImportDeclaration::new(default, kind, specifier, attributes),
+        ))
+    }
+
+    fn export_specifier(&mut self, node: &Value) -> Result<ExportSpecifier> {
+        let local = field(node, "local")?;
+        let string_literal = kind(local)? == "Literal";
+        let private_name = self.name_or_string(local)?;
+        let alias = self.name_or_string(field(node, "exported")?)?;
+        Ok(ExportSpecifier::new(alias, private_name, string_literal))
+    }
+
+    fn export_declaration(&mut self, node: &Value) -> Result<ExportDeclaration> {
+        match kind(node)? {
+            "ExportAllDeclaration" => {
+                let name = optional_field(node, "exported")
+                    .map(|exported| self.name_or_string(exported))
+                    .transpose()?;
+                Ok(ExportDeclaration::ReExport {
+                    kind: ReExportKind::Namespaced { name },
+                    specifier: self.module_specifier(node)?,
+                    attributes: self.import_attributes(node)?,
+                })
+            }
+            "ExportDefaultDeclaration" => {
+                let declaration = field(node, "declaration")?;
+                Ok(match kind(declaration)? {
+                    "FunctionDeclaration" => match self.function_declaration(declaration, true)? {
+                        Declaration::FunctionDeclaration(f) => {
+                            ExportDeclaration::DefaultFunctionDeclaration(f)
+                        }
+                        Declaration::GeneratorDeclaration(f) => {
+                            ExportDeclaration::DefaultGeneratorDeclaration(f)
+                        }
+                        Declaration::AsyncFunctionDeclaration(f) => {
+                            ExportDeclaration::DefaultAsyncFunctionDeclaration(f)
+                        }
+                        Declaration::AsyncGeneratorDeclaration(f) => {
+                            ExportDeclaration::DefaultAsyncGeneratorDeclaration(f)
+                        }
+                        Declaration::ClassDeclaration(_) | Declaration::Lexical(_) => {
+                            unreachable!("function declarations are converted to functions")
+                        }
+                    },
+                    "ClassDeclaration" => ExportDeclaration::DefaultClassDeclaration(
+                        self.class_declaration(declaration, true)?,
+                    ),
+                    _ => ExportDeclaration::DefaultAssignmentExpression(
+                        self.expression(declaration)?,
+                    ),
+                })
+            }
+            "ExportNamedDeclaration" => {
+                if let Some(declaration) = optional_field(node, "declaration") {
+                    return match self.statement_list_item(declaration)? {
+                        StatementListItem::Declaration(declaration) => {
+                            Ok(ExportDeclaration::Declaration(declaration))
+                        }
+                        StatementListItem::Statement(Statement::Var(var)) => {
+                            Ok(ExportDeclaration::VarStatement(var))
+                        }
+                        StatementListItem::Statement(_) => {
+                            Err(unexpected(declaration, "export declaration"))
+                        }
+                    };
+                }
+                let names = array(node, "specifiers")?
+                    .iter()
+                    .map(|specifier| self.export_specifier(specifier))
+                    .collect::<Result<Vec<_>>>()?
+                    .into_boxed_slice();
+                if optional_field(node, "source").is_none() {
+                    return Ok(ExportDeclaration::List(names));
+                }
+                Ok(ExportDeclaration::ReExport {
+                    kind: ReExportKind::Named { names },
+                    specifier: self.module_specifier(node)?,
+                    attributes: self.import_attributes(node)?,
+                })
+            }
+            _ => Err(unexpected(node, "export position")),
+        }
+    }
+}
+
+/// Gets the span of an `ESTree` node from its `loc` property, which has 0-based columns.
+pub(super) fn span(node: &Value) -> Option<Span> {
+    let loc = node.get("loc")?;
+    let start = position(loc.get("start")?)?;
+    let end = position(loc.get("end")?)?;
+    (start <= end).then(|| Span::new(start, end))
+}
+
+fn position(value: &Value) -> Option<Position> {
+    let line = u32::try_from(value.get("line")?.as_u64()?).ok()?;
+    let column = u32::try_from(value.get("column")?.as_u64()?)
+        .ok()?
+        .checked_add(1)?;
+    (line != 0).then(|| Position::new(line, column))
+}
+
+fn spanned<T: Spanned>(node: &Value, mut value: T) -> T {
+    if let Some(span) = span(node) {
+        value.set_span(span);
+    }
+    value
+}
+
+fn kind(node: &Value) -> Result<&str> {
+    node.get("type")
+        .and_then(Value::as_str)
+        .ok_or_else(|| EstreeError::new("expected an ESTree node", node))
+}
+
+fn unexpected(node: &Value, context: &str) -> EstreeError {
+    let message = match kind(node) {
+        Ok(kind) => format!("unexpected `{kind}` node in {context}"),
+        Err(_) => format!("expected an ESTree node in {context}"),
+    };
+    EstreeError::new(message, node)
+}
+
+fn field<'v>(node: &'v Value, name: &str) -> Result<&'v Value> {
+    optional_field(node, name)
+        .ok_or_else(|| EstreeError::new(format!("missing `{name}` property"), node))
+}
+
+fn optional_field<'v>(node: &'v Value, name: &str) -> Option<&'v Value> {
+    node.get(name).filter(|value| !value.is_null())
+}
+
+fn array<'v>(node: &'v Value, name: &str) -> Result<&'v [Value]> {
+    field(node, name)?
+        .as_array()
+        .map(Vec::as_slice)
+        .ok_or_else(|| EstreeError::new(format!("`{name}` must be an array"), node))
+}
+
+fn optional_array<'v>(node: &'v Value, name: &str) -> &'v [Value] {
+    optional_field(node, name)
+        .and_then(Value::as_array)
+        .map_or(&[], Vec::as_slice)
+}
+
+fn string_field<'v>(node: &'v Value, name: &str) -> Result<&'v str> {
+    field(node, name)?
+        .as_str()
+        .ok_or_else(|| EstreeError::new(format!("`{name}` must be a string"), node))
+}
+
+fn flag(node: &Value, name: &str) -> bool {
+    node.get(name).and_then(Value::as_bool).unwrap_or(false)
+}
+
+fn program_body(program: &Value) -> Result<&[Value]> {
+    if kind(program)? != "Program" {
+        return Err(unexpected(program, "program position"));
+    }
+    array(program, "body")
+}
+
+/// Checks if the directive prologue of a body contains a `"use strict"` directive.
+fn has_use_strict(body: &[Value]) -> bool {
+    body.iter()
+        .map_while(|statement| statement.get("directive").and_then(Value::as_str))
+        .any(|directive| directive == "use strict")
+}
+
+/// Splits an `AssignmentPattern` into its target and its default value.
+fn split_default(node: &Value) -> Result<(&Value, Option<&Value>)> {
+    if kind(node)? == "AssignmentPattern" {
+        Ok((field(node, "left")?, Some(field(node, "right")?)))
+    } else {
+        Ok((node, None))
+    }
+}
+
+fn method_kind(function: &Value) -> MethodDefinitionKind {
+    match (flag(function, "generator"), flag(function, "async")) {
+        (false, false) => MethodDefinitionKind::Ordinary,
+        (true, false) => MethodDefinitionKind::Generator,
+        (false, true) => MethodDefinitionKind::Async,
+        (true, true) => MethodDefinitionKind::AsyncGenerator,
+    }
+}
+
+fn variable(binding: Binding, init: Option<Expression>) -> Variable {
+    match binding {
+        Binding::Identifier(ident) => Variable::from_identifier(ident, init),
+        Binding::Pattern(pattern) => Variable::from_pattern(pattern, init),
+    }
+}
+
+fn unary_op(operator: &str) -> Option<UnaryOp> {
+    Some(match operator {
+        "-" => UnaryOp::Minus,
+        "+" => UnaryOp::Plus,
+        "!" => UnaryOp::Not,
+        "~" => UnaryOp::Tilde,
+        "typeof" => UnaryOp::TypeOf,
+        "delete" => UnaryOp::Delete,
+        "void" => UnaryOp::Void,
+        _ => return None,
+    })
+}
+
+fn binary_op(operator: &str) -> Option<BinaryOp> {
+    Some(match operator {
+        "+" => ArithmeticOp::Add.into(),
+        "-" => ArithmeticOp::Sub.into(),
+        "/" => ArithmeticOp::Div.into(),
+        "*" => ArithmeticOp::Mul.into(),
+        "**" => ArithmeticOp::Exp.into(),
+        "%" => ArithmeticOp::Mod.into(),
+        "&" => BitwiseOp::And.into(),
+        "|" => BitwiseOp::Or.into(),
+        "^" => BitwiseOp::Xor.into(),
+        "<<" => BitwiseOp::Shl.into(),
+        ">>" => BitwiseOp::Shr.into(),
+        ">>>" => BitwiseOp::UShr.into(),
+        "==" => RelationalOp::Equal.into(),
+        "!=" => RelationalOp::NotEqual.into(),
+        "===" => RelationalOp::StrictEqual.into(),
+        "!==" => RelationalOp::StrictNotEqual.into(),
+        ">" => RelationalOp::GreaterThan.into(),
+        ">=" => RelationalOp::GreaterThanOrEqual.into(),
+        "<" => RelationalOp::LessThan.into(),
+        "<=" => RelationalOp::LessThanOrEqual.into(),
+        "in" => RelationalOp::In.into(),
+        "instanceof" => RelationalOp::InstanceOf.into(),
+        "&&" => LogicalOp::And.into(),
+        "||" => LogicalOp::Or.into(),
+        "??" => LogicalOp::Coalesce.into(),
+        _ => return None,
+    })
+}
+
+fn assign_op(operator: &str) -> Option<AssignOp> {
+    Some(match operator {
+        "=" => AssignOp::Assign,
+        "+=" => AssignOp::Add,
+        "-=" => AssignOp::Sub,
+        "*=" => AssignOp::Mul,
+        "/=" => AssignOp::Div,
+        "%=" => AssignOp::Mod,
+        "**=" => AssignOp::Exp,
+        "&=" => AssignOp::And,
+        "|=" => AssignOp::Or,
+        "^=" => AssignOp::Xor,
+        "<<=" => AssignOp::Shl,
+        ">>=" => AssignOp::Shr,
+        ">>>=" => AssignOp::Ushr,
+        "&&=" => AssignOp::BoolAnd,
+        "||=" => AssignOp::BoolOr,
+        "??=" => AssignOp::Coalesce,
+        _ => return None,
+    })
+}
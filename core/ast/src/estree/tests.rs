@@ -0,0 +1,247 @@
+use boa_interner::Interner;
+use serde_json::{json, Value};
+
+use super::*;
+use crate::Spanned;
+
+/// Reads `program` as a script and writes it back.
+fn script_round_trip(program: &Value) -> Value {
+    let interner = &mut Interner::default();
+    let script = script_from_estree(program, interner).expect("program should be valid");
+    script_to_estree(&script, interner)
+}
+
+/// Reads `program` as a module and writes it back.
+fn module_round_trip(program: &Value) -> Value {
+    let interner = &mut Interner::default();
+    let module = module_from_estree(program, interner).expect("program should be valid");
+    module_to_estree(&module, interner)
+}
+
+fn identifier(name: &str) -> Value {
+    json!({ "type": "Identifier", "name": name })
+}
+
+#[test]
+fn script() {
+    let program = json!({
+        "type": "Program",
+        "sourceType": "script",
+        "body": [
+            {
+                "type": "ExpressionStatement",
+                "expression": { "type": "Literal", "value": "use strict" },
+                "directive": "use strict",
+            },
+            {
+                "type": "VariableDeclaration",
+                "kind": "let",
+                "declarations": [{
+                    "type": "VariableDeclarator",
+                    "id": identifier("x"),
+                    "init": {
+                        "type": "BinaryExpression",
+                        "operator": "+",
+                        "left": { "type": "Literal", "value": 1 },
+                        "right": { "type": "Literal", "value": 2.5 },
+                    },
+                }],
+            },
+            {
+                "type": "FunctionDeclaration",
+                "id": identifier("f"),
+                "params": [
+                    {
+                        "type": "AssignmentPattern",
+                        "left": identifier("a"),
+                        "right": { "type": "Literal", "value": null, "raw": "null" },
+                    },
+                    { "type": "RestElement", "argument": identifier("rest") },
+                ],
+                "body": {
+                    "type": "BlockStatement",
+                    "body": [{
+                        "type": "ReturnStatement",
+                        "argument": {
+                            "type": "ChainExpression",
+                            "expression": {
+                                "type": "CallExpression",
+                                "callee": {
+                                    "type": "MemberExpression",
+                                    "object": identifier("a"),
+                                    "property": identifier("b"),
+                                    "computed": false,
+                                    "optional": true,
+                                },
+                                "arguments": [],
+                                "optional": false,
+                            },
+                        },
+                    }],
+                },
+                "generator": false,
+                "async": false,
+            },
+        ],
+    });
+
+    let first = script_round_trip(&program);
+    assert_eq!(first["body"][0]["directive"], "use strict");
+    assert_eq!(
+        first["body"][1]["declarations"][0]["init"]["right"]["value"],
+        2.5
+    );
+    let chain = &first["body"][2]["body"]["body"][0]["argument"];
+    assert_eq!(chain["type"], "ChainExpression");
+    assert_eq!(chain["expression"]["callee"]["optional"], true);
+
+    assert_eq!(script_round_trip(&first), first);
+}
+
+#[test]
+fn module() {
+    let program = json!({
+        "type": "Program",
+        "sourceType": "module",
+        "body": [
+            {
+                "type": "ImportDeclaration",
+                "specifiers": [
+                    { "type": "ImportDefaultSpecifier", "local": identifier("a") },
+                    { "type": "ImportSpecifier", "imported": identifier("b"), "local": identifier("c") },
+                ],
+                "source": { "type": "Literal", "value": "mod" },
+                "attributes": [{
+                    "type": "ImportAttribute",
+                    "key": identifier("type"),
+                    "value": { "type": "Literal", "value": "json" },
+                }],
+            },
+            {
+                "type": "ExportDefaultDeclaration",
+                "declaration": {
+                    "type": "ClassDeclaration",
+                    "id": null,
+                    "superClass": null,
+                    "body": {
+                        "type": "ClassBody",
+                        "body": [{
+                            "type": "PropertyDefinition",
+                            "key": { "type": "PrivateIdentifier", "name": "x" },
+                            "value": { "type": "Literal", "value": 1 },
+                            "computed": false,
+                            "static": true,
+                        }],
+                    },
+                },
+            },
+            {
+                "type": "ExportNamedDeclaration",
+                "declaration": null,
+                "specifiers": [{
+                    "type": "ExportSpecifier",
+                    "local": identifier("c"),
+                    "exported": identifier("d"),
+                }],
+                "source": null,
+            },
+        ],
+    });
+
+    let first = module_round_trip(&program);
+    assert_eq!(first["body"][0]["attributes"][0]["value"]["value"], "json");
+    let class = &first["body"][1]["declaration"];
+    assert_eq!(class["id"], Value::Null);
+    assert_eq!(class["body"]["body"][0]["key"]["type"], "PrivateIdentifier");
+    assert_eq!(first["body"][2]["specifiers"][0]["exported"]["name"], "d");
+
+    assert_eq!(module_round_trip(&first), first);
+}
+
+#[test]
+fn template_literal() {
+    let quasi = |value: &str, tail: bool| {
+        json!({
+            "type": "TemplateElement",
+            "value": { "raw": value, "cooked": value },
+            "tail": tail,
+        })
+    };
+    let program = json!({
+        "type": "Program",
+        "sourceType": "script",
+        "body": [{
+            "type": "ExpressionStatement",
+            "expression": {
+                "type": "TemplateLiteral",
+                "quasis": [quasi("", false), quasi("", true)],
+                "expressions": [identifier("x")],
+            },
+        }],
+    });
+
+    let first = script_round_trip(&program);
+    let template = &first["body"][0]["expression"];
+    assert_eq!(template["quasis"].as_array().map(Vec::len), Some(2));
+    assert_eq!(template["expressions"][0]["name"], "x");
+}
+
+#[test]
+fn locations() {
+    let loc = |line: u32, start: u32, end: u32| {
+        json!({
+            "start": { "line": line, "column": start },
+            "end": { "line": line, "column": end },
+        })
+    };
+    let program = json!({
+        "type": "Program",
+        "sourceType": "script",
+        "body": [{
+            "type": "ExpressionStatement",
+            "expression": {
+                "type": "Identifier",
+                "name": "x",
+                "loc": loc(2, 4, 5),
+            },
+            "loc": loc(2, 4, 6),
+        }],
+    });
+
+    let first = script_round_trip(&program);
+    assert_eq!(first["body"][0]["expression"]["loc"], loc(2, 4, 5));
+
+    let interner = &mut Interner::default();
+    let script = script_from_estree(&program, interner).expect("program should be valid");
+    let span = script.statements().statements()[0]
+        .span()
+        .expect("span should be set");
+    assert_eq!(span.start(), Position::new(2, 5));
+}
+
+#[test]
+fn errors() {
+    let interner = &mut Interner::default();
+    let program = json!({
+        "type": "Program",
+        "sourceType": "script",
+        "body": [{
+            "type": "ExpressionStatement",
+            "expression": { "type": "Unknown", "loc": {
+                "start": { "line": 3, "column": 0 },
+                "end": { "line": 3, "column": 1 },
+            } },
+        }],
+    });
+
+    let error = script_from_estree(&program, interner).expect_err("node should be unknown");
+    assert_eq!(error.position(), Some(Position::new(3, 1)));
+    assert_eq!(
+        error.to_string(),
+        "unexpected `Unknown` node in expression position at 3:1"
+    );
+
+    let error = script_from_estree(&json!({ "type": "File" }), interner)
+        .expect_err("node should not be a program");
+    assert_eq!(error.position(), None);
+}
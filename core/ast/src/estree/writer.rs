@@ -0,0 +1,1610 @@
+//! Conversion from Boa's AST to `ESTree` nodes.
+
+use boa_interner::{Interner, Sym};
+use serde_json::{Map, Value};
+
+use crate::{
+    declaration::{
+        Binding, ExportDeclaration, ExportSpecifier, ImportAttribute, ImportDeclaration,
+        ImportKind, LexicalDeclaration, ReExportKind, VariableList,
+    },
+    expression::{
+        access::{PropertyAccess, PropertyAccessField},
+        literal::{LiteralKind, PropertyDefinition, TemplateElement},
+        operator::{
+            assign::AssignTarget,
+            binary::BinaryOp,
+            update::{UpdateOp, UpdateTarget},
+        },
+        Expression, Identifier, Optional, OptionalOperationKind, TaggedTemplate,
+    },
+    function::{
+        ClassElement, ClassElementName, Decorator, FormalParameterList, FunctionBody,
+        FunctionExpression, PrivateName,
+    },
+    pattern::{ArrayPatternElement, ObjectPatternElement, Pattern},
+    property::{MethodDefinitionKind, PropertyName},
+    statement::{
+        iteration::{ForLoopInitializer, IterableLoopInitializer},
+        Block, LabelledItem,
+    },
+    Declaration, Module, ModuleItem, Position, Script, Span, Spanned, Statement, StatementListItem,
+};
+
+/// Converts Boa's AST nodes to `ESTree` nodes.
+pub(super) struct Writer<'i> {
+    interner: &'i Interner,
+}
+
+impl<'i> Writer<'i> {
+    pub(super) const fn new(interner: &'i Interner) -> Self {
+        Self { interner }
+    }
+
+    pub(super) fn script(&self, script: &Script) -> Value {
+        node(
+            "Program",
+            None,
+            [
+                ("sourceType", "script".into()),
+                ("body", self.body(script.statements().statements())),
+            ],
+        )
+    }
+
+    pub(super) fn module(&self, module: &Module) -> Value {
+        let mut prologue = true;
+        let body = module
+            .items()
+            .items()
+            .iter()
+            .map(|item| {
+                let mut value = self.module_item(item);
+                if prologue {
+                    prologue = match item {
+                        ModuleItem::StatementListItem(item) => self.directive(item, &mut value),
+                        _ => false,
+                    };
+                }
+                value
+            })
+            .collect::<Vec<_>>();
+
+        node(
+            "Program",
+            None,
+            [("sourceType", "module".into()), ("body", body.into())],
+        )
+    }
+
+    fn string(&self, sym: Sym) -> String {
+        self.interner.resolve_expect(sym).to_string()
+    }
+
+    /// Marks `value` as a directive if `item` is a string literal statement.
+    fn directive(&self, item: &StatementListItem, value: &mut Value) -> bool {
+        if let StatementListItem::Statement(Statement::Expression(Expression::Literal(literal))) =
+            item
+        {
+            if let LiteralKind::String(sym) = literal.kind() {
+                value["directive"] = self.string(*sym).into();
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Converts the statements of a function or script body, including its directive prologue.
+    fn body(&self, items: &[StatementListItem]) -> Value {
+        let mut prologue = true;
+        items
+            .iter()
+            .map(|item| {
+                let mut value = self.statement_list_item(item);
+                if prologue {
+                    prologue = self.directive(item, &mut value);
+                }
+                value
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    fn statements(&self, items: &[StatementListItem]) -> Value {
+        items
+            .iter()
+            .map(|item| self.statement_list_item(item))
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    fn expressions(&self, expressions: &[Expression]) -> Value {
+        expressions
+            .iter()
+            .map(|expression| self.expression(expression))
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    fn optional_expression(&self, expression: Option<&Expression>) -> Value {
+        expression.map(|e| self.expression(e)).into()
+    }
+
+    fn name(&self, sym: Sym) -> Value {
+        node("Identifier", None, [("name", self.string(sym).into())])
+    }
+
+    fn identifier(&self, identifier: Identifier) -> Value {
+        node(
+            "Identifier",
+            identifier.span(),
+            [("name", self.string(identifier.sym()).into())],
+        )
+    }
+
+    fn private_name(&self, name: PrivateName) -> Value {
+        node(
+            "PrivateIdentifier",
+            None,
+            [("name", self.string(name.description()).into())],
+        )
+    }
+
+    fn string_literal(&self, sym: Sym) -> Value {
+        node("Literal", None, [("value", self.string(sym).into())])
+    }
+
+    /// Converts a name that is an identifier if possible, and a string literal otherwise.
+    fn name_or_string(&self, sym: Sym) -> Value {
+        let name = self.string(sym);
+        if is_identifier_name(&name) {
+            node("Identifier", None, [("name", name.into())])
+        } else {
+            node("Literal", None, [("value", name.into())])
+        }
+    }
+
+    fn statement_list_item(&self, item: &StatementListItem) -> Value {
+        match item {
+            StatementListItem::Statement(statement) => self.statement(statement),
+            StatementListItem::Declaration(declaration) => self.declaration(declaration),
+        }
+    }
+
+    fn block(&self, block: &Block) -> Value {
+        node(
+            "BlockStatement",
+            block.span(),
+            [("body", self.statements(block.statement_list().statements()))],
+        )
+    }
+
+    fn statement(&self, statement: &Statement) -> Value {
+        let span = statement.span();
+        match statement {
+            Statement::Block(block) => self.block(block),
+            Statement::Var(var) => self.variable_declaration("var", &var.0, span),
+            Statement::Empty(_) | Statement::Error(_) => node("EmptyStatement", span, []),
+            Statement::Expression(Expression::Debugger(_)) => node("DebuggerStatement", span, []),
+            Statement::Expression(expression) => node(
+                "ExpressionStatement",
+                span,
+                [("expression", self.expression(expression))],
+            ),
+            Statement::If(r#if) => node(
+                "IfStatement",
+                span,
+                [
+                    ("test", self.expression(r#if.cond())),
+                    ("consequent", self.statement(r#if.body())),
+                    (
+                        "alternate",
+                        r#if.else_node().map(|s| self.statement(s)).into(),
+                    ),
+                ],
+            ),
+            Statement::DoWhileLoop(do_while) => node(
+                "DoWhileStatement",
+                span,
+                [
+                    ("body", self.statement(do_while.body())),
+                    ("test", self.expression(do_while.cond())),
+                ],
+            ),
+            Statement::WhileLoop(while_loop) => node(
+                "WhileStatement",
+                span,
+                [
+                    ("test", self.expression(while_loop.condition())),
+                    ("body", self.statement(while_loop.body())),
+                ],
+            ),
+            Statement::ForLoop(for_loop) => {
+                let init = for_loop.init().map(|init| match init {
+                    ForLoopInitializer::Expression(expression) => self.expression(expression),
+                    ForLoopInitializer::Var(var) => {
+                        self.variable_declaration("var", &var.0, var.span())
+                    }
+                    ForLoopInitializer::Lexical(lexical) => {
+                        self.lexical_declaration(lexical.declaration())
+                    }
+                });
+                node(
+                    "ForStatement",
+                    span,
+                    [
+                        ("init", init.into()),
+                        ("test", self.optional_expression(for_loop.condition())),
+                        ("update", self.optional_expression(for_loop.final_expr())),
+                        ("body", self.statement(for_loop.body())),
+                    ],
+                )
+            }
+            Statement::ForInLoop(for_in) => node(
+                "ForInStatement",
+                span,
+                [
+                    ("left", self.iterable_loop_initializer(for_in.initializer())),
+                    ("right", self.expression(for_in.target())),
+                    ("body", self.statement(for_in.body())),
+                ],
+            ),
+            Statement::ForOfLoop(for_of) => node(
+                "ForOfStatement",
+                span,
+                [
+                    ("await", for_of.r#await().into()),
+                    ("left", self.iterable_loop_initializer(for_of.initializer())),
+                    ("right", self.expression(for_of.iterable())),
+                    ("body", self.statement(for_of.body())),
+                ],
+            ),
+            Statement::Switch(switch) => {
+                let cases = switch
+                    .cases()
+                    .iter()
+                    .map(|case| {
+                        node(
+                            "SwitchCase",
+                            None,
+                            [
+                                ("test", self.optional_expression(case.condition())),
+                                ("consequent", self.statements(case.body().statements())),
+                            ],
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                node(
+                    "SwitchStatement",
+                    span,
+                    [
+                        ("discriminant", self.expression(switch.val())),
+                        ("cases", cases.into()),
+                    ],
+                )
+            }
+            Statement::Continue(cont) => node(
+                "ContinueStatement",
+                span,
+                [("label", cont.label().map(|l| self.name(l)).into())],
+            ),
+            Statement::Break(brk) => node(
+                "BreakStatement",
+                span,
+                [("label", brk.label().map(|l| self.name(l)).into())],
+            ),
+            Statement::Return(ret) => node(
+                "ReturnStatement",
+                span,
+                [("argument", self.optional_expression(ret.target()))],
+            ),
+            Statement::Labelled(labelled) => {
+                let body = match labelled.item() {
+                    LabelledItem::FunctionDeclaration(f) => self.function(
+                        "FunctionDeclaration",
+                        Some(f.name()),
+                        f.parameters(),
+                        f.body(),
+                        MethodDefinitionKind::Ordinary,
+                        f.span(),
+                    ),
+                    LabelledItem::Statement(statement) => self.statement(statement),
+                };
+                node(
+                    "LabeledStatement",
+                    span,
+                    [("label", self.name(labelled.label())), ("body", body)],
+                )
+            }
+            Statement::Throw(throw) => node(
+                "ThrowStatement",
+                span,
+                [("argument", self.expression(throw.target()))],
+            ),
+            Statement::Try(r#try) => {
+                let handler = r#try.catch().map(|catch| {
+                    node(
+                        "CatchClause",
+                        None,
+                        [
+                            ("param", catch.parameter().map(|p| self.binding(p)).into()),
+                            ("body", self.block(catch.block())),
+                        ],
+                    )
+                });
+                node(
+                    "TryStatement",
+                    span,
+                    [
+                        ("block", self.block(r#try.block())),
+                        ("handler", handler.into()),
+                        (
+                            "finalizer",
+                            r#try.finally().map(|f| self.block(f.block())).into(),
+                        ),
+                    ],
+                )
+            }
+            Statement::With(with) => node(
+                "WithStatement",
+                span,
+                [
+                    ("object", self.expression(with.expression())),
+                    ("body", self.statement(with.statement())),
+                ],
+            ),
+        }
+    }
+
+    fn declaration(&self, declaration: &Declaration) -> Value {
+        let span = declaration.span();
+        match declaration {
+            Declaration::FunctionDeclaration(f) => self.function(
+                "FunctionDeclaration",
+                Some(f.name()),
+                f.parameters(),
+                f.body(),
+                MethodDefinitionKind::Ordinary,
+                span,
+            ),
+            Declaration::GeneratorDeclaration(f) => self.function(
+                "FunctionDeclaration",
+                Some(f.name()),
+                f.parameters(),
+                f.body(),
+                MethodDefinitionKind::Generator,
+                span,
+            ),
+            Declaration::AsyncFunctionDeclaration(f) => self.function(
+                "FunctionDeclaration",
+                Some(f.name()),
+                f.parameters(),
+                f.body(),
+                MethodDefinitionKind::Async,
+                span,
+            ),
+            Declaration::AsyncGeneratorDeclaration(f) => self.function(
+                "FunctionDeclaration",
+                Some(f.name()),
+                f.parameters(),
+                f.body(),
+                MethodDefinitionKind::AsyncGenerator,
+                span,
+            ),
+            Declaration::ClassDeclaration(class) => self.class(
+                "ClassDeclaration",
+                Some(class.name()),
+                class.super_ref(),
+                self.class_body(class.constructor(), class.elements()),
+                class.decorators(),
+                span,
+            ),
+            Declaration::Lexical(lexical) => self.lexical_declaration(lexical),
+        }
+    }
+
+    fn lexical_declaration(&self, declaration: &LexicalDeclaration) -> Value {
+        let kind = match declaration {
+            LexicalDeclaration::Const(_) => "const",
+            LexicalDeclaration::Let(_) => "let",
+            LexicalDeclaration::Using(_) => "using",
+            LexicalDeclaration::AwaitUsing(_) => "await using",
+        };
+        self.variable_declaration(kind, declaration.variable_list(), declaration.span())
+    }
+
+    fn variable_declaration(&self, kind: &str, list: &VariableList, span: Option<Span>) -> Value {
+        let declarations = list
+            .as_ref()
+            .iter()
+            .map(|variable| {
+                self.variable_declarator(self.binding(variable.binding()), variable.init())
+            })
+            .collect::<Vec<_>>();
+        node(
+            "VariableDeclaration",
+            span,
+            [("kind", kind.into()), ("declarations", declarations.into())],
+        )
+    }
+
+    fn variable_declarator(&self, id: Value, init: Option<&Expression>) -> Value {
+        node(
+            "VariableDeclarator",
+            None,
+            [("id", id), ("init", self.optional_expression(init))],
+        )
+    }
+
+    fn iterable_loop_initializer(&self, initializer: &IterableLoopInitializer) -> Value {
+        let (kind, binding) = match initializer {
+            IterableLoopInitializer::Identifier(ident) => return self.identifier(*ident),
+            IterableLoopInitializer::Access(access) => return self.property_access(access),
+            IterableLoopInitializer::Pattern(pattern) => return self.pattern(pattern),
+            IterableLoopInitializer::Var(variable) => {
+                let declarator =
+                    self.variable_declarator(self.binding(variable.binding()), variable.init());
+                return node(
+                    "VariableDeclaration",
+                    None,
+                    [
+                        ("kind", "var".into()),
+                        ("declarations", vec![declarator].into()),
+                    ],
+                );
+            }
+            IterableLoopInitializer::Let(binding) => ("let", binding),
+            IterableLoopInitializer::Const(binding) => ("const", binding),
+            IterableLoopInitializer::Using(binding) => ("using", binding),
+            IterableLoopInitializer::AwaitUsing(binding) => ("await using", binding),
+        };
+        let declarator = self.variable_declarator(self.binding(binding), None);
+        node(
+            "VariableDeclaration",
+            None,
+            [
+                ("kind", kind.into()),
+                ("declarations", vec![declarator].into()),
+            ],
+        )
+    }
+
+    fn binding(&self, binding: &Binding) -> Value {
+        match binding {
+            Binding::Identifier(ident) => self.identifier(*ident),
+            Binding::Pattern(pattern) => self.pattern(pattern),
+        }
+    }
+
+    fn with_default(&self, target: Value, default: Option<&Expression>) -> Value {
+        match default {
+            Some(default) => node(
+                "AssignmentPattern",
+                None,
+                [("left", target), ("right", self.expression(default))],
+            ),
+            None => target,
+        }
+    }
+
+    fn pattern(&self, pattern: &Pattern) -> Value {
+        match pattern {
+            Pattern::Object(object) => {
+                let properties = object
+                    .bindings()
+                    .iter()
+                    .map(|element| self.object_pattern_element(element))
+                    .collect::<Vec<_>>();
+                node("ObjectPattern", None, [("properties", properties.into())])
+            }
+            Pattern::Array(array) => {
+                let elements = array
+                    .bindings()
+                    .iter()
+                    .map(|element| self.array_pattern_element(element))
+                    .collect::<Vec<_>>();
+                node("ArrayPattern", None, [("elements", elements.into())])
+            }
+        }
+    }
+
+    fn object_pattern_element(&self, element: &ObjectPatternElement) -> Value {
+        match element {
+            ObjectPatternElement::SingleName {
+                name,
+                ident,
+                default_init,
+            } => {
+                let (key, computed) = self.property_name(name);
+                let shorthand = !computed && name.literal() == Some(ident.sym());
+                let value = self.with_default(self.identifier(*ident), default_init.as_ref());
+                property(key, computed, value, "init", false, shorthand)
+            }
+            ObjectPatternElement::RestProperty { ident } => {
+                node("RestElement", None, [("argument", self.identifier(*ident))])
+            }
+            ObjectPatternElement::AssignmentPropertyAccess {
+                name,
+                access,
+                default_init,
+            } => {
+                let (key, computed) = self.property_name(name);
+                let value = self.with_default(self.property_access(access), default_init.as_ref());
+                property(key, computed, value, "init", false, false)
+            }
+            ObjectPatternElement::AssignmentRestPropertyAccess { access } => node(
+                "RestElement",
+                None,
+                [("argument", self.property_access(access))],
+            ),
+            ObjectPatternElement::Pattern {
+                name,
+                pattern,
+                default_init,
+            } => {
+                let (key, computed) = self.property_name(name);
+                let value = self.with_default(self.pattern(pattern), default_init.as_ref());
+                property(key, computed, value, "init", false, false)
+            }
+        }
+    }
+
+    fn array_pattern_element(&self, element: &ArrayPatternElement) -> Value {
+        match element {
+            ArrayPatternElement::Elision => Value::Null,
+            ArrayPatternElement::SingleName {
+                ident,
+                default_init,
+            } => self.with_default(self.identifier(*ident), default_init.as_ref()),
+            ArrayPatternElement::PropertyAccess {
+                access,
+                default_init,
+            } => self.with_default(self.property_access(access), default_init.as_ref()),
+            ArrayPatternElement::Pattern {
+                pattern,
+                default_init,
+            } => self.with_default(self.pattern(pattern), default_init.as_ref()),
+            ArrayPatternElement::SingleNameRest { ident } => {
+                node("RestElement", None, [("argument", self.identifier(*ident))])
+            }
+            ArrayPatternElement::PropertyAccessRest { access } => node(
+                "RestElement",
+                None,
+                [("argument", self.property_access(access))],
+            ),
+            ArrayPatternElement::PatternRest { pattern } => {
+                node("RestElement", None, [("argument", self.pattern(pattern))])
+            }
+        }
+    }
+
+    fn parameters(&self, parameters: &FormalParameterList) -> Value {
+        parameters
+            .as_ref()
+            .iter()
+            .map(|parameter| {
+                let target = self.binding(parameter.variable().binding());
+                if parameter.is_rest_param() {
+                    node("RestElement", None, [("argument", target)])
+                } else {
+                    self.with_default(target, parameter.init())
+                }
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    fn function(
+        &self,
+        kind: &str,
+        id: Option<Identifier>,
+        parameters: &FormalParameterList,
+        body: &FunctionBody,
+        flavor: MethodDefinitionKind,
+        span: Option<Span>,
+    ) -> Value {
+        let (generator, r#async) = match flavor {
+            MethodDefinitionKind::Generator => (true, false),
+            MethodDefinitionKind::AsyncGenerator => (true, true),
+            MethodDefinitionKind::Async => (false, true),
+            MethodDefinitionKind::Get
+            | MethodDefinitionKind::Set
+            | MethodDefinitionKind::Ordinary => (false, false),
+        };
+        node(
+            kind,
+            span,
+            [
+                ("id", id.map(|id| self.identifier(id)).into()),
+                ("params", self.parameters(parameters)),
+                (
+                    "body",
+                    node(
+                        "BlockStatement",
+                        None,
+                        [("body", self.body(body.statements()))],
+                    ),
+                ),
+                ("generator", generator.into()),
+                ("async", r#async.into()),
+                ("expression", false.into()),
+            ],
+        )
+    }
+
+    fn arrow(
+        &self,
+        parameters: &FormalParameterList,
+        body: &FunctionBody,
+        r#async: bool,
+        span: Option<Span>,
+    ) -> Value {
+        // Concise bodies are parsed as a single `return` statement without a span of its own.
+        let concise = match body.statements() {
+            [StatementListItem::Statement(Statement::Return(ret))] if ret.span().is_none() => {
+                ret.target()
+            }
+            _ => None,
+        };
+        let (body, expression) = match concise {
+            Some(expression) => (self.expression(expression), true),
+            None => (
+                node(
+                    "BlockStatement",
+                    None,
+                    [("body", self.body(body.statements()))],
+                ),
+                false,
+            ),
+        };
+        node(
+            "ArrowFunctionExpression",
+            span,
+            [
+                ("id", Value::Null),
+                ("params", self.parameters(parameters)),
+                ("body", body),
+                ("generator", false.into()),
+                ("async", r#async.into()),
+                ("expression", expression.into()),
+            ],
+        )
+    }
+
+    fn decorators(&self, decorators: &[Decorator]) -> Value {
+        decorators
+            .iter()
+            .map(|decorator| {
+                node(
+                    "Decorator",
+                    None,
+                    [("expression", self.expression(decorator.expression()))],
+                )
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    fn class(
+        &self,
+        kind: &str,
+        id: Option<Identifier>,
+        super_ref: Option<&Expression>,
+        body: Value,
+        decorators: &[Decorator],
+        span: Option<Span>,
+    ) -> Value {
+        let mut class = node(
+            kind,
+            span,
+            [
+                ("id", id.map(|id| self.identifier(id)).into()),
+                ("superClass", self.optional_expression(super_ref)),
+                ("body", body),
+            ],
+        );
+        if !decorators.is_empty() {
+            class["decorators"] = self.decorators(decorators);
+        }
+        class
+    }
+
+    fn class_body(
+        &self,
+        constructor: Option<&FunctionExpression>,
+        elements: &[ClassElement],
+    ) -> Value {
+        let mut body = Vec::with_capacity(elements.len() + 1);
+        if let Some(constructor) = constructor {
+            let value = self.function(
+                "FunctionExpression",
+                None,
+                constructor.parameters(),
+                constructor.body(),
+                MethodDefinitionKind::Ordinary,
+                constructor.span(),
+            );
+            body.push(node(
+                "MethodDefinition",
+                None,
+                [
+                    (
+                        "key",
+                        node("Identifier", None, [("name", "constructor".into())]),
+                    ),
+                    ("computed", false.into()),
+                    ("value", value),
+                    ("kind", "constructor".into()),
+                    ("static", false.into()),
+                ],
+            ));
+        }
+        body.extend(elements.iter().map(|element| self.class_element(element)));
+        node("ClassBody", None, [("body", body.into())])
+    }
+
+    fn class_element(&self, element: &ClassElement) -> Value {
+        let mut value = match element {
+            ClassElement::MethodDefinition(method) => {
+                let (key, computed) = match method.name() {
+                    ClassElementName::PropertyName(name) => self.property_name(name),
+                    ClassElementName::PrivateName(name) => (self.private_name(*name), false),
+                };
+                let kind = match method.kind() {
+                    MethodDefinitionKind::Get => "get",
+                    MethodDefinitionKind::Set => "set",
+                    _ => "method",
+                };
+                let function = self.function(
+                    "FunctionExpression",
+                    None,
+                    method.parameters(),
+                    method.body(),
+                    method.kind(),
+                    None,
+                );
+                node(
+                    "MethodDefinition",
+                    None,
+                    [
+                        ("key", key),
+                        ("computed", computed.into()),
+                        ("value", function),
+                        ("kind", kind.into()),
+                        ("static", method.is_static().into()),
+                    ],
+                )
+            }
+            ClassElement::FieldDefinition(field) | ClassElement::StaticFieldDefinition(field) => {
+                let (key, computed) = self.property_name(field.name());
+                self.field(
+                    field.is_accessor(),
+                    key,
+                    computed,
+                    field.initializer(),
+                    matches!(element, ClassElement::StaticFieldDefinition(_)),
+                )
+            }
+            ClassElement::PrivateFieldDefinition(field)
+            | ClassElement::PrivateStaticFieldDefinition(field) => self.field(
+                field.is_accessor(),
+                self.private_name(*field.name()),
+                false,
+                field.initializer(),
+                matches!(element, ClassElement::PrivateStaticFieldDefinition(_)),
+            ),
+            ClassElement::StaticBlock(block) => node(
+                "StaticBlock",
+                None,
+                [("body", self.statements(block.statements().statements()))],
+            ),
+        };
+        let decorators = element.decorators();
+        if !decorators.is_empty() {
+            value["decorators"] = self.decorators(decorators);
+        }
+        value
+    }
+
+    fn field(
+        &self,
+        accessor: bool,
+        key: Value,
+        computed: bool,
+        initializer: Option<&Expression>,
+        r#static: bool,
+    ) -> Value {
+        node(
+            if accessor {
+                "AccessorProperty"
+            } else {
+                "PropertyDefinition"
+            },
+            None,
+            [
+                ("key", key),
+                ("computed", computed.into()),
+                ("value", self.optional_expression(initializer)),
+                ("static", r#static.into()),
+            ],
+        )
+    }
+
+    /// Converts a property name, returning the key and whether it is computed.
+    fn property_name(&self, name: &PropertyName) -> (Value, bool) {
+        match name {
+            PropertyName::Literal(sym) => (self.name_or_string(*sym), false),
+            // Numeric keys are parsed as computed literals.
+            PropertyName::Computed(expression @ Expression::Literal(literal))
+                if matches!(
+                    literal.kind(),
+                    LiteralKind::Num(_) | LiteralKind::Int(_) | LiteralKind::BigInt(_)
+                ) =>
+            {
+                (self.expression(expression), false)
+            }
+            PropertyName::Computed(expression) => (self.expression(expression), true),
+        }
+    }
+
+    fn property_definition(&self, definition: &PropertyDefinition) -> Value {
+        match definition {
+            PropertyDefinition::IdentifierReference(ident) => property(
+                self.identifier(*ident),
+                false,
+                self.identifier(*ident),
+                "init",
+                false,
+                true,
+            ),
+            PropertyDefinition::Property(name, value) => {
+                let (key, computed) = self.property_name(name);
+                property(key, computed, self.expression(value), "init", false, false)
+            }
+            PropertyDefinition::MethodDefinition(method) => {
+                let (key, computed) = self.property_name(method.name());
+                let (kind, is_method) = match method.kind() {
+                    MethodDefinitionKind::Get => ("get", false),
+                    MethodDefinitionKind::Set => ("set", false),
+                    _ => ("init", true),
+                };
+                let function = self.function(
+                    "FunctionExpression",
+                    None,
+                    method.parameters(),
+                    method.body(),
+                    method.kind(),
+                    None,
+                );
+                property(key, computed, function, kind, is_method, false)
+            }
+            PropertyDefinition::SpreadObject(target) => node(
+                "SpreadElement",
+                None,
+                [("argument", self.expression(target))],
+            ),
+            PropertyDefinition::CoverInitializedName(ident, init) => property(
+                self.identifier(*ident),
+                false,
+                self.with_default(self.identifier(*ident), Some(init)),
+                "init",
+                false,
+                true,
+            ),
+        }
+    }
+
+    fn member(
+        &self,
+        object: Value,
+        field: &PropertyAccessField,
+        optional: bool,
+        span: Option<Span>,
+    ) -> Value {
+        let (property, computed) = match field {
+            PropertyAccessField::Const(sym) => {
+                let name = self.string(*sym);
+                if is_identifier_name(&name) {
+                    (node("Identifier", None, [("name", name.into())]), false)
+                } else {
+                    (node("Literal", None, [("value", name.into())]), true)
+                }
+            }
+            PropertyAccessField::Expr(expression) => (self.expression(expression), true),
+        };
+        node(
+            "MemberExpression",
+            span,
+            [
+                ("object", object),
+                ("property", property),
+                ("computed", computed.into()),
+                ("optional", optional.into()),
+            ],
+        )
+    }
+
+    fn private_member(
+        &self,
+        object: Value,
+        field: PrivateName,
+        optional: bool,
+        span: Option<Span>,
+    ) -> Value {
+        node(
+            "MemberExpression",
+            span,
+            [
+                ("object", object),
+                ("property", self.private_name(field)),
+                ("computed", false.into()),
+                ("optional", optional.into()),
+            ],
+        )
+    }
+
+    fn property_access(&self, access: &PropertyAccess) -> Value {
+        let span = access.span();
+        match access {
+            PropertyAccess::Simple(access) => self.member(
+                self.expression(access.target()),
+                access.field(),
+                false,
+                span,
+            ),
+            PropertyAccess::Private(access) => self.private_member(
+                self.expression(access.target()),
+                access.field(),
+                false,
+                span,
+            ),
+            PropertyAccess::Super(access) => {
+                self.member(node("Super", None, []), access.field(), false, span)
+            }
+        }
+    }
+
+    fn optional(&self, optional: &Optional, span: Option<Span>) -> Value {
+        let mut chain = self.expression(optional.target());
+        for operation in optional.chain() {
+            let shorted = operation.shorted();
+            chain = match operation.kind() {
+                OptionalOperationKind::SimplePropertyAccess { field } => {
+                    self.member(chain, field, shorted, None)
+                }
+                OptionalOperationKind::PrivatePropertyAccess { field } => {
+                    self.private_member(chain, *field, shorted, None)
+                }
+                OptionalOperationKind::Call { args } => node(
+                    "CallExpression",
+                    None,
+                    [
+                        ("callee", chain),
+                        ("arguments", self.expressions(args)),
+                        ("optional", shorted.into()),
+                    ],
+                ),
+            };
+        }
+        node("ChainExpression", span, [("expression", chain)])
+    }
+
+    fn template_literal(&self, elements: &[TemplateElement], span: Option<Span>) -> Value {
+        let mut quasis = Vec::new();
+        let mut expressions = Vec::new();
+        let mut text = None;
+        for element in elements {
+            match element {
+                TemplateElement::String(sym) => text = Some(*sym),
+                TemplateElement::Expr(expression) => {
+                    quasis.push(self.template_element(text.take(), false));
+                    expressions.push(self.expression(expression));
+                }
+            }
+        }
+        quasis.push(self.template_element(text, true));
+        node(
+            "TemplateLiteral",
+            span,
+            [
+                ("quasis", quasis.into()),
+                ("expressions", expressions.into()),
+            ],
+        )
+    }
+
+    /// Converts a quasi of an untagged template, which only keeps its cooked string.
+    fn template_element(&self, text: Option<Sym>, tail: bool) -> Value {
+        let text = text.map_or_else(String::new, |sym| self.string(sym));
+        quasi(text.clone(), Some(text), tail)
+    }
+
+    fn tagged_template(&self, template: &TaggedTemplate, span: Option<Span>) -> Value {
+        let count = template.raws().len();
+        let quasis = template
+            .raws()
+            .iter()
+            .zip(template.cookeds())
+            .enumerate()
+            .map(|(i, (raw, cooked))| {
+                quasi(
+                    self.string(*raw),
+                    cooked.map(|sym| self.string(sym)),
+                    i + 1 == count,
+                )
+            })
+            .collect::<Vec<_>>();
+        let template_literal = node(
+            "TemplateLiteral",
+            None,
+            [
+                ("quasis", quasis.into()),
+                ("expressions", self.expressions(template.exprs())),
+            ],
+        );
+        node(
+            "TaggedTemplateExpression",
+            span,
+            [
+                ("tag", self.expression(template.tag())),
+                ("quasi", template_literal),
+            ],
+        )
+    }
+
+    fn literal(&self, kind: &LiteralKind, span: Option<Span>) -> Value {
+        match kind {
+            LiteralKind::String(sym) => {
+                node("Literal", span, [("value", self.string(*sym).into())])
+            }
+            LiteralKind::Num(num) if num.is_finite() => {
+                node("Literal", span, [("value", (*num).into())])
+            }
+            // JSON cannot represent non-finite numbers.
+            LiteralKind::Num(num) => {
+                let raw = if num.is_nan() {
+                    "NaN"
+                } else if num.is_sign_positive() {
+                    "Infinity"
+                } else {
+                    "-Infinity"
+                };
+                node(
+                    "Literal",
+                    span,
+                    [("value", Value::Null), ("raw", raw.into())],
+                )
+            }
+            LiteralKind::Int(num) => node("Literal", span, [("value", (*num).into())]),
+            LiteralKind::BigInt(num) => node(
+                "Literal",
+                span,
+                [("value", Value::Null), ("bigint", num.to_string().into())],
+            ),
+            LiteralKind::Bool(value) => node("Literal", span, [("value", (*value).into())]),
+            LiteralKind::Null => node("Literal", span, [("value", Value::Null)]),
+            LiteralKind::Undefined => node("Identifier", span, [("name", "undefined".into())]),
+        }
+    }
+
+    fn sequence(&self, expression: &Expression, expressions: &mut Vec<Value>) {
+        if let Expression::Binary(binary) = expression {
+            if matches!(binary.op(), BinaryOp::Comma) {
+                self.sequence(binary.lhs(), expressions);
+                self.sequence(binary.rhs(), expressions);
+                return;
+            }
+        }
+        expressions.push(self.expression(expression));
+    }
+
+    fn expression(&self, expression: &Expression) -> Value {
+        let span = expression.span();
+        match expression {
+            Expression::This(_) => node("ThisExpression", span, []),
+            Expression::Identifier(ident) => self.identifier(*ident),
+            Expression::Literal(literal) => self.literal(literal.kind(), span),
+            Expression::RegExpLiteral(regexp) => {
+                let mut regex = Map::new();
+                regex.insert("pattern".to_owned(), self.string(regexp.pattern()).into());
+                regex.insert("flags".to_owned(), self.string(regexp.flags()).into());
+                node(
+                    "Literal",
+                    span,
+                    [("value", Value::Null), ("regex", regex.into())],
+                )
+            }
+            Expression::ArrayLiteral(array) => {
+                let elements = array
+                    .as_ref()
+                    .iter()
+                    .map(|element| self.optional_expression(element.as_ref()))
+                    .collect::<Vec<_>>();
+                node("ArrayExpression", span, [("elements", elements.into())])
+            }
+            Expression::ObjectLiteral(object) => {
+                let properties = object
+                    .properties()
+                    .iter()
+                    .map(|definition| self.property_definition(definition))
+                    .collect::<Vec<_>>();
+                node(
+                    "ObjectExpression",
+                    span,
+                    [("properties", properties.into())],
+                )
+            }
+            Expression::Spread(spread) => node(
+                "SpreadElement",
+                span,
+                [("argument", self.expression(spread.target()))],
+            ),
+            Expression::FunctionExpression(f) => self.function(
+                "FunctionExpression",
+                f.name(),
+                f.parameters(),
+                f.body(),
+                MethodDefinitionKind::Ordinary,
+                span,
+            ),
+            Expression::GeneratorExpression(f) => self.function(
+                "FunctionExpression",
+                f.name(),
+                f.parameters(),
+                f.body(),
+                MethodDefinitionKind::Generator,
+                span,
+            ),
+            Expression::AsyncFunctionExpression(f) => self.function(
+                "FunctionExpression",
+                f.name(),
+                f.parameters(),
+                f.body(),
+                MethodDefinitionKind::Async,
+                span,
+            ),
+            Expression::AsyncGeneratorExpression(f) => self.function(
+                "FunctionExpression",
+                f.name(),
+                f.parameters(),
+                f.body(),
+                MethodDefinitionKind::AsyncGenerator,
+                span,
+            ),
+            Expression::ArrowFunction(f) => self.arrow(f.parameters(), f.body(), false, span),
+            Expression::AsyncArrowFunction(f) => self.arrow(f.parameters(), f.body(), true, span),
+            Expression::ClassExpression(class) => self.class(
+                "ClassExpression",
+                class.name(),
+                class.super_ref(),
+                self.class_body(class.constructor(), class.elements()),
+                class.decorators(),
+                span,
+            ),
+            Expression::TemplateLiteral(template) => {
+                self.template_literal(template.elements(), span)
+            }
+            Expression::PropertyAccess(access) => self.property_access(access),
+            Expression::New(new) => node(
+                "NewExpression",
+                span,
+                [
+                    ("callee", self.expression(new.constructor())),
+                    ("arguments", self.expressions(new.arguments())),
+                ],
+            ),
+            Expression::Call(call) => node(
+                "CallExpression",
+                span,
+                [
+                    ("callee", self.expression(call.function())),
+                    ("arguments", self.expressions(call.args())),
+                    ("optional", false.into()),
+                ],
+            ),
+            Expression::SuperCall(call) => node(
+                "CallExpression",
+                span,
+                [
+                    ("callee", node("Super", None, [])),
+                    ("arguments", self.expressions(call.arguments())),
+                    ("optional", false.into()),
+                ],
+            ),
+            Expression::ImportCall(call) => node(
+                "ImportExpression",
+                span,
+                [
+                    ("source", self.expression(call.argument())),
+                    ("options", self.optional_expression(call.options())),
+                ],
+            ),
+            Expression::Optional(optional) => self.optional(optional, span),
+            Expression::TaggedTemplate(template) => self.tagged_template(template, span),
+            Expression::NewTarget(_) => meta_property("new", "target", span),
+            Expression::ImportMeta(_) => meta_property("import", "meta", span),
+            Expression::Assign(assign) => {
+                let left = match assign.lhs() {
+                    AssignTarget::Identifier(ident) => self.identifier(*ident),
+                    AssignTarget::Access(access) => self.property_access(access),
+                    AssignTarget::Pattern(pattern) => self.pattern(pattern),
+                };
+                node(
+                    "AssignmentExpression",
+                    span,
+                    [
+                        ("operator", assign.op().to_string().into()),
+                        ("left", left),
+                        ("right", self.expression(assign.rhs())),
+                    ],
+                )
+            }
+            Expression::Unary(unary) => node(
+                "UnaryExpression",
+                span,
+                [
+                    ("operator", unary.op().to_string().into()),
+                    ("prefix", true.into()),
+                    ("argument", self.expression(unary.target())),
+                ],
+            ),
+            Expression::Update(update) => {
+                let argument = match update.target() {
+                    UpdateTarget::Identifier(ident) => self.identifier(*ident),
+                    UpdateTarget::PropertyAccess(access) => self.property_access(access),
+                };
+                let prefix = matches!(update.op(), UpdateOp::IncrementPre | UpdateOp::DecrementPre);
+                node(
+                    "UpdateExpression",
+                    span,
+                    [
+                        ("operator", update.op().to_string().into()),
+                        ("prefix", prefix.into()),
+                        ("argument", argument),
+                    ],
+                )
+            }
+            Expression::Binary(binary) => match binary.op() {
+                BinaryOp::Comma => {
+                    let mut expressions = Vec::new();
+                    self.sequence(expression, &mut expressions);
+                    node(
+                        "SequenceExpression",
+                        span,
+                        [("expressions", expressions.into())],
+                    )
+                }
+                op => node(
+                    if matches!(op, BinaryOp::Logical(_)) {
+                        "LogicalExpression"
+                    } else {
+                        "BinaryExpression"
+                    },
+                    span,
+                    [
+                        ("operator", op.to_string().into()),
+                        ("left", self.expression(binary.lhs())),
+                        ("right", self.expression(binary.rhs())),
+                    ],
+                ),
+            },
+            Expression::BinaryInPrivate(binary) => node(
+                "BinaryExpression",
+                span,
+                [
+                    ("operator", "in".into()),
+                    ("left", self.private_name(*binary.lhs())),
+                    ("right", self.expression(binary.rhs())),
+                ],
+            ),
+            Expression::Conditional(conditional) => node(
+                "ConditionalExpression",
+                span,
+                [
+                    ("test", self.expression(conditional.condition())),
+                    ("consequent", self.expression(conditional.if_true())),
+                    ("alternate", self.expression(conditional.if_false())),
+                ],
+            ),
+            Expression::Await(expr) => node(
+                "AwaitExpression",
+                span,
+                [("argument", self.expression(expr.target()))],
+            ),
+            Expression::Yield(expr) => node(
+                "YieldExpression",
+                span,
+                [
+                    ("argument", self.optional_expression(expr.target())),
+                    ("delegate", expr.delegate().into()),
+                ],
+            ),
+            // ESTree does not represent parentheses.
+            Expression::Parenthesized(parenthesized) => self.expression(parenthesized.expression()),
+            // Only used internally by the parser.
+            Expression::FormalParameterList(_) | Expression::Debugger(_) => Value::Null,
+        }
+    }
+
+    fn module_item(&self, item: &ModuleItem) -> Value {
+        match item {
+            ModuleItem::ImportDeclaration(import) => self.import_declaration(import),
+            ModuleItem::ExportDeclaration(export) => self.export_declaration(export),
+            ModuleItem::StatementListItem(item) => self.statement_list_item(item),
+        }
+    }
+
+    fn import_attributes(&self, attributes: &[ImportAttribute]) -> Value {
+        attributes
+            .iter()
+            .map(|attribute| {
+                node(
+                    "ImportAttribute",
+                    None,
+                    [
+                        ("key", self.name_or_string(attribute.key())),
+                        ("value", self.string_literal(attribute.value())),
+                    ],
+                )
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    fn import_declaration(&self, import: &ImportDeclaration) -> Value {
+        let mut specifiers = Vec::new();
+        if let Some(default) = import.default() {
+            specifiers.push(node(
+                "ImportDefaultSpecifier",
+                None,
+                [("local", self.identifier(default))],
+            ));
+        }
+        match import.kind() {
+            ImportKind::DefaultOrUnnamed => {}
+            ImportKind::Namespaced { binding } => specifiers.push(node(
+                "ImportNamespaceSpecifier",
+                None,
+                [("local", self.identifier(*binding))],
+            )),
+            ImportKind::Named { names } => {
+                specifiers.extend(names.iter().map(|specifier| {
+                    node(
+                        "ImportSpecifier",
+                        None,
+                        [
+                            ("imported", self.name_or_string(specifier.export_name())),
+                            ("local", self.identifier(specifier.binding())),
+                        ],
+                    )
+                }));
+            }
+        }
+        node(
+            "ImportDeclaration",
+            import.span(),
+            [
+                ("specifiers", specifiers.into()),
+                ("source", self.string_literal(import.specifier().sym())),
+                ("attributes", self.import_attributes(import.attributes())),
+            ],
+        )
+    }
+
+    fn export_specifiers(&self, specifiers: &[ExportSpecifier]) -> Value {
+        specifiers
+            .iter()
+            .map(|specifier| {
+                let local = if specifier.string_literal() {
+                    self.string_literal(specifier.private_name())
+                } else {
+                    self.name(specifier.private_name())
+                };
+                node(
+                    "ExportSpecifier",
+                    None,
+                    [
+                        ("local", local),
+                        ("exported", self.name_or_string(specifier.alias())),
+                    ],
+                )
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    fn export_declaration(&self, export: &ExportDeclaration) -> Value {
+        let named_export =
+            |declaration: Value, specifiers: Value, source: Value, attributes: Value| {
+                node(
+                    "ExportNamedDeclaration",
+                    None,
+                    [
+                        ("declaration", declaration),
+                        ("specifiers", specifiers),
+                        ("source", source),
+                        ("attributes", attributes),
+                    ],
+                )
+            };
+        let default = |declaration: Value| {
+            node(
+                "ExportDefaultDeclaration",
+                None,
+                [("declaration", declaration)],
+            )
+        };
+        let empty = || Value::Array(Vec::new());
+
+        match export {
+            ExportDeclaration::ReExport {
+                kind,
+                specifier,
+                attributes,
+            } => {
+                let source = self.string_literal(specifier.sym());
+                let attributes = self.import_attributes(attributes);
+                match kind {
+                    ReExportKind::Namespaced { name } => node(
+                        "ExportAllDeclaration",
+                        None,
+                        [
+                            ("exported", name.map(|n| self.name_or_string(n)).into()),
+                            ("source", source),
+                            ("attributes", attributes),
+                        ],
+                    ),
+                    ReExportKind::Named { names } => named_export(
+                        Value::Null,
+                        self.export_specifiers(names),
+                        source,
+                        attributes,
+                    ),
+                }
+            }
+            ExportDeclaration::List(names) => named_export(
+                Value::Null,
+                self.export_specifiers(names),
+                Value::Null,
+                empty(),
+            ),
+            ExportDeclaration::VarStatement(var) => named_export(
+                self.variable_declaration("var", &var.0, var.span()),
+                empty(),
+                Value::Null,
+                empty(),
+            ),
+            ExportDeclaration::Declaration(declaration) => {
+                named_export(self.declaration(declaration), empty(), Value::Null, empty())
+            }
+            ExportDeclaration::DefaultFunctionDeclaration(f) => default(self.function(
+                "FunctionDeclaration",
+                default_name(f.name()),
+                f.parameters(),
+                f.body(),
+                MethodDefinitionKind::Ordinary,
+                f.span(),
+            )),
+            ExportDeclaration::DefaultGeneratorDeclaration(f) => default(self.function(
+                "FunctionDeclaration",
+                default_name(f.name()),
+                f.parameters(),
+                f.body(),
+                MethodDefinitionKind::Generator,
+                f.span(),
+            )),
+            ExportDeclaration::DefaultAsyncFunctionDeclaration(f) => default(self.function(
+                "FunctionDeclaration",
+                default_name(f.name()),
+                f.parameters(),
+                f.body(),
+                MethodDefinitionKind::Async,
+                f.span(),
+            )),
+            ExportDeclaration::DefaultAsyncGeneratorDeclaration(f) => default(self.function(
+                "FunctionDeclaration",
+                default_name(f.name()),
+                f.parameters(),
+                f.body(),
+                MethodDefinitionKind::AsyncGenerator,
+                f.span(),
+            )),
+            ExportDeclaration::DefaultClassDeclaration(class) => default(self.class(
+                "ClassDeclaration",
+                default_name(class.name()),
+                class.super_ref(),
+                self.class_body(class.constructor(), class.elements()),
+                class.decorators(),
+                class.span(),
+            )),
+            ExportDeclaration::DefaultAssignmentExpression(expression) => {
+                default(self.expression(expression))
+            }
+        }
+    }
+}
+
+/// Creates an `ESTree` node of type `kind`, adding its location if `span` is known.
+fn node<const N: usize>(kind: &str, span: Option<Span>, fields: [(&str, Value); N]) -> Value {
+    let mut object = Map::new();
+    object.insert("type".to_owned(), kind.into());
+    for (key, value) in fields {
+        object.insert(key.to_owned(), value);
+    }
+    if let Some(span) = span {
+        let mut loc = Map::new();
+        loc.insert("start".to_owned(), position(span.start()));
+        loc.insert("end".to_owned(), position(span.end()));
+        object.insert("loc".to_owned(), loc.into());
+    }
+    object.into()
+}
+
+/// Converts a position to an `ESTree` position, which has 0-based columns.
+fn position(position: Position) -> Value {
+    let mut object = Map::new();
+    object.insert("line".to_owned(), position.line_number().into());
+    object.insert("column".to_owned(), (position.column_number() - 1).into());
+    object.into()
+}
+
+fn property(
+    key: Value,
+    computed: bool,
+    value: Value,
+    kind: &str,
+    method: bool,
+    shorthand: bool,
+) -> Value {
+    node(
+        "Property",
+        None,
+        [
+            ("key", key),
+            ("computed", computed.into()),
+            ("value", value),
+            ("kind", kind.into()),
+            ("method", method.into()),
+            ("shorthand", shorthand.into()),
+        ],
+    )
+}
+
+fn quasi(raw: String, cooked: Option<String>, tail: bool) -> Value {
+    let mut value = Map::new();
+    value.insert("raw".to_owned(), raw.into());
+    value.insert("cooked".to_owned(), cooked.into());
+    node(
+        "TemplateElement",
+        None,
+        [("value", value.into()), ("tail", tail.into())],
+    )
+}
+
+fn meta_property(meta: &str, property: &str, span: Option<Span>) -> Value {
+    node(
+        "MetaProperty",
+        span,
+        [
+            ("meta", node("Identifier", None, [("name", meta.into())])),
+            (
+                "property",
+                node("Identifier", None, [("name", property.into())]),
+            ),
+        ],
+    )
+}
+
+/// Anonymous default exports are parsed with the `default` name.
+fn default_name(name: Identifier) -> Option<Identifier> {
+    (name.sym() != Sym::DEFAULT).then_some(name)
+}
+
+/// Checks if `name` can be written as an identifier.
+fn is_identifier_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
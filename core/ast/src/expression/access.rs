@@ -16,7 +16,11 @@
 
 use crate::expression::Expression;
 use crate::function::PrivateName;
-use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{
+    position::impl_spanned,
+    visitor::{VisitWith, Visitor, VisitorMut},
+    Span, SpanIgnoreEq, Spanned,
+};
 use boa_interner::{Interner, Sym, ToInternedString};
 use core::ops::ControlFlow;
 
@@ -95,6 +99,26 @@ impl ToInternedString for PropertyAccess {
     }
 }
 
+impl Spanned for PropertyAccess {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::Simple(s) => s.span(),
+            Self::Private(p) => p.span(),
+            Self::Super(s) => s.span(),
+        }
+    }
+
+    #[inline]
+    fn set_span(&mut self, span: Span) {
+        match self {
+            Self::Simple(s) => s.set_span(span),
+            Self::Private(p) => p.set_span(span),
+            Self::Super(s) => s.set_span(span),
+        }
+    }
+}
+
 impl From<PropertyAccess> for Expression {
     #[inline]
     fn from(access: PropertyAccess) -> Self {
@@ -133,6 +157,7 @@ impl VisitWith for PropertyAccess {
 pub struct SimplePropertyAccess {
     target: Box<Expression>,
     field: PropertyAccessField,
+    span: SpanIgnoreEq,
 }
 
 impl SimplePropertyAccess {
@@ -158,10 +183,13 @@ impl SimplePropertyAccess {
         Self {
             target: target.into(),
             field: field.into(),
+            span: SpanIgnoreEq(None),
         }
     }
 }
 
+impl_spanned!(SimplePropertyAccess);
+
 impl ToInternedString for SimplePropertyAccess {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
@@ -216,6 +244,7 @@ impl VisitWith for SimplePropertyAccess {
 pub struct PrivatePropertyAccess {
     target: Box<Expression>,
     field: PrivateName,
+    span: SpanIgnoreEq,
 }
 
 impl PrivatePropertyAccess {
@@ -226,6 +255,7 @@ impl PrivatePropertyAccess {
         Self {
             target: value.into(),
             field,
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -244,6 +274,8 @@ impl PrivatePropertyAccess {
     }
 }
 
+impl_spanned!(PrivatePropertyAccess);
+
 impl ToInternedString for PrivatePropertyAccess {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
@@ -292,13 +324,17 @@ impl VisitWith for PrivatePropertyAccess {
 #[derive(Clone, Debug, PartialEq)]
 pub struct SuperPropertyAccess {
     field: PropertyAccessField,
+    span: SpanIgnoreEq,
 }
 
 impl SuperPropertyAccess {
     /// Creates a new property access field node.
     #[must_use]
     pub const fn new(field: PropertyAccessField) -> Self {
-        Self { field }
+        Self {
+            field,
+            span: SpanIgnoreEq(None),
+        }
     }
 
     /// Gets the name of the field to retrieve.
@@ -309,6 +345,8 @@ impl SuperPropertyAccess {
     }
 }
 
+impl_spanned!(SuperPropertyAccess);
+
 impl ToInternedString for SuperPropertyAccess {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
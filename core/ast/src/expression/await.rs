@@ -3,7 +3,11 @@
 use core::ops::ControlFlow;
 
 use super::Expression;
-use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{
+    position::impl_spanned,
+    visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
+};
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 
 /// An await expression is used within an async function to pause execution and wait for a
@@ -20,6 +24,7 @@ use boa_interner::{Interner, ToIndentedString, ToInternedString};
 #[derive(Clone, Debug, PartialEq)]
 pub struct Await {
     target: Box<Expression>,
+    span: SpanIgnoreEq,
 }
 
 impl Await {
@@ -31,12 +36,17 @@ impl Await {
     }
 }
 
+impl_spanned!(Await);
+
 impl<T> From<T> for Await
 where
     T: Into<Box<Expression>>,
 {
     fn from(e: T) -> Self {
-        Self { target: e.into() }
+        Self {
+            target: e.into(),
+            span: SpanIgnoreEq(None),
+        }
     }
 }
 
@@ -1,5 +1,6 @@
 use crate::join_nodes;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{position::impl_spanned, Position, PositionIgnoreEq, SpanIgnoreEq};
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
@@ -25,6 +26,8 @@ use super::Expression;
 pub struct Call {
     function: Box<Expression>,
     args: Box<[Expression]>,
+    position: PositionIgnoreEq,
+    span: SpanIgnoreEq,
 }
 
 impl Call {
@@ -35,9 +38,19 @@ impl Call {
         Self {
             function: function.into(),
             args,
+            position: PositionIgnoreEq::default(),
+            span: SpanIgnoreEq(None),
         }
     }
 
+    /// Sets the position in the source code where this call expression starts.
+    #[inline]
+    #[must_use]
+    pub fn with_position(mut self, position: Position) -> Self {
+        self.position = PositionIgnoreEq(Some(position));
+        self
+    }
+
     /// Gets the target function of this call expression.
     #[inline]
     #[must_use]
@@ -51,8 +64,17 @@ impl Call {
     pub const fn args(&self) -> &[Expression] {
         &self.args
     }
+
+    /// Gets the position in the source code where this call expression starts, if known.
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        self.position.0
+    }
 }
 
+impl_spanned!(Call);
+
 impl ToInternedString for Call {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
@@ -108,6 +130,8 @@ impl VisitWith for Call {
 #[derive(Clone, Debug, PartialEq)]
 pub struct SuperCall {
     args: Box<[Expression]>,
+    position: PositionIgnoreEq,
+    span: SpanIgnoreEq,
 }
 
 impl SuperCall {
@@ -116,7 +140,26 @@ impl SuperCall {
     where
         A: Into<Box<[Expression]>>,
     {
-        Self { args: args.into() }
+        Self {
+            args: args.into(),
+            position: PositionIgnoreEq::default(),
+            span: SpanIgnoreEq(None),
+        }
+    }
+
+    /// Sets the position in the source code where this `super` call starts.
+    #[inline]
+    #[must_use]
+    pub fn with_position(mut self, position: Position) -> Self {
+        self.position = PositionIgnoreEq(Some(position));
+        self
+    }
+
+    /// Gets the position in the source code where this `super` call starts, if known.
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        self.position.0
     }
 
     /// Retrieves the arguments of the super call.
@@ -126,6 +169,8 @@ impl SuperCall {
     }
 }
 
+impl_spanned!(SuperCall);
+
 impl ToInternedString for SuperCall {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
@@ -177,30 +222,50 @@ impl VisitWith for SuperCall {
 #[derive(Clone, Debug, PartialEq)]
 pub struct ImportCall {
     arg: Box<Expression>,
+    options: Option<Box<Expression>>,
+    span: SpanIgnoreEq,
 }
 
 impl ImportCall {
     /// Creates a new `ImportCall` AST node.
-    pub fn new<A>(arg: A) -> Self
+    pub fn new<A>(arg: A, options: Option<Expression>) -> Self
     where
         A: Into<Expression>,
     {
         Self {
             arg: Box::new(arg.into()),
+            options: options.map(Box::new),
+            span: SpanIgnoreEq(None),
         }
     }
 
-    /// Retrieves the single argument of the import call.
+    /// Retrieves the specifier argument of the import call.
     #[must_use]
     pub const fn argument(&self) -> &Expression {
         &self.arg
     }
+
+    /// Retrieves the optional options argument of the import call.
+    #[must_use]
+    pub fn options(&self) -> Option<&Expression> {
+        self.options.as_deref()
+    }
 }
 
+impl_spanned!(ImportCall);
+
 impl ToInternedString for ImportCall {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
-        format!("import({})", self.arg.to_interned_string(interner))
+        if let Some(options) = &self.options {
+            format!(
+                "import({}, {})",
+                self.arg.to_interned_string(interner),
+                options.to_interned_string(interner)
+            )
+        } else {
+            format!("import({})", self.arg.to_interned_string(interner))
+        }
     }
 }
 
@@ -216,13 +281,21 @@ impl VisitWith for ImportCall {
     where
         V: Visitor<'a>,
     {
-        visitor.visit_expression(&self.arg)
+        visitor.visit_expression(&self.arg)?;
+        if let Some(options) = &self.options {
+            visitor.visit_expression(options)?;
+        }
+        ControlFlow::Continue(())
     }
 
     fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
         V: VisitorMut<'a>,
     {
-        visitor.visit_expression_mut(&mut self.arg)
+        visitor.visit_expression_mut(&mut self.arg)?;
+        if let Some(options) = &mut self.options {
+            visitor.visit_expression_mut(options)?;
+        }
+        ControlFlow::Continue(())
     }
 }
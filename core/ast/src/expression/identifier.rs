@@ -1,8 +1,9 @@
 //! Local identifier Expression.
 
 use crate::{
+    position::impl_spanned,
     visitor::{VisitWith, Visitor, VisitorMut},
-    ToStringEscaped,
+    SpanIgnoreEq, ToStringEscaped,
 };
 use boa_interner::{Interner, Sym, ToInternedString};
 use core::ops::ControlFlow;
@@ -45,9 +46,10 @@ pub const RESERVED_IDENTIFIERS_STRICT: [Sym; 9] = [
 )]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[repr(transparent)]
 pub struct Identifier {
     ident: Sym,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    span: SpanIgnoreEq,
 }
 
 impl PartialEq<Sym> for Identifier {
@@ -69,7 +71,10 @@ impl Identifier {
     #[inline]
     #[must_use]
     pub const fn new(ident: Sym) -> Self {
-        Self { ident }
+        Self {
+            ident,
+            span: SpanIgnoreEq(None),
+        }
     }
 
     /// Retrieves the identifier's string symbol in the interner.
@@ -80,6 +85,8 @@ impl Identifier {
     }
 }
 
+impl_spanned!(Identifier);
+
 impl ToInternedString for Identifier {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
@@ -94,7 +101,10 @@ impl ToInternedString for Identifier {
 impl From<Sym> for Identifier {
     #[inline]
     fn from(sym: Sym) -> Self {
-        Self { ident: sym }
+        Self {
+            ident: sym,
+            span: SpanIgnoreEq(None),
+        }
     }
 }
 
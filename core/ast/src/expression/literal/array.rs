@@ -3,7 +3,11 @@
 use crate::expression::operator::assign::{AssignOp, AssignTarget};
 use crate::expression::Expression;
 use crate::pattern::{ArrayPattern, ArrayPatternElement, Pattern};
-use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{
+    position::impl_spanned,
+    visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
+};
 use boa_interner::{Interner, Sym, ToInternedString};
 use core::ops::ControlFlow;
 
@@ -29,6 +33,7 @@ use core::ops::ControlFlow;
 pub struct ArrayLiteral {
     arr: Box<[Option<Expression>]>,
     has_trailing_comma_spread: bool,
+    span: SpanIgnoreEq,
 }
 
 impl ArrayLiteral {
@@ -40,6 +45,7 @@ impl ArrayLiteral {
         Self {
             arr: array.into(),
             has_trailing_comma_spread,
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -160,6 +166,8 @@ impl ArrayLiteral {
     }
 }
 
+impl_spanned!(ArrayLiteral);
+
 impl AsRef<[Option<Expression>]> for ArrayLiteral {
     #[inline]
     fn as_ref(&self) -> &[Option<Expression>] {
@@ -182,6 +190,7 @@ where
         Self {
             arr: decl.into(),
             has_trailing_comma_spread: false,
+            span: SpanIgnoreEq(None),
         }
     }
 }
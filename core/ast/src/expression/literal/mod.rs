@@ -16,7 +16,11 @@ use core::ops::ControlFlow;
 pub use object::{ObjectLiteral, ObjectMethodDefinition, PropertyDefinition};
 pub use template::{TemplateElement, TemplateLiteral};
 
-use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{
+    position::impl_spanned,
+    visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
+};
 use boa_interner::{Interner, Sym, ToInternedString};
 use num_bigint::BigInt;
 
@@ -33,8 +37,45 @@ use super::Expression;
 /// [spec]: https://tc39.es/ecma262/#sec-primary-expression-literals
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Grammar_and_types#Literals
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq)]
-pub enum Literal {
+pub struct Literal {
+    kind: LiteralKind,
+    span: SpanIgnoreEq,
+}
+
+impl Literal {
+    /// Creates a new `Literal` AST Expression.
+    #[inline]
+    #[must_use]
+    pub const fn new(kind: LiteralKind) -> Self {
+        Self {
+            kind,
+            span: SpanIgnoreEq(None),
+        }
+    }
+
+    /// Gets the kind of the literal.
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> &LiteralKind {
+        &self.kind
+    }
+
+    /// Gets a mutable reference to the kind of the literal.
+    #[inline]
+    #[must_use]
+    pub fn kind_mut(&mut self) -> &mut LiteralKind {
+        &mut self.kind
+    }
+}
+
+impl_spanned!(Literal);
+
+/// The value of a [`Literal`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub enum LiteralKind {
     /// A string literal is zero or more characters enclosed in double (`"`) or single (`'`) quotation marks.
     ///
     /// A string must be delimited by quotation marks of the same type (that is, either both single quotation marks, or both double quotation marks).
@@ -119,7 +160,7 @@ pub enum Literal {
 
 /// Manual implementation, because `Undefined` is never constructed during parsing.
 #[cfg(feature = "arbitrary")]
-impl<'a> arbitrary::Arbitrary<'a> for Literal {
+impl<'a> arbitrary::Arbitrary<'a> for LiteralKind {
     fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
         let c = <u8 as arbitrary::Arbitrary<'a>>::arbitrary(u)? % 6;
         match c {
@@ -139,42 +180,49 @@ impl<'a> arbitrary::Arbitrary<'a> for Literal {
 impl From<Sym> for Literal {
     #[inline]
     fn from(string: Sym) -> Self {
-        Self::String(string)
+        Self::new(LiteralKind::String(string))
     }
 }
 
 impl From<f64> for Literal {
     #[inline]
     fn from(num: f64) -> Self {
-        Self::Num(num)
+        Self::new(LiteralKind::Num(num))
     }
 }
 
 impl From<i32> for Literal {
     #[inline]
     fn from(i: i32) -> Self {
-        Self::Int(i)
+        Self::new(LiteralKind::Int(i))
     }
 }
 
 impl From<BigInt> for Literal {
     #[inline]
     fn from(i: BigInt) -> Self {
-        Self::BigInt(Box::new(i))
+        Self::new(LiteralKind::BigInt(Box::new(i)))
     }
 }
 
 impl From<Box<BigInt>> for Literal {
     #[inline]
     fn from(i: Box<BigInt>) -> Self {
-        Self::BigInt(i)
+        Self::new(LiteralKind::BigInt(i))
     }
 }
 
 impl From<bool> for Literal {
     #[inline]
     fn from(b: bool) -> Self {
-        Self::Bool(b)
+        Self::new(LiteralKind::Bool(b))
+    }
+}
+
+impl From<LiteralKind> for Literal {
+    #[inline]
+    fn from(kind: LiteralKind) -> Self {
+        Self::new(kind)
     }
 }
 
@@ -185,19 +233,26 @@ impl From<Literal> for Expression {
     }
 }
 
+impl From<LiteralKind> for Expression {
+    #[inline]
+    fn from(kind: LiteralKind) -> Self {
+        Self::Literal(Literal::new(kind))
+    }
+}
+
 impl ToInternedString for Literal {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
-        match *self {
-            Self::String(st) => {
+        match self.kind {
+            LiteralKind::String(st) => {
                 format!("\"{}\"", interner.resolve_expect(st))
             }
-            Self::Num(num) => num.to_string(),
-            Self::Int(num) => num.to_string(),
-            Self::BigInt(ref num) => format!("{num}n"),
-            Self::Bool(v) => v.to_string(),
-            Self::Null => "null".to_owned(),
-            Self::Undefined => "undefined".to_owned(),
+            LiteralKind::Num(num) => num.to_string(),
+            LiteralKind::Int(num) => num.to_string(),
+            LiteralKind::BigInt(ref num) => format!("{num}n"),
+            LiteralKind::Bool(v) => v.to_string(),
+            LiteralKind::Null => "null".to_owned(),
+            LiteralKind::Undefined => "undefined".to_owned(),
         }
     }
 }
@@ -207,7 +262,7 @@ impl VisitWith for Literal {
     where
         V: Visitor<'a>,
     {
-        if let Self::String(sym) = self {
+        if let LiteralKind::String(sym) = &self.kind {
             visitor.visit_sym(sym)
         } else {
             ControlFlow::Continue(())
@@ -218,7 +273,7 @@ impl VisitWith for Literal {
     where
         V: VisitorMut<'a>,
     {
-        if let Self::String(sym) = self {
+        if let LiteralKind::String(sym) = &mut self.kind {
             visitor.visit_sym_mut(sym)
         } else {
             ControlFlow::Continue(())
@@ -10,10 +10,11 @@ use crate::{
     join_nodes,
     operations::{contains, ContainsSymbol},
     pattern::{ObjectPattern, ObjectPatternElement},
+    position::impl_spanned,
     property::{MethodDefinitionKind, PropertyName},
     scope::FunctionScopes,
     visitor::{VisitWith, Visitor, VisitorMut},
-    LinearPosition, LinearSpan, LinearSpanIgnoreEq,
+    LinearPosition, LinearSpan, LinearSpanIgnoreEq, SpanIgnoreEq,
 };
 use boa_interner::{Interner, Sym, ToIndentedString, ToInternedString};
 use core::{fmt::Write as _, ops::ControlFlow};
@@ -43,6 +44,8 @@ use core::{fmt::Write as _, ops::ControlFlow};
 #[derive(Clone, Debug, PartialEq)]
 pub struct ObjectLiteral {
     properties: Box<[PropertyDefinition]>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    span: SpanIgnoreEq,
 }
 
 impl ObjectLiteral {
@@ -207,6 +210,8 @@ impl ObjectLiteral {
     }
 }
 
+impl_spanned!(ObjectLiteral);
+
 impl ToIndentedString for ObjectLiteral {
     fn to_indented_string(&self, interner: &Interner, indent_n: usize) -> String {
         let mut buf = "{\n".to_owned();
@@ -257,6 +262,7 @@ where
     fn from(props: T) -> Self {
         Self {
             properties: props.into(),
+            span: SpanIgnoreEq(None),
         }
     }
 }
@@ -2,7 +2,9 @@
 
 use crate::{
     expression::Expression,
+    position::impl_spanned,
     visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
 };
 use boa_interner::{Interner, Sym, ToInternedString};
 use core::{fmt::Write as _, ops::ControlFlow};
@@ -19,6 +21,7 @@ use core::{fmt::Write as _, ops::ControlFlow};
 #[derive(Clone, Debug, PartialEq)]
 pub struct TemplateLiteral {
     elements: Box<[TemplateElement]>,
+    span: SpanIgnoreEq,
 }
 
 /// Manual implementation, because string and expression in the element list must always appear in order.
@@ -70,7 +73,10 @@ impl TemplateLiteral {
     #[inline]
     #[must_use]
     pub fn new(elements: Box<[TemplateElement]>) -> Self {
-        Self { elements }
+        Self {
+            elements,
+            span: SpanIgnoreEq(None),
+        }
     }
 
     /// Gets the element list of this `TemplateLiteral`.
@@ -80,6 +86,8 @@ impl TemplateLiteral {
     }
 }
 
+impl_spanned!(TemplateLiteral);
+
 impl ToInternedString for TemplateLiteral {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
@@ -36,7 +36,10 @@ mod spread;
 mod tagged_template;
 mod r#yield;
 
-use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{
+    visitor::{VisitWith, Visitor, VisitorMut},
+    Span, SpanIgnoreEq, Spanned,
+};
 pub use call::{Call, ImportCall, SuperCall};
 pub use identifier::{Identifier, RESERVED_IDENTIFIERS_STRICT};
 pub use new::New;
@@ -71,7 +74,7 @@ pub enum Expression {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-this-keyword
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/this
-    This,
+    This(SpanIgnoreEq),
 
     /// See [`Identifier`].
     Identifier(Identifier),
@@ -137,10 +140,10 @@ pub enum Expression {
     TaggedTemplate(TaggedTemplate),
 
     /// The `new.target` pseudo-property expression.
-    NewTarget,
+    NewTarget(SpanIgnoreEq),
 
     /// The `import.meta` pseudo-property expression.
-    ImportMeta,
+    ImportMeta(SpanIgnoreEq),
 
     /// See [`Assign`].
     Assign(Assign),
@@ -177,7 +180,7 @@ pub enum Expression {
     FormalParameterList(FormalParameterList),
 
     #[doc(hidden)]
-    Debugger,
+    Debugger(SpanIgnoreEq),
 }
 
 impl Expression {
@@ -187,7 +190,7 @@ impl Expression {
     /// indents, use [`to_indented_string()`](Self::to_indented_string).
     pub(crate) fn to_no_indent_string(&self, interner: &Interner, indentation: usize) -> String {
         match self {
-            Self::This => "this".to_owned(),
+            Self::This(_) => "this".to_owned(),
             Self::Identifier(id) => id.to_interned_string(interner),
             Self::Literal(lit) => lit.to_interned_string(interner),
             Self::ArrayLiteral(arr) => arr.to_interned_string(interner),
@@ -209,8 +212,8 @@ impl Expression {
             Self::SuperCall(supc) => supc.to_interned_string(interner),
             Self::ImportCall(impc) => impc.to_interned_string(interner),
             Self::Optional(opt) => opt.to_interned_string(interner),
-            Self::NewTarget => "new.target".to_owned(),
-            Self::ImportMeta => "import.meta".to_owned(),
+            Self::NewTarget(_) => "new.target".to_owned(),
+            Self::ImportMeta(_) => "import.meta".to_owned(),
             Self::TaggedTemplate(tag) => tag.to_interned_string(interner),
             Self::Assign(assign) => assign.to_interned_string(interner),
             Self::Unary(unary) => unary.to_interned_string(interner),
@@ -223,7 +226,7 @@ impl Expression {
             Self::Parenthesized(expr) => expr.to_interned_string(interner),
             Self::RegExpLiteral(regexp) => regexp.to_interned_string(interner),
             Self::FormalParameterList(_) => unreachable!(),
-            Self::Debugger => "debugger".to_owned(),
+            Self::Debugger(_) => "debugger".to_owned(),
         }
     }
 
@@ -279,6 +282,87 @@ impl Expression {
     }
 }
 
+impl Spanned for Expression {
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::This(span)
+            | Self::NewTarget(span)
+            | Self::ImportMeta(span)
+            | Self::Debugger(span) => span.0,
+            Self::Identifier(id) => id.span(),
+            Self::Literal(lit) => lit.span(),
+            Self::RegExpLiteral(regexp) => regexp.span(),
+            Self::ArrayLiteral(arlit) => arlit.span(),
+            Self::ObjectLiteral(olit) => olit.span(),
+            Self::Spread(sp) => sp.span(),
+            Self::FunctionExpression(f) => f.span(),
+            Self::ArrowFunction(af) => af.span(),
+            Self::AsyncArrowFunction(af) => af.span(),
+            Self::GeneratorExpression(g) => g.span(),
+            Self::AsyncFunctionExpression(af) => af.span(),
+            Self::AsyncGeneratorExpression(ag) => ag.span(),
+            Self::ClassExpression(c) => c.span(),
+            Self::TemplateLiteral(tlit) => tlit.span(),
+            Self::PropertyAccess(pa) => pa.span(),
+            Self::New(n) => n.span(),
+            Self::Call(c) => c.span(),
+            Self::SuperCall(sc) => sc.span(),
+            Self::ImportCall(ic) => ic.span(),
+            Self::Optional(opt) => opt.span(),
+            Self::TaggedTemplate(tt) => tt.span(),
+            Self::Assign(a) => a.span(),
+            Self::Unary(u) => u.span(),
+            Self::Update(u) => u.span(),
+            Self::Binary(b) => b.span(),
+            Self::BinaryInPrivate(b) => b.span(),
+            Self::Conditional(c) => c.span(),
+            Self::Await(a) => a.span(),
+            Self::Yield(y) => y.span(),
+            Self::Parenthesized(e) => e.span(),
+            Self::FormalParameterList(fpl) => fpl.span(),
+        }
+    }
+
+    fn set_span(&mut self, span: Span) {
+        match self {
+            Self::This(s) | Self::NewTarget(s) | Self::ImportMeta(s) | Self::Debugger(s) => {
+                *s = span.into();
+            }
+            Self::Identifier(id) => id.set_span(span),
+            Self::Literal(lit) => lit.set_span(span),
+            Self::RegExpLiteral(regexp) => regexp.set_span(span),
+            Self::ArrayLiteral(arlit) => arlit.set_span(span),
+            Self::ObjectLiteral(olit) => olit.set_span(span),
+            Self::Spread(sp) => sp.set_span(span),
+            Self::FunctionExpression(f) => f.set_span(span),
+            Self::ArrowFunction(af) => af.set_span(span),
+            Self::AsyncArrowFunction(af) => af.set_span(span),
+            Self::GeneratorExpression(g) => g.set_span(span),
+            Self::AsyncFunctionExpression(af) => af.set_span(span),
+            Self::AsyncGeneratorExpression(ag) => ag.set_span(span),
+            Self::ClassExpression(c) => c.set_span(span),
+            Self::TemplateLiteral(tlit) => tlit.set_span(span),
+            Self::PropertyAccess(pa) => pa.set_span(span),
+            Self::New(n) => n.set_span(span),
+            Self::Call(c) => c.set_span(span),
+            Self::SuperCall(sc) => sc.set_span(span),
+            Self::ImportCall(ic) => ic.set_span(span),
+            Self::Optional(opt) => opt.set_span(span),
+            Self::TaggedTemplate(tt) => tt.set_span(span),
+            Self::Assign(a) => a.set_span(span),
+            Self::Unary(u) => u.set_span(span),
+            Self::Update(u) => u.set_span(span),
+            Self::Binary(b) => b.set_span(span),
+            Self::BinaryInPrivate(b) => b.set_span(span),
+            Self::Conditional(c) => c.set_span(span),
+            Self::Await(a) => a.set_span(span),
+            Self::Yield(y) => y.set_span(span),
+            Self::Parenthesized(e) => e.set_span(span),
+            Self::FormalParameterList(fpl) => fpl.set_span(span),
+        }
+    }
+}
+
 impl From<Expression> for Statement {
     #[inline]
     fn from(expr: Expression) -> Self {
@@ -330,7 +414,7 @@ impl VisitWith for Expression {
             Self::Yield(y) => visitor.visit_yield(y),
             Self::Parenthesized(e) => visitor.visit_parenthesized(e),
             Self::FormalParameterList(fpl) => visitor.visit_formal_parameter_list(fpl),
-            Self::This | Self::NewTarget | Self::ImportMeta | Self::Debugger => {
+            Self::This(_) | Self::NewTarget(_) | Self::ImportMeta(_) | Self::Debugger(_) => {
                 // do nothing; can be handled as special case by visitor
                 ControlFlow::Continue(())
             }
@@ -373,7 +457,7 @@ impl VisitWith for Expression {
             Self::Yield(y) => visitor.visit_yield_mut(y),
             Self::Parenthesized(e) => visitor.visit_parenthesized_mut(e),
             Self::FormalParameterList(fpl) => visitor.visit_formal_parameter_list_mut(fpl),
-            Self::This | Self::NewTarget | Self::ImportMeta | Self::Debugger => {
+            Self::This(_) | Self::NewTarget(_) | Self::ImportMeta(_) | Self::Debugger(_) => {
                 // do nothing; can be handled as special case by visitor
                 ControlFlow::Continue(())
             }
@@ -1,5 +1,9 @@
 use crate::expression::Call;
-use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{
+    position::impl_spanned,
+    visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
+};
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
@@ -25,6 +29,7 @@ use super::Expression;
 #[derive(Clone, Debug, PartialEq)]
 pub struct New {
     call: Call,
+    span: SpanIgnoreEq,
 }
 
 impl New {
@@ -49,10 +54,15 @@ impl New {
     }
 }
 
+impl_spanned!(New);
+
 impl From<Call> for New {
     #[inline]
     fn from(call: Call) -> Self {
-        Self { call }
+        Self {
+            call,
+            span: SpanIgnoreEq(None),
+        }
     }
 }
 
@@ -22,7 +22,9 @@ use boa_interner::{Interner, Sym, ToInternedString};
 use crate::{
     expression::{access::PropertyAccess, identifier::Identifier, Expression},
     pattern::Pattern,
+    position::impl_spanned,
     visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
 };
 
 /// An assignment operator expression.
@@ -35,6 +37,7 @@ pub struct Assign {
     op: AssignOp,
     lhs: Box<AssignTarget>,
     rhs: Box<Expression>,
+    span: SpanIgnoreEq,
 }
 
 impl Assign {
@@ -46,6 +49,7 @@ impl Assign {
             op,
             lhs: Box::new(lhs),
             rhs: Box::new(rhs),
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -71,6 +75,8 @@ impl Assign {
     }
 }
 
+impl_spanned!(Assign);
+
 impl ToInternedString for Assign {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
@@ -19,7 +19,9 @@ mod op;
 use crate::{
     expression::Expression,
     function::PrivateName,
+    position::impl_spanned,
     visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
 };
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
@@ -36,6 +38,7 @@ pub struct Binary {
     op: BinaryOp,
     lhs: Box<Expression>,
     rhs: Box<Expression>,
+    span: SpanIgnoreEq,
 }
 
 impl Binary {
@@ -47,6 +50,7 @@ impl Binary {
             op,
             lhs: Box::new(lhs),
             rhs: Box::new(rhs),
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -86,6 +90,8 @@ impl Binary {
     }
 }
 
+impl_spanned!(Binary);
+
 impl ToInternedString for Binary {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
@@ -134,6 +140,7 @@ impl VisitWith for Binary {
 pub struct BinaryInPrivate {
     lhs: PrivateName,
     rhs: Box<Expression>,
+    span: SpanIgnoreEq,
 }
 
 impl BinaryInPrivate {
@@ -144,6 +151,7 @@ impl BinaryInPrivate {
         Self {
             lhs,
             rhs: Box::new(rhs),
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -162,6 +170,8 @@ impl BinaryInPrivate {
     }
 }
 
+impl_spanned!(BinaryInPrivate);
+
 impl ToInternedString for BinaryInPrivate {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
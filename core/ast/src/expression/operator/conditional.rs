@@ -1,6 +1,8 @@
 use crate::{
     expression::Expression,
+    position::impl_spanned,
     visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
 };
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
@@ -26,6 +28,7 @@ pub struct Conditional {
     condition: Box<Expression>,
     if_true: Box<Expression>,
     if_false: Box<Expression>,
+    span: SpanIgnoreEq,
 }
 
 impl Conditional {
@@ -58,10 +61,13 @@ impl Conditional {
             condition: Box::new(condition),
             if_true: Box::new(if_true),
             if_false: Box::new(if_false),
+            span: SpanIgnoreEq(None),
         }
     }
 }
 
+impl_spanned!(Conditional);
+
 impl ToInternedString for Conditional {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
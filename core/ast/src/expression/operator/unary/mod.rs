@@ -13,7 +13,9 @@ mod op;
 
 use crate::{
     expression::Expression,
+    position::impl_spanned,
     visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
 };
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
@@ -34,6 +36,7 @@ pub use op::*;
 pub struct Unary {
     op: UnaryOp,
     target: Box<Expression>,
+    span: SpanIgnoreEq,
 }
 
 impl Unary {
@@ -44,6 +47,7 @@ impl Unary {
         Self {
             op,
             target: Box::new(target),
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -69,6 +73,8 @@ impl Unary {
     }
 }
 
+impl_spanned!(Unary);
+
 impl ToInternedString for Unary {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
@@ -11,8 +11,9 @@ mod op;
 
 use crate::{
     expression::{access::PropertyAccess, Identifier},
+    position::impl_spanned,
     visitor::{VisitWith, Visitor, VisitorMut},
-    Expression,
+    Expression, SpanIgnoreEq,
 };
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
@@ -33,6 +34,7 @@ pub use op::*;
 pub struct Update {
     op: UpdateOp,
     target: Box<UpdateTarget>,
+    span: SpanIgnoreEq,
 }
 
 impl Update {
@@ -43,6 +45,7 @@ impl Update {
         Self {
             op,
             target: Box::new(target),
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -61,6 +64,8 @@ impl Update {
     }
 }
 
+impl_spanned!(Update);
+
 impl ToInternedString for Update {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
@@ -2,7 +2,9 @@ use super::{access::PropertyAccessField, Expression};
 use crate::{
     function::PrivateName,
     join_nodes,
+    position::impl_spanned,
     visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
 };
 use boa_interner::{Interner, ToInternedString};
 use core::{fmt::Write as _, ops::ControlFlow};
@@ -182,6 +184,7 @@ impl VisitWith for OptionalOperation {
 pub struct Optional {
     target: Box<Expression>,
     chain: Box<[OptionalOperation]>,
+    span: SpanIgnoreEq,
 }
 
 impl VisitWith for Optional {
@@ -216,6 +219,7 @@ impl Optional {
         Self {
             target: Box::new(target),
             chain,
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -234,6 +238,8 @@ impl Optional {
     }
 }
 
+impl_spanned!(Optional);
+
 impl From<Optional> for Expression {
     fn from(opt: Optional) -> Self {
         Self::Optional(opt)
@@ -1,5 +1,9 @@
 use super::Expression;
-use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{
+    position::impl_spanned,
+    visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
+};
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
@@ -16,6 +20,7 @@ use core::ops::ControlFlow;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Parenthesized {
     pub(crate) expression: Box<Expression>,
+    span: SpanIgnoreEq,
 }
 
 impl Parenthesized {
@@ -25,6 +30,7 @@ impl Parenthesized {
     pub fn new(expression: Expression) -> Self {
         Self {
             expression: Box::new(expression),
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -36,6 +42,8 @@ impl Parenthesized {
     }
 }
 
+impl_spanned!(Parenthesized);
+
 impl From<Parenthesized> for Expression {
     fn from(p: Parenthesized) -> Self {
         Self::Parenthesized(p)
@@ -11,7 +11,11 @@ use std::ops::ControlFlow;
 
 use boa_interner::{Interner, Sym, ToInternedString};
 
-use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{
+    position::impl_spanned,
+    visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
+};
 
 use super::Expression;
 
@@ -29,6 +33,7 @@ use super::Expression;
 pub struct RegExpLiteral {
     pattern: Sym,
     flags: Sym,
+    span: SpanIgnoreEq,
 }
 
 impl RegExpLiteral {
@@ -36,7 +41,11 @@ impl RegExpLiteral {
     #[inline]
     #[must_use]
     pub const fn new(pattern: Sym, flags: Sym) -> Self {
-        Self { pattern, flags }
+        Self {
+            pattern,
+            flags,
+            span: SpanIgnoreEq(None),
+        }
     }
 
     /// Get the pattern part of the [`RegExpLiteral`].
@@ -54,6 +63,8 @@ impl RegExpLiteral {
     }
 }
 
+impl_spanned!(RegExpLiteral);
+
 impl ToInternedString for RegExpLiteral {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
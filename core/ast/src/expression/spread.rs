@@ -1,7 +1,11 @@
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
-use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{
+    position::impl_spanned,
+    visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
+};
 
 use super::Expression;
 
@@ -27,6 +31,8 @@ use super::Expression;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Spread {
     target: Box<Expression>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    span: SpanIgnoreEq,
 }
 
 impl Spread {
@@ -43,10 +49,13 @@ impl Spread {
     pub fn new(target: Expression) -> Self {
         Self {
             target: Box::new(target),
+            span: SpanIgnoreEq(None),
         }
     }
 }
 
+impl_spanned!(Spread);
+
 impl ToInternedString for Spread {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
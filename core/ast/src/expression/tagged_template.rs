@@ -1,5 +1,9 @@
 use super::Expression;
-use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{
+    position::impl_spanned,
+    visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
+};
 use boa_interner::{Interner, Sym, ToInternedString};
 use core::{fmt::Write as _, ops::ControlFlow};
 
@@ -19,6 +23,7 @@ pub struct TaggedTemplate {
     cookeds: Box<[Option<Sym>]>,
     exprs: Box<[Expression]>,
     identifier: u64,
+    span: SpanIgnoreEq,
 }
 
 impl TaggedTemplate {
@@ -39,6 +44,7 @@ impl TaggedTemplate {
             cookeds,
             exprs,
             identifier,
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -78,6 +84,8 @@ impl TaggedTemplate {
     }
 }
 
+impl_spanned!(TaggedTemplate);
+
 impl ToInternedString for TaggedTemplate {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
@@ -1,7 +1,11 @@
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
-use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{
+    position::impl_spanned,
+    visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
+};
 
 use super::Expression;
 
@@ -19,6 +23,7 @@ use super::Expression;
 pub struct Yield {
     target: Option<Box<Expression>>,
     delegate: bool,
+    span: SpanIgnoreEq,
 }
 
 impl Yield {
@@ -42,10 +47,13 @@ impl Yield {
         Self {
             target: expr.map(Box::new),
             delegate,
+            span: SpanIgnoreEq(None),
         }
     }
 }
 
+impl_spanned!(Yield);
+
 impl From<Yield> for Expression {
     #[inline]
     fn from(r#yield: Yield) -> Self {
@@ -5,6 +5,8 @@ use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
     expression::{Expression, Identifier},
     join_nodes,
+    position::impl_spanned,
+    SpanIgnoreEq,
 };
 use crate::{LinearSpan, LinearSpanIgnoreEq};
 use boa_interner::{Interner, ToIndentedString};
@@ -31,6 +33,7 @@ pub struct ArrowFunction {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scopes: FunctionScopes,
     linear_span: LinearSpanIgnoreEq,
+    span: SpanIgnoreEq,
 }
 
 impl ArrowFunction {
@@ -52,6 +55,7 @@ impl ArrowFunction {
             contains_direct_eval,
             scopes: FunctionScopes::default(),
             linear_span: linear_span.into(),
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -104,6 +108,8 @@ impl ArrowFunction {
     }
 }
 
+impl_spanned!(ArrowFunction);
+
 impl ToIndentedString for ArrowFunction {
     fn to_indented_string(&self, interner: &Interner, indentation: usize) -> String {
         let mut buf = format!("({}", join_nodes(interner, self.parameters.as_ref()));
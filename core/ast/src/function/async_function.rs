@@ -6,9 +6,10 @@ use crate::{
     expression::{Expression, Identifier},
     join_nodes,
     operations::{contains, ContainsSymbol},
+    position::impl_spanned,
     scope::{FunctionScopes, Scope},
     visitor::{VisitWith, Visitor, VisitorMut},
-    Declaration, LinearSpan, LinearSpanIgnoreEq,
+    Declaration, LinearSpan, LinearSpanIgnoreEq, SpanIgnoreEq,
 };
 use boa_interner::{Interner, ToIndentedString};
 use core::{fmt::Write as _, ops::ControlFlow};
@@ -33,6 +34,7 @@ pub struct AsyncFunctionDeclaration {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scopes: FunctionScopes,
     linear_span: LinearSpanIgnoreEq,
+    span: SpanIgnoreEq,
 }
 
 impl AsyncFunctionDeclaration {
@@ -54,6 +56,7 @@ impl AsyncFunctionDeclaration {
             contains_direct_eval,
             scopes: FunctionScopes::default(),
             linear_span: linear_span.into(),
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -100,6 +103,8 @@ impl AsyncFunctionDeclaration {
     }
 }
 
+impl_spanned!(AsyncFunctionDeclaration);
+
 impl ToIndentedString for AsyncFunctionDeclaration {
     fn to_indented_string(&self, interner: &Interner, indentation: usize) -> String {
         format!(
@@ -162,6 +167,7 @@ pub struct AsyncFunctionExpression {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scopes: FunctionScopes,
     linear_span: LinearSpanIgnoreEq,
+    span: SpanIgnoreEq,
 }
 
 impl AsyncFunctionExpression {
@@ -186,6 +192,7 @@ impl AsyncFunctionExpression {
             contains_direct_eval,
             scopes: FunctionScopes::default(),
             linear_span: linear_span.into(),
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -246,6 +253,8 @@ impl AsyncFunctionExpression {
     }
 }
 
+impl_spanned!(AsyncFunctionExpression);
+
 impl ToIndentedString for AsyncFunctionExpression {
     fn to_indented_string(&self, interner: &Interner, indentation: usize) -> String {
         let mut buf = "async function".to_owned();
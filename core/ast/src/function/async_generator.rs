@@ -7,7 +7,9 @@ use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
     block_to_string,
     expression::{Expression, Identifier},
-    join_nodes, Declaration,
+    join_nodes,
+    position::impl_spanned,
+    Declaration, SpanIgnoreEq,
 };
 use crate::{LinearSpan, LinearSpanIgnoreEq};
 use boa_interner::{Interner, ToIndentedString};
@@ -33,6 +35,7 @@ pub struct AsyncGeneratorDeclaration {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scopes: FunctionScopes,
     linear_span: LinearSpanIgnoreEq,
+    span: SpanIgnoreEq,
 }
 
 impl AsyncGeneratorDeclaration {
@@ -54,6 +57,7 @@ impl AsyncGeneratorDeclaration {
             contains_direct_eval,
             scopes: FunctionScopes::default(),
             linear_span: linear_span.into(),
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -100,6 +104,8 @@ impl AsyncGeneratorDeclaration {
     }
 }
 
+impl_spanned!(AsyncGeneratorDeclaration);
+
 impl ToIndentedString for AsyncGeneratorDeclaration {
     fn to_indented_string(&self, interner: &Interner, indentation: usize) -> String {
         format!(
@@ -162,6 +168,7 @@ pub struct AsyncGeneratorExpression {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scopes: FunctionScopes,
     linear_span: LinearSpanIgnoreEq,
+    span: SpanIgnoreEq,
 }
 
 impl AsyncGeneratorExpression {
@@ -186,6 +193,7 @@ impl AsyncGeneratorExpression {
             contains_direct_eval,
             scopes: FunctionScopes::default(),
             linear_span: linear_span.into(),
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -246,6 +254,8 @@ impl AsyncGeneratorExpression {
     }
 }
 
+impl_spanned!(AsyncGeneratorExpression);
+
 impl ToIndentedString for AsyncGeneratorExpression {
     fn to_indented_string(&self, interner: &Interner, indentation: usize) -> String {
         let mut buf = "async function*".to_owned();
@@ -4,10 +4,11 @@ use crate::{
     expression::{Expression, Identifier},
     join_nodes,
     operations::{contains, ContainsSymbol},
+    position::impl_spanned,
     property::{MethodDefinitionKind, PropertyName},
     scope::{FunctionScopes, Scope},
     visitor::{VisitWith, Visitor, VisitorMut},
-    Declaration, LinearPosition, LinearSpan, LinearSpanIgnoreEq,
+    Declaration, LinearPosition, LinearSpan, LinearSpanIgnoreEq, SpanIgnoreEq,
 };
 use boa_interner::{Interner, Sym, ToIndentedString, ToInternedString};
 use core::{fmt::Write as _, ops::ControlFlow};
@@ -29,9 +30,11 @@ pub struct ClassDeclaration {
     pub(crate) super_ref: Option<Expression>,
     pub(crate) constructor: Option<FunctionExpression>,
     pub(crate) elements: Box<[ClassElement]>,
+    pub(crate) decorators: Box<[Decorator]>,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) name_scope: Scope,
+    span: SpanIgnoreEq,
 }
 
 impl ClassDeclaration {
@@ -49,10 +52,20 @@ impl ClassDeclaration {
             super_ref,
             constructor,
             elements,
+            decorators: Box::default(),
             name_scope: Scope::default(),
+            span: SpanIgnoreEq(None),
         }
     }
 
+    /// Sets the decorators applied to the class declaration.
+    #[inline]
+    #[must_use]
+    pub fn with_decorators(mut self, decorators: Box<[Decorator]>) -> Self {
+        self.decorators = decorators;
+        self
+    }
+
     /// Returns the name of the class declaration.
     #[inline]
     #[must_use]
@@ -81,6 +94,13 @@ impl ClassDeclaration {
         &self.elements
     }
 
+    /// Gets the decorators applied to the class declaration.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Gets the scope containing the class name binding.
     #[inline]
     #[must_use]
@@ -89,9 +109,12 @@ impl ClassDeclaration {
     }
 }
 
+impl_spanned!(ClassDeclaration);
+
 impl ToIndentedString for ClassDeclaration {
     fn to_indented_string(&self, interner: &Interner, indent_n: usize) -> String {
-        let mut buf = format!("class {}", interner.resolve_expect(self.name.sym()));
+        let mut buf = decorators_to_string(&self.decorators, interner);
+        let _ = write!(buf, "class {}", interner.resolve_expect(self.name.sym()));
         if let Some(super_ref) = self.super_ref.as_ref() {
             let _ = write!(buf, " extends {}", super_ref.to_interned_string(interner));
         }
@@ -122,6 +145,9 @@ impl VisitWith for ClassDeclaration {
     where
         V: Visitor<'a>,
     {
+        for decorator in &*self.decorators {
+            visitor.visit_decorator(decorator)?;
+        }
         visitor.visit_identifier(&self.name)?;
         if let Some(expr) = &self.super_ref {
             visitor.visit_expression(expr)?;
@@ -139,6 +165,9 @@ impl VisitWith for ClassDeclaration {
     where
         V: VisitorMut<'a>,
     {
+        for decorator in &mut *self.decorators {
+            visitor.visit_decorator_mut(decorator)?;
+        }
         visitor.visit_identifier_mut(&mut self.name)?;
         if let Some(expr) = &mut self.super_ref {
             visitor.visit_expression_mut(expr)?;
@@ -175,9 +204,11 @@ pub struct ClassExpression {
     pub(crate) super_ref: Option<Expression>,
     pub(crate) constructor: Option<FunctionExpression>,
     pub(crate) elements: Box<[ClassElement]>,
+    pub(crate) decorators: Box<[Decorator]>,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) name_scope: Option<Scope>,
+    span: SpanIgnoreEq,
 }
 
 impl ClassExpression {
@@ -201,10 +232,20 @@ impl ClassExpression {
             super_ref,
             constructor,
             elements,
+            decorators: Box::default(),
             name_scope,
+            span: SpanIgnoreEq(None),
         }
     }
 
+    /// Sets the decorators applied to the class expression.
+    #[inline]
+    #[must_use]
+    pub fn with_decorators(mut self, decorators: Box<[Decorator]>) -> Self {
+        self.decorators = decorators;
+        self
+    }
+
     /// Returns the name of the class expression.
     #[inline]
     #[must_use]
@@ -233,6 +274,13 @@ impl ClassExpression {
         &self.elements
     }
 
+    /// Gets the decorators applied to the class expression.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Gets the scope containing the class name binding if it exists.
     #[inline]
     #[must_use]
@@ -241,9 +289,12 @@ impl ClassExpression {
     }
 }
 
+impl_spanned!(ClassExpression);
+
 impl ToIndentedString for ClassExpression {
     fn to_indented_string(&self, interner: &Interner, indent_n: usize) -> String {
-        let mut buf = "class".to_string();
+        let mut buf = decorators_to_string(&self.decorators, interner);
+        buf.push_str("class");
         if self.name_scope.is_some() {
             if let Some(name) = self.name {
                 let _ = write!(buf, " {}", interner.resolve_expect(name.sym()));
@@ -285,6 +336,9 @@ impl VisitWith for ClassExpression {
     where
         V: Visitor<'a>,
     {
+        for decorator in &*self.decorators {
+            visitor.visit_decorator(decorator)?;
+        }
         if let Some(ident) = &self.name {
             visitor.visit_identifier(ident)?;
         }
@@ -304,6 +358,9 @@ impl VisitWith for ClassExpression {
     where
         V: VisitorMut<'a>,
     {
+        for decorator in &mut *self.decorators {
+            visitor.visit_decorator_mut(decorator)?;
+        }
         if let Some(ident) = &mut self.name {
             visitor.visit_identifier_mut(ident)?;
         }
@@ -391,6 +448,37 @@ pub enum ClassElement {
     StaticBlock(StaticBlockBody),
 }
 
+impl ClassElement {
+    /// Gets the decorators applied to the class element.
+    ///
+    /// Static blocks cannot be decorated, so they always return an empty list.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        match self {
+            Self::MethodDefinition(m) => &m.decorators,
+            Self::FieldDefinition(field) | Self::StaticFieldDefinition(field) => &field.decorators,
+            Self::PrivateFieldDefinition(field) | Self::PrivateStaticFieldDefinition(field) => {
+                &field.decorators
+            }
+            Self::StaticBlock(_) => &[],
+        }
+    }
+
+    /// Returns `true` if the class element is an `accessor` field.
+    #[inline]
+    #[must_use]
+    pub const fn is_accessor(&self) -> bool {
+        match self {
+            Self::FieldDefinition(field) | Self::StaticFieldDefinition(field) => field.is_accessor,
+            Self::PrivateFieldDefinition(field) | Self::PrivateStaticFieldDefinition(field) => {
+                field.is_accessor
+            }
+            Self::MethodDefinition(_) | Self::StaticBlock(_) => false,
+        }
+    }
+}
+
 /// A non-private class element field definition.
 ///
 /// More information:
@@ -403,6 +491,8 @@ pub enum ClassElement {
 pub struct ClassFieldDefinition {
     pub(crate) name: PropertyName,
     pub(crate) initializer: Option<Expression>,
+    pub(crate) decorators: Box<[Decorator]>,
+    is_accessor: bool,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Scope,
@@ -416,10 +506,30 @@ impl ClassFieldDefinition {
         Self {
             name,
             initializer,
+            decorators: Box::default(),
+            is_accessor: false,
             scope: Scope::default(),
         }
     }
 
+    /// Creates a new class field definition declared with the `accessor` keyword.
+    #[inline]
+    #[must_use]
+    pub fn new_accessor(name: PropertyName, initializer: Option<Expression>) -> Self {
+        Self {
+            is_accessor: true,
+            ..Self::new(name, initializer)
+        }
+    }
+
+    /// Sets the decorators applied to the class field definition.
+    #[inline]
+    #[must_use]
+    pub fn with_decorators(mut self, decorators: Box<[Decorator]>) -> Self {
+        self.decorators = decorators;
+        self
+    }
+
     /// Returns the name of the class field definition.
     #[inline]
     #[must_use]
@@ -434,6 +544,20 @@ impl ClassFieldDefinition {
         self.initializer.as_ref()
     }
 
+    /// Returns the decorators applied to the class field definition.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
+    /// Returns `true` if the class field definition is an auto-accessor declared with the `accessor` keyword.
+    #[inline]
+    #[must_use]
+    pub const fn is_accessor(&self) -> bool {
+        self.is_accessor
+    }
+
     /// Returns the scope of the class field definition.
     #[inline]
     #[must_use]
@@ -454,6 +578,8 @@ impl ClassFieldDefinition {
 pub struct PrivateFieldDefinition {
     pub(crate) name: PrivateName,
     pub(crate) initializer: Option<Expression>,
+    pub(crate) decorators: Box<[Decorator]>,
+    is_accessor: bool,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Scope,
@@ -467,10 +593,30 @@ impl PrivateFieldDefinition {
         Self {
             name,
             initializer,
+            decorators: Box::default(),
+            is_accessor: false,
             scope: Scope::default(),
         }
     }
 
+    /// Creates a new private field definition declared with the `accessor` keyword.
+    #[inline]
+    #[must_use]
+    pub fn new_accessor(name: PrivateName, initializer: Option<Expression>) -> Self {
+        Self {
+            is_accessor: true,
+            ..Self::new(name, initializer)
+        }
+    }
+
+    /// Sets the decorators applied to the private field definition.
+    #[inline]
+    #[must_use]
+    pub fn with_decorators(mut self, decorators: Box<[Decorator]>) -> Self {
+        self.decorators = decorators;
+        self
+    }
+
     /// Returns the name of the private field definition.
     #[inline]
     #[must_use]
@@ -485,6 +631,20 @@ impl PrivateFieldDefinition {
         self.initializer.as_ref()
     }
 
+    /// Returns the decorators applied to the private field definition.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
+    /// Returns `true` if the private field definition is an auto-accessor declared with the `accessor` keyword.
+    #[inline]
+    #[must_use]
+    pub const fn is_accessor(&self) -> bool {
+        self.is_accessor
+    }
+
     /// Returns the scope of the private field definition.
     #[inline]
     #[must_use]
@@ -496,19 +656,29 @@ impl PrivateFieldDefinition {
 impl ToIndentedString for ClassElement {
     fn to_indented_string(&self, interner: &Interner, indent_n: usize) -> String {
         let indentation = "    ".repeat(indent_n + 1);
-        match self {
+        let mut buf = String::new();
+        for decorator in self.decorators() {
+            let _ = writeln!(
+                buf,
+                "{indentation}{}",
+                decorator.to_interned_string(interner)
+            );
+        }
+        let element = match self {
             Self::MethodDefinition(m) => m.to_indented_string(interner, indent_n),
             Self::FieldDefinition(field) => match &field.initializer {
                 Some(expr) => {
                     format!(
-                        "{indentation}{} = {};\n",
+                        "{indentation}{}{} = {};\n",
+                        accessor_prefix(field.is_accessor),
                         field.name.to_interned_string(interner),
                         expr.to_no_indent_string(interner, indent_n + 1)
                     )
                 }
                 None => {
                     format!(
-                        "{indentation}{};\n",
+                        "{indentation}{}{};\n",
+                        accessor_prefix(field.is_accessor),
                         field.name.to_interned_string(interner),
                     )
                 }
@@ -516,31 +686,38 @@ impl ToIndentedString for ClassElement {
             Self::StaticFieldDefinition(field) => match &field.initializer {
                 Some(expr) => {
                     format!(
-                        "{indentation}static {} = {};\n",
+                        "{indentation}static {}{} = {};\n",
+                        accessor_prefix(field.is_accessor),
                         field.name.to_interned_string(interner),
                         expr.to_no_indent_string(interner, indent_n + 1)
                     )
                 }
                 None => {
                     format!(
-                        "{indentation}static {};\n",
+                        "{indentation}static {}{};\n",
+                        accessor_prefix(field.is_accessor),
                         field.name.to_interned_string(interner),
                     )
                 }
             },
             Self::PrivateFieldDefinition(PrivateFieldDefinition {
-                name, initializer, ..
+                name,
+                initializer,
+                is_accessor,
+                ..
             }) => match initializer {
                 Some(expr) => {
                     format!(
-                        "{indentation}#{} = {};\n",
+                        "{indentation}{}#{} = {};\n",
+                        accessor_prefix(*is_accessor),
                         interner.resolve_expect(name.description()),
                         expr.to_no_indent_string(interner, indent_n + 1)
                     )
                 }
                 None => {
                     format!(
-                        "{indentation}#{};\n",
+                        "{indentation}{}#{};\n",
+                        accessor_prefix(*is_accessor),
                         interner.resolve_expect(name.description()),
                     )
                 }
@@ -548,18 +725,21 @@ impl ToIndentedString for ClassElement {
             Self::PrivateStaticFieldDefinition(PrivateFieldDefinition {
                 name,
                 initializer,
+                is_accessor,
                 ..
             }) => match initializer {
                 Some(expr) => {
                     format!(
-                        "{indentation}static #{} = {};\n",
+                        "{indentation}static {}#{} = {};\n",
+                        accessor_prefix(*is_accessor),
                         interner.resolve_expect(name.description()),
                         expr.to_no_indent_string(interner, indent_n + 1)
                     )
                 }
                 None => {
                     format!(
-                        "{indentation}static #{};\n",
+                        "{indentation}static {}#{};\n",
+                        accessor_prefix(*is_accessor),
                         interner.resolve_expect(name.description()),
                     )
                 }
@@ -570,7 +750,18 @@ impl ToIndentedString for ClassElement {
                     block_to_string(&block.body.statements, interner, indent_n + 1)
                 )
             }
-        }
+        };
+        buf.push_str(&element);
+        buf
+    }
+}
+
+/// Returns the `accessor` keyword prefix for auto-accessor fields.
+const fn accessor_prefix(is_accessor: bool) -> &'static str {
+    if is_accessor {
+        "accessor "
+    } else {
+        ""
     }
 }
 
@@ -579,6 +770,9 @@ impl VisitWith for ClassElement {
     where
         V: Visitor<'a>,
     {
+        for decorator in self.decorators() {
+            visitor.visit_decorator(decorator)?;
+        }
         match self {
             Self::MethodDefinition(m) => {
                 match &m.name {
@@ -625,6 +819,9 @@ impl VisitWith for ClassElement {
     {
         match self {
             Self::MethodDefinition(m) => {
+                for decorator in &mut *m.decorators {
+                    visitor.visit_decorator_mut(decorator)?;
+                }
                 match m.name {
                     ClassElementName::PropertyName(ref mut pn) => {
                         visitor.visit_property_name_mut(pn)?;
@@ -637,6 +834,9 @@ impl VisitWith for ClassElement {
                 visitor.visit_function_body_mut(&mut m.body)
             }
             Self::FieldDefinition(field) | Self::StaticFieldDefinition(field) => {
+                for decorator in &mut *field.decorators {
+                    visitor.visit_decorator_mut(decorator)?;
+                }
                 visitor.visit_property_name_mut(&mut field.name)?;
                 if let Some(expr) = &mut field.initializer {
                     visitor.visit_expression_mut(expr)
@@ -645,13 +845,20 @@ impl VisitWith for ClassElement {
                 }
             }
             Self::PrivateFieldDefinition(PrivateFieldDefinition {
-                name, initializer, ..
+                name,
+                initializer,
+                decorators,
+                ..
             })
             | Self::PrivateStaticFieldDefinition(PrivateFieldDefinition {
                 name,
                 initializer,
+                decorators,
                 ..
             }) => {
+                for decorator in &mut **decorators {
+                    visitor.visit_decorator_mut(decorator)?;
+                }
                 visitor.visit_private_name_mut(name)?;
                 if let Some(expr) = initializer {
                     visitor.visit_expression_mut(expr)
@@ -683,6 +890,7 @@ pub struct ClassMethodDefinition {
     pub(crate) contains_direct_eval: bool,
     kind: MethodDefinitionKind,
     is_static: bool,
+    pub(crate) decorators: Box<[Decorator]>,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scopes: FunctionScopes,
@@ -713,11 +921,20 @@ impl ClassMethodDefinition {
             contains_direct_eval,
             kind,
             is_static,
+            decorators: Box::default(),
             scopes: FunctionScopes::default(),
             linear_span: linear_span.into(),
         }
     }
 
+    /// Sets the decorators applied to the class method definition.
+    #[inline]
+    #[must_use]
+    pub fn with_decorators(mut self, decorators: Box<[Decorator]>) -> Self {
+        self.decorators = decorators;
+        self
+    }
+
     /// Returns the name of the class method definition.
     #[inline]
     #[must_use]
@@ -760,6 +977,13 @@ impl ClassMethodDefinition {
         self.name.is_private()
     }
 
+    /// Returns the decorators applied to the class method definition.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Gets the scopes of the class method definition.
     #[inline]
     #[must_use]
@@ -883,3 +1107,63 @@ impl VisitWith for PrivateName {
         visitor.visit_sym_mut(&mut self.description)
     }
 }
+
+/// A decorator applied to a class or to one of its elements.
+///
+/// More information:
+///  - [Decorators proposal][spec]
+///
+/// [spec]: https://arai-a.github.io/ecma262-compare/?pr=2417
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Decorator {
+    expression: Expression,
+}
+
+impl Decorator {
+    /// Creates a new decorator.
+    #[inline]
+    #[must_use]
+    pub const fn new(expression: Expression) -> Self {
+        Self { expression }
+    }
+
+    /// Gets the expression that evaluates to the decorator function.
+    #[inline]
+    #[must_use]
+    pub const fn expression(&self) -> &Expression {
+        &self.expression
+    }
+}
+
+impl ToInternedString for Decorator {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        format!("@{}", self.expression.to_interned_string(interner))
+    }
+}
+
+impl VisitWith for Decorator {
+    fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: Visitor<'a>,
+    {
+        visitor.visit_expression(&self.expression)
+    }
+
+    fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: VisitorMut<'a>,
+    {
+        visitor.visit_expression_mut(&mut self.expression)
+    }
+}
+
+/// Formats the decorators of a class, each one followed by a space.
+fn decorators_to_string(decorators: &[Decorator], interner: &Interner) -> String {
+    let mut buf = String::new();
+    for decorator in decorators {
+        let _ = write!(buf, "{} ", decorator.to_interned_string(interner));
+    }
+    buf
+}
@@ -4,9 +4,10 @@ use crate::{
     expression::{Expression, Identifier},
     join_nodes,
     operations::{contains, ContainsSymbol},
+    position::impl_spanned,
     scope::{FunctionScopes, Scope},
     visitor::{VisitWith, Visitor, VisitorMut},
-    Declaration, LinearSpan, LinearSpanIgnoreEq,
+    Declaration, LinearSpan, LinearSpanIgnoreEq, SpanIgnoreEq,
 };
 use boa_interner::{Interner, ToIndentedString};
 use core::{fmt::Write as _, ops::ControlFlow};
@@ -31,6 +32,7 @@ pub struct GeneratorDeclaration {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scopes: FunctionScopes,
     linear_span: LinearSpanIgnoreEq,
+    span: SpanIgnoreEq,
 }
 
 impl GeneratorDeclaration {
@@ -52,6 +54,7 @@ impl GeneratorDeclaration {
             contains_direct_eval,
             scopes: FunctionScopes::default(),
             linear_span: linear_span.into(),
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -98,6 +101,8 @@ impl GeneratorDeclaration {
     }
 }
 
+impl_spanned!(GeneratorDeclaration);
+
 impl ToIndentedString for GeneratorDeclaration {
     fn to_indented_string(&self, interner: &Interner, indentation: usize) -> String {
         format!(
@@ -160,6 +165,7 @@ pub struct GeneratorExpression {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scopes: FunctionScopes,
     linear_span: LinearSpanIgnoreEq,
+    span: SpanIgnoreEq,
 }
 
 impl GeneratorExpression {
@@ -184,6 +190,7 @@ impl GeneratorExpression {
             contains_direct_eval,
             scopes: FunctionScopes::default(),
             linear_span: linear_span.into(),
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -244,6 +251,8 @@ impl GeneratorExpression {
     }
 }
 
+impl_spanned!(GeneratorExpression);
+
 impl ToIndentedString for GeneratorExpression {
     fn to_indented_string(&self, interner: &Interner, indentation: usize) -> String {
         let mut buf = "function*".to_owned();
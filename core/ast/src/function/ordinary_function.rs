@@ -4,10 +4,11 @@ use crate::{
     expression::{Expression, Identifier},
     join_nodes,
     operations::{contains, ContainsSymbol},
+    position::impl_spanned,
     scope::{FunctionScopes, Scope},
     scope_analyzer::{analyze_binding_escapes, collect_bindings},
     visitor::{VisitWith, Visitor, VisitorMut},
-    Declaration, LinearSpan, LinearSpanIgnoreEq,
+    Declaration, LinearSpan, LinearSpanIgnoreEq, SpanIgnoreEq,
 };
 use boa_interner::{Interner, ToIndentedString};
 use core::{fmt::Write as _, ops::ControlFlow};
@@ -32,6 +33,7 @@ pub struct FunctionDeclaration {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scopes: FunctionScopes,
     linear_span: LinearSpanIgnoreEq,
+    span: SpanIgnoreEq,
 }
 
 impl FunctionDeclaration {
@@ -53,6 +55,7 @@ impl FunctionDeclaration {
             contains_direct_eval,
             scopes: FunctionScopes::default(),
             linear_span: linear_span.into(),
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -99,6 +102,8 @@ impl FunctionDeclaration {
     }
 }
 
+impl_spanned!(FunctionDeclaration);
+
 impl ToIndentedString for FunctionDeclaration {
     fn to_indented_string(&self, interner: &Interner, indentation: usize) -> String {
         format!(
@@ -161,11 +166,12 @@ pub struct FunctionExpression {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scopes: FunctionScopes,
     linear_span: Option<LinearSpan>,
+    span: SpanIgnoreEq,
 }
 
 impl PartialEq for FunctionExpression {
     fn eq(&self, other: &Self) -> bool {
-        // all fields except for `linear_span`
+        // all fields except for `linear_span` and `span`
         self.name == other.name
             && self.parameters == other.parameters
             && self.body == other.body
@@ -199,6 +205,7 @@ impl FunctionExpression {
             scopes: FunctionScopes::default(),
             #[allow(clippy::redundant_closure_for_method_calls)]
             linear_span,
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -267,6 +274,8 @@ impl FunctionExpression {
     }
 }
 
+impl_spanned!(FunctionExpression);
+
 impl ToIndentedString for FunctionExpression {
     fn to_indented_string(&self, interner: &Interner, indentation: usize) -> String {
         let mut buf = "function".to_owned();
@@ -2,7 +2,9 @@ use crate::{
     declaration::{Binding, Variable},
     expression::Expression,
     operations::bound_names,
+    position::impl_spanned,
     visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
 };
 use bitflags::bitflags;
 use boa_interner::{Interner, Sym, ToInternedString};
@@ -18,6 +20,7 @@ pub struct FormalParameterList {
     parameters: Box<[FormalParameter]>,
     flags: FormalParameterListFlags,
     length: u32,
+    span: SpanIgnoreEq,
 }
 
 impl FormalParameterList {
@@ -28,6 +31,7 @@ impl FormalParameterList {
             parameters: Box::new([]),
             flags: FormalParameterListFlags::default(),
             length: 0,
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -74,6 +78,7 @@ impl FormalParameterList {
             parameters: parameters.into(),
             flags,
             length,
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -124,6 +129,8 @@ impl FormalParameterList {
     }
 }
 
+impl_spanned!(FormalParameterList);
+
 impl From<Vec<FormalParameter>> for FormalParameterList {
     fn from(parameters: Vec<FormalParameter>) -> Self {
         Self::from_parameters(parameters)
@@ -31,7 +31,11 @@ mod source;
 mod source_text;
 mod statement_list;
 
+pub mod codegen;
+pub mod comment;
 pub mod declaration;
+#[cfg(feature = "estree")]
+pub mod estree;
 pub mod expression;
 pub mod function;
 pub mod keyword;
@@ -52,7 +56,10 @@ pub use self::{
     expression::Expression,
     keyword::Keyword,
     module_item_list::{ModuleItem, ModuleItemList},
-    position::{LinearPosition, LinearSpan, LinearSpanIgnoreEq, Position, PositionGroup, Span},
+    position::{
+        LinearPosition, LinearSpan, LinearSpanIgnoreEq, Position, PositionGroup, PositionIgnoreEq,
+        Span, SpanIgnoreEq, Spanned,
+    },
     punctuator::Punctuator,
     source::{Module, Script},
     source_text::SourceText,
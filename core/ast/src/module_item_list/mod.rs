@@ -8,7 +8,7 @@
 use crate::{
     declaration::{
         ExportDeclaration, ExportEntry, ExportSpecifier, ImportDeclaration, ImportEntry,
-        ImportKind, ImportName, IndirectExportEntry, LocalExportEntry, ModuleSpecifier,
+        ImportKind, ImportName, IndirectExportEntry, LocalExportEntry, ModuleRequest,
         ReExportImportName, ReExportKind,
     },
     expression::Identifier,
@@ -202,9 +202,11 @@ impl ModuleItemList {
     /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-modulerequests
     #[inline]
     #[must_use]
-    pub fn requests(&self) -> IndexSet<Sym, BuildHasherDefault<FxHasher>> {
+    pub fn requests(&self) -> IndexSet<ModuleRequest, BuildHasherDefault<FxHasher>> {
         #[derive(Debug)]
-        struct RequestsVisitor<'vec>(&'vec mut IndexSet<Sym, BuildHasherDefault<FxHasher>>);
+        struct RequestsVisitor<'vec>(
+            &'vec mut IndexSet<ModuleRequest, BuildHasherDefault<FxHasher>>,
+        );
 
         impl<'ast> Visitor<'ast> for RequestsVisitor<'_> {
             type BreakTy = Infallible;
@@ -215,11 +217,29 @@ impl ModuleItemList {
             ) -> ControlFlow<Self::BreakTy> {
                 ControlFlow::Continue(())
             }
-            fn visit_module_specifier(
+            fn visit_import_declaration(
+                &mut self,
+                node: &'ast ImportDeclaration,
+            ) -> ControlFlow<Self::BreakTy> {
+                self.0.insert(ModuleRequest::new(
+                    node.specifier().sym(),
+                    node.attributes(),
+                ));
+                ControlFlow::Continue(())
+            }
+            fn visit_export_declaration(
                 &mut self,
-                node: &'ast ModuleSpecifier,
+                node: &'ast ExportDeclaration,
             ) -> ControlFlow<Self::BreakTy> {
-                self.0.insert(node.sym());
+                if let ExportDeclaration::ReExport {
+                    specifier,
+                    attributes,
+                    ..
+                } = node
+                {
+                    self.0
+                        .insert(ModuleRequest::new(specifier.sym(), attributes));
+                }
                 ControlFlow::Continue(())
             }
         }
@@ -258,11 +278,11 @@ impl ModuleItemList {
                 &mut self,
                 node: &'ast ImportDeclaration,
             ) -> ControlFlow<Self::BreakTy> {
-                let module = node.specifier().sym();
+                let module = ModuleRequest::new(node.specifier().sym(), node.attributes());
 
                 if let Some(default) = node.default() {
                     self.0.push(ImportEntry::new(
-                        module,
+                        module.clone(),
                         ImportName::Name(Sym::DEFAULT),
                         default,
                     ));
@@ -277,7 +297,7 @@ impl ModuleItemList {
                     ImportKind::Named { names } => {
                         for name in &**names {
                             self.0.push(ImportEntry::new(
-                                module,
+                                module.clone(),
                                 ImportName::Name(name.export_name()),
                                 name.binding(),
                             ));
@@ -324,8 +344,12 @@ impl ModuleItemList {
                 node: &'ast ExportDeclaration,
             ) -> ControlFlow<Self::BreakTy> {
                 let name = match node {
-                    ExportDeclaration::ReExport { kind, specifier } => {
-                        let module = specifier.sym();
+                    ExportDeclaration::ReExport {
+                        kind,
+                        specifier,
+                        attributes,
+                    } => {
+                        let module = ModuleRequest::new(specifier.sym(), attributes);
 
                         match kind {
                             ReExportKind::Namespaced { name } => {
@@ -348,7 +372,7 @@ impl ModuleItemList {
                                 for name in &**names {
                                     self.0.push(
                                         IndirectExportEntry::new(
-                                            module,
+                                            module.clone(),
                                             ReExportImportName::Name(name.private_name()),
                                             name.alias(),
                                         )
@@ -202,6 +202,9 @@ where
 
         // `ComputedPropertyContains`: https://tc39.es/ecma262/#sec-static-semantics-computedpropertycontains
         fn visit_class_element(&mut self, node: &'ast ClassElement) -> ControlFlow<Self::BreakTy> {
+            for decorator in node.decorators() {
+                self.visit_decorator(decorator)?;
+            }
             match node {
                 ClassElement::MethodDefinition(m) => {
                     if self.0 == ContainsSymbol::DirectEval {
@@ -311,11 +314,35 @@ where
             node.visit_with(self)
         }
 
+        fn visit_lexical_declaration(
+            &mut self,
+            node: &'ast LexicalDeclaration,
+        ) -> ControlFlow<Self::BreakTy> {
+            if self.0 == ContainsSymbol::AwaitExpression && node.is_await_using() {
+                return ControlFlow::Break(());
+            }
+
+            node.visit_with(self)
+        }
+
+        fn visit_iterable_loop_initializer(
+            &mut self,
+            node: &'ast IterableLoopInitializer,
+        ) -> ControlFlow<Self::BreakTy> {
+            if self.0 == ContainsSymbol::AwaitExpression
+                && matches!(node, IterableLoopInitializer::AwaitUsing(_))
+            {
+                return ControlFlow::Break(());
+            }
+
+            node.visit_with(self)
+        }
+
         fn visit_expression(&mut self, node: &'ast Expression) -> ControlFlow<Self::BreakTy> {
-            if node == &Expression::This && self.0 == ContainsSymbol::This {
+            if matches!(node, Expression::This(_)) && self.0 == ContainsSymbol::This {
                 return ControlFlow::Break(());
             }
-            if node == &Expression::NewTarget && self.0 == ContainsSymbol::NewTarget {
+            if matches!(node, Expression::NewTarget(_)) && self.0 == ContainsSymbol::NewTarget {
                 return ControlFlow::Break(());
             }
             node.visit_with(self)
@@ -408,6 +435,9 @@ where
 
         fn visit_class_element(&mut self, node: &'ast ClassElement) -> ControlFlow<Self::BreakTy> {
             if let ClassElement::MethodDefinition(m) = node {
+                for decorator in m.decorators() {
+                    self.visit_decorator(decorator)?;
+                }
                 if let ClassElementName::PropertyName(name) = m.name() {
                     return name.visit_with(self);
                 }
@@ -857,7 +887,8 @@ impl<'ast> Visitor<'ast> for VarDeclaredNamesVisitor<'_> {
 
     fn visit_statement(&mut self, node: &'ast Statement) -> ControlFlow<Self::BreakTy> {
         match node {
-            Statement::Empty
+            Statement::Empty(_)
+            | Statement::Error(_)
             | Statement::Expression(_)
             | Statement::Continue(_)
             | Statement::Break(_)
@@ -1171,6 +1202,9 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
         &mut self,
         node: &'ast ClassExpression,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in node.decorators() {
+            self.visit_decorator(decorator)?;
+        }
         if let Some(node) = node.super_ref() {
             self.visit(node)?;
         }
@@ -1200,6 +1234,9 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
         }
 
         for element in node.elements() {
+            for decorator in element.decorators() {
+                visitor.visit_decorator(decorator)?;
+            }
             match element {
                 ClassElement::MethodDefinition(m) => {
                     if let ClassElementName::PropertyName(name) = m.name() {
@@ -1240,6 +1277,9 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
         &mut self,
         node: &'ast ClassDeclaration,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in node.decorators() {
+            self.visit_decorator(decorator)?;
+        }
         if let Some(node) = node.super_ref() {
             self.visit(node)?;
         }
@@ -1269,6 +1309,9 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
         }
 
         for element in node.elements() {
+            for decorator in element.decorators() {
+                visitor.visit_decorator(decorator)?;
+            }
             match element {
                 ClassElement::MethodDefinition(m) => {
                     if let ClassElementName::PropertyName(name) = m.name() {
@@ -1433,7 +1476,8 @@ where
             match node {
                 Statement::Block(node) => self.visit_block(node),
                 Statement::Var(_)
-                | Statement::Empty
+                | Statement::Empty(_)
+                | Statement::Error(_)
                 | Statement::Expression(_)
                 | Statement::Return(_)
                 | Statement::Throw(_) => ControlFlow::Continue(()),
@@ -2072,7 +2116,8 @@ impl<'ast> Visitor<'ast> for VarScopedDeclarationsVisitor<'_> {
             Statement::Labelled(s) => self.visit(s),
             Statement::Try(s) => self.visit(s),
             Statement::With(s) => self.visit(s),
-            Statement::Empty
+            Statement::Empty(_)
+            | Statement::Error(_)
             | Statement::Expression(_)
             | Statement::Continue(_)
             | Statement::Break(_)
@@ -2443,7 +2488,10 @@ impl<'ast> Visitor<'ast> for AnnexBFunctionDeclarationNamesVisitor<'_> {
             let bound_names = bound_names(node);
             self.0.retain(|name| !bound_names.contains(name));
         }
-        if let IterableLoopInitializer::Const(node) = node.initializer() {
+        if let IterableLoopInitializer::Const(node)
+        | IterableLoopInitializer::Using(node)
+        | IterableLoopInitializer::AwaitUsing(node) = node.initializer()
+        {
             let bound_names = bound_names(node);
             self.0.retain(|name| !bound_names.contains(name));
         }
@@ -2461,7 +2509,10 @@ impl<'ast> Visitor<'ast> for AnnexBFunctionDeclarationNamesVisitor<'_> {
             let bound_names = bound_names(node);
             self.0.retain(|name| !bound_names.contains(name));
         }
-        if let IterableLoopInitializer::Const(node) = node.initializer() {
+        if let IterableLoopInitializer::Const(node)
+        | IterableLoopInitializer::Using(node)
+        | IterableLoopInitializer::AwaitUsing(node) = node.initializer()
+        {
             let bound_names = bound_names(node);
             self.0.retain(|name| !bound_names.contains(name));
         }
@@ -2512,7 +2563,7 @@ impl<'ast> Visitor<'ast> for ReturnsValueVisitor {
 
     fn visit_statement(&mut self, node: &'ast Statement) -> ControlFlow<Self::BreakTy> {
         match node {
-            Statement::Empty | Statement::Var(_) => {}
+            Statement::Empty(_) | Statement::Var(_) => {}
             Statement::Block(node) => self.visit(node)?,
             Statement::Labelled(node) => self.visit(node)?,
             _ => return ControlFlow::Break(()),
@@ -4,24 +4,33 @@ use crate::{
     expression::{Call, Identifier},
     operations::{contains, ContainsSymbol},
     statement::With,
-    Expression, Statement,
+    Expression, SpanIgnoreEq, Statement,
 };
 
 #[test]
 fn check_contains_this_in_with_statment_expression() {
-    let node = With::new(Expression::This, Statement::Empty);
+    let node = With::new(
+        Expression::This(SpanIgnoreEq::default()),
+        Statement::Empty(SpanIgnoreEq::default()),
+    );
     assert!(contains(&node, ContainsSymbol::This));
 }
 
 #[test]
 fn check_contains_new_target_in_with_statment_expression() {
-    let node = With::new(Expression::NewTarget, Statement::Empty);
+    let node = With::new(
+        Expression::NewTarget(SpanIgnoreEq::default()),
+        Statement::Empty(SpanIgnoreEq::default()),
+    );
     assert!(contains(&node, ContainsSymbol::NewTarget));
 }
 
 #[test]
 fn check_contains_new_target_in_call_function_position() {
-    let node = Call::new(Expression::NewTarget, Box::default());
+    let node = Call::new(
+        Expression::NewTarget(SpanIgnoreEq::default()),
+        Box::default(),
+    );
     assert!(contains(&node, ContainsSymbol::NewTarget));
 }
 
@@ -31,7 +40,7 @@ fn check_contains_this_in_call_argument_position() {
     let function_name: Identifier = interner.get_or_intern("func").into();
     let node = Call::new(
         function_name.into(),
-        vec![Expression::This].into_boxed_slice(),
+        vec![Expression::This(SpanIgnoreEq::default())].into_boxed_slice(),
     );
 
     assert!(contains(&node, ContainsSymbol::This));
@@ -43,7 +52,7 @@ fn check_contains_new_target_in_call_argument_position() {
     let function_name: Identifier = interner.get_or_intern("func").into();
     let node = Call::new(
         function_name.into(),
-        vec![Expression::NewTarget].into_boxed_slice(),
+        vec![Expression::NewTarget(SpanIgnoreEq::default())].into_boxed_slice(),
     );
 
     assert!(contains(&node, ContainsSymbol::NewTarget));
@@ -292,6 +292,100 @@ impl<'a> arbitrary::Arbitrary<'a> for LinearSpanIgnoreEq {
     }
 }
 
+/// Stores an optional `Position` but `PartialEq`, `Eq` always return true.
+///
+/// Nodes built by hand don't have a position, so this allows them to compare equal to the
+/// nodes produced by the parser.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PositionIgnoreEq(pub Option<Position>);
+impl PartialEq for PositionIgnoreEq {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+impl From<Position> for PositionIgnoreEq {
+    fn from(value: Position) -> Self {
+        Self(Some(value))
+    }
+}
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for PositionIgnoreEq {
+    fn arbitrary(_: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self(None))
+    }
+}
+
+/// Stores an optional `Span` but `PartialEq`, `Eq` always return true, and `Hash` hashes nothing.
+///
+/// This is the span stored by every AST node, see [`Spanned`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SpanIgnoreEq(pub Option<Span>);
+impl PartialEq for SpanIgnoreEq {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+impl Eq for SpanIgnoreEq {}
+impl std::hash::Hash for SpanIgnoreEq {
+    fn hash<H: std::hash::Hasher>(&self, _: &mut H) {}
+}
+impl From<Span> for SpanIgnoreEq {
+    fn from(value: Span) -> Self {
+        Self(Some(value))
+    }
+}
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SpanIgnoreEq {
+    fn arbitrary(_: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self(None))
+    }
+}
+
+/// An AST node that knows its location in the source code.
+///
+/// The parser sets the span of every expression, statement and declaration it produces, from
+/// the start of its first token to the end of its last token. Nodes built by hand have no span
+/// until one is set, and spans are ignored when comparing nodes.
+pub trait Spanned {
+    /// Gets the span of the node in the source code, if known.
+    fn span(&self) -> Option<Span>;
+
+    /// Sets the span of the node in the source code.
+    fn set_span(&mut self, span: Span);
+
+    /// Sets the span of the node in the source code, returning the node.
+    #[must_use]
+    fn with_span(mut self, span: Span) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_span(span);
+        self
+    }
+}
+
+/// Implements [`Spanned`] for nodes that store their span in a `span: SpanIgnoreEq` field.
+macro_rules! impl_spanned {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl $crate::Spanned for $ty {
+                #[inline]
+                fn span(&self) -> Option<$crate::Span> {
+                    self.span.0
+                }
+
+                #[inline]
+                fn set_span(&mut self, span: $crate::Span) {
+                    self.span = $crate::SpanIgnoreEq(Some(span));
+                }
+            }
+        )+
+    };
+}
+pub(crate) use impl_spanned;
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// A position group of `LinearPosition` and `Position` related to the same position in the ECMAScript source code.
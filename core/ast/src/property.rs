@@ -1,6 +1,6 @@
 //! Property definition related types, used in object literals and class definitions.
 
-use super::{expression::literal::Literal, Expression};
+use super::{expression::literal::LiteralKind, Expression};
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use boa_interner::{Interner, Sym, ToInternedString};
 use core::ops::ControlFlow;
@@ -57,8 +57,13 @@ impl PropertyName {
     #[must_use]
     pub const fn prop_name(&self) -> Option<Sym> {
         match self {
-            Self::Literal(sym) | Self::Computed(Expression::Literal(Literal::String(sym))) => {
-                Some(*sym)
+            Self::Literal(sym) => Some(*sym),
+            Self::Computed(Expression::Literal(literal)) => {
+                if let LiteralKind::String(sym) = literal.kind() {
+                    Some(*sym)
+                } else {
+                    None
+                }
             }
             Self::Computed(_) => None,
         }
@@ -9,16 +9,27 @@ use std::{
     rc::Rc,
 };
 
-#[derive(Clone, Debug, PartialEq)]
+/// A binding of a [`Scope`].
+///
+/// Bindings are only exposed to store scopes outside of the AST, e.g. in bytecode caches, and to
+/// restore them with [`Scope::from_parts`].
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[allow(clippy::struct_excessive_bools)]
-struct Binding {
-    name: JsString,
-    index: u32,
-    mutable: bool,
-    lex: bool,
-    strict: bool,
-    escapes: bool,
-    accessed: bool,
+pub struct Binding {
+    /// The name of the binding.
+    pub name: JsString,
+    /// The index of the binding in its environment.
+    pub index: u32,
+    /// Whether the binding is mutable.
+    pub mutable: bool,
+    /// Whether the binding is lexically declared.
+    pub lex: bool,
+    /// Whether assigning to the immutable binding throws, even in sloppy mode.
+    pub strict: bool,
+    /// Whether the binding is accessed outside of the function that declares it.
+    pub escapes: bool,
+    /// Whether the binding is accessed at all.
+    pub accessed: bool,
 }
 
 /// A scope maps bound identifiers to their binding positions.
@@ -96,6 +107,48 @@ impl Scope {
         }
     }
 
+    /// Restores a scope from the parts of a scope, as returned by [`Scope::unique_id`],
+    /// [`Scope::scope_index`], [`Scope::is_function`], [`Scope::escaped_this`] and
+    /// [`Scope::bindings`].
+    #[must_use]
+    pub fn from_parts(
+        outer: Self,
+        unique_id: u32,
+        index: u32,
+        function: bool,
+        this_escaped: bool,
+        bindings: Vec<Binding>,
+    ) -> Self {
+        Self {
+            inner: Rc::new(Inner {
+                unique_id,
+                outer: Some(outer),
+                index: Cell::new(index),
+                bindings: RefCell::new(bindings),
+                function,
+                this_escaped: Cell::new(this_escaped),
+            }),
+        }
+    }
+
+    /// Returns the address of this scope, which identifies the scope among its clones.
+    #[must_use]
+    pub fn addr(&self) -> *const () {
+        Rc::as_ptr(&self.inner).cast()
+    }
+
+    /// Returns the unique identifier of this scope.
+    #[must_use]
+    pub fn unique_id(&self) -> u32 {
+        self.inner.unique_id
+    }
+
+    /// Returns a copy of the bindings of this scope.
+    #[must_use]
+    pub fn bindings(&self) -> Vec<Binding> {
+        self.inner.bindings.borrow().clone()
+    }
+
     /// Checks if the scope has only local bindings.
     #[must_use]
     pub fn all_bindings_local(&self) -> bool {
@@ -341,7 +394,7 @@ impl Scope {
 
     /// Crate an immutable binding.
     #[allow(clippy::cast_possible_truncation)]
-    pub(crate) fn create_immutable_binding(&self, name: JsString, strict: bool) {
+    pub fn create_immutable_binding(&self, name: JsString, strict: bool) {
         let mut bindings = self.inner.bindings.borrow_mut();
         if bindings.iter().any(|b| b.name == name) {
             return;
@@ -542,6 +595,26 @@ impl BindingLocator {
         }
     }
 
+    /// Restores a binding locator from the parts of a locator, as returned by
+    /// [`BindingLocator::name`], [`BindingLocator::scope`], [`BindingLocator::binding_index`] and
+    /// [`BindingLocator::unique_scope_id`].
+    #[must_use]
+    pub fn from_parts(
+        name: JsString,
+        scope: BindingLocatorScope,
+        binding_index: u32,
+        unique_scope_id: u32,
+    ) -> Self {
+        let mut locator = Self {
+            name,
+            scope: 0,
+            binding_index,
+            unique_scope_id,
+        };
+        locator.set_scope(scope);
+        locator
+    }
+
     /// Returns the name of the binding.
     #[must_use]
     pub const fn name(&self) -> &JsString {
@@ -583,6 +656,12 @@ impl BindingLocator {
     pub fn set_binding_index(&mut self, index: u32) {
         self.binding_index = index;
     }
+
+    /// Returns the unique identifier of the scope of the binding.
+    #[must_use]
+    pub const fn unique_scope_id(&self) -> u32 {
+        self.unique_scope_id
+    }
 }
 
 /// Action that is returned when a fallible binding operation.
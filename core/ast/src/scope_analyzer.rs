@@ -379,6 +379,9 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
         &mut self,
         node: &'ast mut ClassDeclaration,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            self.visit_decorator_mut(decorator)?;
+        }
         node.name_scope.escape_all_bindings();
         std::mem::swap(&mut self.scope, &mut node.name_scope);
         if let Some(super_ref) = &mut node.super_ref {
@@ -399,6 +402,9 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
         &mut self,
         node: &'ast mut ClassExpression,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            self.visit_decorator_mut(decorator)?;
+        }
         if let Some(name_scope) = &mut node.name_scope {
             if self.direct_eval {
                 name_scope.escape_all_bindings();
@@ -427,13 +433,21 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
         node: &'ast mut ClassElement,
     ) -> ControlFlow<Self::BreakTy> {
         match node {
-            ClassElement::MethodDefinition(node) => self.visit_function_like(
-                &mut node.parameters,
-                &mut node.body,
-                &mut node.scopes,
-                node.contains_direct_eval,
-            ),
+            ClassElement::MethodDefinition(node) => {
+                for decorator in &mut *node.decorators {
+                    self.visit_decorator_mut(decorator)?;
+                }
+                self.visit_function_like(
+                    &mut node.parameters,
+                    &mut node.body,
+                    &mut node.scopes,
+                    node.contains_direct_eval,
+                )
+            }
             ClassElement::FieldDefinition(field) | ClassElement::StaticFieldDefinition(field) => {
+                for decorator in &mut *field.decorators {
+                    self.visit_decorator_mut(decorator)?;
+                }
                 self.visit_property_name_mut(&mut field.name)?;
                 if let Some(e) = &mut field.initializer {
                     self.visit_expression_mut(e)?;
@@ -442,6 +456,9 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
             }
             ClassElement::PrivateFieldDefinition(field)
             | ClassElement::PrivateStaticFieldDefinition(field) => {
+                for decorator in &mut *field.decorators {
+                    self.visit_decorator_mut(decorator)?;
+                }
                 if let Some(e) = &mut field.initializer {
                     self.visit_expression_mut(e)?;
                 }
@@ -477,9 +494,17 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
         node: &'ast mut ExportDeclaration,
     ) -> ControlFlow<Self::BreakTy> {
         match node {
-            ExportDeclaration::ReExport { specifier, kind } => {
+            ExportDeclaration::ReExport {
+                specifier,
+                kind,
+                attributes,
+            } => {
                 self.visit_module_specifier_mut(specifier)?;
-                self.visit_re_export_kind_mut(kind)
+                self.visit_re_export_kind_mut(kind)?;
+                for attribute in &mut **attributes {
+                    self.visit_import_attribute_mut(attribute)?;
+                }
+                ControlFlow::Continue(())
             }
             ExportDeclaration::List(list) => {
                 for item in &mut **list {
@@ -572,7 +597,7 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
         &mut self,
         node: &'ast mut crate::Expression,
     ) -> ControlFlow<Self::BreakTy> {
-        if *node == crate::Expression::This {
+        if matches!(node, crate::Expression::This(_)) {
             // NOTE: Arrow functions inherit 'this' from their enclosing scope, so we must escape it.
             if self.in_arrow {
                 self.scope.escape_this_in_enclosing_function_scope();
@@ -767,6 +792,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
         &mut self,
         node: &'ast mut ClassDeclaration,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            self.visit_decorator_mut(decorator)?;
+        }
         let mut name_scope = Scope::new(self.scope.clone(), false);
         let name = node.name().to_js_string(self.interner);
         name_scope.create_immutable_binding(name, true);
@@ -789,6 +817,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
         &mut self,
         node: &'ast mut ClassExpression,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            self.visit_decorator_mut(decorator)?;
+        }
         let mut name_scope = None;
         if let Some(name) = node.name {
             if node.name_scope.is_some() {
@@ -821,6 +852,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
     ) -> ControlFlow<Self::BreakTy> {
         match node {
             ClassElement::MethodDefinition(node) => {
+                for decorator in &mut *node.decorators {
+                    self.visit_decorator_mut(decorator)?;
+                }
                 let strict = node.body.strict();
                 self.visit_function_like(
                     &mut node.body,
@@ -833,6 +867,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
                 )
             }
             ClassElement::FieldDefinition(field) | ClassElement::StaticFieldDefinition(field) => {
+                for decorator in &mut *field.decorators {
+                    self.visit_decorator_mut(decorator)?;
+                }
                 self.visit_property_name_mut(&mut field.name)?;
                 let mut scope = Scope::new(self.scope.clone(), true);
                 std::mem::swap(&mut self.scope, &mut scope);
@@ -845,6 +882,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
             }
             ClassElement::PrivateFieldDefinition(field)
             | ClassElement::PrivateStaticFieldDefinition(field) => {
+                for decorator in &mut *field.decorators {
+                    self.visit_decorator_mut(decorator)?;
+                }
                 let mut scope = Scope::new(self.scope.clone(), true);
                 std::mem::swap(&mut self.scope, &mut scope);
                 if let Some(e) = &mut field.initializer {
@@ -997,7 +1037,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
     fn visit_for_in_loop_mut(&mut self, node: &'ast mut ForInLoop) -> ControlFlow<Self::BreakTy> {
         let initializer_bound_names = match node.initializer() {
             IterableLoopInitializer::Let(declaration)
-            | IterableLoopInitializer::Const(declaration) => bound_names(declaration),
+            | IterableLoopInitializer::Const(declaration)
+            | IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => bound_names(declaration),
             _ => Vec::new(),
         };
         if initializer_bound_names.is_empty() {
@@ -1030,7 +1072,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
                 }
                 Some(scope)
             }
-            IterableLoopInitializer::Const(declaration) => {
+            IterableLoopInitializer::Const(declaration)
+            | IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => {
                 let scope = Scope::new(self.scope.clone(), false);
                 match declaration {
                     Binding::Identifier(ident) => {
@@ -1064,7 +1108,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
     fn visit_for_of_loop_mut(&mut self, node: &'ast mut ForOfLoop) -> ControlFlow<Self::BreakTy> {
         let initializer_bound_names = match node.initializer() {
             IterableLoopInitializer::Let(declaration)
-            | IterableLoopInitializer::Const(declaration) => bound_names(declaration),
+            | IterableLoopInitializer::Const(declaration)
+            | IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => bound_names(declaration),
             _ => Vec::new(),
         };
         if initializer_bound_names.is_empty() {
@@ -1097,7 +1143,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
                 }
                 Some(scope)
             }
-            IterableLoopInitializer::Const(declaration) => {
+            IterableLoopInitializer::Const(declaration)
+            | IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => {
                 let scope = Scope::new(self.scope.clone(), false);
                 match declaration {
                     Binding::Identifier(ident) => {
@@ -1379,6 +1427,9 @@ impl<'ast> VisitorMut<'ast> for ScopeIndexVisitor {
         &mut self,
         node: &'ast mut ClassDeclaration,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            self.visit_decorator_mut(decorator)?;
+        }
         let index = self.index;
         if !node.name_scope.all_bindings_local() {
             self.index += 1;
@@ -1401,6 +1452,9 @@ impl<'ast> VisitorMut<'ast> for ScopeIndexVisitor {
         &mut self,
         node: &'ast mut ClassExpression,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            self.visit_decorator_mut(decorator)?;
+        }
         let index = self.index;
         if let Some(scope) = &node.name_scope {
             if !scope.all_bindings_local() {
@@ -1427,6 +1481,9 @@ impl<'ast> VisitorMut<'ast> for ScopeIndexVisitor {
     ) -> ControlFlow<Self::BreakTy> {
         match node {
             ClassElement::MethodDefinition(node) => {
+                for decorator in &mut *node.decorators {
+                    self.visit_decorator_mut(decorator)?;
+                }
                 let contains_direct_eval = node.contains_direct_eval();
                 self.visit_function_like(
                     &mut node.body,
@@ -1438,6 +1495,9 @@ impl<'ast> VisitorMut<'ast> for ScopeIndexVisitor {
                 )
             }
             ClassElement::FieldDefinition(field) | ClassElement::StaticFieldDefinition(field) => {
+                for decorator in &mut *field.decorators {
+                    self.visit_decorator_mut(decorator)?;
+                }
                 self.visit_property_name_mut(&mut field.name)?;
                 let index = self.index;
                 self.index += 1;
@@ -1450,6 +1510,9 @@ impl<'ast> VisitorMut<'ast> for ScopeIndexVisitor {
             }
             ClassElement::PrivateFieldDefinition(field)
             | ClassElement::PrivateStaticFieldDefinition(field) => {
+                for decorator in &mut *field.decorators {
+                    self.visit_decorator_mut(decorator)?;
+                }
                 let index = self.index;
                 self.index += 1;
                 field.scope.set_index(self.index);
@@ -1744,7 +1807,11 @@ fn global_declaration_instantiation(
                         drop(env.create_mutable_binding(name, false));
                     }
                 }
-                Declaration::Lexical(LexicalDeclaration::Const(declaration)) => {
+                Declaration::Lexical(
+                    LexicalDeclaration::Const(declaration)
+                    | LexicalDeclaration::Using(declaration)
+                    | LexicalDeclaration::AwaitUsing(declaration),
+                ) => {
                     for name in bound_names(declaration) {
                         let name = name.to_js_string(interner);
                         env.create_immutable_binding(name, true);
@@ -1783,7 +1850,12 @@ where
     // 3. For each element d of declarations, do
     for d in &declarations {
         // i. If IsConstantDeclaration of d is true, then
-        if let LexicallyScopedDeclaration::LexicalDeclaration(LexicalDeclaration::Const(d)) = d {
+        if let LexicallyScopedDeclaration::LexicalDeclaration(
+            LexicalDeclaration::Const(d)
+            | LexicalDeclaration::Using(d)
+            | LexicalDeclaration::AwaitUsing(d),
+        ) = d
+        {
             // a. For each element dn of the BoundNames of d, do
             for dn in bound_names::<'_, VariableList>(d) {
                 // 1. Perform ! env.CreateImmutableBinding(dn, true).
@@ -2124,7 +2196,11 @@ fn function_declaration_instantiation(
                         drop(lex_env.create_mutable_binding(name, false));
                     }
                 }
-                Declaration::Lexical(LexicalDeclaration::Const(declaration)) => {
+                Declaration::Lexical(
+                    LexicalDeclaration::Const(declaration)
+                    | LexicalDeclaration::Using(declaration)
+                    | LexicalDeclaration::AwaitUsing(declaration),
+                ) => {
                     for name in bound_names(declaration) {
                         let name = name.to_js_string(interner);
                         lex_env.create_immutable_binding(name, true);
@@ -2193,7 +2269,11 @@ fn module_instantiation(module: &Module, env: &Scope, interner: &Interner) {
                     drop(env.create_mutable_binding(name, false));
                 }
             }
-            LexicallyScopedDeclaration::LexicalDeclaration(LexicalDeclaration::Const(c)) => {
+            LexicallyScopedDeclaration::LexicalDeclaration(
+                LexicalDeclaration::Const(c)
+                | LexicalDeclaration::Using(c)
+                | LexicalDeclaration::AwaitUsing(c),
+            ) => {
                 for name in bound_names(c) {
                     let name = name.to_js_string(interner);
                     env.create_immutable_binding(name, true);
@@ -2427,7 +2507,11 @@ pub(crate) fn eval_declaration_instantiation_scope(
                         drop(lex_env.create_mutable_binding(name, false));
                     }
                 }
-                Declaration::Lexical(LexicalDeclaration::Const(declaration)) => {
+                Declaration::Lexical(
+                    LexicalDeclaration::Const(declaration)
+                    | LexicalDeclaration::Using(declaration)
+                    | LexicalDeclaration::AwaitUsing(declaration),
+                ) => {
                     for name in bound_names(declaration) {
                         let name = name.to_js_string(interner);
                         lex_env.create_immutable_binding(name, true);
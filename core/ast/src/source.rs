@@ -3,6 +3,8 @@ use std::ops::ControlFlow;
 use boa_interner::{Interner, ToIndentedString};
 
 use crate::{
+    codegen::{generate_indented_module, generate_indented_script},
+    comment::Comment,
     expression::Identifier,
     scope::Scope,
     scope_analyzer::{
@@ -23,13 +25,17 @@ use crate::{
 #[derive(Clone, Debug, Default)]
 pub struct Script {
     statements: StatementList,
+    comments: Box<[Comment]>,
 }
 
 impl Script {
     /// Creates a new `ScriptNode`.
     #[must_use]
-    pub const fn new(statements: StatementList) -> Self {
-        Self { statements }
+    pub fn new(statements: StatementList) -> Self {
+        Self {
+            statements,
+            comments: Box::default(),
+        }
     }
 
     /// Gets the list of statements of this `ScriptNode`.
@@ -43,6 +49,20 @@ impl Script {
         &mut self.statements
     }
 
+    /// Gets the comments of this `ScriptNode`, sorted by position.
+    ///
+    /// This is empty unless the parser was set to record comments.
+    #[inline]
+    #[must_use]
+    pub fn comments(&self) -> &[Comment] {
+        &self.comments
+    }
+
+    /// Sets the comments of this `ScriptNode`, which must be sorted by position.
+    pub fn set_comments(&mut self, comments: Box<[Comment]>) {
+        self.comments = comments;
+    }
+
     /// Gets the strict mode.
     #[inline]
     #[must_use]
@@ -117,8 +137,18 @@ impl VisitWith for Script {
 }
 
 impl ToIndentedString for Script {
+    /// Creates a string of the script with the given indentation.
+    ///
+    /// If the script has comments, it is printed by the [pretty code generator][generator],
+    /// which prints each comment on its own line, before the first statement that follows it.
+    /// Comments after the last statement are printed at the end.
+    ///
+    /// [generator]: crate::codegen::CodegenMode::Pretty
     fn to_indented_string(&self, interner: &Interner, indentation: usize) -> String {
-        self.statements.to_indented_string(interner, indentation)
+        if self.comments.is_empty() {
+            return self.statements.to_indented_string(interner, indentation);
+        }
+        generate_indented_script(self, interner, indentation)
     }
 }
 
@@ -132,7 +162,7 @@ impl PartialEq for Script {
 impl<'a> arbitrary::Arbitrary<'a> for Script {
     fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
         let statements = StatementList::arbitrary(u)?;
-        Ok(Self { statements })
+        Ok(Self::new(statements))
     }
 }
 
@@ -149,6 +179,8 @@ pub struct Module {
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Scope,
+
+    comments: Box<[Comment]>,
 }
 
 impl Module {
@@ -158,9 +190,24 @@ impl Module {
         Self {
             items,
             scope: Scope::default(),
+            comments: Box::default(),
         }
     }
 
+    /// Gets the comments of this `ModuleNode`, sorted by position.
+    ///
+    /// This is empty unless the parser was set to record comments.
+    #[inline]
+    #[must_use]
+    pub fn comments(&self) -> &[Comment] {
+        &self.comments
+    }
+
+    /// Sets the comments of this `ModuleNode`, which must be sorted by position.
+    pub fn set_comments(&mut self, comments: Box<[Comment]>) {
+        self.comments = comments;
+    }
+
     /// Gets the list of itemos of this `ModuleNode`.
     #[must_use]
     pub const fn items(&self) -> &ModuleItemList {
@@ -187,6 +234,19 @@ impl Module {
     }
 }
 
+impl ToIndentedString for Module {
+    /// Creates a string of the module with the given indentation.
+    ///
+    /// The module is printed by the [pretty code generator][generator], which prints each comment
+    /// on its own line, before the first item that follows it. Comments after the last item are
+    /// printed at the end.
+    ///
+    /// [generator]: crate::codegen::CodegenMode::Pretty
+    fn to_indented_string(&self, interner: &Interner, indentation: usize) -> String {
+        generate_indented_module(self, interner, indentation)
+    }
+}
+
 impl VisitWith for Module {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
@@ -2,9 +2,10 @@
 
 use crate::{
     operations::{contains, ContainsSymbol},
+    position::impl_spanned,
     scope::Scope,
     visitor::{VisitWith, Visitor, VisitorMut},
-    Statement, StatementList,
+    SpanIgnoreEq, Statement, StatementList,
 };
 use boa_interner::{Interner, ToIndentedString};
 use core::ops::ControlFlow;
@@ -34,6 +35,7 @@ pub struct Block {
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Option<Scope>,
+    span: SpanIgnoreEq,
 }
 
 impl Block {
@@ -52,6 +54,8 @@ impl Block {
     }
 }
 
+impl_spanned!(Block);
+
 impl<T> From<T> for Block
 where
     T: Into<StatementList>,
@@ -63,6 +67,7 @@ where
             statements,
             scope: None,
             contains_direct_eval,
+            span: SpanIgnoreEq(None),
         }
     }
 }
@@ -2,8 +2,10 @@
 
 use crate::{
     expression::Expression,
+    position::impl_spanned,
     statement::Statement,
     visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
 };
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 use core::{fmt::Write as _, ops::ControlFlow};
@@ -31,6 +33,7 @@ pub struct If {
     condition: Expression,
     body: Box<Statement>,
     else_node: Option<Box<Statement>>,
+    span: SpanIgnoreEq,
 }
 
 impl If {
@@ -62,10 +65,13 @@ impl If {
             condition,
             body: body.into(),
             else_node: else_node.map(Box::new),
+            span: SpanIgnoreEq(None),
         }
     }
 }
 
+impl_spanned!(If);
+
 impl ToIndentedString for If {
     fn to_indented_string(&self, interner: &Interner, indent: usize) -> String {
         let mut buf = format!("if ({}) ", self.cond().to_interned_string(interner));
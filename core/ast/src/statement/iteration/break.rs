@@ -1,8 +1,12 @@
 use boa_interner::{Interner, Sym, ToInternedString};
 use core::ops::ControlFlow;
 
-use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::Statement;
+use crate::{
+    position::impl_spanned,
+    visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
+};
 
 /// The `break` statement terminates the current loop, switch, or label statement and transfers
 /// program control to the statement following the terminated statement.
@@ -23,13 +27,17 @@ use crate::Statement;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Break {
     label: Option<Sym>,
+    span: SpanIgnoreEq,
 }
 
 impl Break {
     /// Creates a `Break` AST node.
     #[must_use]
     pub const fn new(label: Option<Sym>) -> Self {
-        Self { label }
+        Self {
+            label,
+            span: SpanIgnoreEq(None),
+        }
     }
 
     /// Gets the label of the break statement, if any.
@@ -39,6 +47,8 @@ impl Break {
     }
 }
 
+impl_spanned!(Break);
+
 impl ToInternedString for Break {
     fn to_interned_string(&self, interner: &Interner) -> String {
         self.label.map_or_else(
@@ -1,5 +1,9 @@
 use crate::statement::Statement;
-use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{
+    position::impl_spanned,
+    visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
+};
 use boa_interner::{Interner, Sym, ToInternedString};
 use core::ops::ControlFlow;
 
@@ -21,13 +25,17 @@ use core::ops::ControlFlow;
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Continue {
     label: Option<Sym>,
+    span: SpanIgnoreEq,
 }
 
 impl Continue {
     /// Creates a `Continue` AST node.
     #[must_use]
     pub const fn new(label: Option<Sym>) -> Self {
-        Self { label }
+        Self {
+            label,
+            span: SpanIgnoreEq(None),
+        }
     }
 
     /// Gets the label of this `Continue` statement.
@@ -37,6 +45,8 @@ impl Continue {
     }
 }
 
+impl_spanned!(Continue);
+
 impl ToInternedString for Continue {
     fn to_interned_string(&self, interner: &Interner) -> String {
         self.label.map_or_else(
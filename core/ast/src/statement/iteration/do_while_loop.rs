@@ -1,7 +1,9 @@
 use crate::{
     expression::Expression,
+    position::impl_spanned,
     statement::Statement,
     visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
 };
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 use core::ops::ControlFlow;
@@ -24,6 +26,7 @@ use core::ops::ControlFlow;
 pub struct DoWhileLoop {
     body: Box<Statement>,
     condition: Expression,
+    span: SpanIgnoreEq,
 }
 
 impl DoWhileLoop {
@@ -47,10 +50,13 @@ impl DoWhileLoop {
         Self {
             body: body.into(),
             condition,
+            span: SpanIgnoreEq(None),
         }
     }
 }
 
+impl_spanned!(DoWhileLoop);
+
 impl ToIndentedString for DoWhileLoop {
     fn to_indented_string(&self, interner: &Interner, indentation: usize) -> String {
         format!(
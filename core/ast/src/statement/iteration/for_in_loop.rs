@@ -3,7 +3,9 @@ use crate::scope::Scope;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
     expression::Expression,
+    position::impl_spanned,
     statement::{iteration::IterableLoopInitializer, Statement},
+    SpanIgnoreEq,
 };
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 use core::ops::ControlFlow;
@@ -30,6 +32,7 @@ pub struct ForInLoop {
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Option<Scope>,
+    span: SpanIgnoreEq,
 }
 
 impl ForInLoop {
@@ -48,6 +51,7 @@ impl ForInLoop {
             contains_direct_eval,
             target_scope: None,
             scope: None,
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -87,6 +91,8 @@ impl ForInLoop {
     }
 }
 
+impl_spanned!(ForInLoop);
+
 impl ToIndentedString for ForInLoop {
     fn to_indented_string(&self, interner: &Interner, indentation: usize) -> String {
         let mut buf = format!(
@@ -3,8 +3,9 @@ use crate::scope::Scope;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
     declaration::{LexicalDeclaration, VarDeclaration},
+    position::impl_spanned,
     statement::Statement,
-    Expression,
+    Expression, SpanIgnoreEq,
 };
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 use core::{fmt::Write as _, ops::ControlFlow};
@@ -25,6 +26,7 @@ use core::{fmt::Write as _, ops::ControlFlow};
 pub struct ForLoop {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub(crate) inner: Box<InnerForLoop>,
+    span: SpanIgnoreEq,
 }
 
 impl ForLoop {
@@ -39,6 +41,7 @@ impl ForLoop {
     ) -> Self {
         Self {
             inner: Box::new(InnerForLoop::new(init, condition, final_expr, body)),
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -71,6 +74,8 @@ impl ForLoop {
     }
 }
 
+impl_spanned!(ForLoop);
+
 impl ToIndentedString for ForLoop {
     fn to_indented_string(&self, interner: &Interner, indentation: usize) -> String {
         let mut buf = String::from("for (");
@@ -3,7 +3,9 @@ use crate::scope::Scope;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
     expression::Expression,
+    position::impl_spanned,
     statement::{iteration::IterableLoopInitializer, Statement},
+    SpanIgnoreEq,
 };
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 use core::ops::ControlFlow;
@@ -36,6 +38,7 @@ pub struct ForOfLoop {
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Option<Scope>,
+    span: SpanIgnoreEq,
 }
 
 impl ForOfLoop {
@@ -60,6 +63,7 @@ impl ForOfLoop {
             r#await,
             iterable_scope: None,
             scope: None,
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -106,6 +110,8 @@ impl ForOfLoop {
     }
 }
 
+impl_spanned!(ForOfLoop);
+
 impl ToIndentedString for ForOfLoop {
     fn to_indented_string(&self, interner: &Interner, indentation: usize) -> String {
         format!(
@@ -1,7 +1,9 @@
 use crate::{
     expression::Expression,
+    position::impl_spanned,
     statement::Statement,
     visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
 };
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 use core::ops::ControlFlow;
@@ -23,6 +25,7 @@ use core::ops::ControlFlow;
 pub struct WhileLoop {
     condition: Expression,
     body: Box<Statement>,
+    span: SpanIgnoreEq,
 }
 
 impl WhileLoop {
@@ -33,6 +36,7 @@ impl WhileLoop {
         Self {
             condition,
             body: body.into(),
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -51,6 +55,8 @@ impl WhileLoop {
     }
 }
 
+impl_spanned!(WhileLoop);
+
 impl ToIndentedString for WhileLoop {
     fn to_indented_string(&self, interner: &Interner, indentation: usize) -> String {
         format!(
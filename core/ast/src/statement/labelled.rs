@@ -1,7 +1,8 @@
 use crate::{
     function::FunctionDeclaration,
+    position::impl_spanned,
     visitor::{VisitWith, Visitor, VisitorMut},
-    Statement,
+    SpanIgnoreEq, Statement,
 };
 use boa_interner::{Interner, Sym, ToIndentedString, ToInternedString};
 use core::ops::ControlFlow;
@@ -88,6 +89,7 @@ impl VisitWith for LabelledItem {
 pub struct Labelled {
     item: Box<LabelledItem>,
     label: Sym,
+    span: SpanIgnoreEq,
 }
 
 impl Labelled {
@@ -98,6 +100,7 @@ impl Labelled {
         Self {
             item: Box::new(item),
             label,
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -124,6 +127,8 @@ impl Labelled {
     }
 }
 
+impl_spanned!(Labelled);
+
 impl ToInternedString for Labelled {
     fn to_interned_string(&self, interner: &Interner) -> String {
         self.to_indented_string(interner, 0)
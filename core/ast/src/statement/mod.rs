@@ -31,7 +31,10 @@ pub use self::{
 };
 use core::ops::ControlFlow;
 
-use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{
+    visitor::{VisitWith, Visitor, VisitorMut},
+    Span, SpanIgnoreEq, Spanned,
+};
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 
 use super::{declaration::VarDeclaration, expression::Expression};
@@ -59,7 +62,7 @@ pub enum Statement {
     ///
     /// [spec]: https://tc39.es/ecma262/#prod-EmptyStatement
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/Empty
-    Empty,
+    Empty(SpanIgnoreEq),
 
     /// See [`Expression`].
     Expression(Expression),
@@ -105,6 +108,12 @@ pub enum Statement {
 
     /// See [`With`].
     With(With),
+
+    /// A placeholder for a statement or declaration that could not be parsed.
+    ///
+    /// It is only produced by the recovering parse mode of the parser, and throws a `SyntaxError`
+    /// if it is executed.
+    Error(SpanIgnoreEq),
 }
 
 impl Statement {
@@ -116,7 +125,7 @@ impl Statement {
         let mut s = match self {
             Self::Block(block) => return block.to_indented_string(interner, indentation),
             Self::Var(var) => var.to_interned_string(interner),
-            Self::Empty => return ";".to_owned(),
+            Self::Empty(_) => return ";".to_owned(),
             Self::Expression(expr) => expr.to_indented_string(interner, indentation),
             Self::If(if_smt) => return if_smt.to_indented_string(interner, indentation),
             Self::DoWhileLoop(do_while) => do_while.to_indented_string(interner, indentation),
@@ -134,6 +143,7 @@ impl Statement {
             Self::Throw(throw) => throw.to_interned_string(interner),
             Self::Try(try_catch) => return try_catch.to_indented_string(interner, indentation),
             Self::With(with) => return with.to_interned_string(interner),
+            Self::Error(_) => return "/* error */;".to_owned(),
         };
         s.push(';');
         s
@@ -187,6 +197,56 @@ impl ToIndentedString for Statement {
     }
 }
 
+/// The span of an expression statement is the span of its expression, which doesn't include the
+/// semicolon.
+impl Spanned for Statement {
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::Empty(span) | Self::Error(span) => span.0,
+            Self::Block(b) => b.span(),
+            Self::Var(v) => v.span(),
+            Self::Expression(e) => e.span(),
+            Self::If(i) => i.span(),
+            Self::DoWhileLoop(dw) => dw.span(),
+            Self::WhileLoop(w) => w.span(),
+            Self::ForLoop(f) => f.span(),
+            Self::ForInLoop(fi) => fi.span(),
+            Self::ForOfLoop(fo) => fo.span(),
+            Self::Switch(s) => s.span(),
+            Self::Continue(c) => c.span(),
+            Self::Break(b) => b.span(),
+            Self::Return(r) => r.span(),
+            Self::Labelled(l) => l.span(),
+            Self::Throw(th) => th.span(),
+            Self::Try(tr) => tr.span(),
+            Self::With(with) => with.span(),
+        }
+    }
+
+    fn set_span(&mut self, span: Span) {
+        match self {
+            Self::Empty(s) | Self::Error(s) => *s = span.into(),
+            Self::Block(b) => b.set_span(span),
+            Self::Var(v) => v.set_span(span),
+            Self::Expression(e) => e.set_span(span),
+            Self::If(i) => i.set_span(span),
+            Self::DoWhileLoop(dw) => dw.set_span(span),
+            Self::WhileLoop(w) => w.set_span(span),
+            Self::ForLoop(f) => f.set_span(span),
+            Self::ForInLoop(fi) => fi.set_span(span),
+            Self::ForOfLoop(fo) => fo.set_span(span),
+            Self::Switch(s) => s.set_span(span),
+            Self::Continue(c) => c.set_span(span),
+            Self::Break(b) => b.set_span(span),
+            Self::Return(r) => r.set_span(span),
+            Self::Labelled(l) => l.set_span(span),
+            Self::Throw(th) => th.set_span(span),
+            Self::Try(tr) => tr.set_span(span),
+            Self::With(with) => with.set_span(span),
+        }
+    }
+}
+
 impl VisitWith for Statement {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
@@ -195,7 +255,7 @@ impl VisitWith for Statement {
         match self {
             Self::Block(b) => visitor.visit_block(b),
             Self::Var(v) => visitor.visit_var_declaration(v),
-            Self::Empty => {
+            Self::Empty(_) | Self::Error(_) => {
                 // do nothing; there is nothing to visit here
                 ControlFlow::Continue(())
             }
@@ -224,7 +284,7 @@ impl VisitWith for Statement {
         match self {
             Self::Block(b) => visitor.visit_block_mut(b),
             Self::Var(v) => visitor.visit_var_declaration_mut(v),
-            Self::Empty => {
+            Self::Empty(_) | Self::Error(_) => {
                 // do nothing; there is nothing to visit here
                 ControlFlow::Continue(())
             }
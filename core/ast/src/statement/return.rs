@@ -1,7 +1,9 @@
 use crate::{
     expression::Expression,
+    position::impl_spanned,
     statement::Statement,
     visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
 };
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
@@ -28,6 +30,7 @@ use core::ops::ControlFlow;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Return {
     target: Option<Expression>,
+    span: SpanIgnoreEq,
 }
 
 impl Return {
@@ -40,10 +43,15 @@ impl Return {
     /// Creates a `Return` AST node.
     #[must_use]
     pub const fn new(expression: Option<Expression>) -> Self {
-        Self { target: expression }
+        Self {
+            target: expression,
+            span: SpanIgnoreEq(None),
+        }
     }
 }
 
+impl_spanned!(Return);
+
 impl From<Return> for Statement {
     fn from(return_smt: Return) -> Self {
         Self::Return(return_smt)
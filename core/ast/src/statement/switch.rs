@@ -3,10 +3,11 @@
 use crate::{
     expression::Expression,
     operations::{contains, ContainsSymbol},
+    position::impl_spanned,
     scope::Scope,
     statement::Statement,
     visitor::{VisitWith, Visitor, VisitorMut},
-    StatementList,
+    SpanIgnoreEq, StatementList,
 };
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 use core::{fmt::Write as _, ops::ControlFlow};
@@ -122,6 +123,7 @@ pub struct Switch {
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Option<Scope>,
+    span: SpanIgnoreEq,
 }
 
 impl Switch {
@@ -138,6 +140,7 @@ impl Switch {
             cases,
             contains_direct_eval,
             scope: None,
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -175,6 +178,8 @@ impl Switch {
     }
 }
 
+impl_spanned!(Switch);
+
 impl ToIndentedString for Switch {
     fn to_indented_string(&self, interner: &Interner, indentation: usize) -> String {
         let indent = "    ".repeat(indentation);
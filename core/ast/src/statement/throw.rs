@@ -1,7 +1,8 @@
 use crate::{
+    position::impl_spanned,
     statement::Statement,
     visitor::{VisitWith, Visitor, VisitorMut},
-    Expression,
+    Expression, Position, PositionIgnoreEq, SpanIgnoreEq,
 };
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
@@ -25,6 +26,8 @@ use core::ops::ControlFlow;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Throw {
     target: Expression,
+    position: PositionIgnoreEq,
+    span: SpanIgnoreEq,
 }
 
 impl Throw {
@@ -34,13 +37,32 @@ impl Throw {
         &self.target
     }
 
+    /// Gets the position in the source code where this `Throw` statement starts, if known.
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        self.position.0
+    }
+
     /// Creates a `Throw` AST node.
     #[must_use]
     pub const fn new(target: Expression) -> Self {
-        Self { target }
+        Self {
+            target,
+            position: PositionIgnoreEq(None),
+            span: SpanIgnoreEq(None),
+        }
+    }
+
+    /// Sets the position in the source code where this `Throw` statement starts.
+    #[must_use]
+    pub fn with_position(mut self, position: Position) -> Self {
+        self.position = PositionIgnoreEq(Some(position));
+        self
     }
 }
 
+impl_spanned!(Throw);
+
 impl ToInternedString for Throw {
     fn to_interned_string(&self, interner: &Interner) -> String {
         format!("throw {}", self.target.to_interned_string(interner))
@@ -5,7 +5,9 @@ use crate::scope::Scope;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
     declaration::Binding,
+    position::impl_spanned,
     statement::{Block, Statement},
+    SpanIgnoreEq,
 };
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 use core::{fmt::Write as _, ops::ControlFlow};
@@ -29,6 +31,7 @@ use core::{fmt::Write as _, ops::ControlFlow};
 pub struct Try {
     block: Block,
     handler: ErrorHandler,
+    span: SpanIgnoreEq,
 }
 
 /// The type of error handler in a [`Try`] statement.
@@ -49,7 +52,11 @@ impl Try {
     #[inline]
     #[must_use]
     pub const fn new(block: Block, handler: ErrorHandler) -> Self {
-        Self { block, handler }
+        Self {
+            block,
+            handler,
+            span: SpanIgnoreEq(None),
+        }
     }
 
     /// Gets the `try` block.
@@ -80,6 +87,8 @@ impl Try {
     }
 }
 
+impl_spanned!(Try);
+
 impl ToIndentedString for Try {
     fn to_indented_string(&self, interner: &Interner, indentation: usize) -> String {
         let mut buf = format!(
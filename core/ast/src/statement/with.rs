@@ -1,8 +1,10 @@
 use crate::{
     expression::Expression,
+    position::impl_spanned,
     scope::Scope,
     statement::Statement,
     visitor::{VisitWith, Visitor, VisitorMut},
+    SpanIgnoreEq,
 };
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 use core::ops::ControlFlow;
@@ -24,6 +26,7 @@ pub struct With {
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Scope,
+    span: SpanIgnoreEq,
 }
 
 impl With {
@@ -34,6 +37,7 @@ impl With {
             expression,
             statement: Box::new(statement),
             scope: Scope::default(),
+            span: SpanIgnoreEq(None),
         }
     }
 
@@ -56,6 +60,8 @@ impl With {
     }
 }
 
+impl_spanned!(With);
+
 impl From<With> for Statement {
     fn from(with: With) -> Self {
         Self::With(with)
@@ -4,7 +4,7 @@ use super::Declaration;
 use crate::{
     statement::Statement,
     visitor::{VisitWith, Visitor, VisitorMut},
-    LinearPosition,
+    LinearPosition, Span, Spanned,
 };
 use boa_interner::{Interner, ToIndentedString};
 use core::ops::ControlFlow;
@@ -67,6 +67,24 @@ impl From<Declaration> for StatementListItem {
     }
 }
 
+impl Spanned for StatementListItem {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::Statement(statement) => statement.span(),
+            Self::Declaration(declaration) => declaration.span(),
+        }
+    }
+
+    #[inline]
+    fn set_span(&mut self, span: Span) {
+        match self {
+            Self::Statement(statement) => statement.set_span(span),
+            Self::Declaration(declaration) => declaration.set_span(span),
+        }
+    }
+}
+
 impl VisitWith for StatementListItem {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
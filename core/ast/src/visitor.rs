@@ -7,9 +7,9 @@ use std::ops::ControlFlow;
 
 use crate::{
     declaration::{
-        Binding, Declaration, ExportDeclaration, ExportSpecifier, ImportDeclaration, ImportKind,
-        ImportSpecifier, LexicalDeclaration, ModuleSpecifier, ReExportKind, VarDeclaration,
-        Variable, VariableList,
+        Binding, Declaration, ExportDeclaration, ExportSpecifier, ImportAttribute,
+        ImportDeclaration, ImportKind, ImportSpecifier, LexicalDeclaration, ModuleSpecifier,
+        ReExportKind, VarDeclaration, Variable, VariableList,
     },
     expression::{
         access::{
@@ -31,8 +31,9 @@ use crate::{
     function::{
         ArrowFunction, AsyncArrowFunction, AsyncFunctionDeclaration, AsyncFunctionExpression,
         AsyncGeneratorDeclaration, AsyncGeneratorExpression, ClassDeclaration, ClassElement,
-        ClassExpression, FormalParameter, FormalParameterList, FunctionBody, FunctionDeclaration,
-        FunctionExpression, GeneratorDeclaration, GeneratorExpression, PrivateName,
+        ClassExpression, Decorator, FormalParameter, FormalParameterList, FunctionBody,
+        FunctionDeclaration, FunctionExpression, GeneratorDeclaration, GeneratorExpression,
+        PrivateName,
     },
     pattern::{ArrayPattern, ArrayPatternElement, ObjectPattern, ObjectPatternElement, Pattern},
     property::PropertyName,
@@ -151,6 +152,7 @@ node_ref! {
     Identifier,
     FormalParameterList,
     ClassElement,
+    Decorator,
     PrivateName,
     VariableList,
     Variable,
@@ -209,6 +211,7 @@ node_ref! {
     ImportKind,
     ImportDeclaration,
     ImportSpecifier,
+    ImportAttribute,
     ReExportKind,
     ExportDeclaration,
     ExportSpecifier
@@ -260,6 +263,7 @@ pub trait Visitor<'ast>: Sized {
     define_visit!(visit_identifier, Identifier);
     define_visit!(visit_formal_parameter_list, FormalParameterList);
     define_visit!(visit_class_element, ClassElement);
+    define_visit!(visit_decorator, Decorator);
     define_visit!(visit_private_name, PrivateName);
     define_visit!(visit_variable_list, VariableList);
     define_visit!(visit_variable, Variable);
@@ -318,6 +322,7 @@ pub trait Visitor<'ast>: Sized {
     define_visit!(visit_import_kind, ImportKind);
     define_visit!(visit_import_declaration, ImportDeclaration);
     define_visit!(visit_import_specifier, ImportSpecifier);
+    define_visit!(visit_import_attribute, ImportAttribute);
     define_visit!(visit_re_export_kind, ReExportKind);
     define_visit!(visit_export_declaration, ExportDeclaration);
     define_visit!(visit_export_specifier, ExportSpecifier);
@@ -366,6 +371,7 @@ pub trait Visitor<'ast>: Sized {
             NodeRef::Identifier(n) => self.visit_identifier(n),
             NodeRef::FormalParameterList(n) => self.visit_formal_parameter_list(n),
             NodeRef::ClassElement(n) => self.visit_class_element(n),
+            NodeRef::Decorator(n) => self.visit_decorator(n),
             NodeRef::PrivateName(n) => self.visit_private_name(n),
             NodeRef::VariableList(n) => self.visit_variable_list(n),
             NodeRef::Variable(n) => self.visit_variable(n),
@@ -424,6 +430,7 @@ pub trait Visitor<'ast>: Sized {
             NodeRef::ImportKind(n) => self.visit_import_kind(n),
             NodeRef::ImportDeclaration(n) => self.visit_import_declaration(n),
             NodeRef::ImportSpecifier(n) => self.visit_import_specifier(n),
+            NodeRef::ImportAttribute(n) => self.visit_import_attribute(n),
             NodeRef::ReExportKind(n) => self.visit_re_export_kind(n),
             NodeRef::ExportDeclaration(n) => self.visit_export_declaration(n),
             NodeRef::ExportSpecifier(n) => self.visit_export_specifier(n),
@@ -486,6 +493,7 @@ pub trait VisitorMut<'ast>: Sized {
     define_visit_mut!(visit_identifier_mut, Identifier);
     define_visit_mut!(visit_formal_parameter_list_mut, FormalParameterList);
     define_visit_mut!(visit_class_element_mut, ClassElement);
+    define_visit_mut!(visit_decorator_mut, Decorator);
     define_visit_mut!(visit_private_name_mut, PrivateName);
     define_visit_mut!(visit_variable_list_mut, VariableList);
     define_visit_mut!(visit_variable_mut, Variable);
@@ -544,6 +552,7 @@ pub trait VisitorMut<'ast>: Sized {
     define_visit_mut!(visit_import_kind_mut, ImportKind);
     define_visit_mut!(visit_import_declaration_mut, ImportDeclaration);
     define_visit_mut!(visit_import_specifier_mut, ImportSpecifier);
+    define_visit_mut!(visit_import_attribute_mut, ImportAttribute);
     define_visit_mut!(visit_re_export_kind_mut, ReExportKind);
     define_visit_mut!(visit_export_declaration_mut, ExportDeclaration);
     define_visit_mut!(visit_export_specifier_mut, ExportSpecifier);
@@ -594,6 +603,7 @@ pub trait VisitorMut<'ast>: Sized {
             NodeRefMut::Identifier(n) => self.visit_identifier_mut(n),
             NodeRefMut::FormalParameterList(n) => self.visit_formal_parameter_list_mut(n),
             NodeRefMut::ClassElement(n) => self.visit_class_element_mut(n),
+            NodeRefMut::Decorator(n) => self.visit_decorator_mut(n),
             NodeRefMut::PrivateName(n) => self.visit_private_name_mut(n),
             NodeRefMut::VariableList(n) => self.visit_variable_list_mut(n),
             NodeRefMut::Variable(n) => self.visit_variable_mut(n),
@@ -652,6 +662,7 @@ pub trait VisitorMut<'ast>: Sized {
             NodeRefMut::ImportKind(n) => self.visit_import_kind_mut(n),
             NodeRefMut::ImportDeclaration(n) => self.visit_import_declaration_mut(n),
             NodeRefMut::ImportSpecifier(n) => self.visit_import_specifier_mut(n),
+            NodeRefMut::ImportAttribute(n) => self.visit_import_attribute_mut(n),
             NodeRefMut::ReExportKind(n) => self.visit_re_export_kind_mut(n),
             NodeRefMut::ExportDeclaration(n) => self.visit_export_declaration_mut(n),
             NodeRefMut::ExportSpecifier(n) => self.visit_export_specifier_mut(n),
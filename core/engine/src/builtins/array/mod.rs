@@ -1027,6 +1027,7 @@ impl Array {
             r.push(next.clone());
             // e. Set k to k + 1.
         }
+        context.check_string_allocation(r.iter().map(JsString::len).sum())?;
         // 8. Return R.
         Ok(js_string!(&r[..]).into())
     }
@@ -3449,6 +3450,7 @@ fn array_exotic_define_own_property(
                     drop(borrowed_object);
                     let old_len = old_len.to_u32(context)?;
                     if new_len >= old_len {
+                        check_array_growth(obj, index, context)?;
                         if ordinary_define_own_property(obj, key, desc, context)? {
                             let mut borrowed_object = obj.borrow_mut();
                             borrowed_object.properties_mut().storage[0] = JsValue::new(new_len);
@@ -3482,6 +3484,7 @@ fn array_exotic_define_own_property(
             if index >= old_len && !old_len_desc.expect_writable() {
                 return Ok(false);
             }
+            check_array_growth(obj, index, context)?;
 
             // h. Let succeeded be ! OrdinaryDefineOwnProperty(A, P, Desc).
             if ordinary_define_own_property(obj, key, desc, context)? {
@@ -3518,6 +3521,19 @@ fn array_exotic_define_own_property(
     }
 }
 
+/// Checks if storing the element at `index` of an array would grow its dense storage past the
+/// heap size limit.
+///
+/// Only appending an element grows the dense storage. Elements stored past its end make the
+/// storage of the array sparse, which only stores the defined elements.
+fn check_array_growth(obj: &JsObject, index: u32, context: &mut Context) -> JsResult<()> {
+    let dense_len = obj.borrow().properties().dense_indexed_properties_len();
+    if dense_len == Some(index as usize) {
+        context.check_heap_limit((index as usize + 1).saturating_mul(size_of::<JsValue>()))?;
+    }
+    Ok(())
+}
+
 /// Abstract operation `ArraySetLength ( A, Desc )`
 ///
 /// More information:
@@ -217,10 +217,33 @@ impl ArrayBuffer {
         }
     }
 
+    /// Creates an `ArrayBuffer` from the values of its internal slots.
+    pub(crate) fn from_parts(
+        data: Option<Vec<u8>>,
+        max_byte_len: Option<u64>,
+        detach_key: JsValue,
+    ) -> Self {
+        Self {
+            data,
+            max_byte_len,
+            detach_key,
+        }
+    }
+
     pub(crate) fn len(&self) -> usize {
         self.data.as_ref().map_or(0, Vec::len)
     }
 
+    /// Gets the `[[ArrayBufferMaxByteLength]]` internal slot of the buffer.
+    pub(crate) const fn max_byte_len(&self) -> Option<u64> {
+        self.max_byte_len
+    }
+
+    /// Gets the `[[ArrayBufferDetachKey]]` internal slot of the buffer.
+    pub(crate) const fn detach_key(&self) -> &JsValue {
+        &self.detach_key
+    }
+
     pub(crate) fn bytes(&self) -> Option<&[u8]> {
         self.data.as_deref()
     }
@@ -1,5 +1,5 @@
 use crate::object::JsArrayBuffer;
-use crate::{run_test_actions, Context, TestAction};
+use crate::{run_test_actions, Context, JsNativeErrorKind, TestAction};
 
 #[test]
 fn create_byte_data_block() {
@@ -80,6 +80,32 @@ fn get_values() {
     ]);
 }
 
+#[test]
+fn float16_values() {
+    run_test_actions([
+        TestAction::run(
+            r#"
+            var buffer = new ArrayBuffer(8);
+            var sample = new DataView(buffer, 0);
+            var array = new Float16Array(buffer);
+
+            sample.setFloat16(0, 1.5);
+            sample.setFloat16(2, -65504, true);
+            sample.setFloat16(4, 5.05);
+            array[3] = 1e10;
+        "#,
+        ),
+        TestAction::assert("sample.getUint16(0, false) == 0x3e00"),
+        TestAction::assert("sample.getFloat16(0) == 1.5"),
+        TestAction::assert("sample.getFloat16(0, true) != 1.5"),
+        TestAction::assert("sample.getUint16(2, true) == 0xfbff"),
+        TestAction::assert("array[1] == -65504"),
+        TestAction::assert("sample.getFloat16(4) == Math.f16round(5.05)"),
+        TestAction::assert("array[3] == Infinity"),
+        TestAction::assert("Float16Array.BYTES_PER_ELEMENT == 2"),
+    ]);
+}
+
 #[test]
 fn sort() {
     run_test_actions([
@@ -146,14 +172,80 @@ fn sort_negative_zero() {
                 return a.length === b.length && a.every((v, i) => v === b[i]);
             }
 
-            var TypedArrayCtor = [Float32Array, Float64Array];
+            var TypedArrayCtor = [Float16Array, Float32Array, Float64Array];
             var negativeZero = TypedArrayCtor.map((ctor) => new ctor([1, 0, -0, 2]).sort());
             var infinities = TypedArrayCtor.map((ctor) => new ctor([3, 4, Infinity, -Infinity, 1, 2]).sort());
         "#,
         ),
         TestAction::assert("cmp(negativeZero[0], [-0, 0, 1, 2])"),
         TestAction::assert("cmp(negativeZero[1], [-0, 0, 1, 2])"),
+        TestAction::assert("cmp(negativeZero[2], [-0, 0, 1, 2])"),
         TestAction::assert("cmp(infinities[0], [-Infinity, 1, 2, 3, 4, Infinity])"),
         TestAction::assert("cmp(infinities[1], [-Infinity, 1, 2, 3, 4, Infinity])"),
+        TestAction::assert("cmp(infinities[2], [-Infinity, 1, 2, 3, 4, Infinity])"),
+    ]);
+}
+
+#[test]
+fn uint8_array_base64() {
+    run_test_actions([
+        TestAction::run(
+            r#"
+            var bytes = new Uint8Array([251, 255, 191, 0, 1]);
+            var target = new Uint8Array(4);
+            var result = target.setFromBase64("Zm9v YmFy");
+        "#,
+        ),
+        TestAction::assert("bytes.toBase64() === '+/+/AAE='"),
+        TestAction::assert("bytes.toBase64({ alphabet: 'base64url' }) === '-_-_AAE='"),
+        TestAction::assert("bytes.toBase64({ omitPadding: true }) === '+/+/AAE'"),
+        TestAction::assert("Uint8Array.fromBase64('Zm9v\\nYmE=').join() === '102,111,111,98,97'"),
+        TestAction::assert("Uint8Array.fromBase64('Zm9vYmE').length === 5"),
+        TestAction::assert(
+            "Uint8Array.fromBase64('Zm9vYmE', { lastChunkHandling: 'stop-before-partial' }).length === 3",
+        ),
+        TestAction::assert("result.read === 4 && result.written === 3"),
+        TestAction::assert("target.join() === '102,111,111,0'"),
+        TestAction::assert_native_error(
+            "Uint8Array.fromBase64('Zm9vYmE', { lastChunkHandling: 'strict' })",
+            JsNativeErrorKind::Syntax,
+            "base64 string ends with an incomplete chunk",
+        ),
+        TestAction::assert_native_error(
+            "Uint8Array.fromBase64('Zm9v', { alphabet: 'base32' })",
+            JsNativeErrorKind::Type,
+            "alphabet must be either \"base64\" or \"base64url\"",
+        ),
+        TestAction::assert_native_error(
+            "Uint8Array.prototype.toBase64.call(new Int8Array(1))",
+            JsNativeErrorKind::Type,
+            "`this` is not a Uint8Array object",
+        ),
+    ]);
+}
+
+#[test]
+fn uint8_array_hex() {
+    run_test_actions([
+        TestAction::run(
+            r#"
+            var target = new Uint8Array(4);
+            var error;
+            try {
+                target.setFromHex("cafe0x");
+            } catch (e) {
+                error = e;
+            }
+        "#,
+        ),
+        TestAction::assert("new Uint8Array([0, 15, 171, 255]).toHex() === '000fabff'"),
+        TestAction::assert("Uint8Array.fromHex('C0FFee').join() === '192,255,238'"),
+        TestAction::assert("error instanceof SyntaxError"),
+        TestAction::assert("target.join() === '202,254,0,0'"),
+        TestAction::assert_native_error(
+            "Uint8Array.fromHex('abc')",
+            JsNativeErrorKind::Syntax,
+            "hex string must have an even length",
+        ),
     ]);
 }
@@ -0,0 +1,452 @@
+//! Boa's implementation of ECMAScript's global `AsyncDisposableStack` object.
+//!
+//! The `AsyncDisposableStack` object aggregates asynchronously disposable resources and
+//! disposes all of them, in reverse order, when it is itself disposed.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncDisposableStack
+
+use crate::{
+    builtins::{
+        disposable_stack::{
+            adopt_closure, DisposableResource, DisposableState, DisposeCapability, DisposeHint,
+        },
+        promise::{Promise, ResolvingFunctions},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    native_function::NativeFunction,
+    object::{
+        internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsObject,
+        JsPromise,
+    },
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    Context, JsArgs, JsData, JsError, JsNativeError, JsResult, JsString, JsSymbol, JsValue,
+};
+use boa_gc::{Finalize, Gc, GcRefCell, Trace};
+use boa_profiler::Profiler;
+
+/// The internal representation of an `AsyncDisposableStack` object.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct AsyncDisposableStack {
+    state: DisposableState,
+    capability: DisposeCapability,
+}
+
+impl IntrinsicObject for AsyncDisposableStack {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let get_disposed = BuiltInBuilder::callable(realm, Self::get_disposed)
+            .name(js_string!("get disposed"))
+            .build();
+
+        let dispose_async = BuiltInBuilder::callable(realm, Self::dispose_async)
+            .name(js_string!("disposeAsync"))
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .method(Self::adopt, js_string!("adopt"), 2)
+            .method(Self::defer, js_string!("defer"), 1)
+            .property(
+                js_string!("disposeAsync"),
+                dispose_async.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                js_string!("disposed"),
+                Some(get_disposed),
+                None,
+                Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+            )
+            .method(Self::r#move, js_string!("move"), 0)
+            .method(Self::r#use, js_string!("use"), 1)
+            .property(
+                JsSymbol::async_dispose(),
+                dispose_async,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for AsyncDisposableStack {
+    const NAME: JsString = StaticJsStrings::ASYNC_DISPOSABLE_STACK;
+}
+
+impl BuiltInConstructor for AsyncDisposableStack {
+    const LENGTH: usize = 0;
+    const P: usize = 8;
+    const SP: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::async_disposable_stack;
+
+    /// [`AsyncDisposableStack ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("AsyncDisposableStack: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. Let asyncDisposableStack be ? OrdinaryCreateFromConstructor(NewTarget, "%AsyncDisposableStack.prototype%", « [[AsyncDisposableState]], [[DisposeCapability]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::async_disposable_stack,
+            context,
+        )?;
+
+        // 3. Set asyncDisposableStack.[[AsyncDisposableState]] to pending.
+        // 4. Set asyncDisposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 5. Return asyncDisposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self::new(DisposeCapability::default()),
+        )
+        .into())
+    }
+}
+
+impl AsyncDisposableStack {
+    /// Creates a new pending `AsyncDisposableStack` with the provided capability.
+    const fn new(capability: DisposeCapability) -> Self {
+        Self {
+            state: DisposableState::Pending,
+            capability,
+        }
+    }
+
+    /// Abstract operation `RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]])`.
+    fn this_stack(this: &JsValue, method: &str) -> JsResult<JsObject<Self>> {
+        this.as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "AsyncDisposableStack.prototype.{method}: `this` is not an AsyncDisposableStack"
+                    ))
+                    .into()
+            })
+    }
+
+    /// Throws a `ReferenceError` if the stack was already disposed.
+    fn check_pending(stack: &JsObject<Self>, method: &str) -> JsResult<()> {
+        if stack.borrow().data.state == DisposableState::Disposed {
+            return Err(JsNativeError::reference()
+                .with_message(format!(
+                    "AsyncDisposableStack.prototype.{method}: the stack was already disposed"
+                ))
+                .into());
+        }
+        Ok(())
+    }
+
+    /// [`get AsyncDisposableStack.prototype.disposed`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-get-asyncdisposablestack.prototype.disposed
+    fn get_disposed(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_stack(this, "disposed")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, return true.
+        // 4. Otherwise, return false.
+        let disposed = stack.borrow().data.state == DisposableState::Disposed;
+        Ok(disposed.into())
+    }
+
+    /// [`AsyncDisposableStack.prototype.disposeAsync ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.disposeAsync
+    fn dispose_async(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let (promise, resolvers) = JsPromise::new_pending(context);
+
+        // 3. If asyncDisposableStack does not have an [[AsyncDisposableState]] internal slot, then
+        let stack = match Self::this_stack(this, "disposeAsync") {
+            Ok(stack) => stack,
+            Err(err) => {
+                // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+                // b. Return promiseCapability.[[Promise]].
+                let err = err.to_opaque(context);
+                resolvers
+                    .reject
+                    .call(&JsValue::undefined(), &[err], context)
+                    .expect("cannot fail per the spec");
+                return Ok(promise.into());
+            }
+        };
+
+        let capability = {
+            let mut stack = stack.borrow_mut();
+
+            // 4. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, then
+            if stack.data.state == DisposableState::Disposed {
+                drop(stack);
+
+                // a. Perform ! Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+                // b. Return promiseCapability.[[Promise]].
+                resolvers
+                    .resolve
+                    .call(&JsValue::undefined(), &[JsValue::undefined()], context)
+                    .expect("cannot fail per the spec");
+                return Ok(promise.into());
+            }
+
+            // 5. Set asyncDisposableStack.[[AsyncDisposableState]] to disposed.
+            stack.data.state = DisposableState::Disposed;
+            std::mem::take(&mut stack.data.capability)
+        };
+
+        // 6. Let result be DisposeResources(asyncDisposableStack.[[DisposeCapability]], NormalCompletion(undefined)).
+        // 7. IfAbruptRejectPromise(result, promiseCapability).
+        // 8. Perform ! Call(promiseCapability.[[Resolve]], undefined, « result »).
+        // 9. Return promiseCapability.[[Promise]].
+        continue_dispose_async(&Gc::new(GcRefCell::new(capability)), &resolvers, context)?;
+
+        Ok(promise.into())
+    }
+
+    /// [`AsyncDisposableStack.prototype.use ( value )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.use
+    fn r#use(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_stack(this, "use")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        Self::check_pending(&stack, "use")?;
+
+        // 4. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], value, async-dispose).
+        let value = args.get_or_undefined(0).clone();
+        if let Some(resource) =
+            DisposableResource::create(value.clone(), DisposeHint::Async, None, context)?
+        {
+            stack.borrow_mut().data.capability.add_resource(resource);
+        }
+
+        // 5. Return value.
+        Ok(value)
+    }
+
+    /// [`AsyncDisposableStack.prototype.adopt ( value, onDisposeAsync )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.adopt
+    fn adopt(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_stack(this, "adopt")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        Self::check_pending(&stack, "adopt")?;
+
+        // 4. If IsCallable(onDisposeAsync) is false, throw a TypeError exception.
+        let value = args.get_or_undefined(0).clone();
+        let on_dispose_async =
+            args.get_or_undefined(1)
+                .as_callable()
+                .cloned()
+                .ok_or_else(|| {
+                    JsNativeError::typ().with_message(
+                        "AsyncDisposableStack.prototype.adopt: `onDisposeAsync` is not callable",
+                    )
+                })?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures value and onDisposeAsync and performs the following steps when called:
+        //     a. Return ? Call(onDisposeAsync, undefined, « value »).
+        // 6. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+        let f = adopt_closure(value.clone(), on_dispose_async, context);
+
+        // 7. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], undefined, async-dispose, F).
+        if let Some(resource) =
+            DisposableResource::create(JsValue::undefined(), DisposeHint::Async, Some(f), context)?
+        {
+            stack.borrow_mut().data.capability.add_resource(resource);
+        }
+
+        // 8. Return value.
+        Ok(value)
+    }
+
+    /// [`AsyncDisposableStack.prototype.defer ( onDisposeAsync )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.defer
+    fn defer(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_stack(this, "defer")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        Self::check_pending(&stack, "defer")?;
+
+        // 4. If IsCallable(onDisposeAsync) is false, throw a TypeError exception.
+        let on_dispose_async =
+            args.get_or_undefined(0)
+                .as_callable()
+                .cloned()
+                .ok_or_else(|| {
+                    JsNativeError::typ().with_message(
+                        "AsyncDisposableStack.prototype.defer: `onDisposeAsync` is not callable",
+                    )
+                })?;
+
+        // 5. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], undefined, async-dispose, onDisposeAsync).
+        if let Some(resource) = DisposableResource::create(
+            JsValue::undefined(),
+            DisposeHint::Async,
+            Some(on_dispose_async),
+            context,
+        )? {
+            stack.borrow_mut().data.capability.add_resource(resource);
+        }
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// [`AsyncDisposableStack.prototype.move ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.move
+    fn r#move(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_stack(this, "move")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        Self::check_pending(&stack, "move")?;
+
+        // 4. Let newAsyncDisposableStack be ? OrdinaryCreateFromConstructor(%AsyncDisposableStack%, "%AsyncDisposableStack.prototype%", « [[AsyncDisposableState]], [[DisposeCapability]] »).
+        // 5. Set newAsyncDisposableStack.[[AsyncDisposableState]] to pending.
+        // 6. Set newAsyncDisposableStack.[[DisposeCapability]] to asyncDisposableStack.[[DisposeCapability]].
+        // 7. Set asyncDisposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 8. Set asyncDisposableStack.[[AsyncDisposableState]] to disposed.
+        let capability = {
+            let mut stack = stack.borrow_mut();
+            stack.data.state = DisposableState::Disposed;
+            std::mem::take(&mut stack.data.capability)
+        };
+        let prototype = context
+            .intrinsics()
+            .constructors()
+            .async_disposable_stack()
+            .prototype();
+
+        // 9. Return newAsyncDisposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self::new(capability),
+        )
+        .into())
+    }
+}
+
+/// Continues the `DisposeResources` operation of `capability`, chaining the values that need to
+/// be awaited and settling the promise of `resolvers` once all resources were disposed.
+fn continue_dispose_async(
+    capability: &Gc<GcRefCell<DisposeCapability>>,
+    resolvers: &ResolvingFunctions,
+    context: &mut Context,
+) -> JsResult<()> {
+    // Take the capability out of the cell, since disposing can call arbitrary code.
+    let mut inner = std::mem::take(&mut *capability.borrow_mut());
+
+    let Some(value) = inner.dispose_step(context)? else {
+        return match inner.into_completion(Ok(()), context) {
+            Ok(()) => resolvers
+                .resolve
+                .call(&JsValue::undefined(), &[JsValue::undefined()], context)
+                .map(|_| ()),
+            Err(err) => {
+                let err = err.to_opaque(context);
+                resolvers
+                    .reject
+                    .call(&JsValue::undefined(), &[err], context)
+                    .map(|_| ())
+            }
+        };
+    };
+
+    *capability.borrow_mut() = inner;
+
+    // Await(value)
+    let promise = match Promise::promise_resolve(
+        &context.intrinsics().constructors().promise().constructor(),
+        value,
+        context,
+    ) {
+        Ok(promise) => promise,
+        Err(err) => {
+            capability.borrow_mut().record_error(err);
+            return continue_dispose_async(capability, resolvers, context);
+        }
+    };
+
+    let on_fulfilled = FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |_, _, (capability, resolvers), context| {
+                continue_dispose_async(capability, resolvers, context)?;
+                Ok(JsValue::undefined())
+            },
+            (capability.clone(), resolvers.clone()),
+        ),
+    )
+    .name(js_string!())
+    .length(1)
+    .build();
+
+    let on_rejected = FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |_, args, (capability, resolvers), context| {
+                capability
+                    .borrow_mut()
+                    .record_error(JsError::from_opaque(args.get_or_undefined(0).clone()));
+                continue_dispose_async(capability, resolvers, context)?;
+                Ok(JsValue::undefined())
+            },
+            (capability.clone(), resolvers.clone()),
+        ),
+    )
+    .name(js_string!())
+    .length(1)
+    .build();
+
+    Promise::perform_promise_then(
+        &promise,
+        Some(on_fulfilled),
+        Some(on_rejected),
+        None,
+        context,
+    );
+
+    Ok(())
+}
@@ -137,6 +137,7 @@ macro_rules! atomic_op {
                         .$name(num, Ordering::SeqCst)
                         .into(),
                     TypedArrayElement::Uint8Clamped(_)
+                    | TypedArrayElement::Float16(_)
                     | TypedArrayElement::Float32(_)
                     | TypedArrayElement::Float64(_) => unreachable!(
                         "must have been filtered out by the call to `validate_integer_typed_array`"
@@ -333,6 +334,7 @@ impl Atomics {
                     .compare_exchange(exp, rep, Ordering::SeqCst)
                     .into(),
                 TypedArrayKind::Uint8Clamped
+                | TypedArrayKind::Float16
                 | TypedArrayKind::Float32
                 | TypedArrayKind::Float64 => unreachable!(
                     "must have been filtered out by the call to `validate_integer_typed_array`"
@@ -178,6 +178,7 @@ pub(crate) struct BuiltInConstructorWithPrototype<'ctx> {
     __proto__: JsPrototype,
     inherits: Option<JsObject>,
     attributes: Attribute,
+    constructor_accessor: Option<(JsFunction, JsFunction)>,
 }
 
 #[allow(dead_code)]
@@ -362,6 +363,13 @@ impl BuiltInConstructorWithPrototype<'_> {
         self
     }
 
+    /// Defines the prototype's "constructor" property as an accessor property with the given
+    /// getter and setter, instead of a data property.
+    pub(crate) fn constructor_accessor(mut self, get: JsFunction, set: JsFunction) -> Self {
+        self.constructor_accessor = Some((get, set));
+        self
+    }
+
     pub(crate) fn build(mut self) {
         let length = self.length;
         let name = self.name.clone();
@@ -371,8 +379,12 @@ impl BuiltInConstructorWithPrototype<'_> {
         self = self.static_property(PROTOTYPE, prototype, Attribute::empty());
 
         let attributes = self.attributes;
-        let object = self.object.clone();
-        self = self.property(CONSTRUCTOR, object, attributes);
+        if let Some((get, set)) = self.constructor_accessor.take() {
+            self = self.accessor(CONSTRUCTOR, Some(get), Some(set), Attribute::CONFIGURABLE);
+        } else {
+            let object = self.object.clone();
+            self = self.property(CONSTRUCTOR, object, attributes);
+        }
 
         {
             let mut prototype = self.prototype.borrow_mut();
@@ -548,6 +560,7 @@ impl<'ctx> BuiltInBuilder<'ctx, Callable<Constructor>> {
             __proto__: Some(realm.intrinsics().constructors().function().prototype()),
             inherits: Some(realm.intrinsics().constructors().object().prototype()),
             attributes: Attribute::WRITABLE | Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+            constructor_accessor: None,
         }
     }
 }
@@ -30,7 +30,7 @@ use super::{
         utils::{memcpy, BytesConstPtr, BytesMutPtr},
         BufferObject,
     },
-    typed_array::{self, TypedArrayElement},
+    typed_array::{self, Float16, TypedArrayElement},
     BuiltInBuilder, BuiltInConstructor, IntrinsicObject,
 };
 
@@ -130,6 +130,7 @@ impl IntrinsicObject for DataView {
             )
             .method(Self::get_big_int64, js_string!("getBigInt64"), 1)
             .method(Self::get_big_uint64, js_string!("getBigUint64"), 1)
+            .method(Self::get_float16, js_string!("getFloat16"), 1)
             .method(Self::get_float32, js_string!("getFloat32"), 1)
             .method(Self::get_float64, js_string!("getFloat64"), 1)
             .method(Self::get_int8, js_string!("getInt8"), 1)
@@ -140,6 +141,7 @@ impl IntrinsicObject for DataView {
             .method(Self::get_uint32, js_string!("getUint32"), 1)
             .method(Self::set_big_int64, js_string!("setBigInt64"), 2)
             .method(Self::set_big_uint64, js_string!("setBigUint64"), 2)
+            .method(Self::set_float16, js_string!("setFloat16"), 2)
             .method(Self::set_float32, js_string!("setFloat32"), 2)
             .method(Self::set_float64, js_string!("setFloat64"), 2)
             .method(Self::set_int8, js_string!("setInt8"), 2)
@@ -167,7 +169,7 @@ impl BuiltInObject for DataView {
 
 impl BuiltInConstructor for DataView {
     const LENGTH: usize = 1;
-    const P: usize = 24;
+    const P: usize = 26;
     const SP: usize = 0;
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
@@ -552,6 +554,30 @@ impl DataView {
         Self::get_view_value::<u64>(this, byte_offset, is_little_endian, context)
     }
 
+    /// `DataView.prototype.getFloat16 ( byteOffset [ , littleEndian ] )`
+    ///
+    /// The `getFloat16()` method gets a 16-bit float (half) at the specified byte offset
+    /// from the start of the `DataView`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getfloat16
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getFloat16
+    pub(crate) fn get_float16(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let byte_offset = args.get_or_undefined(0);
+        let is_little_endian = args.get_or_undefined(1);
+        // 1. Let v be the this value.
+        // 2. If littleEndian is not present, set littleEndian to false.
+        // 3. Return ? GetViewValue(v, byteOffset, littleEndian, Float16).
+        Self::get_view_value::<Float16>(this, byte_offset, is_little_endian, context)
+    }
+
     /// `DataView.prototype.getBigUint64 ( byteOffset [ , littleEndian ] )`
     ///
     /// The `getFloat32()` method gets a signed 32-bit float (float) at the specified byte offset
@@ -875,6 +901,31 @@ impl DataView {
         Self::set_view_value::<u64>(this, byte_offset, is_little_endian, value, context)
     }
 
+    /// `DataView.prototype.setFloat16 ( byteOffset, value [ , littleEndian ] )`
+    ///
+    /// The `setFloat16()` method stores a 16-bit float (half) value at the specified byte
+    /// offset from the start of the `DataView`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setfloat16
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setFloat16
+    pub(crate) fn set_float16(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let byte_offset = args.get_or_undefined(0);
+        let value = args.get_or_undefined(1);
+        let is_little_endian = args.get_or_undefined(2);
+        // 1. Let v be the this value.
+        // 2. If littleEndian is not present, set littleEndian to false.
+        // 3. Return ? SetViewValue(v, byteOffset, littleEndian, Float16, value).
+        Self::set_view_value::<Float16>(this, byte_offset, is_little_endian, value, context)
+    }
+
     /// `DataView.prototype.setFloat32 ( byteOffset, value [ , littleEndian ] )`
     ///
     /// The `setFloat32()` method stores a signed 32-bit float (float) value at the specified byte
@@ -27,7 +27,7 @@ use crate::{
     string::StaticJsStrings,
     symbol::JsSymbol,
     value::{JsValue, PreferredType},
-    Context, JsArgs, JsData, JsError, JsResult, JsString,
+    Context, JsArgs, JsData, JsResult, JsString,
 };
 use boa_gc::{Finalize, Trace};
 use boa_macros::js_str;
@@ -49,6 +49,11 @@ impl Date {
         Self(dt)
     }
 
+    /// Gets the time value of the `Date`.
+    pub(crate) const fn time_value(self) -> f64 {
+        self.0
+    }
+
     /// Creates a new `Date` from the current UTC time of the host.
     pub(crate) fn utc_now(context: &mut Context) -> Self {
         Self(context.clock().now().millis_since_epoch() as f64)
@@ -1597,55 +1602,131 @@ impl Date {
     /// More information:
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocaledatestring
+    /// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocaledatestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleDateString
     pub(crate) fn to_locale_date_string(
-        _this: &JsValue,
-        _args: &[JsValue],
-        _context: &mut Context,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
     ) -> JsResult<JsValue> {
-        Err(JsError::from_opaque(JsValue::new(js_string!(
-            "Function Unimplemented"
-        ))))
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::{format_date_time_locale, DateTimeReqs};
+
+            // 1. Let dateObject be the this value.
+            // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+            // 3. Let x be dateObject.[[DateValue]].
+            let tv = this
+                .as_object()
+                .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
+                .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
+                .0;
+
+            format_date_time_locale(
+                tv,
+                args.get_or_undefined(0),
+                args.get_or_undefined(1),
+                DateTimeReqs::Date,
+                context,
+            )
+        }
+
+        // Without `Intl`, the output is implementation-defined, so this returns the same string
+        // as `Date.prototype.toDateString`.
+        #[cfg(not(feature = "intl"))]
+        {
+            Self::to_date_string(this, args, context)
+        }
     }
 
     /// [`Date.prototype.toLocaleString()`][spec].
     ///
-    /// The `toLocaleString()` method returns a string representing the specified Date object.
+    /// The `toLocaleString()` method returns a string representing the specified Date object
+    /// according to language-specific conventions.
     ///
     /// More information:
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocalestring
+    /// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocalestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleString
     pub(crate) fn to_locale_string(
-        _this: &JsValue,
-        _: &[JsValue],
-        _context: &mut Context,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
     ) -> JsResult<JsValue> {
-        Err(JsError::from_opaque(JsValue::new(js_string!(
-            "Function Unimplemented]"
-        ))))
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::{format_date_time_locale, DateTimeReqs};
+
+            // 1. Let dateObject be the this value.
+            // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+            // 3. Let x be dateObject.[[DateValue]].
+            let tv = this
+                .as_object()
+                .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
+                .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
+                .0;
+
+            format_date_time_locale(
+                tv,
+                args.get_or_undefined(0),
+                args.get_or_undefined(1),
+                DateTimeReqs::AnyAll,
+                context,
+            )
+        }
+
+        // Without `Intl`, the output is implementation-defined, so this returns the same string
+        // as `Date.prototype.toString`.
+        #[cfg(not(feature = "intl"))]
+        {
+            Self::to_string(this, args, context)
+        }
     }
 
     /// [`Date.prototype.toLocaleTimeString()`][spec].
     ///
-    /// The `toLocaleTimeString()` method returns the time portion of a Date object in human readable
-    /// form in American English.
+    /// The `toLocaleTimeString()` method returns the time portion of a Date object according to
+    /// language-specific conventions.
     ///
     /// More information:
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocaletimestring
+    /// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocaletimestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleTimeString
     pub(crate) fn to_locale_time_string(
-        _this: &JsValue,
-        _args: &[JsValue],
-        _context: &mut Context,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
     ) -> JsResult<JsValue> {
-        Err(JsError::from_opaque(JsValue::new(js_string!(
-            "Function Unimplemented]"
-        ))))
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::{format_date_time_locale, DateTimeReqs};
+
+            // 1. Let dateObject be the this value.
+            // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+            // 3. Let x be dateObject.[[DateValue]].
+            let tv = this
+                .as_object()
+                .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
+                .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
+                .0;
+
+            format_date_time_locale(
+                tv,
+                args.get_or_undefined(0),
+                args.get_or_undefined(1),
+                DateTimeReqs::Time,
+                context,
+            )
+        }
+
+        // Without `Intl`, the output is implementation-defined, so this returns the same string
+        // as `Date.prototype.toTimeString`.
+        #[cfg(not(feature = "intl"))]
+        {
+            Self::to_time_string(this, args, context)
+        }
     }
 
     /// [`Date.prototype.toString()`][spec].
@@ -0,0 +1,348 @@
+//! This module implements the `DisposeCapability` record and its abstract operations, shared by
+//! `using` declarations, `DisposableStack` and `AsyncDisposableStack`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposecapability-records
+
+use crate::{
+    builtins::{error::SuppressedError, promise::PromiseCapability},
+    js_string,
+    native_function::NativeFunction,
+    object::{FunctionObjectBuilder, JsObject},
+    Context, JsError, JsNativeError, JsResult, JsSymbol, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+
+/// The hint used to retrieve the dispose method of a resource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Trace, Finalize)]
+#[boa_gc(empty_trace)]
+pub(crate) enum DisposeHint {
+    /// Dispose the resource using its `@@dispose` method.
+    Sync,
+
+    /// Dispose the resource using its `@@asyncDispose` method, or its `@@dispose` method as a
+    /// fallback, and await the result.
+    Async,
+}
+
+/// A [`DisposableResource`][spec] record.
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposableresource-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct DisposableResource {
+    value: JsValue,
+    hint: DisposeHint,
+    method: Option<JsObject>,
+}
+
+impl DisposableResource {
+    /// Creates the resource to add to a [`DisposeCapability`], following the steps of
+    /// [`AddDisposableResource ( disposeCapability, V, hint [ , method ] )`][spec] up to the
+    /// point where the resource is appended to the capability.
+    ///
+    /// Returns `None` if there is nothing to dispose.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-adddisposableresource
+    pub(crate) fn create(
+        value: JsValue,
+        hint: DisposeHint,
+        method: Option<JsObject>,
+        context: &mut Context,
+    ) -> JsResult<Option<Self>> {
+        // 1. If method is not present, then
+        if method.is_none() {
+            // a. If V is either null or undefined and hint is sync-dispose, return unused.
+            if value.is_null_or_undefined() && hint == DisposeHint::Sync {
+                return Ok(None);
+            }
+
+            // b. NOTE: When V is either null or undefined and hint is async-dispose, we record that the resource was
+            //    evaluated to ensure we will still perform an Await when resources are later disposed.
+            // c. Let resource be ? CreateDisposableResource(V, hint).
+            return Self::new(value, hint, None, context).map(Some);
+        }
+
+        // 2. Else,
+        //     a. Assert: V is undefined.
+        debug_assert!(value.is_undefined());
+
+        //     b. Let resource be ? CreateDisposableResource(undefined, hint, method).
+        Self::new(JsValue::undefined(), hint, method, context).map(Some)
+    }
+
+    /// Abstract operation [`CreateDisposableResource ( V, hint [ , method ] )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-createdisposableresource
+    fn new(
+        value: JsValue,
+        hint: DisposeHint,
+        method: Option<JsObject>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 1. If method is not present, then
+        let Some(method) = method else {
+            // a. If V is either null or undefined, then
+            if value.is_null_or_undefined() {
+                // i. Set V to undefined.
+                // ii. Set method to undefined.
+                return Ok(Self {
+                    value: JsValue::undefined(),
+                    hint,
+                    method: None,
+                });
+            }
+
+            // b. Else,
+            //     i. If V is not an Object, throw a TypeError exception.
+            if !value.is_object() {
+                return Err(JsNativeError::typ()
+                    .with_message("cannot dispose a value that is not an object")
+                    .into());
+            }
+
+            //     ii. Set method to ? GetDisposeMethod(V, hint).
+            //     iii. If method is undefined, throw a TypeError exception.
+            let method = get_dispose_method(&value, hint, context)?.ok_or_else(|| {
+                JsNativeError::typ().with_message(match hint {
+                    DisposeHint::Sync => "value does not have a `Symbol.dispose` method",
+                    DisposeHint::Async => {
+                        "value does not have a `Symbol.asyncDispose` or `Symbol.dispose` method"
+                    }
+                })
+            })?;
+
+            // 3. Return the DisposableResource Record { [[ResourceValue]]: V, [[Hint]]: hint, [[DisposeMethod]]: method }.
+            return Ok(Self {
+                value,
+                hint,
+                method: Some(method),
+            });
+        };
+
+        // 2. Else,
+        //     a. If IsCallable(method) is false, throw a TypeError exception.
+        if !method.is_callable() {
+            return Err(JsNativeError::typ()
+                .with_message("dispose method is not callable")
+                .into());
+        }
+
+        // 3. Return the DisposableResource Record { [[ResourceValue]]: V, [[Hint]]: hint, [[DisposeMethod]]: method }.
+        Ok(Self {
+            value,
+            hint,
+            method: Some(method),
+        })
+    }
+}
+
+/// Abstract operation [`GetDisposeMethod ( V, hint )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-getdisposemethod
+fn get_dispose_method(
+    value: &JsValue,
+    hint: DisposeHint,
+    context: &mut Context,
+) -> JsResult<Option<JsObject>> {
+    // 1. If hint is async-dispose, then
+    if hint == DisposeHint::Async {
+        // a. Let method be ? GetMethod(V, @@asyncDispose).
+        if let Some(method) = value.get_method(JsSymbol::async_dispose(), context)? {
+            return Ok(Some(method));
+        }
+
+        // b. If method is undefined, then
+        //     i. Set method to ? GetMethod(V, @@dispose).
+        //     ii. If method is not undefined, then
+        let Some(method) = value.get_method(JsSymbol::dispose(), context)? else {
+            return Ok(None);
+        };
+
+        // 1. Let closure be a new Abstract Closure with no parameters that captures method and performs the following steps when called:
+        // 2. NOTE: This function is not observable to user code. It is used to ensure that a Promise returned from a
+        //    synchronous @@dispose method will not be awaited and that any exception thrown will not be thrown synchronously.
+        // 3. Return CreateBuiltinFunction(closure, 0, "", « »).
+        let closure = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |this, _, method, context| {
+                    // a. Let O be the this value.
+                    // b. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+                    let capability = PromiseCapability::new(
+                        &context.intrinsics().constructors().promise().constructor(),
+                        context,
+                    )
+                    .expect("cannot fail for the %Promise% intrinsic");
+
+                    // c. Let result be Completion(Call(method, O)).
+                    // d. IfAbruptRejectPromise(result, promiseCapability).
+                    if let Err(err) = method.call(this, &[], context) {
+                        let err = err.to_opaque(context);
+                        capability
+                            .reject()
+                            .call(&JsValue::undefined(), &[err], context)?;
+                        return Ok(capability.promise().clone().into());
+                    }
+
+                    // e. Perform ? Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+                    capability.resolve().call(
+                        &JsValue::undefined(),
+                        &[JsValue::undefined()],
+                        context,
+                    )?;
+
+                    // f. Return promiseCapability.[[Promise]].
+                    Ok(capability.promise().clone().into())
+                },
+                method,
+            ),
+        )
+        .name(js_string!())
+        .length(0)
+        .build();
+
+        return Ok(Some(closure.into()));
+    }
+
+    // 2. Else,
+    //     a. Let method be ? GetMethod(V, @@dispose).
+    // 3. Return method.
+    value.get_method(JsSymbol::dispose(), context)
+}
+
+/// A [`DisposeCapability`][spec] record.
+///
+/// Besides the stack of resources, this also keeps the state of an in-progress
+/// [`DisposeResources`][dispose] operation, which allows suspending it to await the result of
+/// asynchronous dispose methods.
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposecapability-records
+/// [dispose]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+#[derive(Debug, Clone, Default, Trace, Finalize)]
+pub(crate) struct DisposeCapability {
+    resources: Vec<DisposableResource>,
+    needs_await: bool,
+    has_awaited: bool,
+    errors: Vec<JsError>,
+}
+
+impl DisposeCapability {
+    /// Appends `resource` to the stack of resources of this capability.
+    ///
+    /// This is the last step of [`AddDisposableResource`][spec], split from
+    /// [`DisposableResource::create`] so that no borrow of the capability is held while the
+    /// dispose method of the resource is retrieved.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-adddisposableresource
+    pub(crate) fn add_resource(&mut self, resource: DisposableResource) {
+        // 3. Append resource to disposeCapability.[[DisposableResourceStack]].
+        self.resources.push(resource);
+    }
+
+    /// Runs the loop of the [`DisposeResources`][spec] abstract operation.
+    ///
+    /// Returns `Some(value)` when `value` must be awaited before continuing the loop, and `None`
+    /// once all resources have been disposed. Throw completions of the dispose methods are
+    /// recorded by the capability, to be folded by [`DisposeCapability::into_completion`];
+    /// only uncatchable errors are returned.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    pub(crate) fn dispose_step(&mut self, context: &mut Context) -> JsResult<Option<JsValue>> {
+        // 3. For each element resource of disposeCapability.[[DisposableResourceStack]], in reverse list order, do
+        while let Some(resource) = self.resources.pop() {
+            // a. Let value be resource.[[ResourceValue]].
+            // b. Let hint be resource.[[Hint]].
+            // c. Let method be resource.[[DisposeMethod]].
+            // d. If hint is sync-dispose and needsAwait is true and hasAwaited is false, then
+            if resource.hint == DisposeHint::Sync && self.needs_await && !self.has_awaited {
+                // i. Perform ! Await(undefined).
+                // ii. Set needsAwait to false.
+                self.needs_await = false;
+                self.resources.push(resource);
+                return Ok(Some(JsValue::undefined()));
+            }
+
+            // e. If method is not undefined, then
+            if let Some(method) = &resource.method {
+                // i. Let result be Completion(Call(method, value)).
+                match method.call(&resource.value, &[], context) {
+                    // ii. If result is a normal completion and hint is async-dispose, then
+                    Ok(result) if resource.hint == DisposeHint::Async => {
+                        // 1. Set result to Completion(Await(result.[[Value]])).
+                        // 2. Set hasAwaited to true.
+                        self.has_awaited = true;
+                        return Ok(Some(result));
+                    }
+                    Ok(_) => {}
+                    // iii. If result is a throw completion, then
+                    Err(err) if err.is_catchable() => self.errors.push(err),
+                    Err(err) => return Err(err),
+                }
+            } else {
+                // f. Else,
+                //     i. Assert: hint is async-dispose.
+                debug_assert_eq!(resource.hint, DisposeHint::Async);
+
+                //     ii. Set needsAwait to true.
+                //     iii. NOTE: This can only indicate a case where either null or undefined was the initialized
+                //          value of an await using declaration.
+                self.needs_await = true;
+            }
+        }
+
+        // 4. If needsAwait is true and hasAwaited is false, then
+        if self.needs_await && !self.has_awaited {
+            // a. Perform ! Await(undefined).
+            self.needs_await = false;
+            return Ok(Some(JsValue::undefined()));
+        }
+
+        Ok(None)
+    }
+
+    /// Records the throw completion of awaiting the result of an asynchronous dispose method.
+    pub(crate) fn record_error(&mut self, error: JsError) {
+        self.errors.push(error);
+    }
+
+    /// Folds the errors recorded while disposing the resources into `completion`, wrapping each
+    /// one in a `SuppressedError` if there was already a throw completion.
+    ///
+    /// This finishes the [`DisposeResources`][spec] abstract operation.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    pub(crate) fn into_completion(
+        mut self,
+        mut completion: JsResult<()>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        for error in std::mem::take(&mut self.errors) {
+            // iii. If result is a throw completion, then
+            completion = match completion {
+                // 1. If completion is a throw completion, then
+                Err(suppressed) => {
+                    // a. Set result to result.[[Value]].
+                    // b. Let suppressed be completion.[[Value]].
+                    // c. Let error be a newly created SuppressedError object.
+                    // d. Perform CreateNonEnumerableDataPropertyOrThrow(error, "error", result).
+                    // e. Perform CreateNonEnumerableDataPropertyOrThrow(error, "suppressed", suppressed).
+                    // f. Set completion to ThrowCompletion(error).
+                    let error = error.to_opaque(context);
+                    let suppressed = suppressed.to_opaque(context);
+                    Err(JsError::from_opaque(
+                        SuppressedError::create(error, suppressed, context).into(),
+                    ))
+                }
+                // 2. Else,
+                //     a. Set completion to result.
+                Ok(()) => Err(error),
+            };
+        }
+
+        // 6. Set disposeCapability.[[DisposableResourceStack]] to a new empty List.
+        // 7. Return ? completion.
+        completion
+    }
+}
@@ -0,0 +1,374 @@
+//! Boa's implementation of ECMAScript's global `DisposableStack` object.
+//!
+//! The `DisposableStack` object aggregates disposable resources and disposes all of them,
+//! in reverse order, when it is itself disposed.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DisposableStack
+
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    native_function::NativeFunction,
+    object::{internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsSymbol, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+mod capability;
+
+pub(crate) use capability::{DisposableResource, DisposeCapability, DisposeHint};
+
+#[cfg(test)]
+mod tests;
+
+/// The `[[DisposableState]]` internal slot of disposable stacks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Trace, Finalize)]
+#[boa_gc(empty_trace)]
+pub(crate) enum DisposableState {
+    Pending,
+    Disposed,
+}
+
+/// The internal representation of a `DisposableStack` object.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct DisposableStack {
+    state: DisposableState,
+    capability: DisposeCapability,
+}
+
+impl IntrinsicObject for DisposableStack {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let get_disposed = BuiltInBuilder::callable(realm, Self::get_disposed)
+            .name(js_string!("get disposed"))
+            .build();
+
+        let dispose = BuiltInBuilder::callable(realm, Self::dispose)
+            .name(js_string!("dispose"))
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .method(Self::adopt, js_string!("adopt"), 2)
+            .method(Self::defer, js_string!("defer"), 1)
+            .property(
+                js_string!("dispose"),
+                dispose.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                js_string!("disposed"),
+                Some(get_disposed),
+                None,
+                Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+            )
+            .method(Self::r#move, js_string!("move"), 0)
+            .method(Self::r#use, js_string!("use"), 1)
+            .property(
+                JsSymbol::dispose(),
+                dispose,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DisposableStack {
+    const NAME: JsString = StaticJsStrings::DISPOSABLE_STACK;
+}
+
+impl BuiltInConstructor for DisposableStack {
+    const LENGTH: usize = 0;
+    const P: usize = 8;
+    const SP: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::disposable_stack;
+
+    /// [`DisposableStack ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("DisposableStack: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. Let disposableStack be ? OrdinaryCreateFromConstructor(NewTarget, "%DisposableStack.prototype%", « [[DisposableState]], [[DisposeCapability]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::disposable_stack,
+            context,
+        )?;
+
+        // 3. Set disposableStack.[[DisposableState]] to pending.
+        // 4. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 5. Return disposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self::new(DisposeCapability::default()),
+        )
+        .into())
+    }
+}
+
+impl DisposableStack {
+    /// Creates a new pending `DisposableStack` with the provided capability.
+    const fn new(capability: DisposeCapability) -> Self {
+        Self {
+            state: DisposableState::Pending,
+            capability,
+        }
+    }
+
+    /// Abstract operation `RequireInternalSlot(disposableStack, [[DisposableState]])`.
+    fn this_stack(this: &JsValue, method: &str) -> JsResult<JsObject<Self>> {
+        this.as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "DisposableStack.prototype.{method}: `this` is not a DisposableStack"
+                    ))
+                    .into()
+            })
+    }
+
+    /// Throws a `ReferenceError` if the stack was already disposed.
+    fn check_pending(stack: &JsObject<Self>, method: &str) -> JsResult<()> {
+        if stack.borrow().data.state == DisposableState::Disposed {
+            return Err(JsNativeError::reference()
+                .with_message(format!(
+                    "DisposableStack.prototype.{method}: the stack was already disposed"
+                ))
+                .into());
+        }
+        Ok(())
+    }
+
+    /// [`get DisposableStack.prototype.disposed`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-get-disposablestack.prototype.disposed
+    fn get_disposed(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "disposed")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, return true.
+        // 4. Otherwise, return false.
+        let disposed = stack.borrow().data.state == DisposableState::Disposed;
+        Ok(disposed.into())
+    }
+
+    /// [`DisposableStack.prototype.dispose ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.dispose
+    fn dispose(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "dispose")?;
+
+        let mut capability = {
+            let mut stack = stack.borrow_mut();
+
+            // 3. If disposableStack.[[DisposableState]] is disposed, return undefined.
+            if stack.data.state == DisposableState::Disposed {
+                return Ok(JsValue::undefined());
+            }
+
+            // 4. Set disposableStack.[[DisposableState]] to disposed.
+            stack.data.state = DisposableState::Disposed;
+            std::mem::take(&mut stack.data.capability)
+        };
+
+        // 5. Return ? DisposeResources(disposableStack.[[DisposeCapability]], NormalCompletion(undefined)).
+        let awaited = capability.dispose_step(context)?;
+        debug_assert!(
+            awaited.is_none(),
+            "a DisposableStack cannot contain async resources"
+        );
+        capability.into_completion(Ok(()), context)?;
+
+        Ok(JsValue::undefined())
+    }
+
+    /// [`DisposableStack.prototype.use ( value )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.use
+    fn r#use(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "use")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        Self::check_pending(&stack, "use")?;
+
+        // 4. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], value, sync-dispose).
+        let value = args.get_or_undefined(0).clone();
+        if let Some(resource) =
+            DisposableResource::create(value.clone(), DisposeHint::Sync, None, context)?
+        {
+            stack.borrow_mut().data.capability.add_resource(resource);
+        }
+
+        // 5. Return value.
+        Ok(value)
+    }
+
+    /// [`DisposableStack.prototype.adopt ( value, onDispose )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.adopt
+    fn adopt(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "adopt")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        Self::check_pending(&stack, "adopt")?;
+
+        // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+        let value = args.get_or_undefined(0).clone();
+        let on_dispose = args
+            .get_or_undefined(1)
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("DisposableStack.prototype.adopt: `onDispose` is not callable")
+            })?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures value and onDispose and performs the following steps when called:
+        //     a. Return ? Call(onDispose, undefined, « value »).
+        // 6. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+        let f = adopt_closure(value.clone(), on_dispose, context);
+
+        // 7. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], undefined, sync-dispose, F).
+        if let Some(resource) =
+            DisposableResource::create(JsValue::undefined(), DisposeHint::Sync, Some(f), context)?
+        {
+            stack.borrow_mut().data.capability.add_resource(resource);
+        }
+
+        // 8. Return value.
+        Ok(value)
+    }
+
+    /// [`DisposableStack.prototype.defer ( onDispose )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.defer
+    fn defer(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "defer")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        Self::check_pending(&stack, "defer")?;
+
+        // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+        let on_dispose = args
+            .get_or_undefined(0)
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("DisposableStack.prototype.defer: `onDispose` is not callable")
+            })?;
+
+        // 5. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], undefined, sync-dispose, onDispose).
+        if let Some(resource) = DisposableResource::create(
+            JsValue::undefined(),
+            DisposeHint::Sync,
+            Some(on_dispose),
+            context,
+        )? {
+            stack.borrow_mut().data.capability.add_resource(resource);
+        }
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// [`DisposableStack.prototype.move ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.move
+    fn r#move(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "move")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        Self::check_pending(&stack, "move")?;
+
+        // 4. Let newDisposableStack be ? OrdinaryCreateFromConstructor(%DisposableStack%, "%DisposableStack.prototype%", « [[DisposableState]], [[DisposeCapability]] »).
+        // 5. Set newDisposableStack.[[DisposableState]] to pending.
+        // 6. Set newDisposableStack.[[DisposeCapability]] to disposableStack.[[DisposeCapability]].
+        // 7. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 8. Set disposableStack.[[DisposableState]] to disposed.
+        let capability = {
+            let mut stack = stack.borrow_mut();
+            stack.data.state = DisposableState::Disposed;
+            std::mem::take(&mut stack.data.capability)
+        };
+        let prototype = context
+            .intrinsics()
+            .constructors()
+            .disposable_stack()
+            .prototype();
+
+        // 9. Return newDisposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self::new(capability),
+        )
+        .into())
+    }
+}
+
+/// Creates the closure used by `DisposableStack.prototype.adopt` and
+/// `AsyncDisposableStack.prototype.adopt`, which calls `on_dispose` with `value`.
+pub(crate) fn adopt_closure(
+    value: JsValue,
+    on_dispose: JsObject,
+    context: &mut Context,
+) -> JsObject {
+    FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |_, _, (value, on_dispose), context| {
+                on_dispose.call(&JsValue::undefined(), std::slice::from_ref(value), context)
+            },
+            (value, on_dispose),
+        ),
+    )
+    .name(js_string!())
+    .length(0)
+    .build()
+    .into()
+}
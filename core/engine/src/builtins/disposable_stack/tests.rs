@@ -0,0 +1,148 @@
+use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+use boa_macros::js_str;
+use indoc::indoc;
+
+#[test]
+fn disposable_stack_dispose_order() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            var stack = new DisposableStack();
+            stack.use({ [Symbol.dispose]() { log.push("use"); } });
+            stack.defer(() => log.push("defer"));
+            stack.adopt("value", (v) => log.push("adopt " + v));
+            stack.dispose();
+        "#}),
+        TestAction::assert_eq("log.join()", js_str!("adopt value,defer,use")),
+        TestAction::assert("stack.disposed"),
+        TestAction::assert_native_error(
+            "stack.use(null)",
+            JsNativeErrorKind::Reference,
+            "DisposableStack.prototype.use: the stack was already disposed",
+        ),
+    ]);
+}
+
+#[test]
+fn disposable_stack_move() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            var stack = new DisposableStack();
+            stack.defer(() => log.push("moved"));
+            var moved = stack.move();
+            stack.dispose();
+        "#}),
+        TestAction::assert("stack.disposed"),
+        TestAction::assert("!moved.disposed"),
+        TestAction::assert_eq("log.length", 0),
+        TestAction::run("moved[Symbol.dispose]()"),
+        TestAction::assert_eq("log.join()", js_str!("moved")),
+    ]);
+}
+
+#[test]
+fn disposable_stack_suppressed_error() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var stack = new DisposableStack();
+            stack.defer(() => { throw 1; });
+            stack.defer(() => { throw 2; });
+            var error;
+            try {
+                stack.dispose();
+            } catch (e) {
+                error = e;
+            }
+        "#}),
+        TestAction::assert("error instanceof SuppressedError"),
+        TestAction::assert_eq("error.error", 1),
+        TestAction::assert_eq("error.suppressed", 2),
+    ]);
+}
+
+#[test]
+fn using_declaration_disposal() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            function resource(name) {
+                return { [Symbol.dispose]() { log.push(name); } };
+            }
+            {
+                using a = resource("a"), b = resource("b");
+                using c = null;
+                log.push("body");
+            }
+        "#}),
+        TestAction::assert_eq("log.join()", js_str!("body,b,a")),
+        TestAction::assert_native_error(
+            "{ using x = 1; }",
+            JsNativeErrorKind::Type,
+            "cannot dispose a value that is not an object",
+        ),
+    ]);
+}
+
+#[test]
+fn using_declaration_abrupt_completion() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            function resource(name) {
+                return { [Symbol.dispose]() { log.push(name); } };
+            }
+            function f() {
+                using a = resource("return");
+                return "result";
+            }
+            var result = f();
+            for (let i = 0; i < 2; i++) {
+                using b = resource("loop " + i);
+                if (i === 1) {
+                    break;
+                }
+            }
+            for (using c of [resource("of 1"), resource("of 2")]) {}
+        "#}),
+        TestAction::assert_eq("result", js_str!("result")),
+        TestAction::assert_eq("log.join()", js_str!("return,loop 0,loop 1,of 1,of 2")),
+    ]);
+}
+
+#[test]
+fn using_declaration_suppressed_error() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var error;
+            try {
+                using a = { [Symbol.dispose]() { throw "dispose"; } };
+                throw "body";
+            } catch (e) {
+                error = e;
+            }
+        "#}),
+        TestAction::assert("error instanceof SuppressedError"),
+        TestAction::assert_eq("error.error", js_str!("dispose")),
+        TestAction::assert_eq("error.suppressed", js_str!("body")),
+    ]);
+}
+
+#[test]
+fn await_using_declaration() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            async function f() {
+                await using a = {
+                    async [Symbol.asyncDispose]() { log.push("async"); }
+                };
+                await using b = { [Symbol.dispose]() { log.push("sync"); } };
+                log.push("body");
+            }
+            f().then(() => log.push("done"));
+        "#}),
+        TestAction::inspect_context(|ctx| ctx.run_jobs().unwrap()),
+        TestAction::assert_eq("log.join()", js_str!("body,sync,async,done")),
+    ]);
+}
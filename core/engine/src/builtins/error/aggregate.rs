@@ -107,6 +107,8 @@ impl BuiltInConstructor for AggregateError {
         // 4. Perform ? InstallErrorCause(O, options).
         Error::install_error_cause(&o, args.get_or_undefined(2), context)?;
 
+        Error::install_error_stack(&o, context);
+
         // 5. Let errorsList be ? IteratorToList(? GetIterator(errors, sync)).
         let errors = args.get_or_undefined(0);
         let errors_list = errors
@@ -16,9 +16,10 @@ use crate::{
     error::JsNativeError,
     js_string,
     object::{internal_methods::get_prototype_from_constructor, JsObject},
-    property::Attribute,
+    property::{Attribute, PropertyDescriptor},
     realm::Realm,
     string::StaticJsStrings,
+    vm::StackFrame,
     Context, JsArgs, JsData, JsResult, JsString, JsValue,
 };
 use boa_gc::{Finalize, Trace};
@@ -29,6 +30,7 @@ pub(crate) mod aggregate;
 pub(crate) mod eval;
 pub(crate) mod range;
 pub(crate) mod reference;
+pub(crate) mod suppressed;
 pub(crate) mod syntax;
 pub(crate) mod r#type;
 pub(crate) mod uri;
@@ -41,6 +43,7 @@ pub(crate) use self::eval::EvalError;
 pub(crate) use self::r#type::TypeError;
 pub(crate) use self::range::RangeError;
 pub(crate) use self::reference::ReferenceError;
+pub(crate) use self::suppressed::SuppressedError;
 pub(crate) use self::syntax::SyntaxError;
 pub(crate) use self::uri::UriError;
 
@@ -125,6 +128,14 @@ pub enum Error {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard-urierror
     Uri,
+
+    /// The `SuppressedError` type.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    Suppressed,
 }
 
 impl IntrinsicObject for Error {
@@ -135,6 +146,11 @@ impl IntrinsicObject for Error {
         let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .property(js_string!("name"), Self::NAME, attribute)
             .property(js_string!("message"), js_string!(), attribute)
+            .static_property(
+                js_string!("stackTraceLimit"),
+                Self::STACK_TRACE_LIMIT,
+                Attribute::all(),
+            )
             .method(Self::to_string, js_string!("toString"), 0);
 
         #[cfg(feature = "experimental")]
@@ -155,7 +171,7 @@ impl BuiltInObject for Error {
 impl BuiltInConstructor for Error {
     const LENGTH: usize = 1;
     const P: usize = 2;
-    const SP: usize = 0;
+    const SP: usize = 1;
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
         StandardConstructors::error;
@@ -200,6 +216,8 @@ impl BuiltInConstructor for Error {
         // 4. Perform ? InstallErrorCause(O, options).
         Self::install_error_cause(&o, args.get_or_undefined(1), context)?;
 
+        Self::install_error_stack(&o, context);
+
         // 5. Return O.
         Ok(o.into())
     }
@@ -224,6 +242,74 @@ impl Error {
         Ok(())
     }
 
+    /// The default value of the non-standard `Error.stackTraceLimit` property.
+    const STACK_TRACE_LIMIT: u32 = 10;
+
+    /// Gets the maximum number of frames captured in a backtrace, which is the value of the
+    /// non-standard `Error.stackTraceLimit` property of the current realm.
+    ///
+    /// The property is read without invoking getters or proxy traps. No frames are captured if
+    /// its value is not a positive number.
+    pub(crate) fn stack_trace_limit(context: &Context) -> usize {
+        let constructor = context.intrinsics().constructors().error().constructor();
+        constructor
+            .get_property(&js_string!("stackTraceLimit").into())
+            .as_ref()
+            .and_then(PropertyDescriptor::value)
+            .and_then(JsValue::as_number)
+            .map_or(0, |limit| limit.max(0.0) as usize)
+    }
+
+    /// Installs the non-standard `stack` property on a newly created error object `o`, with the
+    /// backtrace of the current call stack.
+    pub(crate) fn install_error_stack(o: &JsObject, context: &mut Context) {
+        let backtrace = context.capture_backtrace();
+        Self::install_error_stack_from(o, &backtrace, context);
+    }
+
+    /// Installs the non-standard `stack` property on a newly created error object `o`.
+    ///
+    /// The value is formatted like the V8 stack strings: a header with the `name` and `message`
+    /// of the error, followed by one line per frame of `backtrace`. The header is read without
+    /// invoking getters or proxy traps, so this operation is not observable from JavaScript.
+    pub(crate) fn install_error_stack_from(
+        o: &JsObject,
+        backtrace: &[StackFrame],
+        context: &mut Context,
+    ) {
+        let string_property = |key: JsString| {
+            o.get_property(&key.into())
+                .as_ref()
+                .and_then(PropertyDescriptor::value)
+                .and_then(JsValue::as_string)
+                .cloned()
+        };
+        let name = string_property(js_string!("name")).unwrap_or(Self::NAME);
+        let message = string_property(js_string!("message")).unwrap_or_default();
+
+        let mut stack = if message.is_empty() {
+            name.to_std_string_escaped()
+        } else if name.is_empty() {
+            message.to_std_string_escaped()
+        } else {
+            format!(
+                "{}: {}",
+                name.to_std_string_escaped(),
+                message.to_std_string_escaped()
+            )
+        };
+        for frame in backtrace {
+            stack.push_str("\n    ");
+            stack.push_str(&frame.to_string());
+        }
+
+        o.create_non_enumerable_data_property_or_throw(
+            js_string!("stack"),
+            js_string!(stack),
+            context,
+        );
+    }
+
     /// `Error.prototype.toString()`
     ///
     /// The `toString()` method returns a string representing the specified Error object.
@@ -1,4 +1,4 @@
-use crate::{run_test_actions, TestAction};
+use crate::{run_test_actions, Context, Source, TestAction};
 use boa_macros::js_str;
 use indoc::indoc;
 
@@ -52,6 +52,7 @@ fn error_names() {
         TestAction::assert_eq("URIError.name", js_str!("URIError")),
         TestAction::assert_eq("TypeError.name", js_str!("TypeError")),
         TestAction::assert_eq("AggregateError.name", js_str!("AggregateError")),
+        TestAction::assert_eq("SuppressedError.name", js_str!("SuppressedError")),
     ]);
 }
 
@@ -66,5 +67,113 @@ fn error_lengths() {
         TestAction::assert_eq("URIError.length", 1),
         TestAction::assert_eq("TypeError.length", 1),
         TestAction::assert_eq("AggregateError.length", 2),
+        TestAction::assert_eq("SuppressedError.length", 3),
     ]);
 }
+
+#[test]
+fn error_stack() {
+    run_test_actions([
+        TestAction::assert_eq(
+            indoc! {r#"
+                function f() {
+                    return new TypeError("oops");
+                }
+                f().stack
+            "#},
+            js_str!("TypeError: oops\n    at f (<anonymous>:2:12)\n    at <anonymous>:4:1"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                function g() { null.foo(); }
+                var stack;
+                try { g(); } catch (e) { stack = e.stack; }
+                stack.split("\n").slice(1).join("|")
+            "#},
+            js_str!("    at g (<anonymous>:1:16)|    at <anonymous>:3:7"),
+        ),
+        TestAction::assert_eq("new Error().stack.split('\\n')[0]", js_str!("Error")),
+        TestAction::assert(indoc! {r#"
+            var desc = Object.getOwnPropertyDescriptor(new RangeError("x"), "stack");
+            typeof desc.value === "string" &&
+                desc.writable &&
+                !desc.enumerable &&
+                desc.configurable
+        "#}),
+    ]);
+}
+
+#[test]
+fn suppressed_error_properties() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var e = new SuppressedError(1, 2, "message");
+        "#}),
+        TestAction::assert_eq("e.error", 1),
+        TestAction::assert_eq("e.suppressed", 2),
+        TestAction::assert_eq("e.message", js_str!("message")),
+        TestAction::assert("e instanceof Error"),
+        TestAction::assert("!Object.getOwnPropertyDescriptor(e, 'error').enumerable"),
+        TestAction::assert("!Object.getOwnPropertyDescriptor(e, 'suppressed').enumerable"),
+        TestAction::assert("!Object.hasOwn(SuppressedError(), 'message')"),
+    ]);
+}
+
+#[test]
+fn error_stack_trace_limit() {
+    run_test_actions([
+        TestAction::assert_eq("Error.stackTraceLimit", 10),
+        TestAction::assert_eq(
+            indoc! {r#"
+                function f(n) {
+                    return n === 0 ? new Error("deep") : f(n - 1);
+                }
+                f(20).stack.split("\n").length
+            "#},
+            11,
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                Error.stackTraceLimit = 1;
+                f(20).stack.split("\n").length
+            "#},
+            2,
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                Error.stackTraceLimit = "not a number";
+                f(20).stack
+            "#},
+            js_str!("Error: deep"),
+        ),
+        TestAction::inspect_context(|context| {
+            let error = context
+                .eval(Source::from_bytes(indoc! {r#"
+                    Error.stackTraceLimit = 3;
+                    function t(n) {
+                        if (n === 0) throw 1;
+                        t(n - 1);
+                    }
+                    t(20);
+                "#}))
+                .unwrap_err();
+            assert_eq!(error.backtrace().map(<[_]>::len), Some(3));
+        }),
+    ]);
+}
+
+#[test]
+fn error_equality_ignores_backtrace() {
+    let context = &mut Context::default();
+    let mut eval = |source: &str| context.eval(Source::from_bytes(source)).unwrap_err();
+
+    let inner = eval("function f() { throw 1; } f()");
+    let outer = eval("throw 1");
+    assert_ne!(inner.backtrace(), outer.backtrace());
+    assert_eq!(inner, outer);
+
+    let inner = eval("function g() { null.foo; } g()");
+    let outer = eval("null.foo");
+    assert_ne!(inner.backtrace(), outer.backtrace());
+    assert_eq!(inner.as_native(), outer.as_native());
+}
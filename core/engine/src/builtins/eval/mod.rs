@@ -26,6 +26,7 @@ use crate::{
 use boa_ast::{
     operations::{contains, contains_arguments, ContainsSymbol},
     scope::Scope,
+    Script,
 };
 use boa_gc::Gc;
 use boa_parser::{Parser, Source};
@@ -77,7 +78,7 @@ impl Eval {
         x: &JsValue,
         direct: bool,
         lexical_scope: Option<Scope>,
-        mut strict: bool,
+        strict: bool,
         context: &mut Context,
     ) -> JsResult<JsValue> {
         bitflags::bitflags! {
@@ -91,14 +92,6 @@ impl Eval {
             }
         }
 
-        /// Possible actions that can be executed after exiting this function to restore the environment to its
-        /// original state.
-        #[derive(Debug)]
-        enum EnvStackAction {
-            Truncate(usize),
-            Restore(Vec<Environment>),
-        }
-
         // 1. Assert: If direct is false, then strictCaller is also false.
         debug_assert!(direct || !strict);
 
@@ -131,7 +124,7 @@ impl Eval {
         if strict {
             parser.set_strict();
         }
-        let (mut body, source) = parser.parse_eval(direct, context.interner_mut())?;
+        let (body, source) = parser.parse_eval(direct, context.interner_mut())?;
 
         // 6. Let inFunction be false.
         // 7. Let inMethod be false.
@@ -196,6 +189,31 @@ impl Eval {
                 .into());
         }
 
+        Self::evaluate_body(body, source, direct, lexical_scope, strict, context)
+    }
+
+    /// Evaluates the `body` of the source text of an `eval` call, once it was parsed and checked
+    /// for early errors.
+    ///
+    /// These are the steps 12 to 33 of [`PerformEval`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performeval
+    pub(crate) fn evaluate_body(
+        mut body: Script,
+        source: boa_ast::SourceText,
+        direct: bool,
+        lexical_scope: Option<Scope>,
+        mut strict: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        /// Possible actions that can be executed after exiting this function to restore the environment to its
+        /// original state.
+        #[derive(Debug)]
+        enum EnvStackAction {
+            Truncate(usize),
+            Restore(Vec<Environment>),
+        }
+
         strict |= body.strict();
 
         // Because our environment model does not map directly to the spec, this section looks very different.
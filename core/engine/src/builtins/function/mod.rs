@@ -185,6 +185,10 @@ pub struct OrdinaryFunction {
 
     /// The `[[PrivateMethods]]` internal slot.
     private_methods: ThinVec<(PrivateName, PrivateElement)>,
+
+    /// The `[[Initializers]]` internal slot, containing the extra initializers added by the
+    /// decorators of non-static class elements.
+    initializers: ThinVec<JsFunction>,
 }
 
 impl JsData for OrdinaryFunction {
@@ -223,6 +227,7 @@ impl OrdinaryFunction {
             realm,
             fields: ThinVec::default(),
             private_methods: ThinVec::default(),
+            initializers: ThinVec::default(),
         }
     }
 
@@ -273,6 +278,11 @@ impl OrdinaryFunction {
             .push(ClassFieldDefinition::Public(key, value, function_name));
     }
 
+    /// Returns a mutable reference to the last value of the `[[Fields]]` internal slot.
+    pub(crate) fn last_field_mut(&mut self) -> Option<&mut ClassFieldDefinition> {
+        self.fields.last_mut()
+    }
+
     /// Pushes a private value to the `[[Fields]]` internal slot if present.
     pub(crate) fn push_field_private(&mut self, name: PrivateName, value: JsFunction) {
         self.fields.push(ClassFieldDefinition::Private(name, value));
@@ -288,6 +298,19 @@ impl OrdinaryFunction {
         self.private_methods.push((name, method));
     }
 
+    /// Returns the values of the `[[Initializers]]` internal slot.
+    pub(crate) fn get_initializers(&self) -> &[JsFunction] {
+        &self.initializers
+    }
+
+    /// Pushes values to the `[[Initializers]]` internal slot.
+    pub(crate) fn push_initializers<I>(&mut self, initializers: I)
+    where
+        I: IntoIterator<Item = JsFunction>,
+    {
+        self.initializers.extend(initializers);
+    }
+
     /// Gets the `Realm` from where this function originates.
     #[must_use]
     pub const fn realm(&self) -> &Realm {
@@ -0,0 +1,1163 @@
+//! This module implements the global `Intl.DateTimeFormat` object.
+//!
+//! `Intl.DateTimeFormat` is a built-in object that has properties and methods for date and time i18n.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma402/#datetimeformat-objects
+
+use std::fmt::Write;
+
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use icu_calendar::preferences::CalendarAlgorithm;
+use icu_datetime::{
+    fieldsets::{
+        builder::{DateFields, FieldSetBuilder},
+        enums::CompositeDateTimeFieldSet,
+    },
+    input::{Date, DateTime, Time},
+    options::{Alignment, Length, SubsecondDigits, TimePrecision, YearStyle},
+    preferences::HourCycle,
+    DateTimeFormatter, DateTimeFormatterPreferences, FormattedDateTime,
+};
+use icu_decimal::{preferences::NumberingSystem, provider::DecimalSymbolsV1};
+use icu_locale::{
+    extensions::unicode::{key, Value},
+    Locale,
+};
+use icu_provider::DataMarkerAttributes;
+
+mod options;
+pub(crate) use options::*;
+
+use super::{
+    locale::{canonicalize_locale_list, filter_locales, resolve_locale, validate_extension},
+    options::{coerce_options_to_object, IntlOptions},
+    Service,
+};
+use crate::{
+    builtins::{
+        date::utils::{
+            date_from_time, hour_from_time, min_from_time, month_from_time, ms_from_time,
+            sec_from_time, time_clip, year_from_time,
+        },
+        options::get_option,
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    error::JsNativeError,
+    js_string,
+    object::{
+        internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsFunction,
+        JsObject, ObjectInitializer,
+    },
+    property::{Attribute, PropertyDescriptor},
+    realm::Realm,
+    string::StaticJsStrings,
+    Context, JsArgs, JsData, JsResult, JsString, JsSymbol, JsValue, NativeFunction,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// JavaScript `Intl.DateTimeFormat` object.
+#[derive(Debug, Trace, Finalize, JsData)]
+// Safety: `DateTimeFormat` only contains non-traceable types.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct DateTimeFormat {
+    locale: Locale,
+    calendar: Option<Value>,
+    numbering_system: Option<Value>,
+    time_zone: FormatTimeZone,
+    hour_cycle: Option<HourCycle>,
+    date_style: Option<DateTimeStyle>,
+    time_style: Option<DateTimeStyle>,
+    components: DateTimeComponents,
+    formatter: DateTimeFormatter<CompositeDateTimeFieldSet>,
+    bound_format: Option<JsFunction>,
+}
+
+/// Represents the `required` and `defaults` arguments in the abstract operation
+/// [`CreateDateTimeFormat`][spec].
+///
+/// Since `required` and `defaults` differ only in the `any` and `all` variants,
+/// we combine both in a single variant `AnyAll`.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-createdatetimeformat
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum DateTimeReqs {
+    Date,
+    Time,
+    AnyAll,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct DateTimeFormatLocaleOptions {
+    calendar: Option<Value>,
+    hour_cycle: Option<HourCycle>,
+    numbering_system: Option<Value>,
+}
+
+impl Service for DateTimeFormat {
+    // Numeric date and time fields are formatted using the decimal symbols of the locale, so
+    // a locale without decimal data cannot format dates either.
+    type LangMarker = DecimalSymbolsV1;
+
+    type LocaleOptions = DateTimeFormatLocaleOptions;
+
+    fn resolve(
+        locale: &mut Locale,
+        options: &mut Self::LocaleOptions,
+        provider: &crate::context::icu::IntlProvider,
+    ) {
+        let keywords = &locale.extensions.unicode.keywords;
+
+        let calendar = options
+            .calendar
+            .take()
+            .or_else(|| keywords.get(&key!("ca")).cloned())
+            .filter(|ca| CalendarAlgorithm::try_from(ca).is_ok());
+
+        let hour_cycle = options.hour_cycle.take().or_else(|| {
+            keywords
+                .get(&key!("hc"))
+                .cloned()
+                .and_then(|hc| HourCycle::try_from(&hc).ok())
+        });
+
+        let validate_numbering_system = |nu: &Value| {
+            NumberingSystem::try_from(nu.clone()).is_ok_and(|nu| {
+                let attr = DataMarkerAttributes::from_str_or_panic(nu.as_str());
+                validate_extension::<Self::LangMarker>(locale.id.clone(), attr, provider)
+            })
+        };
+        let numbering_system = options
+            .numbering_system
+            .take()
+            .filter(|nu| validate_numbering_system(nu))
+            .or_else(|| {
+                keywords
+                    .get(&key!("nu"))
+                    .cloned()
+                    .filter(|nu| validate_numbering_system(nu))
+            });
+
+        locale.extensions.unicode.clear();
+
+        if let Some(ca) = calendar.clone() {
+            locale.extensions.unicode.keywords.set(key!("ca"), ca);
+        }
+        if let Some(hc) = hour_cycle {
+            locale
+                .extensions
+                .unicode
+                .keywords
+                .set(key!("hc"), hc.into());
+        }
+        if let Some(nu) = numbering_system.clone() {
+            locale.extensions.unicode.keywords.set(key!("nu"), nu);
+        }
+
+        options.calendar = calendar;
+        options.hour_cycle = hour_cycle;
+        options.numbering_system = numbering_system;
+    }
+}
+
+impl IntrinsicObject for DateTimeFormat {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let get_format = BuiltInBuilder::callable(realm, Self::get_format)
+            .name(js_string!("get format"))
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.DateTimeFormat"),
+                Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                js_string!("format"),
+                Some(get_format),
+                None,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format_to_parts, js_string!("formatToParts"), 1)
+            .method(Self::format_range, js_string!("formatRange"), 2)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DateTimeFormat {
+    const NAME: JsString = StaticJsStrings::DATE_TIME_FORMAT;
+}
+
+impl BuiltInConstructor for DateTimeFormat {
+    const LENGTH: usize = 0;
+    const P: usize = 5;
+    const SP: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::date_time_format;
+
+    /// [`Intl.DateTimeFormat ( [ locales [ , options ] ] )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. If NewTarget is undefined, let newTarget be the active function object, else let newTarget be NewTarget.
+        let new_target_inner = &if new_target.is_undefined() {
+            context
+                .active_function_object()
+                .unwrap_or_else(|| {
+                    context
+                        .intrinsics()
+                        .constructors()
+                        .date_time_format()
+                        .constructor()
+                })
+                .into()
+        } else {
+            new_target.clone()
+        };
+
+        // 2. Let dateTimeFormat be ? CreateDateTimeFormat(newTarget, locales, options, any, date).
+        let prototype = get_prototype_from_constructor(
+            new_target_inner,
+            StandardConstructors::date_time_format,
+            context,
+        )?;
+        let dtf = Self::try_new(
+            locales,
+            options,
+            DateTimeReqs::AnyAll,
+            DateTimeReqs::Date,
+            context,
+        )?;
+        let date_time_format =
+            JsObject::from_proto_and_data_with_shared_shape(context.root_shape(), prototype, dtf);
+
+        // 3. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Let this be the this value.
+        //     b. Return ? ChainDateTimeFormat(dateTimeFormat, NewTarget, this).
+        // ChainDateTimeFormat ( dateTimeFormat, newTarget, this )
+        // <https://tc39.es/ecma402/#sec-chaindatetimeformat>
+
+        let this = context.vm.stack.get_this(context.vm.frame());
+        let Some(this_obj) = this.as_object() else {
+            return Ok(date_time_format.into());
+        };
+
+        let constructor = context
+            .intrinsics()
+            .constructors()
+            .date_time_format()
+            .constructor();
+
+        // 1. If newTarget is undefined and ? OrdinaryHasInstance(%Intl.DateTimeFormat%, this) is true, then
+        if new_target.is_undefined()
+            && JsValue::ordinary_has_instance(&constructor.into(), &this, context)?
+        {
+            let fallback_symbol = context
+                .intrinsics()
+                .objects()
+                .intl()
+                .borrow()
+                .data
+                .fallback_symbol();
+
+            // a. Perform ? DefinePropertyOrThrow(this, %Intl%.[[FallbackSymbol]], PropertyDescriptor{ [[Value]]: dateTimeFormat, [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }).
+            this_obj.define_property_or_throw(
+                fallback_symbol,
+                PropertyDescriptor::builder()
+                    .value(date_time_format)
+                    .writable(false)
+                    .enumerable(false)
+                    .configurable(false),
+                context,
+            )?;
+            // b. Return this.
+            Ok(this)
+        } else {
+            // 2. Return dateTimeFormat.
+            Ok(date_time_format.into())
+        }
+    }
+}
+
+impl DateTimeFormat {
+    /// Abstract operation [`CreateDateTimeFormat ( newTarget, locales, options, required, defaults )`][spec],
+    /// without the allocation of the `Intl.DateTimeFormat` object itself.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-createdatetimeformat
+    pub(crate) fn try_new(
+        locales: &JsValue,
+        options: &JsValue,
+        required: DateTimeReqs,
+        defaults: DateTimeReqs,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 2. Let hour12 be undefined.
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 4. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(options, context)?;
+
+        // 5. Let opt be a new Record.
+        // 6. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 7. Set opt.[[localeMatcher]] to matcher.
+        let matcher =
+            get_option(&options, js_string!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 8. Let calendar be ? GetOption(options, "calendar", string, empty, undefined).
+        // 9. If calendar is not undefined, then
+        //     a. If calendar cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        // 10. Set opt.[[ca]] to calendar.
+        let calendar = get_option::<CalendarAlgorithm>(&options, js_string!("calendar"), context)?;
+
+        // 11. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 12. If numberingSystem is not undefined, then
+        //     a. If numberingSystem cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        // 13. Set opt.[[nu]] to numberingSystem.
+        let numbering_system =
+            get_option::<NumberingSystem>(&options, js_string!("numberingSystem"), context)?;
+
+        // 14. Let hour12 be ? GetOption(options, "hour12", boolean, empty, undefined).
+        let hour12 = get_option::<bool>(&options, js_string!("hour12"), context)?;
+
+        // 15. Let hourCycle be ? GetOption(options, "hourCycle", string, « "h11", "h12", "h23", "h24" », undefined).
+        // 16. If hour12 is not undefined, then
+        //     a. Set hourCycle to null.
+        // 17. Set opt.[[hc]] to hourCycle.
+        let hour_cycle = get_option::<HourCycle>(&options, js_string!("hourCycle"), context)?
+            .filter(|_| hour12.is_none());
+
+        let mut intl_options = IntlOptions {
+            matcher,
+            service_options: DateTimeFormatLocaleOptions {
+                calendar: calendar.map(Value::from),
+                hour_cycle,
+                numbering_system: numbering_system.map(Value::from),
+            },
+        };
+
+        // 18. Let r be ResolveLocale(%Intl.DateTimeFormat%.[[AvailableLocales]], requestedLocales, opt, %Intl.DateTimeFormat%.[[RelevantExtensionKeys]], %Intl.DateTimeFormat%.[[LocaleData]]).
+        // 19. Set dateTimeFormat.[[Locale]] to r.[[Locale]].
+        // 20. Let resolvedCalendar be r.[[ca]].
+        // 21. Set dateTimeFormat.[[Calendar]] to resolvedCalendar.
+        // 22. Set dateTimeFormat.[[NumberingSystem]] to r.[[nu]].
+        let locale = resolve_locale::<Self>(
+            requested_locales,
+            &mut intl_options,
+            context.intl_provider(),
+        )?;
+
+        // 29. Let timeZone be ? Get(options, "timeZone").
+        let time_zone = options.get(js_string!("timeZone"), context)?;
+        let time_zone = if time_zone.is_undefined() {
+            // 30. If timeZone is undefined, then
+            //     a. Set timeZone to SystemTimeZoneIdentifier().
+            FormatTimeZone::Local
+        } else {
+            // 31. Else,
+            //     a. Set timeZone to ? ToString(timeZone).
+            // 32. If IsTimeZoneOffsetString(timeZone) is true, then
+            //     ...
+            // 33. Else,
+            //     a. Let timeZoneIdentifierRecord be GetAvailableNamedTimeZoneIdentifier(timeZone).
+            //     b. If timeZoneIdentifierRecord is empty, throw a RangeError exception.
+            let time_zone = time_zone.to_string(context)?.to_std_string_escaped();
+            FormatTimeZone::from_identifier(&time_zone).ok_or_else(|| {
+                JsNativeError::range().with_message(format!("unsupported time zone `{time_zone}`"))
+            })?
+        };
+
+        // 35. Let formatOptions be a new Record.
+        // 36. Set formatOptions.[[hourCycle]] to hc.
+        // 37. Let hasExplicitFormatComponents be false.
+        // 38. For each row of Table 16, except the header row, in table order, do
+        //     a. Let prop be the name given in the Property column of the current row.
+        //     b. If prop is "fractionalSecondDigits", then
+        //         i. Let value be ? GetNumberOption(options, "fractionalSecondDigits", 1, 3, undefined).
+        //     c. Else,
+        //         i. Let values be a List whose elements are the strings given in the Values column of the current row.
+        //         ii. Let value be ? GetOption(options, prop, string, values, undefined).
+        //     d. Set formatOptions.[[<prop>]] to value.
+        //     e. If value is not undefined, then
+        //         i. Set hasExplicitFormatComponents to true.
+        let mut components = DateTimeComponents::from_options(&options, context)?;
+
+        // 39. Let formatMatcher be ? GetOption(options, "formatMatcher", string, « "basic", "best fit" », "best fit").
+        // TODO: ICU4X always picks the best fitting pattern for a field set, so the matcher is unused.
+        let _format_matcher =
+            get_option::<FormatMatcher>(&options, js_string!("formatMatcher"), context)?
+                .unwrap_or_default();
+
+        // 40. Let dateStyle be ? GetOption(options, "dateStyle", string, « "full", "long", "medium", "short" », undefined).
+        // 41. Set dateTimeFormat.[[DateStyle]] to dateStyle.
+        let date_style = get_option::<DateTimeStyle>(&options, js_string!("dateStyle"), context)?;
+
+        // 42. Let timeStyle be ? GetOption(options, "timeStyle", string, « "full", "long", "medium", "short" », undefined).
+        // 43. Set dateTimeFormat.[[TimeStyle]] to timeStyle.
+        let time_style = get_option::<DateTimeStyle>(&options, js_string!("timeStyle"), context)?;
+
+        // 44. If dateStyle is not undefined or timeStyle is not undefined, then
+        if date_style.is_some() || time_style.is_some() {
+            // a. If hasExplicitFormatComponents is true, then
+            if components.has_explicit_components() {
+                // i. Throw a TypeError exception.
+                return Err(JsNativeError::typ()
+                    .with_message(
+                        "`dateStyle` and `timeStyle` cannot be combined with explicit date-time components",
+                    )
+                    .into());
+            }
+
+            // b. If required is date and timeStyle is not undefined, then
+            if required == DateTimeReqs::Date && time_style.is_some() {
+                // i. Throw a TypeError exception.
+                return Err(JsNativeError::typ()
+                    .with_message("'date' is required, but timeStyle was defined")
+                    .into());
+            }
+
+            // c. If required is time and dateStyle is not undefined, then
+            if required == DateTimeReqs::Time && date_style.is_some() {
+                // i. Throw a TypeError exception.
+                return Err(JsNativeError::typ()
+                    .with_message("'time' is required, but dateStyle was defined")
+                    .into());
+            }
+        } else {
+            // 45. Else,
+            //     a. Let needDefaults be true.
+            //     b. If required is date or any, then
+            //         i. For each property name prop of « "weekday", "year", "month", "day" », do
+            //             1. Let value be formatOptions.[[<prop>]].
+            //             2. If value is not undefined, let needDefaults be false.
+            //     c. If required is time or any, then
+            //         i. For each property name prop of « "dayPeriod", "hour", "minute", "second", "fractionalSecondDigits" », do
+            //             1. Let value be formatOptions.[[<prop>]].
+            //             2. If value is not undefined, let needDefaults be false.
+            let has_required_date = matches!(required, DateTimeReqs::Date | DateTimeReqs::AnyAll)
+                && components.has_date();
+            let has_required_time = matches!(required, DateTimeReqs::Time | DateTimeReqs::AnyAll)
+                && components.has_time();
+            let need_defaults = !has_required_date && !has_required_time;
+
+            // d. If needDefaults is true and defaults is either date or all, then
+            if need_defaults && matches!(defaults, DateTimeReqs::Date | DateTimeReqs::AnyAll) {
+                // i. For each property name prop of « "year", "month", "day" », do
+                //     1. Set formatOptions.[[<prop>]] to "numeric".
+                components.year = Some(NumericStyle::Numeric);
+                components.month = Some(MonthStyle::Numeric);
+                components.day = Some(NumericStyle::Numeric);
+            }
+
+            // e. If needDefaults is true and defaults is either time or all, then
+            if need_defaults && matches!(defaults, DateTimeReqs::Time | DateTimeReqs::AnyAll) {
+                // i. For each property name prop of « "hour", "minute", "second" », do
+                //     1. Set formatOptions.[[<prop>]] to "numeric".
+                components.hour = Some(NumericStyle::Numeric);
+                components.minute = Some(NumericStyle::Numeric);
+                components.second = Some(NumericStyle::Numeric);
+            }
+        }
+
+        // 46. If dateTimeFormat.[[Hour]] is undefined, then
+        //     a. Set dateTimeFormat.[[HourCycle]] to undefined.
+        // 47. Else,
+        //     a. Let hcDefault be dataLocaleData.[[hourCycle]].
+        //     b. If hour12 is true, then
+        //         i. Let hc be dataLocaleData.[[hourCycle12]].
+        //     c. Else if hour12 is false, then
+        //         i. Let hc be dataLocaleData.[[hourCycle24]].
+        //     d. Else,
+        //         i. Assert: hour12 is undefined.
+        //         ii. Let hc be r.[[hc]].
+        //         iii. If hc is null, set hc to hcDefault.
+        //     e. Set dateTimeFormat.[[HourCycle]] to hc.
+        let hour_cycle = if components.hour.is_none() && time_style.is_none() {
+            None
+        } else {
+            match hour12 {
+                Some(true) => Some(HourCycle::H12),
+                Some(false) => Some(HourCycle::H23),
+                None => intl_options.service_options.hour_cycle,
+            }
+        };
+
+        let field_set = field_set(date_style, time_style, &components)?;
+
+        let mut preferences = DateTimeFormatterPreferences::from(&locale);
+        if hour_cycle.is_some() {
+            preferences.hour_cycle = hour_cycle;
+        }
+
+        let formatter = DateTimeFormatter::try_new_with_buffer_provider(
+            context.intl_provider().erased_provider(),
+            preferences,
+            field_set,
+        )
+        .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
+
+        Ok(Self {
+            locale,
+            calendar: intl_options.service_options.calendar,
+            numbering_system: intl_options.service_options.numbering_system,
+            time_zone,
+            hour_cycle,
+            date_style,
+            time_style,
+            components,
+            formatter,
+            bound_format: None,
+        })
+    }
+
+    /// Abstract operations [`FormatDateTime ( dateTimeFormat, x )`][full] and
+    /// [`FormatDateTimeToParts ( dateTimeFormat, x )`][parts].
+    ///
+    /// The returned struct implements `Writeable`, allowing to either write the date as a full
+    /// string or by parts.
+    ///
+    /// [full]: https://tc39.es/ecma402/#sec-formatdatetime
+    /// [parts]: https://tc39.es/ecma402/#sec-formatdatetimetoparts
+    fn format(&self, x: f64, context: &Context) -> JsResult<FormattedDateTime<'_>> {
+        // PartitionDateTimePattern ( dateTimeFormat, x )
+        // <https://tc39.es/ecma402/#sec-partitiondatetimepattern>
+
+        // 1. Let x be TimeClip(x).
+        let x = time_clip(x);
+
+        // 2. If x is NaN, throw a RangeError exception.
+        if x.is_nan() {
+            return Err(JsNativeError::range()
+                .with_message("cannot format an invalid date")
+                .into());
+        }
+
+        // 3. Let epochNanoseconds be ℤ(ℝ(x) × 10^6).
+        // 4. Let tm be ToLocalTime(epochNanoseconds, dateTimeFormat.[[Calendar]], dateTimeFormat.[[TimeZone]]).
+        let t = x + self
+            .time_zone
+            .offset_millis(x, context.host_hooks().as_ref());
+
+        let date = Date::try_new_iso(year_from_time(t), month_from_time(t) + 1, date_from_time(t))
+            .map_err(|err| JsNativeError::range().with_message(err.to_string()))?;
+        let time = Time::try_new(
+            hour_from_time(t),
+            min_from_time(t),
+            sec_from_time(t),
+            u32::from(ms_from_time(t)) * 1_000_000,
+        )
+        .map_err(|err| JsNativeError::range().with_message(err.to_string()))?;
+
+        // TODO: format the `timeZoneName` component once the time zone is passed to ICU4X.
+        Ok(self.formatter.format(&DateTime { date, time }))
+    }
+
+    /// [`Intl.DateTimeFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in date and
+    /// time formatting without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %Intl.DateTimeFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? FilterLocales(availableLocales, requestedLocales, options).
+        filter_locales::<Self>(requested_locales, options, context).map(JsValue::from)
+    }
+
+    /// [`get Intl.DateTimeFormat.prototype.format`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.format
+    fn get_format(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Set dtf to ? UnwrapDateTimeFormat(dtf).
+        // 3. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = unwrap_date_time_format(this, context)?;
+        let dtf_clone = dtf.clone();
+        let mut dtf = dtf.borrow_mut();
+
+        let bound_format = if let Some(f) = dtf.data.bound_format.clone() {
+            f
+        } else {
+            // 4. If dtf.[[BoundFormat]] is undefined, then
+            //     a. Let F be a new built-in function object as defined in DateTime Format Functions (11.5.4.1).
+            //     b. Set F.[[DateTimeFormat]] to dtf.
+            //     c. Set dtf.[[BoundFormat]] to F.
+            let bound_format = FunctionObjectBuilder::new(
+                context.realm(),
+                // DateTime Format Functions
+                // <https://tc39.es/ecma402/#sec-datetime-format-functions>
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, args, dtf, context| {
+                        // 1. Let dtf be F.[[DateTimeFormat]].
+                        // 2. Assert: Type(dtf) is Object and dtf has an [[InitializedDateTimeFormat]] internal slot.
+                        // 3. If date is not provided or is undefined, then
+                        //     a. Let x be ! Call(%Date.now%, undefined).
+                        // 4. Else,
+                        //     a. Let x be ? ToNumber(date).
+                        let x = to_date_time_value(args.get_or_undefined(0), context)?;
+
+                        // 5. Return ? FormatDateTime(dtf, x).
+                        let formatted = dtf.borrow().data.format(x, context)?.to_string();
+                        Ok(js_string!(formatted).into())
+                    },
+                    dtf_clone,
+                ),
+            )
+            .length(1)
+            .build();
+
+            dtf.data.bound_format = Some(bound_format.clone());
+            bound_format
+        };
+
+        // 5. Return dtf.[[BoundFormat]].
+        Ok(bound_format.into())
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.formatToParts ( date )`][spec].
+    ///
+    /// Returns an array of objects representing the date string in parts that can be used for
+    /// custom locale-aware formatting.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DateTimeFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        use writeable::{PartsWrite, Writeable};
+
+        #[derive(Debug, Clone)]
+        struct WriteString(String);
+
+        impl Write for WriteString {
+            fn write_str(&mut self, s: &str) -> std::fmt::Result {
+                self.0.write_str(s)
+            }
+
+            fn write_char(&mut self, c: char) -> std::fmt::Result {
+                self.0.write_char(c)
+            }
+        }
+
+        impl PartsWrite for WriteString {
+            type SubPartsWrite = Self;
+
+            fn with_part(
+                &mut self,
+                _part: writeable::Part,
+                mut f: impl FnMut(&mut Self::SubPartsWrite) -> std::fmt::Result,
+            ) -> std::fmt::Result {
+                f(self)
+            }
+        }
+
+        /// Collects the top-level parts of a formatted date, merging all the text written
+        /// outside of a date-time field into `literal` parts.
+        #[derive(Debug, Clone)]
+        struct PartsCollector(Vec<(&'static str, String)>);
+
+        impl Write for PartsCollector {
+            fn write_str(&mut self, s: &str) -> std::fmt::Result {
+                if s.is_empty() {
+                    return Ok(());
+                }
+                if let Some(("literal", literal)) = self.0.last_mut() {
+                    literal.push_str(s);
+                } else {
+                    self.0.push(("literal", s.to_owned()));
+                }
+                Ok(())
+            }
+        }
+
+        impl PartsWrite for PartsCollector {
+            type SubPartsWrite = WriteString;
+
+            fn with_part(
+                &mut self,
+                part: writeable::Part,
+                mut f: impl FnMut(&mut Self::SubPartsWrite) -> std::fmt::Result,
+            ) -> std::fmt::Result {
+                let mut string = WriteString(String::new());
+                f(&mut string)?;
+                if part.category == "datetime" {
+                    if !string.0.is_empty() {
+                        self.0.push((part.value, string.0));
+                    }
+                    Ok(())
+                } else {
+                    self.write_str(&string.0)
+                }
+            }
+        }
+
+        // 1. Let dtf be the this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`formatToParts` can only be called on an `Intl.DateTimeFormat` object",
+                )
+            })?;
+
+        // 3. If date is undefined, then
+        //     a. Let x be ! Call(%Date.now%, undefined).
+        // 4. Else,
+        //     a. Let x be ? ToNumber(date).
+        let x = to_date_time_value(args.get_or_undefined(0), context)?;
+
+        // 5. Return ? FormatDateTimeToParts(dtf, x).
+        let mut parts = PartsCollector(Vec::new());
+        dtf.borrow()
+            .data
+            .format(x, context)?
+            .write_to_parts(&mut parts)
+            .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+        // FormatDateTimeToParts ( dateTimeFormat, x )
+        // <https://tc39.es/ecma402/#sec-formatdatetimetoparts>
+
+        // 2. Let result be ! ArrayCreate(0).
+        let result = Array::array_create(0, None, context)
+            .expect("creating an empty array with default proto must not fail");
+
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]] } part in parts, do
+        for (n, (typ, value)) in parts.0.into_iter().enumerate() {
+            // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+            let o = context
+                .intrinsics()
+                .templates()
+                .ordinary_object()
+                .create(OrdinaryObject, vec![]);
+
+            // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+            o.create_data_property_or_throw(js_string!("type"), js_string!(typ), context)
+                .expect("operation must not fail per the spec");
+
+            // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+            o.create_data_property_or_throw(js_string!("value"), js_string!(value), context)
+                .expect("operation must not fail per the spec");
+
+            // d. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), O).
+            result
+                .create_data_property_or_throw(n, o, context)
+                .expect("operation must not fail per the spec");
+
+            // e. Increment n by 1.
+        }
+
+        // 5. Return result.
+        Ok(result.into())
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.formatRange ( startDate, endDate )`][spec].
+    ///
+    /// Formats a date range in the most concise way based on the locale and options provided
+    /// when instantiating the `Intl.DateTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.formatRange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatRange
+    fn format_range(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dtf be this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`formatRange` can only be called on an `Intl.DateTimeFormat` object",
+                )
+            })?;
+
+        let start_date = args.get_or_undefined(0);
+        let end_date = args.get_or_undefined(1);
+
+        // 3. If startDate is undefined or endDate is undefined, throw a TypeError exception.
+        if start_date.is_undefined() || end_date.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("`formatRange` requires both a start and an end date")
+                .into());
+        }
+
+        // 4. Let x be ? ToNumber(startDate).
+        let x = start_date.to_number(context)?;
+
+        // 5. Let y be ? ToNumber(endDate).
+        let y = end_date.to_number(context)?;
+
+        // 6. Return ? FormatDateTimeRange(dtf, x, y).
+        let dtf = dtf.borrow();
+        let start = dtf.data.format(x, context)?.to_string();
+        let end = dtf.data.format(y, context)?.to_string();
+
+        // TODO: ICU4X does not provide interval formats yet, so this always uses the fallback
+        // pattern instead of collapsing the fields shared by both dates.
+        // If both dates produce the same formatted string, the range is formatted as a single date.
+        if start == end {
+            return Ok(js_string!(start).into());
+        }
+
+        Ok(js_string!(format!("{start} – {end}")).into())
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and date and time formatting
+    /// options computed during the construction of the current `Intl.DateTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Set dtf to ? UnwrapDateTimeFormat(dtf).
+        // 3. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = unwrap_date_time_format(this, context)?;
+        let dtf = dtf.borrow();
+        let dtf = &dtf.data;
+
+        let now = context.clock().now().millis_since_epoch() as f64;
+        let time_zone = dtf
+            .time_zone
+            .to_js_string(now, context.host_hooks().as_ref());
+
+        // 4. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 5. For each row of Table 17, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. If there is an Internal Slot value in the current row, then
+        //         i. Let v be the value of dtf's internal slot whose name is the Internal Slot value of the current row.
+        //     c. Else,
+        //         i. Let format be dtf.[[DateTimeFormat]].
+        //         ii. If format has a field [[<p>]] and dtf.[[DateStyle]] is undefined and dtf.[[TimeStyle]] is undefined, then
+        //             1. Let v be format.[[<p>]].
+        //         iii. Else,
+        //             1. Let v be undefined.
+        //     d. If v is not undefined, then
+        //         i. If there is a Conversion value in the current row, then
+        //             1. Let conversion be the Conversion value of the current row.
+        //             2. If conversion is hour12, then
+        //                 a. If v is "h11" or "h12", set v to true. Otherwise, set v to false.
+        //             3. Else,
+        //                 a. Assert: conversion is number.
+        //                 b. Set v to 𝔽(v).
+        //         ii. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut options = ObjectInitializer::new(context);
+        options
+            .property(
+                js_string!("locale"),
+                js_string!(dtf.locale.to_string()),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("calendar"),
+                dtf.calendar
+                    .as_ref()
+                    .map_or_else(|| js_string!("gregory"), |ca| js_string!(ca.to_string())),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("numberingSystem"),
+                dtf.numbering_system
+                    .as_ref()
+                    .map_or_else(|| js_string!("latn"), |nu| js_string!(nu.to_string())),
+                Attribute::all(),
+            )
+            .property(js_string!("timeZone"), time_zone, Attribute::all());
+
+        if let Some(hc) = dtf.hour_cycle {
+            options
+                .property(
+                    js_string!("hourCycle"),
+                    hour_cycle_to_js_string(hc),
+                    Attribute::all(),
+                )
+                .property(
+                    js_string!("hour12"),
+                    matches!(hc, HourCycle::H11 | HourCycle::H12),
+                    Attribute::all(),
+                );
+        }
+
+        if dtf.date_style.is_none() && dtf.time_style.is_none() {
+            let c = &dtf.components;
+            let components = [
+                ("weekday", c.weekday.map(TextStyle::to_js_string)),
+                ("era", c.era.map(TextStyle::to_js_string)),
+                ("year", c.year.map(NumericStyle::to_js_string)),
+                ("month", c.month.map(MonthStyle::to_js_string)),
+                ("day", c.day.map(NumericStyle::to_js_string)),
+                ("dayPeriod", c.day_period.map(TextStyle::to_js_string)),
+                ("hour", c.hour.map(NumericStyle::to_js_string)),
+                ("minute", c.minute.map(NumericStyle::to_js_string)),
+                ("second", c.second.map(NumericStyle::to_js_string)),
+            ];
+            for (property, value) in components {
+                if let Some(value) = value {
+                    options.property(js_string!(property), value, Attribute::all());
+                }
+            }
+            if let Some(digits) = c.fractional_second_digits {
+                options.property(
+                    js_string!("fractionalSecondDigits"),
+                    i32::from(digits),
+                    Attribute::all(),
+                );
+            }
+            if let Some(tz_name) = c.time_zone_name {
+                options.property(
+                    js_string!("timeZoneName"),
+                    tz_name.to_js_string(),
+                    Attribute::all(),
+                );
+            }
+        }
+
+        if let Some(style) = dtf.date_style {
+            options.property(
+                js_string!("dateStyle"),
+                style.to_js_string(),
+                Attribute::all(),
+            );
+        }
+        if let Some(style) = dtf.time_style {
+            options.property(
+                js_string!("timeStyle"),
+                style.to_js_string(),
+                Attribute::all(),
+            );
+        }
+
+        // 6. Return options.
+        Ok(options.build().into())
+    }
+}
+
+/// Builds the ICU4X field set that most closely matches the requested styles or components.
+///
+/// ICU4X works with semantic field sets instead of per-component widths, so the widths of the
+/// textual components are mapped to a single length for the whole pattern.
+fn field_set(
+    date_style: Option<DateTimeStyle>,
+    time_style: Option<DateTimeStyle>,
+    components: &DateTimeComponents,
+) -> JsResult<CompositeDateTimeFieldSet> {
+    let mut builder = FieldSetBuilder::default();
+
+    if date_style.is_some() || time_style.is_some() {
+        builder.length = date_style.or(time_style).map(|style| match style {
+            DateTimeStyle::Full | DateTimeStyle::Long => Length::Long,
+            DateTimeStyle::Medium => Length::Medium,
+            DateTimeStyle::Short => Length::Short,
+        });
+        builder.date_fields = date_style.map(|style| match style {
+            DateTimeStyle::Full => DateFields::YMDE,
+            _ => DateFields::YMD,
+        });
+        builder.time_precision = time_style.map(|style| match style {
+            DateTimeStyle::Short => TimePrecision::Minute,
+            _ => TimePrecision::Second,
+        });
+    } else {
+        let c = components;
+
+        builder.length = Some(match (c.month, c.weekday.or(c.era)) {
+            (Some(MonthStyle::Long), _) | (None, Some(TextStyle::Long)) => Length::Long,
+            (Some(MonthStyle::Short | MonthStyle::Narrow), _) | (None, Some(_)) => Length::Medium,
+            _ => Length::Short,
+        });
+
+        let (year, month, day, weekday) = (
+            c.year.is_some() || c.era.is_some(),
+            c.month.is_some(),
+            c.day.is_some(),
+            c.weekday.is_some(),
+        );
+        builder.date_fields = match (year, month, day, weekday) {
+            (true, _, true, true) | (true, true, false, true) => Some(DateFields::YMDE),
+            (true, _, true, false) => Some(DateFields::YMD),
+            (false, true, _, true) => Some(DateFields::MDE),
+            (false, true, true, false) => Some(DateFields::MD),
+            (false, false, true, true) => Some(DateFields::DE),
+            (false, false, true, false) => Some(DateFields::D),
+            (false, false, false, true) => Some(DateFields::E),
+            (true, true, false, false) => Some(DateFields::YM),
+            (false, true, false, false) => Some(DateFields::M),
+            (true, false, false, _) => Some(DateFields::Y),
+            (false, false, false, false) => None,
+        };
+        if year {
+            builder.year_style = Some(match c.year {
+                _ if c.era.is_some() => YearStyle::WithEra,
+                Some(NumericStyle::TwoDigit) => YearStyle::Auto,
+                _ => YearStyle::Full,
+            });
+        }
+
+        builder.time_precision = match c.fractional_second_digits {
+            Some(1) => Some(TimePrecision::Subsecond(SubsecondDigits::S1)),
+            Some(2) => Some(TimePrecision::Subsecond(SubsecondDigits::S2)),
+            Some(_) => Some(TimePrecision::Subsecond(SubsecondDigits::S3)),
+            None if c.second.is_some() => Some(TimePrecision::Second),
+            None if c.minute.is_some() => Some(TimePrecision::Minute),
+            None if c.hour.is_some() || c.day_period.is_some() => Some(TimePrecision::Hour),
+            None => None,
+        };
+
+        if [c.day, c.hour, c.minute, c.second].contains(&Some(NumericStyle::TwoDigit))
+            || c.month == Some(MonthStyle::TwoDigit)
+        {
+            builder.alignment = Some(Alignment::Column);
+        }
+    }
+
+    builder.build_composite_datetime().map_err(|err| {
+        JsNativeError::range()
+            .with_message(format!(
+                "unsupported combination of date-time options: {err}"
+            ))
+            .into()
+    })
+}
+
+/// Converts the `date` argument of the formatting functions to a time value, defaulting to the
+/// current time if `date` is `undefined`.
+fn to_date_time_value(date: &JsValue, context: &mut Context) -> JsResult<f64> {
+    if date.is_undefined() {
+        Ok(context.clock().now().millis_since_epoch() as f64)
+    } else {
+        date.to_number(context)
+    }
+}
+
+/// Formats the time value `tv` of a `Date` object for the `Date.prototype.toLocaleString`,
+/// `Date.prototype.toLocaleDateString` and `Date.prototype.toLocaleTimeString` methods.
+///
+/// `reqs` is used as both the `required` and `defaults` arguments of `CreateDateTimeFormat`.
+pub(crate) fn format_date_time_locale(
+    tv: f64,
+    locales: &JsValue,
+    options: &JsValue,
+    reqs: DateTimeReqs,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 4. If x is NaN, return "Invalid Date".
+    if tv.is_nan() {
+        return Ok(js_string!("Invalid Date").into());
+    }
+
+    // 5. Let dateFormat be ? CreateDateTimeFormat(%Intl.DateTimeFormat%, locales, options, required, defaults).
+    let dtf = DateTimeFormat::try_new(locales, options, reqs, reqs, context)?;
+
+    // 6. Return ! FormatDateTime(dateFormat, x).
+    let formatted = dtf.format(tv, context)?.to_string();
+    Ok(js_string!(formatted).into())
+}
+
+/// Abstract operation [`UnwrapDateTimeFormat ( dtf )`][spec].
+///
+/// This also checks that the returned object is a `DateTimeFormat`, which skips the
+/// call to `RequireInternalSlot`.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-unwrapdatetimeformat
+fn unwrap_date_time_format(
+    dtf: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsObject<DateTimeFormat>> {
+    // 1. If Type(dtf) is not Object, throw a TypeError exception.
+    let dtf_o = dtf.as_object().ok_or_else(|| {
+        JsNativeError::typ().with_message("value was not an `Intl.DateTimeFormat` object")
+    })?;
+
+    if let Ok(dtf) = dtf_o.clone().downcast::<DateTimeFormat>() {
+        // 3. Return dtf.
+        return Ok(dtf);
+    }
+
+    // 2. If dtf does not have an [[InitializedDateTimeFormat]] internal slot and ? OrdinaryHasInstance(%Intl.DateTimeFormat%, dtf)
+    //    is true, then
+    let constructor = context
+        .intrinsics()
+        .constructors()
+        .date_time_format()
+        .constructor();
+    if JsValue::ordinary_has_instance(&constructor.into(), dtf, context)? {
+        let fallback_symbol = context
+            .intrinsics()
+            .objects()
+            .intl()
+            .borrow()
+            .data
+            .fallback_symbol();
+
+        //    a. Return ? Get(dtf, %Intl%.[[FallbackSymbol]]).
+        let dtf = dtf_o.get(fallback_symbol, context)?;
+        if let Some(dtf) = dtf.as_object() {
+            if let Ok(dtf) = dtf.clone().downcast::<DateTimeFormat>() {
+                return Ok(dtf);
+            }
+        }
+    }
+
+    Err(JsNativeError::typ()
+        .with_message("object was not an `Intl.DateTimeFormat` object")
+        .into())
+}
@@ -0,0 +1,454 @@
+use std::{fmt, str::FromStr};
+
+use cow_utils::CowUtils;
+use icu_calendar::preferences::CalendarAlgorithm;
+use icu_datetime::preferences::HourCycle;
+use icu_locale::extensions::unicode::Value;
+
+use crate::{
+    builtins::{
+        intl::options::get_number_option,
+        options::{get_option, OptionType, ParsableOptionType},
+    },
+    context::HostHooks,
+    js_string, Context, JsNativeError, JsObject, JsResult, JsString, JsValue,
+};
+
+impl OptionType for CalendarAlgorithm {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        let s = value.to_string(context)?.to_std_string_escaped();
+        Value::try_from_str(&s)
+            .ok()
+            .and_then(|v| CalendarAlgorithm::try_from(&v).ok())
+            .ok_or_else(|| {
+                JsNativeError::range()
+                    .with_message(format!("provided calendar `{s}` is invalid"))
+                    .into()
+            })
+    }
+}
+
+// TODO: track https://github.com/unicode-org/icu4x/issues/6597 and
+// https://github.com/tc39/ecma402/issues/1002 for resolution on
+// `h24`, which ICU4X doesn't support. Until then, it is formatted as `h23`.
+impl OptionType for HourCycle {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "h11" => Ok(HourCycle::H11),
+            "h12" => Ok(HourCycle::H12),
+            "h23" | "h24" => Ok(HourCycle::H23),
+            _ => Err(JsNativeError::range()
+                .with_message("provided hour cycle was not `h11`, `h12`, `h23` or `h24`")
+                .into()),
+        }
+    }
+}
+
+/// Gets the string representation of an hour cycle, as returned by `resolvedOptions`.
+pub(crate) fn hour_cycle_to_js_string(hour_cycle: HourCycle) -> JsString {
+    match hour_cycle {
+        HourCycle::H11 => js_string!("h11"),
+        HourCycle::H12 => js_string!("h12"),
+        _ => js_string!("h23"),
+    }
+}
+
+/// The `dateStyle` and `timeStyle` options.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum DateTimeStyle {
+    Full,
+    Long,
+    Medium,
+    Short,
+}
+
+impl DateTimeStyle {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Full => js_string!("full"),
+            Self::Long => js_string!("long"),
+            Self::Medium => js_string!("medium"),
+            Self::Short => js_string!("short"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseDateTimeStyleError;
+
+impl fmt::Display for ParseDateTimeStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("provided string was not `full`, `long`, `medium` or `short`")
+    }
+}
+
+impl FromStr for DateTimeStyle {
+    type Err = ParseDateTimeStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "full" => Ok(Self::Full),
+            "long" => Ok(Self::Long),
+            "medium" => Ok(Self::Medium),
+            "short" => Ok(Self::Short),
+            _ => Err(ParseDateTimeStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for DateTimeStyle {}
+
+/// The textual representation of the `weekday`, `era` and `dayPeriod` components.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum TextStyle {
+    Narrow,
+    Short,
+    Long,
+}
+
+impl TextStyle {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Narrow => js_string!("narrow"),
+            Self::Short => js_string!("short"),
+            Self::Long => js_string!("long"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseTextStyleError;
+
+impl fmt::Display for ParseTextStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("provided string was not `narrow`, `short` or `long`")
+    }
+}
+
+impl FromStr for TextStyle {
+    type Err = ParseTextStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "narrow" => Ok(Self::Narrow),
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            _ => Err(ParseTextStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for TextStyle {}
+
+/// The numeric representation of the `year`, `day`, `hour`, `minute` and `second` components.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum NumericStyle {
+    Numeric,
+    TwoDigit,
+}
+
+impl NumericStyle {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Numeric => js_string!("numeric"),
+            Self::TwoDigit => js_string!("2-digit"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseNumericStyleError;
+
+impl fmt::Display for ParseNumericStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("provided string was not `numeric` or `2-digit`")
+    }
+}
+
+impl FromStr for NumericStyle {
+    type Err = ParseNumericStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "numeric" => Ok(Self::Numeric),
+            "2-digit" => Ok(Self::TwoDigit),
+            _ => Err(ParseNumericStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for NumericStyle {}
+
+/// The representation of the `month` component.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum MonthStyle {
+    Numeric,
+    TwoDigit,
+    Narrow,
+    Short,
+    Long,
+}
+
+impl MonthStyle {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Numeric => js_string!("numeric"),
+            Self::TwoDigit => js_string!("2-digit"),
+            Self::Narrow => js_string!("narrow"),
+            Self::Short => js_string!("short"),
+            Self::Long => js_string!("long"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseMonthStyleError;
+
+impl fmt::Display for ParseMonthStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("provided string was not `numeric`, `2-digit`, `narrow`, `short` or `long`")
+    }
+}
+
+impl FromStr for MonthStyle {
+    type Err = ParseMonthStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "numeric" => Ok(Self::Numeric),
+            "2-digit" => Ok(Self::TwoDigit),
+            "narrow" => Ok(Self::Narrow),
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            _ => Err(ParseMonthStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for MonthStyle {}
+
+/// The representation of the `timeZoneName` component.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum TimeZoneNameStyle {
+    Short,
+    Long,
+    ShortOffset,
+    LongOffset,
+    ShortGeneric,
+    LongGeneric,
+}
+
+impl TimeZoneNameStyle {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Short => js_string!("short"),
+            Self::Long => js_string!("long"),
+            Self::ShortOffset => js_string!("shortOffset"),
+            Self::LongOffset => js_string!("longOffset"),
+            Self::ShortGeneric => js_string!("shortGeneric"),
+            Self::LongGeneric => js_string!("longGeneric"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseTimeZoneNameStyleError;
+
+impl fmt::Display for ParseTimeZoneNameStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("provided string was not a valid time zone name style")
+    }
+}
+
+impl FromStr for TimeZoneNameStyle {
+    type Err = ParseTimeZoneNameStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            "shortOffset" => Ok(Self::ShortOffset),
+            "longOffset" => Ok(Self::LongOffset),
+            "shortGeneric" => Ok(Self::ShortGeneric),
+            "longGeneric" => Ok(Self::LongGeneric),
+            _ => Err(ParseTimeZoneNameStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for TimeZoneNameStyle {}
+
+/// The `formatMatcher` option.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub(crate) enum FormatMatcher {
+    Basic,
+    #[default]
+    BestFit,
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseFormatMatcherError;
+
+impl fmt::Display for ParseFormatMatcherError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("provided string was not `basic` or `best fit`")
+    }
+}
+
+impl FromStr for FormatMatcher {
+    type Err = ParseFormatMatcherError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "basic" => Ok(Self::Basic),
+            "best fit" => Ok(Self::BestFit),
+            _ => Err(ParseFormatMatcherError),
+        }
+    }
+}
+
+impl ParsableOptionType for FormatMatcher {}
+
+/// The time zone used to compute the local time of a formatted date.
+///
+/// Named IANA time zones other than UTC are not supported yet, since that requires time zone data
+/// that is not available through the host hooks.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum FormatTimeZone {
+    /// The local time zone of the host, as reported by
+    /// [`HostHooks::local_timezone_offset_seconds`].
+    Local,
+    /// The `UTC` time zone.
+    Utc,
+    /// A fixed UTC offset, in minutes.
+    Offset(i16),
+}
+
+impl FormatTimeZone {
+    /// Parses a time zone identifier, returning `None` if the identifier is invalid or
+    /// unsupported.
+    pub(crate) fn from_identifier(id: &str) -> Option<Self> {
+        if ["utc", "etc/utc", "etc/gmt", "gmt"].contains(&&*id.cow_to_ascii_lowercase()) {
+            return Some(Self::Utc);
+        }
+
+        // UTCOffset ::: ASCIISign Hour [ [:] MinuteSecond ]
+        let (sign, rest) = match id.as_bytes().first()? {
+            b'+' => (1, &id[1..]),
+            b'-' => (-1, &id[1..]),
+            _ => return None,
+        };
+        let digits = |s: &str| -> Option<i16> {
+            (s.len() == 2 && s.bytes().all(|b| b.is_ascii_digit()))
+                .then(|| s.parse().ok())
+                .flatten()
+        };
+        let (hour, minute) = match rest.len() {
+            2 => (digits(rest)?, 0),
+            4 => (digits(&rest[..2])?, digits(&rest[2..])?),
+            5 if rest.as_bytes()[2] == b':' => (digits(&rest[..2])?, digits(&rest[3..])?),
+            _ => return None,
+        };
+        if hour > 23 || minute > 59 {
+            return None;
+        }
+
+        Some(Self::Offset(sign * (hour * 60 + minute)))
+    }
+
+    /// Gets the offset of this time zone from UTC in milliseconds at the epoch time `t`.
+    pub(crate) fn offset_millis(self, t: f64, hooks: &dyn HostHooks) -> f64 {
+        match self {
+            Self::Local => {
+                let seconds = (t / 1000.0).floor() as i64;
+                f64::from(hooks.local_timezone_offset_seconds(seconds)) * 1000.0
+            }
+            Self::Utc => 0.0,
+            Self::Offset(minutes) => f64::from(minutes) * 60_000.0,
+        }
+    }
+
+    /// Gets the identifier of this time zone, as returned by `resolvedOptions`.
+    ///
+    /// The local time zone is identified by its current offset from UTC.
+    pub(crate) fn to_js_string(self, now: f64, hooks: &dyn HostHooks) -> JsString {
+        let minutes = match self {
+            Self::Utc => return js_string!("UTC"),
+            Self::Offset(minutes) => minutes,
+            Self::Local => {
+                let minutes = (self.offset_millis(now, hooks) / 60_000.0) as i16;
+                if minutes == 0 {
+                    return js_string!("UTC");
+                }
+                minutes
+            }
+        };
+        let sign = if minutes < 0 { '-' } else { '+' };
+        let minutes = minutes.unsigned_abs();
+        js_string!(format!("{sign}{:02}:{:02}", minutes / 60, minutes % 60))
+    }
+}
+
+/// The date-time component options of an `Intl.DateTimeFormat` object, following the order of
+/// [Table 16][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#table-datetimeformat-components
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DateTimeComponents {
+    pub(crate) weekday: Option<TextStyle>,
+    pub(crate) era: Option<TextStyle>,
+    pub(crate) year: Option<NumericStyle>,
+    pub(crate) month: Option<MonthStyle>,
+    pub(crate) day: Option<NumericStyle>,
+    pub(crate) day_period: Option<TextStyle>,
+    pub(crate) hour: Option<NumericStyle>,
+    pub(crate) minute: Option<NumericStyle>,
+    pub(crate) second: Option<NumericStyle>,
+    pub(crate) fractional_second_digits: Option<u8>,
+    pub(crate) time_zone_name: Option<TimeZoneNameStyle>,
+}
+
+impl DateTimeComponents {
+    /// Reads all the component options from `options`, in table order.
+    pub(crate) fn from_options(options: &JsObject, context: &mut Context) -> JsResult<Self> {
+        Ok(Self {
+            weekday: get_option(options, js_string!("weekday"), context)?,
+            era: get_option(options, js_string!("era"), context)?,
+            year: get_option(options, js_string!("year"), context)?,
+            month: get_option(options, js_string!("month"), context)?,
+            day: get_option(options, js_string!("day"), context)?,
+            day_period: get_option(options, js_string!("dayPeriod"), context)?,
+            hour: get_option(options, js_string!("hour"), context)?,
+            minute: get_option(options, js_string!("minute"), context)?,
+            second: get_option(options, js_string!("second"), context)?,
+            fractional_second_digits: get_number_option(
+                options,
+                js_string!("fractionalSecondDigits"),
+                1,
+                3,
+                context,
+            )?,
+            time_zone_name: get_option(options, js_string!("timeZoneName"), context)?,
+        })
+    }
+
+    /// Returns `true` if any of the date components `weekday`, `year`, `month` or `day` is set.
+    pub(crate) const fn has_date(&self) -> bool {
+        self.weekday.is_some() || self.year.is_some() || self.month.is_some() || self.day.is_some()
+    }
+
+    /// Returns `true` if any of the time components `dayPeriod`, `hour`, `minute`, `second` or
+    /// `fractionalSecondDigits` is set.
+    pub(crate) const fn has_time(&self) -> bool {
+        self.day_period.is_some()
+            || self.hour.is_some()
+            || self.minute.is_some()
+            || self.second.is_some()
+            || self.fractional_second_digits.is_some()
+    }
+
+    /// Returns `true` if any of the components is set.
+    pub(crate) const fn has_explicit_components(&self) -> bool {
+        self.has_date() || self.has_time() || self.era.is_some() || self.time_zone_name.is_some()
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+use boa_macros::js_str;
+use indoc::indoc;
+
+#[test]
+fn format() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                const dtf = new Intl.DateTimeFormat('en-US', { timeZone: 'UTC' });
+                const epoch = new Date(0);
+            "#}),
+        TestAction::assert_eq("dtf.format(epoch)", js_str!("1/1/1970")),
+        TestAction::assert_eq("dtf.format(0)", js_str!("1/1/1970")),
+        TestAction::assert("dtf.format === dtf.format"),
+        TestAction::assert_eq("[epoch].map(dtf.format)[0]", js_str!("1/1/1970")),
+        TestAction::assert_eq(
+            indoc! {r#"
+                new Intl.DateTimeFormat('en-US', {
+                    hour: 'numeric',
+                    minute: 'numeric',
+                    hourCycle: 'h23',
+                    timeZone: 'UTC',
+                }).format(0)
+            "#},
+            js_str!("00:00"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DateTimeFormat('en-US', { timeZone: '+01:00' }).format(-1)",
+            js_str!("1/1/1970"),
+        ),
+        TestAction::assert_native_error(
+            "dtf.format(NaN)",
+            JsNativeErrorKind::Range,
+            "cannot format an invalid date",
+        ),
+    ]);
+}
+
+#[test]
+fn format_to_parts() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                const dtf = new Intl.DateTimeFormat('en-US', { timeZone: 'UTC' });
+                const parts = dtf.formatToParts(Date.UTC(2024, 4, 17));
+            "#}),
+        TestAction::assert_eq(
+            "parts.map(p => p.type).join()",
+            js_str!("month,literal,day,literal,year"),
+        ),
+        TestAction::assert_eq("parts.map(p => p.value).join('')", js_str!("5/17/2024")),
+        TestAction::assert_native_error(
+            "Intl.DateTimeFormat.prototype.formatToParts.call({}, 0)",
+            JsNativeErrorKind::Type,
+            "`formatToParts` can only be called on an `Intl.DateTimeFormat` object",
+        ),
+    ]);
+}
+
+#[test]
+fn format_range() {
+    run_test_actions([
+        TestAction::run("const dtf = new Intl.DateTimeFormat('en-US', { timeZone: 'UTC' });"),
+        TestAction::assert_eq("dtf.formatRange(0, 1000)", js_str!("1/1/1970")),
+        TestAction::assert_eq(
+            "dtf.formatRange(0, Date.UTC(1970, 0, 2))",
+            js_str!("1/1/1970 – 1/2/1970"),
+        ),
+        TestAction::assert_native_error(
+            "dtf.formatRange(0)",
+            JsNativeErrorKind::Type,
+            "`formatRange` requires both a start and an end date",
+        ),
+    ]);
+}
+
+#[test]
+fn resolved_options() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                const options = new Intl.DateTimeFormat('en-US', {
+                    timeZone: 'utc',
+                    hour: '2-digit',
+                    hour12: false,
+                }).resolvedOptions();
+                const styled = new Intl.DateTimeFormat('en-US', {
+                    dateStyle: 'long',
+                    timeZone: '-0530',
+                }).resolvedOptions();
+            "#}),
+        TestAction::assert_eq("options.locale", js_str!("en-US")),
+        TestAction::assert_eq("options.calendar", js_str!("gregory")),
+        TestAction::assert_eq("options.numberingSystem", js_str!("latn")),
+        TestAction::assert_eq("options.timeZone", js_str!("UTC")),
+        TestAction::assert_eq("options.hourCycle", js_str!("h23")),
+        TestAction::assert_eq("options.hour12", false),
+        TestAction::assert_eq("options.hour", js_str!("2-digit")),
+        TestAction::assert("!('year' in options)"),
+        TestAction::assert_eq("styled.dateStyle", js_str!("long")),
+        TestAction::assert_eq("styled.timeZone", js_str!("-05:30")),
+        TestAction::assert("!('year' in styled) && !('hourCycle' in styled)"),
+        TestAction::assert_eq(
+            "new Intl.DateTimeFormat('en-US', { hour: 'numeric', hourCycle: 'h24' }).resolvedOptions().hourCycle",
+            js_str!("h23"),
+        ),
+    ]);
+}
+
+#[test]
+fn invalid_options() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "new Intl.DateTimeFormat('en-US', { dateStyle: 'short', year: 'numeric' })",
+            JsNativeErrorKind::Type,
+            "`dateStyle` and `timeStyle` cannot be combined with explicit date-time components",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DateTimeFormat('en-US', { month: 'tiny' })",
+            JsNativeErrorKind::Range,
+            "provided string was not `numeric`, `2-digit`, `narrow`, `short` or `long`",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DateTimeFormat('en-US', { timeZone: 'Mars/Olympus_Mons' })",
+            JsNativeErrorKind::Range,
+            "unsupported time zone `Mars/Olympus_Mons`",
+        ),
+        TestAction::assert_native_error(
+            "new Date(0).toLocaleDateString('en-US', { timeStyle: 'short' })",
+            JsNativeErrorKind::Type,
+            "'date' is required, but timeStyle was defined",
+        ),
+    ]);
+}
+
+#[test]
+fn date_to_locale_string() {
+    run_test_actions([
+        TestAction::run("const date = new Date(Date.UTC(2024, 4, 17, 13, 5));"),
+        TestAction::assert_eq(
+            "date.toLocaleDateString('en-US', { timeZone: 'UTC' })",
+            js_str!("5/17/2024"),
+        ),
+        TestAction::assert_eq(
+            "date.toLocaleTimeString('en-US', { timeZone: 'UTC', hourCycle: 'h23' })",
+            js_str!("13:05:00"),
+        ),
+        TestAction::assert_eq(
+            "date.toLocaleString('en-US', { timeZone: 'UTC', hourCycle: 'h23' })",
+            js_str!("5/17/2024, 13:05:00"),
+        ),
+        TestAction::assert_eq(
+            "new Date(NaN).toLocaleString('en-US')",
+            js_str!("Invalid Date"),
+        ),
+    ]);
+}
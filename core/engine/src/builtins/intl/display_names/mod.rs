@@ -0,0 +1,505 @@
+//! This module implements the global `Intl.DisplayNames` object.
+//!
+//! `Intl.DisplayNames` enables the consistent translation of language, region and script
+//! display names.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma402/#intl-displaynames-objects
+
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use icu_experimental::displaynames::{
+    provider::LanguageDisplayNamesV1, DisplayNamesOptions, Fallback, LanguageDisplay,
+    LocaleDisplayNamesFormatter, RegionDisplayNames, ScriptDisplayNames, Style,
+};
+use icu_locale::{
+    extensions::unicode::Value,
+    subtags::{Region, Script},
+    LanguageIdentifier, Locale,
+};
+
+mod options;
+pub(crate) use options::*;
+
+use super::{
+    locale::{canonicalize_locale_list, filter_locales, resolve_locale},
+    number_format::Currency,
+    options::IntlOptions,
+    Service,
+};
+use crate::{
+    builtins::{
+        options::{get_option, get_options_object},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, ObjectInitializer},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    Context, JsArgs, JsData, JsNativeError, JsObject, JsResult, JsString, JsSymbol, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The ICU formatter used to get the display names of a specific type of code.
+enum NativeDisplayNames {
+    Language(Box<LocaleDisplayNamesFormatter>),
+    Region(RegionDisplayNames),
+    Script(ScriptDisplayNames),
+    // TODO: ICU4X doesn't provide display names for currencies, calendars or date-time fields
+    // yet, so `of` always falls back to the code for those types.
+    Unsupported,
+}
+
+impl std::fmt::Debug for NativeDisplayNames {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Language(_) => "Language",
+            Self::Region(_) => "Region",
+            Self::Script(_) => "Script",
+            Self::Unsupported => "Unsupported",
+        };
+        f.debug_tuple(name).finish_non_exhaustive()
+    }
+}
+
+/// JavaScript `Intl.DisplayNames` object.
+#[derive(Debug, Trace, Finalize, JsData)]
+// Safety: `DisplayNames` only contains non-traceable types.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct DisplayNames {
+    locale: Locale,
+    style: Style,
+    typ: DisplayNamesType,
+    fallback: Fallback,
+    language_display: Option<LanguageDisplay>,
+    native: NativeDisplayNames,
+}
+
+impl Service for DisplayNames {
+    type LangMarker = LanguageDisplayNamesV1;
+
+    type LocaleOptions = ();
+}
+
+impl IntrinsicObject for DisplayNames {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.DisplayNames"),
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::of, js_string!("of"), 1)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DisplayNames {
+    const NAME: JsString = StaticJsStrings::DISPLAY_NAMES;
+}
+
+impl BuiltInConstructor for DisplayNames {
+    const LENGTH: usize = 2;
+    const P: usize = 3;
+    const SP: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::display_names;
+
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.DisplayNames` constructor without `new`")
+                .into());
+        }
+
+        // 2. Let displayNames be ? OrdinaryCreateFromConstructor(NewTarget, "%Intl.DisplayNames.prototype%",
+        //    « [[InitializedDisplayNames]], [[Locale]], [[Style]], [[Type]], [[Fallback]],
+        //    [[LanguageDisplay]], [[Fields]] »).
+        let proto = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::display_names,
+            context,
+        )?;
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 4. If options is undefined, throw a TypeError exception.
+        if options.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("`Intl.DisplayNames` requires an options object")
+                .into());
+        }
+
+        // 5. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(options)?;
+
+        // 6. Let opt be a new Record.
+        // 7. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 8. Set opt.[[localeMatcher]] to matcher.
+        let matcher =
+            get_option(&options, js_string!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 9. Let r be ResolveLocale(%Intl.DisplayNames%.[[AvailableLocales]], requestedLocales, opt,
+        //    %Intl.DisplayNames%.[[RelevantExtensionKeys]], %Intl.DisplayNames%.[[LocaleData]]).
+        let locale = resolve_locale::<Self>(
+            requested_locales,
+            &mut IntlOptions {
+                matcher,
+                ..Default::default()
+            },
+            context.intl_provider(),
+        )?;
+
+        // 10. Let style be ? GetOption(options, "style", string, « "narrow", "short", "long" », "long").
+        // 11. Set displayNames.[[Style]] to style.
+        let style = get_option(&options, js_string!("style"), context)?.unwrap_or(Style::Long);
+
+        // 12. Let type be ? GetOption(options, "type", string, « "language", "region", "script",
+        //     "currency", "calendar", "dateTimeField" », undefined).
+        // 13. If type is undefined, throw a TypeError exception.
+        // 14. Set displayNames.[[Type]] to type.
+        let typ = get_option::<DisplayNamesType>(&options, js_string!("type"), context)?
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("`Intl.DisplayNames` requires a `type` option")
+            })?;
+
+        // 15. Let fallback be ? GetOption(options, "fallback", string, « "code", "none" », "code").
+        // 16. Set displayNames.[[Fallback]] to fallback.
+        let fallback =
+            get_option(&options, js_string!("fallback"), context)?.unwrap_or(Fallback::Code);
+
+        // 17. Set displayNames.[[Locale]] to r.[[Locale]].
+        // 18. Let resolvedLocaleData be r.[[LocaleData]].
+        // 19. Let types be resolvedLocaleData.[[types]].
+        // 20. Assert: types is a Record (see 12.2.3).
+        // 21. Let languageDisplay be ? GetOption(options, "languageDisplay", string, « "dialect", "standard" », "dialect").
+        let language_display = get_option(&options, js_string!("languageDisplay"), context)?
+            .unwrap_or(LanguageDisplay::Dialect);
+
+        // 22. Let typeFields be types.[[<type>]].
+        // 23. Assert: typeFields is a Record (see 12.2.3).
+        // 24. If type is "language", then
+        //     a. Set displayNames.[[LanguageDisplay]] to languageDisplay.
+        //     b. Let typeFields be typeFields.[[<languageDisplay>]].
+        //     c. Assert: typeFields is a Record (see 12.2.3).
+        // 25. Let styleFields be typeFields.[[<style>]].
+        // 26. Assert: styleFields is a Record (see 12.2.3).
+        // 27. Set displayNames.[[Fields]] to styleFields.
+        let mut icu_options = DisplayNamesOptions::default();
+        icu_options.style = Some(style);
+        icu_options.fallback = fallback;
+        icu_options.language_display = language_display;
+
+        let provider = context.intl_provider().erased_provider();
+        let native = match typ {
+            DisplayNamesType::Language => NativeDisplayNames::Language(Box::new(
+                LocaleDisplayNamesFormatter::try_new_with_buffer_provider(
+                    provider,
+                    (&locale).into(),
+                    icu_options,
+                )
+                .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?,
+            )),
+            DisplayNamesType::Region => NativeDisplayNames::Region(
+                RegionDisplayNames::try_new_with_buffer_provider(
+                    provider,
+                    (&locale).into(),
+                    icu_options,
+                )
+                .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?,
+            ),
+            DisplayNamesType::Script => NativeDisplayNames::Script(
+                ScriptDisplayNames::try_new_with_buffer_provider(
+                    provider,
+                    (&locale).into(),
+                    icu_options,
+                )
+                .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?,
+            ),
+            DisplayNamesType::Currency
+            | DisplayNamesType::Calendar
+            | DisplayNamesType::DateTimeField => NativeDisplayNames::Unsupported,
+        };
+
+        // 28. Return displayNames.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            proto,
+            Self {
+                locale,
+                style,
+                typ,
+                fallback,
+                language_display: (typ == DisplayNamesType::Language).then_some(language_display),
+                native,
+            },
+        )
+        .into())
+    }
+}
+
+impl DisplayNames {
+    /// [`Intl.DisplayNames.prototype.of ( code )`][spec].
+    ///
+    /// Returns the locale-aware display name of the provided code.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.prototype.of
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/of
+    fn of(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let displayNames be this value.
+        // 2. Perform ? RequireInternalSlot(displayNames, [[InitializedDisplayNames]]).
+        let display_names = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`of` can only be called on an `Intl.DisplayNames` object")
+            })?;
+        let display_names = display_names.borrow();
+        let display_names = &display_names.data;
+
+        // 3. Let code be ? ToString(code).
+        let code = args
+            .get_or_undefined(0)
+            .to_string(context)?
+            .to_std_string_escaped();
+
+        // 4. Set code to ? CanonicalCodeForDisplayNames(displayNames.[[Type]], code).
+        let code = canonical_code_for_display_names(display_names.typ, &code, context)?;
+
+        // 5. Let fields be displayNames.[[Fields]].
+        // 6. If fields has a field [[<code>]], return fields.[[<code>]].
+        let name = match &display_names.native {
+            NativeDisplayNames::Language(formatter) => {
+                let locale = code
+                    .parse::<Locale>()
+                    .expect("canonical language codes must be valid locales");
+                // The formatter always falls back to a generated name, so a name equal to the
+                // code means the locale doesn't have a display name for it.
+                Some(formatter.of(&locale).into_owned()).filter(|name| *name != code)
+            }
+            NativeDisplayNames::Region(names) => code
+                .parse::<Region>()
+                .ok()
+                .and_then(|region| names.of(region))
+                .map(str::to_owned),
+            NativeDisplayNames::Script(names) => code
+                .parse::<Script>()
+                .ok()
+                .and_then(|script| names.of(script))
+                .map(str::to_owned),
+            NativeDisplayNames::Unsupported => None,
+        };
+
+        if let Some(name) = name {
+            return Ok(js_string!(name).into());
+        }
+
+        // 7. If displayNames.[[Fallback]] is "code", return code.
+        if display_names.fallback == Fallback::Code {
+            return Ok(js_string!(code).into());
+        }
+
+        // 8. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// [`Intl.DisplayNames.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in display
+    /// names without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %Intl.DisplayNames%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? FilterLocales(availableLocales, requestedLocales, options).
+        filter_locales::<Self>(requested_locales, options, context).map(JsValue::from)
+    }
+
+    /// [`Intl.DisplayNames.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and options computed during the
+    /// construction of the current `Intl.DisplayNames` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.prototype.resolvedOptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let displayNames be this value.
+        // 2. Perform ? RequireInternalSlot(displayNames, [[InitializedDisplayNames]]).
+        let display_names = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`resolvedOptions` can only be called on an `Intl.DisplayNames` object",
+                )
+            })?;
+        let display_names = display_names.borrow();
+        let display_names = &display_names.data;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 4. For each row of Table 18, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of displayNames's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut options = ObjectInitializer::new(context);
+        options
+            .property(
+                js_string!("locale"),
+                js_string!(display_names.locale.to_string()),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("style"),
+                style_to_js_string(display_names.style),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("type"),
+                display_names.typ.to_js_string(),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("fallback"),
+                fallback_to_js_string(display_names.fallback),
+                Attribute::all(),
+            );
+
+        if let Some(language_display) = display_names.language_display {
+            options.property(
+                js_string!("languageDisplay"),
+                language_display_to_js_string(language_display),
+                Attribute::all(),
+            );
+        }
+
+        // 5. Return options.
+        Ok(options.build().into())
+    }
+}
+
+/// Abstract operation [`CanonicalCodeForDisplayNames ( type, code )`][spec].
+///
+/// Validates the provided code for the type of display names and returns its canonical form,
+/// throwing a `RangeError` if the code is not well-formed.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-canonicalcodefordisplaynames
+fn canonical_code_for_display_names(
+    typ: DisplayNamesType,
+    code: &str,
+    context: &mut Context,
+) -> JsResult<String> {
+    let invalid = || {
+        JsNativeError::range().with_message(format!(
+            "invalid code `{code}` for display names of type `{}`",
+            typ.to_js_string().to_std_string_escaped()
+        ))
+    };
+
+    match typ {
+        // 1. If type is "language", then
+        DisplayNamesType::Language => {
+            // a. If code cannot be matched by the unicode_language_id Unicode locale nonterminal, throw a RangeError exception.
+            // b. If IsStructurallyValidLanguageTag(code) is false, throw a RangeError exception.
+            if code.contains('_') {
+                return Err(invalid().into());
+            }
+            let id = LanguageIdentifier::try_from_str(code).map_err(|_| invalid())?;
+
+            // c. Return CanonicalizeUnicodeLocaleId(code).
+            let mut locale = Locale::from(id);
+            context
+                .intl_provider()
+                .locale_canonicalizer()?
+                .canonicalize(&mut locale);
+            Ok(locale.to_string())
+        }
+        // 2. If type is "region", then
+        //     a. If code cannot be matched by the unicode_region_subtag Unicode locale nonterminal, throw a RangeError exception.
+        //     b. Return the ASCII-uppercase of code.
+        DisplayNamesType::Region => Region::try_from_str(code)
+            .map(|region| region.to_string())
+            .map_err(|_| invalid().into()),
+        // 3. If type is "script", then
+        //     a. If code cannot be matched by the unicode_script_subtag Unicode locale nonterminal, throw a RangeError exception.
+        //     b. Assert: The length of code is 4, and every code unit of code represents an ASCII letter (0x0041 through 0x005A and 0x0061 through 0x007A, both inclusive).
+        //     c. Let first be the ASCII-uppercase of the substring of code from 0 to 1.
+        //     d. Let rest be the ASCII-lowercase of the substring of code from 1.
+        //     e. Return the string-concatenation of first and rest.
+        DisplayNamesType::Script => Script::try_from_str(code)
+            .map(|script| script.to_string())
+            .map_err(|_| invalid().into()),
+        // 4. If type is "calendar", then
+        //     a. If code cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        //     b. If code uses any of the backwards compatibility syntax described in Unicode Technical Standard #35 Part 1 Core, Section 3.3 BCP 47 Conformance, throw a RangeError exception.
+        //     c. Return the ASCII-lowercase of code.
+        DisplayNamesType::Calendar => Value::try_from_str(code)
+            .ok()
+            .filter(|_| code.split('-').all(|subtag| subtag.len() >= 3))
+            .map(|value| value.to_string())
+            .ok_or_else(|| invalid().into()),
+        // 5. If type is "dateTimeField", then
+        //     a. If the result of IsValidDateTimeFieldCode(code) is false, throw a RangeError exception.
+        //     b. Return code.
+        DisplayNamesType::DateTimeField => DATE_TIME_FIELDS
+            .contains(&code)
+            .then(|| code.to_owned())
+            .ok_or_else(|| invalid().into()),
+        // 6. Assert: type is "currency".
+        // 7. If IsWellFormedCurrencyCode(code) is false, throw a RangeError exception.
+        // 8. Return the ASCII-uppercase of code.
+        DisplayNamesType::Currency => code
+            .parse::<Currency>()
+            .map(|currency| currency.to_js_string().to_std_string_escaped())
+            .map_err(|_| invalid().into()),
+    }
+}
@@ -0,0 +1,143 @@
+use std::{fmt, str::FromStr};
+
+use icu_experimental::displaynames::{Fallback, LanguageDisplay, Style};
+
+use crate::{
+    builtins::options::{OptionType, ParsableOptionType},
+    js_string, Context, JsNativeError, JsResult, JsString, JsValue,
+};
+
+impl OptionType for Style {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "narrow" => Ok(Self::Narrow),
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `narrow`, `short` or `long`")
+                .into()),
+        }
+    }
+}
+
+impl OptionType for Fallback {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "code" => Ok(Self::Code),
+            "none" => Ok(Self::None),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `code` or `none`")
+                .into()),
+        }
+    }
+}
+
+impl OptionType for LanguageDisplay {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "dialect" => Ok(Self::Dialect),
+            "standard" => Ok(Self::Standard),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `dialect` or `standard`")
+                .into()),
+        }
+    }
+}
+
+pub(crate) fn style_to_js_string(style: Style) -> JsString {
+    match style {
+        Style::Narrow => js_string!("narrow"),
+        Style::Short => js_string!("short"),
+        _ => js_string!("long"),
+    }
+}
+
+pub(crate) fn fallback_to_js_string(fallback: Fallback) -> JsString {
+    if fallback == Fallback::None {
+        js_string!("none")
+    } else {
+        js_string!("code")
+    }
+}
+
+pub(crate) fn language_display_to_js_string(language_display: LanguageDisplay) -> JsString {
+    if language_display == LanguageDisplay::Standard {
+        js_string!("standard")
+    } else {
+        js_string!("dialect")
+    }
+}
+
+/// The kind of codes that can be displayed by an `Intl.DisplayNames` object.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum DisplayNamesType {
+    Language,
+    Region,
+    Script,
+    Currency,
+    Calendar,
+    DateTimeField,
+}
+
+impl DisplayNamesType {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Language => js_string!("language"),
+            Self::Region => js_string!("region"),
+            Self::Script => js_string!("script"),
+            Self::Currency => js_string!("currency"),
+            Self::Calendar => js_string!("calendar"),
+            Self::DateTimeField => js_string!("dateTimeField"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseDisplayNamesTypeError;
+
+impl fmt::Display for ParseDisplayNamesTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(
+            "provided string was not `language`, `region`, `script`, `currency`, \
+            `calendar` or `dateTimeField`",
+        )
+    }
+}
+
+impl FromStr for DisplayNamesType {
+    type Err = ParseDisplayNamesTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "language" => Ok(Self::Language),
+            "region" => Ok(Self::Region),
+            "script" => Ok(Self::Script),
+            "currency" => Ok(Self::Currency),
+            "calendar" => Ok(Self::Calendar),
+            "dateTimeField" => Ok(Self::DateTimeField),
+            _ => Err(ParseDisplayNamesTypeError),
+        }
+    }
+}
+
+impl ParsableOptionType for DisplayNamesType {}
+
+/// The valid codes of an `Intl.DisplayNames` object with type `"dateTimeField"`.
+///
+/// See the [Table 19: Codes For Date Time Field of DisplayNames][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#table-validcodefordatetimefield
+pub(crate) const DATE_TIME_FIELDS: [&str; 12] = [
+    "era",
+    "year",
+    "quarter",
+    "month",
+    "weekOfYear",
+    "weekday",
+    "day",
+    "dayPeriod",
+    "hour",
+    "minute",
+    "second",
+    "timeZoneName",
+];
@@ -0,0 +1,103 @@
+use crate::{run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+use boa_macros::js_str;
+use indoc::indoc;
+
+#[test]
+fn of() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                const languages = new Intl.DisplayNames('en-US', { type: 'language' });
+                const regions = new Intl.DisplayNames('en-US', { type: 'region' });
+                const scripts = new Intl.DisplayNames('en-US', { type: 'script' });
+            "#}),
+        TestAction::assert_eq("languages.of('fr')", js_str!("French")),
+        TestAction::assert_eq("languages.of('en-GB')", js_str!("British English")),
+        TestAction::assert_eq("regions.of('de')", js_str!("Germany")),
+        TestAction::assert_eq("regions.of('419')", js_str!("Latin America")),
+        TestAction::assert_eq("scripts.of('latn')", js_str!("Latin")),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('de', { type: 'region' }).of('US')",
+            js_str!("Vereinigte Staaten"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en-US', { type: 'language', languageDisplay: 'standard' }).of('en-GB')",
+            js_str!("English (United Kingdom)"),
+        ),
+    ]);
+}
+
+#[test]
+fn fallback() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en-US', { type: 'region' }).of('xa')",
+            js_str!("XA"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en-US', { type: 'region', fallback: 'none' }).of('XA')",
+            JsValue::undefined(),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en-US', { type: 'currency' }).of('eur')",
+            js_str!("EUR"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en-US', { type: 'dateTimeField' }).of('weekOfYear')",
+            js_str!("weekOfYear"),
+        ),
+    ]);
+}
+
+#[test]
+fn invalid_codes() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en-US', { type: 'region' }).of('ABCD')",
+            JsNativeErrorKind::Range,
+            "invalid code `ABCD` for display names of type `region`",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en-US', { type: 'language' }).of('en_US')",
+            JsNativeErrorKind::Range,
+            "invalid code `en_US` for display names of type `language`",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en-US', { type: 'dateTimeField' }).of('week')",
+            JsNativeErrorKind::Range,
+            "invalid code `week` for display names of type `dateTimeField`",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en-US')",
+            JsNativeErrorKind::Type,
+            "`Intl.DisplayNames` requires an options object",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en-US', {})",
+            JsNativeErrorKind::Type,
+            "`Intl.DisplayNames` requires a `type` option",
+        ),
+    ]);
+}
+
+#[test]
+fn resolved_options() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                const language = new Intl.DisplayNames('en-US', { type: 'language' })
+                    .resolvedOptions();
+                const region = new Intl.DisplayNames('en-US', {
+                    type: 'region',
+                    style: 'short',
+                    fallback: 'none',
+                }).resolvedOptions();
+            "#}),
+        TestAction::assert_eq("language.locale", js_str!("en-US")),
+        TestAction::assert_eq("language.style", js_str!("long")),
+        TestAction::assert_eq("language.type", js_str!("language")),
+        TestAction::assert_eq("language.fallback", js_str!("code")),
+        TestAction::assert_eq("language.languageDisplay", js_str!("dialect")),
+        TestAction::assert_eq("region.style", js_str!("short")),
+        TestAction::assert_eq("region.fallback", js_str!("none")),
+        TestAction::assert("!('languageDisplay' in region)"),
+    ]);
+}
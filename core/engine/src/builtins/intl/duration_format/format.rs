@@ -0,0 +1,535 @@
+//! Implementation of the [`PartitionDurationFormatPattern ( durationFormat, duration )`][spec]
+//! abstract operation.
+//!
+//! [spec]: https://tc39.es/ecma402/#sec-partitiondurationformatpattern
+
+use std::fmt;
+
+use fixed_decimal::{Decimal, Sign, UnsignedDecimal};
+use icu_decimal::{options::DecimalFormatterOptions, DecimalFormatter, FormattedDecimal};
+use icu_experimental::{
+    dimension::units::{
+        format::FormattedUnit,
+        formatter::UnitsFormatter,
+        options::{UnitsFormatterOptions, Width},
+    },
+    duration::provider::DigitalDurationDataV1,
+};
+use icu_list::{
+    options::{ListFormatterOptions, ListLength},
+    ListFormatter,
+};
+use icu_locale::Locale;
+use icu_provider::{DataError, DataIdentifierBorrowed, DataLocale, DataPayload, DataRequest};
+use writeable::{Part, PartsWrite, Writeable};
+
+use super::{
+    BaseStyle, DurationFormat, DurationRecord, DurationUnit, DurationUnitOptions, UnitDisplay,
+    UnitStyle,
+};
+use crate::context::icu::IntlProvider;
+
+/// The ICU4X formatters used to format every part of a duration.
+pub(super) struct DurationFormatters {
+    /// The unit formatters, in the same order as [`DurationUnit::ALL`].
+    units: Box<[UnitsFormatter]>,
+    list: ListFormatter,
+    decimal: DecimalFormatter,
+    digital: DataPayload<DigitalDurationDataV1>,
+}
+
+impl fmt::Debug for DurationFormatters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DurationFormatters")
+            .field("separator", &self.digital.get().separator)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DurationFormatters {
+    /// Creates the formatters for the resolved locale and options of a `DurationFormat`.
+    pub(super) fn try_new(
+        locale: &Locale,
+        style: BaseStyle,
+        units: &[DurationUnitOptions; 10],
+        provider: &IntlProvider,
+    ) -> Result<Self, DataError> {
+        let units = DurationUnit::ALL
+            .into_iter()
+            .zip(units)
+            .map(|(unit, options)| {
+                // The unit formatters replace the `Intl.NumberFormat` with a "unit" style of
+                // step 4.f.iii of PartitionDurationFormatPattern. Units with a numeric style are
+                // only formatted with them when they are the smallest unit that is displayed,
+                // and the spec uses the base style as their `unitDisplay` in that case.
+                let width = match options.style {
+                    UnitStyle::Long => Width::Long,
+                    UnitStyle::Short => Width::Short,
+                    UnitStyle::Narrow => Width::Narrow,
+                    UnitStyle::Numeric | UnitStyle::TwoDigit | UnitStyle::Fractional => match style
+                    {
+                        BaseStyle::Long => Width::Long,
+                        BaseStyle::Short | BaseStyle::Digital => Width::Short,
+                        BaseStyle::Narrow => Width::Narrow,
+                    },
+                };
+                let mut options = UnitsFormatterOptions::default();
+                options.width = width;
+                UnitsFormatter::try_new_with_buffer_provider(
+                    provider.erased_provider(),
+                    locale.into(),
+                    unit.number_format_unit(),
+                    options,
+                )
+            })
+            .collect::<Result<_, _>>()?;
+
+        // ListFormatParts ( durationFormat, partitionedPartsList )
+        // 1. Let lfOpts be OrdinaryObjectCreate(null).
+        // 2. Perform ! CreateDataPropertyOrThrow(lfOpts, "type", "unit").
+        // 3. Let listStyle be durationFormat.[[Style]].
+        // 4. If listStyle is "digital", then
+        //     a. Set listStyle to "short".
+        // 5. Perform ! CreateDataPropertyOrThrow(lfOpts, "style", listStyle).
+        // 6. Let lf be ! Construct(%Intl.ListFormat%, « durationFormat.[[Locale]], lfOpts »).
+        let length = match style {
+            BaseStyle::Long => ListLength::Wide,
+            BaseStyle::Short | BaseStyle::Digital => ListLength::Short,
+            BaseStyle::Narrow => ListLength::Narrow,
+        };
+        let list = ListFormatter::try_new_unit_with_buffer_provider(
+            provider.erased_provider(),
+            locale.into(),
+            ListFormatterOptions::default().with_length(length),
+        )?;
+
+        let decimal = DecimalFormatter::try_new_with_buffer_provider(
+            provider.erased_provider(),
+            locale.into(),
+            DecimalFormatterOptions::default(),
+        )?;
+
+        let digital = icu_provider::DataProvider::<DigitalDurationDataV1>::load(
+            provider,
+            DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&DataLocale::from(locale)),
+                ..Default::default()
+            },
+        )?
+        .payload;
+
+        Ok(Self {
+            units,
+            list,
+            decimal,
+            digital,
+        })
+    }
+}
+
+/// The `Writeable` returned by [`DurationFormat::partition`].
+///
+/// Text written inside a formatted unit is wrapped in a part with the `"duration"` category and
+/// the `NumberFormat` unit of the current unit as its value. The text of the numbers is wrapped
+/// in the parts of the `"decimal"` category written by [`DecimalFormatter`].
+pub(crate) struct FormattedDuration<'a> {
+    df: &'a DurationFormat,
+    /// The absolute values of the units of the duration.
+    values: [u64; 10],
+    sign: Sign,
+}
+
+/// A formatted entry of the list of parts of a duration.
+enum DurationPart {
+    /// A unit formatted with its unit label.
+    Unit(DurationUnit, Decimal),
+    /// Hours, minutes and seconds formatted as a digital clock.
+    Digital(DigitalParts),
+}
+
+/// The numeric hours, minutes and seconds of a duration, if displayed.
+#[derive(Default)]
+struct DigitalParts {
+    hours: Option<Decimal>,
+    minutes: Option<Decimal>,
+    seconds: Option<Decimal>,
+}
+
+impl<'a> FormattedDuration<'a> {
+    pub(super) fn new(df: &'a DurationFormat, duration: &DurationRecord) -> Self {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let values = duration.0.map(|v| v.abs() as u64);
+        let sign = if duration.sign() < 0.0 {
+            Sign::Negative
+        } else {
+            Sign::None
+        };
+        Self { df, values, sign }
+    }
+
+    fn value(&self, unit: DurationUnit) -> u64 {
+        self.values[unit as usize]
+    }
+
+    fn options(&self, unit: DurationUnit) -> DurationUnitOptions {
+        self.df.units[unit as usize]
+    }
+
+    /// Applies the sign of the duration to `value` if it is the first unit that is displayed.
+    fn apply_sign(&self, value: &mut Decimal, sign_displayed: &mut bool) {
+        // If signDisplayed is false, the `Intl.NumberFormat` of the unit uses a "never" sign
+        // display. The values of the units are absolute, so only the first displayed unit gets
+        // the sign of the duration, which also formats a zero value as negative zero.
+        if *sign_displayed {
+            value.set_sign(self.sign);
+            *sign_displayed = false;
+        }
+    }
+
+    /// Abstract operation [`AddFractionalDigits ( durationFormat, duration )`][spec].
+    ///
+    /// Unlike the spec, this returns the value of `unit` with the sum of the smaller units that
+    /// use the "fractional" style already added to it.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-addfractionaldigits
+    fn add_fractional_digits(&self, unit: DurationUnit) -> Decimal {
+        debug_assert!(matches!(
+            unit,
+            DurationUnit::Seconds | DurationUnit::Milliseconds | DurationUnit::Microseconds
+        ));
+
+        // 1. Let result be 0.
+        // 2. Let exponent be 3.
+        // 3. For each row of Table 23, except the header row, in table order, do
+        //     a. Let style be the value of durationFormat's internal slot whose name is the Style Slot value of the current row.
+        //     b. If style is "fractional", then
+        //         i. Assert: The Unit value of the current row is "milliseconds", "microseconds", or "nanoseconds".
+        //         ii. Let value be the value of duration's field whose name is the Value Field value of the current row.
+        //         iii. Set value to value / 10**exponent.
+        //         iv. Set result to result + value.
+        //         v. Set exponent to exponent + 3.
+        // 4. Return result.
+        // All the values are integers, so the sum is computed exactly by scaling it to the
+        // smallest fractional unit.
+        let mut value = u128::from(self.value(unit));
+        let mut exponent = 0;
+        for next in DurationUnit::ALL[unit as usize + 1..]
+            .iter()
+            .take_while(|next| self.options(**next).style == UnitStyle::Fractional)
+        {
+            value = value * 1000 + u128::from(self.value(*next));
+            exponent -= 3;
+        }
+
+        Decimal::new(
+            Sign::None,
+            UnsignedDecimal::from(value).multiplied_pow10(exponent),
+        )
+    }
+
+    /// Truncates the fractional digits of `value` to the `fractionalDigits` option.
+    fn apply_fractional_digits(&self, value: &mut Decimal) {
+        match self.df.fractional_digits {
+            // If durationFormat.[[FractionalDigits]] is undefined, then
+            //     a. Let maximumFractionDigits be 9𝔽.
+            //     b. Let minimumFractionDigits be +0𝔽.
+            None => {
+                value.trunc(-9);
+                value.absolute.pad_end(0);
+            }
+            // Else,
+            //     a. Let maximumFractionDigits be durationFormat.[[FractionalDigits]].
+            //     b. Let minimumFractionDigits be durationFormat.[[FractionalDigits]].
+            Some(digits) => {
+                let digits = i16::from(digits);
+                value.trunc(-digits);
+                value.absolute.pad_end(-digits);
+            }
+        }
+        // Perform ! CreateDataPropertyOrThrow(nfOpts, "roundingMode", "trunc").
+    }
+
+    /// Formats a numeric hours, minutes or seconds value, following the steps shared by the
+    /// [`FormatNumericHours`][hours], [`FormatNumericMinutes`][minutes] and
+    /// [`FormatNumericSeconds`][seconds] abstract operations.
+    ///
+    /// [hours]: https://tc39.es/ecma402/#sec-formatnumerichours
+    /// [minutes]: https://tc39.es/ecma402/#sec-formatnumericminutes
+    /// [seconds]: https://tc39.es/ecma402/#sec-formatnumericseconds
+    fn format_numeric(
+        &self,
+        unit: DurationUnit,
+        mut value: Decimal,
+        sign_displayed: &mut bool,
+    ) -> Decimal {
+        // Assert: style is "numeric" or style is "2-digit".
+        let style = self.options(unit).style;
+        debug_assert!(matches!(style, UnitStyle::Numeric | UnitStyle::TwoDigit));
+
+        // If style is "2-digit", then
+        if style == UnitStyle::TwoDigit {
+            // a. Perform ! CreateDataPropertyOrThrow(nfOpts, "minimumIntegerDigits", 2𝔽).
+            value.absolute.pad_start(2);
+        }
+
+        // If signDisplayed is false, then
+        //     a. Perform ! CreateDataPropertyOrThrow(nfOpts, "signDisplay", "never").
+        self.apply_sign(&mut value, sign_displayed);
+        value
+    }
+
+    /// Abstract operation [`FormatNumericUnits ( durationFormat, duration, firstNumericUnit, signDisplayed )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatnumericunits
+    fn format_numeric_units(
+        &self,
+        first_numeric_unit: DurationUnit,
+        sign_displayed: &mut bool,
+    ) -> DigitalParts {
+        // 1. Assert: firstNumericUnit is "hours", "minutes", or "seconds".
+        debug_assert!(matches!(
+            first_numeric_unit,
+            DurationUnit::Hours | DurationUnit::Minutes | DurationUnit::Seconds
+        ));
+
+        // 2. Let numericPartsList be a new empty List.
+        let mut parts = DigitalParts::default();
+
+        // 3. Let hoursValue be duration.[[Hours]].
+        // 4. Let hoursDisplay be durationFormat.[[HoursOptions]].[[Display]].
+        // 5. Let minutesValue be duration.[[Minutes]].
+        // 6. Let minutesDisplay be durationFormat.[[MinutesOptions]].[[Display]].
+        // 7. Let secondsValue be duration.[[Seconds]].
+        // 8. If duration.[[Milliseconds]] is not 0 or duration.[[Microseconds]] is not 0 or duration.[[Nanoseconds]] is not 0, then
+        //     a. Set secondsValue to secondsValue + AddFractionalDigits(durationFormat, duration).
+        let mut seconds_value = self.add_fractional_digits(DurationUnit::Seconds);
+
+        // 9. Let secondsDisplay be durationFormat.[[SecondsOptions]].[[Display]].
+        // 10. Let hoursFormatted be false.
+        // 11. If firstNumericUnit is "hours", then
+        //     a. If hoursValue is not 0 or hoursDisplay is "always", then
+        //         i. Set hoursFormatted to true.
+        let hours_formatted = first_numeric_unit == DurationUnit::Hours
+            && (self.value(DurationUnit::Hours) != 0
+                || self.options(DurationUnit::Hours).display == UnitDisplay::Always);
+
+        // 12. If secondsValue is not 0 or secondsDisplay is "always", then
+        //     a. Let secondsFormatted be true.
+        // 13. Else,
+        //     a. Let secondsFormatted be false.
+        let seconds_formatted = !seconds_value.absolute.is_zero()
+            || self.options(DurationUnit::Seconds).display == UnitDisplay::Always;
+
+        // 14. Let minutesFormatted be false.
+        // 15. If firstNumericUnit is "hours" or firstNumericUnit is "minutes", then
+        //     a. If hoursFormatted is true and secondsFormatted is true, then
+        //         i. Set minutesFormatted to true.
+        //     b. Else if minutesValue is not 0 or minutesDisplay is "always", then
+        //         i. Set minutesFormatted to true.
+        let minutes_formatted = first_numeric_unit != DurationUnit::Seconds
+            && ((hours_formatted && seconds_formatted)
+                || self.value(DurationUnit::Minutes) != 0
+                || self.options(DurationUnit::Minutes).display == UnitDisplay::Always);
+
+        // 16. If hoursFormatted is true, then
+        if hours_formatted {
+            // a. Append FormatNumericHours(durationFormat, hoursValue, signDisplayed) to numericPartsList.
+            // b. Set signDisplayed to false.
+            let hours = Decimal::from(self.value(DurationUnit::Hours));
+            parts.hours = Some(self.format_numeric(DurationUnit::Hours, hours, sign_displayed));
+        }
+
+        // 17. If minutesFormatted is true, then
+        if minutes_formatted {
+            // a. Append FormatNumericMinutes(durationFormat, minutesValue, hoursFormatted, signDisplayed) to numericPartsList.
+            // b. Set signDisplayed to false.
+            let minutes = Decimal::from(self.value(DurationUnit::Minutes));
+            parts.minutes =
+                Some(self.format_numeric(DurationUnit::Minutes, minutes, sign_displayed));
+        }
+
+        // 18. If secondsFormatted is true, then
+        if seconds_formatted {
+            // a. Append FormatNumericSeconds(durationFormat, secondsValue, minutesFormatted, signDisplayed) to numericPartsList.
+            // b. Set signDisplayed to false.
+            self.apply_fractional_digits(&mut seconds_value);
+            parts.seconds =
+                Some(self.format_numeric(DurationUnit::Seconds, seconds_value, sign_displayed));
+        }
+
+        // 19. Return numericPartsList.
+        parts
+    }
+
+    /// Steps 1 to 4 of [`PartitionDurationFormatPattern ( durationFormat, duration )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitiondurationformatpattern
+    fn partition(&self) -> Vec<DurationPart> {
+        // 1. Let result be a new empty List.
+        let mut result = Vec::new();
+
+        // 2. Let signDisplayed be true.
+        let mut sign_displayed = true;
+
+        // 3. Let numericUnitFound be false.
+        // 4. While numericUnitFound is false, repeat for each row in Table 23 in table order, except the header row:
+        for unit in DurationUnit::ALL {
+            // a. Let value be the value of duration's field whose name is the Value Field value of the current row.
+            // b. Let style be the value of durationFormat's internal slot whose name is the Style Slot value of the current row.
+            // c. Let display be the value of durationFormat's internal slot whose name is the Display Slot value of the current row.
+            // d. Let unit be the Unit value of the current row.
+            let value = self.value(unit);
+            let DurationUnitOptions { style, display } = self.options(unit);
+
+            // e. If style is "numeric" or "2-digit", then
+            if matches!(style, UnitStyle::Numeric | UnitStyle::TwoDigit) {
+                // i. Append FormatNumericUnits(durationFormat, duration, unit, signDisplayed) to result.
+                result.push(DurationPart::Digital(
+                    self.format_numeric_units(unit, &mut sign_displayed),
+                ));
+                // ii. Set numericUnitFound to true.
+                break;
+            }
+
+            // f. Else,
+            //     i. Let nfOpts be OrdinaryObjectCreate(null).
+            //     ii. If unit is "seconds", "milliseconds", or "microseconds", then
+            //         1. If NextUnitFractional(durationFormat, unit) is true, then
+            let next_unit_fractional =
+                matches!(
+                    unit,
+                    DurationUnit::Seconds | DurationUnit::Milliseconds | DurationUnit::Microseconds
+                ) && self.options(DurationUnit::ALL[unit as usize + 1]).style
+                    == UnitStyle::Fractional;
+
+            let mut formatted = if next_unit_fractional {
+                // a. Set value to value + AddFractionalDigits(durationFormat, duration).
+                // b-f. Set the fraction digits options of nfOpts.
+                let mut formatted = self.add_fractional_digits(unit);
+                self.apply_fractional_digits(&mut formatted);
+                formatted
+            } else {
+                Decimal::from(value)
+            };
+
+            // iii. If value is not 0 or display is "always", then
+            if !formatted.absolute.is_zero() || display == UnitDisplay::Always {
+                // 1. Let numberingSystem be durationFormat.[[NumberingSystem]].
+                // 2. Perform ! CreateDataPropertyOrThrow(nfOpts, "numberingSystem", numberingSystem).
+                // 3. If signDisplayed is true, then
+                //     a. Set signDisplayed to false.
+                //     b. If value is 0 and DurationSign(duration) is -1, then
+                //         i. Set value to negative-zero.
+                // 4. Else,
+                //     a. Perform ! CreateDataPropertyOrThrow(nfOpts, "signDisplay", "never").
+                self.apply_sign(&mut formatted, &mut sign_displayed);
+
+                // 5. Let numberFormatUnit be the NumberFormat Unit value of the current row.
+                // 6. Perform ! CreateDataPropertyOrThrow(nfOpts, "style", "unit").
+                // 7. Perform ! CreateDataPropertyOrThrow(nfOpts, "unit", numberFormatUnit).
+                // 8. Perform ! CreateDataPropertyOrThrow(nfOpts, "unitDisplay", style).
+                // 9. Let nf be ! Construct(%Intl.NumberFormat%, « durationFormat.[[Locale]], nfOpts »).
+                // 10. Let parts be PartitionNumberPattern(nf, value).
+                // 11. Let list be a new empty List.
+                // 12. For each Record { [[Type]], [[Value]] } part of parts, do
+                //     a. Append the Record { [[Type]]: part.[[Type]], [[Value]]: part.[[Value]], [[Unit]]: numberFormatUnit } to list.
+                // 13. Append list to result.
+                result.push(DurationPart::Unit(unit, formatted));
+            }
+
+            // g. Set numericUnitFound to NextUnitFractional(durationFormat, unit).
+            if next_unit_fractional {
+                break;
+            }
+        }
+
+        result
+    }
+}
+
+/// The `Writeable` of a single entry of the list of parts of a duration.
+enum FormattedPart<'a> {
+    Unit(DurationUnit, FormattedUnit<'a>),
+    Digital {
+        hours: Option<FormattedDecimal<'a>>,
+        minutes: Option<FormattedDecimal<'a>>,
+        seconds: Option<FormattedDecimal<'a>>,
+        separator: &'a str,
+    },
+}
+
+/// Writes `text` inside of the part of `unit`.
+fn write_unit<S: PartsWrite + ?Sized>(
+    sink: &mut S,
+    unit: DurationUnit,
+    text: &impl Writeable,
+) -> fmt::Result {
+    let part = Part {
+        category: "duration",
+        value: unit.number_format_unit(),
+    };
+    sink.with_part(part, |sink| text.write_to_parts(sink))
+}
+
+impl Writeable for FormattedPart<'_> {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        match self {
+            Self::Unit(unit, formatted) => write_unit(sink, *unit, formatted),
+            Self::Digital {
+                hours,
+                minutes,
+                seconds,
+                separator,
+            } => {
+                // FormatNumericMinutes: if hoursDisplayed is true, append the hours-minutes
+                // separator. FormatNumericSeconds: if minutesDisplayed is true, append the
+                // minutes-seconds separator.
+                let mut needs_separator = false;
+                for (unit, value) in [
+                    (DurationUnit::Hours, hours),
+                    (DurationUnit::Minutes, minutes),
+                    (DurationUnit::Seconds, seconds),
+                ] {
+                    let Some(value) = value else {
+                        needs_separator = false;
+                        continue;
+                    };
+                    if needs_separator {
+                        sink.write_str(separator)?;
+                    }
+                    write_unit(sink, unit, value)?;
+                    needs_separator = true;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Writeable for FormattedDuration<'_> {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        let parts = self.partition();
+
+        // 5. Return ListFormatParts(durationFormat, result).
+        let formatters = &self.df.formatters;
+        let separator = &*formatters.digital.get().separator;
+        let list = parts.iter().map(|part| match part {
+            DurationPart::Unit(unit, value) => FormattedPart::Unit(
+                *unit,
+                formatters.units[*unit as usize].format_fixed_decimal(value),
+            ),
+            DurationPart::Digital(DigitalParts {
+                hours,
+                minutes,
+                seconds,
+            }) => FormattedPart::Digital {
+                hours: hours.as_ref().map(|v| formatters.decimal.format(v)),
+                minutes: minutes.as_ref().map(|v| formatters.decimal.format(v)),
+                seconds: seconds.as_ref().map(|v| formatters.decimal.format(v)),
+                separator,
+            },
+        });
+
+        formatters.list.format(list).write_to_parts(sink)
+    }
+}
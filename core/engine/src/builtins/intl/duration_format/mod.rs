@@ -0,0 +1,739 @@
+//! This module implements the global `Intl.DurationFormat` object.
+//!
+//! `Intl.DurationFormat` enables language-sensitive duration formatting, such as
+//! "1 hr, 40 min, 30 sec" or "1:40:30".
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma402/#durationformat-objects
+
+use std::fmt::Write;
+
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use icu_decimal::{preferences::NumberingSystem, provider::DecimalSymbolsV1};
+use icu_locale::{extensions::unicode::Value, Locale};
+
+mod format;
+mod options;
+pub(crate) use options::*;
+
+use format::{DurationFormatters, FormattedDuration};
+
+use super::{
+    locale::{canonicalize_locale_list, filter_locales, resolve_locale},
+    number_format::{NumberFormat, NumberFormatLocaleOptions},
+    options::{get_number_option, IntlOptions},
+    Service,
+};
+use crate::{
+    builtins::{
+        options::{get_option, get_options_object},
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+    },
+    context::{
+        icu::IntlProvider,
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    },
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, ObjectInitializer},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    Context, JsArgs, JsData, JsNativeError, JsObject, JsResult, JsString, JsSymbol, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// JavaScript `Intl.DurationFormat` object.
+#[derive(Debug, Trace, Finalize, JsData)]
+// Safety: `DurationFormat` only contains non-traceable types.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct DurationFormat {
+    locale: Locale,
+    numbering_system: Option<Value>,
+    style: BaseStyle,
+    /// The options of every unit, in the same order as [`DurationUnit::ALL`].
+    units: [DurationUnitOptions; 10],
+    fractional_digits: Option<u8>,
+    formatters: DurationFormatters,
+}
+
+impl Service for DurationFormat {
+    // Every unit of a duration is formatted using an `Intl.NumberFormat`, and the formatted units
+    // are joined using an `Intl.ListFormat`. The decimal symbols are the limiting factor of both,
+    // and the only relevant extension key is the numbering system of the number formatter.
+    type LangMarker = DecimalSymbolsV1;
+
+    type LocaleOptions = NumberFormatLocaleOptions;
+
+    fn resolve(locale: &mut Locale, options: &mut Self::LocaleOptions, provider: &IntlProvider) {
+        NumberFormat::resolve(locale, options, provider);
+    }
+}
+
+impl IntrinsicObject for DurationFormat {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.DurationFormat"),
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format, js_string!("format"), 1)
+            .method(Self::format_to_parts, js_string!("formatToParts"), 1)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DurationFormat {
+    const NAME: JsString = StaticJsStrings::DURATION_FORMAT;
+}
+
+impl BuiltInConstructor for DurationFormat {
+    const LENGTH: usize = 0;
+    const P: usize = 4;
+    const SP: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::duration_format;
+
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.DurationFormat` constructor without `new`")
+                .into());
+        }
+
+        // 2. Let durationFormat be ? OrdinaryCreateFromConstructor(NewTarget, "%Intl.DurationFormatPrototype%",
+        //    « [[InitializedDurationFormat]], [[Locale]], [[DataLocale]], [[NumberingSystem]], [[Style]],
+        //    [[YearsOptions]], [[MonthsOptions]], [[WeeksOptions]], [[DaysOptions]], [[HoursOptions]],
+        //    [[MinutesOptions]], [[SecondsOptions]], [[MillisecondsOptions]], [[MicrosecondsOptions]],
+        //    [[NanosecondsOptions]], [[HourMinuteSeparator]], [[MinuteSecondSeparator]], [[FractionalDigits]] »).
+        let proto = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::duration_format,
+            context,
+        )?;
+
+        let duration_format =
+            Self::try_new(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            proto,
+            duration_format,
+        )
+        .into())
+    }
+}
+
+impl DurationFormat {
+    /// Creates a new `DurationFormat` from the provided `locales` and `options`, following the
+    /// steps 3 to 29 of the [`Intl.DurationFormat ( [ locales [ , options ] ] )`][spec]
+    /// constructor.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat
+    pub(crate) fn try_new(
+        locales: &JsValue,
+        options: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 4. Let options be ? GetOptionsObject(options).
+        let options = get_options_object(options)?;
+
+        // 5. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        let matcher =
+            get_option(&options, js_string!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 6. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 7. If numberingSystem is not undefined, then
+        //     a. If numberingSystem cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        let numbering_system =
+            get_option::<NumberingSystem>(&options, js_string!("numberingSystem"), context)?;
+
+        // 8. Let opt be the Record { [[localeMatcher]]: matcher, [[nu]]: numberingSystem }.
+        let mut intl_options = IntlOptions {
+            matcher,
+            service_options: NumberFormatLocaleOptions {
+                numbering_system: numbering_system.map(Value::from),
+            },
+        };
+
+        // 9. Let r be ResolveLocale(%Intl.DurationFormat%.[[AvailableLocales]], requestedLocales, opt,
+        //    %Intl.DurationFormat%.[[RelevantExtensionKeys]], %Intl.DurationFormat%.[[LocaleData]]).
+        // 10. Let locale be r.[[Locale]].
+        // 11. Set durationFormat.[[Locale]] to locale.
+        // 12. Set durationFormat.[[DataLocale]] to r.[[DataLocale]].
+        // 13. Let dataLocale be durationFormat.[[DataLocale]].
+        // 14. Let dataLocaleData be durationFormat.[[LocaleData]].[[<dataLocale>]].
+        // 15. Let digitalFormat be dataLocaleData.[[DigitalFormat]].
+        // 16. Let twoDigitHours be digitalFormat.[[TwoDigitHours]].
+        // 17. Set durationFormat.[[HourMinuteSeparator]] to digitalFormat.[[HourMinuteSeparator]].
+        // 18. Set durationFormat.[[MinuteSecondSeparator]] to digitalFormat.[[MinuteSecondSeparator]].
+        // 19. Set durationFormat.[[NumberingSystem]] to r.[[nu]].
+        // The digital format data is handled by ICU4X when formatting the duration.
+        let locale = resolve_locale::<Self>(
+            requested_locales,
+            &mut intl_options,
+            context.intl_provider(),
+        )?;
+
+        // 20. Let style be ? GetOption(options, "style", string, « "long", "short", "narrow", "digital" », "short").
+        // 21. Set durationFormat.[[Style]] to style.
+        let style: BaseStyle =
+            get_option(&options, js_string!("style"), context)?.unwrap_or_default();
+
+        // 22. Let prevStyle be the empty String.
+        let mut prev_style = None;
+        let mut units = Vec::with_capacity(DurationUnit::ALL.len());
+
+        // 23. For each row of Table 20, except the header row, in table order, do
+        for unit in DurationUnit::ALL {
+            // a. Let slot be the Internal Slot value of the current row.
+            // b. Let unit be the Unit value of the current row.
+            // c. Let styles be the Styles value of the current row.
+            // d. Let digitalBase be the Digital Default value of the current row.
+            // e. Let unitOptions be ? GetDurationUnitOptions(unit, options, style, styles, digitalBase, prevStyle, twoDigitHours).
+            let unit_options =
+                get_duration_unit_options(unit, &options, style, prev_style, context)?;
+
+            // f. Set the value of the slot slot of durationFormat to unitOptions.
+            units.push(unit_options);
+
+            // g. If unit is one of "hours", "minutes", "seconds", "milliseconds", or "microseconds", then
+            //     i. Set prevStyle to unitOptions.[[Style]].
+            if !unit.is_date() {
+                prev_style = Some(unit_options.style);
+            }
+        }
+        let units: [DurationUnitOptions; 10] = units
+            .try_into()
+            .expect("there must be exactly one options record for every unit");
+
+        // 24. Set durationFormat.[[FractionalDigits]] to ? GetNumberOption(options, "fractionalDigits", 0, 9, undefined).
+        let fractional_digits =
+            get_number_option(&options, js_string!("fractionalDigits"), 0, 9, context)?;
+
+        let formatters =
+            DurationFormatters::try_new(&locale, style, &units, context.intl_provider())
+                .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+        // 25. Return durationFormat.
+        Ok(Self {
+            locale,
+            numbering_system: intl_options.service_options.numbering_system,
+            style,
+            units,
+            fractional_digits,
+            formatters,
+        })
+    }
+
+    /// Abstract operation [`PartitionDurationFormatPattern ( durationFormat, duration )`][spec].
+    ///
+    /// The returned struct implements `Writeable`, allowing to either write the duration as a
+    /// full string or by parts.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitiondurationformatpattern
+    fn partition(&self, duration: &DurationRecord) -> FormattedDuration<'_> {
+        FormattedDuration::new(self, duration)
+    }
+
+    /// [`Intl.DurationFormat.prototype.format ( duration )`][spec].
+    ///
+    /// Formats a duration according to the locale and formatting options of this
+    /// `Intl.DurationFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/format
+    fn format(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        use writeable::Writeable;
+
+        // 1. Let df be this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let df = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`format` can only be called on an `Intl.DurationFormat` object")
+            })?;
+
+        // 3. Let record be ? ToDurationRecord(duration).
+        let record = DurationRecord::from_value(args.get_or_undefined(0), context)?;
+
+        // 4. Let parts be PartitionDurationFormatPattern(df, record).
+        // 5. Let result be the empty String.
+        // 6. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        // 7. Return result.
+        let df = df.borrow();
+        let formatted = df.data.partition(&record);
+        Ok(js_string!(formatted.write_to_string().as_ref()).into())
+    }
+
+    /// [`Intl.DurationFormat.prototype.formatToParts ( duration )`][spec].
+    ///
+    /// Returns an array of objects representing the duration in parts that can be used for
+    /// custom locale-aware formatting.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        use writeable::{PartsWrite, Writeable};
+
+        /// Collects the parts of a formatted duration.
+        ///
+        /// Text written inside of a duration unit is either part of the formatted number, or
+        /// a literal or unit label of that number. All the remaining text is merged into
+        /// `literal` parts.
+        #[derive(Debug, Clone, Default)]
+        struct PartsCollector {
+            parts: Vec<(&'static str, String, Option<&'static str>)>,
+            unit: Option<&'static str>,
+            number: Option<&'static str>,
+        }
+
+        impl Write for PartsCollector {
+            fn write_str(&mut self, s: &str) -> std::fmt::Result {
+                if s.is_empty() {
+                    return Ok(());
+                }
+                let typ = match (self.number, self.unit) {
+                    (Some(number), _) => number,
+                    (None, Some(_)) if s.chars().any(char::is_alphabetic) => "unit",
+                    _ => "literal",
+                };
+                match self.parts.last_mut() {
+                    Some((last, value, unit)) if *last == typ && *unit == self.unit => {
+                        value.push_str(s);
+                    }
+                    _ => self.parts.push((typ, s.to_owned(), self.unit)),
+                }
+                Ok(())
+            }
+        }
+
+        impl PartsWrite for PartsCollector {
+            type SubPartsWrite = Self;
+
+            fn with_part(
+                &mut self,
+                part: writeable::Part,
+                mut f: impl FnMut(&mut Self::SubPartsWrite) -> std::fmt::Result,
+            ) -> std::fmt::Result {
+                match part.category {
+                    "decimal" => {
+                        let outer = self.number.replace(part.value);
+                        // Forces nested parts of the same type (e.g. integer digits around a
+                        // group separator) to be pushed as separate parts.
+                        self.parts.push((part.value, String::new(), self.unit));
+                        let result = f(self);
+                        self.parts.retain(|(_, value, _)| !value.is_empty());
+                        self.number = outer;
+                        result
+                    }
+                    "duration" if part.value != "literal" => {
+                        let outer = self.unit.replace(part.value);
+                        let result = f(self);
+                        self.unit = outer;
+                        result
+                    }
+                    _ => f(self),
+                }
+            }
+        }
+
+        // 1. Let df be this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let df = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`formatToParts` can only be called on an `Intl.DurationFormat` object",
+                )
+            })?;
+
+        // 3. Let record be ? ToDurationRecord(duration).
+        let record = DurationRecord::from_value(args.get_or_undefined(0), context)?;
+
+        // 4. Let parts be PartitionDurationFormatPattern(df, record).
+        let mut parts = PartsCollector::default();
+        df.borrow()
+            .data
+            .partition(&record)
+            .write_to_parts(&mut parts)
+            .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+        // 5. Let result be ! ArrayCreate(0).
+        let result = Array::array_create(0, None, context)
+            .expect("creating an empty array with default proto must not fail");
+
+        // 6. Let n be 0.
+        // 7. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        for (n, (typ, value, unit)) in parts.parts.into_iter().enumerate() {
+            // a. Let obj be OrdinaryObjectCreate(%Object.prototype%).
+            let o = context
+                .intrinsics()
+                .templates()
+                .ordinary_object()
+                .create(OrdinaryObject, vec![]);
+
+            // b. Perform ! CreateDataPropertyOrThrow(obj, "type", part.[[Type]]).
+            o.create_data_property_or_throw(js_string!("type"), js_string!(typ), context)
+                .expect("operation must not fail per the spec");
+
+            // c. Perform ! CreateDataPropertyOrThrow(obj, "value", part.[[Value]]).
+            o.create_data_property_or_throw(js_string!("value"), js_string!(value), context)
+                .expect("operation must not fail per the spec");
+
+            // d. If part.[[Unit]] is not empty, perform ! CreateDataPropertyOrThrow(obj, "unit", part.[[Unit]]).
+            if let Some(unit) = unit {
+                o.create_data_property_or_throw(js_string!("unit"), js_string!(unit), context)
+                    .expect("operation must not fail per the spec");
+            }
+
+            // e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), obj).
+            result
+                .create_data_property_or_throw(n, o, context)
+                .expect("operation must not fail per the spec");
+
+            // f. Set n to n + 1.
+        }
+
+        // 8. Return result.
+        Ok(result.into())
+    }
+
+    /// [`Intl.DurationFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in duration
+    /// formatting without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %Intl.DurationFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? FilterLocales(availableLocales, requestedLocales, options).
+        filter_locales::<Self>(requested_locales, options, context).map(JsValue::from)
+    }
+
+    /// [`Intl.DurationFormat.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and options computed during the
+    /// construction of the current `Intl.DurationFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat.prototype.resolvedOptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let df be the this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let df = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`resolvedOptions` can only be called on an `Intl.DurationFormat` object",
+                )
+            })?;
+        let df = df.borrow();
+        let df = &df.data;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 4. For each row of Table 21, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of df's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If p is "fractionalDigits", then
+        //         i. If v is not undefined, set v to 𝔽(v).
+        //     d. Else,
+        //         i. Assert: v is not undefined.
+        //     e. If v is "fractional", then
+        //         i. Assert: The Internal Slot value of the current row is [[MillisecondsOptions]],
+        //            [[MicrosecondsOptions]], or [[NanosecondsOptions]] .
+        //         ii. Set v to "numeric".
+        //     f. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut options = ObjectInitializer::new(context);
+        options
+            .property(
+                js_string!("locale"),
+                js_string!(df.locale.to_string()),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("numberingSystem"),
+                df.numbering_system
+                    .as_ref()
+                    .map_or_else(|| js_string!("latn"), |nu| js_string!(nu.to_string())),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("style"),
+                df.style.to_js_string(),
+                Attribute::all(),
+            );
+
+        for (unit, unit_options) in DurationUnit::ALL.into_iter().zip(df.units) {
+            options
+                .property(
+                    js_string!(unit.name()),
+                    unit_options.style.to_js_string(),
+                    Attribute::all(),
+                )
+                .property(
+                    js_string!(unit.display_name()),
+                    unit_options.display.to_js_string(),
+                    Attribute::all(),
+                );
+        }
+
+        if let Some(fractional_digits) = df.fractional_digits {
+            options.property(
+                js_string!("fractionalDigits"),
+                i32::from(fractional_digits),
+                Attribute::all(),
+            );
+        }
+
+        // 5. Return options.
+        Ok(options.build().into())
+    }
+}
+
+/// A record with the value of every unit of a duration, in the same order as
+/// [`DurationUnit::ALL`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct DurationRecord([f64; 10]);
+
+impl DurationRecord {
+    /// Creates a new `DurationRecord` from the values of every unit, which must already be a
+    /// valid duration.
+    #[cfg(feature = "temporal")]
+    pub(crate) const fn new(fields: [f64; 10]) -> Self {
+        Self(fields)
+    }
+
+    /// Abstract operation [`ToDurationRecord ( input )`][spec].
+    ///
+    /// Converts a duration-like object into a `DurationRecord`.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-todurationrecord
+    pub(crate) fn from_value(input: &JsValue, context: &mut Context) -> JsResult<Self> {
+        // 1. If input is not an Object, then
+        let Some(input) = input.as_object() else {
+            // a. If input is a String, throw a RangeError exception.
+            if input.is_string() {
+                return Err(JsNativeError::range()
+                    .with_message("cannot format a duration from a string")
+                    .into());
+            }
+            // b. Throw a TypeError exception.
+            return Err(JsNativeError::typ()
+                .with_message("the duration to format must be an object")
+                .into());
+        };
+
+        // 2. Let result be a new Duration Record with each field set to 0.
+        let mut result = [0.0; 10];
+        let mut any_defined = false;
+
+        // 3-22. For each field of a duration record, in alphabetical order:
+        //     a. Let value be ? Get(input, field).
+        //     b. If value is not undefined, then
+        //         i. Set result.[[<field>]] to ? ToIntegerIfIntegral(value).
+        let mut fields = DurationUnit::ALL;
+        fields.sort_unstable_by_key(|unit| unit.name());
+        for unit in fields {
+            let value = input.get(js_string!(unit.name()), context)?;
+            if value.is_undefined() {
+                continue;
+            }
+            any_defined = true;
+
+            // ToIntegerIfIntegral ( argument )
+            // <https://tc39.es/proposal-temporal/#sec-tointegerifintegral>
+            // 1. Let number be ? ToNumber(argument).
+            let number = value.to_number(context)?;
+            // 2. If number is not an integral Number, throw a RangeError exception.
+            #[allow(clippy::float_cmp)]
+            if !number.is_finite() || number.trunc() != number {
+                return Err(JsNativeError::range()
+                    .with_message(format!("`{}` must be an integral number", unit.name()))
+                    .into());
+            }
+            // 3. Return ℝ(number).
+            result[unit as usize] = number + 0.0;
+        }
+
+        // 23. If years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds,
+        //     and nanoseconds are all undefined, throw a TypeError exception.
+        if !any_defined {
+            return Err(JsNativeError::typ()
+                .with_message("the duration to format must have at least one unit")
+                .into());
+        }
+
+        // 24. If IsValidDuration(...) is false, throw a RangeError exception.
+        let record = Self(result);
+        if !record.is_valid() {
+            return Err(JsNativeError::range()
+                .with_message("the duration to format is out of range")
+                .into());
+        }
+
+        // 25. Return result.
+        Ok(record)
+    }
+
+    /// Abstract operation [`DurationSign ( duration )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-durationsign
+    fn sign(&self) -> f64 {
+        // 1. For each value v of « years, months, weeks, days, hours, minutes, seconds,
+        //    milliseconds, microseconds, nanoseconds », do
+        //     a. If v < 0, return -1.
+        //     b. If v > 0, return 1.
+        // 2. Return 0.
+        self.0
+            .iter()
+            .find(|v| **v != 0.0)
+            .map_or(0.0, |v| v.signum())
+    }
+
+    /// Abstract operation [`IsValidDuration ( ... )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-isvalidduration
+    fn is_valid(&self) -> bool {
+        const MAX_CALENDAR_UNIT: f64 = 4_294_967_296.0;
+
+        // 1. Let sign be DurationSign(...).
+        let sign = self.sign();
+
+        // 2. For each value v of « years, months, weeks, days, hours, minutes, seconds,
+        //    milliseconds, microseconds, nanoseconds », do
+        //     a. If 𝔽(v) is not finite, return false.
+        //     b. If v < 0 and sign > 0, return false.
+        //     c. If v > 0 and sign < 0, return false.
+        if self.0.iter().any(|v| v.signum() * sign < 0.0 && *v != 0.0) {
+            return false;
+        }
+
+        let [years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds] =
+            self.0;
+
+        // 3. If abs(years) ≥ 2**32, return false.
+        // 4. If abs(months) ≥ 2**32, return false.
+        // 5. If abs(weeks) ≥ 2**32, return false.
+        if years.abs() >= MAX_CALENDAR_UNIT
+            || months.abs() >= MAX_CALENDAR_UNIT
+            || weeks.abs() >= MAX_CALENDAR_UNIT
+        {
+            return false;
+        }
+
+        // 6. Let normalizedSeconds be days × 86,400 + hours × 3600 + minutes × 60 + seconds +
+        //    ℝ(𝔽(milliseconds)) × 10**-3 + ℝ(𝔽(microseconds)) × 10**-6 + ℝ(𝔽(nanoseconds)) × 10**-9.
+        // 7. NOTE: The above step cannot be implemented directly using floating-point arithmetic.
+        //    Multiplying by 10**-3, 10**-6, and 10**-9 respectively may be imprecise when
+        //    milliseconds, microseconds, or nanoseconds is an unsafe integer. This multiplication
+        //    can be implemented in C++ with an implementation of std::remquo() with sufficient
+        //    bits in the quotient. String manipulation will also give an exact result, since the
+        //    multiplication is by a power of 10.
+        // All values are integral, so the sum can be computed exactly in nanoseconds.
+        #[allow(clippy::cast_possible_truncation)]
+        let normalized_nanoseconds = [
+            (days, 86_400_000_000_000),
+            (hours, 3_600_000_000_000),
+            (minutes, 60_000_000_000),
+            (seconds, 1_000_000_000),
+            (milliseconds, 1_000_000),
+            (microseconds, 1_000),
+            (nanoseconds, 1),
+        ]
+        .into_iter()
+        .try_fold(0i128, |acc, (value, factor)| {
+            (value as i128)
+                .checked_mul(factor)
+                .and_then(|value| acc.checked_add(value))
+        });
+
+        // 8. If abs(normalizedSeconds) ≥ 2**53, return false.
+        // 9. Return true.
+        normalized_nanoseconds.is_some_and(|ns| ns.unsigned_abs() < (1u128 << 53) * 1_000_000_000)
+    }
+}
+
+/// Formats a `Temporal.Duration` for the [`Temporal.Duration.prototype.toLocaleString ( [ locales [ , options ] ] )`][spec]
+/// method.
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sup-temporal.duration.prototype.tolocalestring
+#[cfg(feature = "temporal")]
+pub(crate) fn format_duration_locale(
+    duration: &DurationRecord,
+    locales: &JsValue,
+    options: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsString> {
+    use writeable::Writeable;
+
+    // 3. Let formatter be ? Construct(%Intl.DurationFormat%, « locales, options »).
+    let df = DurationFormat::try_new(locales, options, context)?;
+
+    // 4. Let parts be PartitionDurationFormatPattern(formatter, duration).
+    // 5. Let result be the empty String.
+    // 6. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+    //     a. Set result to the string-concatenation of result and part.[[Value]].
+    // 7. Return result.
+    let formatted = df.partition(duration);
+    Ok(js_string!(formatted.write_to_string().as_ref()))
+}
@@ -0,0 +1,396 @@
+use std::{fmt, str::FromStr};
+
+use crate::{
+    builtins::options::{get_option, ParsableOptionType},
+    js_string, Context, JsNativeError, JsObject, JsResult, JsString,
+};
+
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub(crate) enum BaseStyle {
+    Long,
+    #[default]
+    Short,
+    Narrow,
+    Digital,
+}
+
+impl BaseStyle {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Long => js_string!("long"),
+            Self::Short => js_string!("short"),
+            Self::Narrow => js_string!("narrow"),
+            Self::Digital => js_string!("digital"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseBaseStyleError;
+
+impl fmt::Display for ParseBaseStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("provided string was not `long`, `short`, `narrow` or `digital`")
+    }
+}
+
+impl FromStr for BaseStyle {
+    type Err = ParseBaseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "long" => Ok(Self::Long),
+            "short" => Ok(Self::Short),
+            "narrow" => Ok(Self::Narrow),
+            "digital" => Ok(Self::Digital),
+            _ => Err(ParseBaseStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for BaseStyle {}
+
+/// The style used to format a single unit of a duration.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum UnitStyle {
+    Long,
+    Short,
+    Narrow,
+    Numeric,
+    TwoDigit,
+    /// Subsecond units formatted as a fraction of the next biggest unit.
+    ///
+    /// This cannot be set directly from an options object.
+    Fractional,
+}
+
+impl UnitStyle {
+    /// Returns the string representation of this style, as returned by `resolvedOptions`.
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Long => js_string!("long"),
+            Self::Short => js_string!("short"),
+            Self::Narrow => js_string!("narrow"),
+            Self::Numeric | Self::Fractional => js_string!("numeric"),
+            Self::TwoDigit => js_string!("2-digit"),
+        }
+    }
+
+    /// Returns `true` if this style formats the unit as part of a digital clock.
+    pub(crate) const fn is_numeric(self) -> bool {
+        matches!(self, Self::Numeric | Self::TwoDigit | Self::Fractional)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseUnitStyleError;
+
+impl fmt::Display for ParseUnitStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("provided string was not `long`, `short`, `narrow`, `numeric` or `2-digit`")
+    }
+}
+
+impl FromStr for UnitStyle {
+    type Err = ParseUnitStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "long" => Ok(Self::Long),
+            "short" => Ok(Self::Short),
+            "narrow" => Ok(Self::Narrow),
+            "numeric" => Ok(Self::Numeric),
+            "2-digit" => Ok(Self::TwoDigit),
+            _ => Err(ParseUnitStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for UnitStyle {}
+
+impl From<BaseStyle> for UnitStyle {
+    fn from(style: BaseStyle) -> Self {
+        match style {
+            BaseStyle::Long => Self::Long,
+            BaseStyle::Short => Self::Short,
+            BaseStyle::Narrow => Self::Narrow,
+            BaseStyle::Digital => Self::Numeric,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum UnitDisplay {
+    Auto,
+    Always,
+}
+
+impl UnitDisplay {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Auto => js_string!("auto"),
+            Self::Always => js_string!("always"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseUnitDisplayError;
+
+impl fmt::Display for ParseUnitDisplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("provided string was not `auto` or `always`")
+    }
+}
+
+impl FromStr for UnitDisplay {
+    type Err = ParseUnitDisplayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            _ => Err(ParseUnitDisplayError),
+        }
+    }
+}
+
+impl ParsableOptionType for UnitDisplay {}
+
+/// A unit of a duration, in the order used by `Intl.DurationFormat`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum DurationUnit {
+    Years,
+    Months,
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl DurationUnit {
+    /// All the units of a duration, from the biggest to the smallest.
+    pub(crate) const ALL: [Self; 10] = [
+        Self::Years,
+        Self::Months,
+        Self::Weeks,
+        Self::Days,
+        Self::Hours,
+        Self::Minutes,
+        Self::Seconds,
+        Self::Milliseconds,
+        Self::Microseconds,
+        Self::Nanoseconds,
+    ];
+
+    /// Gets the name of the property used to get the style of this unit and its value in a
+    /// duration record.
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Self::Years => "years",
+            Self::Months => "months",
+            Self::Weeks => "weeks",
+            Self::Days => "days",
+            Self::Hours => "hours",
+            Self::Minutes => "minutes",
+            Self::Seconds => "seconds",
+            Self::Milliseconds => "milliseconds",
+            Self::Microseconds => "microseconds",
+            Self::Nanoseconds => "nanoseconds",
+        }
+    }
+
+    /// Gets the `NumberFormat` unit used to format this unit, which is also the unit of its
+    /// parts in `formatToParts`.
+    pub(crate) const fn number_format_unit(self) -> &'static str {
+        match self {
+            Self::Years => "year",
+            Self::Months => "month",
+            Self::Weeks => "week",
+            Self::Days => "day",
+            Self::Hours => "hour",
+            Self::Minutes => "minute",
+            Self::Seconds => "second",
+            Self::Milliseconds => "millisecond",
+            Self::Microseconds => "microsecond",
+            Self::Nanoseconds => "nanosecond",
+        }
+    }
+
+    /// Gets the name of the property used to get the display of this unit.
+    pub(crate) const fn display_name(self) -> &'static str {
+        match self {
+            Self::Years => "yearsDisplay",
+            Self::Months => "monthsDisplay",
+            Self::Weeks => "weeksDisplay",
+            Self::Days => "daysDisplay",
+            Self::Hours => "hoursDisplay",
+            Self::Minutes => "minutesDisplay",
+            Self::Seconds => "secondsDisplay",
+            Self::Milliseconds => "millisecondsDisplay",
+            Self::Microseconds => "microsecondsDisplay",
+            Self::Nanoseconds => "nanosecondsDisplay",
+        }
+    }
+
+    /// Returns `true` if this is one of the calendar units of a duration.
+    pub(crate) const fn is_date(self) -> bool {
+        matches!(self, Self::Years | Self::Months | Self::Weeks | Self::Days)
+    }
+
+    const fn is_subsecond(self) -> bool {
+        matches!(
+            self,
+            Self::Milliseconds | Self::Microseconds | Self::Nanoseconds
+        )
+    }
+
+    /// Returns `true` if `style` is one of the allowed styles for this unit.
+    fn allows(self, style: UnitStyle) -> bool {
+        match style {
+            UnitStyle::Long | UnitStyle::Short | UnitStyle::Narrow => true,
+            UnitStyle::Numeric => !self.is_date(),
+            UnitStyle::TwoDigit => !self.is_date() && !self.is_subsecond(),
+            UnitStyle::Fractional => false,
+        }
+    }
+}
+
+/// The resolved style and display of a single unit.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct DurationUnitOptions {
+    pub(crate) style: UnitStyle,
+    pub(crate) display: UnitDisplay,
+}
+
+/// Abstract operation [`GetDurationUnitOptions ( unit, options, baseStyle, stylesList, digitalBase, prevStyle, twoDigitHours )`][spec].
+///
+/// Gets the style and display of `unit` from the options object, validating that the style
+/// is consistent with the style of the previous unit.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-getdurationunitoptions
+pub(crate) fn get_duration_unit_options(
+    unit: DurationUnit,
+    options: &JsObject,
+    base_style: BaseStyle,
+    prev_style: Option<UnitStyle>,
+    context: &mut Context,
+) -> JsResult<DurationUnitOptions> {
+    // 1. Let style be ? GetOption(options, unit, string, stylesList, undefined).
+    let style = get_option::<UnitStyle>(options, js_string!(unit.name()), context)?;
+    if let Some(style) = style.filter(|style| !unit.allows(*style)) {
+        return Err(JsNativeError::range()
+            .with_message(format!(
+                "style `{}` is not allowed for `{}`",
+                style.to_js_string().to_std_string_escaped(),
+                unit.name()
+            ))
+            .into());
+    }
+
+    // 2. Let displayDefault be "always".
+    let mut display_default = UnitDisplay::Always;
+
+    // 3. If style is undefined, then
+    let mut style = style.unwrap_or_else(|| {
+        // a. If baseStyle is "digital", then
+        if base_style == BaseStyle::Digital {
+            // i. If unit is not one of "hours", "minutes", or "seconds", then
+            if !matches!(
+                unit,
+                DurationUnit::Hours | DurationUnit::Minutes | DurationUnit::Seconds
+            ) {
+                // 1. Set displayDefault to "auto".
+                display_default = UnitDisplay::Auto;
+            }
+            // ii. Set style to digitalBase.
+            if unit.is_date() {
+                UnitStyle::Short
+            } else {
+                UnitStyle::Numeric
+            }
+        }
+        // b. Else,
+        //     i. If prevStyle is "fractional", "numeric" or "2-digit", then
+        else if prev_style.is_some_and(UnitStyle::is_numeric) {
+            // 1. If unit is not one of "minutes" or "seconds", then
+            if !matches!(unit, DurationUnit::Minutes | DurationUnit::Seconds) {
+                // a. Set displayDefault to "auto".
+                display_default = UnitDisplay::Auto;
+            }
+            // 2. Set style to "numeric".
+            UnitStyle::Numeric
+        }
+        //     ii. Else,
+        else {
+            // 1. Set displayDefault to "auto".
+            display_default = UnitDisplay::Auto;
+            // 2. Set style to baseStyle.
+            base_style.into()
+        }
+    });
+
+    // 4. If style is "numeric" and unit is one of "milliseconds", "microseconds", or "nanoseconds", then
+    if style == UnitStyle::Numeric && unit.is_subsecond() {
+        // a. Set style to "fractional".
+        style = UnitStyle::Fractional;
+        // b. Set displayDefault to "auto".
+        display_default = UnitDisplay::Auto;
+    }
+
+    // 5. Let displayField be the string-concatenation of unit and "Display".
+    // 6. Let display be ? GetOption(options, displayField, string, « "auto", "always" », displayDefault).
+    let display =
+        get_option(options, js_string!(unit.display_name()), context)?.unwrap_or(display_default);
+
+    // 7. If display is "always" and style is "fractional", then
+    if display == UnitDisplay::Always && style == UnitStyle::Fractional {
+        // a. Throw a RangeError exception.
+        return Err(JsNativeError::range()
+            .with_message(format!(
+                "`{}` cannot be always displayed as a fraction",
+                unit.name()
+            ))
+            .into());
+    }
+
+    match prev_style {
+        // 8. If prevStyle is "fractional", then
+        //     a. If style is not "fractional", then
+        Some(UnitStyle::Fractional) if style != UnitStyle::Fractional => {
+            // i. Throw a RangeError exception.
+            return Err(JsNativeError::range()
+                .with_message(format!(
+                    "`{}` must be numeric because the previous unit is fractional",
+                    unit.name()
+                ))
+                .into());
+        }
+        // 9. If prevStyle is "numeric" or "2-digit", then
+        Some(UnitStyle::Numeric | UnitStyle::TwoDigit) => {
+            // a. If style is not "fractional", "numeric" or "2-digit", then
+            if !style.is_numeric() {
+                // i. Throw a RangeError exception.
+                return Err(JsNativeError::range()
+                    .with_message(format!(
+                        "`{}` must be numeric because the previous unit is numeric",
+                        unit.name()
+                    ))
+                    .into());
+            }
+            // b. If unit is "minutes" or "seconds", then
+            if matches!(unit, DurationUnit::Minutes | DurationUnit::Seconds) {
+                // i. Set style to "2-digit".
+                style = UnitStyle::TwoDigit;
+            }
+        }
+        _ => {}
+    }
+
+    // 10. Return the Record { [[Style]]: style, [[Display]]: display  }.
+    Ok(DurationUnitOptions { style, display })
+}
@@ -0,0 +1,134 @@
+use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+use boa_macros::js_str;
+use indoc::indoc;
+
+#[test]
+fn format() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                const duration = { hours: 1, minutes: 46, seconds: 40 };
+            "#}),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en').format(duration)",
+            js_str!("1 hr, 46 min, 40 sec"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'long' }).format(duration)",
+            js_str!("1 hour, 46 minutes, 40 seconds"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'digital' }).format(duration)",
+            js_str!("1:46:40"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                new Intl.DurationFormat('en', { style: 'digital', fractionalDigits: 2 })
+                    .format({ minutes: 3, seconds: 5, milliseconds: 250 })
+            "#},
+            js_str!("0:03:05.25"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en').format({ days: 0, hours: 2 })",
+            js_str!("2 hr"),
+        ),
+    ]);
+}
+
+#[test]
+fn format_to_parts() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                const parts = new Intl.DurationFormat('en', { style: 'long' })
+                    .formatToParts({ hours: 1, minutes: 46 });
+            "#}),
+        TestAction::assert("parts.map(p => p.value).join('') === '1 hour, 46 minutes'"),
+        TestAction::assert_eq("parts[0].type", js_str!("integer")),
+        TestAction::assert_eq("parts[0].value", js_str!("1")),
+        TestAction::assert_eq("parts[0].unit", js_str!("hour")),
+        TestAction::assert("parts.some(p => p.type === 'literal' && !('unit' in p))"),
+        TestAction::assert_eq("parts[parts.length - 1].unit", js_str!("minute")),
+    ]);
+}
+
+#[test]
+fn resolved_options() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                const options = new Intl.DurationFormat('en-US', { style: 'digital' })
+                    .resolvedOptions();
+            "#}),
+        TestAction::assert_eq("options.locale", js_str!("en-US")),
+        TestAction::assert_eq("options.numberingSystem", js_str!("latn")),
+        TestAction::assert_eq("options.style", js_str!("digital")),
+        TestAction::assert_eq("options.years", js_str!("short")),
+        TestAction::assert_eq("options.yearsDisplay", js_str!("auto")),
+        TestAction::assert_eq("options.hours", js_str!("numeric")),
+        TestAction::assert_eq("options.hoursDisplay", js_str!("always")),
+        TestAction::assert_eq("options.minutes", js_str!("2-digit")),
+        TestAction::assert_eq("options.milliseconds", js_str!("numeric")),
+        TestAction::assert_eq("options.millisecondsDisplay", js_str!("auto")),
+        TestAction::assert("!('fractionalDigits' in options)"),
+    ]);
+}
+
+#[test]
+fn invalid_options() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "new Intl.DurationFormat('en', { years: 'numeric' })",
+            JsNativeErrorKind::Range,
+            "style `numeric` is not allowed for `years`",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DurationFormat('en', { milliseconds: 'numeric', millisecondsDisplay: 'always' })",
+            JsNativeErrorKind::Range,
+            "`milliseconds` cannot be always displayed as a fraction",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DurationFormat('en', { hours: 'numeric', minutes: 'long' })",
+            JsNativeErrorKind::Range,
+            "`minutes` must be numeric because the previous unit is numeric",
+        ),
+        TestAction::assert_native_error(
+            "Intl.DurationFormat()",
+            JsNativeErrorKind::Type,
+            "cannot call `Intl.DurationFormat` constructor without `new`",
+        ),
+    ]);
+}
+
+#[test]
+fn invalid_durations() {
+    run_test_actions([
+        TestAction::run("const df = new Intl.DurationFormat('en');"),
+        TestAction::assert_native_error(
+            "df.format('PT1H')",
+            JsNativeErrorKind::Range,
+            "cannot format a duration from a string",
+        ),
+        TestAction::assert_native_error(
+            "df.format({})",
+            JsNativeErrorKind::Type,
+            "the duration to format must have at least one unit",
+        ),
+        TestAction::assert_native_error(
+            "df.format({ hours: 1.5 })",
+            JsNativeErrorKind::Range,
+            "`hours` must be an integral number",
+        ),
+        TestAction::assert_native_error(
+            "df.format({ hours: 1, minutes: -1 })",
+            JsNativeErrorKind::Range,
+            "the duration to format is out of range",
+        ),
+    ]);
+}
+
+#[cfg(feature = "temporal")]
+#[test]
+fn temporal_duration_to_locale_string() {
+    run_test_actions([TestAction::assert_eq(
+        "Temporal.Duration.from('PT1H46M40S').toLocaleString('en', { style: 'long' })",
+        js_str!("1 hour, 46 minutes, 40 seconds"),
+    )]);
+}
@@ -84,7 +84,7 @@ impl NumberFormat {
 
 #[derive(Debug, Clone)]
 pub(super) struct NumberFormatLocaleOptions {
-    numbering_system: Option<Value>,
+    pub(super) numbering_system: Option<Value>,
 }
 
 impl Service for NumberFormat {
@@ -0,0 +1,598 @@
+//! This module implements the global `Intl.RelativeTimeFormat` object.
+//!
+//! `Intl.RelativeTimeFormat` enables language-sensitive relative time formatting, such as
+//! "yesterday", "in 3 days" or "2 hours ago".
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma402/#relativetimeformat-objects
+
+use std::{cell::OnceCell, fmt::Write};
+
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use icu_decimal::{preferences::NumberingSystem, provider::DecimalSymbolsV1};
+use icu_experimental::relativetime::{
+    options::Numeric, RelativeTimeFormatter, RelativeTimeFormatterOptions,
+    RelativeTimeFormatterPreferences,
+};
+use icu_locale::{extensions::unicode::Value, Locale};
+
+mod options;
+pub(crate) use options::*;
+
+use super::{
+    locale::{canonicalize_locale_list, filter_locales, resolve_locale},
+    number_format::{DigitFormatOptions, NotationKind, NumberFormat, NumberFormatLocaleOptions},
+    options::{coerce_options_to_object, IntlOptions},
+    Service,
+};
+use crate::{
+    builtins::{
+        options::get_option, Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject,
+        IntrinsicObject, OrdinaryObject,
+    },
+    context::{
+        icu::IntlProvider,
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    },
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, ObjectInitializer},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    Context, JsArgs, JsData, JsNativeError, JsObject, JsResult, JsString, JsSymbol, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// JavaScript `Intl.RelativeTimeFormat` object.
+#[derive(Trace, Finalize, JsData)]
+// Safety: `RelativeTimeFormat` only contains non-traceable types.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct RelativeTimeFormat {
+    locale: Locale,
+    numbering_system: Option<Value>,
+    style: Style,
+    numeric: Numeric,
+    format_options: DigitFormatOptions,
+    /// The formatters for every unit, lazily loaded on first use.
+    formatters: [OnceCell<RelativeTimeFormatter>; 8],
+}
+
+impl std::fmt::Debug for RelativeTimeFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RelativeTimeFormat")
+            .field("locale", &self.locale)
+            .field("numbering_system", &self.numbering_system)
+            .field("style", &self.style)
+            .field("numeric", &self.numeric)
+            .field("format_options", &self.format_options)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Service for RelativeTimeFormat {
+    // Relative times are formatted using an `Intl.NumberFormat` internally, which
+    // means this supports the same locales and extension keys as `Intl.NumberFormat`.
+    type LangMarker = DecimalSymbolsV1;
+
+    type LocaleOptions = NumberFormatLocaleOptions;
+
+    fn resolve(locale: &mut Locale, options: &mut Self::LocaleOptions, provider: &IntlProvider) {
+        NumberFormat::resolve(locale, options, provider);
+    }
+}
+
+impl IntrinsicObject for RelativeTimeFormat {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.RelativeTimeFormat"),
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format, js_string!("format"), 2)
+            .method(Self::format_to_parts, js_string!("formatToParts"), 2)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for RelativeTimeFormat {
+    const NAME: JsString = StaticJsStrings::RELATIVE_TIME_FORMAT;
+}
+
+impl BuiltInConstructor for RelativeTimeFormat {
+    const LENGTH: usize = 0;
+    const P: usize = 4;
+    const SP: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::relative_time_format;
+
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.RelativeTimeFormat` constructor without `new`")
+                .into());
+        }
+
+        // 2. Let relativeTimeFormat be ? OrdinaryCreateFromConstructor(NewTarget, "%Intl.RelativeTimeFormat.prototype%",
+        //    « [[InitializedRelativeTimeFormat]], [[Locale]], [[DataLocale]], [[Style]], [[Numeric]],
+        //    [[NumberFormat]], [[NumberingSystem]], [[PluralRules]] »).
+        let proto = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::relative_time_format,
+            context,
+        )?;
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 4. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(options, context)?;
+
+        // 5. Let opt be a new Record.
+        // 6. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 7. Set opt.[[LocaleMatcher]] to matcher.
+        let matcher =
+            get_option(&options, js_string!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 8. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 9. If numberingSystem is not undefined, then
+        //     a. If numberingSystem cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        // 10. Set opt.[[nu]] to numberingSystem.
+        let numbering_system =
+            get_option::<NumberingSystem>(&options, js_string!("numberingSystem"), context)?;
+
+        let mut intl_options = IntlOptions {
+            matcher,
+            service_options: NumberFormatLocaleOptions {
+                numbering_system: numbering_system.map(Value::from),
+            },
+        };
+
+        // 11. Let r be ResolveLocale(%Intl.RelativeTimeFormat%.[[AvailableLocales]], requestedLocales, opt,
+        //     %Intl.RelativeTimeFormat%.[[RelevantExtensionKeys]], %Intl.RelativeTimeFormat%.[[LocaleData]]).
+        // 12. Let locale be r.[[Locale]].
+        // 13. Set relativeTimeFormat.[[Locale]] to locale.
+        // 14. Set relativeTimeFormat.[[DataLocale]] to r.[[DataLocale]].
+        // 15. Set relativeTimeFormat.[[NumberingSystem]] to r.[[nu]].
+        let locale = resolve_locale::<Self>(
+            requested_locales,
+            &mut intl_options,
+            context.intl_provider(),
+        )?;
+
+        // 16. Let style be ? GetOption(options, "style", string, « "long", "short", "narrow" », "long").
+        // 17. Set relativeTimeFormat.[[Style]] to style.
+        let style = get_option(&options, js_string!("style"), context)?.unwrap_or_default();
+
+        // 18. Let numeric be ? GetOption(options, "numeric", string, « "always", "auto" », "always").
+        // 19. Set relativeTimeFormat.[[Numeric]] to numeric.
+        let numeric =
+            get_option(&options, js_string!("numeric"), context)?.unwrap_or(Numeric::Always);
+
+        // 20. Let relativeTimeFormat.[[NumberFormat]] be ! Construct(%Intl.NumberFormat%, « locale »).
+        // 21. Let relativeTimeFormat.[[PluralRules]] be ! Construct(%Intl.PluralRules%, « locale »).
+        // The number format and plural rules are handled by `RelativeTimeFormatter`, but we still
+        // need the default digit options of `Intl.NumberFormat` to round the formatted values.
+        let format_options = DigitFormatOptions::from_options(
+            &JsObject::with_null_proto(),
+            0,
+            3,
+            NotationKind::Standard,
+            context,
+        )?;
+
+        // 22. Return relativeTimeFormat.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            proto,
+            Self {
+                locale,
+                numbering_system: intl_options.service_options.numbering_system,
+                style,
+                numeric,
+                format_options,
+                formatters: Default::default(),
+            },
+        )
+        .into())
+    }
+}
+
+impl RelativeTimeFormat {
+    /// Gets the `RelativeTimeFormatter` for the provided unit, loading its data if this is the
+    /// first time the unit is used.
+    fn formatter(
+        &self,
+        unit: TimeUnit,
+        provider: &IntlProvider,
+    ) -> JsResult<&RelativeTimeFormatter> {
+        macro_rules! try_new {
+            ($prefs:expr, $options:expr; $($unit:ident => $name:ident),* $(,)?) => {
+                paste::paste! {
+                    match (self.style, unit) {
+                        $(
+                            (Style::Long, TimeUnit::$unit) => {
+                                RelativeTimeFormatter::[<try_new_long_ $name _with_buffer_provider>](
+                                    provider.erased_provider(),
+                                    $prefs,
+                                    $options,
+                                )
+                            }
+                            (Style::Short, TimeUnit::$unit) => {
+                                RelativeTimeFormatter::[<try_new_short_ $name _with_buffer_provider>](
+                                    provider.erased_provider(),
+                                    $prefs,
+                                    $options,
+                                )
+                            }
+                            (Style::Narrow, TimeUnit::$unit) => {
+                                RelativeTimeFormatter::[<try_new_narrow_ $name _with_buffer_provider>](
+                                    provider.erased_provider(),
+                                    $prefs,
+                                    $options,
+                                )
+                            }
+                        )*
+                    }
+                }
+            };
+        }
+
+        if let Some(formatter) = self.formatters[unit as usize].get() {
+            return Ok(formatter);
+        }
+
+        let prefs = RelativeTimeFormatterPreferences::from(&self.locale);
+        let options = RelativeTimeFormatterOptions {
+            numeric: self.numeric,
+        };
+
+        let formatter = try_new! {
+            prefs, options;
+            Second => second,
+            Minute => minute,
+            Hour => hour,
+            Day => day,
+            Week => week,
+            Month => month,
+            Quarter => quarter,
+            Year => year,
+        }
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+        Ok(self.formatters[unit as usize].get_or_init(|| formatter))
+    }
+
+    /// Abstract operation [`PartitionRelativeTimePattern ( relativeTimeFormat, value, unit )`][spec].
+    ///
+    /// Returns a struct implementing `Writeable`, allowing to either write the relative time as
+    /// a full string or by parts.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-PartitionRelativeTimePattern
+    fn partition(
+        &self,
+        value: &JsValue,
+        unit: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<(TimeUnit, impl writeable::Writeable + '_)> {
+        // 1. Let value be ? ToNumber(value).
+        let value = value.to_number(context)?;
+
+        // 2. Let unit be ? ToString(unit).
+        let unit = unit.to_string(context)?.to_std_string_escaped();
+
+        // 3. If value is NaN, +∞𝔽, or -∞𝔽, throw a RangeError exception.
+        if !value.is_finite() {
+            return Err(JsNativeError::range()
+                .with_message("cannot format a non-finite relative time")
+                .into());
+        }
+
+        // 4. Let unit be ? SingularRelativeTimeUnit(unit).
+        let unit = unit
+            .parse::<TimeUnit>()
+            .map_err(|e| JsNativeError::range().with_message(format!("{e}: `{unit}`")))?;
+
+        // 5. Let fields be relativeTimeFormat.[[DataLocale]].[[Fields]].
+        // 6. Let patterns be relativeTimeFormat.[[Style]] of fields.
+        // 7. If relativeTimeFormat.[[Numeric]] is "auto", then
+        //     a. Let valueString be ToString(value).
+        //     b. If patterns has a field [[<valueString>]], then
+        //         i. Let result be patterns.[[<valueString>]].
+        //         ii. Return a List containing the Record { [[Type]]: "literal", [[Value]]: result }.
+        // 8. If value is -0𝔽 or if value is less than 0, then
+        //     a. Let tl be "past".
+        //     b. NOTE: The value -0𝔽 is treated as "past" by convention.
+        // 9. Else,
+        //     a. Let tl be "future".
+        // 10. Let fv be ! PartitionNumberPattern(relativeTimeFormat.[[NumberFormat]], value).
+        // 11. Let pr be ! ResolvePlural(relativeTimeFormat.[[PluralRules]], value).[[PluralCategory]].
+        // 12. Let patterns be patterns.[[<tl>]].
+        // 13. Let pattern be patterns.[[<pr>]].
+        // 14. Return MakePartsList(pattern, unit, fv).
+        let formatter = self.formatter(unit, context.intl_provider())?;
+        Ok((
+            unit,
+            formatter.format(self.format_options.format_f64(value)),
+        ))
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.format ( value, unit )`][spec].
+    ///
+    /// Formats a value and unit according to the locale and formatting options of this
+    /// `Intl.RelativeTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/format
+    fn format(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        use writeable::Writeable;
+
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let rtf = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`format` can only be called on an `Intl.RelativeTimeFormat` object",
+                )
+            })?;
+
+        // 3. Let value be ? ToNumber(value).
+        // 4. Let unit be ? ToString(unit).
+        // 5. Return ? FormatRelativeTime(relativeTimeFormat, value, unit).
+        let rtf = rtf.borrow();
+        let (_, formatted) =
+            rtf.data
+                .partition(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+
+        Ok(js_string!(formatted.write_to_string().as_ref()).into())
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.formatToParts ( value, unit )`][spec].
+    ///
+    /// Returns an array of objects representing the relative time format in parts that can be
+    /// used for custom locale-aware formatting.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        use writeable::{PartsWrite, Writeable};
+
+        /// Collects the parts of a formatted relative time.
+        ///
+        /// The parts of the formatted number keep their type, while all the text written outside
+        /// of the number is merged into `literal` parts.
+        #[derive(Debug, Clone, Default)]
+        struct PartsCollector {
+            parts: Vec<(&'static str, String)>,
+            current: Option<&'static str>,
+        }
+
+        impl Write for PartsCollector {
+            fn write_str(&mut self, s: &str) -> std::fmt::Result {
+                if s.is_empty() {
+                    return Ok(());
+                }
+                let typ = self.current.unwrap_or("literal");
+                match self.parts.last_mut() {
+                    Some((last, value)) if *last == typ => value.push_str(s),
+                    _ => self.parts.push((typ, s.to_owned())),
+                }
+                Ok(())
+            }
+        }
+
+        impl PartsWrite for PartsCollector {
+            type SubPartsWrite = Self;
+
+            fn with_part(
+                &mut self,
+                part: writeable::Part,
+                mut f: impl FnMut(&mut Self::SubPartsWrite) -> std::fmt::Result,
+            ) -> std::fmt::Result {
+                if part.category != "decimal" {
+                    return f(self);
+                }
+                let outer = self.current.replace(part.value);
+                // Forces nested parts of the same type (e.g. integer digits around a group
+                // separator) to be pushed as separate parts.
+                self.parts.push((part.value, String::new()));
+                let result = f(self);
+                self.parts.retain(|(_, value)| !value.is_empty());
+                self.current = outer;
+                result
+            }
+        }
+
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let rtf = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`formatToParts` can only be called on an `Intl.RelativeTimeFormat` object",
+                )
+            })?;
+
+        // 3. Let value be ? ToNumber(value).
+        // 4. Let unit be ? ToString(unit).
+        // 5. Return ? FormatRelativeTimeToParts(relativeTimeFormat, value, unit).
+        let mut parts = PartsCollector::default();
+        let unit = {
+            let rtf = rtf.borrow();
+            let (unit, formatted) =
+                rtf.data
+                    .partition(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+            formatted
+                .write_to_parts(&mut parts)
+                .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+            unit
+        };
+
+        // FormatRelativeTimeToParts ( relativeTimeFormat, value, unit )
+        // <https://tc39.es/ecma402/#sec-FormatRelativeTimeToParts>
+
+        // 2. Let result be ! ArrayCreate(0).
+        let result = Array::array_create(0, None, context)
+            .expect("creating an empty array with default proto must not fail");
+
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        for (n, (typ, value)) in parts.parts.into_iter().enumerate() {
+            // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+            let o = context
+                .intrinsics()
+                .templates()
+                .ordinary_object()
+                .create(OrdinaryObject, vec![]);
+
+            // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+            o.create_data_property_or_throw(js_string!("type"), js_string!(typ), context)
+                .expect("operation must not fail per the spec");
+
+            // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+            o.create_data_property_or_throw(js_string!("value"), js_string!(value), context)
+                .expect("operation must not fail per the spec");
+
+            // d. If part.[[Unit]] is not empty, then
+            if typ != "literal" {
+                // i. Perform ! CreateDataPropertyOrThrow(O, "unit", part.[[Unit]]).
+                o.create_data_property_or_throw(js_string!("unit"), unit.to_js_string(), context)
+                    .expect("operation must not fail per the spec");
+            }
+
+            // e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(𝔽(n)), O).
+            result
+                .create_data_property_or_throw(n, o, context)
+                .expect("operation must not fail per the spec");
+
+            // f. Increment n by 1.
+        }
+
+        // 5. Return result.
+        Ok(result.into())
+    }
+
+    /// [`Intl.RelativeTimeFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in relative
+    /// time formatting without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %Intl.RelativeTimeFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? FilterLocales(availableLocales, requestedLocales, options).
+        filter_locales::<Self>(requested_locales, options, context).map(JsValue::from)
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and options computed during the
+    /// construction of the current `Intl.RelativeTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.prototype.resolvedOptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let rtf = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`resolvedOptions` can only be called on an `Intl.RelativeTimeFormat` object",
+                )
+            })?;
+        let rtf = rtf.borrow();
+        let rtf = &rtf.data;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 4. For each row of Table 20, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of relativeTimeFormat's internal slot whose name is the Internal Slot value of the current row.
+        //     c. Assert: v is not undefined.
+        //     d. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut options = ObjectInitializer::new(context);
+        options
+            .property(
+                js_string!("locale"),
+                js_string!(rtf.locale.to_string()),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("style"),
+                rtf.style.to_js_string(),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("numeric"),
+                numeric_to_js_string(rtf.numeric),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("numberingSystem"),
+                rtf.numbering_system
+                    .as_ref()
+                    .map_or_else(|| js_string!("latn"), |nu| js_string!(nu.to_string())),
+                Attribute::all(),
+            );
+
+        // 5. Return options.
+        Ok(options.build().into())
+    }
+}
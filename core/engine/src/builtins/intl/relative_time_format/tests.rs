@@ -0,0 +1,84 @@
+use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+use boa_macros::js_str;
+use indoc::indoc;
+
+#[test]
+fn format() {
+    run_test_actions([
+        TestAction::run("const rtf = new Intl.RelativeTimeFormat('en-US');"),
+        TestAction::assert_eq("rtf.format(3, 'days')", js_str!("in 3 days")),
+        TestAction::assert_eq("rtf.format(-1, 'day')", js_str!("1 day ago")),
+        TestAction::assert_eq("rtf.format(-0, 'hour')", js_str!("0 hours ago")),
+        TestAction::assert_eq("rtf.format(1.5, 'quarter')", js_str!("in 1.5 quarters")),
+        TestAction::assert_eq("rtf.format(-1234, 'year')", js_str!("1,234 years ago")),
+        TestAction::assert_eq(
+            "new Intl.RelativeTimeFormat('en-US', { numeric: 'auto' }).format(-1, 'day')",
+            js_str!("yesterday"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.RelativeTimeFormat('en-US', { style: 'short' }).format(2, 'month')",
+            js_str!("in 2 mo."),
+        ),
+        TestAction::assert_native_error(
+            "rtf.format(1, 'fortnight')",
+            JsNativeErrorKind::Range,
+            "provided string was not a valid relative time unit: `fortnight`",
+        ),
+        TestAction::assert_native_error(
+            "rtf.format(Infinity, 'day')",
+            JsNativeErrorKind::Range,
+            "cannot format a non-finite relative time",
+        ),
+        TestAction::assert_native_error(
+            "Intl.RelativeTimeFormat('en-US')",
+            JsNativeErrorKind::Type,
+            "cannot call `Intl.RelativeTimeFormat` constructor without `new`",
+        ),
+    ]);
+}
+
+#[test]
+fn format_to_parts() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                const rtf = new Intl.RelativeTimeFormat('en-US');
+                const parts = rtf.formatToParts(-1234.5, 'seconds');
+                const auto = new Intl.RelativeTimeFormat('en-US', { numeric: 'auto' })
+                    .formatToParts(1, 'day');
+            "#}),
+        TestAction::assert_eq(
+            "parts.map(p => p.type).join()",
+            js_str!("integer,group,integer,decimal,fraction,literal"),
+        ),
+        TestAction::assert_eq(
+            "parts.map(p => p.value).join('')",
+            js_str!("1,234.5 seconds ago"),
+        ),
+        TestAction::assert_eq("parts[0].unit", js_str!("second")),
+        TestAction::assert("!('unit' in parts[5])"),
+        TestAction::assert_eq("auto.length", 1),
+        TestAction::assert_eq("auto[0].type", js_str!("literal")),
+        TestAction::assert_eq("auto[0].value", js_str!("tomorrow")),
+    ]);
+}
+
+#[test]
+fn resolved_options() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                const options = new Intl.RelativeTimeFormat('en-US', {
+                    style: 'narrow',
+                    numeric: 'auto',
+                }).resolvedOptions();
+            "#}),
+        TestAction::assert_eq("options.locale", js_str!("en-US")),
+        TestAction::assert_eq("options.style", js_str!("narrow")),
+        TestAction::assert_eq("options.numeric", js_str!("auto")),
+        TestAction::assert_eq("options.numberingSystem", js_str!("latn")),
+        TestAction::assert_native_error(
+            "new Intl.RelativeTimeFormat('en-US', { numeric: 'sometimes' })",
+            JsNativeErrorKind::Range,
+            "provided string was not `always` or `auto`",
+        ),
+    ]);
+}
@@ -0,0 +1,784 @@
+//! This module implements the global `Iterator` constructor and the methods of
+//! `%Iterator.prototype%`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-iterator-objects
+
+use super::{
+    get_iterator_direct, if_abrupt_close_iterator,
+    iterator_helper::{HelperClosure, IteratorHelper},
+    IteratorRecord, PrimitiveHandling, WrapForValidIterator,
+};
+use crate::{
+    builtins::{
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    error::JsNativeError,
+    js_string,
+    object::{
+        internal_methods::{get_prototype_from_constructor, InternalMethodContext},
+        JsObject,
+    },
+    property::{Attribute, PropertyKey},
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    value::IntegerOrInfinity,
+    Context, JsArgs, JsResult, JsString, JsValue,
+};
+use boa_profiler::Profiler;
+
+/// The `Iterator` constructor, whose prototype is the `%Iterator.prototype%` object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-iterator-constructor
+pub(crate) struct Iterator;
+
+impl IntrinsicObject for Iterator {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let get_constructor = BuiltInBuilder::callable(realm, Self::get_constructor)
+            .name(js_string!("get constructor"))
+            .build();
+        let set_constructor = BuiltInBuilder::callable(realm, Self::set_constructor)
+            .name(js_string!("set constructor"))
+            .length(1)
+            .build();
+        let get_to_string_tag = BuiltInBuilder::callable(realm, Self::get_to_string_tag)
+            .name(js_string!("get [Symbol.toStringTag]"))
+            .build();
+        let set_to_string_tag = BuiltInBuilder::callable(realm, Self::set_to_string_tag)
+            .name(js_string!("set [Symbol.toStringTag]"))
+            .length(1)
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::from, js_string!("from"), 1)
+            .method(|v, _, _| Ok(v.clone()), JsSymbol::iterator(), 0)
+            .method(Self::map, js_string!("map"), 1)
+            .method(Self::filter, js_string!("filter"), 1)
+            .method(Self::take, js_string!("take"), 1)
+            .method(Self::drop, js_string!("drop"), 1)
+            .method(Self::flat_map, js_string!("flatMap"), 1)
+            .method(Self::reduce, js_string!("reduce"), 1)
+            .method(Self::to_array, js_string!("toArray"), 0)
+            .method(Self::for_each, js_string!("forEach"), 1)
+            .method(Self::some, js_string!("some"), 1)
+            .method(Self::every, js_string!("every"), 1)
+            .method(Self::find, js_string!("find"), 1)
+            .accessor(
+                JsSymbol::to_string_tag(),
+                Some(get_to_string_tag),
+                Some(set_to_string_tag),
+                Attribute::CONFIGURABLE,
+            )
+            .constructor_accessor(get_constructor, set_constructor)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for Iterator {
+    const NAME: JsString = StaticJsStrings::ITERATOR;
+}
+
+impl BuiltInConstructor for Iterator {
+    const LENGTH: usize = 0;
+    const P: usize = 14;
+    const SP: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::iterator;
+
+    /// `Iterator ( )`
+    ///
+    /// The `Iterator` constructor is abstract, and can only be used as the superclass of
+    /// user-defined iterators.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is either undefined or the active function object, throw a TypeError exception.
+        let active_function = context.active_function_object();
+        let is_active_function = match (new_target.as_object(), &active_function) {
+            (Some(new_target), Some(active_function)) => {
+                JsObject::equals(new_target, active_function)
+            }
+            _ => false,
+        };
+        if new_target.is_undefined() || is_active_function {
+            return Err(JsNativeError::typ()
+                .with_message("Iterator: the abstract constructor cannot be called directly")
+                .into());
+        }
+
+        // 2. Return ? OrdinaryCreateFromConstructor(NewTarget, "%Iterator.prototype%").
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardConstructors::iterator, context)?;
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            OrdinaryObject,
+        )
+        .into())
+    }
+}
+
+impl Iterator {
+    /// `Iterator.from ( O )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/from
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let iteratorRecord be ? GetIteratorFlattenable(O, iterate-string-primitives).
+        let iterator_record = args
+            .get_or_undefined(0)
+            .get_iterator_flattenable(PrimitiveHandling::IterateStringPrimitives, context)?;
+
+        // 2. Let hasInstance be ? OrdinaryHasInstance(%Iterator%, iteratorRecord.[[Iterator]]).
+        let has_instance = JsValue::ordinary_has_instance(
+            &context
+                .intrinsics()
+                .constructors()
+                .iterator()
+                .constructor()
+                .into(),
+            &iterator_record.iterator().clone().into(),
+            context,
+        )?;
+
+        // 3. If hasInstance is true, then
+        if has_instance {
+            // a. Return iteratorRecord.[[Iterator]].
+            return Ok(iterator_record.iterator().clone().into());
+        }
+
+        // 4. Let wrapper be OrdinaryObjectCreate(%WrapForValidIteratorPrototype%, « [[Iterated]] »).
+        // 5. Set wrapper.[[Iterated]] to iteratorRecord.
+        // 6. Return wrapper.
+        Ok(WrapForValidIterator::create(iterator_record, context).into())
+    }
+
+    /// `get Iterator.prototype.constructor`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-iterator.prototype.constructor
+    #[allow(clippy::unnecessary_wraps)]
+    fn get_constructor(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return %Iterator%.
+        Ok(context
+            .intrinsics()
+            .constructors()
+            .iterator()
+            .constructor()
+            .into())
+    }
+
+    /// `set Iterator.prototype.constructor`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set-iterator.prototype.constructor
+    fn set_constructor(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? SetterThatIgnoresPrototypeProperties(this value, %Iterator.prototype%, "constructor", v).
+        setter_that_ignores_prototype_properties(
+            this,
+            js_string!("constructor").into(),
+            args.get_or_undefined(0),
+            context,
+        )?;
+
+        // 2. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `get Iterator.prototype [ %Symbol.toStringTag% ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-iterator.prototype-%symbol.tostringtag%
+    #[allow(clippy::unnecessary_wraps)]
+    fn get_to_string_tag(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Return "Iterator".
+        Ok(Self::NAME.into())
+    }
+
+    /// `set Iterator.prototype [ %Symbol.toStringTag% ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set-iterator.prototype-%symbol.tostringtag%
+    fn set_to_string_tag(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? SetterThatIgnoresPrototypeProperties(this value, %Iterator.prototype%, %Symbol.toStringTag%, v).
+        setter_that_ignores_prototype_properties(
+            this,
+            JsSymbol::to_string_tag().into(),
+            args.get_or_undefined(0),
+            context,
+        )?;
+
+        // 2. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `Iterator.prototype.map ( mapper )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.map
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/map
+    fn map(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(mapper) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let (o, mapper) = this_and_callable(this, args.get_or_undefined(0), "map", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&o, context)?;
+
+        // 6. Let closure be a new Abstract Closure with no parameters that captures iterated and mapper [...]
+        // 7. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 8. Set result.[[UnderlyingIterator]] to iterated.
+        // 9. Return result.
+        let closure = HelperClosure::Map { mapper, counter: 0 };
+        Ok(IteratorHelper::create(iterated, closure, context).into())
+    }
+
+    /// `Iterator.prototype.filter ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.filter
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/filter
+    fn filter(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(predicate) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let (o, predicate) = this_and_callable(this, args.get_or_undefined(0), "filter", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&o, context)?;
+
+        // 6. Let closure be a new Abstract Closure with no parameters that captures iterated and predicate [...]
+        // 7. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 8. Set result.[[UnderlyingIterator]] to iterated.
+        // 9. Return result.
+        let closure = HelperClosure::Filter {
+            predicate,
+            counter: 0,
+        };
+        Ok(IteratorHelper::create(iterated, closure, context).into())
+    }
+
+    /// `Iterator.prototype.take ( limit )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.take
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/take
+    fn take(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. Let numLimit be Completion(ToNumber(limit)).
+        // 5. IfAbruptCloseIterator(numLimit, iterated).
+        // 6. If numLimit is NaN, then
+        //     a. Let error be ThrowCompletion(a newly created RangeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        // 7. Let integerLimit be ! ToIntegerOrInfinity(numLimit).
+        // 8. If integerLimit < 0, then
+        //     a. Let error be ThrowCompletion(a newly created RangeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let (o, remaining) = this_and_limit(this, args.get_or_undefined(0), "take", context)?;
+
+        // 9. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&o, context)?;
+
+        // 10. Let closure be a new Abstract Closure with no parameters that captures iterated and integerLimit [...]
+        // 11. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 12. Set result.[[UnderlyingIterator]] to iterated.
+        // 13. Return result.
+        let closure = HelperClosure::Take { remaining };
+        Ok(IteratorHelper::create(iterated, closure, context).into())
+    }
+
+    /// `Iterator.prototype.drop ( limit )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.drop
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/drop
+    fn drop(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. Let numLimit be Completion(ToNumber(limit)).
+        // 5. IfAbruptCloseIterator(numLimit, iterated).
+        // 6. If numLimit is NaN, then
+        //     a. Let error be ThrowCompletion(a newly created RangeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        // 7. Let integerLimit be ! ToIntegerOrInfinity(numLimit).
+        // 8. If integerLimit < 0, then
+        //     a. Let error be ThrowCompletion(a newly created RangeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let (o, remaining) = this_and_limit(this, args.get_or_undefined(0), "drop", context)?;
+
+        // 9. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&o, context)?;
+
+        // 10. Let closure be a new Abstract Closure with no parameters that captures iterated and integerLimit [...]
+        // 11. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 12. Set result.[[UnderlyingIterator]] to iterated.
+        // 13. Return result.
+        let closure = HelperClosure::Drop { remaining };
+        Ok(IteratorHelper::create(iterated, closure, context).into())
+    }
+
+    /// `Iterator.prototype.flatMap ( mapper )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.flatmap
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/flatMap
+    fn flat_map(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(mapper) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let (o, mapper) = this_and_callable(this, args.get_or_undefined(0), "flatMap", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&o, context)?;
+
+        // 6. Let closure be a new Abstract Closure with no parameters that captures iterated and mapper [...]
+        // 7. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 8. Set result.[[UnderlyingIterator]] to iterated.
+        // 9. Return result.
+        let closure = HelperClosure::FlatMap {
+            mapper,
+            counter: 0,
+            inner: None,
+        };
+        Ok(IteratorHelper::create(iterated, closure, context).into())
+    }
+
+    /// `Iterator.prototype.reduce ( reducer [ , initialValue ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.reduce
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/reduce
+    fn reduce(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(reducer) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let (o, reducer) = this_and_callable(this, args.get_or_undefined(0), "reduce", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(&o, context)?;
+
+        let (mut accumulator, mut counter) = if let Some(initial_value) = args.get(1) {
+            // 7. Else,
+            //     a. Let accumulator be initialValue.
+            //     b. Let counter be 0.
+            (initial_value.clone(), 0u64)
+        } else {
+            // 6. If initialValue is not present, then
+            //     a. Let accumulator be ? IteratorStepValue(iterated).
+            //     b. If accumulator is done, throw a TypeError exception.
+            let accumulator = iterated.step_value(context)?.ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "Iterator.prototype.reduce: cannot reduce an empty iterator without an initial value",
+                )
+            })?;
+            //     c. Let counter be 1.
+            (accumulator, 1)
+        };
+
+        // 8. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        while let Some(value) = iterated.step_value(context)? {
+            // c. Let result be Completion(Call(reducer, undefined, « accumulator, value, 𝔽(counter) »)).
+            let result = reducer.call(
+                &JsValue::undefined(),
+                &[accumulator, value, counter.into()],
+                context,
+            );
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            // e. Set accumulator to result.
+            accumulator = if_abrupt_close_iterator!(result, iterated, context);
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        // b. If value is done, return accumulator.
+        Ok(accumulator)
+    }
+
+    /// `Iterator.prototype.toArray ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.toarray
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/toArray
+    fn to_array(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Iterator.prototype.toArray: `this` is not an object")
+        })?;
+
+        // 3. Let iterated be ? GetIteratorDirect(O).
+        // 4. Let items be a new empty List.
+        // 5. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        //     b. If value is done, return CreateArrayFromList(items).
+        //     c. Append value to items.
+        let items = get_iterator_direct(o, context)?.into_list(context)?;
+        Ok(Array::create_array_from_list(items, context).into())
+    }
+
+    /// `Iterator.prototype.forEach ( procedure )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.foreach
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/forEach
+    fn for_each(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(procedure) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let (o, procedure) = this_and_callable(this, args.get_or_undefined(0), "forEach", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(&o, context)?;
+
+        // 6. Let counter be 0.
+        let mut counter = 0u64;
+
+        // 7. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        while let Some(value) = iterated.step_value(context)? {
+            // c. Let result be Completion(Call(procedure, undefined, « value, 𝔽(counter) »)).
+            let result = procedure.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        // b. If value is done, return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `Iterator.prototype.some ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.some
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/some
+    fn some(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(predicate) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let (o, predicate) = this_and_callable(this, args.get_or_undefined(0), "some", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(&o, context)?;
+
+        // 6. Let counter be 0.
+        let mut counter = 0u64;
+
+        // 7. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        while let Some(value) = iterated.step_value(context)? {
+            // c. Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            let result = predicate.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            let result = if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. If ToBoolean(result) is true, return ? IteratorClose(iterated, NormalCompletion(true)).
+            if result.to_boolean() {
+                return iterated.close(Ok(true.into()), context);
+            }
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        // b. If value is done, return false.
+        Ok(false.into())
+    }
+
+    /// `Iterator.prototype.every ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.every
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/every
+    fn every(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(predicate) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let (o, predicate) = this_and_callable(this, args.get_or_undefined(0), "every", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(&o, context)?;
+
+        // 6. Let counter be 0.
+        let mut counter = 0u64;
+
+        // 7. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        while let Some(value) = iterated.step_value(context)? {
+            // c. Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            let result = predicate.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            let result = if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. If ToBoolean(result) is false, return ? IteratorClose(iterated, NormalCompletion(false)).
+            if !result.to_boolean() {
+                return iterated.close(Ok(false.into()), context);
+            }
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        // b. If value is done, return true.
+        Ok(true.into())
+    }
+
+    /// `Iterator.prototype.find ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.find
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/find
+    fn find(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(predicate) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let (o, predicate) = this_and_callable(this, args.get_or_undefined(0), "find", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(&o, context)?;
+
+        // 6. Let counter be 0.
+        let mut counter = 0u64;
+
+        // 7. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        while let Some(value) = iterated.step_value(context)? {
+            // c. Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            let result = predicate.call(
+                &JsValue::undefined(),
+                &[value.clone(), counter.into()],
+                context,
+            );
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            let result = if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. If ToBoolean(result) is true, return ? IteratorClose(iterated, NormalCompletion(value)).
+            if result.to_boolean() {
+                return iterated.close(Ok(value), context);
+            }
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        // b. If value is done, return undefined.
+        Ok(JsValue::undefined())
+    }
+}
+
+/// Shared prologue of the `Iterator.prototype` methods taking a callback.
+///
+/// Checks that `this` is an object and that `callback` is callable, closing `this` as an
+/// iterator if it isn't.
+fn this_and_callable(
+    this: &JsValue,
+    callback: &JsValue,
+    method: &str,
+    context: &mut Context,
+) -> JsResult<(JsObject, JsObject)> {
+    let o = this.as_object().ok_or_else(|| {
+        JsNativeError::typ().with_message(format!(
+            "Iterator.prototype.{method}: `this` is not an object"
+        ))
+    })?;
+
+    let Some(callback) = callback.as_callable() else {
+        let error = JsNativeError::typ()
+            .with_message(format!(
+                "Iterator.prototype.{method}: expected a callable argument"
+            ))
+            .into();
+        return IteratorRecord::new(o.clone(), JsValue::undefined())
+            .close_if_abrupt(Err(error), context);
+    };
+
+    Ok((o.clone(), callback.clone()))
+}
+
+/// Shared prologue of `Iterator.prototype.take` and `Iterator.prototype.drop`.
+///
+/// Checks that `this` is an object and converts `limit` to the number of values to take or
+/// drop, closing `this` as an iterator if the limit is invalid.
+fn this_and_limit(
+    this: &JsValue,
+    limit: &JsValue,
+    method: &str,
+    context: &mut Context,
+) -> JsResult<(JsObject, IntegerOrInfinity)> {
+    let o = this.as_object().ok_or_else(|| {
+        JsNativeError::typ().with_message(format!(
+            "Iterator.prototype.{method}: `this` is not an object"
+        ))
+    })?;
+    let iterated = IteratorRecord::new(o.clone(), JsValue::undefined());
+
+    let num_limit = iterated.close_if_abrupt(limit.to_number(context), context)?;
+    let integer_limit = IntegerOrInfinity::from(num_limit);
+
+    if num_limit.is_nan() || integer_limit < 0 {
+        let error = JsNativeError::range()
+            .with_message(format!(
+                "Iterator.prototype.{method}: limit must be a non-negative number"
+            ))
+            .into();
+        return iterated.close_if_abrupt(Err(error), context);
+    }
+
+    Ok((o.clone(), integer_limit))
+}
+
+/// `SetterThatIgnoresPrototypeProperties ( thisValue, home, p, v )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-SetterThatIgnoresPrototypeProperties
+fn setter_that_ignores_prototype_properties(
+    this: &JsValue,
+    p: PropertyKey,
+    v: &JsValue,
+    context: &mut Context,
+) -> JsResult<()> {
+    let home = context.intrinsics().constructors().iterator().prototype();
+
+    // 1. If thisValue is not an Object, throw a TypeError exception.
+    let this = this.as_object().ok_or_else(|| {
+        JsNativeError::typ().with_message("Iterator.prototype: `this` is not an object")
+    })?;
+
+    // 2. If SameValue(thisValue, home) is true, then
+    if JsObject::equals(this, &home) {
+        // a. NOTE: Throwing here emulates assignment to a non-writable data property on the home object in strict mode code.
+        // b. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("Iterator.prototype: cannot assign to a property of the prototype")
+            .into());
+    }
+
+    // 3. Let desc be ? thisValue.[[GetOwnProperty]](p).
+    let desc = this.__get_own_property__(&p, &mut InternalMethodContext::new(context))?;
+
+    if desc.is_none() {
+        // 4. If desc is undefined, then
+        //     a. Perform ? CreateDataPropertyOrThrow(thisValue, p, v).
+        this.create_data_property_or_throw(p, v.clone(), context)?;
+    } else {
+        // 5. Else,
+        //     a. Perform ? Set(thisValue, p, v, true).
+        this.set(p, v.clone(), true, context)?;
+    }
+
+    // 6. Return unused.
+    Ok(())
+}
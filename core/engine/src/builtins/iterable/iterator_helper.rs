@@ -0,0 +1,447 @@
+//! This module implements the iterator helper objects returned by the methods of
+//! `%Iterator.prototype%`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-iterator-helper-objects
+
+use super::{create_iter_result_object, IteratorRecord, PrimitiveHandling};
+use crate::{
+    builtins::{BuiltInBuilder, IntrinsicObject},
+    context::intrinsics::Intrinsics,
+    error::JsNativeError,
+    js_string,
+    object::JsObject,
+    property::Attribute,
+    realm::Realm,
+    symbol::JsSymbol,
+    value::IntegerOrInfinity,
+    Context, JsData, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// The `[[GeneratorState]]` of an iterator helper.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HelperState {
+    SuspendedStart,
+    SuspendedYield,
+    Executing,
+    Completed,
+}
+
+/// The state of the abstract closure backing an iterator helper.
+///
+/// Every call to [`HelperClosure::resume`] runs the closure until its next `Yield`.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) enum HelperClosure {
+    /// The closure of `Iterator.prototype.map`.
+    Map { mapper: JsObject, counter: u64 },
+
+    /// The closure of `Iterator.prototype.filter`.
+    Filter { predicate: JsObject, counter: u64 },
+
+    /// The closure of `Iterator.prototype.take`.
+    Take {
+        #[unsafe_ignore_trace]
+        remaining: IntegerOrInfinity,
+    },
+
+    /// The closure of `Iterator.prototype.drop`.
+    Drop {
+        #[unsafe_ignore_trace]
+        remaining: IntegerOrInfinity,
+    },
+
+    /// The closure of `Iterator.prototype.flatMap`.
+    FlatMap {
+        mapper: JsObject,
+        counter: u64,
+        inner: Option<IteratorRecord>,
+    },
+}
+
+impl HelperClosure {
+    /// Resumes the closure with a normal completion, returning the next yielded value, or
+    /// `None` if the closure returned.
+    fn resume(
+        &mut self,
+        iterated: &mut IteratorRecord,
+        context: &mut Context,
+    ) -> JsResult<Option<JsValue>> {
+        match self {
+            Self::Map { mapper, counter } => {
+                // i. Let value be ? IteratorStepValue(iterated).
+                // ii. If value is done, return ReturnCompletion(undefined).
+                let Some(value) = iterated.step_value(context)? else {
+                    return Ok(None);
+                };
+
+                // iii. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+                let mapped =
+                    mapper.call(&JsValue::undefined(), &[value, (*counter).into()], context);
+
+                // iv. IfAbruptCloseIterator(mapped, iterated).
+                let mapped = iterated.close_if_abrupt(mapped, context)?;
+
+                // vii. Set counter to counter + 1.
+                *counter += 1;
+
+                // v. Let completion be Completion(Yield(mapped)).
+                Ok(Some(mapped))
+            }
+            Self::Filter { predicate, counter } => loop {
+                // i. Let value be ? IteratorStepValue(iterated).
+                // ii. If value is done, return ReturnCompletion(undefined).
+                let Some(value) = iterated.step_value(context)? else {
+                    return Ok(None);
+                };
+
+                // iii. Let selected be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+                let selected = predicate.call(
+                    &JsValue::undefined(),
+                    &[value.clone(), (*counter).into()],
+                    context,
+                );
+
+                // iv. IfAbruptCloseIterator(selected, iterated).
+                let selected = iterated.close_if_abrupt(selected, context)?;
+
+                // vi. Set counter to counter + 1.
+                *counter += 1;
+
+                // v. If ToBoolean(selected) is true, then
+                if selected.to_boolean() {
+                    // 1. Let completion be Completion(Yield(value)).
+                    return Ok(Some(value));
+                }
+            },
+            Self::Take { remaining } => {
+                // i. If remaining = 0, then
+                if *remaining == 0 {
+                    // 1. Return ? IteratorClose(iterated, ReturnCompletion(undefined)).
+                    iterated.close(Ok(JsValue::undefined()), context)?;
+                    return Ok(None);
+                }
+
+                // ii. If remaining ≠ +∞, then
+                if let IntegerOrInfinity::Integer(remaining) = remaining {
+                    // 1. Set remaining to remaining - 1.
+                    *remaining -= 1;
+                }
+
+                // iii. Let value be ? IteratorStepValue(iterated).
+                // iv. If value is done, return ReturnCompletion(undefined).
+                // v. Let completion be Completion(Yield(value)).
+                iterated.step_value(context)
+            }
+            Self::Drop { remaining } => {
+                // b. Repeat, while remaining > 0,
+                while *remaining > 0 {
+                    // i. If remaining ≠ +∞, then
+                    if let IntegerOrInfinity::Integer(remaining) = remaining {
+                        // 1. Set remaining to remaining - 1.
+                        *remaining -= 1;
+                    }
+
+                    // ii. Let next be ? IteratorStep(iterated).
+                    // iii. If next is done, return ReturnCompletion(undefined).
+                    if iterated.step(context)? {
+                        return Ok(None);
+                    }
+                }
+
+                // c. Repeat,
+                //     i. Let value be ? IteratorStepValue(iterated).
+                //     ii. If value is done, return ReturnCompletion(undefined).
+                //     iii. Let completion be Completion(Yield(value)).
+                iterated.step_value(context)
+            }
+            Self::FlatMap {
+                mapper,
+                counter,
+                inner,
+            } => loop {
+                // viii. Repeat, while innerAlive is true,
+                if let Some(inner_iterator) = inner {
+                    // 1. Let innerValue be Completion(IteratorStepValue(innerIterator)).
+                    let inner_value = inner_iterator.step_value(context);
+
+                    // 2. IfAbruptCloseIterator(innerValue, iterated).
+                    if let Some(inner_value) = iterated.close_if_abrupt(inner_value, context)? {
+                        // 4. Else,
+                        //     a. Let completion be Completion(Yield(innerValue)).
+                        return Ok(Some(inner_value));
+                    }
+
+                    // 3. If innerValue is done, then
+                    //     a. Set innerAlive to false.
+                    *inner = None;
+
+                    // ix. Set counter to counter + 1.
+                    *counter += 1;
+                    continue;
+                }
+
+                // i. Let value be ? IteratorStepValue(iterated).
+                // ii. If value is done, return ReturnCompletion(undefined).
+                let Some(value) = iterated.step_value(context)? else {
+                    return Ok(None);
+                };
+
+                // iii. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+                let mapped =
+                    mapper.call(&JsValue::undefined(), &[value, (*counter).into()], context);
+
+                // iv. IfAbruptCloseIterator(mapped, iterated).
+                let mapped = iterated.close_if_abrupt(mapped, context)?;
+
+                // v. Let innerIterator be Completion(GetIteratorFlattenable(mapped, reject-primitives)).
+                let inner_iterator =
+                    mapped.get_iterator_flattenable(PrimitiveHandling::RejectPrimitives, context);
+
+                // vi. IfAbruptCloseIterator(innerIterator, iterated).
+                // vii. Let innerAlive be true.
+                *inner = Some(iterated.close_if_abrupt(inner_iterator, context)?);
+            },
+        }
+    }
+
+    /// Resumes the closure from a suspended `Yield` with a return completion.
+    fn resume_return(&self, iterated: &IteratorRecord, context: &mut Context) -> JsResult<()> {
+        // `flatMap` is suspended within the inner iterator, which must be closed first.
+        if let Self::FlatMap {
+            inner: Some(inner_iterator),
+            ..
+        } = self
+        {
+            // b. If completion is an abrupt completion, then
+            //     i. Let backupCompletion be Completion(IteratorClose(innerIterator, completion)).
+            let backup_completion = inner_iterator.close(Ok(JsValue::undefined()), context);
+
+            //     ii. IfAbruptCloseIterator(backupCompletion, iterated).
+            iterated.close_if_abrupt(backup_completion, context)?;
+        }
+
+        // IfAbruptCloseIterator(completion, iterated).
+        iterated.close(Ok(JsValue::undefined()), context)?;
+
+        Ok(())
+    }
+}
+
+/// An iterator helper object, created by `CreateIteratorFromClosure` from the methods of
+/// `%Iterator.prototype%`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%iteratorhelperprototype%-object
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct IteratorHelper {
+    /// `[[UnderlyingIterator]]`
+    underlying_iterator: IteratorRecord,
+
+    /// `[[GeneratorState]]`
+    #[unsafe_ignore_trace]
+    state: HelperState,
+
+    /// The abstract closure run by `next`.
+    closure: HelperClosure,
+}
+
+impl IntrinsicObject for IteratorHelper {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::with_intrinsic::<Self>(realm)
+            .prototype(
+                realm
+                    .intrinsics()
+                    .objects()
+                    .iterator_prototypes()
+                    .iterator(),
+            )
+            .static_method(Self::next, js_string!("next"), 0)
+            .static_method(Self::r#return, js_string!("return"), 0)
+            .static_property(
+                JsSymbol::to_string_tag(),
+                js_string!("Iterator Helper"),
+                Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics.objects().iterator_prototypes().iterator_helper()
+    }
+}
+
+impl IteratorHelper {
+    /// Creates a new iterator helper over `underlying_iterator`, running `closure` on each
+    /// call to `next`.
+    pub(crate) fn create(
+        underlying_iterator: IteratorRecord,
+        closure: HelperClosure,
+        context: &mut Context,
+    ) -> JsObject {
+        JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .objects()
+                .iterator_prototypes()
+                .iterator_helper(),
+            Self {
+                underlying_iterator,
+                state: HelperState::SuspendedStart,
+                closure,
+            },
+        )
+    }
+
+    /// `%IteratorHelperPrototype%.next ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%iteratorhelperprototype%.next
+    fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? GeneratorResume(this value, undefined, "Iterator Helper").
+        let object = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("`this` is not an Iterator Helper object")
+        })?;
+
+        let (mut iterated, mut closure) = {
+            let mut helper = object.downcast_mut::<Self>().ok_or_else(|| {
+                JsNativeError::typ().with_message("`this` is not an Iterator Helper object")
+            })?;
+
+            match helper.state {
+                HelperState::Executing => {
+                    return Err(JsNativeError::typ()
+                        .with_message("Iterator Helper is already running")
+                        .into());
+                }
+                HelperState::Completed => {
+                    return Ok(create_iter_result_object(
+                        JsValue::undefined(),
+                        true,
+                        context,
+                    ));
+                }
+                HelperState::SuspendedStart | HelperState::SuspendedYield => {}
+            }
+
+            helper.state = HelperState::Executing;
+            (helper.underlying_iterator.clone(), helper.closure.clone())
+        };
+
+        let result = closure.resume(&mut iterated, context);
+
+        let mut helper = object
+            .downcast_mut::<Self>()
+            .expect("must be an Iterator Helper object");
+        helper.underlying_iterator = iterated;
+        helper.closure = closure;
+
+        match result {
+            Ok(Some(value)) => {
+                helper.state = HelperState::SuspendedYield;
+                drop(helper);
+                Ok(create_iter_result_object(value, false, context))
+            }
+            Ok(None) => {
+                helper.state = HelperState::Completed;
+                drop(helper);
+                Ok(create_iter_result_object(
+                    JsValue::undefined(),
+                    true,
+                    context,
+                ))
+            }
+            Err(err) => {
+                helper.state = HelperState::Completed;
+                Err(err)
+            }
+        }
+    }
+
+    /// `%IteratorHelperPrototype%.return ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%iteratorhelperprototype%.return
+    fn r#return(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[UnderlyingIterator]]).
+        let object = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("`this` is not an Iterator Helper object")
+        })?;
+
+        let (iterated, closure, state) = {
+            let mut helper = object.downcast_mut::<Self>().ok_or_else(|| {
+                JsNativeError::typ().with_message("`this` is not an Iterator Helper object")
+            })?;
+
+            // 3. Assert: O has a [[GeneratorState]] slot.
+            let state = helper.state;
+            match state {
+                // 4. If O.[[GeneratorState]] is suspended-start, then
+                //     a. Set O.[[GeneratorState]] to completed.
+                // 6. Return ? GeneratorResumeAbrupt(O, C, "Iterator Helper").
+                HelperState::SuspendedStart => helper.state = HelperState::Completed,
+                HelperState::SuspendedYield => helper.state = HelperState::Executing,
+                HelperState::Executing => {
+                    return Err(JsNativeError::typ()
+                        .with_message("Iterator Helper is already running")
+                        .into());
+                }
+                HelperState::Completed => {
+                    return Ok(create_iter_result_object(
+                        JsValue::undefined(),
+                        true,
+                        context,
+                    ));
+                }
+            }
+
+            (
+                helper.underlying_iterator.clone(),
+                helper.closure.clone(),
+                state,
+            )
+        };
+
+        if state == HelperState::SuspendedStart {
+            // b. NOTE: Once a generator enters the completed state it never leaves it and its associated execution context is never resumed. Any execution state associated with O can be discarded at this point.
+            // c. Perform ? IteratorClose(O.[[UnderlyingIterator]], NormalCompletion(unused)).
+            iterated.close(Ok(JsValue::undefined()), context)?;
+
+            // d. Return CreateIteratorResultObject(undefined, true).
+            return Ok(create_iter_result_object(
+                JsValue::undefined(),
+                true,
+                context,
+            ));
+        }
+
+        // 5. Let C be ReturnCompletion(undefined).
+        let result = closure.resume_return(&iterated, context);
+
+        object
+            .downcast_mut::<Self>()
+            .expect("must be an Iterator Helper object")
+            .state = HelperState::Completed;
+
+        result?;
+
+        Ok(create_iter_result_object(
+            JsValue::undefined(),
+            true,
+            context,
+        ))
+    }
+}
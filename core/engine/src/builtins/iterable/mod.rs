@@ -14,7 +14,17 @@ use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
 
 mod async_from_sync_iterator;
+mod iterator;
+mod iterator_helper;
+mod wrap_for_valid_iterator;
+
 pub(crate) use async_from_sync_iterator::AsyncFromSyncIterator;
+pub(crate) use iterator::Iterator;
+pub(crate) use iterator_helper::IteratorHelper;
+pub(crate) use wrap_for_valid_iterator::WrapForValidIterator;
+
+#[cfg(test)]
+mod tests;
 
 /// `IfAbruptCloseIterator ( value, iteratorRecord )`
 ///
@@ -42,11 +52,17 @@ pub(crate) use if_abrupt_close_iterator;
 use super::OrdinaryObject;
 
 /// The built-in iterator prototypes.
-#[derive(Debug, Default, Trace, Finalize)]
+#[derive(Debug, Trace, Finalize)]
 pub struct IteratorPrototypes {
     /// The `IteratorPrototype` object.
     iterator: JsObject,
 
+    /// The `%IteratorHelperPrototype%` object.
+    iterator_helper: JsObject,
+
+    /// The `%WrapForValidIteratorPrototype%` object.
+    wrap_for_valid_iterator: JsObject,
+
     /// The `AsyncIteratorPrototype` object.
     async_iterator: JsObject,
 
@@ -77,6 +93,26 @@ pub struct IteratorPrototypes {
 }
 
 impl IteratorPrototypes {
+    /// Creates the set of iterator prototypes, using `iterator` as the `IteratorPrototype`
+    /// object shared with the `Iterator` constructor.
+    pub(crate) fn new(iterator: JsObject) -> Self {
+        Self {
+            iterator,
+            iterator_helper: JsObject::default(),
+            wrap_for_valid_iterator: JsObject::default(),
+            async_iterator: JsObject::default(),
+            async_from_sync_iterator: JsObject::default(),
+            array: JsObject::default(),
+            set: JsObject::default(),
+            string: JsObject::default(),
+            regexp_string: JsObject::default(),
+            map: JsObject::default(),
+            for_in: JsObject::default(),
+            #[cfg(feature = "intl")]
+            segment: JsObject::default(),
+        }
+    }
+
     /// Returns the `ArrayIteratorPrototype` object.
     #[inline]
     #[must_use]
@@ -91,6 +127,20 @@ impl IteratorPrototypes {
         self.iterator.clone()
     }
 
+    /// Returns the `%IteratorHelperPrototype%` object.
+    #[inline]
+    #[must_use]
+    pub fn iterator_helper(&self) -> JsObject {
+        self.iterator_helper.clone()
+    }
+
+    /// Returns the `%WrapForValidIteratorPrototype%` object.
+    #[inline]
+    #[must_use]
+    pub fn wrap_for_valid_iterator(&self) -> JsObject {
+        self.wrap_for_valid_iterator.clone()
+    }
+
     /// Returns the `AsyncIteratorPrototype` object.
     #[inline]
     #[must_use]
@@ -149,28 +199,6 @@ impl IteratorPrototypes {
     }
 }
 
-/// `%IteratorPrototype%` object
-///
-/// More information:
-///  - [ECMA reference][spec]
-///
-/// [spec]: https://tc39.es/ecma262/#sec-%iteratorprototype%-object
-pub(crate) struct Iterator;
-
-impl IntrinsicObject for Iterator {
-    fn init(realm: &Realm) {
-        let _timer = Profiler::global().start_event("Iterator Prototype", "init");
-
-        BuiltInBuilder::with_intrinsic::<Self>(realm)
-            .static_method(|v, _, _| Ok(v.clone()), JsSymbol::iterator(), 0)
-            .build();
-    }
-
-    fn get(intrinsics: &Intrinsics) -> JsObject {
-        intrinsics.objects().iterator_prototypes().iterator()
-    }
-}
-
 /// `%AsyncIteratorPrototype%` object
 ///
 /// More information:
@@ -302,6 +330,79 @@ impl JsValue {
         // 4. Return ? GetIteratorFromMethod(obj, method).
         self.get_iterator_from_method(&method, context)
     }
+
+    /// `GetIteratorFlattenable ( obj, primitiveHandling )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getiteratorflattenable
+    pub(crate) fn get_iterator_flattenable(
+        &self,
+        primitive_handling: PrimitiveHandling,
+        context: &mut Context,
+    ) -> JsResult<IteratorRecord> {
+        // 1. If obj is not an Object, then
+        if !self.is_object() {
+            // a. If primitiveHandling is reject-primitives, throw a TypeError exception.
+            // b. Assert: primitiveHandling is iterate-string-primitives.
+            // c. If obj is not a String, throw a TypeError exception.
+            if primitive_handling == PrimitiveHandling::RejectPrimitives || !self.is_string() {
+                return Err(JsNativeError::typ()
+                    .with_message(format!(
+                        "value with type `{}` is not an iterator or iterable",
+                        self.type_of()
+                    ))
+                    .into());
+            }
+        }
+
+        // 2. Let method be ? GetMethod(obj, %Symbol.iterator%).
+        let iterator = match self.get_method(JsSymbol::iterator(), context)? {
+            // 4. Else,
+            //     a. Let iterator be ? Call(method, obj).
+            Some(method) => method.call(self, &[], context)?,
+            // 3. If method is undefined, then
+            //     a. Let iterator be obj.
+            None => self.clone(),
+        };
+
+        // 5. If iterator is not an Object, throw a TypeError exception.
+        let iterator = iterator.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("returned iterator is not an object")
+        })?;
+
+        // 6. Return ? GetIteratorDirect(iterator).
+        get_iterator_direct(iterator, context)
+    }
+}
+
+/// The way `GetIteratorFlattenable` handles primitive values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PrimitiveHandling {
+    /// Strings are iterated, other primitives throw a `TypeError`.
+    IterateStringPrimitives,
+
+    /// All primitives throw a `TypeError`.
+    RejectPrimitives,
+}
+
+/// `GetIteratorDirect ( obj )`
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getiteratordirect
+pub(crate) fn get_iterator_direct(
+    object: &JsObject,
+    context: &mut Context,
+) -> JsResult<IteratorRecord> {
+    // 1. Let nextMethod be ? Get(obj, "next").
+    let next_method = object.get(js_string!("next"), context)?;
+
+    // 2. Let iteratorRecord be the Iterator Record { [[Iterator]]: obj, [[NextMethod]]: nextMethod, [[Done]]: false }.
+    // 3. Return iteratorRecord.
+    Ok(IteratorRecord::new(object.clone(), next_method))
 }
 
 /// The result of the iteration process.
@@ -629,6 +730,22 @@ impl IteratorRecord {
         }
     }
 
+    /// Closes this iterator if `value` is an abrupt completion.
+    ///
+    /// This is the same as [`if_abrupt_close_iterator`], but it returns the (possibly
+    /// replaced) completion instead of returning from the calling function, which makes it
+    /// usable from functions that don't return a [`JsValue`].
+    pub(crate) fn close_if_abrupt<T>(
+        &self,
+        value: JsResult<T>,
+        context: &mut Context,
+    ) -> JsResult<T> {
+        value.map_err(|err| {
+            self.close(Err(err), context)
+                .expect_err("closing an iterator with a throw completion must return an error")
+        })
+    }
+
     /// `IteratorToList ( iteratorRecord )`
     ///
     /// More information:
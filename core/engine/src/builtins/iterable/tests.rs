@@ -0,0 +1,213 @@
+use crate::{run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+use boa_macros::js_str;
+use indoc::indoc;
+
+#[test]
+fn iterator_constructor() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "Iterator()",
+            JsNativeErrorKind::Type,
+            "Iterator: the abstract constructor cannot be called directly",
+        ),
+        TestAction::assert_native_error(
+            "new Iterator()",
+            JsNativeErrorKind::Type,
+            "Iterator: the abstract constructor cannot be called directly",
+        ),
+        TestAction::run(indoc! {r#"
+                class Counter extends Iterator {
+                    #i = 0;
+                    next() {
+                        return this.#i < 3
+                            ? { value: this.#i++, done: false }
+                            : { value: undefined, done: true };
+                    }
+                }
+            "#}),
+        TestAction::assert("new Counter() instanceof Iterator"),
+        TestAction::assert_eq("Iterator.prototype.constructor === Iterator", true),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(new Counter())",
+            js_str!("[object Iterator]"),
+        ),
+        TestAction::assert_eq("new Counter().toArray().join()", js_str!("0,1,2")),
+    ]);
+}
+
+#[test]
+fn iterator_prototype_accessors() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "Iterator.prototype[Symbol.toStringTag] = 'foo'",
+            JsNativeErrorKind::Type,
+            "Iterator.prototype: cannot assign to a property of the prototype",
+        ),
+        TestAction::run(indoc! {r#"
+                const it = Object.create(Iterator.prototype);
+                it[Symbol.toStringTag] = 'Custom';
+                it.constructor = 'ctor';
+            "#}),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(it)",
+            js_str!("[object Custom]"),
+        ),
+        TestAction::assert_eq("it.constructor", js_str!("ctor")),
+        TestAction::assert_eq(
+            "Iterator.prototype[Symbol.toStringTag]",
+            js_str!("Iterator"),
+        ),
+    ]);
+}
+
+#[test]
+fn iterator_from() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                const arrayIterator = [1, 2].values();
+                let closed = false;
+                const plain = {
+                    i: 0,
+                    next() { return { value: this.i++, done: this.i > 2 }; },
+                    return() { closed = true; return {}; },
+                };
+                const wrapper = Iterator.from(plain);
+            "#}),
+        TestAction::assert("Iterator.from(arrayIterator) === arrayIterator"),
+        TestAction::assert_eq("Iterator.from('abc').toArray().join()", js_str!("a,b,c")),
+        TestAction::assert("wrapper !== plain && wrapper instanceof Iterator"),
+        TestAction::assert_eq("wrapper.next().value", 0),
+        TestAction::assert("wrapper.return() && closed"),
+        TestAction::assert_native_error(
+            "Iterator.from(5)",
+            JsNativeErrorKind::Type,
+            "value with type `number` is not an iterator or iterable",
+        ),
+    ]);
+}
+
+#[test]
+fn lazy_helpers() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                function* naturals() {
+                    let i = 0;
+                    while (true) yield i++;
+                }
+            "#}),
+        TestAction::assert_eq(
+            "naturals().map(x => x * 2).filter(x => x % 3 === 0).take(3).toArray().join()",
+            js_str!("0,6,12"),
+        ),
+        TestAction::assert_eq(
+            "naturals().drop(5).take(2).toArray().join()",
+            js_str!("5,6"),
+        ),
+        TestAction::assert_eq(
+            "naturals().take(3).flatMap(x => [x, x]).toArray().join()",
+            js_str!("0,0,1,1,2,2"),
+        ),
+        TestAction::assert_eq(
+            "naturals().map((x, i) => x === i).take(3).every(x => x)",
+            true,
+        ),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(naturals().map(x => x))",
+            js_str!("[object Iterator Helper]"),
+        ),
+        TestAction::assert_native_error(
+            "naturals().take(-1)",
+            JsNativeErrorKind::Range,
+            "Iterator.prototype.take: limit must be a non-negative number",
+        ),
+        TestAction::assert_native_error(
+            "naturals().flatMap(x => x).next()",
+            JsNativeErrorKind::Type,
+            "value with type `number` is not an iterator or iterable",
+        ),
+    ]);
+}
+
+#[test]
+fn eager_helpers() {
+    run_test_actions([
+        TestAction::run("const values = () => [1, 2, 3, 4].values();"),
+        TestAction::assert_eq("values().reduce((a, b) => a + b)", 10),
+        TestAction::assert_eq("values().reduce((a, b) => a + b, 5)", 15),
+        TestAction::assert_eq("values().some(x => x > 3)", true),
+        TestAction::assert_eq("values().every(x => x > 3)", false),
+        TestAction::assert_eq("values().find(x => x > 2)", 3),
+        TestAction::assert_eq("values().find(x => x > 5)", JsValue::undefined()),
+        TestAction::assert_eq(
+            indoc! {r#"
+                let sum = 0;
+                values().forEach((x, i) => sum += x * i);
+                sum
+            "#},
+            20,
+        ),
+        TestAction::assert_native_error(
+            "[].values().reduce((a, b) => a + b)",
+            JsNativeErrorKind::Type,
+            "Iterator.prototype.reduce: cannot reduce an empty iterator without an initial value",
+        ),
+        TestAction::assert_native_error(
+            "values().map(5)",
+            JsNativeErrorKind::Type,
+            "Iterator.prototype.map: expected a callable argument",
+        ),
+    ]);
+}
+
+#[test]
+fn helper_closes_underlying_iterator() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                let returnCount = 0;
+                function makeIterator() {
+                    return Iterator.from({
+                        i: 0,
+                        next() { return { value: this.i++, done: false }; },
+                        return() { returnCount++; return {}; },
+                    });
+                }
+
+                const notStarted = makeIterator().map(x => x);
+                notStarted.return();
+
+                const started = makeIterator().filter(x => x % 2);
+                started.next();
+                started.return();
+
+                makeIterator().some(x => x === 3);
+                makeIterator().take(0).next();
+            "#}),
+        TestAction::assert_eq("returnCount", 4),
+        TestAction::assert_eq("started.next().done", true),
+        TestAction::assert_eq("started.return().done", true),
+        TestAction::assert_eq("returnCount", 4),
+    ]);
+}
+
+#[test]
+fn helper_is_not_reentrant() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                let helper;
+                let error;
+                helper = [1].values().map(() => {
+                    try {
+                        helper.next();
+                    } catch (e) {
+                        error = e;
+                    }
+                });
+                helper.next();
+            "#}),
+        TestAction::assert("error instanceof TypeError"),
+        TestAction::assert_eq(
+            "error.message",
+            js_str!("Iterator Helper is already running"),
+        ),
+    ]);
+}
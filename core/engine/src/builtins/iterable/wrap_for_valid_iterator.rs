@@ -0,0 +1,132 @@
+//! This module implements the wrapper objects returned by `Iterator.from`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-%wrapforvaliditeratorprototype%-object
+
+use super::{create_iter_result_object, IteratorRecord};
+use crate::{
+    builtins::{BuiltInBuilder, IntrinsicObject},
+    context::intrinsics::Intrinsics,
+    error::JsNativeError,
+    js_string,
+    object::JsObject,
+    realm::Realm,
+    Context, JsData, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// A wrapper over an iterator that doesn't inherit from `%Iterator.prototype%`, created by
+/// `Iterator.from`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%wrapforvaliditeratorprototype%-object
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct WrapForValidIterator {
+    /// `[[Iterated]]`
+    iterated: IteratorRecord,
+}
+
+impl IntrinsicObject for WrapForValidIterator {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::with_intrinsic::<Self>(realm)
+            .prototype(
+                realm
+                    .intrinsics()
+                    .objects()
+                    .iterator_prototypes()
+                    .iterator(),
+            )
+            .static_method(Self::next, js_string!("next"), 0)
+            .static_method(Self::r#return, js_string!("return"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics
+            .objects()
+            .iterator_prototypes()
+            .wrap_for_valid_iterator()
+    }
+}
+
+impl WrapForValidIterator {
+    /// Creates a new wrapper over `iterated`.
+    pub(crate) fn create(iterated: IteratorRecord, context: &mut Context) -> JsObject {
+        JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .objects()
+                .iterator_prototypes()
+                .wrap_for_valid_iterator(),
+            Self { iterated },
+        )
+    }
+
+    /// Gets the `[[Iterated]]` slot of `this`, throwing if it is not a wrapper object.
+    fn this_iterated(this: &JsValue) -> JsResult<IteratorRecord> {
+        this.as_object()
+            .and_then(|o| {
+                o.downcast_ref::<Self>()
+                    .map(|wrapper| wrapper.iterated.clone())
+            })
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`this` is not a wrapper object created by `Iterator.from`")
+                    .into()
+            })
+    }
+
+    /// `%WrapForValidIteratorPrototype%.next ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%wrapforvaliditeratorprototype%.next
+    fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[Iterated]]).
+        // 3. Let iteratorRecord be O.[[Iterated]].
+        let iterator_record = Self::this_iterated(this)?;
+
+        // 4. Return ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
+        iterator_record
+            .next_method()
+            .call(&iterator_record.iterator().clone().into(), &[], context)
+    }
+
+    /// `%WrapForValidIteratorPrototype%.return ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%wrapforvaliditeratorprototype%.return
+    fn r#return(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[Iterated]]).
+        // 3. Let iterator be O.[[Iterated]].[[Iterator]].
+        // 4. Assert: iterator is an Object.
+        let iterator = Self::this_iterated(this)?.iterator().clone();
+
+        // 5. Let returnMethod be ? GetMethod(iterator, "return").
+        let Some(return_method) = iterator.get_method(js_string!("return"), context)? else {
+            // 6. If returnMethod is undefined, then
+            //     a. Return CreateIteratorResultObject(undefined, true).
+            return Ok(create_iter_result_object(
+                JsValue::undefined(),
+                true,
+                context,
+            ));
+        };
+
+        // 7. Return ? Call(returnMethod, iterator).
+        return_method.call(&iterator.into(), &[], context)
+    }
+}
@@ -25,24 +25,28 @@ use crate::{
     context::intrinsics::Intrinsics,
     error::JsNativeError,
     js_string,
-    object::{internal_methods::InternalMethodContext, JsObject},
+    object::{internal_methods::InternalMethodContext, IntegrityLevel, JsObject},
     property::{Attribute, PropertyNameKind},
     realm::Realm,
     string::{CodePoint, StaticJsStrings},
     symbol::JsSymbol,
     value::IntegerOrInfinity,
     vm::{CallFrame, CallFrameFlags},
-    Context, JsArgs, JsBigInt, JsResult, JsString, JsValue, SpannedSourceText,
+    Context, JsArgs, JsBigInt, JsData, JsResult, JsString, JsValue, SpannedSourceText,
 };
-use boa_gc::Gc;
+use boa_gc::{Finalize, Gc, Trace};
 use boa_parser::{Parser, Source};
 use boa_profiler::Profiler;
 
 use super::{BuiltInBuilder, IntrinsicObject};
 
+mod parse_record;
+
 #[cfg(test)]
 mod tests;
 
+use parse_record::JsonParseRecord;
+
 /// JavaScript `JSON` global object.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub(crate) struct Json;
@@ -55,7 +59,9 @@ impl IntrinsicObject for Json {
         let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
 
         BuiltInBuilder::with_intrinsic::<Self>(realm)
+            .static_method(Self::is_raw_json, js_string!("isRawJSON"), 1)
             .static_method(Self::parse, js_string!("parse"), 2)
+            .static_method(Self::raw_json, js_string!("rawJSON"), 1)
             .static_method(Self::stringify, js_string!("stringify"), 3)
             .static_property(to_string_tag, Self::NAME, attribute)
             .build();
@@ -159,11 +165,14 @@ impl Json {
 
             // b. Let rootName be the empty String.
             // c. Perform ! CreateDataPropertyOrThrow(root, rootName, unfiltered).
-            root.create_data_property_or_throw(js_string!(), unfiltered, context)
+            root.create_data_property_or_throw(js_string!(), unfiltered.clone(), context)
                 .expect("CreateDataPropertyOrThrow should never throw here");
 
-            // d. Return ? InternalizeJSONProperty(root, rootName, reviver).
-            Self::internalize_json_property(&root, js_string!(), obj, context)
+            // d. Let snapshot be CreateJSONParseRecord(script, rootName, unfiltered).
+            let snapshot = JsonParseRecord::new(&json_string, unfiltered, context)?;
+
+            // e. Return ? InternalizeJSONProperty(root, rootName, reviver, snapshot).
+            Self::internalize_json_property(&root, js_string!(), obj, Some(&snapshot), context)
         } else {
             // 12. Else,
             // a. Return unfiltered.
@@ -171,22 +180,43 @@ impl Json {
         }
     }
 
-    /// `25.5.1.1 InternalizeJSONProperty ( holder, name, reviver )`
+    /// `InternalizeJSONProperty ( holder, name, reviver, parseRecord )`
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
+    ///  - [JSON.parse source text access proposal][proposal]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-internalizejsonproperty
+    /// [proposal]: https://tc39.es/proposal-json-parse-with-source/#sec-internalizejsonproperty
     fn internalize_json_property(
         holder: &JsObject,
         name: JsString,
         reviver: &JsObject,
+        parse_record: Option<&JsonParseRecord>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let val be ? Get(holder, name).
         let val = holder.get(name.clone(), context)?;
 
-        // 2. If Type(val) is Object, then
+        // 2. Let context be OrdinaryObjectCreate(%Object.prototype%).
+        let reviver_context = JsObject::with_object_proto(context.intrinsics());
+
+        // 3. If parseRecord is a JSON Parse Record and SameValue(parseRecord.[[Value]], val) is true, then
+        let parse_record = parse_record.filter(|record| JsValue::same_value(&record.value, &val));
+        if let Some(source) = parse_record.and_then(|record| record.source.clone()) {
+            // a. If val is not an Object, then
+            //     i. Perform ! CreateDataPropertyOrThrow(context, "source", the source text matched by parseRecord.[[ParseNode]]).
+            reviver_context
+                .create_data_property_or_throw(js_string!("source"), source, context)
+                .expect("CreateDataPropertyOrThrow should never throw here");
+        }
+        // b. Let elementRecords be parseRecord.[[Elements]].
+        // c. Let entryRecords be parseRecord.[[Entries]].
+        // 4. Else,
+        //     a. Let elementRecords be a new empty List.
+        //     b. Let entryRecords be a new empty List.
+
+        // 5. If val is an Object, then
         if let Some(obj) = val.as_object() {
             // a. Let isArray be ? IsArray(val).
             // b. If isArray is true, then
@@ -197,9 +227,18 @@ impl Json {
                 let len = obj.length_of_array_like(context)? as i64;
                 for i in 0..len {
                     // 1. Let prop be ! ToString(𝔽(I)).
-                    // 2. Let newElement be ? InternalizeJSONProperty(val, prop, reviver).
-                    let new_element =
-                        Self::internalize_json_property(obj, i.into(), reviver, context)?;
+                    // 2. If I < the number of elements in elementRecords, let elementRecord be elementRecords[I]. Otherwise, let elementRecord be empty.
+                    let element_record =
+                        parse_record.and_then(|record| record.elements.get(i as usize));
+
+                    // 3. Let newElement be ? InternalizeJSONProperty(val, prop, reviver, elementRecord).
+                    let new_element = Self::internalize_json_property(
+                        obj,
+                        i.into(),
+                        reviver,
+                        element_record,
+                        context,
+                    )?;
 
                     // 3. If newElement is undefined, then
                     if new_element.is_undefined() {
@@ -226,9 +265,17 @@ impl Json {
                         .expect("EnumerableOwnPropertyNames only returns strings")
                         .clone();
 
-                    // 1. Let newElement be ? InternalizeJSONProperty(val, P, reviver).
-                    let new_element =
-                        Self::internalize_json_property(obj, p.clone(), reviver, context)?;
+                    // 1. Let entryRecord be the element of entryRecords whose [[Key]] field is P. If there is no such element, let entryRecord be empty.
+                    let entry_record = parse_record.and_then(|record| record.entries.get(&p));
+
+                    // 2. Let newElement be ? InternalizeJSONProperty(val, P, reviver, entryRecord).
+                    let new_element = Self::internalize_json_property(
+                        obj,
+                        p.clone(),
+                        reviver,
+                        entry_record,
+                        context,
+                    )?;
 
                     // 2. If newElement is undefined, then
                     if new_element.is_undefined() {
@@ -244,8 +291,93 @@ impl Json {
             }
         }
 
-        // 3. Return ? Call(reviver, holder, « name, val »).
-        reviver.call(&holder.clone().into(), &[name.into(), val], context)
+        // 6. Return ? Call(reviver, holder, « name, val, context »).
+        reviver.call(
+            &holder.clone().into(),
+            &[name.into(), val, reviver_context.into()],
+            context,
+        )
+    }
+
+    /// `JSON.rawJSON ( text )`
+    ///
+    /// Creates a frozen object containing the JSON text of a primitive value, which is emitted
+    /// verbatim by `JSON.stringify`.
+    ///
+    /// More information:
+    ///  - [JSON.parse source text access proposal][proposal]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [proposal]: https://tc39.es/proposal-json-parse-with-source/#sec-json.rawjson
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/rawJSON
+    pub(crate) fn raw_json(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let jsonString be ? ToString(text).
+        let json_string = args.get_or_undefined(0).to_string(context)?;
+
+        // 2. Throw a SyntaxError exception if jsonString is the empty String, or if either the first or last code unit of jsonString is any of 0x0009 (CHARACTER TABULATION), 0x000A (LINE FEED), 0x000D (CARRIAGE RETURN), or 0x0020 (SPACE).
+        let is_whitespace = |unit: Option<u16>| matches!(unit, Some(0x09 | 0x0A | 0x0D | 0x20));
+        if json_string.is_empty()
+            || is_whitespace(json_string.get(0))
+            || is_whitespace(json_string.get(json_string.len() - 1))
+        {
+            return Err(JsNativeError::syntax()
+                .with_message("raw JSON text cannot be empty or start or end with whitespace")
+                .into());
+        }
+
+        // 3. Parse StringToCodePoints(jsonString) as a JSON text as specified in ECMA-404. Throw a SyntaxError exception if it is not a valid JSON text as defined in that specification, or if its outermost value is an object or array as defined in that specification.
+        let text = json_string
+            .to_std_string()
+            .map_err(|e| JsNativeError::syntax().with_message(e.to_string()))?;
+        match serde_json::from_str::<serde_json::Value>(&text) {
+            Err(e) => return Err(JsNativeError::syntax().with_message(e.to_string()).into()),
+            Ok(serde_json::Value::Array(_) | serde_json::Value::Object(_)) => {
+                return Err(JsNativeError::syntax()
+                    .with_message("raw JSON text must be a primitive value")
+                    .into());
+            }
+            Ok(_) => {}
+        }
+
+        // 4. Let internalSlotsList be « [[IsRawJSON]] ».
+        // 5. Let obj be OrdinaryObjectCreate(null, internalSlotsList).
+        let obj = JsObject::from_proto_and_data(None, RawJson);
+
+        // 6. Perform ! CreateDataPropertyOrThrow(obj, "rawJSON", jsonString).
+        obj.create_data_property_or_throw(js_string!("rawJSON"), json_string, context)
+            .expect("CreateDataPropertyOrThrow should never throw here");
+
+        // 7. Perform ! SetIntegrityLevel(obj, frozen).
+        obj.set_integrity_level(IntegrityLevel::Frozen, context)
+            .expect("SetIntegrityLevel should never throw here");
+
+        // 8. Return obj.
+        Ok(obj.into())
+    }
+
+    /// `JSON.isRawJSON ( O )`
+    ///
+    /// Returns `true` if the value was created by `JSON.rawJSON`.
+    ///
+    /// More information:
+    ///  - [JSON.parse source text access proposal][proposal]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [proposal]: https://tc39.es/proposal-json-parse-with-source/#sec-json.israwjson
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/isRawJSON
+    #[allow(clippy::unnecessary_wraps)]
+    pub(crate) fn is_raw_json(_: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. If Type(O) is Object and O has an [[IsRawJSON]] internal slot, return true.
+        // 2. Return false.
+        Ok(args
+            .get_or_undefined(0)
+            .as_object()
+            .is_some_and(JsObject::is::<RawJson>)
+            .into())
     }
 
     /// `JSON.stringify( value[, replacer[, space]] )`
@@ -444,22 +576,33 @@ impl Json {
 
         // 4. If Type(value) is Object, then
         if let Some(obj) = value.as_object().cloned() {
-            // a. If value has a [[NumberData]] internal slot, then
+            // a. If value has an [[IsRawJSON]] internal slot, then
+            if obj.is::<RawJson>() {
+                // i. Return ! Get(value, "rawJSON").
+                return Ok(Some(
+                    obj.get(js_string!("rawJSON"), context)
+                        .expect("raw JSON objects are frozen objects without accessors")
+                        .as_string()
+                        .expect("the raw JSON text must be a string")
+                        .clone(),
+                ));
+            }
+            // b. If value has a [[NumberData]] internal slot, then
             if obj.is::<f64>() {
                 // i. Set value to ? ToNumber(value).
                 value = value.to_number(context)?.into();
             }
-            // b. Else if value has a [[StringData]] internal slot, then
+            // c. Else if value has a [[StringData]] internal slot, then
             else if obj.is::<JsString>() {
                 // i. Set value to ? ToString(value).
                 value = value.to_string(context)?.into();
             }
-            // c. Else if value has a [[BooleanData]] internal slot, then
+            // d. Else if value has a [[BooleanData]] internal slot, then
             else if let Some(boolean) = obj.downcast_ref::<bool>() {
                 // i. Set value to value.[[BooleanData]].
                 value = (*boolean).into();
             }
-            // d. Else if value has a [[BigIntData]] internal slot, then
+            // e. Else if value has a [[BigIntData]] internal slot, then
             else if let Some(bigint) = obj.downcast_ref::<JsBigInt>() {
                 // i. Set value to value.[[BigIntData]].
                 value = bigint.clone().into();
@@ -825,6 +968,11 @@ impl Json {
     }
 }
 
+/// The `[[IsRawJSON]]` internal slot of the objects created by `JSON.rawJSON`.
+#[derive(Debug, Clone, Copy, Trace, Finalize, JsData)]
+#[boa_gc(empty_trace)]
+struct RawJson;
+
 struct StateRecord {
     replacer_function: Option<JsObject>,
     stack: Vec<JsObject>,
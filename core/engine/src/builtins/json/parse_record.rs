@@ -0,0 +1,209 @@
+//! JSON Parse Records, used to give `JSON.parse` revivers access to the source text of
+//! primitive values.
+//!
+//! More information:
+//!  - [ECMAScript proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-json-parse-record
+
+use rustc_hash::FxHashMap;
+
+use crate::{Context, JsResult, JsString, JsValue};
+
+/// A [JSON Parse Record][spec], which associates a parsed value with its source text.
+///
+/// Instead of keeping the parse node of the value, this only keeps the source text of
+/// primitive values, which is the only information exposed to revivers.
+///
+/// [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-json-parse-record
+#[derive(Debug)]
+pub(super) struct JsonParseRecord {
+    /// The value originally parsed from the source text.
+    pub(super) value: JsValue,
+    /// The source text of the value, if it is a primitive.
+    pub(super) source: Option<JsString>,
+    /// The parse records of the elements of an array.
+    pub(super) elements: Vec<JsonParseRecord>,
+    /// The parse records of the properties of an object.
+    pub(super) entries: FxHashMap<JsString, JsonParseRecord>,
+}
+
+impl JsonParseRecord {
+    /// Abstract operation [`CreateJSONParseRecord ( parseNode, key, val )`][spec]
+    ///
+    /// Creates the parse record of `value`, which must be the result of evaluating the valid JSON
+    /// text `source`.
+    ///
+    /// [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-createjsonparserecord
+    pub(super) fn new(source: &str, value: JsValue, context: &mut Context) -> JsResult<Self> {
+        JsonSourceScanner {
+            source,
+            position: 0,
+        }
+        .record(value, context)
+    }
+}
+
+/// A scanner over a JSON text that was already validated.
+struct JsonSourceScanner<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl JsonSourceScanner<'_> {
+    /// Returns the next byte of the source text, without consuming it.
+    fn peek(&self) -> u8 {
+        self.source.as_bytes()[self.position]
+    }
+
+    /// Skips the JSON whitespace at the current position.
+    fn skip_whitespace(&mut self) {
+        while self.position < self.source.len()
+            && matches!(self.peek(), b'\t' | b'\n' | b'\r' | b' ')
+        {
+            self.position += 1;
+        }
+    }
+
+    /// Consumes the next non-whitespace byte of the source text.
+    fn next_token(&mut self) -> u8 {
+        self.skip_whitespace();
+        let token = self.peek();
+        self.position += 1;
+        token
+    }
+
+    /// Creates the parse record of the JSON value at the current position.
+    fn record(&mut self, value: JsValue, context: &mut Context) -> JsResult<JsonParseRecord> {
+        let mut record = JsonParseRecord {
+            value,
+            source: None,
+            elements: Vec::new(),
+            entries: FxHashMap::default(),
+        };
+
+        self.skip_whitespace();
+        match self.peek() {
+            // If val is an Object, then
+            //     a. If typedValNode is an ArrayLiteral Parse Node, then
+            //         i. Let elements be ? the JSON Parse Records of the elements of typedValNode.
+            b'[' => {
+                self.position += 1;
+                // Earlier definitions of duplicated keys can have a value of a different type.
+                let object = record.value.as_object().cloned();
+
+                self.skip_whitespace();
+                if self.peek() == b']' {
+                    self.position += 1;
+                    return Ok(record);
+                }
+
+                loop {
+                    let index = record.elements.len();
+                    let element = match &object {
+                        Some(object) => object.get(index, context)?,
+                        None => JsValue::undefined(),
+                    };
+                    record.elements.push(self.record(element, context)?);
+
+                    if self.next_token() == b']' {
+                        break;
+                    }
+                }
+            }
+            //     b. Else,
+            //         i. Assert: typedValNode is an ObjectLiteral Parse Node.
+            //         ii. Let entries be ? the JSON Parse Records of the property definitions of typedValNode.
+            b'{' => {
+                self.position += 1;
+                // Earlier definitions of duplicated keys can have a value of a different type.
+                let object = record.value.as_object().cloned();
+
+                self.skip_whitespace();
+                if self.peek() == b'}' {
+                    self.position += 1;
+                    return Ok(record);
+                }
+
+                loop {
+                    self.skip_whitespace();
+                    let key = self.string();
+                    let colon = self.next_token();
+                    debug_assert_eq!(colon, b':');
+
+                    let entry = match &object {
+                        Some(object) => object.get(key.clone(), context)?,
+                        None => JsValue::undefined(),
+                    };
+
+                    // Only the last of duplicated keys defines the property value.
+                    let entry = self.record(entry, context)?;
+                    record.entries.insert(key, entry);
+
+                    if self.next_token() == b'}' {
+                        break;
+                    }
+                }
+            }
+            // Else,
+            //     a. Let elements be a new empty List.
+            //     b. Let entries be a new empty List.
+            b'"' => {
+                let start = self.position;
+                self.string();
+                record.source = Some(JsString::from(&self.source[start..self.position]));
+            }
+            _ => {
+                let start = self.position;
+                while self.position < self.source.len()
+                    && !matches!(
+                        self.peek(),
+                        b'\t' | b'\n' | b'\r' | b' ' | b',' | b']' | b'}'
+                    )
+                {
+                    self.position += 1;
+                }
+                record.source = Some(JsString::from(&self.source[start..self.position]));
+            }
+        }
+
+        // Return the JSON Parse Record { [[ParseNode]]: typedValNode, [[Key]]: key, [[Value]]: val, [[Elements]]: elements, [[Entries]]: entries }.
+        Ok(record)
+    }
+
+    /// Consumes the JSON string at the current position, returning its string value.
+    fn string(&mut self) -> JsString {
+        debug_assert_eq!(self.peek(), b'"');
+        self.position += 1;
+
+        let mut value = Vec::new();
+        let mut chars = self.source[self.position..].char_indices();
+        while let Some((offset, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.position += offset + 1;
+                    break;
+                }
+                '\\' => {
+                    let (_, escape) = chars.next().expect("escapes must be complete");
+                    let unit = match escape {
+                        'b' => 0x0008,
+                        'f' => 0x000C,
+                        'n' => 0x000A,
+                        'r' => 0x000D,
+                        't' => 0x0009,
+                        'u' => {
+                            let digits = chars.by_ref().take(4).map(|(_, c)| c).collect::<String>();
+                            u16::from_str_radix(&digits, 16).expect("unicode escapes must be valid")
+                        }
+                        c => c as u16,
+                    };
+                    value.push(unit);
+                }
+                c => value.extend_from_slice(c.encode_utf16(&mut [0; 2])),
+            }
+        }
+
+        JsString::from(&value[..])
+    }
+}
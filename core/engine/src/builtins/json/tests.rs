@@ -315,3 +315,68 @@ fn json_parse_with_no_args_throws_syntax_error() {
         "expected value at line 1 column 1",
     )]);
 }
+
+#[test]
+fn json_parse_reviver_source_text() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var sources = [];
+                var result = JSON.parse(
+                    ' [1.0, "a\\u0041", {"id": 12345678901234567890, "n": null}, -0] ',
+                    (key, value, context) => {
+                        sources.push(key + "=" + context.source);
+                        return key === "id" ? BigInt(context.source) : value;
+                    },
+                );
+            "#}),
+        TestAction::assert_eq(
+            "sources.join()",
+            js_str!(
+                r#"0=1.0,1="a\u0041",id=12345678901234567890,n=null,2=undefined,3=-0,=undefined"#
+            ),
+        ),
+        TestAction::assert("result[2].id === 12345678901234567890n"),
+    ]);
+}
+
+#[test]
+fn json_parse_reviver_source_text_modified_value() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var sources = [];
+                JSON.parse('[1, 2]', function (key, value, context) {
+                    if (key === "0") {
+                        this[1] = 3;
+                    }
+                    sources.push(context.source);
+                    return value;
+                });
+            "#}),
+        TestAction::assert_eq("sources.join()", js_str!("1,,")),
+    ]);
+}
+
+#[test]
+fn json_raw_json() {
+    run_test_actions([
+        TestAction::run(r#"var raw = JSON.rawJSON("12345678901234567890");"#),
+        TestAction::assert("JSON.isRawJSON(raw)"),
+        TestAction::assert("!JSON.isRawJSON({ rawJSON: '1' })"),
+        TestAction::assert("Object.isFrozen(raw)"),
+        TestAction::assert("Object.getPrototypeOf(raw) === null"),
+        TestAction::assert_eq(
+            "JSON.stringify({ id: raw, list: [JSON.rawJSON('\"a\"')] })",
+            js_str!(r#"{"id":12345678901234567890,"list":["a"]}"#),
+        ),
+        TestAction::assert_native_error(
+            "JSON.rawJSON('{}')",
+            JsNativeErrorKind::Syntax,
+            "raw JSON text must be a primitive value",
+        ),
+        TestAction::assert_native_error(
+            "JSON.rawJSON(' 1')",
+            JsNativeErrorKind::Syntax,
+            "raw JSON text cannot be empty or start or end with whitespace",
+        ),
+    ]);
+}
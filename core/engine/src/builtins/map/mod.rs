@@ -34,7 +34,7 @@ pub(crate) use map_iterator::MapIterator;
 
 pub mod ordered_map;
 use crate::value::JsVariant;
-use ordered_map::OrderedMap;
+use ordered_map::{MapKey, OrderedMap};
 
 #[cfg(test)]
 mod tests;
@@ -231,12 +231,28 @@ impl Map {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-map.prototype.set
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/set
-    pub(crate) fn set(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+    pub(crate) fn set(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
         let key = args.get_or_undefined(0);
         let value = args.get_or_undefined(1);
 
         // 1. Let M be the this value.
         if let Some(object) = this.as_object() {
+            // Appending an entry grows the storage of the map, which is not allocated by the
+            // garbage collector.
+            let len = object
+                .downcast_ref::<OrderedMap<JsValue>>()
+                .filter(|map| !map.contains_key(key))
+                .map(|map| map.full_len());
+            if let Some(len) = len {
+                context.check_heap_limit(
+                    (len + 1).saturating_mul(size_of::<(MapKey, Option<JsValue>)>()),
+                )?;
+            }
+
             // 2. Perform ? RequireInternalSlot(M, [[MapData]]).
             // 3. Let entries be the List that is M.[[MapData]].
             if let Some(mut map) = object.downcast_mut::<OrderedMap<JsValue>>() {
@@ -17,6 +17,7 @@ use crate::{
     JsResult, JsString, JsValue,
 };
 use boa_profiler::Profiler;
+use float16::f16;
 
 use super::{BuiltInBuilder, IntrinsicObject};
 
@@ -61,6 +62,7 @@ impl IntrinsicObject for Math {
             .static_method(Self::exp, js_string!("exp"), 1)
             .static_method(Self::expm1, js_string!("expm1"), 1)
             .static_method(Self::floor, js_string!("floor"), 1)
+            .static_method(Self::f16round, js_string!("f16round"), 1)
             .static_method(Self::fround, js_string!("fround"), 1)
             .static_method(Self::hypot, js_string!("hypot"), 2)
             .static_method(Self::imul, js_string!("imul"), 2)
@@ -450,6 +452,30 @@ impl Math {
             .into())
     }
 
+    /// Get the nearest 16-bit half precision float representation of a number.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-math.f16round
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/f16round
+    pub(crate) fn f16round(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let n be ? ToNumber(x).
+        let x = args.get_or_undefined(0).to_number(context)?;
+
+        // 2. If n is NaN, return NaN.
+        // 3. If n is one of +0𝔽, -0𝔽, +∞𝔽, or -∞𝔽, return n.
+        // 4. Let n16 be the result of converting n to IEEE 754-2019 binary16 format using roundTiesToEven mode.
+        // 5. Let n64 be the result of converting n16 to IEEE 754-2019 binary64 format.
+        // 6. Return the ECMAScript Number value corresponding to n64.
+        Ok(f16::from_f64(x).to_f64().into())
+    }
+
     /// Get the nearest 32-bit single precision float representation of a number.
     ///
     /// More information:
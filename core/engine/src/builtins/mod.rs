@@ -2,6 +2,7 @@
 
 pub mod array;
 pub mod array_buffer;
+pub mod async_disposable_stack;
 pub mod async_function;
 pub mod async_generator;
 pub mod async_generator_function;
@@ -10,6 +11,7 @@ pub mod bigint;
 pub mod boolean;
 pub mod dataview;
 pub mod date;
+pub mod disposable_stack;
 pub mod error;
 pub mod eval;
 pub mod function;
@@ -45,13 +47,14 @@ pub mod escape;
 #[cfg(feature = "intl")]
 pub mod intl;
 
-// TODO: remove `cfg` when `Temporal` gets to stage 4.
-#[cfg(any(feature = "intl", feature = "temporal"))]
 pub(crate) mod options;
 
 #[cfg(feature = "temporal")]
 pub mod temporal;
 
+#[cfg(feature = "experimental")]
+pub mod shadow_realm;
+
 pub(crate) use self::{
     array::Array,
     async_function::AsyncFunction,
@@ -60,8 +63,8 @@ pub(crate) use self::{
     dataview::DataView,
     date::Date,
     error::{
-        AggregateError, Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError,
-        UriError,
+        AggregateError, Error, EvalError, RangeError, ReferenceError, SuppressedError, SyntaxError,
+        TypeError, UriError,
     },
     eval::Eval,
     function::BuiltInFunctionObject,
@@ -78,8 +81,8 @@ pub(crate) use self::{
     string::String,
     symbol::Symbol,
     typed_array::{
-        BigInt64Array, BigUint64Array, Float32Array, Float64Array, Int16Array, Int32Array,
-        Int8Array, Uint16Array, Uint32Array, Uint8Array, Uint8ClampedArray,
+        BigInt64Array, BigUint64Array, Float16Array, Float32Array, Float64Array, Int16Array,
+        Int32Array, Int8Array, Uint16Array, Uint32Array, Uint8Array, Uint8ClampedArray,
     },
 };
 
@@ -87,13 +90,17 @@ use crate::{
     builtins::{
         array::ArrayIterator,
         array_buffer::{ArrayBuffer, SharedArrayBuffer},
+        async_disposable_stack::AsyncDisposableStack,
         async_generator::AsyncGenerator,
         async_generator_function::AsyncGeneratorFunction,
         atomics::Atomics,
+        disposable_stack::DisposableStack,
         error::r#type::ThrowTypeError,
         generator::Generator,
         generator_function::GeneratorFunction,
-        iterable::{AsyncFromSyncIterator, AsyncIterator, Iterator},
+        iterable::{
+            AsyncFromSyncIterator, AsyncIterator, Iterator, IteratorHelper, WrapForValidIterator,
+        },
         map::MapIterator,
         object::for_in_iterator::ForInIterator,
         regexp::RegExpStringIterator,
@@ -101,7 +108,7 @@ use crate::{
         string::StringIterator,
         typed_array::BuiltinTypedArray,
         uri::{DecodeUri, DecodeUriComponent, EncodeUri, EncodeUriComponent},
-        weak::WeakRef,
+        weak::{FinalizationRegistry, WeakRef},
         weak_map::WeakMap,
         weak_set::WeakSet,
     },
@@ -203,6 +210,8 @@ impl Realm {
         BuiltInFunctionObject::init(self);
         OrdinaryObject::init(self);
         Iterator::init(self);
+        IteratorHelper::init(self);
+        WrapForValidIterator::init(self);
         AsyncIterator::init(self);
         AsyncFromSyncIterator::init(self);
         ForInIterator::init(self);
@@ -241,6 +250,7 @@ impl Realm {
         Uint32Array::init(self);
         BigInt64Array::init(self);
         BigUint64Array::init(self);
+        Float16Array::init(self);
         Float32Array::init(self);
         Float64Array::init(self);
         Symbol::init(self);
@@ -253,6 +263,7 @@ impl Realm {
         EvalError::init(self);
         UriError::init(self);
         AggregateError::init(self);
+        SuppressedError::init(self);
         Reflect::init(self);
         Generator::init(self);
         GeneratorFunction::init(self);
@@ -267,6 +278,9 @@ impl Realm {
         WeakRef::init(self);
         WeakMap::init(self);
         WeakSet::init(self);
+        FinalizationRegistry::init(self);
+        DisposableStack::init(self);
+        AsyncDisposableStack::init(self);
         Atomics::init(self);
 
         #[cfg(feature = "annex-b")]
@@ -287,6 +301,9 @@ impl Realm {
             intl::segmenter::SegmentIterator::init(self);
             intl::PluralRules::init(self);
             intl::NumberFormat::init(self);
+            intl::RelativeTimeFormat::init(self);
+            intl::DisplayNames::init(self);
+            intl::DurationFormat::init(self);
         }
 
         #[cfg(feature = "temporal")]
@@ -302,6 +319,9 @@ impl Realm {
             temporal::PlainYearMonth::init(self);
             temporal::ZonedDateTime::init(self);
         }
+
+        #[cfg(feature = "experimental")]
+        shadow_realm::ShadowRealm::init(self);
     }
 }
 
@@ -346,6 +366,7 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
     global_binding::<OrdinaryObject>(context)?;
     global_binding::<Math>(context)?;
     global_binding::<Json>(context)?;
+    global_binding::<Iterator>(context)?;
     global_binding::<Array>(context)?;
     global_binding::<Proxy>(context)?;
     global_binding::<ArrayBuffer>(context)?;
@@ -374,6 +395,7 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
     global_binding::<Uint32Array>(context)?;
     global_binding::<BigInt64Array>(context)?;
     global_binding::<BigUint64Array>(context)?;
+    global_binding::<Float16Array>(context)?;
     global_binding::<Float32Array>(context)?;
     global_binding::<Float64Array>(context)?;
     global_binding::<Symbol>(context)?;
@@ -385,6 +407,7 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
     global_binding::<EvalError>(context)?;
     global_binding::<UriError>(context)?;
     global_binding::<AggregateError>(context)?;
+    global_binding::<SuppressedError>(context)?;
     global_binding::<Reflect>(context)?;
     global_binding::<Promise>(context)?;
     global_binding::<EncodeUri>(context)?;
@@ -394,6 +417,9 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
     global_binding::<WeakRef>(context)?;
     global_binding::<WeakMap>(context)?;
     global_binding::<WeakSet>(context)?;
+    global_binding::<FinalizationRegistry>(context)?;
+    global_binding::<DisposableStack>(context)?;
+    global_binding::<AsyncDisposableStack>(context)?;
     global_binding::<Atomics>(context)?;
 
     #[cfg(feature = "annex-b")]
@@ -410,5 +436,8 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
         global_binding::<temporal::Temporal>(context)?;
     }
 
+    #[cfg(feature = "experimental")]
+    global_binding::<shadow_realm::ShadowRealm>(context)?;
+
     Ok(())
 }
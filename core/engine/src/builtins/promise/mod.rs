@@ -144,6 +144,8 @@ macro_rules! if_abrupt_reject_promise {
     ($value:expr, $capability:expr, $context: expr) => {
         match $value {
             // 1. If value is an abrupt completion, then
+            // Uncatchable errors cannot be passed to JavaScript code, so they are propagated.
+            Err(err) if !err.is_catchable() => return Err(err),
             Err(err) => {
                 let err = err.to_opaque($context);
                 // a. Perform ? Call(capability.[[Reject]], undefined, « value.[[Value]] »).
@@ -440,6 +442,9 @@ impl BuiltInConstructor for Promise {
 
         // 10. If completion is an abrupt completion, then
         if let Err(e) = completion {
+            if !e.is_catchable() {
+                return Err(e);
+            }
             let e = e.to_opaque(context);
             // a. Perform ? Call(resolvingFunctions.[[Reject]], undefined, « completion.[[Value]] »).
             resolving_functions
@@ -496,6 +501,7 @@ impl Promise {
 
         match status {
             // 5. If status is an abrupt completion, then
+            Err(err) if !err.is_catchable() => return Err(err),
             Err(err) => {
                 let value = err.to_opaque(context);
 
@@ -1460,6 +1466,9 @@ impl Promise {
         e: &JsError,
         context: &mut Context,
     ) -> JsResult<JsObject> {
+        if !e.is_catchable() {
+            return Err(e.clone());
+        }
         let e = e.to_opaque(context);
 
         // 2. Let promiseCapability be ? NewPromiseCapability(C).
@@ -2142,6 +2151,7 @@ impl Promise {
 
                     // 9. Let then be Completion(Get(resolution, "then")).
                     let then_action = match then.get(js_string!("then"), context) {
+                        Err(e) if !e.is_catchable() => return Err(e),
                         // 10. If then is an abrupt completion, then
                         Err(e) => {
                             //   a. Perform RejectPromise(promise, then.[[Value]]).
@@ -2279,10 +2289,16 @@ fn new_promise_reaction_job(
                 }
             },
             //   e. Else, let handlerResult be Completion(HostCallJobCallback(handler, undefined, « argument »)).
-            Some(handler) => context
-                .host_hooks()
-                .call_job_callback(handler, &JsValue::undefined(), &[argument.clone()], context)
-                .map_err(|e| e.to_opaque(context)),
+            Some(handler) => match context.host_hooks().call_job_callback(
+                handler,
+                &JsValue::undefined(),
+                &[argument.clone()],
+                context,
+            ) {
+                // Uncatchable errors cannot be passed to JavaScript code, so they are propagated.
+                Err(e) if !e.is_catchable() => return Err(e),
+                result => result.map_err(|e| e.to_opaque(context)),
+            },
         };
 
         match promise_capability {
@@ -2364,6 +2380,9 @@ fn new_promise_resolve_thenable_job(
 
         //    c. If thenCallResult is an abrupt completion, then
         if let Err(value) = then_call_result {
+            if !value.is_catchable() {
+                return Err(value);
+            }
             let value = value.to_opaque(context);
             //    i. Return ? Call(resolvingFunctions.[[Reject]], undefined, « thenCallResult.[[Value]] »).
             return resolving_functions
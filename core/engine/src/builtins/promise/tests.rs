@@ -1,4 +1,10 @@
-use crate::{run_test_actions, TestAction};
+use std::rc::Rc;
+
+use crate::{
+    run_test_actions,
+    vm::{InterruptAction, InterruptReason},
+    Context, JsNativeError, JsNativeErrorKind, TestAction,
+};
 use indoc::indoc;
 
 #[test]
@@ -17,3 +23,33 @@ fn promise() {
         TestAction::assert_eq("count", 3),
     ]);
 }
+
+#[test]
+fn promise_uncatchable_errors() {
+    run_test_actions([
+        // Exhausting the fuel in the executor doesn't reject the promise.
+        TestAction::inspect_context(|ctx| ctx.set_fuel(Some(1000))),
+        TestAction::assert_native_error(
+            "new Promise(() => { for(;;); })",
+            JsNativeErrorKind::RuntimeLimit,
+            "exceeded the execution budget",
+        ),
+        TestAction::inspect_context(|ctx| ctx.set_fuel(None)),
+        // Terminating the execution in a reaction job stops running the jobs.
+        TestAction::run(indoc! {r#"
+            var reached = false;
+            Promise.resolve().then(() => { for(;;); }).catch(() => { reached = true; });
+        "#}),
+        TestAction::inspect_context(|ctx| {
+            ctx.set_interrupt_handler(Rc::new(|_: InterruptReason, _: &mut Context| {
+                InterruptAction::Terminate
+            }));
+            ctx.set_fuel(Some(1000));
+            let err = ctx.run_jobs().unwrap_err();
+            assert!(err.as_native().is_some_and(JsNativeError::is_terminated));
+            ctx.interrupt_handle().cancel_termination();
+            ctx.set_fuel(None);
+        }),
+        TestAction::assert_eq("reached", false),
+    ]);
+}
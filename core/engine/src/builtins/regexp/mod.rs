@@ -24,9 +24,9 @@ use crate::{
 };
 use boa_gc::{Finalize, Trace};
 use boa_macros::{js_str, utf16};
-use boa_parser::lexer::regex::RegExpFlags;
+use boa_parser::lexer::regex::{RegExpFlags, RegExpMatcher};
 use boa_profiler::Profiler;
-use regress::{Flags, Range, Regex};
+use regress::{Flags, Range};
 use std::str::FromStr;
 
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
@@ -42,7 +42,7 @@ mod tests;
 #[boa_gc(unsafe_empty_trace)]
 pub struct RegExp {
     /// Regex matcher.
-    matcher: Regex,
+    matcher: RegExpMatcher,
     flags: RegExpFlags,
     original_source: JsString,
     original_flags: JsString,
@@ -95,6 +95,7 @@ impl IntrinsicObject for RegExp {
                 None,
                 Attribute::CONFIGURABLE,
             )
+            .static_method(Self::escape, js_string!("escape"), 1)
             .property(js_string!("lastIndex"), 0, Attribute::all())
             .method(Self::test, js_string!("test"), 1)
             .method(Self::exec, js_string!("exec"), 1)
@@ -178,7 +179,7 @@ impl BuiltInObject for RegExp {
 impl BuiltInConstructor for RegExp {
     const LENGTH: usize = 2;
     const P: usize = 19;
-    const SP: usize = 1;
+    const SP: usize = 2;
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
         StandardConstructors::regexp;
@@ -271,6 +272,16 @@ impl BuiltInConstructor for RegExp {
 }
 
 impl RegExp {
+    /// Gets the source text the regular expression was compiled from.
+    pub(crate) const fn original_source(&self) -> &JsString {
+        &self.original_source
+    }
+
+    /// Gets the flags the regular expression was compiled with.
+    pub(crate) const fn original_flags(&self) -> &JsString {
+        &self.original_flags
+    }
+
     /// `7.2.8 IsRegExp ( argument )`
     ///
     /// This modified to return the object if it's `true`, [`None`] otherwise.
@@ -310,7 +321,7 @@ impl RegExp {
     /// Equivalent to the beginning of [`RegExpInitialize ( obj, pattern, flags )`][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-regexpinitialize
-    fn compile_native_regexp(
+    pub(crate) fn compile_native_regexp(
         pattern: &JsValue,
         flags: &JsValue,
         context: &mut Context,
@@ -341,12 +352,11 @@ impl RegExp {
 
         // 13. Let parseResult be ParsePattern(patternText, u, v).
         // 14. If parseResult is a non-empty List of SyntaxError objects, throw a SyntaxError exception.
-        let matcher =
-            Regex::from_unicode(p.code_points().map(CodePoint::as_u32), Flags::from(flags))
-                .map_err(|error| {
-                    JsNativeError::syntax()
-                        .with_message(format!("failed to create matcher: {}", error.text))
-                })?;
+        let pattern = p.code_points().map(CodePoint::as_u32).collect::<Vec<_>>();
+        let matcher = RegExpMatcher::new(&pattern, Flags::from(flags)).map_err(|error| {
+            JsNativeError::syntax()
+                .with_message(format!("failed to create matcher: {}", error.text))
+        })?;
 
         // 15. Assert: parseResult is a Pattern Parse Node.
         // 16. Set obj.[[OriginalSource]] to P.
@@ -775,6 +785,53 @@ impl RegExp {
         }
     }
 
+    /// `RegExp.escape ( S )`
+    ///
+    /// Escapes any characters of a string that could have a special meaning in a pattern.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-regexp.escape
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/escape
+    pub(crate) fn escape(_: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. If S is not a String, throw a TypeError exception.
+        let Some(s) = args.get_or_undefined(0).as_string() else {
+            return Err(JsNativeError::typ()
+                .with_message("RegExp.escape requires a string argument")
+                .into());
+        };
+
+        // 2. Let escaped be the empty String.
+        let mut escaped = Vec::with_capacity(s.len());
+
+        // 3. Let cpList be StringToCodePoints(S).
+        // 4. For each code point c of cpList, do
+        for c in s.code_points() {
+            match c {
+                // a. If escaped is the empty String and c is matched by either DecimalDigit or AsciiLetter, then
+                CodePoint::Unicode(c) if escaped.is_empty() && c.is_ascii_alphanumeric() => {
+                    // i. NOTE: Escaping a leading digit ensures that output corresponds with pattern text which may be used
+                    //    after a \0 character escape or a DecimalEscape such as \1 and still match S rather than be
+                    //    interpreted as an extension of the preceding escape sequence. Escaping a leading ASCII letter
+                    //    does the same for the context after \c.
+                    // ii. Let numericValue be the numeric value of c.
+                    // iii. Let hex be Number::toString(𝔽(numericValue), 16).
+                    // iv. Assert: The length of hex is 2.
+                    // v. Set escaped to the string-concatenation of the code unit 0x005C (REVERSE SOLIDUS), "x", and hex.
+                    escaped.extend(format!("\\x{:02x}", u32::from(c)).encode_utf16());
+                }
+                // b. Else,
+                //     i. Set escaped to the string-concatenation of escaped and EncodeForRegExpEscape(c).
+                c => encode_for_regexp_escape(c, &mut escaped),
+            }
+        }
+
+        // 5. Return escaped.
+        Ok(js_string!(&escaped[..]).into())
+    }
+
     /// `RegExp.prototype.test( string )`
     ///
     /// The `test()` method executes a search for a match between a regular expression and a specified string.
@@ -940,7 +997,7 @@ impl RegExp {
         }
 
         // 8. Let matcher be R.[[RegExpMatcher]].
-        let matcher = &rx.matcher;
+        let matcher = rx.matcher.regex();
 
         // 9. If flags contains "u" or flags contains "v", let fullUnicode be true; else let fullUnicode be false.
         let full_unicode = flags.contains(b'u') || flags.contains(b'v');
@@ -1074,6 +1131,7 @@ impl RegExp {
 
         let mut named_groups = match_value
             .named_groups()
+            .map(|(name, range)| (rx.matcher.group_name(name), range))
             .collect::<Vec<(&str, Option<Range>)>>();
         // Strict mode requires groups to be created in a sorted order
         named_groups.sort_by(|(name_x, _), (name_y, _)| name_x.cmp(name_y));
@@ -1088,12 +1146,29 @@ impl RegExp {
             // a. Let groups be OrdinaryObjectCreate(null).
             let groups = JsObject::with_null_proto();
             let group_names = JsObject::with_null_proto();
+            let mut matched_group_names = Vec::new();
 
             // e. If the ith capture of R was defined with a GroupName, then
             // i. Let s be the CapturingGroupName of that GroupName.
-            // ii. Perform ! CreateDataPropertyOrThrow(groups, s, capturedValue).
-            // iii. Append s to groupNames.
             for (name, range) in named_groups {
+                // ii. If matchedGroupNames contains s, then
+                //     1. Assert: capturedValue is undefined.
+                //     2. Append undefined to groupNames.
+                if matched_group_names.contains(&name) {
+                    debug_assert!(range.is_none());
+                    continue;
+                }
+
+                // iii. Else,
+                //     1. If capturedValue is not undefined, append s to matchedGroupNames.
+                //     2. NOTE: If there are multiple groups named s, groups may already have an s property at this point.
+                //        However, because groups is an ordinary object whose properties are all writable data properties,
+                //        the call to CreateDataPropertyOrThrow is nevertheless guaranteed to succeed.
+                //     3. Perform ! CreateDataPropertyOrThrow(groups, s, capturedValue).
+                //     4. Append s to groupNames.
+                if range.is_some() {
+                    matched_group_names.push(name);
+                }
                 let name = js_string!(name);
                 if let Some(range) = range {
                     let value = input.get_expect(range.clone());
@@ -1622,6 +1697,9 @@ impl RegExp {
                 //    In such cases, the corresponding substitution is ignored.
 
                 // ii. Set accumulatedResult to the string-concatenation of accumulatedResult, the substring of S from nextSourcePosition to position, and replacement.
+                context.check_string_allocation(
+                    accumulated_result.len() + position - next_source_position + replacement.len(),
+                )?;
                 accumulated_result.extend(s.get_expect(next_source_position..position).iter());
                 accumulated_result.extend(replacement.iter());
 
@@ -1935,6 +2013,82 @@ impl RegExp {
     }
 }
 
+/// Abstract operation `EncodeForRegExpEscape ( c )`
+///
+/// Appends the escaped form of `c` to `escaped`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-encodeforregexpescape
+fn encode_for_regexp_escape(c: CodePoint, escaped: &mut Vec<u16>) {
+    let c = match c {
+        CodePoint::Unicode(c) => c,
+        // 5. If ... c has the same numeric value as a leading surrogate or trailing surrogate, then
+        //     c. Let escaped be the empty String.
+        //     d. Let codeUnits be UTF16EncodeCodePoint(c).
+        //     e. For each code unit cu of codeUnits, do
+        //         i. Set escaped to the string-concatenation of escaped and UnicodeEscape(cu).
+        //     f. Return escaped.
+        CodePoint::UnpairedSurrogate(surrogate) => {
+            escaped.extend(format!("\\u{surrogate:04x}").encode_utf16());
+            return;
+        }
+    };
+
+    match c {
+        // 1. If c is matched by SyntaxCharacter or c is U+002F (SOLIDUS), then
+        '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|'
+        | '/' => {
+            // a. Return the string-concatenation of 0x005C (REVERSE SOLIDUS) and UTF16EncodeCodePoint(c).
+            escaped.push(u16::from(b'\\'));
+            escaped.push(c as u16);
+        }
+        // 2. Else if c is the code point listed in some cell of the “Code Point” column of Table 67, then
+        //     a. Return the string-concatenation of 0x005C (REVERSE SOLIDUS) and the string in the “ControlEscape”
+        //        column of the row whose “Code Point” column contains c.
+        '\t' => escaped.extend_from_slice(utf16!(r"\t")),
+        '\n' => escaped.extend_from_slice(utf16!(r"\n")),
+        '\u{000B}' => escaped.extend_from_slice(utf16!(r"\v")),
+        '\u{000C}' => escaped.extend_from_slice(utf16!(r"\f")),
+        '\r' => escaped.extend_from_slice(utf16!(r"\r")),
+        // 3. Let otherPunctuators be the string-concatenation of ",-=<>#&!%:;@~'`" and the code unit 0x0022 (QUOTATION MARK).
+        // 4. Let toEscape be StringToCodePoints(otherPunctuators).
+        // 5. If toEscape contains c, c is matched by either WhiteSpace or LineTerminator, ..., then
+        ',' | '-' | '=' | '<' | '>' | '#' | '&' | '!' | '%' | ':' | ';' | '@' | '~' | '\''
+        | '`' | '"' | '\u{2028}' | '\u{2029}' => encode_escaped_code_point(c, escaped),
+        c if string::is_trimmable_whitespace(c) => encode_escaped_code_point(c, escaped),
+        // 6. Return UTF16EncodeCodePoint(c).
+        c => escaped.extend_from_slice(c.encode_utf16(&mut [0; 2])),
+    }
+}
+
+/// Steps 5.a to 5.f of [`EncodeForRegExpEscape ( c )`][spec], which escape `c` as an hexadecimal
+/// or unicode escape sequence.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-encodeforregexpescape
+fn encode_escaped_code_point(c: char, escaped: &mut Vec<u16>) {
+    // a. Let cNum be the numeric value of c.
+    let c_num = u32::from(c);
+
+    // b. If cNum ≤ 0xFF, then
+    if c_num <= 0xFF {
+        // i. Let hex be Number::toString(𝔽(cNum), 16).
+        // ii. Return the string-concatenation of the code unit 0x005C (REVERSE SOLIDUS), "x", and StringPad(hex, 2, "0", start).
+        escaped.extend(format!("\\x{c_num:02x}").encode_utf16());
+        return;
+    }
+
+    // c. Let escaped be the empty String.
+    // d. Let codeUnits be UTF16EncodeCodePoint(c).
+    // e. For each code unit cu of codeUnits, do
+    for cu in c.encode_utf16(&mut [0; 2]) {
+        // i. Set escaped to the string-concatenation of escaped and UnicodeEscape(cu).
+        escaped.extend(format!("\\u{cu:04x}").encode_utf16());
+    }
+    // f. Return escaped.
+}
+
 /// `22.2.5.2.3 AdvanceStringIndex ( S, index, unicode )`
 ///
 /// More information:
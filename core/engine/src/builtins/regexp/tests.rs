@@ -225,3 +225,63 @@ fn regular_expression_construction_independant_of_global_reg_exp() {
         TestAction::run(regex),
     ]);
 }
+
+#[test]
+fn regexp_escape() {
+    run_test_actions([
+        TestAction::assert_eq("RegExp.escape('foo.bar')", js_str!(r"\x66oo\.bar")),
+        TestAction::assert_eq("RegExp.escape('(a|b) c,d')", js_str!(r"\(a\|b\)\x20c\x2cd")),
+        TestAction::assert_eq(
+            "RegExp.escape('_\\n\\u2028\\ud800')",
+            js_str!(r"_\n\u2028\ud800"),
+        ),
+        TestAction::assert("new RegExp(RegExp.escape('1+1=2?')).test('1+1=2?')"),
+        TestAction::assert_native_error(
+            "RegExp.escape(1)",
+            JsNativeErrorKind::Type,
+            "RegExp.escape requires a string argument",
+        ),
+    ]);
+}
+
+#[test]
+fn duplicate_named_groups() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var re = /(?<year>\d{4})-\d{2}|\d{2}-(?<year>\d{4})/;
+            var first = re.exec("2024-10");
+            var second = re.exec("10-2024");
+        "#}),
+        TestAction::assert_eq("first.groups.year", js_str!("2024")),
+        TestAction::assert_eq("second.groups.year", js_str!("2024")),
+        TestAction::assert_eq("Object.keys(second.groups).length", 1),
+        TestAction::assert_eq("'10-2024'.replace(re, '$<year>')", js_str!("2024")),
+        TestAction::assert("/(?:(?<a>x)|(?<a>y))\\k<a>/.test('yy')"),
+        TestAction::assert("!/(?:(?<a>x)|(?<a>y))\\k<a>/.test('yx')"),
+        TestAction::assert_eq(
+            "/(?<a>x)|(?<a>y)/d.exec('y').indices.groups.a.join()",
+            js_str!("0,1"),
+        ),
+        TestAction::assert_native_error(
+            "new RegExp('(?<a>x)(?<a>y)')",
+            JsNativeErrorKind::Syntax,
+            "failed to create matcher: Duplicate capture group name",
+        ),
+    ]);
+}
+
+#[test]
+fn modifiers() {
+    run_test_actions([
+        TestAction::assert("/(?i:a)b/.test('Ab')"),
+        TestAction::assert("!/(?i:a)b/.test('AB')"),
+        TestAction::assert("/(?-i:a)b/i.test('aB')"),
+        TestAction::assert("!/(?-i:a)b/i.test('AB')"),
+        TestAction::assert("/(?s:.)./.test('\\na')"),
+        TestAction::assert_native_error(
+            "new RegExp('(?i-i:a)')",
+            JsNativeErrorKind::Syntax,
+            "failed to create matcher: Invalid group modifier",
+        ),
+    ]);
+}
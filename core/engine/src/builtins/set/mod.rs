@@ -20,7 +20,10 @@ pub mod ordered_set;
 use self::ordered_set::OrderedSet;
 use super::iterable::IteratorHint;
 use crate::{
-    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    builtins::{
+        map::ordered_map::MapKey, BuiltInBuilder, BuiltInConstructor, BuiltInObject,
+        IntrinsicObject,
+    },
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     error::JsNativeError,
     js_string,
@@ -331,7 +334,22 @@ impl Set {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.add
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/add
-    pub(crate) fn add(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+    pub(crate) fn add(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // Appending a value grows the storage of the set, which is not allocated by the garbage
+        // collector.
+        let len = this
+            .as_object()
+            .and_then(JsObject::downcast_ref::<OrderedSet>)
+            .filter(|set| !set.contains(args.get_or_undefined(0)))
+            .map(|set| set.full_len());
+        if let Some(len) = len {
+            context.check_heap_limit((len + 1).saturating_mul(size_of::<MapKey>()))?;
+        }
+
         // 1. Let S be the this value.
         // 2. Perform ? RequireInternalSlot(S, [[SetData]]).
         let Some(mut set) = this
@@ -0,0 +1,364 @@
+//! Boa's implementation of ECMAScript's `ShadowRealm` builtin object.
+//!
+//! A `ShadowRealm` is a distinct global environment with its own set of intrinsics, in which
+//! code can be evaluated without access to the object graph of its creator.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-shadowrealm/
+
+use boa_gc::{Finalize, Trace};
+use boa_parser::{Parser, Source};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{
+        eval::Eval, promise::PromiseCapability, BuiltInBuilder, BuiltInConstructor, BuiltInObject,
+        IntrinsicObject, Promise,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    module::{load_dynamic_import, ModuleRequest, Referrer},
+    native_function::NativeFunction,
+    object::{internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsError, JsNativeError, JsResult, JsString, JsValue,
+};
+
+mod wrapped_function;
+
+pub(crate) use wrapped_function::WrappedFunction;
+
+use wrapped_function::get_wrapped_value;
+
+#[cfg(test)]
+mod tests;
+
+/// Boa's implementation of ECMAScript's `ShadowRealm` builtin object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm-objects
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct ShadowRealm {
+    /// `[[ShadowRealm]]`
+    realm: Realm,
+}
+
+impl IntrinsicObject for ShadowRealm {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::evaluate, js_string!("evaluate"), 1)
+            .method(Self::import_value, js_string!("importValue"), 2)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for ShadowRealm {
+    const NAME: JsString = StaticJsStrings::SHADOW_REALM;
+}
+
+impl BuiltInConstructor for ShadowRealm {
+    const LENGTH: usize = 0;
+    const P: usize = 3;
+    const SP: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::shadow_realm;
+
+    /// [`ShadowRealm ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("ShadowRealm: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. Let O be ? OrdinaryCreateFromConstructor(NewTarget, "%ShadowRealm.prototype%", « [[ShadowRealm]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::shadow_realm,
+            context,
+        )?;
+
+        // 3. Let callerContext be the running execution context.
+        // 4. Perform ? InitializeHostDefinedRealm().
+        // 5. Let innerContext be the running execution context.
+        // 6. Remove innerContext from the execution context stack and restore callerContext as the running execution context.
+        // 7. Let realmRec be the Realm of innerContext.
+        // 8. Set O.[[ShadowRealm]] to realmRec.
+        // 9. Perform ? SetDefaultGlobalBindings(realmRec.[[GlobalObject]]).
+        let realm = context.create_realm()?;
+
+        // 10. Perform ? HostInitializeShadowRealm(realmRec).
+        context
+            .host_hooks()
+            .initialize_shadow_realm(&realm, context)?;
+
+        // 11. Return O.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self { realm },
+        )
+        .into())
+    }
+}
+
+impl ShadowRealm {
+    /// Abstract operation [`ValidateShadowRealmObject ( O )`][spec], returning the
+    /// `[[ShadowRealm]]` of `this`.
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-validateshadowrealmobject
+    fn this_realm(this: &JsValue, method: &str) -> JsResult<Realm> {
+        // 1. Perform ? RequireInternalSlot(O, [[ShadowRealm]]).
+        this.as_object()
+            .and_then(|o| o.downcast_ref::<Self>().map(|sr| sr.realm.clone()))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "ShadowRealm.prototype.{method}: `this` is not a ShadowRealm object"
+                    ))
+                    .into()
+            })
+    }
+
+    /// [`ShadowRealm.prototype.evaluate ( sourceText )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm.prototype.evaluate
+    fn evaluate(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? ValidateShadowRealmObject(O).
+        let eval_realm = Self::this_realm(this, "evaluate")?;
+
+        // 3. If sourceText is not a String, throw a TypeError exception.
+        let source_text = args.get_or_undefined(0);
+        if !source_text.is_string() {
+            return Err(JsNativeError::typ()
+                .with_message("ShadowRealm.prototype.evaluate: source text must be a string")
+                .into());
+        }
+
+        // 4. Let callerRealm be the current Realm Record.
+        // 5. Let evalRealm be O.[[ShadowRealm]].
+        // 6. Return ? PerformShadowRealmEval(sourceText, callerRealm, evalRealm).
+        Self::perform_shadow_realm_eval(source_text, eval_realm, context)
+    }
+
+    /// Abstract operation [`PerformShadowRealmEval ( sourceText, callerRealm, evalRealm )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-performshadowrealmeval
+    fn perform_shadow_realm_eval(
+        source_text: &JsValue,
+        eval_realm: Realm,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let caller_realm = context.realm().clone();
+        let source_string = source_text
+            .as_string()
+            .expect("the source text must be a string");
+
+        // 1. Perform ? HostEnsureCanCompileStrings(evalRealm, « », sourceText, false).
+        context.host_hooks().ensure_can_compile_strings(
+            eval_realm.clone(),
+            &[],
+            source_string,
+            false,
+            context,
+        )?;
+        let source = source_string.to_vec();
+
+        // 2. Perform the following substeps in an implementation-defined order, possibly interleaving parsing and error detection:
+        //     a. Let script be ParseText(sourceText, Script).
+        //     b. If script is a List of errors, throw a SyntaxError exception.
+        //     c. If script Contains ScriptBody is false, return undefined.
+        //     d. Let body be the ScriptBody of script.
+        //     e. If body Contains NewTarget is true, throw a SyntaxError exception.
+        //     f. If body Contains SuperProperty is true, throw a SyntaxError exception.
+        //     g. If body Contains SuperCall is true, throw a SyntaxError exception.
+        // NOTE: The parser already throws the errors of steps e to g for the code of an indirect
+        //       eval.
+        let mut parser = Parser::new(Source::from_utf16(&source));
+        parser.set_identifier(context.next_parser_identifier());
+        let (body, source) = parser.parse_eval(false, context.interner_mut())?;
+
+        // 3. Let strictEval be IsStrict of script.
+        // 4. Let runningContext be the running execution context.
+        // 5. If runningContext is not already suspended, suspend runningContext.
+        // 6. Let evalContext be GetShadowRealmContext(evalRealm, strictEval).
+        // 7. Push evalContext onto the execution context stack; evalContext is now the running execution context.
+        let old_realm = context.enter_realm(eval_realm);
+
+        // 8-14. Let result be Completion(EvalDeclarationInstantiation(...)) and evaluate body.
+        // NOTE: An indirect eval in the realm of the ShadowRealm has the same semantics as steps 8
+        //       to 14, so the body is evaluated like the body of an indirect eval.
+        let result = Eval::evaluate_body(body, source, false, None, false, context);
+
+        // 15. Suspend evalContext and remove it from the execution context stack.
+        // 16. Resume the context that is now on the top of the execution context stack as the running execution context.
+        context.enter_realm(old_realm);
+
+        let result = match result {
+            // 17. If result is an abrupt completion, then
+            //     a. Throw a TypeError exception.
+            Err(err) if err.is_catchable() => {
+                let message = err
+                    .as_native()
+                    .map(|native| format!(": {native}"))
+                    .unwrap_or_default();
+                return Err(JsNativeError::typ()
+                    .with_message(format!(
+                        "ShadowRealm.prototype.evaluate: the evaluated code threw an error{message}"
+                    ))
+                    .into());
+            }
+            result => result?,
+        };
+
+        // 18. Return ? GetWrappedValue(callerRealm, result).
+        get_wrapped_value(&caller_realm, result, context)
+    }
+
+    /// [`ShadowRealm.prototype.importValue ( specifier, exportName )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm.prototype.importvalue
+    fn import_value(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? ValidateShadowRealmObject(O).
+        let eval_realm = Self::this_realm(this, "importValue")?;
+
+        // 3. Let specifierString be ? ToString(specifier).
+        let specifier = args.get_or_undefined(0).to_string(context)?;
+
+        // 4. If exportName is not a String, throw a TypeError exception.
+        let Some(export_name) = args.get_or_undefined(1).as_string().cloned() else {
+            return Err(JsNativeError::typ()
+                .with_message("ShadowRealm.prototype.importValue: export name must be a string")
+                .into());
+        };
+
+        // 5. Let callerRealm be the current Realm Record.
+        // 6. Let evalRealm be O.[[ShadowRealm]].
+        // 7. Return ShadowRealmImportValue(specifierString, exportName, callerRealm, evalRealm).
+        Ok(Self::shadow_realm_import_value(specifier, export_name, eval_realm, context).into())
+    }
+
+    /// Abstract operation [`ShadowRealmImportValue ( specifierString, exportNameString, callerRealm, evalRealm )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealmimportvalue
+    fn shadow_realm_import_value(
+        specifier: JsString,
+        export_name: JsString,
+        eval_realm: Realm,
+        context: &mut Context,
+    ) -> JsObject {
+        // 1. Let evalContext be GetShadowRealmContext(evalRealm, true).
+        // 2. Let innerCapability be ! NewPromiseCapability(%Promise%).
+        let inner_capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("operation cannot fail for the %Promise% intrinsic");
+        let inner_promise = inner_capability.promise().clone();
+
+        // 3. Let runningContext be the running execution context.
+        // 4. If runningContext is not already suspended, suspend runningContext.
+        // 5. Push evalContext onto the execution context stack; evalContext is now the running execution context.
+        let old_realm = context.enter_realm(eval_realm.clone());
+
+        // 6. Let referrer be the Realm component of evalContext.
+        // 7. Perform HostLoadImportedModule(referrer, specifierString, empty, innerCapability).
+        load_dynamic_import(
+            Referrer::Realm(eval_realm),
+            ModuleRequest::from_specifier(specifier),
+            inner_capability,
+            context,
+        );
+
+        // 8. Suspend evalContext and remove it from the execution context stack.
+        // 9. Resume the context that is now on the top of the execution context stack as the running execution context.
+        context.enter_realm(old_realm);
+
+        // 10. Let onFulfilled be a new Abstract Closure with parameters (exports) that captures exportNameString and performs the following steps when called:
+        // 11. Let onFulfilled be CreateBuiltinFunction(onFulfilled, 1, "", « [[ExportNameString]] »).
+        let on_fulfilled = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |_, args, export_name, context| {
+                    // a. Let string be exportNameString.
+                    // b. Assert: string is a String.
+                    let exports = args.get_or_undefined(0).as_object().ok_or_else(|| {
+                        JsError::from(
+                            JsNativeError::typ()
+                                .with_message("ShadowRealm: module namespace must be an object"),
+                        )
+                    })?;
+
+                    // c. Let hasOwn be ? HasOwnProperty(exports, string).
+                    // d. If hasOwn is false, throw a TypeError exception.
+                    if !exports.has_own_property(export_name.clone(), context)? {
+                        return Err(JsNativeError::typ()
+                            .with_message(format!(
+                                "ShadowRealm.prototype.importValue: module does not export `{}`",
+                                export_name.to_std_string_escaped()
+                            ))
+                            .into());
+                    }
+
+                    // e. Let value be ? Get(exports, string).
+                    let value = exports.get(export_name.clone(), context)?;
+
+                    // f. Let realm be the current Realm Record.
+                    // g. Return ? GetWrappedValue(realm, value).
+                    let realm = context.realm().clone();
+                    get_wrapped_value(&realm, value, context)
+                },
+                export_name,
+            ),
+        )
+        .length(1)
+        .build();
+
+        // 12. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("operation cannot fail for the %Promise% intrinsic");
+        let promise = capability.promise().clone();
+
+        // 13. Return PerformPromiseThen(innerCapability.[[Promise]], onFulfilled, callerRealm.[[Intrinsics]].[[%ThrowTypeError%]], promiseCapability).
+        Promise::perform_promise_then(
+            &inner_promise,
+            Some(on_fulfilled),
+            Some(context.intrinsics().objects().throw_type_error()),
+            Some(capability),
+            context,
+        );
+
+        promise
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+use boa_macros::js_str;
+use indoc::indoc;
+
+#[test]
+fn constructor() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "ShadowRealm()",
+            JsNativeErrorKind::Type,
+            "ShadowRealm: cannot call constructor without `new`",
+        ),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(new ShadowRealm())",
+            js_str!("[object ShadowRealm]"),
+        ),
+        TestAction::assert_native_error(
+            "ShadowRealm.prototype.evaluate.call({}, '1')",
+            JsNativeErrorKind::Type,
+            "ShadowRealm.prototype.evaluate: `this` is not a ShadowRealm object",
+        ),
+    ]);
+}
+
+#[test]
+fn evaluate() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var hostValue = 'host';
+                const realm = new ShadowRealm();
+                realm.evaluate('var counter = 0;');
+            "#}),
+        TestAction::assert_eq("realm.evaluate('1 + 2')", 3),
+        TestAction::assert_eq("realm.evaluate('++counter')", 1),
+        TestAction::assert_eq("realm.evaluate('++counter')", 2),
+        TestAction::assert_eq("realm.evaluate('typeof hostValue')", js_str!("undefined")),
+        TestAction::assert_eq("typeof counter", js_str!("undefined")),
+        TestAction::assert_native_error(
+            "realm.evaluate(1)",
+            JsNativeErrorKind::Type,
+            "ShadowRealm.prototype.evaluate: source text must be a string",
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate('[]')",
+            JsNativeErrorKind::Type,
+            "ShadowRealm: only primitives and callables can cross realms",
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate('new.target')",
+            JsNativeErrorKind::Syntax,
+            "invalid new.target usage at line 1, col 1",
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate('throw new Error()')",
+            JsNativeErrorKind::Type,
+            "ShadowRealm.prototype.evaluate: the evaluated code threw an error",
+        ),
+        TestAction::assert(indoc! {r#"
+                try {
+                    realm.evaluate('undeclared');
+                    false
+                } catch (e) {
+                    e instanceof TypeError
+                }
+            "#}),
+    ]);
+}
+
+#[test]
+fn wrapped_functions() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                const realm = new ShadowRealm();
+                const add = realm.evaluate('(function add(a, b) { return a + b; })');
+                const apply = realm.evaluate('(f, x) => f(x)');
+                const leak = realm.evaluate('() => ({})');
+                const thrower = realm.evaluate('() => { throw new Error("inner"); }');
+            "#}),
+        TestAction::assert_eq("typeof add", js_str!("function")),
+        TestAction::assert_eq("add.name", js_str!("add")),
+        TestAction::assert_eq("add.length", 2),
+        TestAction::assert("Object.getPrototypeOf(add) === Function.prototype"),
+        TestAction::assert_eq("add(1, 2)", 3),
+        TestAction::assert_eq("apply(x => x * 2, 21)", 42),
+        TestAction::assert_native_error(
+            "leak()",
+            JsNativeErrorKind::Type,
+            "ShadowRealm: the wrapped function threw an error",
+        ),
+        TestAction::assert_native_error(
+            "add({}, 1)",
+            JsNativeErrorKind::Type,
+            "ShadowRealm: the wrapped function threw an error",
+        ),
+        TestAction::assert_native_error(
+            "thrower()",
+            JsNativeErrorKind::Type,
+            "ShadowRealm: the wrapped function threw an error",
+        ),
+        TestAction::assert("realm.evaluate('Array') !== Array"),
+    ]);
+}
+
+#[test]
+fn import_value() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                const realm = new ShadowRealm();
+                let result;
+                let error;
+                realm.importValue('tests/assets/file1.js', 'file1')
+                    .then((f) => { result = f(); });
+                realm.importValue('tests/assets/file1.js', 'missing')
+                    .catch((e) => { error = e; });
+            "#}),
+        TestAction::inspect_context(|ctx| ctx.run_jobs().unwrap()),
+        TestAction::assert_eq("result", js_str!("file1..file1_1.file1_2")),
+        TestAction::assert("error instanceof TypeError"),
+        TestAction::assert_native_error(
+            "realm.importValue('tests/assets/file1.js', 1)",
+            JsNativeErrorKind::Type,
+            "ShadowRealm.prototype.importValue: export name must be a string",
+        ),
+    ]);
+}
+
+#[test]
+fn evaluate_checks_host_once() {
+    use crate::{
+        context::{ContextBuilder, HostHooks},
+        realm::Realm,
+        Context, JsResult, JsString, Source,
+    };
+    use std::{cell::Cell, rc::Rc};
+
+    #[derive(Default)]
+    struct Hooks {
+        calls: Cell<usize>,
+    }
+
+    impl HostHooks for Hooks {
+        fn ensure_can_compile_strings(
+            &self,
+            _realm: Realm,
+            _parameters: &[JsString],
+            _body: &JsString,
+            _direct: bool,
+            _context: &mut Context,
+        ) -> JsResult<()> {
+            self.calls.set(self.calls.get() + 1);
+            Ok(())
+        }
+    }
+
+    let hooks = Rc::new(Hooks::default());
+    let context = &mut ContextBuilder::new()
+        .host_hooks(hooks.clone())
+        .build()
+        .unwrap();
+
+    context
+        .eval(Source::from_bytes("new ShadowRealm().evaluate('1 + 2')"))
+        .unwrap();
+    assert_eq!(hooks.calls.get(), 1);
+}
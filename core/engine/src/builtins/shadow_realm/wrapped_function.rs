@@ -0,0 +1,277 @@
+//! This module implements the wrapped function exotic objects used to pass callables between
+//! the realm of a `ShadowRealm` and its caller realm.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrapped-function-exotic-objects
+
+use boa_gc::{Finalize, Trace};
+
+use crate::{
+    builtins::function::set_function_name,
+    js_string,
+    object::{
+        internal_methods::{CallValue, InternalObjectMethods, ORDINARY_INTERNAL_METHODS},
+        JsData, JsObject,
+    },
+    property::PropertyDescriptor,
+    realm::Realm,
+    string::StaticJsStrings,
+    value::IntegerOrInfinity,
+    Context, JsError, JsNativeError, JsResult, JsString, JsValue,
+};
+
+/// A function object that wraps a callable from another realm.
+///
+/// Calling a wrapped function wraps its arguments into the realm of the target function, and
+/// its result back into the realm of the wrapped function. Only primitives and callables can
+/// cross the boundary, which keeps the object graphs of both realms isolated from each other.
+#[derive(Debug, Trace, Finalize)]
+pub(crate) struct WrappedFunction {
+    /// `[[WrappedTargetFunction]]`
+    target: JsObject,
+    /// `[[Realm]]`
+    realm: Realm,
+}
+
+impl JsData for WrappedFunction {
+    fn internal_methods(&self) -> &'static InternalObjectMethods {
+        static METHODS: InternalObjectMethods = InternalObjectMethods {
+            __call__: wrapped_function_exotic_call,
+            ..ORDINARY_INTERNAL_METHODS
+        };
+
+        &METHODS
+    }
+}
+
+impl WrappedFunction {
+    /// Gets the `[[Realm]]` of this wrapped function.
+    pub(crate) const fn realm(&self) -> &Realm {
+        &self.realm
+    }
+
+    /// Abstract operation [`WrappedFunctionCreate ( callerRealm, Target )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrappedfunctioncreate
+    pub(crate) fn create(
+        caller_realm: Realm,
+        target: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let internalSlotsList be the internal slots listed in Table 2, plus [[Prototype]] and [[Extensible]].
+        // 2. Let wrapped be MakeBasicObject(internalSlotsList).
+        // 3. Set wrapped.[[Prototype]] to callerRealm.[[Intrinsics]].[[%Function.prototype%]].
+        // 4. Set wrapped.[[Call]] as described in 2.1.
+        // 5. Set wrapped.[[WrappedTargetFunction]] to Target.
+        // 6. Set wrapped.[[Realm]] to callerRealm.
+        let prototype = caller_realm
+            .intrinsics()
+            .constructors()
+            .function()
+            .prototype();
+        let wrapped = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                target: target.clone(),
+                realm: caller_realm,
+            },
+        );
+
+        // 7. Let result be Completion(CopyNameAndLength(wrapped, Target)).
+        // 8. If result is an abrupt completion, throw a TypeError exception.
+        copy_name_and_length(&wrapped, target, context).map_err(|err| {
+            if !err.is_catchable() {
+                return err;
+            }
+            JsNativeError::typ()
+                .with_message("cannot copy the name and length of the wrapped function")
+                .into()
+        })?;
+
+        // 9. Return wrapped.
+        Ok(wrapped)
+    }
+}
+
+/// Abstract operation [`CopyNameAndLength ( F, Target [ , prefix [ , argCount ] ] )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-copynameandlength
+fn copy_name_and_length(
+    function: &JsObject,
+    target: &JsObject,
+    context: &mut Context,
+) -> JsResult<()> {
+    // 1. If argCount is undefined, then set argCount to 0.
+    // 2. Let L be 0.
+    let mut length = JsValue::new(0);
+
+    // 3. Let targetHasLength be ? HasOwnProperty(Target, "length").
+    // 4. If targetHasLength is true, then
+    if target.has_own_property(StaticJsStrings::LENGTH, context)? {
+        // a. Let targetLen be ? Get(Target, "length").
+        let target_len = target.get(StaticJsStrings::LENGTH, context)?;
+
+        // b. If Type(targetLen) is Number, then
+        if target_len.is_number() {
+            match target_len
+                .to_integer_or_infinity(context)
+                .expect("to_integer_or_infinity cannot fail for a number")
+            {
+                // i. If targetLen is +∞𝔽, set L to +∞.
+                IntegerOrInfinity::PositiveInfinity => length = f64::INFINITY.into(),
+                // ii. Else if targetLen is -∞𝔽, set L to 0.
+                IntegerOrInfinity::NegativeInfinity => {}
+                // iii. Else,
+                IntegerOrInfinity::Integer(target_len) => {
+                    // 1. Let targetLenAsInt be ! ToIntegerOrInfinity(targetLen).
+                    // 2. Assert: targetLenAsInt is finite.
+                    // 3. Set L to max(targetLenAsInt - argCount, 0).
+                    length = target_len.max(0).into();
+                }
+            }
+        }
+    }
+
+    // 5. Perform SetFunctionLength(F, L).
+    function.define_property_or_throw(
+        StaticJsStrings::LENGTH,
+        PropertyDescriptor::builder()
+            .value(length)
+            .writable(false)
+            .enumerable(false)
+            .configurable(true),
+        context,
+    )?;
+
+    // 6. Let targetName be ? Get(Target, "name").
+    let target_name = target.get(js_string!("name"), context)?;
+
+    // 7. If Type(targetName) is not String, set targetName to the empty String.
+    let target_name = target_name
+        .as_string()
+        .map_or_else(JsString::default, Clone::clone);
+
+    // 8. Perform SetFunctionName(F, targetName, prefix).
+    set_function_name(function, &target_name.into(), None, context);
+
+    Ok(())
+}
+
+/// Abstract operation [`GetWrappedValue ( callerRealm, value )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-getwrappedvalue
+pub(crate) fn get_wrapped_value(
+    caller_realm: &Realm,
+    value: JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. If Type(value) is Object, then
+    if let Some(object) = value.as_object() {
+        // a. If IsCallable(value) is false, throw a TypeError exception.
+        if !object.is_callable() {
+            return Err(JsNativeError::typ()
+                .with_message("ShadowRealm: only primitives and callables can cross realms")
+                .into());
+        }
+
+        // b. Return ? WrappedFunctionCreate(callerRealm, value).
+        return WrappedFunction::create(caller_realm.clone(), object, context).map(Into::into);
+    }
+
+    // 2. Return value.
+    Ok(value)
+}
+
+/// Internal method [`[[Call]]`][spec] for Wrapped Function Exotic Objects.
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrapped-function-exotic-objects-call-thisargument-argumentslist
+fn wrapped_function_exotic_call(
+    obj: &JsObject,
+    argument_count: usize,
+    context: &mut Context,
+) -> JsResult<CallValue> {
+    let args = context
+        .vm
+        .stack
+        .calling_convention_pop_arguments(argument_count);
+    let _func = context.vm.stack.pop();
+    let this = context.vm.stack.pop();
+
+    // 1. Let target be F.[[WrappedTargetFunction]].
+    // 2. Assert: IsCallable(target) is true.
+    let (target, mut caller_realm) = {
+        let wrapped = obj.downcast_ref::<WrappedFunction>().expect(
+            "wrapped function exotic method should only be callable from wrapped functions",
+        );
+        (wrapped.target.clone(), wrapped.realm.clone())
+    };
+
+    // 3. Let callerContext be the running execution context.
+    // 4. Let calleeContext be PrepareForWrappedFunctionCall(F).
+    context.swap_realm(&mut caller_realm);
+
+    // 5. Let result be Completion(OrdinaryWrappedFunctionCall(F, thisArgument, argumentsList)).
+    // 7. If result.[[Type]] is normal or result.[[Type]] is return, return result.[[Value]].
+    // 8. Throw a TypeError exception.
+    let result = ordinary_wrapped_function_call(&target, &this, &args, context).map_err(|err| {
+        if !err.is_catchable() {
+            return err;
+        }
+        JsError::from(
+            JsNativeError::typ().with_message("ShadowRealm: the wrapped function threw an error"),
+        )
+        .inject_realm(context.realm().clone())
+    });
+
+    // 6. Remove calleeContext from the execution context stack and restore callerContext as the running execution context.
+    context.swap_realm(&mut caller_realm);
+
+    context.vm.stack.push(result?);
+
+    Ok(CallValue::Complete)
+}
+
+/// Abstract operation [`OrdinaryWrappedFunctionCall ( F, thisArgument, argumentsList )`][spec].
+///
+/// Must be called with the realm of `F` as the current realm.
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-ordinary-wrapped-function-call
+fn ordinary_wrapped_function_call(
+    target: &JsObject,
+    this: &JsValue,
+    args: &[JsValue],
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. Let target be F.[[WrappedTargetFunction]].
+    // 2. Assert: IsCallable(target) is true.
+    // 3. Let callerRealm be F.[[Realm]].
+    // 4. NOTE: Any exception objects produced after this point are associated with callerRealm.
+    let caller_realm = context.realm().clone();
+
+    // 5. Let targetRealm be ? GetFunctionRealm(target).
+    let target_realm = target.get_function_realm(context)?;
+
+    // 6. Let wrappedArgs be a new empty List.
+    // 7. For each element arg of argumentsList, do
+    //     a. Let wrappedValue be ? GetWrappedValue(targetRealm, arg).
+    //     b. Append wrappedValue to wrappedArgs.
+    let wrapped_args = args
+        .iter()
+        .map(|arg| get_wrapped_value(&target_realm, arg.clone(), context))
+        .collect::<JsResult<Vec<_>>>()?;
+
+    // 8. Let wrappedThisArgument to ? GetWrappedValue(targetRealm, thisArgument).
+    let wrapped_this = get_wrapped_value(&target_realm, this.clone(), context)?;
+
+    // 9. Let result be the Completion Record of Call(target, wrappedThisArgument, wrappedArgs).
+    let result = target.call(&wrapped_this, &wrapped_args, context)?;
+
+    // 10. If result.[[Type]] is normal or result.[[Type]] is return, then
+    //     a. Return ? GetWrappedValue(callerRealm, result.[[Value]]).
+    // 11. Else,
+    //     a. Throw a TypeError exception.
+    get_wrapped_value(&caller_realm, result, context)
+}
@@ -684,7 +684,9 @@ impl String {
         for arg in args {
             // a. Let nextString be ? ToString(next).
             // b. Set R to the string-concatenation of R and nextString.
-            string = js_string!(&string, &arg.to_string(context)?);
+            let next = arg.to_string(context)?;
+            context.check_string_allocation(string.len() + next.len())?;
+            string = js_string!(&string, &next);
         }
 
         // 5. Return R.
@@ -724,6 +726,7 @@ impl String {
                     return Ok(js_string!().into());
                 }
                 let n = n as usize;
+                context.check_string_allocation(n * len)?;
                 let mut result = Vec::with_capacity(n);
 
                 std::iter::repeat_n(string.as_str(), n).for_each(|s| result.push(s));
@@ -1237,11 +1240,11 @@ impl String {
                     &JsValue::undefined(),
                     replace_str,
                     context,
-                )
-                .expect("GetSubstitution should never fail here."),
+                )?,
             };
 
             // d. Set result to the string-concatenation of result, preserved, and replacement.
+            context.check_string_allocation(result.len() + preserved.len() + replacement.len())?;
             result.extend(preserved.iter());
             result.extend(replacement.iter());
 
@@ -1554,6 +1557,7 @@ impl String {
         // 8. Let fillLen be intMaxLength - stringLength.
         let fill_len = int_max_length - string_length;
         let filler_len = filler.len() as u64;
+        context.check_string_allocation(usize::try_from(int_max_length).unwrap_or(usize::MAX))?;
 
         // 9. Let truncatedStringFiller be the String value consisting of repeated
         // concatenations of filler truncated to length fillLen.
@@ -2672,11 +2676,13 @@ pub(crate) fn get_substitution(
                 // $&
                 Some(CodePoint::Unicode('&')) => {
                     // matched
+                    context.check_string_allocation(result.len() + match_length)?;
                     result.extend(matched.iter());
                 }
                 // $`
                 Some(CodePoint::Unicode('`')) => {
                     // The replacement is the substring of str from 0 to position.
+                    context.check_string_allocation(result.len() + position)?;
                     result.extend(str.get_expect(..position).iter());
                 }
                 // $'
@@ -2684,6 +2690,7 @@ pub(crate) fn get_substitution(
                     // If tailPos ≥ stringLength, the replacement is the empty String.
                     // Otherwise the replacement is the substring of str from tailPos.
                     if tail_pos < str_length {
+                        context.check_string_allocation(result.len() + str_length - tail_pos)?;
                         result.extend(str.get_expect(tail_pos..).iter());
                     }
                 }
@@ -2731,6 +2738,7 @@ pub(crate) fn get_substitution(
                         //     a. Let refReplacement be capture.
                         if let Some(capture) = captures.get(index - 1) {
                             if let Some(s) = capture.as_string() {
+                                context.check_string_allocation(result.len() + s.len())?;
                                 result.extend(s.iter());
                             }
                         }
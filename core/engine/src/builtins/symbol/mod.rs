@@ -89,6 +89,17 @@ impl GlobalSymbolRegistry {
     }
 }
 
+/// Gets the symbol of the global symbol registry with the given `key`, creating it if it doesn't
+/// exist.
+pub(crate) fn registered_symbol(key: &JsString) -> JsResult<JsSymbol> {
+    GLOBAL_SYMBOL_REGISTRY.get_or_create_symbol(key)
+}
+
+/// Gets the key of `symbol` in the global symbol registry, if it was registered.
+pub(crate) fn registered_key(symbol: &JsSymbol) -> Option<JsString> {
+    GLOBAL_SYMBOL_REGISTRY.get_key(symbol)
+}
+
 /// The internal representation of a `Symbol` object.
 #[derive(Debug, Clone, Copy)]
 pub struct Symbol;
@@ -97,7 +108,9 @@ impl IntrinsicObject for Symbol {
     fn init(realm: &Realm) {
         let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
 
+        let symbol_async_dispose = JsSymbol::async_dispose();
         let symbol_async_iterator = JsSymbol::async_iterator();
+        let symbol_dispose = JsSymbol::dispose();
         let symbol_has_instance = JsSymbol::has_instance();
         let symbol_is_concat_spreadable = JsSymbol::is_concat_spreadable();
         let symbol_iterator = JsSymbol::iterator();
@@ -125,11 +138,13 @@ impl IntrinsicObject for Symbol {
         BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .static_method(Self::for_, js_string!("for"), 1)
             .static_method(Self::key_for, js_string!("keyFor"), 1)
+            .static_property(js_string!("asyncDispose"), symbol_async_dispose, attribute)
             .static_property(
                 js_string!("asyncIterator"),
                 symbol_async_iterator,
                 attribute,
             )
+            .static_property(js_string!("dispose"), symbol_dispose, attribute)
             .static_property(js_string!("hasInstance"), symbol_has_instance, attribute)
             .static_property(
                 js_string!("isConcatSpreadable"),
@@ -187,7 +202,7 @@ impl BuiltInObject for Symbol {
 impl BuiltInConstructor for Symbol {
     const LENGTH: usize = 0;
     const P: usize = 5;
-    const SP: usize = 15;
+    const SP: usize = 17;
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
         StandardConstructors::symbol;
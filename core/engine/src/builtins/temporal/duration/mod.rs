@@ -868,26 +868,55 @@ impl Duration {
         Ok(JsString::from(result).into())
     }
 
-    // TODO: Potentially update docs if localeString is inverted.
-    /// 7.3.24 `Temporal.Duration.prototype.toLocaleString ( )`
+    /// 7.3.24 `Temporal.Duration.prototype.toLocaleString ( [ locales [ , options ] ] )`
     pub(crate) fn to_locale_string(
         this: &JsValue,
-        _: &[JsValue],
-        _: &mut Context,
+        args: &[JsValue],
+        context: &mut Context,
     ) -> JsResult<JsValue> {
-        // TODO: Update for ECMA-402 compliance
-        let duration = this
-            .as_object()
-            .and_then(JsObject::downcast_ref::<Self>)
-            .ok_or_else(|| {
-                JsNativeError::typ().with_message("this value must be a Duration object.")
-            })?;
-
-        let result = duration
-            .inner
-            .as_temporal_string(ToStringRoundingOptions::default())?;
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::duration_format::{format_duration_locale, DurationRecord};
+
+            // 1. Let duration be the this value.
+            // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+            let duration = this
+                .as_object()
+                .and_then(JsObject::downcast_ref::<Self>)
+                .ok_or_else(|| {
+                    JsNativeError::typ().with_message("this value must be a Duration object.")
+                })?
+                .inner;
+
+            let record = DurationRecord::new([
+                duration.years() as f64,
+                duration.months() as f64,
+                duration.weeks() as f64,
+                duration.days() as f64,
+                duration.hours() as f64,
+                duration.minutes() as f64,
+                duration.seconds() as f64,
+                duration.milliseconds() as f64,
+                duration.microseconds() as f64,
+                duration.nanoseconds() as f64,
+            ]);
+
+            format_duration_locale(
+                &record,
+                args.get_or_undefined(0),
+                args.get_or_undefined(1),
+                context,
+            )
+            .map(Into::into)
+        }
 
-        Ok(JsString::from(result).into())
+        // Without `Intl`, the output is implementation-defined, so this returns the same string
+        // as `Temporal.Duration.prototype.toString`.
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = args;
+            Self::to_string(this, &[], context)
+        }
     }
 
     /// 7.3.25 `Temporal.Duration.prototype.valueOf ( )`
@@ -0,0 +1,955 @@
+//! Boa's implementation of the base64 and hex conversion methods of `Uint8Array`.
+//!
+//! More information:
+//!  - [ECMAScript proposal][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8Array/fromBase64
+
+use std::{str::FromStr, sync::atomic::Ordering};
+
+use crate::{
+    builtins::{
+        array_buffer::utils::{memcpy, BytesConstPtr, BytesMutPtr},
+        options::{get_option, get_options_object, OptionType},
+    },
+    js_string,
+    object::JsObject,
+    Context, JsArgs, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{BuiltinTypedArray, TypedArray, TypedArrayKind, Uint8Array};
+
+/// The alphabet used to encode and decode base64 strings.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Base64Alphabet {
+    /// The standard base64 alphabet, using `+` and `/`.
+    #[default]
+    Base64,
+    /// The URL and filename safe base64 alphabet, using `-` and `_`.
+    Base64Url,
+}
+
+impl FromStr for Base64Alphabet {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "base64" => Ok(Self::Base64),
+            "base64url" => Ok(Self::Base64Url),
+            _ => Err("alphabet must be either \"base64\" or \"base64url\""),
+        }
+    }
+}
+
+/// How to handle the final chunk of a base64 string that doesn't have exactly four characters.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LastChunkHandling {
+    /// Decodes partial chunks, with or without padding, ignoring any non-zero padding bits.
+    #[default]
+    Loose,
+    /// Only decodes padded chunks, throwing if the padding bits are not zero.
+    Strict,
+    /// Stops decoding before a partial chunk, leaving it unread.
+    StopBeforePartial,
+}
+
+impl FromStr for LastChunkHandling {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "loose" => Ok(Self::Loose),
+            "strict" => Ok(Self::Strict),
+            "stop-before-partial" => Ok(Self::StopBeforePartial),
+            _ => Err(
+                "lastChunkHandling must be either \"loose\", \"strict\" or \"stop-before-partial\"",
+            ),
+        }
+    }
+}
+
+/// Parses a string option, throwing a `TypeError` if the value is not a string or is not one of
+/// the allowed values.
+fn string_option_from_value<T>(value: &JsValue) -> JsResult<T>
+where
+    T: FromStr<Err = &'static str>,
+{
+    value
+        .as_string()
+        .and_then(|s| s.to_std_string().ok())
+        .ok_or("option must be a string")
+        .and_then(|s| s.parse())
+        .map_err(|err| JsNativeError::typ().with_message(err).into())
+}
+
+impl OptionType for Base64Alphabet {
+    fn from_value(value: JsValue, _: &mut Context) -> JsResult<Self> {
+        string_option_from_value(&value)
+    }
+}
+
+impl OptionType for LastChunkHandling {
+    fn from_value(value: JsValue, _: &mut Context) -> JsResult<Self> {
+        string_option_from_value(&value)
+    }
+}
+
+/// The result of decoding a base64 or hex string.
+#[derive(Debug, Default)]
+struct DecodeResult {
+    /// The number of code units of the string that were read.
+    read: usize,
+    /// The decoded bytes.
+    bytes: Vec<u8>,
+    /// The error found while decoding the string, if any.
+    error: Option<&'static str>,
+}
+
+impl DecodeResult {
+    /// Converts the decoding error of this result into a `SyntaxError`.
+    fn error(&self) -> JsResult<()> {
+        match self.error {
+            Some(error) => Err(JsNativeError::syntax().with_message(error).into()),
+            None => Ok(()),
+        }
+    }
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Encodes `bytes` into a base64 string using `alphabet`.
+fn encode_base64(bytes: &[u8], alphabet: Base64Alphabet, omit_padding: bool) -> String {
+    let table = match alphabet {
+        Base64Alphabet::Base64 => BASE64_ALPHABET,
+        Base64Alphabet::Base64Url => BASE64URL_ALPHABET,
+    };
+
+    let mut output = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let triple = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |acc, (i, &b)| acc | (u32::from(b) << (16 - 8 * i)));
+
+        for i in 0..=chunk.len() {
+            let index = (triple >> (18 - 6 * i)) & 0x3F;
+            output.push(char::from(table[index as usize]));
+        }
+
+        if !omit_padding {
+            for _ in chunk.len()..3 {
+                output.push('=');
+            }
+        }
+    }
+    output
+}
+
+/// Encodes `bytes` into a lowercase hex string.
+fn encode_hex(bytes: &[u8]) -> String {
+    const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    let mut output = String::with_capacity(bytes.len() * 2);
+    for &byte in bytes {
+        output.push(char::from(HEX_DIGITS[usize::from(byte >> 4)]));
+        output.push(char::from(HEX_DIGITS[usize::from(byte & 0xF)]));
+    }
+    output
+}
+
+/// Abstract operation [`SkipAsciiWhitespace ( string, index )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-skipasciiwhitespace
+fn skip_ascii_whitespace(string: &[u16], mut index: usize) -> usize {
+    // 1. Let length be the length of string.
+    // 2. Repeat, while index < length,
+    while let Some(&char) = string.get(index) {
+        // a. Let char be the code unit at index index of string.
+        // b. If char is neither 0x0009 (TAB), 0x000A (LF), 0x000C (FF), 0x000D (CR), nor 0x0020 (SPACE), then
+        if !matches!(char, 0x09 | 0x0A | 0x0C | 0x0D | 0x20) {
+            // i. Return index.
+            return index;
+        }
+        // c. Set index to index + 1.
+        index += 1;
+    }
+    // 3. Return index.
+    index
+}
+
+/// Abstract operation [`DecodeBase64Chunk ( chunk [ , throwOnExtraBits ] )`][spec]
+///
+/// `chunk` contains the six-bit values of the characters of the chunk.
+///
+/// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-decodebase64chunk
+fn decode_base64_chunk(chunk: &[u8], throw_on_extra_bits: bool) -> Result<Vec<u8>, ()> {
+    // 1. Let chunkLength be the length of chunk.
+    // 2. If chunkLength = 2, then
+    //     a. Set chunk to the string-concatenation of chunk and "AA".
+    // 3. Else if chunkLength = 3, then
+    //     a. Set chunk to the string-concatenation of chunk and "A".
+    // 4. Else,
+    //     a. Assert: chunkLength is 4.
+    // 5. Let byteSequence be the unique sequence of 3 bytes resulting from decoding chunk as base64.
+    let triple = chunk.iter().enumerate().fold(0u32, |acc, (i, &sextet)| {
+        acc | (u32::from(sextet) << (18 - 6 * i))
+    });
+    let byte_sequence = [(triple >> 16) as u8, (triple >> 8) as u8, triple as u8];
+
+    // 6. Let bytes be a List whose elements are the elements of byteSequence, in order.
+    // 7. If chunkLength = 2, then
+    //     a. Assert: throwOnExtraBits is present.
+    //     b. If throwOnExtraBits is true and bytes[1] ≠ 0, then
+    //         i. Return ThrowCompletion(a newly created SyntaxError object).
+    //     c. Return « bytes[0] ».
+    // 8. Else if chunkLength = 3, then
+    //     a. Assert: throwOnExtraBits is present.
+    //     b. If throwOnExtraBits is true and bytes[2] ≠ 0, then
+    //         i. Return ThrowCompletion(a newly created SyntaxError object).
+    //     c. Return « bytes[0], bytes[1] ».
+    // 9. Else,
+    //     a. Return bytes.
+    let length = chunk.len() - 1;
+    if throw_on_extra_bits && byte_sequence[length..].iter().any(|&b| b != 0) {
+        return Err(());
+    }
+    Ok(byte_sequence[..length].to_vec())
+}
+
+/// Abstract operation [`FromBase64 ( string, alphabet, lastChunkHandling [ , maxLength ] )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-frombase64
+fn from_base64(
+    string: &[u16],
+    alphabet: Base64Alphabet,
+    last_chunk_handling: LastChunkHandling,
+    max_length: usize,
+) -> DecodeResult {
+    const INVALID_CHARACTER: &str = "invalid character in base64 string";
+    const INVALID_PADDING: &str = "invalid padding in base64 string";
+    const INCOMPLETE_CHUNK: &str = "base64 string ends with an incomplete chunk";
+    const NON_ZERO_PADDING_BITS: &str = "base64 string has non-zero padding bits";
+
+    // 1. If maxLength is not present, then
+    //     a. Let maxLength be 2**53 - 1.
+    //     b. NOTE: Because the input is a string, the length of strings is limited to 2**53 - 1 characters, and the output requires no more bytes than the input has characters, this limit can never be reached. However, it is editorially convenient to use a finite value here.
+    // 2. NOTE: The order of validation and decoding in the algorithm below is not observable. Implementations are encouraged to perform them in whatever order is most efficient, possibly interleaving validation with decoding, as long as the behaviour is observably equivalent.
+    let mut result = DecodeResult::default();
+
+    // 3. If maxLength = 0, then
+    if max_length == 0 {
+        // a. Return the Record { [[Read]]: 0, [[Bytes]]: « », [[Error]]: none }.
+        return result;
+    }
+
+    // 4. Let read be 0.
+    // 5. Let bytes be « ».
+    // 6. Let chunk be the empty String.
+    // 7. Let chunkLength be 0.
+    let mut chunk = Vec::with_capacity(4);
+
+    // 8. Let index be 0.
+    let mut index = 0;
+
+    // 9. Let length be the length of string.
+    let length = string.len();
+
+    // 10. Repeat,
+    loop {
+        // a. Set index to SkipAsciiWhitespace(string, index).
+        index = skip_ascii_whitespace(string, index);
+
+        // b. If index = length, then
+        let Some(&char) = string.get(index) else {
+            // i. If chunkLength > 0, then
+            if !chunk.is_empty() {
+                match last_chunk_handling {
+                    // 1. If lastChunkHandling is "stop-before-partial", then
+                    LastChunkHandling::StopBeforePartial => {
+                        // a. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+                        return result;
+                    }
+                    // 2. Else if lastChunkHandling is "loose", then
+                    LastChunkHandling::Loose => {
+                        // a. If chunkLength = 1, then
+                        if chunk.len() == 1 {
+                            // i. Let error be a new SyntaxError exception.
+                            // ii. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                            result.error = Some(INCOMPLETE_CHUNK);
+                            return result;
+                        }
+                        // b. Set bytes to the list-concatenation of bytes and ! DecodeBase64Chunk(chunk, false).
+                        result.bytes.extend(
+                            decode_base64_chunk(&chunk, false)
+                                .expect("cannot fail without checking the extra bits"),
+                        );
+                    }
+                    // 3. Else,
+                    LastChunkHandling::Strict => {
+                        // a. Assert: lastChunkHandling is "strict".
+                        // b. Let error be a new SyntaxError exception.
+                        // c. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                        result.error = Some(INCOMPLETE_CHUNK);
+                        return result;
+                    }
+                }
+            }
+
+            // ii. Return the Record { [[Read]]: length, [[Bytes]]: bytes, [[Error]]: none }.
+            result.read = length;
+            return result;
+        };
+
+        // c. Let char be the substring of string from index to index + 1.
+        // d. Set index to index + 1.
+        index += 1;
+
+        // e. If char is "=", then
+        if char == u16::from(b'=') {
+            // i. If chunkLength < 2, then
+            if chunk.len() < 2 {
+                // 1. Let error be a new SyntaxError exception.
+                // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                result.error = Some(INVALID_PADDING);
+                return result;
+            }
+
+            // ii. Set index to SkipAsciiWhitespace(string, index).
+            index = skip_ascii_whitespace(string, index);
+
+            // iii. If chunkLength = 2, then
+            if chunk.len() == 2 {
+                // 1. If index = length, then
+                let Some(&char) = string.get(index) else {
+                    // a. If lastChunkHandling is "stop-before-partial", then
+                    if last_chunk_handling == LastChunkHandling::StopBeforePartial {
+                        // i. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+                        return result;
+                    }
+                    // b. Let error be a new SyntaxError exception.
+                    // c. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                    result.error = Some(INCOMPLETE_CHUNK);
+                    return result;
+                };
+
+                // 2. Set char to the substring of string from index to index + 1.
+                // 3. If char is "=", then
+                if char == u16::from(b'=') {
+                    // a. Set index to SkipAsciiWhitespace(string, index + 1).
+                    index = skip_ascii_whitespace(string, index + 1);
+                }
+            }
+
+            // iv. If index < length, then
+            if index < length {
+                // 1. Let error be a new SyntaxError exception.
+                // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                result.error = Some(INVALID_PADDING);
+                return result;
+            }
+
+            // v. If lastChunkHandling is "strict", let throwOnExtraBits be true.
+            // vi. Else, let throwOnExtraBits be false.
+            let throw_on_extra_bits = last_chunk_handling == LastChunkHandling::Strict;
+
+            // vii. Let decodeResult be Completion(DecodeBase64Chunk(chunk, throwOnExtraBits)).
+            // viii. If decodeResult is an abrupt completion, then
+            //     1. Let error be decodeResult.[[Value]].
+            //     2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+            let Ok(decoded) = decode_base64_chunk(&chunk, throw_on_extra_bits) else {
+                result.error = Some(NON_ZERO_PADDING_BITS);
+                return result;
+            };
+
+            // ix. Set bytes to the list-concatenation of bytes and ! decodeResult.
+            result.bytes.extend(decoded);
+
+            // x. Return the Record { [[Read]]: length, [[Bytes]]: bytes, [[Error]]: none }.
+            result.read = length;
+            return result;
+        }
+
+        // f. If alphabet is "base64url", then
+        //     i. If char is either "+" or "/", then
+        //         1. Let error be a new SyntaxError exception.
+        //         2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+        //     ii. Else if char is "-", then
+        //         1. Set char to "+".
+        //     iii. Else if char is "_", then
+        //         1. Set char to "/".
+        // g. If the sole code unit of char is not an element of the standard base64 alphabet, then
+        //     i. Let error be a new SyntaxError exception.
+        //     ii. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+        let table = match alphabet {
+            Base64Alphabet::Base64 => BASE64_ALPHABET,
+            Base64Alphabet::Base64Url => BASE64URL_ALPHABET,
+        };
+        let Some(sextet) = u8::try_from(char)
+            .ok()
+            .and_then(|char| table.iter().position(|&c| c == char))
+        else {
+            result.error = Some(INVALID_CHARACTER);
+            return result;
+        };
+
+        // h. Let remaining be maxLength - the length of bytes.
+        let remaining = max_length - result.bytes.len();
+
+        // i. If remaining = 1 and chunkLength = 2, or if remaining = 2 and chunkLength = 3, then
+        if (remaining == 1 && chunk.len() == 2) || (remaining == 2 && chunk.len() == 3) {
+            // i. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+            return result;
+        }
+
+        // j. Set chunk to the string-concatenation of chunk and char.
+        // k. Set chunkLength to the length of chunk.
+        chunk.push(sextet as u8);
+
+        // l. If chunkLength = 4, then
+        if chunk.len() == 4 {
+            // i. Set bytes to the list-concatenation of bytes and ! DecodeBase64Chunk(chunk).
+            result.bytes.extend(
+                decode_base64_chunk(&chunk, false).expect("full chunks cannot have extra bits"),
+            );
+
+            // ii. Set chunk to the empty String.
+            // iii. Set chunkLength to 0.
+            chunk.clear();
+
+            // iv. Set read to index.
+            result.read = index;
+
+            // v. If the length of bytes = maxLength, then
+            if result.bytes.len() == max_length {
+                // 1. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+                return result;
+            }
+        }
+    }
+}
+
+/// Abstract operation [`FromHex ( string [ , maxLength ] )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-fromhex
+fn from_hex(string: &[u16], max_length: usize) -> DecodeResult {
+    fn hex_digit(code_unit: u16) -> Option<u8> {
+        char::from_u32(u32::from(code_unit))
+            .and_then(|c| c.to_digit(16))
+            .map(|digit| digit as u8)
+    }
+
+    // 1. If maxLength is not present, let maxLength be 2**53 - 1.
+    // 2. Let length be the length of string.
+    // 3. Let bytes be « ».
+    // 4. Let read be 0.
+    let mut result = DecodeResult::default();
+
+    // 5. If length modulo 2 is not 0, then
+    if !string.len().is_multiple_of(2) {
+        // a. Let error be a new SyntaxError exception.
+        // b. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+        result.error = Some("hex string must have an even length");
+        return result;
+    }
+
+    // 6. Repeat, while read < length and the length of bytes < maxLength,
+    for pair in string.chunks_exact(2).take(max_length) {
+        // a. Let hexits be the substring of string from read to read + 2.
+        // b. If hexits contains any code units which are not in "0123456789abcdefABCDEF", then
+        let (Some(high), Some(low)) = (hex_digit(pair[0]), hex_digit(pair[1])) else {
+            // i. Let error be a new SyntaxError exception.
+            // ii. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+            result.error = Some("invalid character in hex string");
+            return result;
+        };
+
+        // c. Set read to read + 2.
+        result.read += 2;
+
+        // d. Let byte be the integer value represented by hexits in base-16 notation, using the letters A-F and a-f for the digits with values 10 through 15.
+        // e. Append byte to bytes.
+        result.bytes.push((high << 4) | low);
+    }
+
+    // 7. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+    result
+}
+
+/// Abstract operation [`ValidateUint8Array ( ta )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-validateuint8array
+fn validate_uint8_array(ta: &JsValue) -> JsResult<JsObject<TypedArray>> {
+    // 1. Perform ? RequireInternalSlot(ta, [[TypedArrayName]]).
+    // 2. If ta.[[TypedArrayName]] is not "Uint8Array", throw a TypeError exception.
+    ta.as_object()
+        .and_then(|o| o.clone().downcast::<TypedArray>().ok())
+        .filter(|ta| ta.borrow().data.kind() == TypedArrayKind::Uint8)
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`this` is not a Uint8Array object")
+                .into()
+        })
+}
+
+/// Abstract operation [`GetUint8ArrayBytes ( ta )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-getuint8arraybytes
+fn get_uint8_array_bytes(ta: &JsObject<TypedArray>) -> JsResult<Vec<u8>> {
+    let ta = ta.borrow();
+    let ta = &ta.data;
+
+    // 1. Let buffer be ta.[[ViewedArrayBuffer]].
+    // 2. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(ta, seq-cst).
+    // 3. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+    let buffer = ta.viewed_array_buffer().as_buffer();
+    let Some(buffer) = buffer
+        .bytes(Ordering::SeqCst)
+        .filter(|buf| !ta.is_out_of_bounds(buf.len()))
+    else {
+        return Err(JsNativeError::typ()
+            .with_message("typed array is outside the bounds of its inner buffer")
+            .into());
+    };
+
+    // 4. Let len be TypedArrayLength(taRecord).
+    let len = ta.array_length(buffer.len()) as usize;
+
+    // 5. Let byteOffset be ta.[[ByteOffset]].
+    let byte_offset = ta.byte_offset() as usize;
+
+    // 6. Let bytes be a new empty List.
+    // 7. Let index be 0.
+    // 8. Repeat, while index < len,
+    //     a. Let byteIndex be byteOffset + index.
+    //     b. Let byte be ℝ(GetValueFromBuffer(buffer, byteIndex, uint8, true, unordered)).
+    //     c. Append byte to bytes.
+    //     d. Set index to index + 1.
+    let mut bytes = vec![0; len];
+    let src = buffer.subslice(byte_offset..byte_offset + len);
+
+    // SAFETY: `src` and `bytes` are both `len` bytes long.
+    unsafe {
+        memcpy(src.as_ptr(), BytesMutPtr::Bytes(bytes.as_mut_ptr()), len);
+    }
+
+    // 9. Return bytes.
+    Ok(bytes)
+}
+
+/// Abstract operation [`SetUint8ArrayBytes ( into, bytes )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-setuint8arraybytes
+fn set_uint8_array_bytes(into: &JsObject<TypedArray>, bytes: &[u8]) {
+    let into = into.borrow();
+    let into = &into.data;
+
+    // 1. Let offset be into.[[ByteOffset]].
+    let offset = into.byte_offset() as usize;
+
+    // 2. Let len be the length of bytes.
+    // 3. Let index be 0.
+    // 4. Repeat, while index < len,
+    //     a. Let byte be bytes[index].
+    //     b. Let byteIndexInBuffer be index + offset.
+    //     c. Perform SetValueInBuffer(into.[[ViewedArrayBuffer]], byteIndexInBuffer, uint8, 𝔽(byte), true, unordered).
+    //     d. Set index to index + 1.
+    let mut buffer = into.viewed_array_buffer().as_buffer_mut();
+    let mut buffer = buffer
+        .bytes(Ordering::SeqCst)
+        .expect("the buffer cannot be detached while decoding");
+    let mut target = buffer.subslice_mut(offset..offset + bytes.len());
+
+    // SAFETY: `bytes` and `target` are both `bytes.len()` bytes long.
+    unsafe {
+        memcpy(
+            BytesConstPtr::Bytes(bytes.as_ptr()),
+            target.as_ptr(),
+            bytes.len(),
+        );
+    }
+}
+
+/// Allocates a new `Uint8Array` with the contents of `bytes`.
+fn allocate_uint8_array(bytes: &[u8], context: &mut Context) -> JsResult<JsObject> {
+    let constructor = context
+        .intrinsics()
+        .constructors()
+        .typed_uint8_array()
+        .constructor();
+
+    // 1. Let resultLength be the length of result.[[Bytes]].
+    // 2. Let ta be ? AllocateTypedArray("Uint8Array", %Uint8Array%, %Uint8Array.prototype%, resultLength).
+    let ta = BuiltinTypedArray::allocate::<Uint8Array>(
+        &constructor.into(),
+        bytes.len() as u64,
+        context,
+    )?;
+
+    // 3. Set the value at each index of ta.[[ViewedArrayBuffer]].[[ArrayBufferData]] to the value at the corresponding index of result.[[Bytes]].
+    let typed = ta
+        .clone()
+        .downcast::<TypedArray>()
+        .expect("must be a typed array");
+    set_uint8_array_bytes(&typed, bytes);
+
+    // 4. Return ta.
+    Ok(ta)
+}
+
+/// Encodes the contents of the `Uint8Array` `ta` into a base64 string.
+pub(crate) fn to_base64(
+    ta: &JsObject<TypedArray>,
+    alphabet: Base64Alphabet,
+    omit_padding: bool,
+) -> JsResult<JsString> {
+    let bytes = get_uint8_array_bytes(ta)?;
+    Ok(JsString::from(encode_base64(
+        &bytes,
+        alphabet,
+        omit_padding,
+    )))
+}
+
+/// Encodes the contents of the `Uint8Array` `ta` into a hex string.
+pub(crate) fn to_hex(ta: &JsObject<TypedArray>) -> JsResult<JsString> {
+    let bytes = get_uint8_array_bytes(ta)?;
+    Ok(JsString::from(encode_hex(&bytes)))
+}
+
+/// Decodes the base64 `string` into a new `Uint8Array`.
+pub(crate) fn decode_base64(
+    string: &JsString,
+    alphabet: Base64Alphabet,
+    last_chunk_handling: LastChunkHandling,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    let string = string.iter().collect::<Vec<_>>();
+    let result = from_base64(&string, alphabet, last_chunk_handling, usize::MAX);
+    result.error()?;
+    allocate_uint8_array(&result.bytes, context)
+}
+
+/// Decodes the hex `string` into a new `Uint8Array`.
+pub(crate) fn decode_hex(string: &JsString, context: &mut Context) -> JsResult<JsObject> {
+    let string = string.iter().collect::<Vec<_>>();
+    let result = from_hex(&string, usize::MAX);
+    result.error()?;
+    allocate_uint8_array(&result.bytes, context)
+}
+
+/// Decodes the base64 `string` into the `Uint8Array` `into`, returning the number of code units
+/// read and the number of bytes written.
+///
+/// The bytes decoded before finding an error are written even if the decoding fails.
+pub(crate) fn decode_base64_into(
+    into: &JsObject<TypedArray>,
+    string: &JsString,
+    alphabet: Base64Alphabet,
+    last_chunk_handling: LastChunkHandling,
+) -> JsResult<(usize, usize)> {
+    let byte_length = uint8_array_length(into)?;
+    let string = string.iter().collect::<Vec<_>>();
+    let result = from_base64(&string, alphabet, last_chunk_handling, byte_length);
+    write_decode_result(into, &result)
+}
+
+/// Decodes the hex `string` into the `Uint8Array` `into`, returning the number of code units read
+/// and the number of bytes written.
+///
+/// The bytes decoded before finding an error are written even if the decoding fails.
+pub(crate) fn decode_hex_into(
+    into: &JsObject<TypedArray>,
+    string: &JsString,
+) -> JsResult<(usize, usize)> {
+    let byte_length = uint8_array_length(into)?;
+    let string = string.iter().collect::<Vec<_>>();
+    let result = from_hex(&string, byte_length);
+    write_decode_result(into, &result)
+}
+
+/// Gets the length of the `Uint8Array` `ta`, throwing if it is out of bounds.
+fn uint8_array_length(ta: &JsObject<TypedArray>) -> JsResult<usize> {
+    let ta = ta.borrow();
+    let ta = &ta.data;
+
+    // 1. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(into, seq-cst).
+    // 2. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+    let Some(buf_len) = ta
+        .viewed_array_buffer()
+        .as_buffer()
+        .bytes(Ordering::SeqCst)
+        .filter(|buf| !ta.is_out_of_bounds(buf.len()))
+        .map(|buf| buf.len())
+    else {
+        return Err(JsNativeError::typ()
+            .with_message("typed array is outside the bounds of its inner buffer")
+            .into());
+    };
+
+    // 3. Let byteLength be TypedArrayLength(taRecord).
+    Ok(ta.array_length(buf_len) as usize)
+}
+
+/// Writes the bytes of `result` into `into`, then throws the decoding error of `result` if any.
+fn write_decode_result(
+    into: &JsObject<TypedArray>,
+    result: &DecodeResult,
+) -> JsResult<(usize, usize)> {
+    // 1. Let bytes be result.[[Bytes]].
+    // 2. Let written be the length of bytes.
+    // 3. NOTE: FromBase64 and FromHex do not invoke any user code, so the ArrayBuffer backing into cannot have been detached or shrunk.
+    // 4. Assert: written ≤ byteLength.
+    // 5. Perform SetUint8ArrayBytes(into, bytes).
+    set_uint8_array_bytes(into, &result.bytes);
+
+    // 6. If result.[[Error]] is not none, then
+    //     a. Throw result.[[Error]].
+    result.error()?;
+
+    Ok((result.read, result.bytes.len()))
+}
+
+/// Creates the `{ read, written }` object returned by the `setFrom*` methods.
+fn read_written_object(read: usize, written: usize, context: &mut Context) -> JsValue {
+    // 1. Let resultObject be OrdinaryObjectCreate(%Object.prototype%).
+    let result = JsObject::with_object_proto(context.intrinsics());
+
+    // 2. Perform ! CreateDataPropertyOrThrow(resultObject, "read", 𝔽(read)).
+    result
+        .create_data_property_or_throw(js_string!("read"), read, context)
+        .expect("cannot fail for a new object");
+
+    // 3. Perform ! CreateDataPropertyOrThrow(resultObject, "written", 𝔽(written)).
+    result
+        .create_data_property_or_throw(js_string!("written"), written, context)
+        .expect("cannot fail for a new object");
+
+    // 4. Return resultObject.
+    result.into()
+}
+
+/// Gets the string argument of the decoding methods, throwing a `TypeError` if it is not a string.
+fn string_argument(value: &JsValue) -> JsResult<JsString> {
+    value.as_string().cloned().ok_or_else(|| {
+        JsNativeError::typ()
+            .with_message("the input to decode must be a string")
+            .into()
+    })
+}
+
+impl Uint8Array {
+    /// `Uint8Array.fromBase64 ( string [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.frombase64
+    pub(crate) fn from_base64(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If string is not a String, throw a TypeError exception.
+        let string = string_argument(args.get_or_undefined(0))?;
+
+        // 2. Let opts be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 3. Let alphabet be ? Get(opts, "alphabet").
+        // 4. If alphabet is undefined, set alphabet to "base64".
+        // 5. If alphabet is neither "base64" nor "base64url", throw a TypeError exception.
+        let alphabet = get_option(&options, js_string!("alphabet"), context)?.unwrap_or_default();
+
+        // 6. Let lastChunkHandling be ? Get(opts, "lastChunkHandling").
+        // 7. If lastChunkHandling is undefined, set lastChunkHandling to "loose".
+        // 8. If lastChunkHandling is not one of "loose", "strict", or "stop-before-partial", throw a TypeError exception.
+        let last_chunk_handling =
+            get_option(&options, js_string!("lastChunkHandling"), context)?.unwrap_or_default();
+
+        // 9. Let result be FromBase64(string, alphabet, lastChunkHandling).
+        // 10. If result.[[Error]] is not none, then
+        //     a. Throw result.[[Error]].
+        // 11. Let resultLength be the length of result.[[Bytes]].
+        // 12. Let ta be ? AllocateTypedArray("Uint8Array", %Uint8Array%, %Uint8Array.prototype%, resultLength).
+        // 13. Set the value at each index of ta.[[ViewedArrayBuffer]].[[ArrayBufferData]] to the value at the corresponding index of result.[[Bytes]].
+        // 14. Return ta.
+        decode_base64(&string, alphabet, last_chunk_handling, context).map(JsValue::from)
+    }
+
+    /// `Uint8Array.fromHex ( string )`
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.fromhex
+    pub(crate) fn from_hex(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If string is not a String, throw a TypeError exception.
+        let string = string_argument(args.get_or_undefined(0))?;
+
+        // 2. Let result be FromHex(string).
+        // 3. If result.[[Error]] is not none, then
+        //     a. Throw result.[[Error]].
+        // 4. Let resultLength be the length of result.[[Bytes]].
+        // 5. Let ta be ? AllocateTypedArray("Uint8Array", %Uint8Array%, %Uint8Array.prototype%, resultLength).
+        // 6. Set the value at each index of ta.[[ViewedArrayBuffer]].[[ArrayBufferData]] to the value at the corresponding index of result.[[Bytes]].
+        // 7. Return ta.
+        decode_hex(&string, context).map(JsValue::from)
+    }
+
+    /// `Uint8Array.prototype.setFromBase64 ( string [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.setfrombase64
+    pub(crate) fn set_from_base64(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let into be the this value.
+        // 2. Perform ? ValidateUint8Array(into).
+        let into = validate_uint8_array(this)?;
+
+        // 3. If string is not a String, throw a TypeError exception.
+        let string = string_argument(args.get_or_undefined(0))?;
+
+        // 4. Let opts be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 5. Let alphabet be ? Get(opts, "alphabet").
+        // 6. If alphabet is undefined, set alphabet to "base64".
+        // 7. If alphabet is neither "base64" nor "base64url", throw a TypeError exception.
+        let alphabet = get_option(&options, js_string!("alphabet"), context)?.unwrap_or_default();
+
+        // 8. Let lastChunkHandling be ? Get(opts, "lastChunkHandling").
+        // 9. If lastChunkHandling is undefined, set lastChunkHandling to "loose".
+        // 10. If lastChunkHandling is not one of "loose", "strict", or "stop-before-partial", throw a TypeError exception.
+        let last_chunk_handling =
+            get_option(&options, js_string!("lastChunkHandling"), context)?.unwrap_or_default();
+
+        // 11. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(into, seq-cst).
+        // 12. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+        // 13. Let byteLength be TypedArrayLength(taRecord).
+        // 14. Let result be FromBase64(string, alphabet, lastChunkHandling, byteLength).
+        // 15. Let bytes be result.[[Bytes]].
+        // 16. Let written be the length of bytes.
+        // 17. NOTE: FromBase64 does not invoke any user code, so the ArrayBuffer backing into cannot have been detached or shrunk.
+        // 18. Assert: written ≤ byteLength.
+        // 19. Perform SetUint8ArrayBytes(into, bytes).
+        // 20. If result.[[Error]] is not none, then
+        //     a. Throw result.[[Error]].
+        let (read, written) = decode_base64_into(&into, &string, alphabet, last_chunk_handling)?;
+
+        // 21. Let resultObject be OrdinaryObjectCreate(%Object.prototype%).
+        // 22. Perform ! CreateDataPropertyOrThrow(resultObject, "read", 𝔽(result.[[Read]])).
+        // 23. Perform ! CreateDataPropertyOrThrow(resultObject, "written", 𝔽(written)).
+        // 24. Return resultObject.
+        Ok(read_written_object(read, written, context))
+    }
+
+    /// `Uint8Array.prototype.setFromHex ( string )`
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.setfromhex
+    pub(crate) fn set_from_hex(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let into be the this value.
+        // 2. Perform ? ValidateUint8Array(into).
+        let into = validate_uint8_array(this)?;
+
+        // 3. If string is not a String, throw a TypeError exception.
+        let string = string_argument(args.get_or_undefined(0))?;
+
+        // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(into, seq-cst).
+        // 5. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+        // 6. Let byteLength be TypedArrayLength(taRecord).
+        // 7. Let result be FromHex(string, byteLength).
+        // 8. Let bytes be result.[[Bytes]].
+        // 9. Let written be the length of bytes.
+        // 10. NOTE: FromHex does not invoke any user code, so the ArrayBuffer backing into cannot have been detached or shrunk.
+        // 11. Assert: written ≤ byteLength.
+        // 12. Perform SetUint8ArrayBytes(into, bytes).
+        // 13. If result.[[Error]] is not none, then
+        //     a. Throw result.[[Error]].
+        let (read, written) = decode_hex_into(&into, &string)?;
+
+        // 14. Let resultObject be OrdinaryObjectCreate(%Object.prototype%).
+        // 15. Perform ! CreateDataPropertyOrThrow(resultObject, "read", 𝔽(result.[[Read]])).
+        // 16. Perform ! CreateDataPropertyOrThrow(resultObject, "written", 𝔽(written)).
+        // 17. Return resultObject.
+        Ok(read_written_object(read, written, context))
+    }
+
+    /// `Uint8Array.prototype.toBase64 ( [ options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.tobase64
+    pub(crate) fn to_base64(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateUint8Array(O).
+        let o = validate_uint8_array(this)?;
+
+        // 3. Let opts be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(0))?;
+
+        // 4. Let alphabet be ? Get(opts, "alphabet").
+        // 5. If alphabet is undefined, set alphabet to "base64".
+        // 6. If alphabet is neither "base64" nor "base64url", throw a TypeError exception.
+        let alphabet = get_option(&options, js_string!("alphabet"), context)?.unwrap_or_default();
+
+        // 7. Let omitPadding be ToBoolean(? Get(opts, "omitPadding")).
+        let omit_padding =
+            get_option(&options, js_string!("omitPadding"), context)?.unwrap_or(false);
+
+        // 8. Let toEncode be ? GetUint8ArrayBytes(O).
+        // 9. If alphabet is "base64", then
+        //     a. Let outAscii be the sequence of code points which results from encoding toEncode according to the base64 encoding specified in section 4 of RFC 4648. Padding is included if and only if omitPadding is false.
+        // 10. Else,
+        //     a. Assert: alphabet is "base64url".
+        //     b. Let outAscii be the sequence of code points which results from encoding toEncode according to the base64url encoding specified in section 5 of RFC 4648. Padding is included if and only if omitPadding is false.
+        // 11. Return CodePointsToString(outAscii).
+        to_base64(&o, alphabet, omit_padding).map(JsValue::from)
+    }
+
+    /// `Uint8Array.prototype.toHex ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.tohex
+    pub(crate) fn to_hex(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateUint8Array(O).
+        let o = validate_uint8_array(this)?;
+
+        // 3. Let toEncode be ? GetUint8ArrayBytes(O).
+        // 4. Let out be the empty String.
+        // 5. For each byte byte of toEncode, do
+        //     a. Let hex be Number::toString(𝔽(byte), 16).
+        //     b. Set hex to StringPad(hex, 2, "0", start).
+        //     c. Set out to the string-concatenation of out and hex.
+        // 6. Return out.
+        to_hex(&o).map(JsValue::from)
+    }
+}
@@ -10,6 +10,7 @@ use std::sync::atomic::Ordering;
 use std::{convert::identity, ops::BitAnd};
 
 use bytemuck::{AnyBitPattern, NoUninit};
+use float16::f16;
 use num_traits::{WrappingAdd, WrappingSub};
 use portable_atomic::{
     AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicU16, AtomicU32, AtomicU64, AtomicU8,
@@ -186,6 +187,39 @@ impl From<ClampedU8> for Numeric {
     }
 }
 
+/// A half-precision float, stored as its raw bits to be able to read it from array buffers.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, AnyBitPattern, NoUninit)]
+#[repr(transparent)]
+pub(crate) struct Float16(pub(crate) u16);
+
+impl Float16 {
+    /// Converts `value` to the nearest half-precision float, rounding ties to even.
+    pub(crate) fn from_f64(value: f64) -> Self {
+        Self(f16::from_f64(value).to_bits())
+    }
+
+    /// Converts this `Float16` to a double-precision float.
+    pub(crate) fn to_f64(self) -> f64 {
+        f16::from_bits(self.0).to_f64()
+    }
+
+    /// Converts this `Float16` to its big endian representation.
+    pub(crate) fn to_be(self) -> Self {
+        Self(self.0.to_be())
+    }
+
+    /// Converts this `Float16` to its little endian representation.
+    pub(crate) fn to_le(self) -> Self {
+        Self(self.0.to_le())
+    }
+}
+
+impl From<Float16> for Numeric {
+    fn from(value: Float16) -> Self {
+        Numeric::Number(value.to_f64())
+    }
+}
+
 /// A native element that can be inside a `TypedArray`.
 pub(crate) trait Element:
     Sized + Into<TypedArrayElement> + NoUninit + AnyBitPattern
@@ -331,6 +365,16 @@ element!(
     to_le: |this: ClampedU8| this.to_le(),
 );
 
+element!(
+    Float16,
+    AtomicU16,
+    from_js: |value: &JsValue, context| value.to_number(context).map(Float16::from_f64),
+    from_plain: Float16,
+    to_plain: |f: Float16| f.0,
+    to_be: |this: Float16| this.to_be(),
+    to_le: |this: Float16| this.to_le(),
+);
+
 element!(
     f32,
     AtomicU32,
@@ -28,12 +28,14 @@ use crate::{
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
 
+pub(crate) mod base64;
 mod builtin;
 mod element;
 mod object;
 
+pub use base64::{Base64Alphabet, LastChunkHandling};
 pub(crate) use builtin::{is_valid_integer_index, BuiltinTypedArray};
-pub(crate) use element::{Atomic, ClampedU8, Element};
+pub(crate) use element::{Atomic, ClampedU8, Element, Float16};
 pub use object::TypedArray;
 
 pub(crate) trait TypedArrayMarker {
@@ -53,7 +55,7 @@ impl<T: TypedArrayMarker> IntrinsicObject for T {
             .name(js_string!("get [Symbol.species]"))
             .build();
 
-        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .prototype(
                 realm
                     .intrinsics()
@@ -79,8 +81,21 @@ impl<T: TypedArrayMarker> IntrinsicObject for T {
                 js_string!("BYTES_PER_ELEMENT"),
                 size_of::<T::Element>(),
                 Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
-            )
-            .build();
+            );
+
+        // `Uint8Array` is the only typed array with base64 and hex conversion methods.
+        if T::ERASED == TypedArrayKind::Uint8 {
+            builder
+                .static_method(Uint8Array::from_base64, js_string!("fromBase64"), 1)
+                .static_method(Uint8Array::from_hex, js_string!("fromHex"), 1)
+                .method(Uint8Array::set_from_base64, js_string!("setFromBase64"), 1)
+                .method(Uint8Array::set_from_hex, js_string!("setFromHex"), 1)
+                .method(Uint8Array::to_base64, js_string!("toBase64"), 0)
+                .method(Uint8Array::to_hex, js_string!("toHex"), 0)
+                .build();
+        } else {
+            builder.build();
+        }
     }
 }
 
@@ -93,8 +108,16 @@ impl<T: TypedArrayMarker> BuiltInObject for T {
 
 impl<T: TypedArrayMarker> BuiltInConstructor for T {
     const LENGTH: usize = 3;
-    const P: usize = 1;
-    const SP: usize = 2;
+    const P: usize = if matches!(<Self as TypedArrayMarker>::ERASED, TypedArrayKind::Uint8) {
+        5
+    } else {
+        1
+    };
+    const SP: usize = if matches!(<Self as TypedArrayMarker>::ERASED, TypedArrayKind::Uint8) {
+        4
+    } else {
+        2
+    };
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
         <Self as TypedArrayMarker>::ERASED.standard_constructor();
@@ -311,6 +334,16 @@ impl TypedArrayMarker for BigUint64Array {
     const ERASED: TypedArrayKind = TypedArrayKind::BigUint64;
 }
 
+/// JavaScript `Float16Array` built-in implementation.
+#[derive(Debug, Copy, Clone)]
+pub struct Float16Array;
+
+impl TypedArrayMarker for Float16Array {
+    type Element = Float16;
+
+    const ERASED: TypedArrayKind = TypedArrayKind::Float16;
+}
+
 /// JavaScript `Float32Array` built-in implementation.
 #[derive(Debug, Copy, Clone)]
 pub struct Float32Array;
@@ -351,6 +384,7 @@ pub(crate) enum TypedArrayKind {
     Uint32,
     BigInt64,
     BigUint64,
+    Float16,
     Float32,
     Float64,
 }
@@ -368,6 +402,7 @@ impl TypedArrayKind {
             TypedArrayKind::Uint32 => StaticJsStrings::UINT32_ARRAY,
             TypedArrayKind::BigInt64 => StaticJsStrings::BIG_INT64_ARRAY,
             TypedArrayKind::BigUint64 => StaticJsStrings::BIG_UINT64_ARRAY,
+            TypedArrayKind::Float16 => StaticJsStrings::FLOAT16_ARRAY,
             TypedArrayKind::Float32 => StaticJsStrings::FLOAT32_ARRAY,
             TypedArrayKind::Float64 => StaticJsStrings::FLOAT64_ARRAY,
         }
@@ -385,6 +420,7 @@ impl TypedArrayKind {
             TypedArrayKind::Uint32 => "Uint32",
             TypedArrayKind::BigInt64 => "BigInt64",
             TypedArrayKind::BigUint64 => "BigUint64",
+            TypedArrayKind::Float16 => "Float16",
             TypedArrayKind::Float32 => "Float32",
             TypedArrayKind::Float64 => "Float64",
         }
@@ -404,6 +440,7 @@ impl TypedArrayKind {
             TypedArrayKind::Uint32 => StandardConstructors::typed_uint32_array,
             TypedArrayKind::BigInt64 => StandardConstructors::typed_bigint64_array,
             TypedArrayKind::BigUint64 => StandardConstructors::typed_biguint64_array,
+            TypedArrayKind::Float16 => StandardConstructors::typed_float16_array,
             TypedArrayKind::Float32 => StandardConstructors::typed_float32_array,
             TypedArrayKind::Float64 => StandardConstructors::typed_float64_array,
         }
@@ -426,9 +463,10 @@ impl TypedArrayKind {
             // could require polyfilling the operations using CAS.
             // `u8` clamps to the limits, which atomic operations don't support since
             // they always overflow.
-            TypedArrayKind::Uint8Clamped | TypedArrayKind::Float32 | TypedArrayKind::Float64 => {
-                false
-            }
+            TypedArrayKind::Uint8Clamped
+            | TypedArrayKind::Float16
+            | TypedArrayKind::Float32
+            | TypedArrayKind::Float64 => false,
         }
     }
 
@@ -438,7 +476,9 @@ impl TypedArrayKind {
             TypedArrayKind::Int8 | TypedArrayKind::Uint8 | TypedArrayKind::Uint8Clamped => {
                 size_of::<u8>() as u64
             }
-            TypedArrayKind::Int16 | TypedArrayKind::Uint16 => size_of::<u16>() as u64,
+            TypedArrayKind::Int16 | TypedArrayKind::Uint16 | TypedArrayKind::Float16 => {
+                size_of::<u16>() as u64
+            }
             TypedArrayKind::Int32 | TypedArrayKind::Uint32 | TypedArrayKind::Float32 => {
                 size_of::<u32>() as u64
             }
@@ -459,6 +499,7 @@ impl TypedArrayKind {
             | TypedArrayKind::Uint16
             | TypedArrayKind::Int32
             | TypedArrayKind::Uint32
+            | TypedArrayKind::Float16
             | TypedArrayKind::Float32
             | TypedArrayKind::Float64 => ContentType::Number,
         }
@@ -486,6 +527,9 @@ impl TypedArrayKind {
             TypedArrayKind::BigUint64 => value
                 .to_big_uint64(context)
                 .map(TypedArrayElement::BigUint64),
+            TypedArrayKind::Float16 => value
+                .to_number(context)
+                .map(|f| TypedArrayElement::Float16(Float16::from_f64(f))),
             TypedArrayKind::Float32 => value
                 .to_number(context)
                 .map(|f| TypedArrayElement::Float32(f as f32)),
@@ -506,6 +550,7 @@ pub(crate) enum TypedArrayElement {
     Uint32(u32),
     BigInt64(i64),
     BigUint64(u64),
+    Float16(Float16),
     Float32(f32),
     Float64(f64),
 }
@@ -527,6 +572,7 @@ impl TypedArrayElement {
             TypedArrayElement::Uint32(num) => num as u64,
             TypedArrayElement::BigInt64(num) => num as u64,
             TypedArrayElement::BigUint64(num) => num,
+            TypedArrayElement::Float16(num) => num.0 as u64,
             TypedArrayElement::Float32(num) => num.to_bits() as u64,
             TypedArrayElement::Float64(num) => num.to_bits(),
         }
@@ -587,6 +633,12 @@ impl From<u64> for TypedArrayElement {
     }
 }
 
+impl From<Float16> for TypedArrayElement {
+    fn from(value: Float16) -> Self {
+        Self::Float16(value)
+    }
+}
+
 impl From<f32> for TypedArrayElement {
     fn from(value: f32) -> Self {
         Self::Float32(value)
@@ -611,6 +663,7 @@ impl From<TypedArrayElement> for JsValue {
             TypedArrayElement::Uint32(value) => Numeric::from(value),
             TypedArrayElement::BigInt64(value) => Numeric::from(value),
             TypedArrayElement::BigUint64(value) => Numeric::from(value),
+            TypedArrayElement::Float16(value) => Numeric::from(value),
             TypedArrayElement::Float32(value) => Numeric::from(value),
             TypedArrayElement::Float64(value) => Numeric::from(value),
         }
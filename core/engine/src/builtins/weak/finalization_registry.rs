@@ -0,0 +1,458 @@
+use std::{cell::Cell, rc::Rc};
+
+use boa_gc::{empty_trace, Ephemeron, Finalize, Trace, WeakGc};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    job::GenericJob,
+    js_string,
+    object::{
+        internal_methods::get_prototype_from_constructor, ErasedVTableObject, JsFunction, JsObject,
+    },
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+/// Value of the ephemeron of a [`RegistryCell`], which notifies its registry when dropped.
+///
+/// The garbage collector drops the value of an ephemeron as soon as its key is collected,
+/// which lets the registry know that it has cells to clean up without having to poll every
+/// cell of every registry.
+#[derive(Debug, Clone)]
+struct CollectionNotifier(Rc<Cell<bool>>);
+
+impl Finalize for CollectionNotifier {}
+
+// SAFETY: `CollectionNotifier` doesn't contain any garbage collected types.
+unsafe impl Trace for CollectionNotifier {
+    empty_trace!();
+}
+
+impl Drop for CollectionNotifier {
+    fn drop(&mut self) {
+        self.0.set(true);
+    }
+}
+
+/// A `FinalizationRegistry` cell record.
+#[derive(Trace, Finalize)]
+struct RegistryCell {
+    /// `[[WeakRefTarget]]`
+    target: Ephemeron<ErasedVTableObject, CollectionNotifier>,
+    /// `[[HeldValue]]`
+    held_value: JsValue,
+    /// `[[UnregisterToken]]`
+    unregister_token: Option<WeakGc<ErasedVTableObject>>,
+}
+
+impl RegistryCell {
+    /// Returns `true` if the target of this cell was collected.
+    fn is_collected(&self) -> bool {
+        !self.target.has_value()
+    }
+}
+
+/// Boa's implementation of ECMAScript's `FinalizationRegistry` builtin object.
+///
+/// The `FinalizationRegistry` allows requesting a callback when the registered objects are
+/// collected by the garbage collector. Cleanup callbacks are scheduled as [`GenericJob`]s on the
+/// [`JobExecutor`][crate::job::JobExecutor] of the context.
+///
+/// More Information:
+///  - [ECMAScript Reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-finalization-registry-objects
+#[derive(Trace, Finalize, JsData)]
+pub(crate) struct FinalizationRegistry {
+    /// `[[Realm]]`
+    realm: Realm,
+    /// `[[CleanupCallback]]`
+    cleanup_callback: JsFunction,
+    /// `[[Cells]]`
+    cells: Vec<RegistryCell>,
+    /// Set by the [`CollectionNotifier`]s of the cells when their targets are collected.
+    #[unsafe_ignore_trace]
+    has_collected_cells: Rc<Cell<bool>>,
+}
+
+impl IntrinsicObject for FinalizationRegistry {
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::register, js_string!("register"), 2)
+            .method(Self::unregister, js_string!("unregister"), 1);
+
+        #[cfg(feature = "experimental")]
+        let builder = builder.method(Self::cleanup_some, js_string!("cleanupSome"), 0);
+
+        builder.build();
+    }
+}
+
+impl BuiltInObject for FinalizationRegistry {
+    const NAME: JsString = StaticJsStrings::FINALIZATION_REGISTRY;
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE.union(Attribute::CONFIGURABLE);
+}
+
+impl BuiltInConstructor for FinalizationRegistry {
+    const LENGTH: usize = 1;
+    const P: usize = 4;
+    const SP: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::finalization_registry;
+
+    /// Constructor [`FinalizationRegistry ( cleanupCallback )`][cons]
+    ///
+    /// [cons]: https://tc39.es/ecma262/#sec-finalization-registry-cleanup-callback
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("FinalizationRegistry: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. If IsCallable(cleanupCallback) is false, throw a TypeError exception.
+        let cleanup_callback = args
+            .get_or_undefined(0)
+            .as_callable()
+            .cloned()
+            .and_then(JsFunction::from_object)
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("FinalizationRegistry: cleanup callback must be callable")
+            })?;
+
+        // 3. Let finalizationRegistry be ? OrdinaryCreateFromConstructor(NewTarget, "%FinalizationRegistry.prototype%", « [[Realm]], [[CleanupCallback]], [[Cells]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::finalization_registry,
+            context,
+        )?;
+
+        // 4. Let fn be the active function object.
+        // 5. Set finalizationRegistry.[[Realm]] to fn.[[Realm]].
+        // 6. Set finalizationRegistry.[[CleanupCallback]] to HostMakeJobCallback(cleanupCallback).
+        // 7. Set finalizationRegistry.[[Cells]] to a new empty List.
+        let registry = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                realm: context.realm().clone(),
+                cleanup_callback,
+                cells: Vec::new(),
+                has_collected_cells: Rc::default(),
+            },
+        );
+
+        context
+            .finalization_registries
+            .push(WeakGc::new(registry.inner()));
+
+        // 8. Return finalizationRegistry.
+        Ok(registry.into())
+    }
+}
+
+impl FinalizationRegistry {
+    /// Method [`FinalizationRegistry.prototype.register ( target, heldValue [ , unregisterToken ] )`][spec].
+    ///
+    /// Registers `target` on this registry, which will call its cleanup callback with `heldValue`
+    /// after `target` is collected.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.register
+    pub(crate) fn register(
+        this: &JsValue,
+        args: &[JsValue],
+        _context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let registry = this
+            .as_object()
+            .filter(|o| o.is::<Self>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "FinalizationRegistry.prototype.register: expected `this` to be a `FinalizationRegistry` object",
+                )
+            })?;
+
+        // 3. If CanBeHeldWeakly(target) is false, throw a TypeError exception.
+        let target = args.get_or_undefined(0);
+        let Some(target) = target.as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "FinalizationRegistry.prototype.register: expected target argument of type `object`, got target of type `{}`",
+                    target.type_of()
+                ))
+                .into());
+        };
+
+        // 4. If SameValue(target, heldValue) is true, throw a TypeError exception.
+        let held_value = args.get_or_undefined(1);
+        if held_value
+            .as_object()
+            .is_some_and(|held_value| JsObject::equals(held_value, target))
+        {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "FinalizationRegistry.prototype.register: target and held value cannot be the same",
+                )
+                .into());
+        }
+
+        // 5. If CanBeHeldWeakly(unregisterToken) is false, then
+        let token = args.get_or_undefined(2);
+        let unregister_token = if let Some(token) = token.as_object() {
+            Some(WeakGc::new(token.inner()))
+        } else if token.is_undefined() {
+            // b. Set unregisterToken to empty.
+            None
+        } else {
+            // a. If unregisterToken is not undefined, throw a TypeError exception.
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "FinalizationRegistry.prototype.register: expected unregister token of type `object` or `undefined`, got `{}`",
+                    token.type_of()
+                ))
+                .into());
+        };
+
+        // 6. Let cell be the Record { [[WeakRefTarget]]: target, [[HeldValue]]: heldValue, [[UnregisterToken]]: unregisterToken }.
+        // Note: the cell must be allocated before mutably borrowing the registry, since the
+        // allocation could trigger a garbage collection.
+        let notifier = CollectionNotifier(
+            registry
+                .downcast_ref::<Self>()
+                .expect("must be a `FinalizationRegistry` object")
+                .has_collected_cells
+                .clone(),
+        );
+        let cell = RegistryCell {
+            target: Ephemeron::new(target.inner(), notifier),
+            held_value: held_value.clone(),
+            unregister_token,
+        };
+
+        // 7. Append cell to finalizationRegistry.[[Cells]].
+        registry
+            .downcast_mut::<Self>()
+            .expect("must be a `FinalizationRegistry` object")
+            .cells
+            .push(cell);
+
+        // 8. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// Method [`FinalizationRegistry.prototype.unregister ( unregisterToken )`][spec].
+    ///
+    /// Removes all the cells registered with `unregisterToken`, returning `true` if any cell
+    /// was removed.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.unregister
+    pub(crate) fn unregister(
+        this: &JsValue,
+        args: &[JsValue],
+        _context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let mut registry = this
+            .as_object()
+            .and_then(JsObject::downcast_mut::<Self>)
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "FinalizationRegistry.prototype.unregister: expected `this` to be a `FinalizationRegistry` object",
+                )
+            })?;
+
+        // 3. If CanBeHeldWeakly(unregisterToken) is false, throw a TypeError exception.
+        let token = args.get_or_undefined(0);
+        let Some(token) = token.as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "FinalizationRegistry.prototype.unregister: expected unregister token of type `object`, got `{}`",
+                    token.type_of()
+                ))
+                .into());
+        };
+
+        // 4. Let removed be false.
+        // 5. For each Record { [[WeakRefTarget]], [[HeldValue]], [[UnregisterToken]] } cell of finalizationRegistry.[[Cells]], do
+        //     a. If cell.[[UnregisterToken]] is not empty and SameValue(cell.[[UnregisterToken]], unregisterToken) is true, then
+        //         i. Remove cell from finalizationRegistry.[[Cells]].
+        //         ii. Set removed to true.
+        let len = registry.cells.len();
+        registry.cells.retain(|cell| {
+            cell.unregister_token
+                .as_ref()
+                .and_then(WeakGc::upgrade)
+                .is_none_or(|cell_token| !JsObject::equals(&JsObject::from(cell_token), token))
+        });
+
+        // 6. Return removed.
+        Ok((registry.cells.len() != len).into())
+    }
+
+    /// Method [`FinalizationRegistry.prototype.cleanupSome ( [ callback ] )`][spec].
+    ///
+    /// Synchronously calls `callback`, or the cleanup callback of the registry if not provided,
+    /// for the cells of the registry whose targets were collected.
+    ///
+    /// [spec]: https://tc39.es/proposal-cleanup-some/#sec-finalization-registry.prototype.cleanupSome
+    #[cfg(feature = "experimental")]
+    pub(crate) fn cleanup_some(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let registry = this
+            .as_object()
+            .filter(|o| o.is::<Self>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "FinalizationRegistry.prototype.cleanupSome: expected `this` to be a `FinalizationRegistry` object",
+                )
+            })?;
+
+        // 3. If callback is present and IsCallable(callback) is false, throw a TypeError exception.
+        let callback = args.get_or_undefined(0);
+        let callback = if callback.is_undefined() {
+            None
+        } else {
+            let callback = callback
+                .as_callable()
+                .cloned()
+                .and_then(JsFunction::from_object)
+                .ok_or_else(|| {
+                    JsNativeError::typ().with_message(
+                        "FinalizationRegistry.prototype.cleanupSome: callback must be callable",
+                    )
+                })?;
+            Some(callback)
+        };
+
+        // 4. Perform ? CleanupFinalizationRegistry(finalizationRegistry, callback).
+        Self::cleanup(registry, callback, context)?;
+
+        // 5. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// Abstract operation [`CleanupFinalizationRegistry ( finalizationRegistry )`][spec].
+    ///
+    /// Calls `callback`, or the cleanup callback of `registry` if it's `None`, with the held
+    /// values of all the cells whose targets were collected, removing them from the registry.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-cleanup-finalization-registry
+    fn cleanup(
+        registry: &JsObject,
+        callback: Option<JsFunction>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. Assert: finalizationRegistry has [[Cells]] and [[CleanupCallback]] internal slots.
+        // 2. Let callback be finalizationRegistry.[[CleanupCallback]].
+        let callback = callback.unwrap_or_else(|| {
+            registry
+                .downcast_ref::<Self>()
+                .expect("must be a `FinalizationRegistry` object")
+                .cleanup_callback
+                .clone()
+        });
+
+        // 3. While finalizationRegistry.[[Cells]] contains a Record cell such that cell.[[WeakRefTarget]] is empty,
+        //    an implementation may perform the following steps:
+        loop {
+            let held_value = {
+                let mut registry = registry
+                    .downcast_mut::<Self>()
+                    .expect("must be a `FinalizationRegistry` object");
+
+                // a. Choose any such cell.
+                let Some(index) = registry.cells.iter().position(RegistryCell::is_collected) else {
+                    break;
+                };
+
+                // b. Remove cell from finalizationRegistry.[[Cells]].
+                let cell = registry.cells.remove(index);
+                cell.held_value.clone()
+            };
+
+            // c. Perform ? HostCallJobCallback(callback, undefined, « cell.[[HeldValue]] »).
+            callback.call(&JsValue::undefined(), &[held_value], context)?;
+        }
+
+        // 4. Return unused.
+        Ok(())
+    }
+
+    /// Host operation [`HostEnqueueFinalizationRegistryCleanupJob ( finalizationRegistry )`][spec].
+    ///
+    /// Enqueues a cleanup job on the job executor for every live registry that has cells whose
+    /// targets were collected since the last call.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-cleanup-finalization-registry
+    pub(crate) fn enqueue_cleanup_jobs(context: &mut Context) {
+        let mut registries = Vec::new();
+        context.finalization_registries.retain(|registry| {
+            let Some(registry) = registry.upgrade() else {
+                return false;
+            };
+            let registry = JsObject::from(registry);
+            let data = registry
+                .downcast_ref::<Self>()
+                .expect("must be a `FinalizationRegistry` object");
+
+            if data.has_collected_cells.replace(false)
+                && data.cells.iter().any(RegistryCell::is_collected)
+            {
+                let realm = data.realm.clone();
+                drop(data);
+                registries.push((registry, realm));
+            }
+            true
+        });
+
+        for (registry, realm) in registries {
+            // 1. Let cleanupJob be a new Job Abstract Closure with no parameters that captures
+            //    finalizationRegistry and performs the following steps when called:
+            //     a. Let cleanupResult be Completion(CleanupFinalizationRegistry(finalizationRegistry)).
+            //     b. If cleanupResult is an abrupt completion, perform any host-defined steps for reporting the error.
+            //     c. Return unused.
+            let job = GenericJob::with_realm(
+                move |context| {
+                    Self::cleanup(&registry, None, context)?;
+                    Ok(JsValue::undefined())
+                },
+                realm,
+                context,
+            );
+
+            // 2. Perform HostEnqueueGenericJob(cleanupJob, finalizationRegistry.[[Realm]]).
+            context.enqueue_job(job.into());
+        }
+    }
+}
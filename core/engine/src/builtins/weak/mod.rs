@@ -1,5 +1,10 @@
-//! Boa's implementation of ECMAScript's `WeakRef` object.
+//! Boa's implementation of ECMAScript's `WeakRef` and `FinalizationRegistry` objects.
 
+mod finalization_registry;
 mod weak_ref;
 
+#[cfg(test)]
+mod tests;
+
+pub(crate) use finalization_registry::FinalizationRegistry;
 pub(crate) use weak_ref::WeakRef;
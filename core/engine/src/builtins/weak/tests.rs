@@ -0,0 +1,53 @@
+use indoc::indoc;
+
+use crate::{run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+use boa_macros::js_str;
+
+#[test]
+fn finalization_registry_cleanup() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var heldValues = [];
+            var registry = new FinalizationRegistry((held) => heldValues.push(held));
+            var token = {};
+            {
+                let collected = {};
+                let unregistered = {};
+                registry.register(collected, "collected");
+                registry.register(unregistered, "unregistered", token);
+            }
+        "#}),
+        TestAction::assert("registry.unregister(token)"),
+        TestAction::assert("!registry.unregister(token)"),
+        TestAction::inspect_context(|context| {
+            context.clear_kept_objects();
+            boa_gc::force_collect();
+            context.run_jobs().unwrap();
+        }),
+        TestAction::assert_eq("heldValues.length", 1),
+        TestAction::assert_eq("heldValues[0]", js_str!("collected")),
+    ]);
+}
+
+#[test]
+fn finalization_registry_errors() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "new FinalizationRegistry()",
+            JsNativeErrorKind::Type,
+            "FinalizationRegistry: cleanup callback must be callable",
+        ),
+        TestAction::run("var registry = new FinalizationRegistry(() => {}); var obj = {};"),
+        TestAction::assert_native_error(
+            "registry.register(obj, obj)",
+            JsNativeErrorKind::Type,
+            "FinalizationRegistry.prototype.register: target and held value cannot be the same",
+        ),
+        TestAction::assert_native_error(
+            "registry.register(obj, 1, 2)",
+            JsNativeErrorKind::Type,
+            "FinalizationRegistry.prototype.register: expected unregister token of type `object` or `undefined`, got `number`",
+        ),
+        TestAction::assert_eq("registry.register(obj, 1)", JsValue::undefined()),
+    ]);
+}
@@ -1,13 +1,17 @@
 use super::{BindingAccessOpcode, ByteCompiler, Literal, Register, ToJsString};
 use crate::{
-    js_string,
-    vm::{opcode::BindingOpcode, CodeBlock, CodeBlockFlags},
+    js_str, js_string,
+    vm::{
+        opcode::{BindingOpcode, DecorationKind},
+        CodeBlock, CodeBlockFlags,
+    },
+    JsString,
 };
 use boa_ast::{
     expression::Identifier,
     function::{
-        ClassDeclaration, ClassElement, ClassElementName, ClassExpression, FormalParameterList,
-        FunctionExpression,
+        ClassDeclaration, ClassElement, ClassElementName, ClassExpression, Decorator,
+        FormalParameterList, FunctionExpression,
     },
     property::{MethodDefinitionKind, PropertyName},
     scope::Scope,
@@ -23,10 +27,12 @@ enum StaticElement {
     StaticBlock(Gc<CodeBlock>),
 
     // A static class field with it's function code, an optional name index and the information if the function is an anonymous function.
+    // Decorated fields also keep the target of their decorators, to apply the returned initializers.
     StaticField {
         code: Gc<CodeBlock>,
         name_index: StaticFieldName,
         is_anonymous_function: bool,
+        target: Option<Register>,
     },
 }
 
@@ -43,6 +49,7 @@ pub(crate) struct ClassSpec<'a> {
     super_ref: Option<&'a Expression>,
     constructor: Option<&'a FunctionExpression>,
     elements: &'a [ClassElement],
+    decorators: &'a [Decorator],
     has_binding_identifier: bool,
     name_scope: Option<&'a Scope>,
 }
@@ -54,6 +61,7 @@ impl<'a> From<&'a ClassDeclaration> for ClassSpec<'a> {
             super_ref: class.super_ref(),
             constructor: class.constructor(),
             elements: class.elements(),
+            decorators: class.decorators(),
             has_binding_identifier: true,
             name_scope: Some(class.name_scope()),
         }
@@ -67,6 +75,7 @@ impl<'a> From<&'a ClassExpression> for ClassSpec<'a> {
             super_ref: class.super_ref(),
             constructor: class.constructor(),
             elements: class.elements(),
+            decorators: class.decorators(),
             has_binding_identifier: class.name().is_some(),
             name_scope: class.name_scope(),
         }
@@ -90,6 +99,9 @@ impl ByteCompiler<'_> {
             .map_or(Sym::EMPTY_STRING, Identifier::sym)
             .to_js_string(self.interner());
 
+        // Class decorators are evaluated in the scope that contains the class.
+        let class_decorators = self.compile_decorators(class.decorators);
+
         let outer_scope = self.push_declarative_scope(class.name_scope);
 
         // The new span is not the same as the parent `ByteCompiler` have.
@@ -213,7 +225,34 @@ impl ByteCompiler<'_> {
             );
         }
 
-        for element in class.elements {
+        let decorations = (!class.decorators.is_empty()
+            || class
+                .elements
+                .iter()
+                .any(|element| !element.decorators().is_empty() || element.is_accessor()))
+        .then(|| {
+            let decorations = self.register_allocator.alloc();
+            self.bytecode
+                .emit_create_class_decorations(decorations.variable(), class_register.variable());
+            decorations
+        });
+
+        for (element_index, element) in class.elements.iter().enumerate() {
+            if let Some(decorations) = &decorations {
+                if !element.decorators().is_empty() || element.is_accessor() {
+                    self.compile_decorated_class_element(
+                        element,
+                        element_index,
+                        &class_name,
+                        &class_register,
+                        &proto_register,
+                        decorations,
+                        &mut static_elements,
+                    );
+                    continue;
+                }
+            }
+
             match element {
                 ClassElement::MethodDefinition(m) => match m.name() {
                     ClassElementName::PropertyName(PropertyName::Literal(name)) => {
@@ -539,6 +578,7 @@ impl ByteCompiler<'_> {
                         code,
                         name_index,
                         is_anonymous_function,
+                        target: None,
                     });
                 }
                 ClassElement::PrivateStaticFieldDefinition(field) => {
@@ -582,6 +622,7 @@ impl ByteCompiler<'_> {
                         code,
                         name_index: StaticFieldName::PrivateName(name_index),
                         is_anonymous_function,
+                        target: None,
                     });
                 }
                 ClassElement::StaticBlock(block) => {
@@ -621,6 +662,16 @@ impl ByteCompiler<'_> {
             }
         }
 
+        if let Some(decorations) = &decorations {
+            self.bytecode
+                .emit_push_class_initializers(decorations.variable());
+            self.bytecode.emit_call_class_initializers(
+                decorations.variable(),
+                class_register.variable(),
+                0u32.into(),
+            );
+        }
+
         for element in static_elements {
             match element {
                 StaticElement::StaticBlock(code) => {
@@ -639,6 +690,7 @@ impl ByteCompiler<'_> {
                     code,
                     name_index,
                     is_anonymous_function,
+                    target,
                 } => {
                     let index = self.push_function_to_constants(code);
                     let function = self.register_allocator.alloc();
@@ -653,6 +705,7 @@ impl ByteCompiler<'_> {
                     self.pop_into_register(&value);
                     match name_index {
                         StaticFieldName::PrivateName(name) => {
+                            self.apply_field_initializers(&value, &class_register, target);
                             self.bytecode.emit_define_private_field(
                                 class_register.variable(),
                                 value.variable(),
@@ -660,6 +713,7 @@ impl ByteCompiler<'_> {
                             );
                         }
                         StaticFieldName::Index(name) => {
+                            self.apply_field_initializers(&value, &class_register, target);
                             self.bytecode.emit_define_own_property_by_name(
                                 class_register.variable(),
                                 value.variable(),
@@ -676,6 +730,7 @@ impl ByteCompiler<'_> {
                                     0u32.into(),
                                 );
                             }
+                            self.apply_field_initializers(&value, &class_register, target);
                             self.bytecode.emit_define_own_property_by_value(
                                 value.variable(),
                                 key.variable(),
@@ -692,6 +747,45 @@ impl ByteCompiler<'_> {
 
         self.register_allocator.dealloc(proto_register);
 
+        if let Some(decorations) = decorations {
+            if !class_decorators.is_empty() {
+                let name = self.register_allocator.alloc();
+                if class.name.is_some() {
+                    self.emit_push_literal(Literal::String(class_name.clone()), &name);
+                } else {
+                    self.bytecode.emit_push_undefined(name.variable());
+                }
+                let target = self.register_allocator.alloc();
+                self.bytecode.emit_create_decoration_target(
+                    target.variable(),
+                    decorations.variable(),
+                    name.variable(),
+                    DecorationKind::Class.to_flags(false, false).into(),
+                );
+                self.register_allocator.dealloc(name);
+                self.apply_decorators(&class_register, &target, class_decorators);
+                self.register_allocator.dealloc(target);
+
+                // The class binding inside the class body refers to the decorated class.
+                if let Some(scope) = class.name_scope {
+                    let binding = scope.get_identifier_reference(class_name.clone());
+                    let index = self.insert_binding(binding);
+                    self.emit_binding_access(
+                        BindingAccessOpcode::PutLexicalValue,
+                        &index,
+                        &class_register,
+                    );
+                }
+
+                self.bytecode.emit_call_class_initializers(
+                    decorations.variable(),
+                    class_register.variable(),
+                    1u32.into(),
+                );
+            }
+            self.register_allocator.dealloc(decorations);
+        }
+
         self.pop_declarative_scope(outer_scope);
         self.bytecode.emit_pop_private_environment();
 
@@ -707,4 +801,343 @@ impl ByteCompiler<'_> {
         // NOTE: Reset strict mode to before class declaration/expression evalutation.
         self.code_block_flags.set(CodeBlockFlags::STRICT, strict);
     }
+
+    /// Evaluates the expressions of a list of decorators, in source order.
+    fn compile_decorators(&mut self, decorators: &[Decorator]) -> Vec<Register> {
+        decorators
+            .iter()
+            .map(|decorator| {
+                let register = self.register_allocator.alloc();
+                self.compile_expr(decorator.expression(), &register);
+                register
+            })
+            .collect()
+    }
+
+    /// Applies evaluated decorators to the value in the `value` register.
+    ///
+    /// Decorators are applied from the innermost to the outermost, which is the reverse of the
+    /// order in which they are evaluated.
+    fn apply_decorators(&mut self, value: &Register, target: &Register, decorators: Vec<Register>) {
+        for decorator in decorators.into_iter().rev() {
+            self.bytecode.emit_apply_decorator(
+                value.variable(),
+                target.variable(),
+                decorator.variable(),
+            );
+            self.register_allocator.dealloc(decorator);
+        }
+    }
+
+    /// Passes the value of a static field through the initializers returned by its decorators.
+    fn apply_field_initializers(
+        &mut self,
+        value: &Register,
+        receiver: &Register,
+        target: Option<Register>,
+    ) {
+        if let Some(target) = target {
+            self.bytecode.emit_apply_field_initializers(
+                value.variable(),
+                receiver.variable(),
+                target.variable(),
+            );
+            self.register_allocator.dealloc(target);
+        }
+    }
+
+    /// Compiles the initializer of a class field into the code of a function.
+    ///
+    /// Returns the code and if the initializer is an anonymous function definition.
+    fn compile_field_initializer(
+        &mut self,
+        name: JsString,
+        scope: &Scope,
+        initializer: Option<&Expression>,
+    ) -> (Gc<CodeBlock>, bool) {
+        let mut field_compiler = ByteCompiler::new(
+            name,
+            true,
+            self.json_parse,
+            self.variable_scope.clone(),
+            self.lexical_scope.clone(),
+            false,
+            false,
+            self.interner,
+            self.in_with,
+            self.spanned_source_text.clone_only_source(),
+        );
+        field_compiler.code_block_flags |= CodeBlockFlags::HAS_FUNCTION_SCOPE;
+        let _ = field_compiler.push_scope(scope);
+        let value = field_compiler.register_allocator.alloc();
+        let is_anonymous_function = if let Some(node) = initializer {
+            field_compiler.compile_expr(node, &value);
+            node.is_anonymous_function_definition()
+        } else {
+            field_compiler
+                .bytecode
+                .emit_push_undefined(value.variable());
+            false
+        };
+        field_compiler
+            .bytecode
+            .emit_set_accumulator(value.variable());
+        field_compiler.register_allocator.dealloc(value);
+
+        field_compiler.code_block_flags |= CodeBlockFlags::IN_CLASS_FIELD_INITIALIZER;
+
+        (Gc::new(field_compiler.finish()), is_anonymous_function)
+    }
+
+    /// Compiles the key of a decorated class element into the `key` register.
+    ///
+    /// Public keys are converted to property keys, while private names are stored as their
+    /// description. Returns `true` if the key is a private name.
+    fn compile_decorated_element_key(&mut self, name: &ClassElementName, key: &Register) -> bool {
+        match name {
+            ClassElementName::PropertyName(PropertyName::Literal(name)) => {
+                self.emit_push_literal(
+                    Literal::String(self.interner().resolve_expect(*name).into_common(false)),
+                    key,
+                );
+                false
+            }
+            ClassElementName::PropertyName(PropertyName::Computed(name)) => {
+                self.compile_expr(name, key);
+                self.bytecode
+                    .emit_to_property_key(key.variable(), key.variable());
+                false
+            }
+            ClassElementName::PrivateName(name) => {
+                let description = name.description().to_js_string(self.interner());
+                self.emit_push_literal(Literal::String(description), key);
+                true
+            }
+        }
+    }
+
+    /// Compiles a decorated class element or an `accessor` field.
+    ///
+    /// The decorators of the element are evaluated before its key, and applied as soon as the
+    /// element is defined. Static fields are initialized later with the other static elements.
+    #[allow(clippy::too_many_arguments)]
+    fn compile_decorated_class_element(
+        &mut self,
+        element: &ClassElement,
+        element_index: usize,
+        class_name: &JsString,
+        class_register: &Register,
+        proto_register: &Register,
+        decorations: &Register,
+        static_elements: &mut Vec<StaticElement>,
+    ) {
+        let decorators = self.compile_decorators(element.decorators());
+
+        let key = self.register_allocator.alloc();
+        let target = self.register_allocator.alloc();
+
+        let (name, is_static, initializer, scope) = match element {
+            ClassElement::MethodDefinition(m) => {
+                let is_private = self.compile_decorated_element_key(m.name(), &key);
+                let (kind, prefix) = match m.kind() {
+                    MethodDefinitionKind::Get => (DecorationKind::Getter, 1u32),
+                    MethodDefinitionKind::Set => (DecorationKind::Setter, 2u32),
+                    _ => (DecorationKind::Method, 0u32),
+                };
+                self.bytecode.emit_create_decoration_target(
+                    target.variable(),
+                    decorations.variable(),
+                    key.variable(),
+                    kind.to_flags(m.is_static(), is_private).into(),
+                );
+
+                let method = self.method(m.into());
+                let home_object = if m.is_static() {
+                    class_register
+                } else {
+                    proto_register
+                };
+                self.bytecode
+                    .emit_set_home_object(method.variable(), home_object.variable());
+                if let ClassElementName::PrivateName(name) = m.name() {
+                    let description = name.description().to_js_string(self.interner());
+                    self.emit_push_literal(
+                        Literal::String(js_string!(js_str!("#"), &description)),
+                        &key,
+                    );
+                }
+                self.bytecode.emit_set_function_name(
+                    method.variable(),
+                    key.variable(),
+                    prefix.into(),
+                );
+
+                self.apply_decorators(&method, &target, decorators);
+
+                let object = if m.is_static() || is_private {
+                    class_register
+                } else {
+                    proto_register
+                };
+                self.bytecode.emit_define_decorated_element(
+                    object.variable(),
+                    target.variable(),
+                    method.variable(),
+                );
+
+                self.register_allocator.dealloc(method);
+                self.register_allocator.dealloc(key);
+                self.register_allocator.dealloc(target);
+                return;
+            }
+            ClassElement::FieldDefinition(field) | ClassElement::StaticFieldDefinition(field) => (
+                ClassElementName::PropertyName(field.name().clone()),
+                matches!(element, ClassElement::StaticFieldDefinition(_)),
+                field.initializer(),
+                field.scope(),
+            ),
+            ClassElement::PrivateFieldDefinition(field)
+            | ClassElement::PrivateStaticFieldDefinition(field) => (
+                ClassElementName::PrivateName(*field.name()),
+                matches!(element, ClassElement::PrivateStaticFieldDefinition(_)),
+                field.initializer(),
+                field.scope(),
+            ),
+            ClassElement::StaticBlock(_) => unreachable!("static blocks cannot be decorated"),
+        };
+
+        let is_private = self.compile_decorated_element_key(&name, &key);
+
+        if element.is_accessor() {
+            self.bytecode.emit_create_decoration_target(
+                target.variable(),
+                decorations.variable(),
+                key.variable(),
+                DecorationKind::Accessor
+                    .to_flags(is_static, is_private)
+                    .into(),
+            );
+            self.register_allocator.dealloc(key);
+
+            // The value of an accessor is stored in a private field that cannot be named
+            // from the source code, since descriptions of private names cannot contain spaces.
+            let storage_index =
+                self.get_or_insert_string(js_string!(format!("accessor storage {element_index}")));
+            let (code, _) = self.compile_field_initializer(class_name.clone(), scope, initializer);
+            if !is_static {
+                let index = self.push_function_to_constants(code.clone());
+                let function = self.register_allocator.alloc();
+                self.emit_get_function(&function, index);
+                self.bytecode.emit_push_class_field_private(
+                    class_register.variable(),
+                    function.variable(),
+                    storage_index.into(),
+                );
+                self.register_allocator.dealloc(function);
+            }
+
+            let accessor = self.register_allocator.alloc();
+            self.bytecode.emit_create_auto_accessor(
+                accessor.variable(),
+                class_register.variable(),
+                target.variable(),
+                storage_index.into(),
+            );
+            self.apply_decorators(&accessor, &target, decorators);
+
+            let object = if is_static || is_private {
+                class_register
+            } else {
+                proto_register
+            };
+            self.bytecode.emit_define_decorated_element(
+                object.variable(),
+                target.variable(),
+                accessor.variable(),
+            );
+            self.register_allocator.dealloc(accessor);
+
+            if is_static {
+                static_elements.push(StaticElement::StaticField {
+                    code,
+                    name_index: StaticFieldName::PrivateName(storage_index),
+                    is_anonymous_function: false,
+                    target: Some(target),
+                });
+            } else {
+                self.bytecode.emit_set_class_field_initializers(
+                    class_register.variable(),
+                    target.variable(),
+                );
+                self.register_allocator.dealloc(target);
+            }
+            return;
+        }
+
+        self.bytecode.emit_create_decoration_target(
+            target.variable(),
+            decorations.variable(),
+            key.variable(),
+            DecorationKind::Field.to_flags(is_static, is_private).into(),
+        );
+
+        // Field decorators receive `undefined` as the decorated value.
+        let value = self.register_allocator.alloc();
+        self.bytecode.emit_push_undefined(value.variable());
+        self.apply_decorators(&value, &target, decorators);
+        self.register_allocator.dealloc(value);
+
+        let function_name = if is_static || is_private {
+            class_name.clone()
+        } else {
+            js_string!()
+        };
+        let (code, is_anonymous_function) =
+            self.compile_field_initializer(function_name, scope, initializer);
+
+        let name_index = match &name {
+            ClassElementName::PrivateName(name) => Some(self.get_or_insert_private_name(*name)),
+            ClassElementName::PropertyName(_) => None,
+        };
+
+        if is_static {
+            let name_index = match name_index {
+                Some(name_index) => {
+                    self.register_allocator.dealloc(key);
+                    StaticFieldName::PrivateName(name_index)
+                }
+                None => StaticFieldName::Register(key),
+            };
+            static_elements.push(StaticElement::StaticField {
+                code,
+                name_index,
+                is_anonymous_function,
+                target: Some(target),
+            });
+            return;
+        }
+
+        let index = self.push_function_to_constants(code);
+        let function = self.register_allocator.alloc();
+        self.emit_get_function(&function, index);
+        if let Some(name_index) = name_index {
+            self.bytecode.emit_push_class_field_private(
+                class_register.variable(),
+                function.variable(),
+                name_index.into(),
+            );
+        } else {
+            self.bytecode.emit_push_class_field(
+                class_register.variable(),
+                key.variable(),
+                function.variable(),
+                is_anonymous_function.into(),
+            );
+        }
+        self.bytecode
+            .emit_set_class_field_initializers(class_register.variable(), target.variable());
+        self.register_allocator.dealloc(function);
+        self.register_allocator.dealloc(key);
+        self.register_allocator.dealloc(target);
+    }
 }
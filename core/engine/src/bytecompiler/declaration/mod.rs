@@ -1 +1,4 @@
 mod declaration_pattern;
+mod using;
+
+pub(crate) use using::dispose_hint;
@@ -0,0 +1,151 @@
+use crate::{
+    builtins::disposable_stack::DisposeHint,
+    bytecompiler::{jump_control::JumpControlInfoFlags, ByteCompiler},
+    vm::GeneratorResumeKind,
+};
+use boa_ast::{declaration::LexicalDeclaration, Declaration, StatementListItem};
+
+/// Returns the hint of the dispose capability needed by a list of statements, or `None`
+/// if the list doesn't directly contain any `using` or `await using` declarations.
+pub(crate) fn dispose_hint<'a, I>(items: I) -> Option<DisposeHint>
+where
+    I: IntoIterator<Item = &'a StatementListItem>,
+{
+    let mut hint = None;
+    for item in items {
+        match item {
+            StatementListItem::Declaration(Declaration::Lexical(
+                LexicalDeclaration::AwaitUsing(_),
+            )) => return Some(DisposeHint::Async),
+            StatementListItem::Declaration(Declaration::Lexical(LexicalDeclaration::Using(_))) => {
+                hint = Some(DisposeHint::Sync);
+            }
+            _ => {}
+        }
+    }
+    hint
+}
+
+impl ByteCompiler<'_> {
+    /// Compiles the code emitted by `f` inside a new dispose scope, disposing the resources
+    /// added by its `using` declarations when the scope is exited.
+    ///
+    /// This behaves like a `try` statement whose `finally` block runs the `DisposeResources`
+    /// abstract operation, so `break`, `continue` and `return` also dispose the resources.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    pub(crate) fn compile_dispose_scope<F>(&mut self, hint: DisposeHint, use_expr: bool, f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let has_error = self.register_allocator.alloc();
+        let error = self.register_allocator.alloc();
+        self.bytecode.emit_push_false(has_error.variable());
+
+        // Is this a generator `return()` empty exception?
+        let re_throw_generator = self.is_generator().then(|| {
+            let re_throw_generator = self.register_allocator.alloc();
+            self.bytecode.emit_push_false(re_throw_generator.variable());
+            re_throw_generator
+        });
+
+        self.push_try_with_finally_control_info(&has_error, use_expr);
+        self.bytecode.emit_push_dispose_scope();
+
+        let handler = self.push_handler();
+        f(self);
+
+        self.bytecode.emit_push_false(has_error.variable());
+        let exit = self.jump();
+
+        self.patch_handler(handler);
+        self.bytecode
+            .emit_maybe_exception(has_error.variable(), error.variable());
+        if let Some(re_throw_generator) = &re_throw_generator {
+            let is_exception = self.jump_if_true(&has_error);
+            self.bytecode.emit_push_true(re_throw_generator.variable());
+            self.patch_jump(is_exception);
+        }
+
+        self.patch_jump(exit);
+
+        // The jump table emitted after the disposal expects an index on the stack when there
+        // are `break`, `continue` or `return` records, where zero is the fallthrough entry.
+        let has_jumps = !self
+            .jump_info
+            .last()
+            .expect("there should be a try block")
+            .jumps
+            .is_empty();
+        if has_jumps {
+            let jump_index = self.register_allocator.alloc();
+            self.emit_push_integer(0, &jump_index);
+            self.push_from_register(&jump_index);
+            self.register_allocator.dealloc(jump_index);
+        }
+
+        let finally_start = self.next_opcode_location();
+        self.jump_info
+            .last_mut()
+            .expect("there should be a try block")
+            .flags |= JumpControlInfoFlags::IN_FINALLY;
+
+        let accumulator = self.register_allocator.alloc();
+        self.bytecode
+            .emit_set_register_from_accumulator(accumulator.variable());
+
+        let value = self.register_allocator.alloc();
+        let done = self.register_allocator.alloc();
+        match hint {
+            DisposeHint::Sync => {
+                self.bytecode
+                    .emit_dispose_resources(value.variable(), done.variable());
+            }
+            DisposeHint::Async => {
+                let loop_start = self.next_opcode_location();
+                self.bytecode
+                    .emit_dispose_resources(value.variable(), done.variable());
+                let disposed = self.jump_if_true(&done);
+
+                self.bytecode.emit_await(value.variable());
+                let resume_kind = self.register_allocator.alloc();
+                self.pop_into_register(&resume_kind);
+                self.pop_into_register(&value);
+                let fulfilled =
+                    self.jump_if_not_resume_kind(GeneratorResumeKind::Throw, &resume_kind);
+                self.register_allocator.dealloc(resume_kind);
+                self.bytecode.emit_record_dispose_error(value.variable());
+                self.patch_jump(fulfilled);
+                self.bytecode.emit_jump(loop_start);
+
+                self.patch_jump(disposed);
+            }
+        }
+        self.register_allocator.dealloc(value);
+        self.register_allocator.dealloc(done);
+
+        self.bytecode
+            .emit_pop_dispose_scope(error.variable(), has_error.variable());
+
+        self.bytecode.emit_set_accumulator(accumulator.variable());
+        self.register_allocator.dealloc(accumulator);
+
+        let do_not_throw_exit = self.jump_if_false(&has_error);
+        self.bytecode.emit_throw(error.variable());
+        self.patch_jump(do_not_throw_exit);
+
+        if let Some(re_throw_generator) = re_throw_generator {
+            let is_not_generator_exit = self.jump_if_false(&re_throw_generator);
+            self.bytecode.emit_re_throw();
+            self.patch_jump(is_not_generator_exit);
+            self.register_allocator.dealloc(re_throw_generator);
+        }
+
+        self.register_allocator.dealloc(error);
+        self.pop_try_with_finally_control_info(finally_start);
+        self.register_allocator.dealloc(has_error);
+    }
+}
@@ -14,7 +14,7 @@ use crate::{
 use boa_ast::{
     expression::{
         access::{PropertyAccess, PropertyAccessField},
-        literal::{Literal as AstLiteral, TemplateElement, TemplateLiteral},
+        literal::{Literal as AstLiteral, LiteralKind, TemplateElement, TemplateLiteral},
         operator::Conditional,
         Identifier,
     },
@@ -24,19 +24,19 @@ use thin_vec::ThinVec;
 
 impl ByteCompiler<'_> {
     fn compile_literal(&mut self, lit: &AstLiteral, dst: &Register) {
-        match lit {
-            AstLiteral::String(v) => {
+        match lit.kind() {
+            LiteralKind::String(v) => {
                 self.emit_push_literal(Literal::String(v.to_js_string(self.interner())), dst);
             }
-            AstLiteral::Int(v) => self.emit_push_integer(*v, dst),
-            AstLiteral::Num(v) => self.emit_push_rational(*v, dst),
-            AstLiteral::BigInt(v) => {
+            LiteralKind::Int(v) => self.emit_push_integer(*v, dst),
+            LiteralKind::Num(v) => self.emit_push_rational(*v, dst),
+            LiteralKind::BigInt(v) => {
                 self.emit_push_literal(Literal::BigInt(v.clone().into()), dst);
             }
-            AstLiteral::Bool(true) => self.bytecode.emit_push_true(dst.variable()),
-            AstLiteral::Bool(false) => self.bytecode.emit_push_false(dst.variable()),
-            AstLiteral::Null => self.bytecode.emit_push_null(dst.variable()),
-            AstLiteral::Undefined => self.bytecode.emit_push_undefined(dst.variable()),
+            LiteralKind::Bool(true) => self.bytecode.emit_push_true(dst.variable()),
+            LiteralKind::Bool(false) => self.bytecode.emit_push_false(dst.variable()),
+            LiteralKind::Null => self.bytecode.emit_push_null(dst.variable()),
+            LiteralKind::Undefined => self.bytecode.emit_push_undefined(dst.variable()),
         }
     }
 
@@ -120,7 +120,7 @@ impl ByteCompiler<'_> {
                 }
                 self.register_allocator.dealloc(value);
             }
-            Expression::This => self.access_get(Access::This, dst),
+            Expression::This(_) => self.access_get(Access::This, dst),
             Expression::Spread(spread) => self.compile_expr(spread.target(), dst),
             Expression::FunctionExpression(function) => {
                 self.function_with_binding(function.into(), NodeKind::Expression, dst);
@@ -351,6 +351,9 @@ impl ByteCompiler<'_> {
                 self.compile_class(class.deref().into(), Some(dst));
             }
             Expression::SuperCall(super_call) => {
+                let previous_position =
+                    self.set_source_position(super_call.position().or(self.source_position));
+
                 let this = self.register_allocator.alloc();
                 let value = self.register_allocator.alloc();
                 self.bytecode.emit_super_call_prepare(value.variable());
@@ -403,15 +406,25 @@ impl ByteCompiler<'_> {
                 }
                 self.pop_into_register(dst);
                 self.bytecode.emit_bind_this_value(dst.variable());
+
+                self.set_source_position(previous_position);
             }
             Expression::ImportCall(import) => {
                 self.compile_expr(import.argument(), dst);
-                self.bytecode.emit_import_call(dst.variable());
+                let options = self.register_allocator.alloc();
+                if let Some(expr) = import.options() {
+                    self.compile_expr(expr, &options);
+                } else {
+                    self.bytecode.emit_push_undefined(options.variable());
+                }
+                self.bytecode
+                    .emit_import_call(dst.variable(), options.variable());
+                self.register_allocator.dealloc(options);
             }
-            Expression::NewTarget => {
+            Expression::NewTarget(_) => {
                 self.bytecode.emit_new_target(dst.variable());
             }
-            Expression::ImportMeta => {
+            Expression::ImportMeta(_) => {
                 self.bytecode.emit_import_meta(dst.variable());
             }
             Expression::Optional(opt) => {
@@ -424,7 +437,7 @@ impl ByteCompiler<'_> {
             }
             // TODO: try to remove this variant somehow
             Expression::FormalParameterList(_) => unreachable!(),
-            Expression::Debugger => (),
+            Expression::Debugger(_) => (),
         }
     }
 }
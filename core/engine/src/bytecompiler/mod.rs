@@ -20,6 +20,7 @@ use crate::{
     vm::{
         opcode::{BindingOpcode, ByteCodeEmitter},
         CallFrame, CodeBlock, CodeBlockFlags, Constant, GeneratorResumeKind, Handler, InlineCache,
+        SourceMap, SourceMapEntry,
     },
     JsBigInt, JsStr, JsString, SourceText, SpannedSourceText,
 };
@@ -41,7 +42,8 @@ use boa_ast::{
     pattern::Pattern,
     property::MethodDefinitionKind,
     scope::{BindingLocator, BindingLocatorError, FunctionScopes, IdentifierReference, Scope},
-    Declaration, Expression, LinearSpan, Statement, StatementList, StatementListItem,
+    Declaration, Expression, LinearSpan, Position, Span, Spanned, Statement, StatementList,
+    StatementListItem,
 };
 use boa_gc::Gc;
 use boa_interner::{Interner, Sym};
@@ -377,7 +379,7 @@ impl Access<'_> {
         match expr {
             Expression::Identifier(name) => Some(Access::Variable { name: *name }),
             Expression::PropertyAccess(access) => Some(Access::Property { access }),
-            Expression::This => Some(Access::This),
+            Expression::This(_) => Some(Access::This),
             Expression::Parenthesized(expr) => Self::from_expression(expr.expression()),
             _ => None,
         }
@@ -466,6 +468,12 @@ pub struct ByteCompiler<'ctx> {
     pub(crate) interner: &'ctx mut Interner,
     spanned_source_text: SpannedSourceText,
 
+    /// The position in the source code of the node currently being compiled.
+    source_position: Option<Position>,
+
+    /// Entries of the [`SourceMap`] of the code block.
+    source_map_entries: Vec<SourceMapEntry>,
+
     #[cfg(feature = "annex-b")]
     pub(crate) annex_b_function_names: Vec<Identifier>,
 }
@@ -558,6 +566,8 @@ impl<'ctx> ByteCompiler<'ctx> {
             lexical_scope,
             interner,
             spanned_source_text,
+            source_position: None,
+            source_map_entries: Vec::new(),
 
             #[cfg(feature = "annex-b")]
             annex_b_function_names: Vec::new(),
@@ -739,6 +749,37 @@ impl<'ctx> ByteCompiler<'ctx> {
         self.bytecode.next_opcode_location()
     }
 
+    /// Sets the position in the source code of the node being compiled, returning the
+    /// previous one so that it can be restored once the node has been compiled.
+    ///
+    /// All instructions emitted after this call are mapped to `position` in the [`SourceMap`].
+    pub(crate) fn set_source_position(&mut self, position: Option<Position>) -> Option<Position> {
+        let previous = self.source_position;
+        if previous == position {
+            return previous;
+        }
+        self.source_position = position;
+
+        let pc = self.next_opcode_location();
+        match self.source_map_entries.last_mut() {
+            Some(entry) if entry.pc == pc => {
+                entry.position = position;
+
+                // No instructions were emitted with the previous position, so check if
+                // the entry became redundant.
+                let len = self.source_map_entries.len();
+                if len >= 2 && self.source_map_entries[len - 2].position == position {
+                    self.source_map_entries.pop();
+                }
+            }
+            _ => self
+                .source_map_entries
+                .push(SourceMapEntry { pc, position }),
+        }
+
+        previous
+    }
+
     pub(crate) fn emit_get_function(&mut self, dst: &Register, index: u32) {
         self.bytecode
             .emit_get_function(dst.variable(), index.into());
@@ -1260,6 +1301,23 @@ impl<'ctx> ByteCompiler<'ctx> {
 
     /// Compile a [`StatementList`].
     pub fn compile_statement_list(&mut self, list: &StatementList, use_expr: bool, block: bool) {
+        if let Some(hint) = declaration::dispose_hint(list.statements()) {
+            self.compile_dispose_scope(hint, use_expr, |compiler| {
+                compiler.compile_statement_list_items(list, use_expr, block);
+            });
+        } else {
+            self.compile_statement_list_items(list, use_expr, block);
+        }
+    }
+
+    /// Compile the items of a [`StatementList`], without creating a dispose scope for its
+    /// `using` declarations.
+    pub(crate) fn compile_statement_list_items(
+        &mut self,
+        list: &StatementList,
+        use_expr: bool,
+        block: bool,
+    ) {
         if use_expr || self.jump_control_info_has_use_expr() {
             let mut use_expr_index = 0;
             for (i, statement) in list.statements().iter().enumerate() {
@@ -1267,7 +1325,7 @@ impl<'ctx> ByteCompiler<'ctx> {
                     StatementListItem::Statement(Statement::Break(_) | Statement::Continue(_)) => {
                         break;
                     }
-                    StatementListItem::Statement(Statement::Empty | Statement::Var(_))
+                    StatementListItem::Statement(Statement::Empty(_) | Statement::Var(_))
                     | StatementListItem::Declaration(_) => {}
                     StatementListItem::Statement(Statement::Block(block))
                         if !returns_value(block) => {}
@@ -1589,6 +1647,27 @@ impl<'ctx> ByteCompiler<'ctx> {
                     }
                 }
             }
+            LexicalDeclaration::Using(decls) | LexicalDeclaration::AwaitUsing(decls) => {
+                for variable in decls.as_ref() {
+                    let Binding::Identifier(ident) = variable.binding() else {
+                        unreachable!("using declarations cannot have binding patterns");
+                    };
+                    let ident = ident.to_js_string(self.interner());
+                    let init = variable
+                        .init()
+                        .expect("using declaration must have initializer");
+                    let value = self.register_allocator.alloc();
+                    self.compile_expr(init, &value);
+                    if decl.is_await_using() {
+                        self.bytecode
+                            .emit_add_async_disposable_resource(value.variable());
+                    } else {
+                        self.bytecode.emit_add_disposable_resource(value.variable());
+                    }
+                    self.emit_binding(BindingOpcode::InitLexical, ident, &value);
+                    self.register_allocator.dealloc(value);
+                }
+            }
             LexicalDeclaration::Const(decls) => {
                 for variable in decls.as_ref() {
                     match variable.binding() {
@@ -1633,8 +1712,14 @@ impl<'ctx> ByteCompiler<'ctx> {
     }
 
     /// Compile a [`Declaration`].
+    ///
+    /// The instructions of the declaration are mapped to its start in the source map, unless a
+    /// nested node records a more precise position.
     #[allow(unused_variables)]
     pub fn compile_decl(&mut self, decl: &Declaration, block: bool) {
+        let previous_position =
+            self.set_source_position(decl.span().map(Span::start).or(self.source_position));
+
         match decl {
             #[cfg(feature = "annex-b")]
             Declaration::FunctionDeclaration(function) if block => {
@@ -1664,6 +1749,8 @@ impl<'ctx> ByteCompiler<'ctx> {
             Declaration::Lexical(lexical) => self.compile_lexical_decl(lexical),
             _ => {}
         }
+
+        self.set_source_position(previous_position);
     }
 
     /// Compiles a function AST Node into bytecode, and returns its index into
@@ -1859,6 +1946,8 @@ impl<'ctx> ByteCompiler<'ctx> {
             Callable::New(new) => (new.call(), CallKind::New),
         };
 
+        let previous_position = self.set_source_position(call.position().or(self.source_position));
+
         match call.function().flatten() {
             Expression::PropertyAccess(access) if kind == CallKind::Call => {
                 let this = self.register_allocator.alloc();
@@ -1984,6 +2073,8 @@ impl<'ctx> ByteCompiler<'ctx> {
             CallKind::New => self.bytecode.emit_new((call.args().len() as u32).into()),
         }
         self.pop_into_register(dst);
+
+        self.set_source_position(previous_position);
     }
 
     /// Finish compiling code with the [`ByteCompiler`] and return the generated [`CodeBlock`].
@@ -2020,6 +2111,7 @@ impl<'ctx> ByteCompiler<'ctx> {
             flags: Cell::new(self.code_block_flags),
             ic: self.ic.into_boxed_slice(),
             source_text_spanned: self.spanned_source_text,
+            source_map: SourceMap::new(self.source_map_entries.into_boxed_slice()),
         }
     }
 
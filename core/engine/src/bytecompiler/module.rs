@@ -1,14 +1,27 @@
-use super::{ByteCompiler, Literal, ToJsString};
+use super::{declaration::dispose_hint, ByteCompiler, Literal, ToJsString};
 use crate::vm::opcode::BindingOpcode;
-use boa_ast::{declaration::ExportDeclaration, ModuleItem, ModuleItemList};
+use boa_ast::{declaration::ExportDeclaration, ModuleItem, ModuleItemList, Span, Spanned};
 use boa_interner::Sym;
 
 impl ByteCompiler<'_> {
     /// Compiles a [`ModuleItemList`].
     #[inline]
     pub fn compile_module_item_list(&mut self, list: &ModuleItemList) {
-        for node in list.items() {
-            self.compile_module_item(node);
+        let hint = dispose_hint(list.items().iter().filter_map(|item| match item {
+            ModuleItem::StatementListItem(item) => Some(item),
+            _ => None,
+        }));
+
+        if let Some(hint) = hint {
+            self.compile_dispose_scope(hint, false, |compiler| {
+                for node in list.items() {
+                    compiler.compile_module_item(node);
+                }
+            });
+        } else {
+            for node in list.items() {
+                self.compile_module_item(node);
+            }
         }
     }
 
@@ -39,12 +52,21 @@ impl ByteCompiler<'_> {
                     | ExportDeclaration::DefaultAsyncGeneratorDeclaration(_) => {
                         // Already instantiated in `initialize_environment`.
                     }
-                    ExportDeclaration::VarStatement(var) => self.compile_var_decl(var),
+                    ExportDeclaration::VarStatement(var) => {
+                        let previous_position = self.set_source_position(
+                            var.span().map(Span::start).or(self.source_position),
+                        );
+                        self.compile_var_decl(var);
+                        self.set_source_position(previous_position);
+                    }
                     ExportDeclaration::Declaration(decl) => self.compile_decl(decl, false),
                     ExportDeclaration::DefaultClassDeclaration(cl) => {
                         self.compile_class(cl.into(), None);
                     }
                     ExportDeclaration::DefaultAssignmentExpression(expr) => {
+                        let previous_position = self.set_source_position(
+                            expr.span().map(Span::start).or(self.source_position),
+                        );
                         let function = self.register_allocator.alloc();
                         self.compile_expr(expr, &function);
 
@@ -66,6 +88,7 @@ impl ByteCompiler<'_> {
                         let name = Sym::DEFAULT_EXPORT.to_js_string(self.interner());
                         self.emit_binding(BindingOpcode::InitLexical, name, &function);
                         self.register_allocator.dealloc(function);
+                        self.set_source_position(previous_position);
                     }
                 }
             }
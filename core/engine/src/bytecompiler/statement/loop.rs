@@ -10,7 +10,8 @@ use boa_ast::{
 use boa_interner::Sym;
 
 use crate::{
-    bytecompiler::{Access, BindingAccessOpcode, ByteCompiler, ToJsString},
+    builtins::disposable_stack::DisposeHint,
+    bytecompiler::{Access, BindingAccessOpcode, ByteCompiler, Register, ToJsString},
     vm::opcode::BindingOpcode,
 };
 
@@ -21,6 +22,24 @@ impl ByteCompiler<'_> {
         label: Option<Sym>,
         use_expr: bool,
     ) {
+        if let Some(ForLoopInitializer::Lexical(decl)) = for_loop.init() {
+            if decl.declaration().is_using() {
+                let hint = if decl.declaration().is_await_using() {
+                    DisposeHint::Async
+                } else {
+                    DisposeHint::Sync
+                };
+                self.compile_dispose_scope(hint, use_expr, |compiler| {
+                    compiler.compile_for_loop_impl(for_loop, label, use_expr);
+                });
+                return;
+            }
+        }
+
+        self.compile_for_loop_impl(for_loop, label, use_expr);
+    }
+
+    fn compile_for_loop_impl(&mut self, for_loop: &ForLoop, label: Option<Sym>, use_expr: bool) {
         let mut let_binding_indices = None;
         let mut outer_scope_local = None;
         let mut outer_scope = None;
@@ -221,6 +240,9 @@ impl ByteCompiler<'_> {
             IterableLoopInitializer::Pattern(pattern) => {
                 self.compile_declaration_pattern(pattern, BindingOpcode::SetName, &value);
             }
+            IterableLoopInitializer::Using(_) | IterableLoopInitializer::AwaitUsing(_) => {
+                unreachable!("using declarations are not allowed in for-in loops")
+            }
         }
 
         self.register_allocator.dealloc(value);
@@ -295,7 +317,54 @@ impl ByteCompiler<'_> {
         let outer_scope = self.push_declarative_scope(for_of_loop.scope());
         let handler_index = self.push_handler();
 
-        match for_of_loop.initializer() {
+        let hint = match for_of_loop.initializer() {
+            IterableLoopInitializer::Using(_) => Some(DisposeHint::Sync),
+            IterableLoopInitializer::AwaitUsing(_) => Some(DisposeHint::Async),
+            _ => None,
+        };
+        if let Some(hint) = hint {
+            self.compile_dispose_scope(hint, use_expr, |compiler| {
+                compiler.compile_for_of_initializer(for_of_loop.initializer(), value);
+                compiler.compile_stmt(for_of_loop.body(), use_expr, true);
+            });
+        } else {
+            self.compile_for_of_initializer(for_of_loop.initializer(), value);
+            self.compile_stmt(for_of_loop.body(), use_expr, true);
+        }
+
+        {
+            let exit = self.jump();
+            self.patch_handler(handler_index);
+
+            let error = self.register_allocator.alloc();
+            self.bytecode.emit_exception(error.variable());
+
+            // NOTE: Capture throw of the iterator close and ignore it.
+            let handler_index = self.push_handler();
+            self.iterator_close(for_of_loop.r#await());
+            self.patch_handler(handler_index);
+
+            self.bytecode.emit_throw(error.variable());
+            self.register_allocator.dealloc(error);
+            self.patch_jump(exit);
+        }
+
+        self.pop_declarative_scope(outer_scope);
+        self.bytecode.emit_jump(start_address);
+
+        self.patch_jump(exit);
+        self.pop_loop_control_info();
+
+        self.iterator_close(for_of_loop.r#await());
+    }
+
+    /// Binds the value of the current iteration of a `for...of` loop to its initializer.
+    fn compile_for_of_initializer(
+        &mut self,
+        initializer: &IterableLoopInitializer,
+        value: Register,
+    ) {
+        match initializer {
             IterableLoopInitializer::Identifier(ref ident) => {
                 let ident = ident.to_js_string(self.interner());
                 match self.lexical_scope.set_mutable_binding(ident.clone()) {
@@ -339,36 +408,23 @@ impl ByteCompiler<'_> {
             IterableLoopInitializer::Pattern(pattern) => {
                 self.compile_declaration_pattern(pattern, BindingOpcode::SetName, &value);
             }
+            IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => {
+                let Binding::Identifier(ident) = declaration else {
+                    unreachable!("using declarations cannot have binding patterns");
+                };
+                if matches!(initializer, IterableLoopInitializer::AwaitUsing(_)) {
+                    self.bytecode
+                        .emit_add_async_disposable_resource(value.variable());
+                } else {
+                    self.bytecode.emit_add_disposable_resource(value.variable());
+                }
+                let ident = ident.to_js_string(self.interner());
+                self.emit_binding(BindingOpcode::InitLexical, ident, &value);
+            }
         }
 
         self.register_allocator.dealloc(value);
-
-        self.compile_stmt(for_of_loop.body(), use_expr, true);
-
-        {
-            let exit = self.jump();
-            self.patch_handler(handler_index);
-
-            let error = self.register_allocator.alloc();
-            self.bytecode.emit_exception(error.variable());
-
-            // NOTE: Capture throw of the iterator close and ignore it.
-            let handler_index = self.push_handler();
-            self.iterator_close(for_of_loop.r#await());
-            self.patch_handler(handler_index);
-
-            self.bytecode.emit_throw(error.variable());
-            self.register_allocator.dealloc(error);
-            self.patch_jump(exit);
-        }
-
-        self.pop_declarative_scope(outer_scope);
-        self.bytecode.emit_jump(start_address);
-
-        self.patch_jump(exit);
-        self.pop_loop_control_info();
-
-        self.iterator_close(for_of_loop.r#await());
     }
 
     pub(crate) fn compile_while_loop(
@@ -1,6 +1,6 @@
 use super::jump_control::{JumpRecord, JumpRecordAction, JumpRecordKind};
 use crate::bytecompiler::ByteCompiler;
-use boa_ast::Statement;
+use boa_ast::{Span, Spanned, Statement};
 
 mod block;
 mod r#break;
@@ -14,7 +14,13 @@ mod with;
 
 impl ByteCompiler<'_> {
     /// Compiles a [`Statement`] `boa_ast` node.
+    ///
+    /// The instructions of the statement are mapped to its start in the source map, unless a
+    /// nested node records a more precise position.
     pub fn compile_stmt(&mut self, node: &Statement, use_expr: bool, root_statement: bool) {
+        let previous_position =
+            self.set_source_position(node.span().map(Span::start).or(self.source_position));
+
         match node {
             Statement::Var(var) => self.compile_var_decl(var),
             Statement::If(node) => self.compile_if(node, use_expr),
@@ -58,10 +64,13 @@ impl ByteCompiler<'_> {
                 self.compile_break(*node, use_expr);
             }
             Statement::Throw(throw) => {
+                let previous_position =
+                    self.set_source_position(throw.position().or(self.source_position));
                 let error = self.register_allocator.alloc();
                 self.compile_expr(throw.target(), &error);
                 self.bytecode.emit_throw(error.variable());
                 self.register_allocator.dealloc(error);
+                self.set_source_position(previous_position);
             }
             Statement::Switch(switch) => {
                 self.compile_switch(switch, use_expr);
@@ -98,8 +107,11 @@ impl ByteCompiler<'_> {
                 self.register_allocator.dealloc(value);
             }
             Statement::With(with) => self.compile_with(with, use_expr),
-            Statement::Empty => {}
+            Statement::Empty(_) => {}
+            Statement::Error(_) => self.emit_syntax_error("invalid statement"),
         }
+
+        self.set_source_position(previous_position);
     }
 
     pub(crate) fn r#return(&mut self, return_value_on_stack: bool) {
@@ -158,6 +158,24 @@ pub trait HostHooks {
         Ok(())
     }
 
+    /// [`HostInitializeShadowRealm ( realm )`][spec]
+    ///
+    /// Called after the global bindings of the realm of a new `ShadowRealm` object are set up,
+    /// which allows hosts to add their own properties to its global object.
+    ///
+    /// # Requirements
+    ///
+    /// - If the returned Completion Record is a normal completion, it must be a normal completion
+    ///   containing unused. This is already ensured by the return type.
+    /// - Any property added to the global object must be configurable.
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-hostinitializeshadowrealm
+    #[cfg(feature = "experimental")]
+    fn initialize_shadow_realm(&self, _realm: &Realm, _context: &mut Context) -> JsResult<()> {
+        // The default implementation of HostInitializeShadowRealm is to return NormalCompletion(unused).
+        Ok(())
+    }
+
     /// Creates the global object of a new [`Context`] from the initial intrinsics.
     ///
     /// Equivalent to the step 7 of [`InitializeHostDefinedRealm ( )`][ihdr].
@@ -217,6 +235,20 @@ pub trait HostHooks {
     fn max_buffer_size(&self, _context: &mut Context) -> u64 {
         1_610_612_736 // 1.5 GiB
     }
+
+    /// Called when an allocation would make the heap exceed the heap size limit of the
+    /// [`RuntimeLimits`] of the context, even after collecting the unreachable values.
+    ///
+    /// `used` is the size the heap would have after the allocation, and `limit` is the current
+    /// heap size limit. The returned value is the new heap size limit. If the new limit is still
+    /// lower than `used`, the allocation throws a `RangeError`.
+    ///
+    /// By default, this returns `limit`, so the allocation always throws.
+    ///
+    /// [`RuntimeLimits`]: crate::vm::RuntimeLimits
+    fn near_heap_limit(&self, _used: usize, limit: usize, _context: &mut Context) -> usize {
+        limit
+    }
 }
 
 /// Default implementation of [`HostHooks`], which doesn't carry any state.
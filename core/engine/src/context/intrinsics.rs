@@ -42,10 +42,11 @@ impl Intrinsics {
     pub(crate) fn uninit(root_shape: &RootShape) -> Option<Self> {
         let constructors = StandardConstructors::default();
         let templates = ObjectTemplates::new(root_shape, &constructors);
+        let objects = IntrinsicObjects::uninit(&constructors)?;
 
         Some(Self {
             constructors,
-            objects: IntrinsicObjects::uninit()?,
+            objects,
             templates,
         })
     }
@@ -67,6 +68,144 @@ impl Intrinsics {
     pub(crate) const fn templates(&self) -> &ObjectTemplates {
         &self.templates
     }
+
+    /// Returns all the intrinsic objects of the realm, in a stable order.
+    ///
+    /// This includes intrinsics that cannot be reached from the global object, like the
+    /// `%ThrowTypeError%` function or the `%GeneratorFunction%` constructor.
+    pub(crate) fn all_objects(&self) -> Vec<JsObject> {
+        let c = &self.constructors;
+        let constructors = [
+            &c.object,
+            &c.proxy,
+            &c.date,
+            &c.function,
+            &c.async_function,
+            &c.generator_function,
+            &c.async_generator_function,
+            &c.array,
+            &c.iterator,
+            &c.bigint,
+            &c.number,
+            &c.boolean,
+            &c.string,
+            &c.regexp,
+            &c.symbol,
+            &c.error,
+            &c.type_error,
+            &c.reference_error,
+            &c.range_error,
+            &c.syntax_error,
+            &c.eval_error,
+            &c.uri_error,
+            &c.aggregate_error,
+            &c.suppressed_error,
+            &c.map,
+            &c.set,
+            &c.typed_array,
+            &c.typed_int8_array,
+            &c.typed_uint8_array,
+            &c.typed_uint8clamped_array,
+            &c.typed_int16_array,
+            &c.typed_uint16_array,
+            &c.typed_int32_array,
+            &c.typed_uint32_array,
+            &c.typed_bigint64_array,
+            &c.typed_biguint64_array,
+            &c.typed_float16_array,
+            &c.typed_float32_array,
+            &c.typed_float64_array,
+            &c.array_buffer,
+            &c.shared_array_buffer,
+            &c.data_view,
+            &c.date_time_format,
+            &c.promise,
+            &c.weak_ref,
+            &c.weak_map,
+            &c.weak_set,
+            &c.finalization_registry,
+            &c.disposable_stack,
+            &c.async_disposable_stack,
+        ]
+        .into_iter();
+        #[cfg(feature = "experimental")]
+        let constructors = constructors.chain([&c.shadow_realm]);
+        #[cfg(feature = "intl")]
+        let constructors = constructors.chain([
+            &c.collator,
+            &c.list_format,
+            &c.locale,
+            &c.segmenter,
+            &c.plural_rules,
+            &c.number_format,
+            &c.relative_time_format,
+            &c.display_names,
+            &c.duration_format,
+        ]);
+        #[cfg(feature = "temporal")]
+        let constructors = constructors.chain([
+            &c.instant,
+            &c.plain_date_time,
+            &c.plain_date,
+            &c.plain_time,
+            &c.plain_year_month,
+            &c.plain_month_day,
+            &c.time_zone,
+            &c.duration,
+            &c.zoned_date_time,
+            &c.calendar,
+        ]);
+
+        let mut objects = constructors
+            .flat_map(|c| [c.constructor(), c.prototype()])
+            .collect::<Vec<_>>();
+
+        let o = &self.objects;
+        let iterators = &o.iterator_prototypes;
+        objects.extend([
+            o.reflect.clone(),
+            o.math.clone(),
+            o.json.clone(),
+            o.throw_type_error.clone().into(),
+            o.array_prototype_values.clone().into(),
+            o.array_prototype_to_string.clone().into(),
+            iterators.iterator(),
+            iterators.iterator_helper(),
+            iterators.wrap_for_valid_iterator(),
+            iterators.async_iterator(),
+            iterators.async_from_sync_iterator(),
+            iterators.array(),
+            iterators.set(),
+            iterators.string(),
+            iterators.regexp_string(),
+            iterators.map(),
+            iterators.for_in(),
+            o.generator.clone(),
+            o.async_generator.clone(),
+            o.atomics.clone(),
+            o.eval.clone().into(),
+            o.uri_functions.decode_uri().into(),
+            o.uri_functions.decode_uri_component().into(),
+            o.uri_functions.encode_uri().into(),
+            o.uri_functions.encode_uri_component().into(),
+            o.is_finite.clone().into(),
+            o.is_nan.clone().into(),
+            o.parse_float.clone().into(),
+            o.parse_int.clone().into(),
+        ]);
+        #[cfg(feature = "annex-b")]
+        objects.extend([o.escape.clone().into(), o.unescape.clone().into()]);
+        #[cfg(feature = "intl")]
+        objects.extend([
+            iterators.segment(),
+            o.intl.clone().upcast(),
+            o.segments_prototype.clone(),
+        ]);
+        #[cfg(feature = "temporal")]
+        objects.extend([o.temporal.clone(), o.now.clone()]);
+
+        objects
+    }
 }
 
 /// Stores a constructor (such as `Object`) and its corresponding prototype.
@@ -132,6 +271,7 @@ pub struct StandardConstructors {
     generator_function: StandardConstructor,
     async_generator_function: StandardConstructor,
     array: StandardConstructor,
+    iterator: StandardConstructor,
     bigint: StandardConstructor,
     number: StandardConstructor,
     boolean: StandardConstructor,
@@ -146,6 +286,7 @@ pub struct StandardConstructors {
     eval_error: StandardConstructor,
     uri_error: StandardConstructor,
     aggregate_error: StandardConstructor,
+    suppressed_error: StandardConstructor,
     map: StandardConstructor,
     set: StandardConstructor,
     typed_array: StandardConstructor,
@@ -158,6 +299,7 @@ pub struct StandardConstructors {
     typed_uint32_array: StandardConstructor,
     typed_bigint64_array: StandardConstructor,
     typed_biguint64_array: StandardConstructor,
+    typed_float16_array: StandardConstructor,
     typed_float32_array: StandardConstructor,
     typed_float64_array: StandardConstructor,
     array_buffer: StandardConstructor,
@@ -168,6 +310,11 @@ pub struct StandardConstructors {
     weak_ref: StandardConstructor,
     weak_map: StandardConstructor,
     weak_set: StandardConstructor,
+    finalization_registry: StandardConstructor,
+    disposable_stack: StandardConstructor,
+    async_disposable_stack: StandardConstructor,
+    #[cfg(feature = "experimental")]
+    shadow_realm: StandardConstructor,
     #[cfg(feature = "intl")]
     collator: StandardConstructor,
     #[cfg(feature = "intl")]
@@ -180,6 +327,12 @@ pub struct StandardConstructors {
     plural_rules: StandardConstructor,
     #[cfg(feature = "intl")]
     number_format: StandardConstructor,
+    #[cfg(feature = "intl")]
+    relative_time_format: StandardConstructor,
+    #[cfg(feature = "intl")]
+    display_names: StandardConstructor,
+    #[cfg(feature = "intl")]
+    duration_format: StandardConstructor,
     #[cfg(feature = "temporal")]
     instant: StandardConstructor,
     #[cfg(feature = "temporal")]
@@ -219,6 +372,7 @@ impl Default for StandardConstructors {
             async_function: StandardConstructor::default(),
             generator_function: StandardConstructor::default(),
             array: StandardConstructor::with_prototype(JsObject::from_proto_and_data(None, Array)),
+            iterator: StandardConstructor::default(),
             bigint: StandardConstructor::default(),
             number: StandardConstructor::with_prototype(JsObject::from_proto_and_data(None, 0.0)),
             boolean: StandardConstructor::with_prototype(JsObject::from_proto_and_data(
@@ -238,6 +392,7 @@ impl Default for StandardConstructors {
             eval_error: StandardConstructor::default(),
             uri_error: StandardConstructor::default(),
             aggregate_error: StandardConstructor::default(),
+            suppressed_error: StandardConstructor::default(),
             map: StandardConstructor::default(),
             set: StandardConstructor::default(),
             typed_array: StandardConstructor::default(),
@@ -250,6 +405,7 @@ impl Default for StandardConstructors {
             typed_uint32_array: StandardConstructor::default(),
             typed_bigint64_array: StandardConstructor::default(),
             typed_biguint64_array: StandardConstructor::default(),
+            typed_float16_array: StandardConstructor::default(),
             typed_float32_array: StandardConstructor::default(),
             typed_float64_array: StandardConstructor::default(),
             array_buffer: StandardConstructor::default(),
@@ -260,6 +416,11 @@ impl Default for StandardConstructors {
             weak_ref: StandardConstructor::default(),
             weak_map: StandardConstructor::default(),
             weak_set: StandardConstructor::default(),
+            finalization_registry: StandardConstructor::default(),
+            disposable_stack: StandardConstructor::default(),
+            async_disposable_stack: StandardConstructor::default(),
+            #[cfg(feature = "experimental")]
+            shadow_realm: StandardConstructor::default(),
             #[cfg(feature = "intl")]
             collator: StandardConstructor::default(),
             #[cfg(feature = "intl")]
@@ -272,6 +433,12 @@ impl Default for StandardConstructors {
             plural_rules: StandardConstructor::default(),
             #[cfg(feature = "intl")]
             number_format: StandardConstructor::default(),
+            #[cfg(feature = "intl")]
+            relative_time_format: StandardConstructor::default(),
+            #[cfg(feature = "intl")]
+            display_names: StandardConstructor::default(),
+            #[cfg(feature = "intl")]
+            duration_format: StandardConstructor::default(),
             #[cfg(feature = "temporal")]
             instant: StandardConstructor::default(),
             #[cfg(feature = "temporal")]
@@ -393,6 +560,18 @@ impl StandardConstructors {
         &self.array
     }
 
+    /// Returns the `Iterator` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator-constructor
+    #[inline]
+    #[must_use]
+    pub const fn iterator(&self) -> &StandardConstructor {
+        &self.iterator
+    }
+
     /// Returns the `BigInt` constructor.
     ///
     /// More information:
@@ -561,6 +740,18 @@ impl StandardConstructors {
         &self.aggregate_error
     }
 
+    /// Returns the `SuppressedError` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-constructor
+    #[inline]
+    #[must_use]
+    pub const fn suppressed_error(&self) -> &StandardConstructor {
+        &self.suppressed_error
+    }
+
     /// Returns the `Map` constructor.
     ///
     /// More information:
@@ -705,6 +896,18 @@ impl StandardConstructors {
         &self.typed_biguint64_array
     }
 
+    /// Returns the `Float16Array` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarray-constructors
+    #[inline]
+    #[must_use]
+    pub const fn typed_float16_array(&self) -> &StandardConstructor {
+        &self.typed_float16_array
+    }
+
     /// Returns the `Float32Array` constructor.
     ///
     /// More information:
@@ -825,6 +1028,55 @@ impl StandardConstructors {
         &self.weak_set
     }
 
+    /// Returns the `DisposableStack` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-constructor
+    #[inline]
+    #[must_use]
+    pub const fn disposable_stack(&self) -> &StandardConstructor {
+        &self.disposable_stack
+    }
+
+    /// Returns the `AsyncDisposableStack` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack-constructor
+    #[inline]
+    #[must_use]
+    pub const fn async_disposable_stack(&self) -> &StandardConstructor {
+        &self.async_disposable_stack
+    }
+
+    /// Returns the `FinalizationRegistry` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry-constructor
+    #[inline]
+    #[must_use]
+    pub const fn finalization_registry(&self) -> &StandardConstructor {
+        &self.finalization_registry
+    }
+
+    /// Returns the `ShadowRealm` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm-constructor
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "experimental")]
+    pub const fn shadow_realm(&self) -> &StandardConstructor {
+        &self.shadow_realm
+    }
+
     /// Returns the `Intl.Collator` constructor.
     ///
     /// More information:
@@ -903,6 +1155,45 @@ impl StandardConstructors {
         &self.number_format
     }
 
+    /// Returns the `Intl.RelativeTimeFormat` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl-relativetimeformat-constructor
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "intl")]
+    pub const fn relative_time_format(&self) -> &StandardConstructor {
+        &self.relative_time_format
+    }
+
+    /// Returns the `Intl.DisplayNames` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl-displaynames-constructor
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "intl")]
+    pub const fn display_names(&self) -> &StandardConstructor {
+        &self.display_names
+    }
+
+    /// Returns the `Intl.DurationFormat` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl-durationformat-constructor
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "intl")]
+    pub const fn duration_format(&self) -> &StandardConstructor {
+        &self.duration_format
+    }
+
     /// Returns the `Temporal.Instant` constructor.
     ///
     /// More information:
@@ -1119,7 +1410,7 @@ impl IntrinsicObjects {
     ///
     /// [`Realm::initialize`]: crate::realm::Realm::initialize
     #[allow(clippy::unnecessary_wraps)]
-    pub(crate) fn uninit() -> Option<Self> {
+    pub(crate) fn uninit(constructors: &StandardConstructors) -> Option<Self> {
         Some(Self {
             reflect: JsObject::default(),
             math: JsObject::default(),
@@ -1127,7 +1418,7 @@ impl IntrinsicObjects {
             throw_type_error: JsFunction::empty_intrinsic_function(false),
             array_prototype_values: JsFunction::empty_intrinsic_function(false),
             array_prototype_to_string: JsFunction::empty_intrinsic_function(false),
-            iterator_prototypes: IteratorPrototypes::default(),
+            iterator_prototypes: IteratorPrototypes::new(constructors.iterator().prototype()),
             generator: JsObject::default(),
             async_generator: JsObject::default(),
             atomics: JsObject::default(),
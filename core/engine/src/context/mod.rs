@@ -4,6 +4,7 @@ use std::cell::RefCell;
 use std::{cell::Cell, path::Path, rc::Rc};
 
 use boa_ast::StatementList;
+use boa_gc::WeakGc;
 use boa_interner::Interner;
 use boa_parser::source::ReadChar;
 use boa_profiler::Profiler;
@@ -15,19 +16,20 @@ use intrinsics::Intrinsics;
 use temporal_rs::tzdb::FsTzdbProvider;
 
 use crate::job::Job;
-use crate::vm::RuntimeLimits;
+use crate::vm::{InterruptHandle, InterruptHandler, RuntimeLimits};
 use crate::{
-    builtins,
+    builtins::{self, weak::FinalizationRegistry},
     class::{Class, ClassBuilder},
     job::{JobExecutor, SimpleJobExecutor},
     js_string,
     module::{IdleModuleLoader, ModuleLoader, SimpleModuleLoader},
     native_function::NativeFunction,
-    object::{shape::RootShape, FunctionObjectBuilder, JsObject},
+    object::{shape::RootShape, ErasedVTableObject, FunctionObjectBuilder, JsObject},
     optimizer::{Optimizer, OptimizerOptions, OptimizerStatistics},
     property::{Attribute, PropertyDescriptor, PropertyKey},
     realm::Realm,
     script::Script,
+    snapshot::{HostObjects, Snapshot},
     vm::{ActiveRunnable, CallFrame, Vm},
     HostDefined, JsNativeError, JsResult, JsString, JsValue, NativeObject, Source,
 };
@@ -106,6 +108,9 @@ pub struct Context {
 
     pub(crate) kept_alive: Vec<JsObject>,
 
+    /// Weak references to all the `FinalizationRegistry` objects created in this context.
+    pub(crate) finalization_registries: Vec<WeakGc<ErasedVTableObject>>,
+
     can_block: bool,
 
     #[cfg(feature = "temporal")]
@@ -123,6 +128,8 @@ pub struct Context {
 
     module_loader: Rc<dyn ModuleLoader>,
 
+    pub(crate) interrupt_handler: Option<Rc<dyn InterruptHandler>>,
+
     optimizer_options: OptimizerOptions,
     root_shape: RootShape,
 
@@ -145,6 +152,10 @@ impl std::fmt::Debug for Context {
             .field("hooks", &"HostHooks")
             .field("clock", &"Clock")
             .field("module_loader", &"ModuleLoader")
+            .field(
+                "interrupt_handler",
+                &self.interrupt_handler.as_ref().map(|_| "InterruptHandler"),
+            )
             .field("optimizer_options", &self.optimizer_options);
 
         #[cfg(feature = "intl")]
@@ -483,8 +494,12 @@ impl Context {
     }
 
     /// Runs all the jobs with the provided job executor.
+    ///
+    /// This also enqueues the cleanup jobs of the `FinalizationRegistry` objects whose registered
+    /// targets were collected.
     #[inline]
     pub fn run_jobs(&mut self) -> JsResult<()> {
+        FinalizationRegistry::enqueue_cleanup_jobs(self);
         let result = self.job_executor().run_jobs(self);
         self.clear_kept_objects();
         result
@@ -499,6 +514,7 @@ impl Context {
     /// provide a custom implementatin of `JobExecutor` to the context.
     #[allow(clippy::future_not_send)]
     pub async fn run_jobs_async(&mut self) -> JsResult<()> {
+        FinalizationRegistry::enqueue_cleanup_jobs(self);
         let result = self
             .job_executor()
             .run_jobs_async(&RefCell::new(self))
@@ -558,6 +574,56 @@ impl Context {
         Ok(self.enter_realm(old_realm))
     }
 
+    /// Creates a [`Snapshot`] of the current realm, which can be restored into a new context
+    /// with [`ContextBuilder::snapshot`].
+    ///
+    /// Snapshots are limited to objects that can be recreated from their internal slots. Promises,
+    /// proxies and generators are not supported, and native functions of the host must be
+    /// registered as host objects with [`Context::snapshot_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the global bindings of the realm can reach an object that cannot
+    /// be stored in a snapshot. See the [`snapshot`][crate::snapshot] module for the objects that
+    /// can be stored.
+    pub fn snapshot(&mut self) -> JsResult<Snapshot> {
+        Snapshot::create(self, &HostObjects::default())
+    }
+
+    /// Creates a [`Snapshot`] of the current realm, storing the objects of `host_objects` only by
+    /// their identifier.
+    ///
+    /// The snapshot must be restored with [`Context::restore_snapshot`], which provides the host
+    /// objects of the new context.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the global bindings of the realm can reach an object that cannot
+    /// be stored in a snapshot and is not a host object.
+    pub fn snapshot_with(&mut self, host_objects: &HostObjects) -> JsResult<Snapshot> {
+        Snapshot::create(self, host_objects)
+    }
+
+    /// Restores a [`Snapshot`] created by [`Context::snapshot_with`] into this context, replacing
+    /// its host objects by the objects of `host_objects` with the same identifier.
+    ///
+    /// This must be called before any code is evaluated in the context, and before any property
+    /// of its global object or built-in objects is changed, since the snapshot refers to the
+    /// built-in objects by their position in a new realm. Host objects can be created before
+    /// restoring the snapshot, as long as they are not added to the global object.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the snapshot is invalid, was created by a context with different
+    /// built-in objects, or requires a host object that is not in `host_objects`.
+    pub fn restore_snapshot(
+        &mut self,
+        snapshot: &Snapshot,
+        host_objects: &HostObjects,
+    ) -> JsResult<()> {
+        snapshot.restore(self, host_objects)
+    }
+
     /// Get the [`RootShape`].
     #[inline]
     #[must_use]
@@ -611,6 +677,41 @@ impl Context {
         &mut self.vm.runtime_limits
     }
 
+    /// Gets the remaining fuel of the context, or [`None`] if the execution is not limited.
+    #[inline]
+    #[must_use]
+    pub const fn fuel(&self) -> Option<u64> {
+        self.vm.fuel
+    }
+
+    /// Sets the remaining fuel of the context.
+    ///
+    /// Each executed instruction consumes one unit of fuel. When the fuel is exhausted, the
+    /// [`InterruptHandler`] of the context is called, which can give more fuel to the context,
+    /// pause or terminate the execution. If the context doesn't have an interrupt handler, or the
+    /// handler doesn't give more fuel, an uncatchable
+    /// [`RuntimeLimit`][crate::JsNativeErrorKind::RuntimeLimit] error is thrown.
+    ///
+    /// Setting the fuel to [`None`] removes the limit.
+    #[inline]
+    pub fn set_fuel(&mut self, fuel: Option<u64>) {
+        self.vm.fuel = fuel;
+    }
+
+    /// Gets a thread-safe [`InterruptHandle`] to terminate, pause or interrupt the execution of
+    /// the context from other threads.
+    #[inline]
+    #[must_use]
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        self.vm.interrupt.clone()
+    }
+
+    /// Sets the [`InterruptHandler`] of the context, replacing the previous one.
+    #[inline]
+    pub fn set_interrupt_handler<H: InterruptHandler + 'static>(&mut self, handler: Rc<H>) {
+        self.interrupt_handler = Some(handler);
+    }
+
     /// Returns `true` if this context can be suspended by an `Atomics.wait` call.
     #[inline]
     #[must_use]
@@ -917,11 +1018,14 @@ pub struct ContextBuilder {
     clock: Option<Rc<dyn Clock>>,
     job_executor: Option<Rc<dyn JobExecutor>>,
     module_loader: Option<Rc<dyn ModuleLoader>>,
+    interrupt_handler: Option<Rc<dyn InterruptHandler>>,
+    fuel: Option<u64>,
     can_block: bool,
     #[cfg(feature = "intl")]
     icu: Option<icu::IntlProvider>,
     #[cfg(feature = "fuzz")]
     instructions_remaining: usize,
+    snapshot: Option<Snapshot>,
 }
 
 impl std::fmt::Debug for ContextBuilder {
@@ -934,6 +1038,8 @@ impl std::fmt::Debug for ContextBuilder {
         struct Clock;
         #[derive(Clone, Copy, Debug)]
         struct ModuleLoader;
+        #[derive(Clone, Copy, Debug)]
+        struct InterruptHandler;
 
         let mut out = f.debug_struct("ContextBuilder");
 
@@ -948,7 +1054,13 @@ impl std::fmt::Debug for ContextBuilder {
                 "module_loader",
                 &self.module_loader.as_ref().map(|_| ModuleLoader),
             )
-            .field("can_block", &self.can_block);
+            .field(
+                "interrupt_handler",
+                &self.interrupt_handler.as_ref().map(|_| InterruptHandler),
+            )
+            .field("fuel", &self.fuel)
+            .field("can_block", &self.can_block)
+            .field("snapshot", &self.snapshot);
 
         #[cfg(feature = "intl")]
         out.field("icu", &self.icu);
@@ -1043,6 +1155,22 @@ impl ContextBuilder {
         self
     }
 
+    /// Initializes the [`InterruptHandler`] for the context.
+    #[must_use]
+    pub fn interrupt_handler<H: InterruptHandler + 'static>(mut self, handler: Rc<H>) -> Self {
+        self.interrupt_handler = Some(handler);
+        self
+    }
+
+    /// Specifies the initial fuel of the [`Context`].
+    ///
+    /// See [`Context::set_fuel`] for more information.
+    #[must_use]
+    pub const fn fuel(mut self, fuel: u64) -> Self {
+        self.fuel = Some(fuel);
+        self
+    }
+
     /// [`AgentCanSuspend ( )`][spec] aka `[[CanBlock]]`
     ///
     /// Defines if this context can be suspended by calls to the [`Atomics.wait`][wait] function.
@@ -1061,6 +1189,17 @@ impl ContextBuilder {
         self
     }
 
+    /// Restores a [`Snapshot`] into the [`Context`] after it is built.
+    ///
+    /// The snapshot must have been created by a context with the same [`HostHooks`] and built-in
+    /// objects, otherwise building the context returns an error. Snapshots that store host
+    /// objects must be restored with [`Context::restore_snapshot`] instead.
+    #[must_use]
+    pub fn snapshot(mut self, snapshot: Snapshot) -> Self {
+        self.snapshot = Some(snapshot);
+        self
+    }
+
     /// Specifies the number of instructions remaining to the [`Context`].
     ///
     /// This function is only available if the `fuzz` feature is enabled.
@@ -1132,10 +1271,12 @@ impl ContextBuilder {
             #[cfg(feature = "fuzz")]
             instructions_remaining: self.instructions_remaining,
             kept_alive: Vec::new(),
+            finalization_registries: Vec::new(),
             host_hooks,
             clock,
             job_executor,
             module_loader,
+            interrupt_handler: self.interrupt_handler,
             optimizer_options: OptimizerOptions::OPTIMIZE_ALL,
             root_shape,
             parser_identifier: 0,
@@ -1145,6 +1286,13 @@ impl ContextBuilder {
 
         builtins::set_default_global_bindings(&mut context)?;
 
+        if let Some(snapshot) = &self.snapshot {
+            snapshot.restore(&mut context, &HostObjects::default())?;
+        }
+
+        // The fuel is only consumed by the code of the user.
+        context.vm.fuel = self.fuel;
+
         Ok(context)
     }
 }
@@ -27,8 +27,8 @@
 mod runtime;
 
 pub(crate) use runtime::{
-    DeclarativeEnvironment, Environment, EnvironmentStack, FunctionSlots, PrivateEnvironment,
-    ThisBindingStatus,
+    DeclarativeEnvironment, DeclarativeEnvironmentKind, Environment, EnvironmentStack,
+    FunctionEnvironment, FunctionSlots, LexicalEnvironment, PrivateEnvironment, ThisBindingStatus,
 };
 
 #[cfg(test)]
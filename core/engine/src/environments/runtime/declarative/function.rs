@@ -213,6 +213,11 @@ impl FunctionSlots {
         }
     }
 
+    /// Returns the values of the `[[ThisValue]]` and `[[ThisBindingStatus]]` internal slots.
+    pub(crate) fn this(&self) -> ThisBindingStatus {
+        self.this.borrow().clone()
+    }
+
     /// Returns the value of the `[[FunctionObject]]` internal slot.
     pub(crate) const fn function_object(&self) -> &JsObject {
         &self.function_object
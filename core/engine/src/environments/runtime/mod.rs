@@ -73,6 +73,16 @@ impl EnvironmentStack {
         &self.global
     }
 
+    /// Gets the environments of the stack, excluding the global environment.
+    pub(crate) fn environments(&self) -> &[Environment] {
+        &self.stack
+    }
+
+    /// Gets the private environments of the stack.
+    pub(crate) fn private_environments(&self) -> &[Gc<PrivateEnvironment>] {
+        &self.private_stack
+    }
+
     /// Gets the next outer function environment.
     pub(crate) fn outer_function_environment(&self) -> Option<(Gc<DeclarativeEnvironment>, Scope)> {
         for env in self
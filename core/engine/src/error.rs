@@ -6,6 +6,7 @@ use crate::{
     object::JsObject,
     property::PropertyDescriptor,
     realm::Realm,
+    vm::{SourceLocation, StackFrame},
     Context, JsString, JsValue,
 };
 use boa_gc::{custom_trace, Finalize, Trace};
@@ -187,12 +188,27 @@ macro_rules! js_error {
 /// let kind = &native_error.as_native().unwrap().kind;
 /// assert!(matches!(kind, JsNativeErrorKind::Type));
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Trace, Finalize)]
+#[derive(Debug, Clone, Trace, Finalize)]
 #[boa_gc(unsafe_no_drop)]
 pub struct JsError {
     inner: Repr,
+
+    /// The backtrace captured when an opaque error was thrown.
+    ///
+    /// Native errors store their backtrace in the [`JsNativeError`] itself.
+    #[unsafe_ignore_trace]
+    backtrace: Option<Box<[StackFrame]>>,
 }
 
+// The backtrace is not compared, since it only depends on where the error was thrown.
+impl PartialEq for JsError {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl Eq for JsError {}
+
 /// Internal representation of a [`JsError`].
 ///
 /// `JsError` is represented by an opaque enum because it restricts
@@ -281,6 +297,7 @@ impl JsError {
     pub const fn from_native(err: JsNativeError) -> Self {
         Self {
             inner: Repr::Native(err),
+            backtrace: None,
         }
     }
 
@@ -321,6 +338,7 @@ impl JsError {
     pub const fn from_opaque(value: JsValue) -> Self {
         Self {
             inner: Repr::Opaque(value),
+            backtrace: None,
         }
     }
 
@@ -363,8 +381,9 @@ impl JsError {
     ///
     /// This operation should be considered a lossy conversion, since it
     /// won't store any additional properties of the opaque
-    /// error, other than `message`, `cause` and `errors` (in the case of
-    /// `AggregateError`s). If you cannot affort a lossy conversion, clone
+    /// error, other than `message`, `cause`, `errors` (in the case of
+    /// `AggregateError`s) and `error` and `suppressed` (in the case of
+    /// `SuppressedError`s). If you cannot affort a lossy conversion, clone
     /// the object before calling [`from_opaque`][JsError::from_opaque]
     /// to preserve its original properties.
     ///
@@ -459,6 +478,26 @@ impl JsError {
 
                         JsNativeErrorKind::Aggregate(error_list)
                     }
+                    Error::Suppressed => {
+                        let error = obj.get(js_string!("error"), context).map_err(|e| {
+                            TryNativeError::InaccessibleProperty {
+                                property: "error",
+                                source: e,
+                            }
+                        })?;
+                        let suppressed =
+                            obj.get(js_string!("suppressed"), context).map_err(|e| {
+                                TryNativeError::InaccessibleProperty {
+                                    property: "suppressed",
+                                    source: e,
+                                }
+                            })?;
+
+                        JsNativeErrorKind::Suppressed {
+                            error: Box::new(Self::from_opaque(error)),
+                            suppressed: Box::new(Self::from_opaque(suppressed)),
+                        }
+                    }
                 };
 
                 let realm = try_get_property(js_string!("constructor"), "constructor", context)?
@@ -473,6 +512,7 @@ impl JsError {
                     message,
                     cause: cause.map(|v| Box::new(Self::from_opaque(v))),
                     realm: Some(realm),
+                    backtrace: self.backtrace.clone(),
                 })
             }
         }
@@ -526,6 +566,83 @@ impl JsError {
         }
     }
 
+    /// Gets the location in the source code where this error was thrown, if it was thrown
+    /// from JavaScript code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{Context, Source};
+    /// let context = &mut Context::default();
+    /// let error = context
+    ///     .eval(Source::from_bytes("let a = 1;\nthrow new Error('oops');"))
+    ///     .unwrap_err();
+    ///
+    /// let location = error.location().unwrap();
+    /// assert_eq!(location.to_string(), "<anonymous>:2:1");
+    /// ```
+    #[must_use]
+    pub fn location(&self) -> Option<&SourceLocation> {
+        self.backtrace()?.first().map(StackFrame::location)
+    }
+
+    /// Gets the backtrace captured when this error was thrown, if it was thrown from
+    /// JavaScript code.
+    ///
+    /// The backtrace is captured when the error leaves the frame that threw it, so an error that
+    /// was caught in that frame doesn't have one. The most recent frame is first. Like the `stack` property of error objects, the backtrace
+    /// has at most `Error.stackTraceLimit` frames.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{Context, Source};
+    /// let context = &mut Context::default();
+    /// let error = context
+    ///     .eval(Source::from_bytes(
+    ///         "function inner() { throw 1; }\nfunction outer() { inner(); }\nouter();",
+    ///     ))
+    ///     .unwrap_err();
+    ///
+    /// let frames: Vec<_> = error
+    ///     .backtrace()
+    ///     .unwrap()
+    ///     .iter()
+    ///     .map(ToString::to_string)
+    ///     .collect();
+    /// assert_eq!(
+    ///     frames,
+    ///     [
+    ///         "at inner (<anonymous>:1:20)",
+    ///         "at outer (<anonymous>:2:20)",
+    ///         "at <anonymous>:3:1",
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn backtrace(&self) -> Option<&[StackFrame]> {
+        match &self.inner {
+            Repr::Native(e) => e.backtrace(),
+            Repr::Opaque(_) => self.backtrace.as_deref(),
+        }
+    }
+
+    /// Sets the backtrace captured when this error was thrown.
+    ///
+    /// This is a no-op if the error already has a backtrace.
+    pub(crate) fn with_backtrace(mut self, backtrace: Box<[StackFrame]>) -> Self {
+        match &mut self.inner {
+            Repr::Native(err) if err.backtrace.is_none() => {
+                err.backtrace = Some(backtrace);
+            }
+            Repr::Opaque(_) if self.backtrace.is_none() => {
+                self.backtrace = Some(backtrace);
+            }
+            _ => {}
+        }
+        self
+    }
+
     /// Converts this error into its thread-safe, erased version.
     ///
     /// Even though this operation is lossy, converting into a `JsErasedError`
@@ -579,6 +696,12 @@ impl JsError {
                     .map(|err| err.into_erased(context))
                     .collect(),
             ),
+            JsNativeErrorKind::Suppressed { error, suppressed } => {
+                JsErasedNativeErrorKind::Suppressed {
+                    error: Box::new(error.into_erased(context)),
+                    suppressed: Box::new(suppressed.into_erased(context)),
+                }
+            }
             JsNativeErrorKind::Error => JsErasedNativeErrorKind::Error,
             JsNativeErrorKind::Eval => JsErasedNativeErrorKind::Eval,
             JsNativeErrorKind::Range => JsErasedNativeErrorKind::Range,
@@ -587,6 +710,7 @@ impl JsError {
             JsNativeErrorKind::Type => JsErasedNativeErrorKind::Type,
             JsNativeErrorKind::Uri => JsErasedNativeErrorKind::Uri,
             JsNativeErrorKind::RuntimeLimit => JsErasedNativeErrorKind::RuntimeLimit,
+            JsNativeErrorKind::Terminated => JsErasedNativeErrorKind::Terminated,
             #[cfg(feature = "fuzz")]
             JsNativeErrorKind::NoInstructionsRemain => unreachable!(
                 "The NoInstructionsRemain native error cannot be converted to an erased kind."
@@ -633,6 +757,7 @@ impl From<JsNativeError> for JsError {
     fn from(error: JsNativeError) -> Self {
         Self {
             inner: Repr::Native(error),
+            backtrace: None,
         }
     }
 }
@@ -667,7 +792,7 @@ impl fmt::Display for JsError {
 ///
 /// assert_eq!(native_error.message(), "cannot decode uri");
 /// ```
-#[derive(Clone, Finalize, Error, PartialEq, Eq)]
+#[derive(Clone, Finalize, Error)]
 pub struct JsNativeError {
     /// The kind of native error (e.g. `TypeError`, `SyntaxError`, etc.)
     pub kind: JsNativeErrorKind,
@@ -675,6 +800,7 @@ pub struct JsNativeError {
     #[source]
     cause: Option<Box<JsError>>,
     realm: Option<Realm>,
+    backtrace: Option<Box<[StackFrame]>>,
 }
 
 impl fmt::Display for JsNativeError {
@@ -690,6 +816,18 @@ impl fmt::Display for JsNativeError {
     }
 }
 
+// The backtrace is not compared, since it only depends on where the error was thrown.
+impl PartialEq for JsNativeError {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+            && self.message == other.message
+            && self.cause == other.cause
+            && self.realm == other.realm
+    }
+}
+
+impl Eq for JsNativeError {}
+
 // SAFETY: just mirroring the default derive to allow destructuring.
 unsafe impl Trace for JsNativeError {
     custom_trace!(this, mark, {
@@ -705,6 +843,7 @@ impl fmt::Debug for JsNativeError {
             .field("kind", &self.kind)
             .field("message", &self.message)
             .field("cause", &self.cause)
+            .field("backtrace", &self.backtrace)
             .finish_non_exhaustive()
     }
 }
@@ -731,6 +870,8 @@ impl JsNativeError {
     pub const NO_INSTRUCTIONS_REMAIN: Self = Self::no_instructions_remain();
     /// Default `error` kind `JsNativeError`.
     pub const RUNTIME_LIMIT: Self = Self::runtime_limit();
+    /// Default `Terminated` kind `JsNativeError`.
+    pub const TERMINATED: Self = Self::terminated();
 
     /// Creates a new `JsNativeError` from its `kind`, `message` and (optionally) its `cause`.
     const fn new(
@@ -743,6 +884,7 @@ impl JsNativeError {
             message,
             cause,
             realm: None,
+            backtrace: None,
         }
     }
 
@@ -781,6 +923,40 @@ impl JsNativeError {
         matches!(self.kind, JsNativeErrorKind::Aggregate(_))
     }
 
+    /// Creates a new `JsNativeError` of kind `SuppressedError` from the [`JsError`] thrown last
+    /// and the [`JsError`] it suppressed, with empty `message` and undefined `cause`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{JsNativeError, JsNativeErrorKind};
+    /// let error = JsNativeError::suppressed(
+    ///     JsNativeError::typ().into(),
+    ///     JsNativeError::syntax().into(),
+    /// );
+    ///
+    /// assert!(matches!(error.kind, JsNativeErrorKind::Suppressed { .. }));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn suppressed(error: JsError, suppressed: JsError) -> Self {
+        Self::new(
+            JsNativeErrorKind::Suppressed {
+                error: Box::new(error),
+                suppressed: Box::new(suppressed),
+            },
+            Cow::Borrowed(""),
+            None,
+        )
+    }
+
+    /// Check if it's a [`JsNativeErrorKind::Suppressed`].
+    #[must_use]
+    #[inline]
+    pub const fn is_suppressed(&self) -> bool {
+        matches!(self.kind, JsNativeErrorKind::Suppressed { .. })
+    }
+
     /// Creates a new `JsNativeError` of kind `Error`, with empty `message` and undefined `cause`.
     ///
     /// # Examples
@@ -976,6 +1152,21 @@ impl JsNativeError {
         matches!(self.kind, JsNativeErrorKind::RuntimeLimit)
     }
 
+    /// Creates a new `JsNativeError` that indicates that the execution of the context was
+    /// terminated by the host.
+    #[must_use]
+    #[inline]
+    pub const fn terminated() -> Self {
+        Self::new(JsNativeErrorKind::Terminated, Cow::Borrowed(""), None)
+    }
+
+    /// Check if it's a [`JsNativeErrorKind::Terminated`].
+    #[must_use]
+    #[inline]
+    pub const fn is_terminated(&self) -> bool {
+        matches!(self.kind, JsNativeErrorKind::Terminated)
+    }
+
     /// Sets the message of this error.
     ///
     /// # Examples
@@ -1060,6 +1251,52 @@ impl JsNativeError {
         self.cause.as_deref()
     }
 
+    /// Gets the location in the source code where this error was thrown, if it was thrown
+    /// from JavaScript code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{Context, Source};
+    /// let context = &mut Context::default();
+    /// let error = context
+    ///     .eval(Source::from_bytes("null.foo();"))
+    ///     .unwrap_err();
+    ///
+    /// assert!(error.as_native().unwrap().location().is_some());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn location(&self) -> Option<&SourceLocation> {
+        self.backtrace()?.first().map(StackFrame::location)
+    }
+
+    /// Gets the backtrace captured when this error was thrown, if it was thrown from
+    /// JavaScript code.
+    ///
+    /// The backtrace is captured when the error leaves the frame that threw it, so an error that
+    /// was caught in that frame doesn't have one. The most recent frame is first. Like the `stack` property of error objects, the backtrace
+    /// has at most `Error.stackTraceLimit` frames.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{Context, Source};
+    /// let context = &mut Context::default();
+    /// let error = context
+    ///     .eval(Source::from_bytes("function f() { null.foo(); }\nf();"))
+    ///     .unwrap_err();
+    ///
+    /// let backtrace = error.as_native().unwrap().backtrace().unwrap();
+    /// assert_eq!(backtrace.len(), 2);
+    /// assert_eq!(backtrace[0].function_name().unwrap(), "f");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn backtrace(&self) -> Option<&[StackFrame]> {
+        self.backtrace.as_deref()
+    }
+
     /// Converts this native error to its opaque representation as a [`JsObject`].
     ///
     /// # Examples
@@ -1081,7 +1318,8 @@ impl JsNativeError {
     ///
     /// # Panics
     ///
-    /// If converting a [`JsNativeErrorKind::RuntimeLimit`] to an opaque object.
+    /// If converting a [`JsNativeErrorKind::RuntimeLimit`] or a [`JsNativeErrorKind::Terminated`]
+    /// to an opaque object.
     #[inline]
     pub fn to_opaque(&self, context: &mut Context) -> JsObject {
         let Self {
@@ -1089,6 +1327,7 @@ impl JsNativeError {
             message,
             cause,
             realm,
+            backtrace,
         } = self;
         let constructors = realm.as_ref().map_or_else(
             || context.intrinsics().constructors(),
@@ -1107,6 +1346,10 @@ impl JsNativeError {
             JsNativeErrorKind::Syntax => (constructors.syntax_error().prototype(), Error::Syntax),
             JsNativeErrorKind::Type => (constructors.type_error().prototype(), Error::Type),
             JsNativeErrorKind::Uri => (constructors.uri_error().prototype(), Error::Uri),
+            JsNativeErrorKind::Suppressed { .. } => (
+                constructors.suppressed_error().prototype(),
+                Error::Suppressed,
+            ),
             #[cfg(feature = "fuzz")]
             JsNativeErrorKind::NoInstructionsRemain => {
                 unreachable!(
@@ -1116,6 +1359,9 @@ impl JsNativeError {
             JsNativeErrorKind::RuntimeLimit => {
                 panic!("The RuntimeLimit native error cannot be converted to an opaque type.")
             }
+            JsNativeErrorKind::Terminated => {
+                panic!("The Terminated native error cannot be converted to an opaque type.")
+            }
         };
 
         let o =
@@ -1135,6 +1381,11 @@ impl JsNativeError {
             );
         }
 
+        match backtrace {
+            Some(backtrace) => Error::install_error_stack_from(&o, backtrace, context),
+            None => Error::install_error_stack(&o, context),
+        }
+
         if let JsNativeErrorKind::Aggregate(errors) = kind {
             let errors = errors
                 .iter()
@@ -1152,6 +1403,17 @@ impl JsNativeError {
             )
             .expect("The spec guarantees this succeeds for a newly created object ");
         }
+
+        if let JsNativeErrorKind::Suppressed { error, suppressed } = kind {
+            let error = error.to_opaque(context);
+            let suppressed = suppressed.to_opaque(context);
+            o.create_non_enumerable_data_property_or_throw(js_string!("error"), error, context);
+            o.create_non_enumerable_data_property_or_throw(
+                js_string!("suppressed"),
+                suppressed,
+                context,
+            );
+        }
         o
     }
 
@@ -1261,6 +1523,20 @@ pub enum JsNativeErrorKind {
     /// [e_uri]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/encodeURI
     /// [d_uri]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURI
     Uri,
+    /// An error thrown while disposing resources that suppressed a previously thrown error.
+    ///
+    /// More information:
+    /// - [ECMAScript reference][spec]
+    /// - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SuppressedError
+    Suppressed {
+        /// The error that was thrown last.
+        error: Box<JsError>,
+        /// The error that was suppressed by `error`.
+        suppressed: Box<JsError>,
+    },
 
     /// Error thrown when no instructions remain. Only used in a fuzzing context; not a valid JS
     /// error variant.
@@ -1269,6 +1545,10 @@ pub enum JsNativeErrorKind {
 
     /// Error thrown when a runtime limit is exceeded. It's not a valid JS error variant.
     RuntimeLimit,
+
+    /// Error thrown when the execution is terminated by the host. It's not a valid JS error
+    /// variant.
+    Terminated,
 }
 
 // SAFETY: just mirroring the default derive to allow destructuring.
@@ -1278,6 +1558,10 @@ unsafe impl Trace for JsNativeErrorKind {
         mark,
         match &this {
             Self::Aggregate(errors) => mark(errors),
+            Self::Suppressed { error, suppressed } => {
+                mark(error);
+                mark(suppressed);
+            }
             Self::Error
             | Self::Eval
             | Self::Range
@@ -1285,7 +1569,8 @@ unsafe impl Trace for JsNativeErrorKind {
             | Self::Syntax
             | Self::Type
             | Self::Uri
-            | Self::RuntimeLimit => {}
+            | Self::RuntimeLimit
+            | Self::Terminated => {}
             #[cfg(feature = "fuzz")]
             Self::NoInstructionsRemain => {}
         }
@@ -1298,6 +1583,7 @@ impl JsNativeErrorKind {
     pub(crate) fn is_catchable(&self) -> bool {
         match self {
             Self::Aggregate(_)
+            | Self::Suppressed { .. }
             | Self::Error
             | Self::Eval
             | Self::Range
@@ -1305,7 +1591,7 @@ impl JsNativeErrorKind {
             | Self::Syntax
             | Self::Type
             | Self::Uri => true,
-            Self::RuntimeLimit => false,
+            Self::RuntimeLimit | Self::Terminated => false,
             #[cfg(feature = "fuzz")]
             Self::NoInstructionsRemain => false,
         }
@@ -1324,6 +1610,7 @@ impl PartialEq<Error> for JsNativeErrorKind {
                 | (Self::Syntax, Error::Syntax)
                 | (Self::Type, Error::Type)
                 | (Self::Uri, Error::Uri)
+                | (Self::Suppressed { .. }, Error::Suppressed)
         )
     }
 }
@@ -1339,7 +1626,9 @@ impl fmt::Display for JsNativeErrorKind {
             Self::Syntax => "SyntaxError",
             Self::Type => "TypeError",
             Self::Uri => "UriError",
+            Self::Suppressed { .. } => "SuppressedError",
             Self::RuntimeLimit => "RuntimeLimit",
+            Self::Terminated => "Terminated",
             #[cfg(feature = "fuzz")]
             Self::NoInstructionsRemain => "NoInstructionsRemain",
         }
@@ -1510,9 +1799,27 @@ pub enum JsErasedNativeErrorKind {
     /// [e_uri]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/encodeURI
     /// [d_uri]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURI
     Uri,
+    /// An error thrown while disposing resources that suppressed a previously thrown error.
+    ///
+    /// More information:
+    /// - [ECMAScript reference][spec]
+    /// - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SuppressedError
+    Suppressed {
+        /// The error that was thrown last.
+        error: Box<JsErasedError>,
+        /// The error that was suppressed by `error`.
+        suppressed: Box<JsErasedError>,
+    },
 
     /// Error thrown when a runtime limit is exceeded. It's not a valid JS error variant.
     RuntimeLimit,
+
+    /// Error thrown when the execution is terminated by the host. It's not a valid JS error
+    /// variant.
+    Terminated,
 }
 
 impl fmt::Display for JsErasedNativeErrorKind {
@@ -1528,7 +1835,9 @@ impl fmt::Display for JsErasedNativeErrorKind {
             Self::Syntax => "SyntaxError",
             Self::Type => "TypeError",
             Self::Uri => "UriError",
+            Self::Suppressed { .. } => "SuppressedError",
             Self::RuntimeLimit => "RuntimeLimit",
+            Self::Terminated => "Terminated",
         }
         .fmt(f)
     }
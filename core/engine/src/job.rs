@@ -6,6 +6,7 @@
 //! - [`TimeoutJob`] for jobs that run after a certain amount of time.
 //! - [`NativeAsyncJob`] for jobs that support [`Future`].
 //! - [`NativeJob`] for generic jobs that aren't related to Promises.
+//! - [`GenericJob`] for host jobs that aren't related to Promises, like `FinalizationRegistry` cleanups.
 //!
 //! [`JobCallback`] is an ECMAScript [`JobCallback`] record, containing an `ECMAScript` function
 //! that is executed when a promise is either fulfilled or rejected.
@@ -355,6 +356,66 @@ impl PromiseJob {
     }
 }
 
+/// An ECMAScript [Job Abstract Closure] that isn't related to [`Promise`] objects.
+///
+/// This represents the [`HostEnqueueGenericJob`] and [`HostEnqueueFinalizationRegistryCleanupJob`]
+/// operations from the specification.
+///
+/// ### Requirements
+///
+/// - If realm is not null, each time job is invoked the implementation must perform implementation-defined
+///   steps such that execution is prepared to evaluate ECMAScript code at the time of job's invocation.
+/// - Jobs must run in the same order as the `HostEnqueueGenericJob` invocations that scheduled them.
+///
+/// Boa guarantees the first requirement by its internal implementation of `NativeJob`, meaning
+/// implementations of [`JobExecutor`] must only guarantee that jobs are run in the same order as they're enqueued.
+///
+/// [`Promise`]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise
+/// [`HostEnqueueGenericJob`]: https://tc39.es/ecma262/#sec-hostenqueuegenericjob
+/// [`HostEnqueueFinalizationRegistryCleanupJob`]: https://tc39.es/ecma262/#sec-host-cleanup-finalization-registry
+/// [Job Abstract Closure]: https://tc39.es/ecma262/#sec-jobs
+pub struct GenericJob(NativeJob);
+
+impl Debug for GenericJob {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GenericJob").finish_non_exhaustive()
+    }
+}
+
+impl GenericJob {
+    /// Creates a new `GenericJob` from a closure.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnOnce(&mut Context) -> JsResult<JsValue> + 'static,
+    {
+        Self(NativeJob::new(f))
+    }
+
+    /// Creates a new `GenericJob` from a closure and an execution realm.
+    pub fn with_realm<F>(f: F, realm: Realm, context: &mut Context) -> Self
+    where
+        F: FnOnce(&mut Context) -> JsResult<JsValue> + 'static,
+    {
+        Self(NativeJob::with_realm(f, realm, context))
+    }
+
+    /// Gets a reference to the execution realm of the `GenericJob`.
+    #[must_use]
+    pub const fn realm(&self) -> Option<&Realm> {
+        self.0.realm()
+    }
+
+    /// Calls the `GenericJob` with the specified [`Context`].
+    ///
+    /// # Note
+    ///
+    /// If the job has an execution realm defined, this sets the running execution
+    /// context to the realm's before calling the inner closure, and resets it after execution.
+    pub fn call(self, context: &mut Context) -> JsResult<JsValue> {
+        self.0.call(context)
+    }
+}
+
 /// [`JobCallback`][spec] records.
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-jobcallback-records
@@ -439,6 +500,10 @@ pub enum Job {
     ///
     /// See [`TimeoutJob`] for more information.
     TimeoutJob(TimeoutJob),
+    /// A generic job that isn't related to `Promise`s.
+    ///
+    /// See [`GenericJob`] for more information.
+    GenericJob(GenericJob),
 }
 
 impl From<NativeAsyncJob> for Job {
@@ -459,6 +524,12 @@ impl From<TimeoutJob> for Job {
     }
 }
 
+impl From<GenericJob> for Job {
+    fn from(job: GenericJob) -> Self {
+        Job::GenericJob(job)
+    }
+}
+
 /// An executor of `ECMAscript` [Jobs].
 ///
 /// This is the main API that allows creating custom event loops.
@@ -532,6 +603,7 @@ pub struct SimpleJobExecutor {
     promise_jobs: RefCell<VecDeque<PromiseJob>>,
     async_jobs: RefCell<VecDeque<NativeAsyncJob>>,
     timeout_jobs: RefCell<BTreeMap<JsInstant, TimeoutJob>>,
+    generic_jobs: RefCell<VecDeque<GenericJob>>,
 }
 
 impl Debug for SimpleJobExecutor {
@@ -546,6 +618,13 @@ impl SimpleJobExecutor {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Clears all the pending promise, async and generic jobs.
+    fn clear(&self) {
+        self.async_jobs.borrow_mut().clear();
+        self.promise_jobs.borrow_mut().clear();
+        self.generic_jobs.borrow_mut().clear();
+    }
 }
 
 impl JobExecutor for SimpleJobExecutor {
@@ -557,6 +636,7 @@ impl JobExecutor for SimpleJobExecutor {
                 let now = context.clock().now();
                 self.timeout_jobs.borrow_mut().insert(now + t.timeout(), t);
             }
+            Job::GenericJob(g) => self.generic_jobs.borrow_mut().push_back(g),
         }
     }
 
@@ -579,7 +659,10 @@ impl JobExecutor for SimpleJobExecutor {
 
         let context = RefCell::new(context);
         loop {
-            if self.promise_jobs.borrow().is_empty() && self.async_jobs.borrow().is_empty() {
+            if self.promise_jobs.borrow().is_empty()
+                && self.async_jobs.borrow().is_empty()
+                && self.generic_jobs.borrow().is_empty()
+            {
                 break;
             }
 
@@ -587,8 +670,7 @@ impl JobExecutor for SimpleJobExecutor {
             let mut next_job = self.async_jobs.borrow_mut().pop_front();
             while let Some(job) = next_job {
                 if let Err(err) = futures_lite::future::block_on(job.call(&context)) {
-                    self.async_jobs.borrow_mut().clear();
-                    self.promise_jobs.borrow_mut().clear();
+                    self.clear();
                     return Err(err);
                 }
                 next_job = self.async_jobs.borrow_mut().pop_front();
@@ -596,12 +678,20 @@ impl JobExecutor for SimpleJobExecutor {
             let mut next_job = self.promise_jobs.borrow_mut().pop_front();
             while let Some(job) = next_job {
                 if let Err(err) = job.call(&mut context.borrow_mut()) {
-                    self.async_jobs.borrow_mut().clear();
-                    self.promise_jobs.borrow_mut().clear();
+                    self.clear();
                     return Err(err);
                 }
                 next_job = self.promise_jobs.borrow_mut().pop_front();
             }
+
+            // Generic jobs run after the promise job queue is drained, one at a time.
+            let next_job = self.generic_jobs.borrow_mut().pop_front();
+            if let Some(job) = next_job {
+                if let Err(err) = job.call(&mut context.borrow_mut()) {
+                    self.clear();
+                    return Err(err);
+                }
+            }
         }
 
         Ok(())
@@ -98,6 +98,7 @@ pub mod optimizer;
 pub mod property;
 pub mod realm;
 pub mod script;
+pub mod snapshot;
 pub mod string;
 pub mod symbol;
 pub mod value;
@@ -3,7 +3,7 @@ use std::path::{Component, Path, PathBuf};
 use boa_profiler::Profiler;
 use rustc_hash::FxHashMap;
 
-use boa_gc::GcRefCell;
+use boa_gc::{empty_trace, Finalize, GcRefCell, Trace};
 use boa_parser::Source;
 
 use crate::script::Script;
@@ -110,6 +110,144 @@ pub fn resolve_module_specifier(
     }
 }
 
+/// An import attribute, such as the `type: "json"` entry of
+/// `import data from "./data.json" with { type: "json" }`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ImportAttribute {
+    key: JsString,
+    value: JsString,
+}
+
+impl ImportAttribute {
+    /// Creates a new `ImportAttribute` from its key and value.
+    #[must_use]
+    pub const fn new(key: JsString, value: JsString) -> Self {
+        Self { key, value }
+    }
+
+    /// Gets the key of the attribute.
+    #[must_use]
+    pub const fn key(&self) -> &JsString {
+        &self.key
+    }
+
+    /// Gets the value of the attribute.
+    #[must_use]
+    pub const fn value(&self) -> &JsString {
+        &self.value
+    }
+}
+
+/// The type of module requested by the `type` import attribute of a [`ModuleRequest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Trace, Finalize)]
+#[boa_gc(empty_trace)]
+pub enum ModuleType {
+    /// An ECMAScript module, requested without a `type` attribute.
+    JavaScript,
+    /// A JSON module, requested with `type: "json"`.
+    Json,
+}
+
+/// A [**`ModuleRequest` Record**][spec].
+///
+/// Contains the specifier of a requested module, along with the import attributes it was
+/// requested with.
+///
+/// [spec]: https://tc39.es/ecma262/#modulerequest-record
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Finalize)]
+pub struct ModuleRequest {
+    specifier: JsString,
+    attributes: Box<[ImportAttribute]>,
+}
+
+// SAFETY: `ModuleRequest` doesn't contain any garbage collected types.
+unsafe impl Trace for ModuleRequest {
+    empty_trace!();
+}
+
+impl ModuleRequest {
+    /// Creates a new `ModuleRequest` from a specifier and its import attributes.
+    ///
+    /// The attributes are sorted by key, so requests with the same set of attributes compare
+    /// equal.
+    #[must_use]
+    pub fn new(specifier: JsString, mut attributes: Box<[ImportAttribute]>) -> Self {
+        attributes.sort_unstable_by(|a, b| a.key.cmp(&b.key));
+        Self {
+            specifier,
+            attributes,
+        }
+    }
+
+    /// Creates a new `ModuleRequest` from a specifier without import attributes.
+    #[must_use]
+    pub fn from_specifier(specifier: JsString) -> Self {
+        Self {
+            specifier,
+            attributes: Box::default(),
+        }
+    }
+
+    /// Gets the specifier of the requested module.
+    #[must_use]
+    pub const fn specifier(&self) -> &JsString {
+        &self.specifier
+    }
+
+    /// Gets the import attributes of the request.
+    #[must_use]
+    pub const fn attributes(&self) -> &[ImportAttribute] {
+        &self.attributes
+    }
+
+    /// Gets the value of the import attribute with the provided key, if the request has it.
+    #[must_use]
+    pub fn get_attribute(&self, key: &str) -> Option<&JsString> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.key == key)
+            .map(ImportAttribute::value)
+    }
+
+    /// Gets the type of module requested by the `type` import attribute.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the `type` attribute has an unsupported value.
+    pub fn module_type(&self) -> JsResult<ModuleType> {
+        match self.get_attribute("type") {
+            None => Ok(ModuleType::JavaScript),
+            Some(ty) if ty == "json" => Ok(ModuleType::Json),
+            Some(ty) => Err(JsNativeError::typ()
+                .with_message(format!(
+                    "unsupported module type `{}`",
+                    ty.to_std_string_escaped()
+                ))
+                .into()),
+        }
+    }
+}
+
+impl From<JsString> for ModuleRequest {
+    fn from(specifier: JsString) -> Self {
+        Self::from_specifier(specifier)
+    }
+}
+
+/// Abstract operation [`AllImportAttributesSupported ( attributes )`][spec].
+///
+/// Returns the key of the first attribute that is not supported by the engine, if any.
+///
+/// The only supported import attribute is `type`.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-AllImportAttributesSupported
+pub(crate) fn unsupported_import_attribute(attributes: &[ImportAttribute]) -> Option<&JsString> {
+    attributes
+        .iter()
+        .map(ImportAttribute::key)
+        .find(|key| **key != "type")
+}
+
 /// The referrer from which a load request of a module originates.
 #[derive(Debug, Clone)]
 pub enum Referrer {
@@ -147,22 +285,27 @@ impl From<ActiveRunnable> for Referrer {
 /// This trait allows to customize the behaviour of the engine on module load requests and
 /// `import.meta` requests.
 pub trait ModuleLoader {
-    /// Host hook [`HostLoadImportedModule ( referrer, specifier, hostDefined, payload )`][spec].
+    /// Host hook [`HostLoadImportedModule ( referrer, moduleRequest, hostDefined, payload )`][spec].
     ///
     /// This hook allows to customize the module loading functionality of the engine. Technically,
     /// this should call the [`FinishLoadingImportedModule`][finish] operation, but this simpler API just provides
     /// a closure that replaces `FinishLoadingImportedModule`.
     ///
+    /// The `request` contains the specifier of the module and the import attributes it was
+    /// requested with. Hosts should use [`ModuleRequest::module_type`] to decide how to
+    /// interpret the loaded source, e.g. producing a JSON module with [`Module::parse_json`]
+    /// for `type: "json"` requests.
+    ///
     /// # Requirements
     ///
-    /// - The host environment must perform `FinishLoadingImportedModule(referrer, specifier, payload, result)`,
+    /// - The host environment must perform `FinishLoadingImportedModule(referrer, moduleRequest, payload, result)`,
     ///   where result is either a normal completion containing the loaded Module Record or a throw
     ///   completion, either synchronously or asynchronously. This is equivalent to calling the `finish_load`
     ///   callback.
-    /// - If this operation is called multiple times with the same `(referrer, specifier)` pair and
-    ///   it performs FinishLoadingImportedModule(referrer, specifier, payload, result) where result
+    /// - If this operation is called multiple times with the same `(referrer, moduleRequest)` pair and
+    ///   it performs FinishLoadingImportedModule(referrer, moduleRequest, payload, result) where result
     ///   is a normal completion, then it must perform
-    ///   `FinishLoadingImportedModule(referrer, specifier, payload, result)` with the same result each
+    ///   `FinishLoadingImportedModule(referrer, moduleRequest, payload, result)` with the same result each
     ///   time.
     /// - The operation must treat payload as an opaque value to be passed through to
     ///   `FinishLoadingImportedModule`. (can be ignored)
@@ -173,7 +316,7 @@ pub trait ModuleLoader {
     fn load_imported_module(
         &self,
         referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     );
@@ -223,7 +366,7 @@ impl ModuleLoader for IdleModuleLoader {
     fn load_imported_module(
         &self,
         _referrer: Referrer,
-        _specifier: JsString,
+        _request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
@@ -243,10 +386,13 @@ impl ModuleLoader for IdleModuleLoader {
 /// This loader only works by using the type methods [`SimpleModuleLoader::insert`] and
 /// [`SimpleModuleLoader::get`]. The utility methods on [`ModuleLoader`] don't work at the moment,
 /// but we'll unify both APIs in the future.
+///
+/// Modules are cached by their path and their [`ModuleType`], so a file imported both as
+/// JavaScript and with `type: "json"` is loaded once as each type.
 #[derive(Debug)]
 pub struct SimpleModuleLoader {
     root: PathBuf,
-    module_map: GcRefCell<FxHashMap<PathBuf, Module>>,
+    module_map: GcRefCell<FxHashMap<(PathBuf, ModuleType), Module>>,
 }
 
 impl SimpleModuleLoader {
@@ -270,16 +416,21 @@ impl SimpleModuleLoader {
         })
     }
 
-    /// Inserts a new module onto the module map.
+    /// Inserts a new JavaScript module onto the module map.
     #[inline]
     pub fn insert(&self, path: PathBuf, module: Module) {
-        self.module_map.borrow_mut().insert(path, module);
+        self.module_map
+            .borrow_mut()
+            .insert((path, ModuleType::JavaScript), module);
     }
 
-    /// Gets a module from its original path.
+    /// Gets a JavaScript module from its original path.
     #[inline]
     pub fn get(&self, path: &Path) -> Option<Module> {
-        self.module_map.borrow().get(path).cloned()
+        self.module_map
+            .borrow()
+            .get(&(path.to_path_buf(), ModuleType::JavaScript))
+            .cloned()
     }
 }
 
@@ -287,29 +438,43 @@ impl ModuleLoader for SimpleModuleLoader {
     fn load_imported_module(
         &self,
         referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
         let result = (|| {
+            let specifier = request.specifier();
             let short_path = specifier.to_std_string_escaped();
+            let module_type = request.module_type()?;
             let path =
-                resolve_module_specifier(Some(&self.root), &specifier, referrer.path(), context)?;
-            if let Some(module) = self.get(&path) {
+                resolve_module_specifier(Some(&self.root), specifier, referrer.path(), context)?;
+            let key = (path, module_type);
+            if let Some(module) = self.module_map.borrow().get(&key).cloned() {
                 return Ok(module);
             }
+            let path = &key.0;
 
-            let source = Source::from_filepath(&path).map_err(|err| {
+            let open_error = |err: std::io::Error| {
                 JsNativeError::typ()
                     .with_message(format!("could not open file `{short_path}`"))
                     .with_cause(JsError::from_opaque(js_string!(err.to_string()).into()))
-            })?;
-            let module = Module::parse(source, None, context).map_err(|err| {
+            };
+            let module = match module_type {
+                ModuleType::JavaScript => {
+                    let source = Source::from_filepath(path).map_err(open_error)?;
+                    Module::parse(source, None, context)
+                }
+                ModuleType::Json => {
+                    let json = std::fs::read_to_string(path).map_err(open_error)?;
+                    Module::parse_json(js_string!(json), context)
+                }
+            }
+            .map_err(|err| {
                 JsNativeError::syntax()
                     .with_message(format!("could not parse module `{short_path}`"))
                     .with_cause(err)
             })?;
-            self.insert(path, module.clone());
+            self.module_map.borrow_mut().insert(key, module.clone());
             Ok(module)
         })();
 
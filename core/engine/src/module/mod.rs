@@ -47,8 +47,9 @@ use crate::spanned_source_text::SourceText;
 use crate::{
     builtins,
     builtins::promise::{PromiseCapability, PromiseState},
+    builtins::Promise,
     environments::DeclarativeEnvironment,
-    object::{JsObject, JsPromise},
+    object::{FunctionObjectBuilder, JsObject, JsPromise},
     realm::Realm,
     Context, HostDefined, JsError, JsNativeError, JsResult, JsString, JsValue, NativeFunction,
 };
@@ -172,7 +173,102 @@ impl Module {
             parser.parse_module_with_source(realm.scope(), context.interner_mut())?;
 
         let source_text = SourceText::new(source);
-        let src = SourceTextModule::new(module, context.interner(), source_text);
+        let src = SourceTextModule::new(module, context.interner(), source_text)?;
+
+        Ok(Self {
+            inner: Gc::new(ModuleRepr {
+                realm,
+                namespace: GcRefCell::default(),
+                kind: ModuleKind::SourceText(Box::new(src)),
+                host_defined: HostDefined::default(),
+                path,
+            }),
+        })
+    }
+
+    /// Creates a module from an already built AST, such as one imported from `ESTree`.
+    ///
+    /// The scopes of `module` are analyzed before returning. The module has no source text, so
+    /// functions created by it show `[native code]` when converted to a string.
+    ///
+    /// Unlike [`Module::parse`], this does not check `module` for early errors, such as duplicate
+    /// lexical declarations or invalid assignment targets, so it must come from a conforming
+    /// parser. The behaviour of a module whose AST has early errors is unspecified.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SyntaxError` if the scope analysis of `module` fails, or if the module
+    /// imports a module with unsupported import attributes.
+    pub fn from_ast(
+        mut module: boa_ast::Module,
+        realm: Option<Realm>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let realm = realm.unwrap_or_else(|| context.realm().clone());
+        if !module.analyze_scope(realm.scope(), context.interner()) {
+            return Err(JsNativeError::syntax()
+                .with_message("invalid scope analysis")
+                .into());
+        }
+
+        let src = SourceTextModule::new(module, context.interner(), SourceText::new_empty())?;
+
+        Ok(Self {
+            inner: Gc::new(ModuleRepr {
+                realm,
+                namespace: GcRefCell::default(),
+                kind: ModuleKind::SourceText(Box::new(src)),
+                host_defined: HostDefined::default(),
+                path: None,
+            }),
+        })
+    }
+
+    /// Serializes the compiled code of this module into a bytecode cache.
+    ///
+    /// The cache can be loaded with [`Module::from_cache`] to create this module again without
+    /// parsing or compiling its source text. This compiles the module if it wasn't compiled yet.
+    ///
+    /// Caches are only compatible with the same version of the engine, compiled with the same
+    /// features.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if this is not a source text module.
+    pub fn to_cache(&self, context: &mut Context) -> JsResult<Vec<u8>> {
+        let _timer = Profiler::global().start_event("Module cache serialization", "Main");
+
+        let ModuleKind::SourceText(src) = self.kind() else {
+            return Err(JsNativeError::typ()
+                .with_message("only source text modules can be cached")
+                .into());
+        };
+
+        Ok(src.to_cache(context))
+    }
+
+    /// Loads a module from a bytecode cache created by [`Module::to_cache`], without parsing or
+    /// compiling its source text.
+    ///
+    /// The source text is still read from `src`, to check that the cache was created from the
+    /// same source text and to keep the source text of the functions of the module.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading `src` fails, if the cache was not created from the same
+    /// source text by the same version of the engine, or if the cache is invalid.
+    pub fn from_cache<R: ReadChar>(
+        cache: &[u8],
+        mut src: Source<'_, R>,
+        realm: Option<Realm>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let _timer = Profiler::global().start_event("Module cache loading", "Main");
+        let path = src.path().map(Path::to_path_buf);
+        let source_text = SourceText::new(src.read_source_text()?);
+        let realm = realm.unwrap_or_else(|| context.realm().clone());
+
+        let src = SourceTextModule::from_cache(cache, source_text, realm.scope(), context)?;
 
         Ok(Self {
             inner: Gc::new(ModuleRepr {
@@ -668,6 +764,180 @@ impl Hash for Module {
     }
 }
 
+/// Performs `HostLoadImportedModule ( referrer, moduleRequest, empty, promiseCapability )` for a
+/// dynamic import, continuing with [`ContinueDynamicImport`][spec] once the module is loaded.
+///
+/// `cap` gets resolved with the namespace of the imported module, or rejected if the module
+/// could not be loaded, linked or evaluated.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ContinueDynamicImport
+pub(crate) fn load_dynamic_import(
+    referrer: Referrer,
+    request: ModuleRequest,
+    cap: PromiseCapability,
+    context: &mut Context,
+) {
+    context.module_loader().load_imported_module(
+        referrer.clone(),
+        request.clone(),
+        Box::new(move |completion, context| {
+            // `ContinueDynamicImport ( promiseCapability, moduleCompletion )`
+            // https://tc39.es/ecma262/#sec-ContinueDynamicImport
+
+            // `FinishLoadingImportedModule ( referrer, moduleRequest, payload, result )`
+            // https://tc39.es/ecma262/#sec-FinishLoadingImportedModule
+            let module = match completion {
+                // 1. If result is a normal completion, then
+                Ok(m) => {
+                    match referrer {
+                        Referrer::Module(module) => {
+                            let ModuleKind::SourceText(src) = module.kind() else {
+                                panic!("referrer cannot be a synthetic module");
+                            };
+
+                            let mut loaded_modules = src.loaded_modules().borrow_mut();
+
+                            //     a. If referrer.[[LoadedModules]] contains a LoadedModuleRequest Record record such that
+                            //        ModuleRequestsEqual(record, moduleRequest) is true, then
+                            //     b. Else,
+                            //         i. Append the LoadedModuleRequest Record { [[Specifier]]: moduleRequest.[[Specifier]],
+                            //            [[Attributes]]: moduleRequest.[[Attributes]], [[Module]]: result.[[Value]] }
+                            //            to referrer.[[LoadedModules]].
+                            let entry =
+                                loaded_modules.entry(request).or_insert_with(|| m.clone());
+
+                            //         i. Assert: That Record's [[Module]] is result.[[Value]].
+                            debug_assert_eq!(&m, entry);
+
+                            // Same steps apply to referrers below
+                        }
+                        Referrer::Realm(realm) => {
+                            let mut loaded_modules = realm.loaded_modules().borrow_mut();
+                            let entry =
+                                loaded_modules.entry(request).or_insert_with(|| m.clone());
+                            debug_assert_eq!(&m, entry);
+                        }
+                        Referrer::Script(script) => {
+                            let mut loaded_modules = script.loaded_modules().borrow_mut();
+                            let entry =
+                                loaded_modules.entry(request).or_insert_with(|| m.clone());
+                            debug_assert_eq!(&m, entry);
+                        }
+                    }
+
+                    m
+                }
+                // 1. If moduleCompletion is an abrupt completion, then
+                Err(err) => {
+                    // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « moduleCompletion.[[Value]] »).
+                    let err = err.to_opaque(context);
+                    cap.reject()
+                        .call(&JsValue::undefined(), &[err], context)
+                        .expect("default `reject` function cannot throw");
+
+                    // b. Return unused.
+                    return;
+                }
+            };
+
+            // 2. Let module be moduleCompletion.[[Value]].
+            // 3. Let loadPromise be module.LoadRequestedModules().
+            let load = module.load(context);
+
+            // 4. Let rejectedClosure be a new Abstract Closure with parameters (reason) that captures promiseCapability and performs the following steps when called:
+            // 5. Let onRejected be CreateBuiltinFunction(rejectedClosure, 1, "", « »).
+            let on_rejected = FunctionObjectBuilder::new(
+                context.realm(),
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, args, cap, context| {
+                        //     a. Perform ! Call(promiseCapability.[[Reject]], undefined, « reason »).
+                        cap.reject()
+                            .call(&JsValue::undefined(), args, context)
+                            .expect("default `reject` function cannot throw");
+
+                        //     b. Return unused.
+                        Ok(JsValue::undefined())
+                    },
+                    cap.clone(),
+                ),
+            )
+            .build();
+
+            // 6. Let linkAndEvaluateClosure be a new Abstract Closure with no parameters that captures module, promiseCapability, and onRejected and performs the following steps when called:
+            // 7. Let linkAndEvaluate be CreateBuiltinFunction(linkAndEvaluateClosure, 0, "", « »).
+            let link_evaluate = FunctionObjectBuilder::new(
+                context.realm(),
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, _, (module, cap, on_rejected), context| {
+                        // a. Let link be Completion(module.Link()).
+                        // b. If link is an abrupt completion, then
+                        if let Err(e) = module.link(context) {
+                            // i. Perform ! Call(promiseCapability.[[Reject]], undefined, « link.[[Value]] »).
+                            let e = e.to_opaque(context);
+                            cap.reject()
+                                .call(&JsValue::undefined(), &[e], context)
+                                .expect("default `reject` function cannot throw");
+                            // ii. Return unused.
+                            return Ok(JsValue::undefined());
+                        }
+
+                        // c. Let evaluatePromise be module.Evaluate().
+                        let evaluate = module.evaluate(context);
+
+                        // d. Let fulfilledClosure be a new Abstract Closure with no parameters that captures module and promiseCapability and performs the following steps when called:
+                        // e. Let onFulfilled be CreateBuiltinFunction(fulfilledClosure, 0, "", « »).
+                        let fulfill = FunctionObjectBuilder::new(
+                            context.realm(),
+                            NativeFunction::from_copy_closure_with_captures(
+                                |_, _, (module, cap), context| {
+                                    // i. Let namespace be GetModuleNamespace(module).
+                                    let namespace = module.namespace(context);
+
+                                    // ii. Perform ! Call(promiseCapability.[[Resolve]], undefined, « namespace »).
+                                    cap.resolve()
+                                        .call(&JsValue::undefined(), &[namespace.into()], context)
+                                        .expect("default `resolve` function cannot throw");
+
+                                    // iii. Return unused.
+                                    Ok(JsValue::undefined())
+                                },
+                                (module.clone(), cap.clone()),
+                            ),
+                        )
+                        .build();
+
+                        // f. Perform PerformPromiseThen(evaluatePromise, onFulfilled, onRejected).
+                        Promise::perform_promise_then(
+                            &evaluate,
+                            Some(fulfill),
+                            Some(on_rejected.clone()),
+                            None,
+                            context,
+                        );
+
+                        // g. Return unused.
+                        Ok(JsValue::undefined())
+                    },
+                    (module.clone(), cap.clone(), on_rejected.clone()),
+                ),
+            )
+            .build();
+
+            // 8. Perform PerformPromiseThen(loadPromise, linkAndEvaluate, onRejected).
+            Promise::perform_promise_then(
+                &load,
+                Some(link_evaluate),
+                Some(on_rejected),
+                None,
+                context,
+            );
+
+            // 9. Return unused.
+        }),
+        context,
+    );
+}
+
 /// A trait to convert a type into a JS module.
 pub trait IntoJsModule {
     /// Converts the type into a JS module.
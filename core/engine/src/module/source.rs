@@ -5,14 +5,15 @@ use boa_ast::{
         ExportEntry, ImportEntry, ImportName, IndirectExportEntry, LocalExportEntry,
         ReExportImportName,
     },
+    expression::Identifier,
     operations::{
         bound_names, contains, lexically_scoped_declarations, var_scoped_declarations,
         ContainsSymbol, LexicallyScopedDeclaration,
     },
-    scope::BindingLocator,
+    scope::{BindingLocator, Scope},
 };
 use boa_gc::{Finalize, Gc, GcRefCell, Trace};
-use boa_interner::Interner;
+use boa_interner::{Interner, Sym};
 use boa_macros::js_str;
 use indexmap::IndexSet;
 use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
@@ -26,16 +27,16 @@ use crate::{
     object::{FunctionObjectBuilder, JsPromise},
     realm::Realm,
     vm::{
-        create_function_object_fast, ActiveRunnable, CallFrame, CallFrameFlags, CodeBlock,
-        CompletionRecord,
+        create_function_object_fast, ActiveRunnable, CacheKind, CacheReader, CacheWriter,
+        CallFrame, CallFrameFlags, CodeBlock, CompletionRecord,
     },
     Context, JsArgs, JsError, JsNativeError, JsObject, JsResult, JsString, JsValue, NativeFunction,
     SpannedSourceText,
 };
 
 use super::{
-    BindingName, GraphLoadingState, Module, Referrer, ResolveExportError, ResolvedBinding,
-    SourceText,
+    unsupported_import_attribute, BindingName, GraphLoadingState, ImportAttribute, Module,
+    ModuleRequest, Referrer, ResolveExportError, ResolvedBinding, SourceText,
 };
 
 /// Information for the [**Depth-first search**] algorithm used in the
@@ -205,15 +206,27 @@ impl std::fmt::Debug for SourceTextContext {
     }
 }
 
+/// The compiled code of a [`SourceTextModule`].
+#[derive(Clone, Trace, Finalize)]
+struct CompiledModule {
+    codeblock: Gc<CodeBlock>,
+
+    /// The function declarations of the module, as the index of their code in the constants
+    /// of `codeblock` and the locator of their binding.
+    #[unsafe_ignore_trace]
+    functions: Rc<[(u32, BindingLocator)]>,
+}
+
 /// ECMAScript's [**Source Text Module Records**][spec].
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-source-text-module-records
 #[derive(Trace, Finalize)]
 pub(crate) struct SourceTextModule {
     status: GcRefCell<ModuleStatus>,
-    loaded_modules: GcRefCell<FxHashMap<JsString, Module>>,
+    loaded_modules: GcRefCell<FxHashMap<ModuleRequest, Module>>,
     async_parent_modules: GcRefCell<Vec<Module>>,
     import_meta: GcRefCell<Option<JsObject>>,
+    compiled: GcRefCell<Option<CompiledModule>>,
     #[unsafe_ignore_trace]
     code: ModuleCode,
 }
@@ -232,13 +245,15 @@ impl std::fmt::Debug for SourceTextModule {
 #[derive(Debug)]
 struct ModuleCode {
     has_tla: bool,
-    requested_modules: IndexSet<JsString, BuildHasherDefault<FxHasher>>,
-    source: boa_ast::Module,
+    requested_modules: IndexSet<ModuleRequest, BuildHasherDefault<FxHasher>>,
+    /// The parsed source of the module, or `None` if the module was loaded from a bytecode cache.
+    source: Option<boa_ast::Module>,
+    scope: Scope,
     source_text: SourceText,
     import_entries: Vec<ImportEntry>,
     local_export_entries: Vec<LocalExportEntry>,
     indirect_export_entries: Vec<IndirectExportEntry>,
-    star_export_entries: Vec<JsString>,
+    star_export_entries: Vec<ModuleRequest>,
 }
 
 impl SourceTextModule {
@@ -247,14 +262,28 @@ impl SourceTextModule {
     /// Contains part of the abstract operation [`ParseModule`][parse].
     ///
     /// [parse]: https://tc39.es/ecma262/#sec-parsemodule
-    pub(super) fn new(code: boa_ast::Module, interner: &Interner, source_text: SourceText) -> Self {
+    pub(super) fn new(
+        code: boa_ast::Module,
+        interner: &Interner,
+        source_text: SourceText,
+    ) -> JsResult<Self> {
         // 3. Let requestedModules be the ModuleRequests of body.
-        let requested_modules = code
-            .items()
-            .requests()
-            .iter()
-            .map(|name| name.to_js_string(interner))
-            .collect();
+        let mut requested_modules: IndexSet<_, BuildHasherDefault<FxHasher>> = IndexSet::default();
+        for request in code.items().requests() {
+            let request = module_request(&request, interner);
+
+            // Throw a SyntaxError if AllImportAttributesSupported(request.[[Attributes]]) is false.
+            if let Some(key) = unsupported_import_attribute(request.attributes()) {
+                return Err(JsNativeError::syntax()
+                    .with_message(format!(
+                        "unsupported import attribute `{}`",
+                        key.to_std_string_escaped()
+                    ))
+                    .into());
+            }
+
+            requested_modules.insert(request);
+        }
         // 4. Let importEntries be ImportEntries of body.
         let import_entries = code.items().import_entries();
 
@@ -278,7 +307,7 @@ impl SourceTextModule {
                     if let Some((module, import)) =
                         import_entries.iter().find_map(|ie| match ie.import_name() {
                             ImportName::Name(name) if ie.local_name() == entry.local_name() => {
-                                Some((ie.module_request(), name))
+                                Some((ie.module_request().clone(), name))
                             }
                             _ => None,
                         })
@@ -304,10 +333,12 @@ impl SourceTextModule {
                     }
                 }
                 // b. Else if ee.[[ImportName]] is all-but-default, then
-                ExportEntry::StarReExport { module_request } => {
+                ExportEntry::StarReExport {
+                    module_request: request,
+                } => {
                     // i. Assert: ee.[[ExportName]] is null.
                     // ii. Append ee to starExportEntries.
-                    star_export_entries.push(module_request.to_js_string(interner));
+                    star_export_entries.push(module_request(&request, interner));
                 }
                 // c. Else,
                 //    i. Append ee to indirectExportEntries.
@@ -331,13 +362,112 @@ impl SourceTextModule {
         //     [[DFSIndex]]: empty, [[DFSAncestorIndex]]: empty
         // }.
         // Most of this can be ignored, since `Status` takes care of the remaining state.
-        Self {
+        Ok(Self {
+            status: GcRefCell::default(),
+            loaded_modules: GcRefCell::default(),
+            async_parent_modules: GcRefCell::default(),
+            import_meta: GcRefCell::default(),
+            compiled: GcRefCell::default(),
+            code: ModuleCode {
+                scope: code.scope().clone(),
+                source: Some(code),
+                source_text,
+                requested_modules,
+                has_tla,
+                import_entries,
+                local_export_entries,
+                indirect_export_entries,
+                star_export_entries,
+            },
+        })
+    }
+
+    /// Creates a new `SourceTextModule` from a bytecode cache created by
+    /// [`SourceTextModule::to_cache`].
+    pub(super) fn from_cache(
+        cache: &[u8],
+        source_text: SourceText,
+        global_scope: &Scope,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let mut reader = CacheReader::new(
+            cache,
+            CacheKind::Module,
+            source_text.clone(),
+            global_scope.clone(),
+        )?;
+
+        let has_tla = reader.read_bool()?;
+
+        let mut requested_modules: IndexSet<_, BuildHasherDefault<FxHasher>> = IndexSet::default();
+        for _ in 0..reader.read_usize()? {
+            requested_modules.insert(read_request(&mut reader)?);
+        }
+
+        let interner = context.interner_mut();
+
+        let mut import_entries = Vec::new();
+        for _ in 0..reader.read_usize()? {
+            let module_request = read_ast_request(&mut reader, interner)?;
+            let import_name = if reader.read_bool()? {
+                ImportName::Name(read_sym(&mut reader, interner)?)
+            } else {
+                ImportName::Namespace
+            };
+            let local_name = Identifier::new(read_sym(&mut reader, interner)?);
+            import_entries.push(ImportEntry::new(module_request, import_name, local_name));
+        }
+
+        let mut local_export_entries = Vec::new();
+        for _ in 0..reader.read_usize()? {
+            let local_name = Identifier::new(read_sym(&mut reader, interner)?);
+            let export_name = read_sym(&mut reader, interner)?;
+            local_export_entries.push(LocalExportEntry::new(local_name, export_name));
+        }
+
+        let mut indirect_export_entries = Vec::new();
+        for _ in 0..reader.read_usize()? {
+            let module_request = read_ast_request(&mut reader, interner)?;
+            let import_name = if reader.read_bool()? {
+                ReExportImportName::Name(read_sym(&mut reader, interner)?)
+            } else {
+                ReExportImportName::Star
+            };
+            let export_name = read_sym(&mut reader, interner)?;
+            indirect_export_entries.push(IndirectExportEntry::new(
+                module_request,
+                import_name,
+                export_name,
+            ));
+        }
+
+        let mut star_export_entries = Vec::new();
+        for _ in 0..reader.read_usize()? {
+            star_export_entries.push(read_request(&mut reader)?);
+        }
+
+        let scope = reader.read_scope()?;
+
+        let mut functions = Vec::new();
+        for _ in 0..reader.read_usize()? {
+            functions.push((reader.read_u32()?, reader.read_locator()?));
+        }
+
+        let codeblock = Gc::new(reader.read_code_block()?);
+        reader.finish()?;
+
+        Ok(Self {
             status: GcRefCell::default(),
             loaded_modules: GcRefCell::default(),
             async_parent_modules: GcRefCell::default(),
             import_meta: GcRefCell::default(),
+            compiled: GcRefCell::new(Some(CompiledModule {
+                codeblock,
+                functions: functions.into(),
+            })),
             code: ModuleCode {
-                source: code,
+                source: None,
+                scope,
                 source_text,
                 requested_modules,
                 has_tla,
@@ -346,7 +476,78 @@ impl SourceTextModule {
                 indirect_export_entries,
                 star_export_entries,
             },
+        })
+    }
+
+    /// Serializes the compiled code of this module into a bytecode cache, compiling the module
+    /// if it wasn't compiled yet.
+    pub(super) fn to_cache(&self, context: &mut Context) -> Vec<u8> {
+        let compiled = self.compile(context);
+        let codeblock = compiled.codeblock.clone();
+        let functions = compiled.functions.clone();
+        let interner = context.interner();
+
+        let mut writer = CacheWriter::new(CacheKind::Module, &self.code.source_text);
+        writer.write_bool(self.code.has_tla);
+
+        writer.write_usize(self.code.requested_modules.len());
+        for request in &self.code.requested_modules {
+            write_request(&mut writer, request);
+        }
+
+        writer.write_usize(self.code.import_entries.len());
+        for entry in &self.code.import_entries {
+            write_request(
+                &mut writer,
+                &module_request(entry.module_request(), interner),
+            );
+            match entry.import_name() {
+                ImportName::Name(name) => {
+                    writer.write_bool(true);
+                    writer.write_string(&name.to_js_string(interner));
+                }
+                ImportName::Namespace => writer.write_bool(false),
+            }
+            writer.write_string(&entry.local_name().to_js_string(interner));
+        }
+
+        writer.write_usize(self.code.local_export_entries.len());
+        for entry in &self.code.local_export_entries {
+            writer.write_string(&entry.local_name().to_js_string(interner));
+            writer.write_string(&entry.export_name().to_js_string(interner));
         }
+
+        writer.write_usize(self.code.indirect_export_entries.len());
+        for entry in &self.code.indirect_export_entries {
+            write_request(
+                &mut writer,
+                &module_request(entry.module_request(), interner),
+            );
+            match entry.import_name() {
+                ReExportImportName::Name(name) => {
+                    writer.write_bool(true);
+                    writer.write_string(&name.to_js_string(interner));
+                }
+                ReExportImportName::Star => writer.write_bool(false),
+            }
+            writer.write_string(&entry.export_name().to_js_string(interner));
+        }
+
+        writer.write_usize(self.code.star_export_entries.len());
+        for request in &self.code.star_export_entries {
+            write_request(&mut writer, request);
+        }
+
+        writer.write_scope(&self.code.scope);
+
+        writer.write_usize(functions.len());
+        for (index, locator) in functions.iter() {
+            writer.write_u32(*index);
+            writer.write_locator(locator);
+        }
+
+        writer.write_code_block(&codeblock);
+        writer.finish()
     }
 
     /// Abstract operation [`InnerModuleLoading`][spec].
@@ -370,27 +571,28 @@ impl SourceTextModule {
             state
                 .pending_modules
                 .set(state.pending_modules.get() + requested.len());
-            // d. For each String required of module.[[RequestedModules]], do
-            for required in requested.iter().cloned() {
-                // i. If module.[[LoadedModules]] contains a Record whose [[Specifier]] is required, then
-                let loaded = self.loaded_modules.borrow().get(&required).cloned();
+            // d. For each ModuleRequest Record request of module.[[RequestedModules]], do
+            for request in requested {
+                // i. If module.[[LoadedModules]] contains a LoadedModuleRequest Record record such that
+                //    ModuleRequestsEqual(record, request) is true, then
+                let loaded = self.loaded_modules.borrow().get(request).cloned();
                 if let Some(loaded) = loaded {
                     // 1. Let record be that Record.
                     // 2. Perform InnerModuleLoading(state, record.[[Module]]).
                     loaded.inner_load(state, context);
                 } else {
                     //    ii. Else,
-                    //       1. Perform HostLoadImportedModule(module, required, state.[[HostDefined]], state).
+                    //       1. Perform HostLoadImportedModule(module, request, state.[[HostDefined]], state).
                     //       2. NOTE: HostLoadImportedModule will call FinishLoadingImportedModule, which re-enters
                     //          the graph loading process through ContinueModuleLoading.
-                    let name_specifier = required.clone();
                     let src = module_self.clone();
                     let state = state.clone();
+                    let required = request.clone();
                     context.module_loader().load_imported_module(
                         Referrer::Module(module_self.clone()),
-                        name_specifier,
+                        request.clone(),
                         Box::new(move |completion, context| {
-                            // FinishLoadingImportedModule ( referrer, specifier, payload, result )
+                            // FinishLoadingImportedModule ( referrer, moduleRequest, payload, result )
                             // https://tc39.es/ecma262/#sec-FinishLoadingImportedModule
 
                             // 1. If result is a normal completion, then
@@ -398,9 +600,12 @@ impl SourceTextModule {
                                 let ModuleKind::SourceText(src) = src.kind() else {
                                     unreachable!("captured src must be a source text module");
                                 };
-                                // a. If referrer.[[LoadedModules]] contains a Record whose [[Specifier]] is specifier, then
+                                // a. If referrer.[[LoadedModules]] contains a LoadedModuleRequest Record record such that
+                                //    ModuleRequestsEqual(record, moduleRequest) is true, then
                                 // b. Else,
-                                //    i. Append the Record { [[Specifier]]: specifier, [[Module]]: result.[[Value]] } to referrer.[[LoadedModules]].
+                                //    i. Append the LoadedModuleRequest Record { [[Specifier]]: moduleRequest.[[Specifier]],
+                                //       [[Attributes]]: moduleRequest.[[Attributes]], [[Module]]: result.[[Value]] }
+                                //       to referrer.[[LoadedModules]].
                                 let mut loaded_modules = src.loaded_modules.borrow_mut();
                                 let entry = loaded_modules
                                     .entry(required)
@@ -564,7 +769,7 @@ impl SourceTextModule {
             // a. If SameValue(exportName, e.[[ExportName]]) is true, then
             if export_name == &e.export_name().to_js_string(interner) {
                 // i. Let importedModule be GetImportedModule(module, e.[[ModuleRequest]]).
-                let module_request = e.module_request().to_js_string(interner);
+                let module_request = module_request(e.module_request(), interner);
                 let imported_module = self.loaded_modules.borrow()[&module_request].clone();
                 return match e.import_name() {
                     // ii. If e.[[ImportName]] is all, then
@@ -1426,189 +1631,82 @@ impl SourceTextModule {
         // 5. Let env be NewModuleEnvironment(realm.[[GlobalEnv]]).
         // 6. Set module.[[Environment]] to env.
         let global_env = realm.environment().clone();
-        let env = self.code.source.scope().clone();
+        let env = self.code.scope.clone();
 
-        let spanned_source_text = SpannedSourceText::new_source_only(self.code.source_text.clone());
-        let mut compiler = ByteCompiler::new(
-            js_string!("<main>"),
-            true,
-            false,
-            self.code.source.scope().clone(),
-            self.code.source.scope().clone(),
-            true,
-            false,
-            context.interner_mut(),
-            false,
-            spanned_source_text,
-        );
+        let mut imports = Vec::new();
 
-        compiler.async_handler = Some(compiler.push_handler());
+        // 7. For each ImportEntry Record in of module.[[ImportEntries]], do
+        for entry in &self.code.import_entries {
+            // a. Let importedModule be GetImportedModule(module, in.[[ModuleRequest]]).
+            let module_request = module_request(entry.module_request(), context.interner());
+            let imported_module = self.loaded_modules.borrow()[&module_request].clone();
 
-        let mut imports = Vec::new();
+            if let ImportName::Name(name) = entry.import_name() {
+                let name = name.to_js_string(context.interner());
+                // c. Else,
+                //    i. Let resolution be importedModule.ResolveExport(in.[[ImportName]]).
+                let resolution = imported_module
+                    .resolve_export(name.clone(), &mut HashSet::default(), context.interner())
+                    // ii. If resolution is either null or ambiguous, throw a SyntaxError exception.
+                    .map_err(|err| match err {
+                        ResolveExportError::NotFound => JsNativeError::syntax().with_message(
+                            format!("could not find export `{}`", name.to_std_string_escaped()),
+                        ),
+                        ResolveExportError::Ambiguous => {
+                            JsNativeError::syntax().with_message(format!(
+                                "could not resolve ambiguous export `{}`",
+                                name.to_std_string_escaped()
+                            ))
+                        }
+                    })?;
 
-        let (codeblock, functions) = {
-            // 7. For each ImportEntry Record in of module.[[ImportEntries]], do
-            for entry in &self.code.import_entries {
-                // a. Let importedModule be GetImportedModule(module, in.[[ModuleRequest]]).
-                let module_request = entry.module_request().to_js_string(compiler.interner());
-                let imported_module = self.loaded_modules.borrow()[&module_request].clone();
+                // 2. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
+                // 3. Perform ! env.InitializeBinding(in.[[LocalName]], namespace).
+                let local_name = entry.local_name().to_js_string(context.interner());
+                let locator = env.get_binding(&local_name).expect("binding must exist");
 
-                if let ImportName::Name(name) = entry.import_name() {
-                    let name = name.to_js_string(compiler.interner());
-                    // c. Else,
-                    //    i. Let resolution be importedModule.ResolveExport(in.[[ImportName]]).
-                    let resolution = imported_module
-                        .resolve_export(name.clone(), &mut HashSet::default(), compiler.interner())
-                        // ii. If resolution is either null or ambiguous, throw a SyntaxError exception.
-                        .map_err(|err| match err {
-                            ResolveExportError::NotFound => JsNativeError::syntax().with_message(
-                                format!("could not find export `{}`", name.to_std_string_escaped()),
-                            ),
-                            ResolveExportError::Ambiguous => {
-                                JsNativeError::syntax().with_message(format!(
-                                    "could not resolve ambiguous export `{}`",
-                                    name.to_std_string_escaped()
-                                ))
-                            }
-                        })?;
-
-                    // 2. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
-                    // 3. Perform ! env.InitializeBinding(in.[[LocalName]], namespace).
-                    let local_name = entry.local_name().to_js_string(compiler.interner());
-                    let locator = env.get_binding(&local_name).expect("binding must exist");
-
-                    if let BindingName::Name(_) = resolution.binding_name {
-                        // 1. Perform env.CreateImportBinding(in.[[LocalName]], resolution.[[Module]],
-                        //    resolution.[[BindingName]]).
-                        //    deferred to initialization below
-                        imports.push(ImportBinding::Single {
-                            locator,
-                            export_locator: resolution,
-                        });
-                    } else {
-                        // 1. Let namespace be GetModuleNamespace(resolution.[[Module]]).
-                        // deferred to initialization below
-                        imports.push(ImportBinding::Namespace {
-                            locator,
-                            module: resolution.module,
-                        });
-                    }
+                if let BindingName::Name(_) = resolution.binding_name {
+                    // 1. Perform env.CreateImportBinding(in.[[LocalName]], resolution.[[Module]],
+                    //    resolution.[[BindingName]]).
+                    //    deferred to initialization below
+                    imports.push(ImportBinding::Single {
+                        locator,
+                        export_locator: resolution,
+                    });
                 } else {
-                    // b. If in.[[ImportName]] is namespace-object, then
-                    //    ii. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
-                    //    iii. Perform ! env.InitializeBinding(in.[[LocalName]], namespace).
-                    let name = entry.local_name().to_js_string(compiler.interner());
-                    let locator = env.get_binding(&name).expect("binding must exist");
-
-                    //    i. Let namespace be GetModuleNamespace(importedModule).
-                    //       deferred to initialization below
+                    // 1. Let namespace be GetModuleNamespace(resolution.[[Module]]).
+                    // deferred to initialization below
                     imports.push(ImportBinding::Namespace {
                         locator,
-                        module: imported_module.clone(),
+                        module: resolution.module,
                     });
                 }
+            } else {
+                // b. If in.[[ImportName]] is namespace-object, then
+                //    ii. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
+                //    iii. Perform ! env.InitializeBinding(in.[[LocalName]], namespace).
+                let name = entry.local_name().to_js_string(context.interner());
+                let locator = env.get_binding(&name).expect("binding must exist");
+
+                //    i. Let namespace be GetModuleNamespace(importedModule).
+                //       deferred to initialization below
+                imports.push(ImportBinding::Namespace {
+                    locator,
+                    module: imported_module.clone(),
+                });
             }
+        }
 
-            // 18. Let code be module.[[ECMAScriptCode]].
-            // 19. Let varDeclarations be the VarScopedDeclarations of code.
-            let var_declarations = var_scoped_declarations(&self.code.source);
-            // 20. Let declaredVarNames be a new empty List.
-            let mut declared_var_names = Vec::new();
-            // 21. For each element d of varDeclarations, do
-            for var in var_declarations {
-                // a. For each element dn of the BoundNames of d, do
-                for name in var.bound_names() {
-                    let name = name.to_js_string(compiler.interner());
-
-                    // i. If declaredVarNames does not contain dn, then
-                    if !declared_var_names.contains(&name) {
-                        // 1. Perform ! env.CreateMutableBinding(dn, false).
-                        // 2. Perform ! env.InitializeBinding(dn, undefined).
-                        let binding = env
-                            .get_binding_reference(&name)
-                            .expect("binding must exist");
-                        let index = compiler.insert_binding(binding);
-                        let value = compiler.register_allocator.alloc();
-                        compiler.bytecode.emit_push_undefined(value.variable());
-                        compiler.emit_binding_access(
-                            BindingAccessOpcode::DefInitVar,
-                            &index,
-                            &value,
-                        );
-                        compiler.register_allocator.dealloc(value);
-
-                        // 3. Append dn to declaredVarNames.
-                        declared_var_names.push(name);
-                    }
-                }
-            }
-
-            // 22. Let lexDeclarations be the LexicallyScopedDeclarations of code.
-            // 23. Let privateEnv be null.
-            let lex_declarations = lexically_scoped_declarations(&self.code.source);
-            let mut functions = Vec::new();
-            // 24. For each element d of lexDeclarations, do
-            for declaration in lex_declarations {
-                // ii. Else,
-                // a. For each element dn of the BoundNames of d, do
-                // 1. Perform ! env.CreateMutableBinding(dn, false).
-                //
-                // iii. If d is either a FunctionDeclaration, a GeneratorDeclaration, an
-                //      AsyncFunctionDeclaration, or an AsyncGeneratorDeclaration, then
-                // 1. Let fo be InstantiateFunctionObject of d with arguments env and privateEnv.
-                // 2. Perform ! env.InitializeBinding(dn, fo).
-                //
-                // deferred to below.
-                let (spec, locator): (FunctionSpec<'_>, _) = match declaration {
-                    LexicallyScopedDeclaration::FunctionDeclaration(f) => {
-                        let name = bound_names(f)[0].to_js_string(compiler.interner());
-                        let locator = env.get_binding(&name).expect("binding must exist");
-
-                        (f.into(), locator)
-                    }
-                    LexicallyScopedDeclaration::GeneratorDeclaration(g) => {
-                        let name = bound_names(g)[0].to_js_string(compiler.interner());
-                        let locator = env.get_binding(&name).expect("binding must exist");
-
-                        (g.into(), locator)
-                    }
-                    LexicallyScopedDeclaration::AsyncFunctionDeclaration(af) => {
-                        let name = bound_names(af)[0].to_js_string(compiler.interner());
-                        let locator = env.get_binding(&name).expect("binding must exist");
-
-                        (af.into(), locator)
-                    }
-                    LexicallyScopedDeclaration::AsyncGeneratorDeclaration(ag) => {
-                        let name = bound_names(ag)[0].to_js_string(compiler.interner());
-                        let locator = env.get_binding(&name).expect("binding must exist");
-
-                        (ag.into(), locator)
-                    }
-                    LexicallyScopedDeclaration::ClassDeclaration(_)
-                    | LexicallyScopedDeclaration::LexicalDeclaration(_)
-                    | LexicallyScopedDeclaration::AssignmentExpression(_) => {
-                        continue;
-                    }
-                };
-
-                functions.push((spec, locator));
-            }
-
-            // Should compile after initializing bindings first to ensure inner calls
-            // are correctly resolved to the outer functions instead of as global bindings.
-            let functions = functions
-                .into_iter()
-                .map(|(spec, locator)| (compiler.function(spec), locator))
-                .collect::<Vec<_>>();
-
-            compiler.compile_module_item_list(self.code.source.items());
-
-            (Gc::new(compiler.finish()), functions)
-        };
+        // 18. Let code be module.[[ECMAScriptCode]].
+        // NOTE: Steps 19 to 24 only depend on the code of the module, and are done when
+        //       compiling the module.
+        let compiled = self.compile(context);
+        let codeblock = compiled.codeblock.clone();
+        let functions = compiled.functions.clone();
 
         // 8. Let moduleContext be a new ECMAScript code execution context.
         let mut envs = EnvironmentStack::new(global_env);
-        envs.push_module(self.code.source.scope().clone());
+        envs.push_module(self.code.scope.clone());
 
         // 9. Set the Function of moduleContext to null.
         // 10. Assert: module.[[Realm]] is not undefined.
@@ -1667,8 +1765,8 @@ impl SourceTextModule {
         }
 
         // deferred initialization of function exports
-        for (index, locator) in functions {
-            let code = codeblock.constant_function(index as usize);
+        for (index, locator) in functions.iter() {
+            let code = codeblock.constant_function(*index as usize);
 
             let function = create_function_object_fast(code, context);
 
@@ -1711,6 +1809,137 @@ impl SourceTextModule {
         Ok(())
     }
 
+    /// Compiles the code of this module, or returns the code compiled previously.
+    ///
+    /// Contains the steps of [`InitializeEnvironment ( )`][spec] that only depend on the code of
+    /// the module.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-source-text-module-record-initialize-environment
+    fn compile(&self, context: &mut Context) -> CompiledModule {
+        if let Some(compiled) = &*self.compiled.borrow() {
+            return compiled.clone();
+        }
+
+        let source = self
+            .code
+            .source
+            .as_ref()
+            .expect("modules loaded from a bytecode cache are compiled when loaded");
+        let env = &self.code.scope;
+
+        let spanned_source_text = SpannedSourceText::new_source_only(self.code.source_text.clone());
+        let mut compiler = ByteCompiler::new(
+            js_string!("<main>"),
+            true,
+            false,
+            env.clone(),
+            env.clone(),
+            true,
+            false,
+            context.interner_mut(),
+            false,
+            spanned_source_text,
+        );
+
+        compiler.async_handler = Some(compiler.push_handler());
+
+        // 19. Let varDeclarations be the VarScopedDeclarations of code.
+        let var_declarations = var_scoped_declarations(source);
+        // 20. Let declaredVarNames be a new empty List.
+        let mut declared_var_names = Vec::new();
+        // 21. For each element d of varDeclarations, do
+        for var in var_declarations {
+            // a. For each element dn of the BoundNames of d, do
+            for name in var.bound_names() {
+                let name = name.to_js_string(compiler.interner());
+
+                // i. If declaredVarNames does not contain dn, then
+                if !declared_var_names.contains(&name) {
+                    // 1. Perform ! env.CreateMutableBinding(dn, false).
+                    // 2. Perform ! env.InitializeBinding(dn, undefined).
+                    let binding = env
+                        .get_binding_reference(&name)
+                        .expect("binding must exist");
+                    let index = compiler.insert_binding(binding);
+                    let value = compiler.register_allocator.alloc();
+                    compiler.bytecode.emit_push_undefined(value.variable());
+                    compiler.emit_binding_access(BindingAccessOpcode::DefInitVar, &index, &value);
+                    compiler.register_allocator.dealloc(value);
+
+                    // 3. Append dn to declaredVarNames.
+                    declared_var_names.push(name);
+                }
+            }
+        }
+
+        // 22. Let lexDeclarations be the LexicallyScopedDeclarations of code.
+        // 23. Let privateEnv be null.
+        let lex_declarations = lexically_scoped_declarations(source);
+        let mut functions = Vec::new();
+        // 24. For each element d of lexDeclarations, do
+        for declaration in lex_declarations {
+            // ii. Else,
+            // a. For each element dn of the BoundNames of d, do
+            // 1. Perform ! env.CreateMutableBinding(dn, false).
+            //
+            // iii. If d is either a FunctionDeclaration, a GeneratorDeclaration, an
+            //      AsyncFunctionDeclaration, or an AsyncGeneratorDeclaration, then
+            // 1. Let fo be InstantiateFunctionObject of d with arguments env and privateEnv.
+            // 2. Perform ! env.InitializeBinding(dn, fo).
+            //
+            // deferred to below.
+            let (spec, locator): (FunctionSpec<'_>, _) = match declaration {
+                LexicallyScopedDeclaration::FunctionDeclaration(f) => {
+                    let name = bound_names(f)[0].to_js_string(compiler.interner());
+                    let locator = env.get_binding(&name).expect("binding must exist");
+
+                    (f.into(), locator)
+                }
+                LexicallyScopedDeclaration::GeneratorDeclaration(g) => {
+                    let name = bound_names(g)[0].to_js_string(compiler.interner());
+                    let locator = env.get_binding(&name).expect("binding must exist");
+
+                    (g.into(), locator)
+                }
+                LexicallyScopedDeclaration::AsyncFunctionDeclaration(af) => {
+                    let name = bound_names(af)[0].to_js_string(compiler.interner());
+                    let locator = env.get_binding(&name).expect("binding must exist");
+
+                    (af.into(), locator)
+                }
+                LexicallyScopedDeclaration::AsyncGeneratorDeclaration(ag) => {
+                    let name = bound_names(ag)[0].to_js_string(compiler.interner());
+                    let locator = env.get_binding(&name).expect("binding must exist");
+
+                    (ag.into(), locator)
+                }
+                LexicallyScopedDeclaration::ClassDeclaration(_)
+                | LexicallyScopedDeclaration::LexicalDeclaration(_)
+                | LexicallyScopedDeclaration::AssignmentExpression(_) => {
+                    continue;
+                }
+            };
+
+            functions.push((spec, locator));
+        }
+
+        // Should compile after initializing bindings first to ensure inner calls
+        // are correctly resolved to the outer functions instead of as global bindings.
+        let functions = functions
+            .into_iter()
+            .map(|(spec, locator)| (compiler.function(spec), locator))
+            .collect::<Vec<_>>();
+
+        compiler.compile_module_item_list(source.items());
+
+        let module = CompiledModule {
+            codeblock: Gc::new(compiler.finish()),
+            functions: functions.into(),
+        };
+        *self.compiled.borrow_mut() = Some(module.clone());
+        module
+    }
+
     /// Abstract operation [`ExecuteModule ( [ capability ] )`][spec].
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-source-text-module-record-execute-module
@@ -1781,7 +2010,7 @@ impl SourceTextModule {
     }
 
     /// Gets the loaded modules of this module.
-    pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<JsString, Module>> {
+    pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<ModuleRequest, Module>> {
         &self.loaded_modules
     }
 
@@ -1797,6 +2026,70 @@ impl SourceTextModule {
     }
 }
 
+/// Reads a string from a bytecode cache, and interns it in `interner`.
+fn read_sym(reader: &mut CacheReader<'_>, interner: &mut Interner) -> JsResult<Sym> {
+    let string = reader.read_string()?;
+    Ok(interner.get_or_intern(&string.to_vec()[..]))
+}
+
+/// Converts a module request of the AST into a [`ModuleRequest`].
+fn module_request(
+    request: &boa_ast::declaration::ModuleRequest,
+    interner: &Interner,
+) -> ModuleRequest {
+    let attributes = request
+        .attributes()
+        .iter()
+        .map(|attribute| {
+            ImportAttribute::new(
+                attribute.key().to_js_string(interner),
+                attribute.value().to_js_string(interner),
+            )
+        })
+        .collect();
+    ModuleRequest::new(request.specifier().to_js_string(interner), attributes)
+}
+
+fn write_request(writer: &mut CacheWriter, request: &ModuleRequest) {
+    writer.write_string(request.specifier());
+    writer.write_usize(request.attributes().len());
+    for attribute in request.attributes() {
+        writer.write_string(attribute.key());
+        writer.write_string(attribute.value());
+    }
+}
+
+fn read_request(reader: &mut CacheReader<'_>) -> JsResult<ModuleRequest> {
+    let specifier = reader.read_string()?;
+    let mut attributes = Vec::new();
+    for _ in 0..reader.read_usize()? {
+        attributes.push(ImportAttribute::new(
+            reader.read_string()?,
+            reader.read_string()?,
+        ));
+    }
+    Ok(ModuleRequest::new(specifier, attributes.into_boxed_slice()))
+}
+
+/// Reads a request written by [`write_request`] as a module request of the AST.
+fn read_ast_request(
+    reader: &mut CacheReader<'_>,
+    interner: &mut Interner,
+) -> JsResult<boa_ast::declaration::ModuleRequest> {
+    let specifier = read_sym(reader, interner)?;
+    let mut attributes = Vec::new();
+    for _ in 0..reader.read_usize()? {
+        attributes.push(boa_ast::declaration::ImportAttribute::new(
+            read_sym(reader, interner)?,
+            read_sym(reader, interner)?,
+        ));
+    }
+    Ok(boa_ast::declaration::ModuleRequest::new(
+        specifier,
+        &attributes,
+    ))
+}
+
 /// Abstract operation [`AsyncModuleExecutionFulfilled ( module )`][spec].
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-async-module-execution-fulfilled
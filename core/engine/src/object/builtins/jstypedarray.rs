@@ -1,6 +1,6 @@
 //! Rust API wrappers for the `TypedArray` Builtin ECMAScript Objects
 use crate::{
-    builtins::typed_array::BuiltinTypedArray,
+    builtins::typed_array::{base64, Base64Alphabet, BuiltinTypedArray, LastChunkHandling},
     builtins::{typed_array::TypedArray, BuiltInConstructor},
     error::JsNativeError,
     object::{JsArrayBuffer, JsFunction, JsObject},
@@ -8,6 +8,7 @@ use crate::{
     Context, JsResult, JsString, JsValue,
 };
 use boa_gc::{Finalize, Trace};
+use float16::f16;
 use std::ops::Deref;
 
 /// `JsTypedArray` provides a wrapper for Boa's implementation of the ECMAScript `TypedArray`
@@ -1087,6 +1088,72 @@ JsTypedArrayType!(
     to_uint8,
     u8
 );
+
+impl JsUint8Array {
+    /// Creates a `JsUint8Array` from a base64 encoded string.
+    ///
+    /// Same as `Uint8Array.fromBase64(string, { alphabet, lastChunkHandling })` in JavaScript.
+    pub fn from_base64(
+        string: &JsString,
+        alphabet: Base64Alphabet,
+        last_chunk_handling: LastChunkHandling,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let object = base64::decode_base64(string, alphabet, last_chunk_handling, context)?;
+        Self::from_object(object)
+    }
+
+    /// Creates a `JsUint8Array` from a hex encoded string.
+    ///
+    /// Same as `Uint8Array.fromHex(string)` in JavaScript.
+    pub fn from_hex(string: &JsString, context: &mut Context) -> JsResult<Self> {
+        let object = base64::decode_hex(string, context)?;
+        Self::from_object(object)
+    }
+
+    /// Encodes the contents of the array into a base64 string.
+    ///
+    /// Same as `array.toBase64({ alphabet, omitPadding })` in JavaScript.
+    pub fn to_base64(&self, alphabet: Base64Alphabet, omit_padding: bool) -> JsResult<JsString> {
+        base64::to_base64(&self.typed_array(), alphabet, omit_padding)
+    }
+
+    /// Encodes the contents of the array into a lowercase hex string.
+    ///
+    /// Same as `array.toHex()` in JavaScript.
+    pub fn to_hex(&self) -> JsResult<JsString> {
+        base64::to_hex(&self.typed_array())
+    }
+
+    /// Decodes a base64 encoded string into the array, returning the number of code units read
+    /// from the string and the number of bytes written to the array.
+    ///
+    /// Same as `array.setFromBase64(string, { alphabet, lastChunkHandling })` in JavaScript.
+    pub fn set_from_base64(
+        &self,
+        string: &JsString,
+        alphabet: Base64Alphabet,
+        last_chunk_handling: LastChunkHandling,
+    ) -> JsResult<(usize, usize)> {
+        base64::decode_base64_into(&self.typed_array(), string, alphabet, last_chunk_handling)
+    }
+
+    /// Decodes a hex encoded string into the array, returning the number of code units read
+    /// from the string and the number of bytes written to the array.
+    ///
+    /// Same as `array.setFromHex(string)` in JavaScript.
+    pub fn set_from_hex(&self, string: &JsString) -> JsResult<(usize, usize)> {
+        base64::decode_hex_into(&self.typed_array(), string)
+    }
+
+    fn typed_array(&self) -> JsObject<TypedArray> {
+        self.inner
+            .inner
+            .clone()
+            .downcast::<TypedArray>()
+            .expect("a `JsUint8Array` must be a typed array")
+    }
+}
 JsTypedArrayType!(
     JsInt8Array,
     Int8Array,
@@ -1127,6 +1194,14 @@ JsTypedArrayType!(
     to_i32,
     i32
 );
+JsTypedArrayType!(
+    JsFloat16Array,
+    Float16Array,
+    is_typed_float16_array,
+    typed_float16_array,
+    to_f16,
+    f16
+);
 JsTypedArrayType!(
     JsFloat32Array,
     Float32Array,
@@ -1154,6 +1229,44 @@ fn typed_iterators_uint8() {
     assert_eq!(vec, vec2);
 }
 
+#[test]
+fn uint8_array_base64() {
+    use crate::js_string;
+
+    let context = &mut Context::default();
+    let array = JsUint8Array::from_iter(*b"boa engine", context).unwrap();
+
+    assert_eq!(
+        array.to_base64(Base64Alphabet::Base64, false).unwrap(),
+        js_string!("Ym9hIGVuZ2luZQ==")
+    );
+    assert_eq!(
+        array.to_base64(Base64Alphabet::Base64Url, true).unwrap(),
+        js_string!("Ym9hIGVuZ2luZQ")
+    );
+    assert_eq!(array.to_hex().unwrap(), js_string!("626f6120656e67696e65"));
+
+    let decoded = JsUint8Array::from_base64(
+        &js_string!("Ym9hIGVuZ2luZQ"),
+        Base64Alphabet::Base64,
+        LastChunkHandling::Loose,
+        context,
+    )
+    .unwrap();
+    assert_eq!(decoded.iter(context).collect::<Vec<_>>(), b"boa engine");
+    assert!(JsUint8Array::from_base64(
+        &js_string!("Ym9hIGVuZ2luZQ"),
+        Base64Alphabet::Base64,
+        LastChunkHandling::Strict,
+        context,
+    )
+    .is_err());
+
+    let target = JsUint8Array::from_iter([0; 4], context).unwrap();
+    assert_eq!(target.set_from_hex(&js_string!("cafe")).unwrap(), (4, 2));
+    assert_eq!(target.iter(context).collect::<Vec<_>>(), [0xCA, 0xFE, 0, 0]);
+}
+
 #[test]
 fn typed_iterators_uint32() {
     let context = &mut Context::default();
@@ -1164,6 +1277,22 @@ fn typed_iterators_uint32() {
     assert_eq!(vec, vec2);
 }
 
+#[test]
+fn typed_iterators_f16() {
+    let context = &mut Context::default();
+    let vec = vec![0.5, 1.0, 65504.0, -2.25, 0.000_061_035_156_25]
+        .into_iter()
+        .map(f16::from_f64)
+        .collect::<Vec<_>>();
+
+    let array = JsFloat16Array::from_iter(vec.clone(), context).unwrap();
+    let vec2 = array.iter(context).collect::<Vec<_>>();
+    assert_eq!(
+        vec.iter().map(|f| f.to_bits()).collect::<Vec<_>>(),
+        vec2.iter().map(|f| f.to_bits()).collect::<Vec<_>>()
+    );
+}
+
 #[test]
 fn typed_iterators_f32() {
     let context = &mut Context::default();
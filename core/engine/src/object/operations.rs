@@ -1,4 +1,6 @@
 use super::internal_methods::InternalMethodContext;
+#[cfg(feature = "experimental")]
+use crate::builtins::shadow_realm::WrappedFunction;
 use crate::value::JsVariant;
 use crate::{
     builtins::{
@@ -830,6 +832,11 @@ impl JsObject {
             return fun.get_function_realm(context);
         }
 
+        #[cfg(feature = "experimental")]
+        if let Some(wrapped) = constructor.downcast_ref::<WrappedFunction>() {
+            return Ok(wrapped.realm().clone());
+        }
+
         Ok(context.realm().clone())
     }
 
@@ -1142,6 +1149,12 @@ impl JsObject {
             self.private_method_or_accessor_add(name, method, context)?;
         }
 
+        // NOTE: The extra initializers added by the decorators of class elements run after the
+        //       private methods are added, and before any field is defined.
+        for initializer in constructor_function.get_initializers() {
+            initializer.call(&self.clone().into(), &[], context)?;
+        }
+
         // 3. Let fields be the value of constructor.[[Fields]].
         // 4. For each element fieldRecord of fields, do
         for field_record in constructor_function.get_fields() {
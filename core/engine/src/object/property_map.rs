@@ -685,6 +685,16 @@ impl PropertyMap {
         }
     }
 
+    /// Returns the number of dense indexed properties, or `None` if they are stored sparsely.
+    pub(crate) fn dense_indexed_properties_len(&self) -> Option<usize> {
+        match &self.indexed_properties {
+            IndexedProperties::DenseI32(properties) => Some(properties.len()),
+            IndexedProperties::DenseF64(properties) => Some(properties.len()),
+            IndexedProperties::DenseElement(properties) => Some(properties.len()),
+            IndexedProperties::Sparse(_) => None,
+        }
+    }
+
     /// Returns the vec of dense indexed properties if they exist.
     pub(crate) fn to_dense_indexed_properties(&self) -> Option<ThinVec<JsValue>> {
         match &self.indexed_properties {
@@ -5,7 +5,7 @@ use crate::{
 };
 use boa_ast::{
     expression::{
-        literal::Literal,
+        literal::{Literal, LiteralKind},
         operator::{
             binary::{ArithmeticOp, BinaryOp, BitwiseOp, LogicalOp, RelationalOp},
             unary::UnaryOp,
@@ -17,34 +17,35 @@ use boa_ast::{
 use boa_interner::JStrRef;
 
 fn literal_to_js_value(literal: &Literal, context: &mut Context) -> JsValue {
-    match literal {
-        Literal::String(v) => JsValue::new(v.to_js_string(context.interner())),
-        Literal::Num(v) => JsValue::new(*v),
-        Literal::Int(v) => JsValue::new(*v),
-        Literal::BigInt(v) => JsValue::new(JsBigInt::new(v.clone())),
-        Literal::Bool(v) => JsValue::new(*v),
-        Literal::Null => JsValue::null(),
-        Literal::Undefined => JsValue::undefined(),
+    match literal.kind() {
+        LiteralKind::String(v) => JsValue::new(v.to_js_string(context.interner())),
+        LiteralKind::Num(v) => JsValue::new(*v),
+        LiteralKind::Int(v) => JsValue::new(*v),
+        LiteralKind::BigInt(v) => JsValue::new(JsBigInt::new(v.clone())),
+        LiteralKind::Bool(v) => JsValue::new(*v),
+        LiteralKind::Null => JsValue::null(),
+        LiteralKind::Undefined => JsValue::undefined(),
     }
 }
 
 fn js_value_to_literal(value: &JsValue, context: &mut Context) -> Literal {
-    match value.variant() {
-        JsVariant::Null => Literal::Null,
-        JsVariant::Undefined => Literal::Undefined,
-        JsVariant::Boolean(v) => Literal::Bool(v),
+    let kind = match value.variant() {
+        JsVariant::Null => LiteralKind::Null,
+        JsVariant::Undefined => LiteralKind::Undefined,
+        JsVariant::Boolean(v) => LiteralKind::Bool(v),
         JsVariant::String(v) => {
             // TODO: Replace JStrRef with JsStr this would eliminate the to_vec call.
             let v = v.to_vec();
-            Literal::String(context.interner_mut().get_or_intern(JStrRef::Utf16(&v)))
+            LiteralKind::String(context.interner_mut().get_or_intern(JStrRef::Utf16(&v)))
         }
-        JsVariant::Float64(v) => Literal::Num(v),
-        JsVariant::Integer32(v) => Literal::Int(v),
-        JsVariant::BigInt(v) => Literal::BigInt(Box::new(v.as_inner().clone())),
+        JsVariant::Float64(v) => LiteralKind::Num(v),
+        JsVariant::Integer32(v) => LiteralKind::Int(v),
+        JsVariant::BigInt(v) => LiteralKind::BigInt(Box::new(v.as_inner().clone())),
         JsVariant::Object(_) | JsVariant::Symbol(_) => {
             unreachable!("value must not be an object or symbol")
         }
-    }
+    };
+    Literal::new(kind)
 }
 
 #[derive(Debug, Default)]
@@ -90,10 +91,10 @@ impl ConstantFolding {
                 literal_to_js_value(literal, context).js_type_of(),
             )),
             (_, UnaryOp::Delete) => {
-                return PassAction::Replace(Expression::Literal(Literal::Bool(true)))
+                return PassAction::Replace(Expression::from(LiteralKind::Bool(true)))
             }
             (_, UnaryOp::Void) => {
-                return PassAction::Replace(Expression::Literal(Literal::Undefined))
+                return PassAction::Replace(Expression::from(LiteralKind::Undefined))
             }
         };
 
@@ -129,16 +130,16 @@ impl ConstantFolding {
             if !matches!(binary.rhs(), Expression::Literal(_)) {
                 // If left-hand side is already undefined then just keep it,
                 // so we don't cause an infinite loop.
-                if *binary.lhs() == Expression::Literal(Literal::Undefined) {
+                if *binary.lhs() == Expression::from(LiteralKind::Undefined) {
                     return PassAction::Keep;
                 }
 
-                *binary.lhs_mut() = Expression::Literal(Literal::Undefined);
+                *binary.lhs_mut() = Expression::from(LiteralKind::Undefined);
                 return PassAction::Modified;
             }
 
             // We take rhs, by replacing with a dummy value.
-            let rhs = std::mem::replace(binary.rhs_mut(), Expression::Literal(Literal::Undefined));
+            let rhs = std::mem::replace(binary.rhs_mut(), Expression::from(LiteralKind::Undefined));
             return PassAction::Replace(rhs);
         }
 
@@ -161,23 +162,41 @@ impl ConstantFolding {
             let expr = match op {
                 LogicalOp::And => {
                     if lhs.to_boolean() {
-                        std::mem::replace(binary.rhs_mut(), Expression::Literal(Literal::Undefined))
+                        std::mem::replace(
+                            binary.rhs_mut(),
+                            Expression::from(LiteralKind::Undefined),
+                        )
                     } else {
-                        std::mem::replace(binary.lhs_mut(), Expression::Literal(Literal::Undefined))
+                        std::mem::replace(
+                            binary.lhs_mut(),
+                            Expression::from(LiteralKind::Undefined),
+                        )
                     }
                 }
                 LogicalOp::Or => {
                     if lhs.to_boolean() {
-                        std::mem::replace(binary.lhs_mut(), Expression::Literal(Literal::Undefined))
+                        std::mem::replace(
+                            binary.lhs_mut(),
+                            Expression::from(LiteralKind::Undefined),
+                        )
                     } else {
-                        std::mem::replace(binary.rhs_mut(), Expression::Literal(Literal::Undefined))
+                        std::mem::replace(
+                            binary.rhs_mut(),
+                            Expression::from(LiteralKind::Undefined),
+                        )
                     }
                 }
                 LogicalOp::Coalesce => {
                     if lhs.is_null_or_undefined() {
-                        std::mem::replace(binary.rhs_mut(), Expression::Literal(Literal::Undefined))
+                        std::mem::replace(
+                            binary.rhs_mut(),
+                            Expression::from(LiteralKind::Undefined),
+                        )
                     } else {
-                        std::mem::replace(binary.lhs_mut(), Expression::Literal(Literal::Undefined))
+                        std::mem::replace(
+                            binary.lhs_mut(),
+                            Expression::from(LiteralKind::Undefined),
+                        )
                     }
                 }
             };
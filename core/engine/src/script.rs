@@ -8,23 +8,39 @@
 //! [spec]: https://tc39.es/ecma262/#sec-scripts
 //! [script]: https://tc39.es/ecma262/#sec-script-records
 
-use std::path::{Path, PathBuf};
+use std::{
+    cell::RefCell,
+    path::{Path, PathBuf},
+};
 
 use rustc_hash::FxHashMap;
 
+use boa_ast::{
+    declaration::LexicalDeclaration,
+    operations::{bound_names, var_declared_names},
+    scope::Scope,
+    Declaration, StatementListItem,
+};
 use boa_gc::{Finalize, Gc, GcRefCell, Trace};
+use boa_interner::Interner;
 use boa_parser::{source::ReadChar, Parser, Source};
 use boa_profiler::Profiler;
 
 use crate::{
-    bytecompiler::{global_declaration_instantiation_context, ByteCompiler},
+    bytecompiler::{global_declaration_instantiation_context, ByteCompiler, ToJsString},
     js_string,
+    module::ModuleRequest,
     realm::Realm,
     spanned_source_text::SourceText,
-    vm::{ActiveRunnable, CallFrame, CallFrameFlags, CodeBlock},
-    Context, HostDefined, JsResult, JsString, JsValue, Module, SpannedSourceText,
+    vm::{
+        ActiveRunnable, CacheKind, CacheReader, CacheWriter, CallFrame, CallFrameFlags, CodeBlock,
+    },
+    Context, HostDefined, JsNativeError, JsResult, JsString, JsValue, Module, SpannedSourceText,
 };
 
+#[cfg(feature = "annex-b")]
+use boa_ast::operations::{annex_b_function_declarations_names, lexically_declared_names};
+
 /// ECMAScript's [**Script Record**][spec].
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-script-records
@@ -37,7 +53,7 @@ impl std::fmt::Debug for Script {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Script")
             .field("realm", &self.inner.realm.addr())
-            .field("code", &self.inner.source)
+            .field("code", &self.inner.code)
             .field("loaded_modules", &self.inner.loaded_modules)
             .finish()
     }
@@ -47,10 +63,12 @@ impl std::fmt::Debug for Script {
 struct Inner {
     realm: Realm,
     #[unsafe_ignore_trace]
-    source: boa_ast::Script,
+    code: ScriptCode,
     source_text: SourceText,
     codeblock: GcRefCell<Option<Gc<CodeBlock>>>,
-    loaded_modules: GcRefCell<FxHashMap<JsString, Module>>,
+    #[unsafe_ignore_trace]
+    annex_b_function_names: RefCell<Vec<JsString>>,
+    loaded_modules: GcRefCell<FxHashMap<ModuleRequest, Module>>,
     host_defined: HostDefined,
     path: Option<PathBuf>,
 }
@@ -71,7 +89,7 @@ impl Script {
     }
 
     /// Gets the loaded modules of this script.
-    pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<JsString, Module>> {
+    pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<ModuleRequest, Module>> {
         &self.inner.loaded_modules
     }
 
@@ -103,9 +121,10 @@ impl Script {
         Ok(Self {
             inner: Gc::new(Inner {
                 realm: realm.unwrap_or_else(|| context.realm().clone()),
-                source: code,
+                code: ScriptCode::Parsed(code),
                 source_text,
                 codeblock: GcRefCell::default(),
+                annex_b_function_names: RefCell::default(),
                 loaded_modules: GcRefCell::default(),
                 host_defined: HostDefined::default(),
                 path,
@@ -113,6 +132,47 @@ impl Script {
         })
     }
 
+    /// Creates a script from an already built AST, such as one imported from `ESTree`.
+    ///
+    /// The scopes of `code` are analyzed before returning. The script has no source text, so
+    /// functions created by it show `[native code]` when converted to a string.
+    ///
+    /// Unlike [`Script::parse`], this does not check `code` for early errors, such as duplicate
+    /// lexical declarations or invalid assignment targets, so it must come from a conforming
+    /// parser. The behaviour of a script whose AST has early errors is unspecified.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SyntaxError` if the scope analysis of `code` fails.
+    pub fn from_ast(
+        mut code: boa_ast::Script,
+        realm: Option<Realm>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let scope = context.realm().scope().clone();
+        if !code.analyze_scope(&scope, context.interner()) {
+            return Err(JsNativeError::syntax()
+                .with_message("invalid scope analysis")
+                .into());
+        }
+        if !context.optimizer_options().is_empty() {
+            context.optimize_statement_list(code.statements_mut());
+        }
+
+        Ok(Self {
+            inner: Gc::new(Inner {
+                realm: realm.unwrap_or_else(|| context.realm().clone()),
+                code: ScriptCode::Parsed(code),
+                source_text: SourceText::new_empty(),
+                codeblock: GcRefCell::default(),
+                annex_b_function_names: RefCell::default(),
+                loaded_modules: GcRefCell::default(),
+                host_defined: HostDefined::default(),
+                path: None,
+            }),
+        })
+    }
+
     /// Compiles the codeblock of this script.
     ///
     /// This is a no-op if this has been called previously.
@@ -123,21 +183,30 @@ impl Script {
             return Ok(codeblock.clone());
         }
 
+        let ScriptCode::Parsed(source) = &self.inner.code else {
+            unreachable!("scripts loaded from a bytecode cache are compiled when loaded");
+        };
+
         let _timer = Profiler::global().start_event("Script compilation", "Main");
 
         let mut annex_b_function_names = Vec::new();
 
         global_declaration_instantiation_context(
             &mut annex_b_function_names,
-            &self.inner.source,
+            source,
             self.inner.realm.scope(),
             context,
         )?;
 
+        *self.inner.annex_b_function_names.borrow_mut() = annex_b_function_names
+            .iter()
+            .map(|name| name.to_js_string(context.interner()))
+            .collect();
+
         let spanned_source_text = SpannedSourceText::new_source_only(self.get_source());
         let mut compiler = ByteCompiler::new(
             js_string!("<main>"),
-            self.inner.source.strict(),
+            source.strict(),
             false,
             self.inner.realm.scope().clone(),
             self.inner.realm.scope().clone(),
@@ -154,8 +223,8 @@ impl Script {
         }
 
         // TODO: move to `Script::evaluate` to make this operation infallible.
-        compiler.global_declaration_instantiation(&self.inner.source);
-        compiler.compile_statement_list(self.inner.source.statements(), true, false);
+        compiler.global_declaration_instantiation(source);
+        compiler.compile_statement_list(source.statements(), true, false);
 
         let cb = Gc::new(compiler.finish());
 
@@ -164,6 +233,86 @@ impl Script {
         Ok(cb)
     }
 
+    /// Serializes the compiled code of this script into a bytecode cache.
+    ///
+    /// The cache can be loaded with [`Script::from_cache`] to create this script again without
+    /// parsing or compiling its source text. This compiles the script if it wasn't compiled yet.
+    ///
+    /// Caches are only compatible with the same version of the engine, compiled with the same
+    /// features.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if compiling the script fails.
+    pub fn to_cache(&self, context: &mut Context) -> JsResult<Vec<u8>> {
+        let _timer = Profiler::global().start_event("Script cache serialization", "Main");
+
+        let codeblock = self.codeblock(context)?;
+        let declarations = match &self.inner.code {
+            ScriptCode::Parsed(source) => GlobalDeclarations::new(
+                source,
+                self.inner.annex_b_function_names.borrow().clone(),
+                context.interner(),
+            ),
+            ScriptCode::Cached(declarations) => declarations.clone(),
+        };
+
+        let mut writer = CacheWriter::new(CacheKind::Script, &self.inner.source_text);
+        declarations.write(&mut writer);
+        writer.write_code_block(&codeblock);
+        Ok(writer.finish())
+    }
+
+    /// Loads a script from a bytecode cache created by [`Script::to_cache`], without parsing or
+    /// compiling its source text.
+    ///
+    /// The source text is still read from `src`, to check that the cache was created from the
+    /// same source text and to keep the source text of the functions of the script. This
+    /// also instantiates the global declarations of the script in its realm, which would
+    /// otherwise be done when parsing and compiling the script.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading `src` fails, if the cache was not created from the same
+    /// source text by the same version of the engine, if the cache is invalid, or if the global
+    /// declarations of the script conflict with the declarations of the realm.
+    pub fn from_cache<R: ReadChar>(
+        cache: &[u8],
+        mut src: Source<'_, R>,
+        realm: Option<Realm>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let _timer = Profiler::global().start_event("Script cache loading", "Main");
+        let path = src.path().map(Path::to_path_buf);
+        let source_text = SourceText::new(src.read_source_text()?);
+        let realm = realm.unwrap_or_else(|| context.realm().clone());
+
+        let mut reader = CacheReader::new(
+            cache,
+            CacheKind::Script,
+            source_text.clone(),
+            realm.scope().clone(),
+        )?;
+        let declarations = GlobalDeclarations::read(&mut reader)?;
+        declarations.instantiate_lexical(realm.scope())?;
+        let codeblock = reader.read_code_block()?;
+        reader.finish()?;
+        declarations.instantiate_annex_b(realm.scope(), context)?;
+
+        Ok(Self {
+            inner: Gc::new(Inner {
+                realm,
+                code: ScriptCode::Cached(declarations),
+                source_text,
+                codeblock: GcRefCell::new(Some(Gc::new(codeblock))),
+                annex_b_function_names: RefCell::default(),
+                loaded_modules: GcRefCell::default(),
+                host_defined: HostDefined::default(),
+                path,
+            }),
+        })
+    }
+
     /// Evaluates this script and returns its result.
     ///
     /// Note that this won't run any scheduled promise jobs; you need to call [`Context::run_jobs`]
@@ -250,3 +399,205 @@ impl Script {
         self.inner.source_text.clone()
     }
 }
+
+/// The code of a [`Script`].
+#[derive(Debug)]
+enum ScriptCode {
+    /// A script parsed from its source text.
+    Parsed(boa_ast::Script),
+
+    /// A script loaded from a bytecode cache, which only keeps its global declarations.
+    Cached(GlobalDeclarations),
+}
+
+/// The global declarations of a script, stored in its bytecode cache.
+///
+/// When a script is parsed and compiled, its lexical declarations are added to the global scope
+/// of the realm, and the function declarations in blocks may create global var bindings. A
+/// script loaded from a bytecode cache must do the same before it can run.
+#[derive(Debug, Clone)]
+struct GlobalDeclarations {
+    /// The lexically declared names of the script, and whether they are constant.
+    lexical_names: Vec<(JsString, bool)>,
+
+    /// The var declared names of the script.
+    var_names: Vec<JsString>,
+
+    /// The names of the function declarations in blocks that are candidates for the Annex B
+    /// global var bindings.
+    annex_b_candidates: Vec<JsString>,
+
+    /// The names of the function declarations in blocks that the script was compiled with a
+    /// global var binding for.
+    annex_b_function_names: Vec<JsString>,
+}
+
+impl GlobalDeclarations {
+    /// Collects the global declarations of `script`.
+    fn new(
+        script: &boa_ast::Script,
+        annex_b_function_names: Vec<JsString>,
+        interner: &Interner,
+    ) -> Self {
+        let mut lexical_names = Vec::new();
+        for statement in &**script.statements() {
+            let StatementListItem::Declaration(declaration) = statement else {
+                continue;
+            };
+            let (names, constant) = match declaration {
+                Declaration::ClassDeclaration(class) => (bound_names(class), false),
+                Declaration::Lexical(LexicalDeclaration::Let(declaration)) => {
+                    (bound_names(declaration), false)
+                }
+                Declaration::Lexical(
+                    LexicalDeclaration::Const(declaration)
+                    | LexicalDeclaration::Using(declaration)
+                    | LexicalDeclaration::AwaitUsing(declaration),
+                ) => (bound_names(declaration), true),
+                _ => continue,
+            };
+            for name in names {
+                lexical_names.push((name.to_js_string(interner), constant));
+            }
+        }
+
+        let var_names = var_declared_names(script)
+            .into_iter()
+            .map(|name| name.to_js_string(interner))
+            .collect();
+
+        #[cfg(feature = "annex-b")]
+        let annex_b_candidates = if script.strict() {
+            Vec::new()
+        } else {
+            let lex_names = lexically_declared_names(script);
+            annex_b_function_declarations_names(script)
+                .into_iter()
+                .filter(|name| !lex_names.contains(name))
+                .map(|name| name.to_js_string(interner))
+                .collect()
+        };
+
+        #[cfg(not(feature = "annex-b"))]
+        let annex_b_candidates = Vec::new();
+
+        Self {
+            lexical_names,
+            var_names,
+            annex_b_candidates,
+            annex_b_function_names,
+        }
+    }
+
+    fn write(&self, writer: &mut CacheWriter) {
+        writer.write_usize(self.lexical_names.len());
+        for (name, constant) in &self.lexical_names {
+            writer.write_string(name);
+            writer.write_bool(*constant);
+        }
+        for names in [
+            &self.var_names,
+            &self.annex_b_candidates,
+            &self.annex_b_function_names,
+        ] {
+            writer.write_usize(names.len());
+            for name in names {
+                writer.write_string(name);
+            }
+        }
+    }
+
+    fn read(reader: &mut CacheReader<'_>) -> JsResult<Self> {
+        let mut lexical_names = Vec::new();
+        for _ in 0..reader.read_usize()? {
+            lexical_names.push((reader.read_string()?, reader.read_bool()?));
+        }
+
+        let mut read_names = || -> JsResult<Vec<JsString>> {
+            let mut names = Vec::new();
+            for _ in 0..reader.read_usize()? {
+                names.push(reader.read_string()?);
+            }
+            Ok(names)
+        };
+
+        Ok(Self {
+            lexical_names,
+            var_names: read_names()?,
+            annex_b_candidates: read_names()?,
+            annex_b_function_names: read_names()?,
+        })
+    }
+
+    /// Creates the lexical bindings of the script in the global scope `env`.
+    ///
+    /// This replaces the global declaration instantiation done when parsing the script.
+    fn instantiate_lexical(&self, env: &Scope) -> JsResult<()> {
+        // Note: Our implementation differs from the spec here.
+        // a. If env.HasVarDeclaration(name) is true, throw a SyntaxError exception.
+        // b. If env.HasLexicalDeclaration(name) is true, throw a SyntaxError exception.
+        let lexical_conflict = self
+            .lexical_names
+            .iter()
+            .any(|(name, _)| env.has_binding(name));
+
+        // a. If env.HasLexicalDeclaration(name) is true, throw a SyntaxError exception.
+        let var_conflict = self.var_names.iter().any(|name| env.has_lex_binding(name));
+
+        if lexical_conflict || var_conflict {
+            return Err(JsNativeError::syntax()
+                .with_message("duplicate lexical declaration")
+                .into());
+        }
+
+        for (name, constant) in &self.lexical_names {
+            if *constant {
+                env.create_immutable_binding(name.clone(), true);
+            } else {
+                drop(env.create_mutable_binding(name.clone(), false));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Creates the global var bindings of the function declarations in blocks, checking that the
+    /// script was compiled with the same bindings.
+    ///
+    /// This replaces the global declaration instantiation done when compiling the script.
+    #[cfg_attr(
+        not(feature = "annex-b"),
+        allow(clippy::unnecessary_wraps, clippy::unused_self, unused_variables)
+    )]
+    fn instantiate_annex_b(&self, env: &Scope, context: &mut Context) -> JsResult<()> {
+        #[cfg(feature = "annex-b")]
+        {
+            let mut declared_function_or_var_names = self.var_names.clone();
+            let mut annex_b_function_names = Vec::new();
+            for name in &self.annex_b_candidates {
+                // a. If env.HasLexicalDeclaration(F) is false, then
+                //     i. Let fnDefinable be ? env.CanDeclareGlobalVar(F).
+                //     ii. If fnDefinable is true, then
+                if !env.has_lex_binding(name) && context.can_declare_global_function(name)? {
+                    // ii. If declaredFunctionOrVarNames does not contain F, then
+                    if !declared_function_or_var_names.contains(name) {
+                        // i. Perform ? env.CreateGlobalVarBinding(F, false).
+                        context.create_global_var_binding(name.clone(), false)?;
+
+                        // ii. Append F to declaredFunctionOrVarNames.
+                        declared_function_or_var_names.push(name.clone());
+                    }
+                    annex_b_function_names.push(name.clone());
+                }
+            }
+
+            if annex_b_function_names != self.annex_b_function_names {
+                return Err(JsNativeError::error()
+                    .with_message("bytecode cache does not match the global environment")
+                    .into());
+            }
+        }
+
+        Ok(())
+    }
+}
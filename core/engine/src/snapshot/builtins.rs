@@ -0,0 +1,187 @@
+//! The table of built-in objects of a realm.
+
+use rustc_hash::FxHashMap;
+
+use crate::{native_function::NativeFunctionObject, property::PropertyKey, realm::Realm, JsObject};
+
+use super::own_property_keys;
+
+/// An edge of the graph of built-in objects.
+#[derive(Debug, Clone)]
+enum Edge {
+    /// The prototype of an object.
+    Prototype,
+    /// The value of a data property.
+    Value(PropertyKey),
+    /// The getter of an accessor property.
+    Get(PropertyKey),
+    /// The setter of an accessor property.
+    Set(PropertyKey),
+}
+
+/// The path from the roots of a realm to a built-in object.
+#[derive(Debug, Clone)]
+enum Path {
+    /// The root with the given index.
+    Root(usize),
+    /// An edge from the built-in object with the given index.
+    Edge(u32, Edge),
+}
+
+/// The built-in objects of a realm, in the order they are found by a breadth-first traversal of
+/// the objects reachable from its global object and intrinsics.
+///
+/// Since the traversal only depends on the order the built-in objects are created in, the same
+/// built-in object has the same index in every new realm, which is used by snapshots to refer to
+/// built-in objects instead of storing them.
+#[derive(Debug, Default)]
+pub(super) struct Builtins {
+    objects: Vec<JsObject>,
+    paths: Vec<Path>,
+    indices: FxHashMap<JsObject, u32>,
+}
+
+impl Builtins {
+    /// Creates the table of built-in objects of `realm`.
+    ///
+    /// This must only be used with a realm that wasn't modified by any code yet.
+    pub(super) fn new(realm: &Realm) -> Self {
+        let mut builtins = Self::default();
+        for (index, root) in roots(realm).into_iter().enumerate() {
+            builtins.push(root, Path::Root(index));
+        }
+
+        let mut index = 0;
+        while let Some(object) = builtins.objects.get(index).cloned() {
+            for (edge, child) in edges(&object) {
+                builtins.push(child, Path::Edge(index as u32, edge));
+            }
+            index += 1;
+        }
+
+        builtins
+    }
+
+    fn push(&mut self, object: JsObject, path: Path) {
+        if self.indices.contains_key(&object) {
+            return;
+        }
+        self.indices
+            .insert(object.clone(), self.objects.len() as u32);
+        self.objects.push(object);
+        self.paths.push(path);
+    }
+
+    /// Gets the number of built-in objects.
+    pub(super) fn len(&self) -> usize {
+        self.objects.len()
+    }
+
+    /// Gets the built-in object with the given index.
+    pub(super) fn get(&self, index: u32) -> Option<&JsObject> {
+        self.objects.get(index as usize)
+    }
+
+    /// Gets the index of a built-in object of this table.
+    pub(super) fn index_of(&self, object: &JsObject) -> Option<u32> {
+        self.indices.get(object).copied()
+    }
+
+    /// Gets the index of the first native function of this table that calls `f`.
+    pub(super) fn native_function_index(&self, f: usize) -> Option<u32> {
+        self.objects
+            .iter()
+            .position(|object| native_function_ptr(object) == Some(f))
+            .map(|index| index as u32)
+    }
+
+    /// Finds the built-in objects of `realm` that are in the same place as the built-in objects
+    /// of this table, which must have been created for a new realm.
+    ///
+    /// An object of `realm` is only matched if it is reached by the same path from the roots of
+    /// the realm as a built-in object of this table, and if it has the same kind of data, so code
+    /// that replaces built-in objects doesn't make a snapshot refer to the wrong object.
+    pub(super) fn find_in(&self, realm: &Realm) -> FxHashMap<JsObject, u32> {
+        let roots = roots(realm);
+        let mut found: Vec<Option<JsObject>> = Vec::with_capacity(self.objects.len());
+        let mut indices = FxHashMap::default();
+
+        for (index, path) in self.paths.iter().enumerate() {
+            let object = match path {
+                Path::Root(root) => roots.get(*root).cloned(),
+                Path::Edge(parent, edge) => found[*parent as usize]
+                    .as_ref()
+                    .and_then(|parent| follow(parent, edge)),
+            }
+            .filter(|object| {
+                !indices.contains_key(object) && same_kind(object, &self.objects[index])
+            });
+
+            if let Some(object) = &object {
+                indices.insert(object.clone(), index as u32);
+            }
+            found.push(object);
+        }
+
+        indices
+    }
+}
+
+/// Gets the roots of the built-in objects of a realm.
+fn roots(realm: &Realm) -> Vec<JsObject> {
+    let mut roots = vec![realm.global_object().clone(), realm.global_this().clone()];
+    roots.extend(realm.intrinsics().all_objects());
+    roots
+}
+
+/// Gets the objects directly reachable from `object`, in a deterministic order.
+fn edges(object: &JsObject) -> Vec<(Edge, JsObject)> {
+    let object = object.borrow();
+    let mut edges = Vec::new();
+    if let Some(prototype) = object.prototype() {
+        edges.push((Edge::Prototype, prototype));
+    }
+
+    for key in own_property_keys(&object) {
+        let Some(property) = object.properties().get(&key) else {
+            continue;
+        };
+        let accessors = [
+            (property.value(), Edge::Value(key.clone())),
+            (property.get(), Edge::Get(key.clone())),
+            (property.set(), Edge::Set(key)),
+        ];
+        for (value, edge) in accessors {
+            if let Some(child) = value.and_then(|value| value.as_object()) {
+                edges.push((edge, child.clone()));
+            }
+        }
+    }
+
+    edges
+}
+
+/// Follows an edge from `object`.
+fn follow(object: &JsObject, edge: &Edge) -> Option<JsObject> {
+    let object = object.borrow();
+    let value = match edge {
+        Edge::Prototype => return object.prototype(),
+        Edge::Value(key) => object.properties().get(key)?.value().cloned(),
+        Edge::Get(key) => object.properties().get(key)?.get().cloned(),
+        Edge::Set(key) => object.properties().get(key)?.set().cloned(),
+    };
+    value?.as_object().cloned()
+}
+
+/// Gets the address of the function called by a native function object.
+fn native_function_ptr(object: &JsObject) -> Option<usize> {
+    let function = object.downcast_ref::<NativeFunctionObject>()?;
+    function.f.as_fn_ptr().map(|f| f as usize)
+}
+
+/// Checks if two objects have the same kind of data and internal methods.
+fn same_kind(a: &JsObject, b: &JsObject) -> bool {
+    std::ptr::eq(a.vtable(), b.vtable())
+        && a.borrow().data().as_any().type_id() == b.borrow().data().as_any().type_id()
+        && native_function_ptr(a) == native_function_ptr(b)
+}
@@ -0,0 +1,1188 @@
+//! Restoration of snapshots.
+//!
+//! A snapshot is restored in two steps. First, all of its records are read, since objects can
+//! refer to objects that are stored after them. Then, all the objects and environments are
+//! created before their internal slots and properties are set, since they can refer to each other
+//! in any order.
+
+use boa_ast::scope::Scope;
+use boa_gc::Gc;
+
+use crate::{
+    builtins::{
+        array_buffer::ArrayBuffer,
+        function::{BoundFunction, ConstructorKind, OrdinaryFunction},
+        map::ordered_map::OrderedMap,
+        set::ordered_set::OrderedSet,
+        symbol::registered_symbol,
+        Array, Date, Error, OrdinaryObject, RegExp,
+    },
+    environments::{
+        DeclarativeEnvironment, DeclarativeEnvironmentKind, Environment, EnvironmentStack,
+        FunctionEnvironment, FunctionSlots, LexicalEnvironment, PrivateEnvironment,
+        ThisBindingStatus,
+    },
+    native_function::NativeFunctionObject,
+    object::{JsFunction, PrivateElement, PrivateName},
+    property::{PropertyDescriptor, PropertyKey},
+    spanned_source_text::SourceText,
+    vm::{CacheKind, CacheReader, CodeBlock},
+    Context, JsBigInt, JsNativeError, JsObject, JsResult, JsString, JsSymbol, JsValue,
+    NativeObject,
+};
+
+use super::{
+    builtins::Builtins, invalid_snapshot, object_addr, own_property_keys, HostObjects,
+    ATTRIBUTE_CONFIGURABLE, ATTRIBUTE_ENUMERABLE, ATTRIBUTE_WRITABLE, DECLARATIVE_FUNCTION,
+    DECLARATIVE_LEXICAL, ENVIRONMENT_DECLARATIVE, ENVIRONMENT_OBJECT, FIELD_PRIVATE, FIELD_PUBLIC,
+    KEY_INDEX, KEY_STRING, KEY_SYMBOL, KIND_ARRAY, KIND_ARRAY_BUFFER, KIND_BIGINT, KIND_BOOLEAN,
+    KIND_BOUND_FUNCTION, KIND_DATE, KIND_ERROR, KIND_FUNCTION, KIND_MAP, KIND_NATIVE_FUNCTION,
+    KIND_NUMBER, KIND_ORDINARY, KIND_REGEXP, KIND_SET, KIND_STRING, KIND_SYMBOL, OBJECT_BUILTIN,
+    OBJECT_HEAP, OBJECT_HOST, PRIVATE_ACCESSOR, PRIVATE_FIELD, PRIVATE_METHOD, PROPERTY_ACCESSOR,
+    PROPERTY_DATA, RECORD_BUILTIN, RECORD_END, RECORD_ENVIRONMENT, RECORD_OBJECT, SHARED_NEW,
+    SHARED_REF, SYMBOL_NEW, SYMBOL_REF, SYMBOL_REGISTERED, SYMBOL_WELL_KNOWN, THIS_INITIALIZED,
+    THIS_LEXICAL, THIS_UNINITIALIZED, VALUE_BIGINT, VALUE_FALSE, VALUE_INTEGER, VALUE_NULL,
+    VALUE_OBJECT, VALUE_RATIONAL, VALUE_STRING, VALUE_SYMBOL, VALUE_TRUE, VALUE_UNDEFINED,
+};
+
+/// A reference to an object of a snapshot.
+#[derive(Debug, Clone, Copy)]
+enum ObjectRef {
+    Builtin(u32),
+    Heap(u32),
+    Host(u32),
+}
+
+/// A value of a snapshot, whose objects are not created yet.
+#[derive(Debug, Clone)]
+enum Value {
+    Primitive(JsValue),
+    Object(ObjectRef),
+}
+
+#[derive(Debug)]
+struct PrivateNameRecord {
+    description: JsString,
+    id: u32,
+}
+
+#[derive(Debug)]
+enum PrivateElementRecord {
+    Field(Value),
+    Method(ObjectRef),
+    Accessor {
+        getter: Option<ObjectRef>,
+        setter: Option<ObjectRef>,
+    },
+}
+
+#[derive(Debug)]
+enum PropertyRecord {
+    Data {
+        value: Value,
+        attributes: u8,
+    },
+    Accessor {
+        get: Value,
+        set: Value,
+        attributes: u8,
+    },
+}
+
+/// The prototype, properties and private elements of an object.
+#[derive(Debug)]
+struct ObjectState {
+    prototype: Option<ObjectRef>,
+    extensible: bool,
+    properties: Vec<(PropertyKey, PropertyRecord)>,
+    private_elements: Vec<(PrivateNameRecord, PrivateElementRecord)>,
+}
+
+#[derive(Debug)]
+enum EnvironmentRef {
+    Declarative(u32),
+    Object(ObjectRef),
+}
+
+#[derive(Debug)]
+struct EnvironmentStackRecord {
+    environments: Vec<EnvironmentRef>,
+    private_environments: Vec<u32>,
+}
+
+#[derive(Debug)]
+enum ClassFieldRecord {
+    Public(PropertyKey, ObjectRef, Option<PropertyKey>),
+    Private(PrivateNameRecord, ObjectRef),
+}
+
+#[derive(Debug)]
+struct FunctionRecord {
+    code: Gc<CodeBlock>,
+    environments: EnvironmentStackRecord,
+    home_object: Option<ObjectRef>,
+    fields: Vec<ClassFieldRecord>,
+    private_methods: Vec<(PrivateNameRecord, PrivateElementRecord)>,
+    initializers: Vec<ObjectRef>,
+}
+
+/// The kind of an object and the internal slots that depend on it.
+#[derive(Debug)]
+enum ObjectKind {
+    Ordinary,
+    Array,
+    Function(Box<FunctionRecord>),
+    NativeFunction {
+        builtin: u32,
+        constructor: Option<ConstructorKind>,
+        has_realm: bool,
+    },
+    BoundFunction {
+        target: ObjectRef,
+        this: Value,
+        args: Vec<Value>,
+    },
+    Error(Error),
+    Boolean(bool),
+    Number(f64),
+    String(JsString),
+    Symbol(JsSymbol),
+    BigInt(JsBigInt),
+    Date(f64),
+    RegExp {
+        source: JsString,
+        flags: JsString,
+    },
+    Map(Vec<(Value, Value)>),
+    Set(Vec<Value>),
+    ArrayBuffer {
+        data: Option<Vec<u8>>,
+        max_byte_len: Option<u64>,
+        detach_key: Value,
+    },
+}
+
+#[derive(Debug)]
+struct ObjectRecord {
+    kind: ObjectKind,
+    state: ObjectState,
+}
+
+#[derive(Debug)]
+enum ThisRecord {
+    Lexical,
+    Uninitialized,
+    Initialized(Value),
+}
+
+#[derive(Debug)]
+struct FunctionSlotsRecord {
+    scope: Scope,
+    this: ThisRecord,
+    function_object: ObjectRef,
+    new_target: Option<ObjectRef>,
+}
+
+#[derive(Debug)]
+struct EnvironmentRecord {
+    poisoned: bool,
+    with: bool,
+    bindings: Vec<Option<Value>>,
+    function: Option<FunctionSlotsRecord>,
+}
+
+/// All the records of a snapshot.
+#[derive(Debug, Default)]
+struct Records {
+    global_bindings: Vec<Option<Value>>,
+    builtins: Vec<(u32, ObjectState)>,
+    objects: Vec<ObjectRecord>,
+    environments: Vec<EnvironmentRecord>,
+    private_environments: Vec<(u32, Vec<JsString>)>,
+    private_ids: Vec<Option<ObjectRef>>,
+}
+
+/// Restores the snapshot stored in `bytes` into `context`.
+pub(super) fn deserialize(bytes: &[u8], host: &HostObjects, context: &mut Context) -> JsResult<()> {
+    let realm = context.realm().clone();
+    let builtins = Builtins::new(&realm);
+
+    let mut reader = RecordReader {
+        reader: CacheReader::new(
+            bytes,
+            CacheKind::Snapshot,
+            SourceText::new_empty(),
+            realm.scope().clone(),
+        )?,
+        symbols: Vec::new(),
+        code_blocks: Vec::new(),
+        records: Records::default(),
+    };
+
+    if reader.reader.read_usize()? != builtins.len() {
+        return Err(JsNativeError::error()
+            .with_message("snapshot was created with different built-in objects")
+            .into());
+    }
+
+    let len = reader.reader.read_len(1)?;
+    let mut host_objects = Vec::with_capacity(len);
+    for _ in 0..len {
+        let id = reader.reader.read_string()?;
+        let object = host.get(&id).cloned().ok_or_else(|| {
+            JsNativeError::error().with_message(format!(
+                "snapshot requires the host object `{}`",
+                id.to_std_string_escaped()
+            ))
+        })?;
+        host_objects.push(object);
+    }
+
+    // The global bindings must be declared before reading any code that refers to them.
+    let len = reader.reader.read_len(4)?;
+    for _ in 0..len {
+        let name = reader.reader.read_string()?;
+        let mutable = reader.reader.read_bool()?;
+        let lex = reader.reader.read_bool()?;
+        let strict = reader.reader.read_bool()?;
+        if realm.scope().has_binding(&name) {
+            return Err(invalid_snapshot().into());
+        }
+        if mutable {
+            drop(realm.scope().create_mutable_binding(name, !lex));
+        } else {
+            realm.scope().create_immutable_binding(name, strict);
+        }
+    }
+    realm.resize_global_env();
+
+    let records = reader.read()?;
+    let mut restorer = Restorer {
+        context,
+        builtins,
+        host_objects,
+        objects: vec![None; records.objects.len()],
+        environments: vec![None; records.environments.len()],
+        private_ids: Vec::new(),
+        private_environments: Vec::new(),
+    };
+    restorer.restore(records)
+}
+
+/// Reads the records of a snapshot.
+struct RecordReader<'a> {
+    reader: CacheReader<'a>,
+    symbols: Vec<JsSymbol>,
+    code_blocks: Vec<Gc<CodeBlock>>,
+    records: Records,
+}
+
+impl RecordReader<'_> {
+    fn read(mut self) -> JsResult<Records> {
+        let len = self.reader.read_len(1)?;
+        for _ in 0..len {
+            let binding = self.read_binding()?;
+            self.records.global_bindings.push(binding);
+        }
+
+        loop {
+            match self.reader.read_u8()? {
+                RECORD_END => break,
+                RECORD_BUILTIN => {
+                    let index = self.reader.read_u32()?;
+                    let state = self.read_object_state()?;
+                    self.records.builtins.push((index, state));
+                }
+                RECORD_OBJECT => {
+                    let kind = self.read_object_kind()?;
+                    let state = self.read_object_state()?;
+                    self.records.objects.push(ObjectRecord { kind, state });
+                }
+                RECORD_ENVIRONMENT => {
+                    let environment = self.read_environment()?;
+                    self.records.environments.push(environment);
+                }
+                _ => return Err(invalid_snapshot().into()),
+            }
+        }
+
+        let len = self.reader.read_len(1)?;
+        for _ in 0..len {
+            let anchor = if self.reader.read_bool()? {
+                Some(self.read_object_tag()?)
+            } else {
+                None
+            };
+            self.records.private_ids.push(anchor);
+        }
+
+        self.reader.finish()?;
+        Ok(self.records)
+    }
+
+    fn read_binding(&mut self) -> JsResult<Option<Value>> {
+        if self.reader.read_bool()? {
+            self.read_value().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn read_value(&mut self) -> JsResult<Value> {
+        let value = match self.reader.read_u8()? {
+            VALUE_UNDEFINED => JsValue::undefined(),
+            VALUE_NULL => JsValue::null(),
+            VALUE_FALSE => JsValue::new(false),
+            VALUE_TRUE => JsValue::new(true),
+            VALUE_INTEGER => JsValue::new(self.reader.read_u32()? as i32),
+            VALUE_RATIONAL => JsValue::new(f64::from_bits(self.reader.read_u64()?)),
+            VALUE_STRING => JsValue::new(self.reader.read_string()?),
+            VALUE_BIGINT => JsValue::new(self.read_bigint()?),
+            VALUE_SYMBOL => JsValue::new(self.read_symbol()?),
+            VALUE_OBJECT => return self.read_object_ref().map(Value::Object),
+            _ => return Err(invalid_snapshot().into()),
+        };
+        Ok(Value::Primitive(value))
+    }
+
+    fn read_bigint(&mut self) -> JsResult<JsBigInt> {
+        let digits =
+            std::str::from_utf8(self.reader.read_bytes()?).map_err(|_| invalid_snapshot())?;
+        JsBigInt::from_string_radix(digits, 16).ok_or_else(|| invalid_snapshot().into())
+    }
+
+    fn read_symbol(&mut self) -> JsResult<JsSymbol> {
+        match self.reader.read_u8()? {
+            SYMBOL_WELL_KNOWN => JsSymbol::well_known(self.reader.read_u64()?)
+                .ok_or_else(|| invalid_snapshot().into()),
+            SYMBOL_REGISTERED => registered_symbol(&self.reader.read_string()?),
+            SYMBOL_REF => {
+                let index = self.reader.read_u32()?;
+                self.symbols
+                    .get(index as usize)
+                    .cloned()
+                    .ok_or_else(|| invalid_snapshot().into())
+            }
+            SYMBOL_NEW => {
+                let description = self.read_optional_string()?;
+                let symbol = JsSymbol::new(description).ok_or_else(|| {
+                    JsNativeError::range()
+                        .with_message("reached the maximum number of symbols that can be created")
+                })?;
+                self.symbols.push(symbol.clone());
+                Ok(symbol)
+            }
+            _ => Err(invalid_snapshot().into()),
+        }
+    }
+
+    fn read_optional_string(&mut self) -> JsResult<Option<JsString>> {
+        if self.reader.read_bool()? {
+            self.reader.read_string().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn read_object_tag(&mut self) -> JsResult<ObjectRef> {
+        let tag = self.reader.read_u8()?;
+        let index = self.reader.read_u32()?;
+        match tag {
+            OBJECT_BUILTIN => Ok(ObjectRef::Builtin(index)),
+            OBJECT_HEAP => Ok(ObjectRef::Heap(index)),
+            OBJECT_HOST => Ok(ObjectRef::Host(index)),
+            _ => Err(invalid_snapshot().into()),
+        }
+    }
+
+    fn read_object_ref(&mut self) -> JsResult<ObjectRef> {
+        self.read_object_tag()
+    }
+
+    fn read_optional_object_ref(&mut self) -> JsResult<Option<ObjectRef>> {
+        if self.reader.read_bool()? {
+            self.read_object_ref().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn read_key(&mut self) -> JsResult<PropertyKey> {
+        match self.reader.read_u8()? {
+            KEY_STRING => Ok(self.reader.read_string()?.into()),
+            KEY_SYMBOL => Ok(self.read_symbol()?.into()),
+            KEY_INDEX => Ok(self.reader.read_u32()?.into()),
+            _ => Err(invalid_snapshot().into()),
+        }
+    }
+
+    fn read_property(&mut self) -> JsResult<PropertyRecord> {
+        match self.reader.read_u8()? {
+            PROPERTY_DATA => {
+                let attributes = self.reader.read_u8()?;
+                let value = self.read_value()?;
+                Ok(PropertyRecord::Data { value, attributes })
+            }
+            PROPERTY_ACCESSOR => {
+                let attributes = self.reader.read_u8()?;
+                let get = self.read_value()?;
+                let set = self.read_value()?;
+                Ok(PropertyRecord::Accessor {
+                    get,
+                    set,
+                    attributes,
+                })
+            }
+            _ => Err(invalid_snapshot().into()),
+        }
+    }
+
+    fn read_private_name(&mut self) -> JsResult<PrivateNameRecord> {
+        Ok(PrivateNameRecord {
+            description: self.reader.read_string()?,
+            id: self.reader.read_u32()?,
+        })
+    }
+
+    fn read_private_element(&mut self) -> JsResult<PrivateElementRecord> {
+        match self.reader.read_u8()? {
+            PRIVATE_FIELD => self.read_value().map(PrivateElementRecord::Field),
+            PRIVATE_METHOD => self.read_object_ref().map(PrivateElementRecord::Method),
+            PRIVATE_ACCESSOR => Ok(PrivateElementRecord::Accessor {
+                getter: self.read_optional_object_ref()?,
+                setter: self.read_optional_object_ref()?,
+            }),
+            _ => Err(invalid_snapshot().into()),
+        }
+    }
+
+    fn read_object_state(&mut self) -> JsResult<ObjectState> {
+        let prototype = self.read_optional_object_ref()?;
+        let extensible = self.reader.read_bool()?;
+
+        let len = self.reader.read_len(4)?;
+        let mut properties = Vec::with_capacity(len);
+        for _ in 0..len {
+            let key = self.read_key()?;
+            let property = self.read_property()?;
+            properties.push((key, property));
+        }
+
+        let len = self.reader.read_len(13)?;
+        let mut private_elements = Vec::with_capacity(len);
+        for _ in 0..len {
+            let name = self.read_private_name()?;
+            let element = self.read_private_element()?;
+            private_elements.push((name, element));
+        }
+
+        Ok(ObjectState {
+            prototype,
+            extensible,
+            properties,
+            private_elements,
+        })
+    }
+
+    fn read_object_kind(&mut self) -> JsResult<ObjectKind> {
+        let kind = match self.reader.read_u8()? {
+            KIND_ORDINARY => ObjectKind::Ordinary,
+            KIND_ARRAY => ObjectKind::Array,
+            KIND_FUNCTION => ObjectKind::Function(Box::new(self.read_function()?)),
+            KIND_NATIVE_FUNCTION => ObjectKind::NativeFunction {
+                builtin: self.reader.read_u32()?,
+                constructor: match self.reader.read_u8()? {
+                    0 => None,
+                    1 => Some(ConstructorKind::Base),
+                    2 => Some(ConstructorKind::Derived),
+                    _ => return Err(invalid_snapshot().into()),
+                },
+                has_realm: self.reader.read_bool()?,
+            },
+            KIND_BOUND_FUNCTION => {
+                let target = self.read_object_ref()?;
+                let this = self.read_value()?;
+                let len = self.reader.read_len(1)?;
+                let mut args = Vec::with_capacity(len);
+                for _ in 0..len {
+                    args.push(self.read_value()?);
+                }
+                ObjectKind::BoundFunction { target, this, args }
+            }
+            KIND_ERROR => ObjectKind::Error(match self.reader.read_u8()? {
+                0 => Error::Aggregate,
+                1 => Error::Error,
+                2 => Error::Eval,
+                3 => Error::Type,
+                4 => Error::Range,
+                5 => Error::Reference,
+                6 => Error::Syntax,
+                7 => Error::Uri,
+                8 => Error::Suppressed,
+                _ => return Err(invalid_snapshot().into()),
+            }),
+            KIND_BOOLEAN => ObjectKind::Boolean(self.reader.read_bool()?),
+            KIND_NUMBER => ObjectKind::Number(f64::from_bits(self.reader.read_u64()?)),
+            KIND_STRING => ObjectKind::String(self.reader.read_string()?),
+            KIND_SYMBOL => ObjectKind::Symbol(self.read_symbol()?),
+            KIND_BIGINT => ObjectKind::BigInt(self.read_bigint()?),
+            KIND_DATE => ObjectKind::Date(f64::from_bits(self.reader.read_u64()?)),
+            KIND_REGEXP => ObjectKind::RegExp {
+                source: self.reader.read_string()?,
+                flags: self.reader.read_string()?,
+            },
+            KIND_MAP => {
+                let len = self.reader.read_len(2)?;
+                let mut entries = Vec::with_capacity(len);
+                for _ in 0..len {
+                    let key = self.read_value()?;
+                    let value = self.read_value()?;
+                    entries.push((key, value));
+                }
+                ObjectKind::Map(entries)
+            }
+            KIND_SET => {
+                let len = self.reader.read_len(1)?;
+                let mut values = Vec::with_capacity(len);
+                for _ in 0..len {
+                    values.push(self.read_value()?);
+                }
+                ObjectKind::Set(values)
+            }
+            KIND_ARRAY_BUFFER => {
+                let data = if self.reader.read_bool()? {
+                    Some(self.reader.read_bytes()?.to_vec())
+                } else {
+                    None
+                };
+                let max_byte_len = if self.reader.read_bool()? {
+                    Some(self.reader.read_u64()?)
+                } else {
+                    None
+                };
+                ObjectKind::ArrayBuffer {
+                    data,
+                    max_byte_len,
+                    detach_key: self.read_value()?,
+                }
+            }
+            _ => return Err(invalid_snapshot().into()),
+        };
+        Ok(kind)
+    }
+
+    fn read_function(&mut self) -> JsResult<FunctionRecord> {
+        let code = match self.reader.read_u8()? {
+            SHARED_NEW => {
+                let code = Gc::new(self.reader.read_code_block()?);
+                self.code_blocks.push(code.clone());
+                code
+            }
+            SHARED_REF => {
+                let index = self.reader.read_u32()?;
+                self.code_blocks
+                    .get(index as usize)
+                    .cloned()
+                    .ok_or_else(invalid_snapshot)?
+            }
+            _ => return Err(invalid_snapshot().into()),
+        };
+
+        let environments = self.read_environment_stack()?;
+        let home_object = self.read_optional_object_ref()?;
+
+        let len = self.reader.read_len(6)?;
+        let mut fields = Vec::with_capacity(len);
+        for _ in 0..len {
+            let field = match self.reader.read_u8()? {
+                FIELD_PUBLIC => {
+                    let key = self.read_key()?;
+                    let initializer = self.read_object_ref()?;
+                    let function_name = if self.reader.read_bool()? {
+                        Some(self.read_key()?)
+                    } else {
+                        None
+                    };
+                    ClassFieldRecord::Public(key, initializer, function_name)
+                }
+                FIELD_PRIVATE => {
+                    let name = self.read_private_name()?;
+                    let initializer = self.read_object_ref()?;
+                    ClassFieldRecord::Private(name, initializer)
+                }
+                _ => return Err(invalid_snapshot().into()),
+            };
+            fields.push(field);
+        }
+
+        let len = self.reader.read_len(13)?;
+        let mut private_methods = Vec::with_capacity(len);
+        for _ in 0..len {
+            let name = self.read_private_name()?;
+            let method = self.read_private_element()?;
+            private_methods.push((name, method));
+        }
+
+        let len = self.reader.read_len(5)?;
+        let mut initializers = Vec::with_capacity(len);
+        for _ in 0..len {
+            initializers.push(self.read_object_ref()?);
+        }
+
+        Ok(FunctionRecord {
+            code,
+            environments,
+            home_object,
+            fields,
+            private_methods,
+            initializers,
+        })
+    }
+
+    fn read_environment_stack(&mut self) -> JsResult<EnvironmentStackRecord> {
+        let len = self.reader.read_len(5)?;
+        let mut environments = Vec::with_capacity(len);
+        for _ in 0..len {
+            let environment = match self.reader.read_u8()? {
+                ENVIRONMENT_DECLARATIVE => EnvironmentRef::Declarative(self.reader.read_u32()?),
+                ENVIRONMENT_OBJECT => EnvironmentRef::Object(self.read_object_ref()?),
+                _ => return Err(invalid_snapshot().into()),
+            };
+            environments.push(environment);
+        }
+
+        let len = self.reader.read_len(5)?;
+        let mut private_environments = Vec::with_capacity(len);
+        for _ in 0..len {
+            let index = match self.reader.read_u8()? {
+                SHARED_NEW => {
+                    let id = self.reader.read_u32()?;
+                    let len = self.reader.read_len(8)?;
+                    let mut descriptions = Vec::with_capacity(len);
+                    for _ in 0..len {
+                        descriptions.push(self.reader.read_string()?);
+                    }
+                    self.records.private_environments.push((id, descriptions));
+                    (self.records.private_environments.len() - 1) as u32
+                }
+                SHARED_REF => self.reader.read_u32()?,
+                _ => return Err(invalid_snapshot().into()),
+            };
+            private_environments.push(index);
+        }
+
+        Ok(EnvironmentStackRecord {
+            environments,
+            private_environments,
+        })
+    }
+
+    fn read_environment(&mut self) -> JsResult<EnvironmentRecord> {
+        let kind = self.reader.read_u8()?;
+        let poisoned = self.reader.read_bool()?;
+        let with = self.reader.read_bool()?;
+
+        let len = self.reader.read_len(1)?;
+        let mut bindings = Vec::with_capacity(len);
+        for _ in 0..len {
+            bindings.push(self.read_binding()?);
+        }
+
+        let function = match kind {
+            DECLARATIVE_LEXICAL => None,
+            DECLARATIVE_FUNCTION => {
+                let scope = self.reader.read_scope()?;
+                let this = match self.reader.read_u8()? {
+                    THIS_LEXICAL => ThisRecord::Lexical,
+                    THIS_UNINITIALIZED => ThisRecord::Uninitialized,
+                    THIS_INITIALIZED => ThisRecord::Initialized(self.read_value()?),
+                    _ => return Err(invalid_snapshot().into()),
+                };
+                Some(FunctionSlotsRecord {
+                    scope,
+                    this,
+                    function_object: self.read_object_ref()?,
+                    new_target: self.read_optional_object_ref()?,
+                })
+            }
+            _ => return Err(invalid_snapshot().into()),
+        };
+
+        Ok(EnvironmentRecord {
+            poisoned,
+            with,
+            bindings,
+            function,
+        })
+    }
+}
+
+/// Creates the objects and environments of a snapshot.
+struct Restorer<'a> {
+    context: &'a mut Context,
+    builtins: Builtins,
+    host_objects: Vec<JsObject>,
+    objects: Vec<Option<JsObject>>,
+    environments: Vec<Option<Gc<DeclarativeEnvironment>>>,
+    private_ids: Vec<usize>,
+    private_environments: Vec<Gc<PrivateEnvironment>>,
+}
+
+impl Restorer<'_> {
+    fn restore(&mut self, mut records: Records) -> JsResult<()> {
+        for index in 0..records.objects.len() {
+            self.object(ObjectRef::Heap(index as u32), &records.objects)?;
+        }
+
+        for anchor in &records.private_ids {
+            let id = if let Some(anchor) = anchor {
+                object_addr(&self.object(*anchor, &records.objects)?)
+            } else {
+                // The class of these private names is not in the snapshot, so they only need an
+                // identifier that is not used by any other class. Leaking a single byte ensures
+                // no other allocation can have the same address.
+                let ptr: *const u8 = Box::leak(Box::new(0u8));
+                ptr as usize
+            };
+            self.private_ids.push(id);
+        }
+
+        for (id, descriptions) in std::mem::take(&mut records.private_environments) {
+            let id = self.private_id(id)?;
+            self.private_environments
+                .push(Gc::new(PrivateEnvironment::new(id, descriptions)));
+        }
+
+        for (index, environment) in records.environments.iter().enumerate() {
+            let created = self.create_environment(environment, &records.objects)?;
+            self.environments[index] = Some(created);
+        }
+
+        for (index, environment) in records.environments.iter().enumerate() {
+            let created = self.environments[index]
+                .clone()
+                .expect("environments must have been created");
+            let bindings = environment
+                .bindings
+                .iter()
+                .map(|binding| {
+                    binding
+                        .as_ref()
+                        .map(|value| self.value(value, &records.objects))
+                        .transpose()
+                })
+                .collect::<JsResult<Vec<_>>>()?;
+            let inner = match created.kind() {
+                DeclarativeEnvironmentKind::Lexical(env) => env.poisonable_environment(),
+                DeclarativeEnvironmentKind::Function(env) => env.poisonable_environment(),
+                DeclarativeEnvironmentKind::Global(_) | DeclarativeEnvironmentKind::Module(_) => {
+                    unreachable!("snapshots only create lexical and function environments")
+                }
+            };
+            *inner.bindings().borrow_mut() = bindings;
+        }
+
+        let objects = std::mem::take(&mut records.objects);
+        for (index, record) in objects.iter().enumerate() {
+            let object = self.objects[index]
+                .clone()
+                .expect("objects must have been created");
+            self.fill_object(&object, &record.kind, &objects)?;
+            self.set_state(&object, &record.state, &objects)?;
+        }
+
+        for (index, state) in &records.builtins {
+            let object = self.object(ObjectRef::Builtin(*index), &objects)?;
+            self.set_state(&object, state, &objects)?;
+        }
+
+        let realm = self.context.realm().clone();
+        let global = realm
+            .environment()
+            .kind()
+            .as_global()
+            .expect("Realm should only store global environments")
+            .poisonable_environment();
+        let mut values = Vec::with_capacity(records.global_bindings.len());
+        for binding in &records.global_bindings {
+            values.push(
+                binding
+                    .as_ref()
+                    .map(|value| self.value(value, &objects))
+                    .transpose()?,
+            );
+        }
+        let mut bindings = global.bindings().borrow_mut();
+        if values.len() != bindings.len() {
+            return Err(invalid_snapshot().into());
+        }
+        *bindings = values;
+
+        Ok(())
+    }
+
+    fn private_id(&self, index: u32) -> JsResult<usize> {
+        self.private_ids
+            .get(index as usize)
+            .copied()
+            .ok_or_else(|| invalid_snapshot().into())
+    }
+
+    fn private_name(&self, name: &PrivateNameRecord) -> JsResult<PrivateName> {
+        Ok(PrivateName::new(
+            name.description.clone(),
+            self.private_id(name.id)?,
+        ))
+    }
+
+    /// Gets an object of the snapshot, creating it if it doesn't exist yet.
+    ///
+    /// Only the internal slots that are needed to create the object are set, the rest of them are
+    /// set by [`Restorer::fill_object`].
+    fn object(&mut self, object: ObjectRef, records: &[ObjectRecord]) -> JsResult<JsObject> {
+        let index = match object {
+            ObjectRef::Builtin(index) => {
+                return self
+                    .builtins
+                    .get(index)
+                    .cloned()
+                    .ok_or_else(|| invalid_snapshot().into())
+            }
+            ObjectRef::Host(index) => {
+                return self
+                    .host_objects
+                    .get(index as usize)
+                    .cloned()
+                    .ok_or_else(|| invalid_snapshot().into())
+            }
+            ObjectRef::Heap(index) => index as usize,
+        };
+
+        if let Some(object) = self.objects.get(index).ok_or_else(invalid_snapshot)? {
+            return Ok(object.clone());
+        }
+
+        let realm = self.context.realm().clone();
+        let object = match &records[index].kind {
+            ObjectKind::Ordinary => self.create(OrdinaryObject),
+            ObjectKind::Array => self.create(Array),
+            ObjectKind::Function(function) => self.create(OrdinaryFunction::new(
+                function.code.clone(),
+                EnvironmentStack::new(realm.environment().clone()),
+                None,
+                realm,
+            )),
+            ObjectKind::NativeFunction {
+                builtin,
+                constructor,
+                has_realm,
+            } => {
+                let f = self
+                    .builtins
+                    .get(*builtin)
+                    .and_then(|object| {
+                        object
+                            .downcast_ref::<NativeFunctionObject>()
+                            .map(|function| function.f.clone())
+                    })
+                    .ok_or_else(invalid_snapshot)?;
+                self.create(NativeFunctionObject {
+                    f,
+                    constructor: *constructor,
+                    realm: has_realm.then_some(realm),
+                })
+            }
+            ObjectKind::BoundFunction { target, this, args } => {
+                let target = self.object(*target, records)?;
+                let this = self.value(this, records)?;
+                let args = args
+                    .iter()
+                    .map(|arg| self.value(arg, records))
+                    .collect::<JsResult<Vec<_>>>()?;
+                BoundFunction::create(target, this, args, self.context)?
+            }
+            ObjectKind::Error(error) => self.create(*error),
+            ObjectKind::Boolean(boolean) => self.create(*boolean),
+            ObjectKind::Number(number) => self.create(*number),
+            ObjectKind::String(string) => self.create(string.clone()),
+            ObjectKind::Symbol(symbol) => self.create(symbol.clone()),
+            ObjectKind::BigInt(bigint) => self.create(bigint.clone()),
+            ObjectKind::Date(time) => self.create(Date::new(*time)),
+            ObjectKind::RegExp { source, flags } => {
+                let regexp = RegExp::compile_native_regexp(
+                    &source.clone().into(),
+                    &flags.clone().into(),
+                    self.context,
+                )?;
+                self.create(regexp)
+            }
+            ObjectKind::Map(_) => self.create(OrderedMap::<JsValue>::new()),
+            ObjectKind::Set(_) => self.create(OrderedSet::new()),
+            ObjectKind::ArrayBuffer {
+                data,
+                max_byte_len,
+                detach_key,
+            } => {
+                let detach_key = self.value(detach_key, records)?;
+                self.create(ArrayBuffer::from_parts(
+                    data.clone(),
+                    *max_byte_len,
+                    detach_key,
+                ))
+            }
+        };
+
+        self.objects[index] = Some(object.clone());
+        Ok(object)
+    }
+
+    fn create<T: NativeObject>(&self, data: T) -> JsObject {
+        JsObject::from_proto_and_data_with_shared_shape(self.context.root_shape(), None, data)
+    }
+
+    fn value(&mut self, value: &Value, records: &[ObjectRecord]) -> JsResult<JsValue> {
+        match value {
+            Value::Primitive(value) => Ok(value.clone()),
+            Value::Object(object) => self.object(*object, records).map(Into::into),
+        }
+    }
+
+    fn optional_object(
+        &mut self,
+        object: Option<ObjectRef>,
+        records: &[ObjectRecord],
+    ) -> JsResult<Option<JsObject>> {
+        object
+            .map(|object| self.object(object, records))
+            .transpose()
+    }
+
+    fn private_element(
+        &mut self,
+        element: &PrivateElementRecord,
+        records: &[ObjectRecord],
+    ) -> JsResult<PrivateElement> {
+        Ok(match element {
+            PrivateElementRecord::Field(value) => {
+                PrivateElement::Field(self.value(value, records)?)
+            }
+            PrivateElementRecord::Method(method) => {
+                PrivateElement::Method(self.object(*method, records)?)
+            }
+            PrivateElementRecord::Accessor { getter, setter } => PrivateElement::Accessor {
+                getter: self.optional_object(*getter, records)?,
+                setter: self.optional_object(*setter, records)?,
+            },
+        })
+    }
+
+    fn create_environment(
+        &mut self,
+        environment: &EnvironmentRecord,
+        records: &[ObjectRecord],
+    ) -> JsResult<Gc<DeclarativeEnvironment>> {
+        let bindings = environment.bindings.len() as u32;
+        let kind = match &environment.function {
+            None => DeclarativeEnvironmentKind::Lexical(LexicalEnvironment::new(
+                bindings,
+                environment.poisoned,
+                environment.with,
+            )),
+            Some(function) => {
+                let this = match &function.this {
+                    ThisRecord::Lexical => ThisBindingStatus::Lexical,
+                    ThisRecord::Uninitialized => ThisBindingStatus::Uninitialized,
+                    ThisRecord::Initialized(value) => {
+                        ThisBindingStatus::Initialized(self.value(value, records)?)
+                    }
+                };
+                let slots = FunctionSlots::new(
+                    this,
+                    self.object(function.function_object, records)?,
+                    self.optional_object(function.new_target, records)?,
+                );
+                DeclarativeEnvironmentKind::Function(FunctionEnvironment::new(
+                    bindings,
+                    environment.poisoned,
+                    environment.with,
+                    slots,
+                    function.scope.clone(),
+                ))
+            }
+        };
+        Ok(Gc::new(DeclarativeEnvironment::new(kind)))
+    }
+
+    fn environment_stack(
+        &mut self,
+        stack: &EnvironmentStackRecord,
+        records: &[ObjectRecord],
+    ) -> JsResult<EnvironmentStack> {
+        let mut environments = Vec::with_capacity(stack.environments.len());
+        for environment in &stack.environments {
+            environments.push(match environment {
+                EnvironmentRef::Declarative(index) => Environment::Declarative(
+                    self.environments
+                        .get(*index as usize)
+                        .cloned()
+                        .flatten()
+                        .ok_or_else(invalid_snapshot)?,
+                ),
+                EnvironmentRef::Object(object) => {
+                    Environment::Object(self.object(*object, records)?)
+                }
+            });
+        }
+
+        let mut result = EnvironmentStack::new(self.context.realm().environment().clone());
+        result.extend(environments);
+        for index in &stack.private_environments {
+            result.push_private(
+                self.private_environments
+                    .get(*index as usize)
+                    .cloned()
+                    .ok_or_else(invalid_snapshot)?,
+            );
+        }
+        Ok(result)
+    }
+
+    /// Sets the internal slots of an object that refer to other objects or environments.
+    fn fill_object(
+        &mut self,
+        object: &JsObject,
+        kind: &ObjectKind,
+        records: &[ObjectRecord],
+    ) -> JsResult<()> {
+        match kind {
+            ObjectKind::Function(record) => {
+                let environments = self.environment_stack(&record.environments, records)?;
+                let home_object = self.optional_object(record.home_object, records)?;
+
+                let mut fields = Vec::with_capacity(record.fields.len());
+                for field in &record.fields {
+                    fields.push(match field {
+                        ClassFieldRecord::Public(key, initializer, function_name) => (
+                            Ok((key.clone(), function_name.clone())),
+                            self.object(*initializer, records)?,
+                        ),
+                        ClassFieldRecord::Private(name, initializer) => (
+                            Err(self.private_name(name)?),
+                            self.object(*initializer, records)?,
+                        ),
+                    });
+                }
+
+                let mut private_methods = Vec::with_capacity(record.private_methods.len());
+                for (name, method) in &record.private_methods {
+                    private_methods.push((
+                        self.private_name(name)?,
+                        self.private_element(method, records)?,
+                    ));
+                }
+
+                let mut initializers = Vec::with_capacity(record.initializers.len());
+                for initializer in &record.initializers {
+                    initializers.push(JsFunction::from_object_unchecked(
+                        self.object(*initializer, records)?,
+                    ));
+                }
+
+                let mut function = object
+                    .downcast_mut::<OrdinaryFunction>()
+                    .expect("function records must create functions");
+                function.environments = environments;
+                function.home_object = home_object;
+                for (name, initializer) in fields {
+                    let initializer = JsFunction::from_object_unchecked(initializer);
+                    match name {
+                        Ok((key, function_name)) => {
+                            function.push_field(key, initializer, function_name);
+                        }
+                        Err(name) => function.push_field_private(name, initializer),
+                    }
+                }
+                for (name, method) in private_methods {
+                    function.push_private_method(name, method);
+                }
+                function.push_initializers(initializers);
+            }
+            ObjectKind::Map(entries) => {
+                let mut map = OrderedMap::new();
+                for (key, value) in entries {
+                    map.insert(self.value(key, records)?, self.value(value, records)?);
+                }
+                *object
+                    .downcast_mut::<OrderedMap<JsValue>>()
+                    .expect("map records must create maps") = map;
+            }
+            ObjectKind::Set(values) => {
+                let mut set = OrderedSet::new();
+                for value in values {
+                    set.add(self.value(value, records)?);
+                }
+                *object
+                    .downcast_mut::<OrderedSet>()
+                    .expect("set records must create sets") = set;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Sets the prototype, properties and private elements of an object, replacing the ones it
+    /// already has.
+    fn set_state(
+        &mut self,
+        object: &JsObject,
+        state: &ObjectState,
+        records: &[ObjectRecord],
+    ) -> JsResult<()> {
+        let prototype = self.optional_object(state.prototype, records)?;
+
+        let mut properties = Vec::with_capacity(state.properties.len());
+        for (key, property) in &state.properties {
+            let descriptor = match property {
+                PropertyRecord::Data { value, attributes } => PropertyDescriptor::builder()
+                    .value(self.value(value, records)?)
+                    .writable(attributes & ATTRIBUTE_WRITABLE != 0)
+                    .enumerable(attributes & ATTRIBUTE_ENUMERABLE != 0)
+                    .configurable(attributes & ATTRIBUTE_CONFIGURABLE != 0),
+                PropertyRecord::Accessor {
+                    get,
+                    set,
+                    attributes,
+                } => PropertyDescriptor::builder()
+                    .get(self.value(get, records)?)
+                    .set(self.value(set, records)?)
+                    .enumerable(attributes & ATTRIBUTE_ENUMERABLE != 0)
+                    .configurable(attributes & ATTRIBUTE_CONFIGURABLE != 0),
+            };
+            properties.push((key.clone(), descriptor.build()));
+        }
+
+        let mut private_elements = Vec::with_capacity(state.private_elements.len());
+        for (name, element) in &state.private_elements {
+            private_elements.push((
+                self.private_name(name)?,
+                self.private_element(element, records)?,
+            ));
+        }
+
+        let mut object = object.borrow_mut();
+        object.extensible = true;
+        object.set_prototype(prototype);
+        for key in own_property_keys(&object) {
+            object.remove(&key);
+        }
+        for (key, property) in properties {
+            object.insert(key, property);
+        }
+        for (name, element) in private_elements {
+            object.append_private_element(name, element);
+        }
+        object.extensible = state.extensible;
+
+        Ok(())
+    }
+}
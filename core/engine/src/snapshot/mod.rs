@@ -0,0 +1,300 @@
+//! Snapshots of the state of a [`Context`].
+//!
+//! A [`Snapshot`] stores the global bindings of a context and all the objects, functions and
+//! environments reachable from them, so they can be restored into a new context with
+//! [`ContextBuilder::snapshot`] instead of evaluating the code that created them again. This
+//! makes it possible to evaluate the setup code of an application once, and start new contexts
+//! from its result.
+//!
+//! The built-in objects of the realm are not stored in a snapshot. Instead, a snapshot refers to
+//! them by their position in the graph of built-in objects of a new realm, and only stores the
+//! properties of the built-in objects that were changed. This requires the context a snapshot is
+//! restored into to have the same built-in objects as the context it was created from, which
+//! means it must be created by the same version of the engine, with the same features and
+//! [`HostHooks`].
+//!
+//! Only objects that can be recreated from their internal slots can be stored in a snapshot:
+//! ordinary objects, arrays, functions, bound functions, errors, primitive wrappers, dates,
+//! regular expressions, maps, sets and array buffers. Creating a snapshot of a context that can
+//! reach any other object, like a promise, a proxy or a generator, or a native function that is
+//! not a built-in function, throws a `TypeError`.
+//!
+//! Objects of the host, like the `console` object or native functions and classes registered by
+//! the host, can be stored by registering them in [`HostObjects`] with an identifier. The
+//! snapshot then stores only the identifier, and the context it is restored into must provide
+//! its own object with the same identifier.
+//!
+//! [`ContextBuilder::snapshot`]: crate::context::ContextBuilder::snapshot
+//! [`HostHooks`]: crate::context::HostHooks
+
+mod builtins;
+mod deserializer;
+mod serializer;
+
+use crate::{
+    object::ErasedObject, property::PropertyKey, Context, JsNativeError, JsObject, JsResult,
+    JsString,
+};
+
+/// Tags of the values stored in a snapshot.
+const VALUE_UNDEFINED: u8 = 0;
+const VALUE_NULL: u8 = 1;
+const VALUE_FALSE: u8 = 2;
+const VALUE_TRUE: u8 = 3;
+const VALUE_INTEGER: u8 = 4;
+const VALUE_RATIONAL: u8 = 5;
+const VALUE_STRING: u8 = 6;
+const VALUE_BIGINT: u8 = 7;
+const VALUE_SYMBOL: u8 = 8;
+const VALUE_OBJECT: u8 = 9;
+
+/// Tags of the symbols stored in a snapshot.
+const SYMBOL_WELL_KNOWN: u8 = 0;
+const SYMBOL_REGISTERED: u8 = 1;
+const SYMBOL_NEW: u8 = 2;
+const SYMBOL_REF: u8 = 3;
+
+/// Tags of the references to objects.
+const OBJECT_BUILTIN: u8 = 0;
+const OBJECT_HEAP: u8 = 1;
+const OBJECT_HOST: u8 = 2;
+
+/// Tags of the property keys.
+const KEY_STRING: u8 = 0;
+const KEY_SYMBOL: u8 = 1;
+const KEY_INDEX: u8 = 2;
+
+/// Tags of the properties.
+const PROPERTY_DATA: u8 = 0;
+const PROPERTY_ACCESSOR: u8 = 1;
+
+/// Flags of the attributes of a property.
+const ATTRIBUTE_WRITABLE: u8 = 1;
+const ATTRIBUTE_ENUMERABLE: u8 = 1 << 1;
+const ATTRIBUTE_CONFIGURABLE: u8 = 1 << 2;
+
+/// Tags of the private elements.
+const PRIVATE_FIELD: u8 = 0;
+const PRIVATE_METHOD: u8 = 1;
+const PRIVATE_ACCESSOR: u8 = 2;
+
+/// Tags of the class field definitions.
+const FIELD_PUBLIC: u8 = 0;
+const FIELD_PRIVATE: u8 = 1;
+
+/// Tags of the records that follow the global bindings.
+const RECORD_END: u8 = 0;
+const RECORD_OBJECT: u8 = 1;
+const RECORD_ENVIRONMENT: u8 = 2;
+const RECORD_BUILTIN: u8 = 3;
+
+/// Kinds of the objects stored in a snapshot.
+const KIND_ORDINARY: u8 = 0;
+const KIND_ARRAY: u8 = 1;
+const KIND_FUNCTION: u8 = 2;
+const KIND_NATIVE_FUNCTION: u8 = 3;
+const KIND_BOUND_FUNCTION: u8 = 4;
+const KIND_ERROR: u8 = 5;
+const KIND_BOOLEAN: u8 = 6;
+const KIND_NUMBER: u8 = 7;
+const KIND_STRING: u8 = 8;
+const KIND_SYMBOL: u8 = 9;
+const KIND_BIGINT: u8 = 10;
+const KIND_DATE: u8 = 11;
+const KIND_REGEXP: u8 = 12;
+const KIND_MAP: u8 = 13;
+const KIND_SET: u8 = 14;
+const KIND_ARRAY_BUFFER: u8 = 15;
+
+/// Tags of the environments of an environment stack.
+const ENVIRONMENT_DECLARATIVE: u8 = 0;
+const ENVIRONMENT_OBJECT: u8 = 1;
+
+/// Kinds of the declarative environments stored in a snapshot.
+const DECLARATIVE_LEXICAL: u8 = 0;
+const DECLARATIVE_FUNCTION: u8 = 1;
+
+/// Tags of the `[[ThisBindingStatus]]` of function environments.
+const THIS_LEXICAL: u8 = 0;
+const THIS_UNINITIALIZED: u8 = 1;
+const THIS_INITIALIZED: u8 = 2;
+
+/// Tags of the private environments and code blocks, which are stored only once.
+const SHARED_NEW: u8 = 0;
+const SHARED_REF: u8 = 1;
+
+/// A snapshot of the state of a [`Context`].
+///
+/// A snapshot is created by [`Context::snapshot`], and restored into a new context with
+/// [`ContextBuilder::snapshot`]. See the [module level documentation][self] for the objects that
+/// can be stored in a snapshot.
+///
+/// The bytes of a snapshot can be stored and loaded again with [`Snapshot::from_bytes`], but
+/// they are only validated when the snapshot is restored. Like bytecode caches, snapshots store
+/// a checksum of their contents, so restoring a snapshot that was truncated or corrupted
+/// returns an error.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{context::ContextBuilder, js_string, Context, JsValue, Source};
+/// let mut context = Context::default();
+/// context
+///     .eval(Source::from_bytes("function greet(name) { return `Hello, ${name}!`; }"))
+///     .unwrap();
+/// let snapshot = context.snapshot().unwrap();
+///
+/// let mut context = ContextBuilder::new().snapshot(snapshot).build().unwrap();
+/// assert_eq!(
+///     context.eval(Source::from_bytes("greet('snapshot')")).unwrap(),
+///     JsValue::from(js_string!("Hello, snapshot!"))
+/// );
+/// ```
+///
+/// [`ContextBuilder::snapshot`]: crate::context::ContextBuilder::snapshot
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    bytes: Vec<u8>,
+}
+
+impl Snapshot {
+    /// Creates a snapshot from the bytes returned by [`Snapshot::as_bytes`].
+    #[must_use]
+    pub const fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self { bytes }
+    }
+
+    /// Gets the bytes of the snapshot.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Converts the snapshot into its bytes.
+    #[must_use]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Creates a snapshot of the current realm of `context`.
+    pub(crate) fn create(context: &mut Context, host_objects: &HostObjects) -> JsResult<Self> {
+        serializer::serialize(context, host_objects).map(Self::from_bytes)
+    }
+
+    /// Restores the snapshot into `context`, which must have been just created.
+    pub(crate) fn restore(
+        &self,
+        context: &mut Context,
+        host_objects: &HostObjects,
+    ) -> JsResult<()> {
+        deserializer::deserialize(&self.bytes, host_objects, context)
+    }
+}
+
+/// Objects of the host that are stored in a [`Snapshot`] by an identifier.
+///
+/// Host objects can't be recreated from their internal slots, so a snapshot only stores the
+/// identifier they were registered with when it was created by [`Context::snapshot_with`]. The
+/// context the snapshot is restored into with [`Context::restore_snapshot`] must provide an
+/// object with the same identifier, which replaces the host object everywhere in the snapshot.
+///
+/// The properties of host objects are not stored in the snapshot either, so changes made to them
+/// before the snapshot was created are not restored.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{
+/// #     js_string, native_function::NativeFunction, object::FunctionObjectBuilder,
+/// #     property::Attribute, snapshot::HostObjects, Context, JsObject, JsValue, Source,
+/// # };
+/// fn double(context: &mut Context) -> JsObject {
+///     FunctionObjectBuilder::new(
+///         context.realm(),
+///         NativeFunction::from_fn_ptr(|_, args, context| {
+///             let x = args.first().cloned().unwrap_or_default().to_number(context)?;
+///             Ok(JsValue::new(x * 2.0))
+///         }),
+///     )
+///     .name(js_string!("double"))
+///     .length(1)
+///     .build()
+///     .into()
+/// }
+///
+/// let mut context = Context::default();
+/// let function = double(&mut context);
+/// context
+///     .register_global_property(js_string!("double"), function.clone(), Attribute::all())
+///     .unwrap();
+/// context
+///     .eval(Source::from_bytes("function quadruple(x) { return double(double(x)); }"))
+///     .unwrap();
+///
+/// let mut host_objects = HostObjects::new();
+/// host_objects.insert(js_string!("double"), function);
+/// let snapshot = context.snapshot_with(&host_objects).unwrap();
+///
+/// // The new context provides its own `double` function, which is also restored as the
+/// // `double` property of the global object.
+/// let mut context = Context::default();
+/// let mut host_objects = HostObjects::new();
+/// host_objects.insert(js_string!("double"), double(&mut context));
+/// context.restore_snapshot(&snapshot, &host_objects).unwrap();
+/// assert_eq!(
+///     context.eval(Source::from_bytes("quadruple(3)")).unwrap(),
+///     JsValue::new(12)
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HostObjects {
+    objects: Vec<(JsString, JsObject)>,
+}
+
+impl HostObjects {
+    /// Creates an empty set of host objects.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `object` with the identifier `id`, returning the object that was registered with
+    /// it before, if any.
+    pub fn insert(&mut self, id: JsString, object: JsObject) -> Option<JsObject> {
+        if let Some((_, registered)) = self.objects.iter_mut().find(|(key, _)| *key == id) {
+            return Some(std::mem::replace(registered, object));
+        }
+        self.objects.push((id, object));
+        None
+    }
+
+    /// Gets the object registered with the identifier `id`.
+    #[must_use]
+    pub fn get(&self, id: &JsString) -> Option<&JsObject> {
+        self.objects
+            .iter()
+            .find_map(|(key, object)| (key == id).then_some(object))
+    }
+}
+
+fn invalid_snapshot() -> JsNativeError {
+    JsNativeError::error().with_message("invalid snapshot")
+}
+
+/// Gets the own property keys of an object, with the index keys in ascending order followed by
+/// the other keys in the order they were created.
+fn own_property_keys(object: &ErasedObject) -> Vec<PropertyKey> {
+    let mut indices: Vec<_> = object.properties().index_property_keys().collect();
+    indices.sort_unstable();
+
+    let mut keys: Vec<PropertyKey> = indices.into_iter().map(Into::into).collect();
+    keys.extend(object.shape().keys());
+    keys
+}
+
+/// Gets the address of an object, which is used as the unique identifier of the private names of
+/// the class it is the constructor of.
+fn object_addr(object: &JsObject) -> usize {
+    let ptr: *const _ = object.as_ref();
+    ptr.cast::<()>() as usize
+}
@@ -0,0 +1,763 @@
+//! Creation of snapshots.
+
+use std::collections::VecDeque;
+
+use boa_gc::Gc;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    builtins::{
+        array_buffer::ArrayBuffer,
+        function::{BoundFunction, ClassFieldDefinition, ConstructorKind, OrdinaryFunction},
+        map::ordered_map::OrderedMap,
+        set::ordered_set::OrderedSet,
+        symbol::registered_key,
+        Array, Date, Error, OrdinaryObject, RegExp,
+    },
+    environments::{
+        DeclarativeEnvironment, DeclarativeEnvironmentKind, Environment, EnvironmentStack,
+        PrivateEnvironment, ThisBindingStatus,
+    },
+    native_function::NativeFunctionObject,
+    object::{PrivateElement, PrivateName},
+    property::{PropertyDescriptor, PropertyKey},
+    realm::Realm,
+    spanned_source_text::SourceText,
+    vm::{CacheKind, CacheWriter, CodeBlock},
+    Context, JsBigInt, JsNativeError, JsObject, JsResult, JsString, JsSymbol, JsValue, JsVariant,
+};
+
+use super::{
+    builtins::Builtins, object_addr, own_property_keys, HostObjects, ATTRIBUTE_CONFIGURABLE,
+    ATTRIBUTE_ENUMERABLE, ATTRIBUTE_WRITABLE, DECLARATIVE_FUNCTION, DECLARATIVE_LEXICAL,
+    ENVIRONMENT_DECLARATIVE, ENVIRONMENT_OBJECT, FIELD_PRIVATE, FIELD_PUBLIC, KEY_INDEX,
+    KEY_STRING, KEY_SYMBOL, KIND_ARRAY, KIND_ARRAY_BUFFER, KIND_BIGINT, KIND_BOOLEAN,
+    KIND_BOUND_FUNCTION, KIND_DATE, KIND_ERROR, KIND_FUNCTION, KIND_MAP, KIND_NATIVE_FUNCTION,
+    KIND_NUMBER, KIND_ORDINARY, KIND_REGEXP, KIND_SET, KIND_STRING, KIND_SYMBOL, OBJECT_BUILTIN,
+    OBJECT_HEAP, OBJECT_HOST, PRIVATE_ACCESSOR, PRIVATE_FIELD, PRIVATE_METHOD, PROPERTY_ACCESSOR,
+    PROPERTY_DATA, RECORD_BUILTIN, RECORD_END, RECORD_ENVIRONMENT, RECORD_OBJECT, SHARED_NEW,
+    SHARED_REF, SYMBOL_NEW, SYMBOL_REF, SYMBOL_REGISTERED, SYMBOL_WELL_KNOWN, THIS_INITIALIZED,
+    THIS_LEXICAL, THIS_UNINITIALIZED, VALUE_BIGINT, VALUE_FALSE, VALUE_INTEGER, VALUE_NULL,
+    VALUE_OBJECT, VALUE_RATIONAL, VALUE_STRING, VALUE_SYMBOL, VALUE_TRUE, VALUE_UNDEFINED,
+};
+
+/// An object or environment found while writing a snapshot, that must be written later.
+enum Pending {
+    Object(JsObject),
+    Environment(Gc<DeclarativeEnvironment>),
+}
+
+/// Writes the state of a context into a snapshot.
+struct Serializer {
+    writer: CacheWriter,
+    realm: Realm,
+
+    /// The built-in objects of a new realm.
+    baseline: Builtins,
+
+    /// The built-in objects of the realm of the snapshot, with their index in `baseline`.
+    builtins: FxHashMap<JsObject, u32>,
+
+    /// The identifiers of the host objects, and the index of each host object among them.
+    host_ids: Vec<JsString>,
+    host_objects: FxHashMap<JsObject, u32>,
+
+    objects: FxHashMap<JsObject, u32>,
+    environments: FxHashMap<*const DeclarativeEnvironment, u32>,
+    private_environments: FxHashMap<*const PrivateEnvironment, u32>,
+    private_ids: FxHashMap<usize, u32>,
+    code_blocks: FxHashMap<*const CodeBlock, u32>,
+    symbols: FxHashMap<JsSymbol, u32>,
+    pending: VecDeque<Pending>,
+}
+
+/// Creates a snapshot of the current realm of `context`.
+pub(super) fn serialize(context: &mut Context, host: &HostObjects) -> JsResult<Vec<u8>> {
+    let realm = context.realm().clone();
+    let baseline = Builtins::new(&context.create_realm()?);
+    let mut builtins = baseline.find_in(&realm);
+
+    let mut host_ids = Vec::with_capacity(host.objects.len());
+    let mut host_objects = FxHashMap::default();
+    for (id, object) in &host.objects {
+        // Host objects are only stored by their identifier, even if they are built-in objects.
+        builtins.remove(object);
+        host_objects.insert(object.clone(), host_ids.len() as u32);
+        host_ids.push(id.clone());
+    }
+
+    let mut serializer = Serializer {
+        writer: CacheWriter::new(CacheKind::Snapshot, &SourceText::new_empty()),
+        realm,
+        baseline,
+        builtins,
+        host_ids,
+        host_objects,
+        objects: FxHashMap::default(),
+        environments: FxHashMap::default(),
+        private_environments: FxHashMap::default(),
+        private_ids: FxHashMap::default(),
+        code_blocks: FxHashMap::default(),
+        symbols: FxHashMap::default(),
+        pending: VecDeque::new(),
+    };
+    serializer.write()?;
+    Ok(serializer.writer.finish())
+}
+
+impl Serializer {
+    fn write(&mut self) -> JsResult<()> {
+        self.writer.write_usize(self.baseline.len());
+
+        self.writer.write_usize(self.host_ids.len());
+        for id in &self.host_ids {
+            self.writer.write_string(id);
+        }
+
+        // The bindings of the global scope must be known before any code is read, since the
+        // bytecode refers to them.
+        let bindings = self.realm.scope().bindings();
+        self.writer.write_usize(bindings.len());
+        for binding in &bindings {
+            self.writer.write_string(&binding.name);
+            self.writer.write_bool(binding.mutable);
+            self.writer.write_bool(binding.lex);
+            self.writer.write_bool(binding.strict);
+        }
+
+        let values = self
+            .realm
+            .environment()
+            .kind()
+            .as_global()
+            .expect("Realm should only store global environments")
+            .poisonable_environment()
+            .bindings()
+            .borrow()
+            .clone();
+        self.writer.write_usize(values.len());
+        for value in &values {
+            self.write_binding(value.as_ref());
+        }
+
+        let mut builtins = self
+            .builtins
+            .iter()
+            .map(|(object, index)| (*index, object.clone()))
+            .collect::<Vec<_>>();
+        builtins.sort_unstable_by_key(|(index, _)| *index);
+        for (index, object) in builtins {
+            if self.builtin_changed(&object, index) {
+                self.writer.write_u8(RECORD_BUILTIN);
+                self.writer.write_u32(index);
+                self.write_object_state(&object);
+            }
+        }
+
+        while let Some(pending) = self.pending.pop_front() {
+            match pending {
+                Pending::Object(object) => {
+                    self.writer.write_u8(RECORD_OBJECT);
+                    self.write_object_kind(&object)?;
+                    self.write_object_state(&object);
+                }
+                Pending::Environment(environment) => {
+                    self.writer.write_u8(RECORD_ENVIRONMENT);
+                    self.write_environment(&environment)?;
+                }
+            }
+        }
+        self.writer.write_u8(RECORD_END);
+
+        // The unique identifier of private names is the address of their class constructor, so
+        // it is replaced by the address of the restored constructor.
+        let mut anchors = FxHashMap::default();
+        for (object, index) in &self.objects {
+            anchors.insert(object_addr(object), (OBJECT_HEAP, *index));
+        }
+        for (object, index) in &self.builtins {
+            anchors.insert(object_addr(object), (OBJECT_BUILTIN, *index));
+        }
+        for (object, index) in &self.host_objects {
+            anchors.insert(object_addr(object), (OBJECT_HOST, *index));
+        }
+
+        let mut ids = self
+            .private_ids
+            .iter()
+            .map(|(id, index)| (*index, *id))
+            .collect::<Vec<_>>();
+        ids.sort_unstable_by_key(|(index, _)| *index);
+        self.writer.write_usize(ids.len());
+        for (_, id) in ids {
+            match anchors.get(&id) {
+                Some((tag, index)) => {
+                    self.writer.write_bool(true);
+                    self.writer.write_u8(*tag);
+                    self.writer.write_u32(*index);
+                }
+                None => self.writer.write_bool(false),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks if a built-in object is different from the built-in object with the same index in
+    /// a new realm.
+    fn builtin_changed(&self, object: &JsObject, index: u32) -> bool {
+        let baseline = self
+            .baseline
+            .get(index)
+            .expect("built-in objects must have a valid index");
+        let (object, baseline) = (object.borrow(), baseline.borrow());
+
+        if object.extensible != baseline.extensible || !object.private_elements().is_empty() {
+            return true;
+        }
+
+        match (object.prototype(), baseline.prototype()) {
+            (None, None) => {}
+            (Some(a), Some(b)) if self.same_object(&a, &b) => {}
+            _ => return true,
+        }
+
+        let keys = own_property_keys(&object);
+        if keys != own_property_keys(&baseline) {
+            return true;
+        }
+
+        keys.iter().any(|key| {
+            let (Some(a), Some(b)) = (object.properties().get(key), baseline.properties().get(key))
+            else {
+                return true;
+            };
+
+            a.enumerable() != b.enumerable()
+                || a.configurable() != b.configurable()
+                || a.writable() != b.writable()
+                || !self.same_value(a.value(), b.value())
+                || !self.same_value(a.get(), b.get())
+                || !self.same_value(a.set(), b.set())
+        })
+    }
+
+    /// Checks if an object of the snapshot is the same built-in object as an object of the new
+    /// realm.
+    fn same_object(&self, object: &JsObject, baseline: &JsObject) -> bool {
+        self.builtins
+            .get(object)
+            .is_some_and(|index| self.baseline.index_of(baseline) == Some(*index))
+    }
+
+    fn same_value(&self, value: Option<&JsValue>, baseline: Option<&JsValue>) -> bool {
+        match (value, baseline) {
+            (None, None) => true,
+            (Some(a), Some(b)) => match (a.as_object(), b.as_object()) {
+                (Some(a), Some(b)) => self.same_object(a, b),
+                (None, None) => JsValue::same_value(a, b),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    fn write_binding(&mut self, value: Option<&JsValue>) {
+        match value {
+            Some(value) => {
+                self.writer.write_bool(true);
+                self.write_value(value);
+            }
+            None => self.writer.write_bool(false),
+        }
+    }
+
+    fn write_value(&mut self, value: &JsValue) {
+        match value.variant() {
+            JsVariant::Undefined => self.writer.write_u8(VALUE_UNDEFINED),
+            JsVariant::Null => self.writer.write_u8(VALUE_NULL),
+            JsVariant::Boolean(false) => self.writer.write_u8(VALUE_FALSE),
+            JsVariant::Boolean(true) => self.writer.write_u8(VALUE_TRUE),
+            JsVariant::Integer32(integer) => {
+                self.writer.write_u8(VALUE_INTEGER);
+                self.writer.write_u32(integer as u32);
+            }
+            JsVariant::Float64(rational) => {
+                self.writer.write_u8(VALUE_RATIONAL);
+                self.writer.write_u64(rational.to_bits());
+            }
+            JsVariant::String(string) => {
+                self.writer.write_u8(VALUE_STRING);
+                self.writer.write_string(string);
+            }
+            JsVariant::BigInt(bigint) => {
+                self.writer.write_u8(VALUE_BIGINT);
+                self.write_bigint(bigint);
+            }
+            JsVariant::Symbol(symbol) => {
+                self.writer.write_u8(VALUE_SYMBOL);
+                self.write_symbol(symbol);
+            }
+            JsVariant::Object(object) => {
+                self.writer.write_u8(VALUE_OBJECT);
+                self.write_object_ref(object);
+            }
+        }
+    }
+
+    fn write_bigint(&mut self, bigint: &JsBigInt) {
+        self.writer
+            .write_bytes(bigint.to_string_radix(16).as_bytes());
+    }
+
+    fn write_symbol(&mut self, symbol: &JsSymbol) {
+        if JsSymbol::well_known(symbol.hash()).is_some() {
+            self.writer.write_u8(SYMBOL_WELL_KNOWN);
+            self.writer.write_u64(symbol.hash());
+        } else if let Some(key) = registered_key(symbol) {
+            self.writer.write_u8(SYMBOL_REGISTERED);
+            self.writer.write_string(&key);
+        } else if let Some(&index) = self.symbols.get(symbol) {
+            self.writer.write_u8(SYMBOL_REF);
+            self.writer.write_u32(index);
+        } else {
+            self.writer.write_u8(SYMBOL_NEW);
+            self.write_optional_string(symbol.description().as_ref());
+            let index = self.symbols.len() as u32;
+            self.symbols.insert(symbol.clone(), index);
+        }
+    }
+
+    fn write_optional_string(&mut self, string: Option<&JsString>) {
+        match string {
+            Some(string) => {
+                self.writer.write_bool(true);
+                self.writer.write_string(string);
+            }
+            None => self.writer.write_bool(false),
+        }
+    }
+
+    /// Writes a reference to an object, queuing it to be written if it is not a built-in object
+    /// or a host object.
+    fn write_object_ref(&mut self, object: &JsObject) {
+        if let Some(&index) = self.host_objects.get(object) {
+            self.writer.write_u8(OBJECT_HOST);
+            self.writer.write_u32(index);
+            return;
+        }
+
+        if let Some(&index) = self.builtins.get(object) {
+            self.writer.write_u8(OBJECT_BUILTIN);
+            self.writer.write_u32(index);
+            return;
+        }
+
+        let index = if let Some(&index) = self.objects.get(object) {
+            index
+        } else {
+            let index = self.objects.len() as u32;
+            self.objects.insert(object.clone(), index);
+            self.pending.push_back(Pending::Object(object.clone()));
+            index
+        };
+        self.writer.write_u8(OBJECT_HEAP);
+        self.writer.write_u32(index);
+    }
+
+    fn write_optional_object_ref(&mut self, object: Option<&JsObject>) {
+        match object {
+            Some(object) => {
+                self.writer.write_bool(true);
+                self.write_object_ref(object);
+            }
+            None => self.writer.write_bool(false),
+        }
+    }
+
+    fn write_key(&mut self, key: &PropertyKey) {
+        match key {
+            PropertyKey::String(string) => {
+                self.writer.write_u8(KEY_STRING);
+                self.writer.write_string(string);
+            }
+            PropertyKey::Symbol(symbol) => {
+                self.writer.write_u8(KEY_SYMBOL);
+                self.write_symbol(symbol);
+            }
+            PropertyKey::Index(index) => {
+                self.writer.write_u8(KEY_INDEX);
+                self.writer.write_u32(index.get());
+            }
+        }
+    }
+
+    fn write_property(&mut self, property: &PropertyDescriptor) {
+        let mut attributes = 0;
+        if property.writable() == Some(true) {
+            attributes |= ATTRIBUTE_WRITABLE;
+        }
+        if property.enumerable() == Some(true) {
+            attributes |= ATTRIBUTE_ENUMERABLE;
+        }
+        if property.configurable() == Some(true) {
+            attributes |= ATTRIBUTE_CONFIGURABLE;
+        }
+
+        if property.is_accessor_descriptor() {
+            self.writer.write_u8(PROPERTY_ACCESSOR);
+            self.writer.write_u8(attributes);
+            self.write_value(property.get().unwrap_or(&JsValue::undefined()));
+            self.write_value(property.set().unwrap_or(&JsValue::undefined()));
+        } else {
+            self.writer.write_u8(PROPERTY_DATA);
+            self.writer.write_u8(attributes);
+            self.write_value(property.value().unwrap_or(&JsValue::undefined()));
+        }
+    }
+
+    /// Writes the private identifier of a private name or private environment.
+    fn write_private_id(&mut self, id: usize) {
+        let len = self.private_ids.len() as u32;
+        let index = *self.private_ids.entry(id).or_insert(len);
+        self.writer.write_u32(index);
+    }
+
+    fn write_private_name(&mut self, name: &PrivateName) {
+        self.writer.write_string(name.description());
+        self.write_private_id(name.id());
+    }
+
+    fn write_private_element(&mut self, element: &PrivateElement) {
+        match element {
+            PrivateElement::Field(value) => {
+                self.writer.write_u8(PRIVATE_FIELD);
+                self.write_value(value);
+            }
+            PrivateElement::Method(method) => {
+                self.writer.write_u8(PRIVATE_METHOD);
+                self.write_object_ref(method);
+            }
+            PrivateElement::Accessor { getter, setter } => {
+                self.writer.write_u8(PRIVATE_ACCESSOR);
+                self.write_optional_object_ref(getter.as_ref());
+                self.write_optional_object_ref(setter.as_ref());
+            }
+        }
+    }
+
+    /// Writes the prototype, properties and private elements of an object.
+    fn write_object_state(&mut self, object: &JsObject) {
+        let object = object.borrow();
+        self.write_optional_object_ref(object.prototype().as_ref());
+        self.writer.write_bool(object.extensible);
+
+        let keys = own_property_keys(&object);
+        self.writer.write_usize(keys.len());
+        for key in &keys {
+            let property = object
+                .properties()
+                .get(key)
+                .expect("own property keys must have a property");
+            self.write_key(key);
+            self.write_property(&property);
+        }
+
+        let private_elements = object.private_elements();
+        self.writer.write_usize(private_elements.len());
+        for (name, element) in private_elements {
+            self.write_private_name(name);
+            self.write_private_element(element);
+        }
+    }
+
+    /// Writes the kind of an object and the internal slots that depend on it.
+    fn write_object_kind(&mut self, object: &JsObject) -> JsResult<()> {
+        let object = object.borrow();
+        let data = object.data();
+
+        if data.is::<OrdinaryObject>() {
+            self.writer.write_u8(KIND_ORDINARY);
+        } else if data.is::<Array>() {
+            self.writer.write_u8(KIND_ARRAY);
+        } else if let Some(function) = data.downcast_ref::<OrdinaryFunction>() {
+            self.writer.write_u8(KIND_FUNCTION);
+            self.write_function(function)?;
+        } else if let Some(function) = data.downcast_ref::<NativeFunctionObject>() {
+            self.writer.write_u8(KIND_NATIVE_FUNCTION);
+            self.write_native_function(function)?;
+        } else if let Some(function) = data.downcast_ref::<BoundFunction>() {
+            self.writer.write_u8(KIND_BOUND_FUNCTION);
+            self.write_object_ref(function.target_function());
+            self.write_value(function.this());
+            self.writer.write_usize(function.args().len());
+            for arg in function.args() {
+                self.write_value(arg);
+            }
+        } else if let Some(error) = data.downcast_ref::<Error>() {
+            self.writer.write_u8(KIND_ERROR);
+            self.writer.write_u8(match error {
+                Error::Aggregate => 0,
+                Error::Error => 1,
+                Error::Eval => 2,
+                Error::Type => 3,
+                Error::Range => 4,
+                Error::Reference => 5,
+                Error::Syntax => 6,
+                Error::Uri => 7,
+                Error::Suppressed => 8,
+            });
+        } else if let Some(boolean) = data.downcast_ref::<bool>() {
+            self.writer.write_u8(KIND_BOOLEAN);
+            self.writer.write_bool(*boolean);
+        } else if let Some(number) = data.downcast_ref::<f64>() {
+            self.writer.write_u8(KIND_NUMBER);
+            self.writer.write_u64(number.to_bits());
+        } else if let Some(string) = data.downcast_ref::<JsString>() {
+            self.writer.write_u8(KIND_STRING);
+            self.writer.write_string(string);
+        } else if let Some(symbol) = data.downcast_ref::<JsSymbol>() {
+            self.writer.write_u8(KIND_SYMBOL);
+            self.write_symbol(symbol);
+        } else if let Some(bigint) = data.downcast_ref::<JsBigInt>() {
+            self.writer.write_u8(KIND_BIGINT);
+            self.write_bigint(bigint);
+        } else if let Some(date) = data.downcast_ref::<Date>() {
+            self.writer.write_u8(KIND_DATE);
+            self.writer.write_u64(date.time_value().to_bits());
+        } else if let Some(regexp) = data.downcast_ref::<RegExp>() {
+            self.writer.write_u8(KIND_REGEXP);
+            self.writer.write_string(regexp.original_source());
+            self.writer.write_string(regexp.original_flags());
+        } else if let Some(map) = data.downcast_ref::<OrderedMap<JsValue>>() {
+            self.writer.write_u8(KIND_MAP);
+            let entries = map.iter().collect::<Vec<_>>();
+            self.writer.write_usize(entries.len());
+            for (key, value) in entries {
+                self.write_value(key);
+                self.write_value(value);
+            }
+        } else if let Some(set) = data.downcast_ref::<OrderedSet>() {
+            self.writer.write_u8(KIND_SET);
+            let values = set.iter().collect::<Vec<_>>();
+            self.writer.write_usize(values.len());
+            for value in values {
+                self.write_value(value);
+            }
+        } else if let Some(buffer) = data.downcast_ref::<ArrayBuffer>() {
+            self.writer.write_u8(KIND_ARRAY_BUFFER);
+            match buffer.bytes() {
+                Some(bytes) => {
+                    self.writer.write_bool(true);
+                    self.writer.write_bytes(bytes);
+                }
+                None => self.writer.write_bool(false),
+            }
+            match buffer.max_byte_len() {
+                Some(max_byte_len) => {
+                    self.writer.write_bool(true);
+                    self.writer.write_u64(max_byte_len);
+                }
+                None => self.writer.write_bool(false),
+            }
+            self.write_value(buffer.detach_key());
+        } else {
+            let name = data.type_name_of_value();
+            let name = name.rsplit("::").next().unwrap_or(name);
+            return Err(JsNativeError::typ()
+                .with_message(format!("cannot snapshot `{name}` objects"))
+                .into());
+        }
+
+        Ok(())
+    }
+
+    fn write_function(&mut self, function: &OrdinaryFunction) -> JsResult<()> {
+        if function.realm != self.realm {
+            return Err(JsNativeError::typ()
+                .with_message("cannot snapshot functions of other realms")
+                .into());
+        }
+
+        let code = &function.code;
+        let ptr = std::ptr::from_ref::<CodeBlock>(code);
+        if let Some(&index) = self.code_blocks.get(&ptr) {
+            self.writer.write_u8(SHARED_REF);
+            self.writer.write_u32(index);
+        } else {
+            self.writer.write_u8(SHARED_NEW);
+            self.writer.write_code_block(code);
+            let index = self.code_blocks.len() as u32;
+            self.code_blocks.insert(ptr, index);
+        }
+
+        self.write_environment_stack(&function.environments)?;
+        self.write_optional_object_ref(function.get_home_object());
+
+        let fields = function.get_fields();
+        self.writer.write_usize(fields.len());
+        for field in fields {
+            match field {
+                ClassFieldDefinition::Public(key, initializer, function_name) => {
+                    self.writer.write_u8(FIELD_PUBLIC);
+                    self.write_key(key);
+                    self.write_object_ref(initializer);
+                    match function_name {
+                        Some(name) => {
+                            self.writer.write_bool(true);
+                            self.write_key(name);
+                        }
+                        None => self.writer.write_bool(false),
+                    }
+                }
+                ClassFieldDefinition::Private(name, initializer) => {
+                    self.writer.write_u8(FIELD_PRIVATE);
+                    self.write_private_name(name);
+                    self.write_object_ref(initializer);
+                }
+            }
+        }
+
+        let private_methods = function.get_private_methods();
+        self.writer.write_usize(private_methods.len());
+        for (name, method) in private_methods {
+            self.write_private_name(name);
+            self.write_private_element(method);
+        }
+
+        let initializers = function.get_initializers();
+        self.writer.write_usize(initializers.len());
+        for initializer in initializers {
+            self.write_object_ref(initializer);
+        }
+
+        Ok(())
+    }
+
+    fn write_native_function(&mut self, function: &NativeFunctionObject) -> JsResult<()> {
+        let index = function
+            .f
+            .as_fn_ptr()
+            .and_then(|f| self.baseline.native_function_index(f as usize))
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "cannot snapshot native functions that are not built-in or host objects",
+                )
+            })?;
+
+        if function
+            .realm
+            .as_ref()
+            .is_some_and(|realm| *realm != self.realm)
+        {
+            return Err(JsNativeError::typ()
+                .with_message("cannot snapshot functions of other realms")
+                .into());
+        }
+
+        self.writer.write_u32(index);
+        self.writer.write_u8(match function.constructor {
+            None => 0,
+            Some(ConstructorKind::Base) => 1,
+            Some(ConstructorKind::Derived) => 2,
+        });
+        self.writer.write_bool(function.realm.is_some());
+        Ok(())
+    }
+
+    fn write_environment_stack(&mut self, environments: &EnvironmentStack) -> JsResult<()> {
+        if !Gc::ptr_eq(environments.global(), self.realm.environment()) {
+            return Err(JsNativeError::typ()
+                .with_message("cannot snapshot functions of other realms")
+                .into());
+        }
+
+        self.writer.write_usize(environments.environments().len());
+        for environment in environments.environments() {
+            match environment {
+                Environment::Declarative(environment) => {
+                    self.writer.write_u8(ENVIRONMENT_DECLARATIVE);
+                    let ptr = std::ptr::from_ref::<DeclarativeEnvironment>(environment);
+                    let index = if let Some(&index) = self.environments.get(&ptr) {
+                        index
+                    } else {
+                        let index = self.environments.len() as u32;
+                        self.environments.insert(ptr, index);
+                        self.pending
+                            .push_back(Pending::Environment(environment.clone()));
+                        index
+                    };
+                    self.writer.write_u32(index);
+                }
+                Environment::Object(object) => {
+                    self.writer.write_u8(ENVIRONMENT_OBJECT);
+                    self.write_object_ref(object);
+                }
+            }
+        }
+
+        self.writer
+            .write_usize(environments.private_environments().len());
+        for environment in environments.private_environments() {
+            let ptr = std::ptr::from_ref::<PrivateEnvironment>(environment);
+            if let Some(&index) = self.private_environments.get(&ptr) {
+                self.writer.write_u8(SHARED_REF);
+                self.writer.write_u32(index);
+                continue;
+            }
+
+            self.writer.write_u8(SHARED_NEW);
+            self.write_private_id(environment.id());
+            self.writer.write_usize(environment.descriptions().len());
+            for description in environment.descriptions() {
+                self.writer.write_string(description);
+            }
+            let index = self.private_environments.len() as u32;
+            self.private_environments.insert(ptr, index);
+        }
+
+        Ok(())
+    }
+
+    fn write_environment(&mut self, environment: &DeclarativeEnvironment) -> JsResult<()> {
+        let (inner, slots) = match environment.kind() {
+            DeclarativeEnvironmentKind::Lexical(lexical) => {
+                self.writer.write_u8(DECLARATIVE_LEXICAL);
+                (lexical.poisonable_environment(), None)
+            }
+            DeclarativeEnvironmentKind::Function(function) => {
+                self.writer.write_u8(DECLARATIVE_FUNCTION);
+                (function.poisonable_environment(), Some(function))
+            }
+            DeclarativeEnvironmentKind::Global(_) | DeclarativeEnvironmentKind::Module(_) => {
+                return Err(JsNativeError::typ()
+                    .with_message("cannot snapshot functions of modules")
+                    .into());
+            }
+        };
+
+        self.writer.write_bool(environment.poisoned());
+        self.writer.write_bool(environment.with());
+        let bindings = inner.bindings().borrow().clone();
+        self.writer.write_usize(bindings.len());
+        for binding in &bindings {
+            self.write_binding(binding.as_ref());
+        }
+
+        if let Some(function) = slots {
+            self.writer.write_scope(function.compile());
+            match function.slots().this() {
+                ThisBindingStatus::Lexical => self.writer.write_u8(THIS_LEXICAL),
+                ThisBindingStatus::Uninitialized => self.writer.write_u8(THIS_UNINITIALIZED),
+                ThisBindingStatus::Initialized(value) => {
+                    self.writer.write_u8(THIS_INITIALIZED);
+                    self.write_value(&value);
+                }
+            }
+            self.write_object_ref(function.slots().function_object());
+            self.write_optional_object_ref(function.slots().new_target());
+        }
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use boa_ast::LinearSpan;
+use boa_ast::{LinearPosition, LinearSpan};
 use boa_gc::{Finalize, Gc, Trace};
 
 #[derive(Trace, Finalize)]
@@ -25,7 +25,7 @@ impl SourceText {
         }
     }
 
-    fn new_empty() -> Self {
+    pub(crate) fn new_empty() -> Self {
         Self { source_text: None }
     }
 
@@ -37,6 +37,21 @@ impl SourceText {
     fn is_empty(&self) -> bool {
         self.source_text.is_none()
     }
+
+    /// Gets the address of the source text, which identifies it while it is alive.
+    pub(crate) fn addr(&self) -> *const () {
+        self.source_text.as_ref().map_or(std::ptr::null(), |inner| {
+            let ptr = std::ptr::from_ref::<Inner>(inner);
+            ptr.cast()
+        })
+    }
+
+    /// Gets all the code points of the source text.
+    pub(crate) fn code_points(&self) -> &[u16] {
+        self.inner().map_or(&[], |source_text| {
+            source_text.get_code_points_from_pos(LinearPosition::new(0))
+        })
+    }
 }
 
 /// Contains pointer to source code and span of the object.
@@ -79,6 +94,18 @@ impl SpannedSourceText {
         self.source_text.clone()
     }
 
+    /// Returns the span of the source text, if any.
+    #[must_use]
+    pub(crate) const fn span(&self) -> Option<LinearSpan> {
+        self.span
+    }
+
+    /// Test if there is a source text, regardless of the span.
+    #[must_use]
+    pub(crate) fn has_source_text(&self) -> bool {
+        !self.source_text.is_empty()
+    }
+
     /// Test if the span is empty.
     #[inline]
     #[must_use]
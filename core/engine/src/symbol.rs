@@ -61,7 +61,9 @@ fn get_id() -> Option<u64> {
 #[derive(Debug, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
 #[repr(u8)]
 enum WellKnown {
+    AsyncDispose,
     AsyncIterator,
+    Dispose,
     HasInstance,
     IsConcatSpreadable,
     Iterator,
@@ -79,7 +81,9 @@ enum WellKnown {
 impl WellKnown {
     const fn description(self) -> JsString {
         match self {
+            Self::AsyncDispose => StaticJsStrings::SYMBOL_ASYNC_DISPOSE,
             Self::AsyncIterator => StaticJsStrings::SYMBOL_ASYNC_ITERATOR,
+            Self::Dispose => StaticJsStrings::SYMBOL_DISPOSE,
             Self::HasInstance => StaticJsStrings::SYMBOL_HAS_INSTANCE,
             Self::IsConcatSpreadable => StaticJsStrings::SYMBOL_IS_CONCAT_SPREADABLE,
             Self::Iterator => StaticJsStrings::SYMBOL_ITERATOR,
@@ -97,7 +101,9 @@ impl WellKnown {
 
     const fn fn_name(self) -> JsString {
         match self {
+            Self::AsyncDispose => StaticJsStrings::FN_SYMBOL_ASYNC_DISPOSE,
             Self::AsyncIterator => StaticJsStrings::FN_SYMBOL_ASYNC_ITERATOR,
+            Self::Dispose => StaticJsStrings::FN_SYMBOL_DISPOSE,
             Self::HasInstance => StaticJsStrings::FN_SYMBOL_HAS_INSTANCE,
             Self::IsConcatSpreadable => StaticJsStrings::FN_SYMBOL_IS_CONCAT_SPREADABLE,
             Self::Iterator => StaticJsStrings::FN_SYMBOL_ITERATOR,
@@ -177,6 +183,14 @@ impl JsSymbol {
         })
     }
 
+    /// Gets the well known symbol with the given `hash`, if there is one.
+    pub(crate) fn well_known(hash: u64) -> Option<Self> {
+        let tag = usize::try_from(hash).ok()?;
+        WellKnown::from_tag(tag).map(|_| Self {
+            repr: Tagged::from_tag(tag),
+        })
+    }
+
     /// Returns the `Symbol` description.
     #[inline]
     #[must_use]
@@ -249,8 +263,12 @@ impl JsSymbol {
     }
 
     well_known_symbols! {
+        /// Gets the static `JsSymbol` for `"Symbol.asyncDispose"`.
+        (async_dispose, WellKnown::AsyncDispose),
         /// Gets the static `JsSymbol` for `"Symbol.asyncIterator"`.
         (async_iterator, WellKnown::AsyncIterator),
+        /// Gets the static `JsSymbol` for `"Symbol.dispose"`.
+        (dispose, WellKnown::Dispose),
         /// Gets the static `JsSymbol` for `"Symbol.hasInstance"`.
         (has_instance, WellKnown::HasInstance),
         /// Gets the static `JsSymbol` for `"Symbol.isConcatSpreadable"`.
@@ -72,3 +72,153 @@ fn class_can_access_super_from_static_initializer() {
         TestAction::assert_eq("c.field", js_str!("super field")),
     ]);
 }
+
+#[test]
+fn class_method_decorators() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const calls = [];
+            function log(name) {
+                return function(value, context) {
+                    calls.push(`${name}:${context.kind}:${context.name}`);
+                    return function(...args) {
+                        return `${name}(${value.call(this, ...args)})`;
+                    };
+                };
+            }
+
+            class C {
+                @log("a") @log("b") m() { return "m"; }
+                @log("c") static #s() { return "s"; }
+                static s() { return this.#s(); }
+            }
+        "#}),
+        TestAction::assert_eq("calls.join()", js_str!("b:method:m,a:method:m,c:method:#s")),
+        TestAction::assert_eq("new C().m()", js_str!("a(b(m))")),
+        TestAction::assert_eq("C.s()", js_str!("c(s)")),
+        TestAction::assert_eq("C.prototype.m.name", js_str!("")),
+    ]);
+}
+
+#[test]
+fn class_decorator_context() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            let ctx;
+            function capture(value, context) { ctx = context; }
+
+            class C {
+                #x = 1;
+                @capture get #y() { return this.#x; }
+            }
+        "#}),
+        TestAction::assert_eq("ctx.kind", js_str!("getter")),
+        TestAction::assert_eq("ctx.name", js_str!("#y")),
+        TestAction::assert("ctx.private && !ctx.static"),
+        TestAction::assert("!('set' in ctx.access)"),
+        TestAction::assert_eq("ctx.access.get(new C())", 1),
+        TestAction::assert("ctx.access.has(new C()) && !ctx.access.has({})"),
+        TestAction::assert_native_error(
+            "ctx.addInitializer(() => {})",
+            crate::JsNativeErrorKind::Type,
+            "cannot add an initializer after the decorator has finished",
+        ),
+    ]);
+}
+
+#[test]
+fn class_field_and_accessor_decorators() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const double = (value, context) => (initial) => initial * 2;
+            function track(value, context) {
+                return {
+                    get() { return value.get.call(this) + 1; },
+                    set(v) { value.set.call(this, v * 10); },
+                    init(initial) { return initial + 1; },
+                };
+            }
+
+            class C {
+                @double x = 2;
+                @double static y = 3;
+                @track accessor z = 1;
+                accessor #w = 5;
+                get w() { return this.#w; }
+                static accessor s = "s";
+            }
+
+            const c = new C();
+        "#}),
+        TestAction::assert_eq("c.x", 4),
+        TestAction::assert_eq("C.y", 6),
+        TestAction::assert_eq("c.z", 3),
+        TestAction::run("c.z = 2"),
+        TestAction::assert_eq("c.z", 21),
+        TestAction::assert_eq("c.w", 5),
+        TestAction::assert_eq("C.s", js_str!("s")),
+        TestAction::assert("!Object.hasOwn(c, 'z')"),
+        TestAction::assert_eq(
+            "Object.getOwnPropertyDescriptor(C.prototype, 'z').get.name",
+            js_str!("get"),
+        ),
+    ]);
+}
+
+#[test]
+fn class_decorator_initializers() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const order = [];
+            function init(name) {
+                return function(value, context) {
+                    context.addInitializer(function() {
+                        order.push(`${name}:${typeof this}`);
+                    });
+                };
+            }
+            function replace(value, context) {
+                context.addInitializer(function() { order.push(`class:${typeof this}`); });
+                return class extends value {
+                    static replaced = true;
+                };
+            }
+
+            @replace
+            class C {
+                @init("method") m() {}
+                @init("static") static s() {}
+                static field = order.push("field");
+                static self() { return C; }
+            }
+
+            new C();
+        "#}),
+        TestAction::assert_eq(
+            "order.join()",
+            js_str!("static:function,field,class:function,method:object"),
+        ),
+        TestAction::assert("C.replaced && C.self() === C"),
+    ]);
+}
+
+#[test]
+fn class_decorator_errors() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "(class { @(() => 1) m() {} })",
+            crate::JsNativeErrorKind::Type,
+            "decorator must return a function or undefined",
+        ),
+        TestAction::assert_native_error(
+            "(class { @(() => ({ get: 1 })) accessor x; })",
+            crate::JsNativeErrorKind::Type,
+            "accessor decorator `get` must be a function",
+        ),
+        TestAction::assert_native_error(
+            "(class { @((v, ctx) => { ctx.addInitializer(1); }) x; })",
+            crate::JsNativeErrorKind::Type,
+            "initializer must be a function",
+        ),
+    ]);
+}
@@ -59,6 +59,7 @@ impl_try_into_js_by_from![
     crate::object::JsUint8Array,
     crate::object::JsUint16Array,
     crate::object::JsUint32Array,
+    crate::object::JsFloat16Array,
     crate::object::JsFloat32Array,
     crate::object::JsFloat64Array
 ];
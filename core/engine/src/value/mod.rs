@@ -9,6 +9,7 @@ use std::{
     sync::LazyLock,
 };
 
+use float16::f16;
 use num_bigint::BigInt;
 use num_integer::Integer;
 use num_traits::{ToPrimitive, Zero};
@@ -949,6 +950,11 @@ impl JsValue {
         }
     }
 
+    /// Converts a value to a 16 bit floating point, rounding to the nearest representable value.
+    pub fn to_f16(&self, context: &mut Context) -> JsResult<f16> {
+        self.to_number(context).map(f16::from_f64)
+    }
+
     /// Converts a value to a 32 bit floating point.
     pub fn to_f32(&self, context: &mut Context) -> JsResult<f32> {
         self.to_number(context).map(|n| n as f32)
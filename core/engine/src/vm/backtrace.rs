@@ -0,0 +1,72 @@
+//! Backtraces of thrown errors.
+//!
+//! This module contains the [`StackFrame`] type, which describes a single frame of the
+//! call stack at the moment an error was created or thrown.
+
+use super::SourceLocation;
+use crate::{builtins::Error, Context, JsString};
+use std::fmt;
+
+/// A single frame of a backtrace, captured when an error was created or thrown.
+///
+/// Backtraces can be retrieved with [`JsError::backtrace`] or [`JsNativeError::backtrace`],
+/// and are also used to build the `stack` property of `Error` objects.
+///
+/// [`JsError::backtrace`]: crate::JsError::backtrace
+/// [`JsNativeError::backtrace`]: crate::JsNativeError::backtrace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackFrame {
+    function_name: Option<JsString>,
+    location: SourceLocation,
+}
+
+impl StackFrame {
+    /// Creates a new `StackFrame`.
+    #[must_use]
+    pub const fn new(function_name: Option<JsString>, location: SourceLocation) -> Self {
+        Self {
+            function_name,
+            location,
+        }
+    }
+
+    /// Gets the name of the function executed by this frame.
+    ///
+    /// Returns `None` for anonymous functions and top-level code.
+    #[inline]
+    #[must_use]
+    pub const fn function_name(&self) -> Option<&JsString> {
+        self.function_name.as_ref()
+    }
+
+    /// Gets the location in the source code of the instruction executed by this frame.
+    #[inline]
+    #[must_use]
+    pub const fn location(&self) -> &SourceLocation {
+        &self.location
+    }
+}
+
+impl fmt::Display for StackFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.function_name {
+            Some(name) => write!(f, "at {} ({})", name.to_std_string_escaped(), self.location),
+            None => write!(f, "at {}", self.location),
+        }
+    }
+}
+
+impl Context {
+    /// Captures the most recent frames of the call stack, up to the value of the non-standard
+    /// `Error.stackTraceLimit` property.
+    pub(crate) fn capture_backtrace(&self) -> Box<[StackFrame]> {
+        self.stack_trace()
+            .take(Error::stack_trace_limit(self))
+            .map(|frame| {
+                let name = frame.code_block().name();
+                let function_name = (!name.is_empty() && name != "<main>").then(|| name.clone());
+                StackFrame::new(function_name, frame.location())
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,720 @@
+//! Bytecode caches of compiled code.
+//!
+//! This module contains the binary format used by [`Script::to_cache`] and [`Module::to_cache`]
+//! to store compiled [`CodeBlock`]s, which can be loaded again without parsing or compiling their
+//! source code.
+//!
+//! Every cache starts with a header that identifies the version of the format, the version of
+//! the engine and the source text the code was compiled from, and a checksum of the rest of the
+//! cache, followed by the data written by the script or module. Integers are stored in little-endian order, and scopes and source texts
+//! are stored only once, with later uses referring to the first one.
+//!
+//! The same format is used by [`Context::snapshot`] to store the code of the functions of a
+//! snapshot.
+//!
+//! [`Script::to_cache`]: crate::Script::to_cache
+//! [`Module::to_cache`]: crate::Module::to_cache
+//! [`Context::snapshot`]: crate::Context::snapshot
+
+use boa_ast::{
+    scope::{Binding, BindingLocator, BindingLocatorScope, Scope},
+    LinearPosition, LinearSpan, Position,
+};
+use boa_gc::Gc;
+use rustc_hash::FxHashMap;
+use std::cell::Cell;
+use thin_vec::ThinVec;
+
+use crate::{
+    builtins::function::ThisMode, spanned_source_text::SourceText, JsBigInt, JsNativeError,
+    JsResult, JsString, SpannedSourceText,
+};
+
+use super::{
+    opcode::ByteCode, CodeBlock, CodeBlockFlags, Constant, Handler, InlineCache, SourceMap,
+    SourceMapEntry,
+};
+
+/// The bytes every bytecode cache starts with.
+const MAGIC: &[u8; 4] = b"BOAC";
+
+/// The version of the bytecode cache format, which must be incremented on any change to it.
+const FORMAT_VERSION: u32 = 2;
+
+/// The version of the engine, since the bytecode itself is not stable between releases.
+const ENGINE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The build configuration that changes the compiled bytecode.
+const BUILD_FLAGS: u8 =
+    (cfg!(feature = "annex-b") as u8) | ((cfg!(target_endian = "big") as u8) << 1);
+
+/// Tags of the scopes stored in a bytecode cache.
+const SCOPE_GLOBAL: u8 = 0;
+const SCOPE_NEW: u8 = 1;
+const SCOPE_REF: u8 = 2;
+
+/// Tags of the constants of a [`CodeBlock`].
+const CONSTANT_STRING: u8 = 0;
+const CONSTANT_FUNCTION: u8 = 1;
+const CONSTANT_BIGINT: u8 = 2;
+const CONSTANT_SCOPE: u8 = 3;
+
+/// Tags of the source texts of a [`CodeBlock`].
+const SOURCE_TEXT_NONE: u8 = 0;
+const SOURCE_TEXT_ONLY: u8 = 1;
+const SOURCE_TEXT_SPANNED: u8 = 2;
+
+/// Tags of the source texts stored in a bytecode cache.
+const SOURCE_NEW: u8 = 0;
+const SOURCE_REF: u8 = 1;
+
+/// The kind of code stored in a bytecode cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub(crate) enum CacheKind {
+    Script = 0,
+    Module = 1,
+    Snapshot = 2,
+}
+
+/// Computes the 64-bit FNV-1a hash of `bytes`, which doesn't depend on the platform.
+fn fnv_hash(bytes: impl IntoIterator<Item = u8>) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = OFFSET_BASIS;
+    for byte in bytes {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(PRIME);
+    }
+    hash
+}
+
+/// Computes the hash of a source text, used to check that a bytecode cache was compiled from the
+/// same source text it is loaded with.
+fn source_hash(source: &[u16]) -> u64 {
+    fnv_hash(source.iter().flat_map(|unit| unit.to_le_bytes()))
+}
+
+fn invalid_cache() -> JsNativeError {
+    JsNativeError::error().with_message("invalid bytecode cache")
+}
+
+/// Writes compiled code into a bytecode cache.
+#[derive(Debug)]
+pub(crate) struct CacheWriter {
+    bytes: Vec<u8>,
+    checksum_offset: usize,
+    scopes: FxHashMap<*const (), u64>,
+    sources: FxHashMap<*const (), u64>,
+}
+
+impl CacheWriter {
+    /// Creates a new `CacheWriter` for code of the given `kind`, compiled from `source`.
+    pub(crate) fn new(kind: CacheKind, source: &SourceText) -> Self {
+        let mut writer = Self {
+            bytes: Vec::new(),
+            checksum_offset: 0,
+            scopes: FxHashMap::default(),
+            sources: FxHashMap::default(),
+        };
+        writer.sources.insert(source.addr(), 0);
+
+        let code_points = source.code_points();
+        writer.bytes.extend_from_slice(MAGIC);
+        writer.write_u32(FORMAT_VERSION);
+        writer.write_bytes(ENGINE_VERSION.as_bytes());
+        writer.write_u8(BUILD_FLAGS);
+        writer.write_u8(kind as u8);
+        writer.write_usize(code_points.len());
+        writer.write_u64(source_hash(code_points));
+
+        // The checksum of the rest of the cache is filled in by `finish`.
+        writer.checksum_offset = writer.bytes.len();
+        writer.write_u64(0);
+        writer
+    }
+
+    /// Returns the bytes of the bytecode cache.
+    pub(crate) fn finish(mut self) -> Vec<u8> {
+        let payload = self.checksum_offset + size_of::<u64>();
+        let checksum = fnv_hash(self.bytes[payload..].iter().copied());
+        self.bytes[self.checksum_offset..payload].copy_from_slice(&checksum.to_le_bytes());
+        self.bytes
+    }
+
+    pub(crate) fn write_u8(&mut self, value: u8) {
+        self.bytes.push(value);
+    }
+
+    pub(crate) fn write_bool(&mut self, value: bool) {
+        self.write_u8(value.into());
+    }
+
+    pub(crate) fn write_u16(&mut self, value: u16) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub(crate) fn write_u32(&mut self, value: u32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub(crate) fn write_u64(&mut self, value: u64) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub(crate) fn write_usize(&mut self, value: usize) {
+        self.write_u64(value as u64);
+    }
+
+    pub(crate) fn write_bytes(&mut self, bytes: &[u8]) {
+        self.write_usize(bytes.len());
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    pub(crate) fn write_string(&mut self, string: &JsString) {
+        self.write_usize(string.len());
+        for unit in string {
+            self.bytes.extend_from_slice(&unit.to_le_bytes());
+        }
+    }
+
+    /// Writes a source text, or a reference to it if it was already written.
+    fn write_source_text(&mut self, source_text: &SourceText) {
+        if let Some(&index) = self.sources.get(&source_text.addr()) {
+            self.write_u8(SOURCE_REF);
+            self.write_u64(index);
+            return;
+        }
+
+        self.write_u8(SOURCE_NEW);
+        let code_points = source_text.code_points();
+        self.write_usize(code_points.len());
+        for unit in code_points {
+            self.bytes.extend_from_slice(&unit.to_le_bytes());
+        }
+
+        let index = self.sources.len() as u64;
+        self.sources.insert(source_text.addr(), index);
+    }
+
+    /// Writes a scope, and all of its outer scopes that were not written yet.
+    ///
+    /// The global scope is not written, since it is replaced by the global scope of the realm
+    /// the cache is loaded into.
+    pub(crate) fn write_scope(&mut self, scope: &Scope) {
+        if scope.is_global() {
+            self.write_u8(SCOPE_GLOBAL);
+            return;
+        }
+
+        if let Some(&index) = self.scopes.get(&scope.addr()) {
+            self.write_u8(SCOPE_REF);
+            self.write_u64(index);
+            return;
+        }
+
+        self.write_u8(SCOPE_NEW);
+        let outer = scope
+            .outer()
+            .expect("only the global scope has no outer scope");
+        self.write_scope(&outer);
+        self.write_u32(scope.unique_id());
+        self.write_u32(scope.scope_index());
+        self.write_bool(scope.is_function());
+        self.write_bool(scope.escaped_this());
+
+        let bindings = scope.bindings();
+        self.write_usize(bindings.len());
+        for binding in &bindings {
+            self.write_string(&binding.name);
+            self.write_u32(binding.index);
+            self.write_bool(binding.mutable);
+            self.write_bool(binding.lex);
+            self.write_bool(binding.strict);
+            self.write_bool(binding.escapes);
+            self.write_bool(binding.accessed);
+        }
+
+        let index = self.scopes.len() as u64;
+        self.scopes.insert(scope.addr(), index);
+    }
+
+    pub(crate) fn write_locator(&mut self, locator: &BindingLocator) {
+        self.write_string(locator.name());
+        match locator.scope() {
+            BindingLocatorScope::GlobalObject => self.write_u8(0),
+            BindingLocatorScope::GlobalDeclarative => self.write_u8(1),
+            BindingLocatorScope::Stack(index) => {
+                self.write_u8(2);
+                self.write_u32(index);
+            }
+        }
+        self.write_u32(locator.binding_index());
+        self.write_u32(locator.unique_scope_id());
+    }
+
+    /// Writes a [`CodeBlock`], including the code blocks of its nested functions.
+    pub(crate) fn write_code_block(&mut self, code: &CodeBlock) {
+        self.write_string(&code.name);
+        self.write_u16(code.flags.get().bits());
+        self.write_u32(code.length);
+        self.write_u32(code.parameter_length);
+        self.write_u32(code.register_count);
+        self.write_u8(match code.this_mode {
+            ThisMode::Lexical => 0,
+            ThisMode::Strict => 1,
+            ThisMode::Global => 2,
+        });
+
+        self.write_usize(code.mapped_arguments_binding_indices.len());
+        for index in &code.mapped_arguments_binding_indices {
+            match index {
+                Some(index) => {
+                    self.write_bool(true);
+                    self.write_u32(*index);
+                }
+                None => self.write_bool(false),
+            }
+        }
+
+        self.write_bytes(&code.bytecode.bytecode);
+
+        self.write_usize(code.constants.len());
+        for constant in &code.constants {
+            match constant {
+                Constant::String(string) => {
+                    self.write_u8(CONSTANT_STRING);
+                    self.write_string(string);
+                }
+                Constant::Function(function) => {
+                    self.write_u8(CONSTANT_FUNCTION);
+                    self.write_code_block(function);
+                }
+                Constant::BigInt(bigint) => {
+                    self.write_u8(CONSTANT_BIGINT);
+                    self.write_bytes(bigint.to_string_radix(16).as_bytes());
+                }
+                Constant::Scope(scope) => {
+                    self.write_u8(CONSTANT_SCOPE);
+                    self.write_scope(scope);
+                }
+            }
+        }
+
+        self.write_usize(code.bindings.len());
+        for locator in &code.bindings {
+            self.write_locator(locator);
+        }
+
+        self.write_usize(code.handlers.len());
+        for handler in &code.handlers {
+            self.write_u32(handler.start);
+            self.write_u32(handler.end);
+            self.write_u32(handler.environment_count);
+        }
+
+        self.write_usize(code.ic.len());
+        for ic in &code.ic {
+            self.write_string(&ic.name);
+        }
+
+        let source_text = &code.source_text_spanned;
+        if !source_text.has_source_text() {
+            self.write_u8(SOURCE_TEXT_NONE);
+        } else if let Some(span) = source_text.span() {
+            self.write_u8(SOURCE_TEXT_SPANNED);
+            self.write_source_text(&source_text.source_text());
+            self.write_usize(span.start().pos());
+            self.write_usize(span.end().pos());
+        } else {
+            self.write_u8(SOURCE_TEXT_ONLY);
+            self.write_source_text(&source_text.source_text());
+        }
+
+        let entries = code.source_map.entries();
+        self.write_usize(entries.len());
+        for entry in entries {
+            self.write_u32(entry.pc);
+            // Positions start at 1, so 0 marks an entry without a position.
+            let (line, column) = entry.position.map_or((0, 0), |position| {
+                (position.line_number(), position.column_number())
+            });
+            self.write_u32(line);
+            self.write_u32(column);
+        }
+    }
+}
+
+/// Reads compiled code from a bytecode cache.
+///
+/// The header of the cache and the checksum of the rest of the cache are checked when the reader
+/// is created, so caches that were truncated or corrupted are rejected with an error before any
+/// code is read from them.
+#[derive(Debug)]
+pub(crate) struct CacheReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    scopes: Vec<Scope>,
+    global_scope: Scope,
+    sources: Vec<SourceText>,
+}
+
+impl<'a> CacheReader<'a> {
+    /// Creates a new `CacheReader` over `bytes`, checking that they are a cache of code of the
+    /// given `kind` that was compiled from `source_text`.
+    ///
+    /// Scopes and bindings in the global scope are resolved against `global_scope`.
+    pub(crate) fn new(
+        bytes: &'a [u8],
+        kind: CacheKind,
+        source_text: SourceText,
+        global_scope: Scope,
+    ) -> JsResult<Self> {
+        let mut reader = Self {
+            bytes,
+            position: 0,
+            scopes: Vec::new(),
+            global_scope,
+            sources: vec![source_text],
+        };
+
+        if reader.read_slice(MAGIC.len())? != MAGIC {
+            return Err(invalid_cache().into());
+        }
+
+        if reader.read_u32()? != FORMAT_VERSION
+            || reader.read_bytes()? != ENGINE_VERSION.as_bytes()
+            || reader.read_u8()? != BUILD_FLAGS
+        {
+            return Err(JsNativeError::error()
+                .with_message("bytecode cache was created by an incompatible engine")
+                .into());
+        }
+
+        if reader.read_u8()? != kind as u8 {
+            return Err(JsNativeError::error()
+                .with_message(match kind {
+                    CacheKind::Script => "bytecode cache does not contain a script",
+                    CacheKind::Module => "bytecode cache does not contain a module",
+                    CacheKind::Snapshot => "bytecode cache does not contain a snapshot",
+                })
+                .into());
+        }
+
+        let source = reader.sources[0].code_points();
+        let (length, hash) = (source.len(), source_hash(source));
+        if reader.read_usize()? != length || reader.read_u64()? != hash {
+            return Err(JsNativeError::error()
+                .with_message("bytecode cache does not match the source text")
+                .into());
+        }
+
+        let checksum = reader.read_u64()?;
+        if fnv_hash(reader.bytes[reader.position..].iter().copied()) != checksum {
+            return Err(invalid_cache().into());
+        }
+
+        Ok(reader)
+    }
+
+    /// Checks that all the bytes of the cache were read.
+    pub(crate) fn finish(self) -> JsResult<()> {
+        if self.position != self.bytes.len() {
+            return Err(invalid_cache().into());
+        }
+        Ok(())
+    }
+
+    fn read_slice(&mut self, len: usize) -> JsResult<&'a [u8]> {
+        let end = self
+            .position
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(invalid_cache)?;
+        let slice = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(slice)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> JsResult<[u8; N]> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.read_slice(N)?);
+        Ok(array)
+    }
+
+    pub(crate) fn read_u8(&mut self) -> JsResult<u8> {
+        Ok(self.read_array::<1>()?[0])
+    }
+
+    pub(crate) fn read_bool(&mut self) -> JsResult<bool> {
+        match self.read_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(invalid_cache().into()),
+        }
+    }
+
+    pub(crate) fn read_u16(&mut self) -> JsResult<u16> {
+        self.read_array().map(u16::from_le_bytes)
+    }
+
+    pub(crate) fn read_u32(&mut self) -> JsResult<u32> {
+        self.read_array().map(u32::from_le_bytes)
+    }
+
+    pub(crate) fn read_u64(&mut self) -> JsResult<u64> {
+        self.read_array().map(u64::from_le_bytes)
+    }
+
+    pub(crate) fn read_usize(&mut self) -> JsResult<usize> {
+        usize::try_from(self.read_u64()?).map_err(|_| invalid_cache().into())
+    }
+
+    /// Reads the length of a list of elements that take at least `min_size` bytes each.
+    ///
+    /// This prevents huge allocations for the lists of invalid caches.
+    pub(crate) fn read_len(&mut self, min_size: usize) -> JsResult<usize> {
+        let len = self.read_usize()?;
+        if len.saturating_mul(min_size) > self.bytes.len() - self.position {
+            return Err(invalid_cache().into());
+        }
+        Ok(len)
+    }
+
+    pub(crate) fn read_bytes(&mut self) -> JsResult<&'a [u8]> {
+        let len = self.read_usize()?;
+        self.read_slice(len)
+    }
+
+    pub(crate) fn read_string(&mut self) -> JsResult<JsString> {
+        let len = self.read_len(2)?;
+        let units = self
+            .read_slice(len * 2)?
+            .chunks_exact(2)
+            .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+            .collect::<Vec<_>>();
+        Ok(JsString::from(&units[..]))
+    }
+
+    /// Reads a source text written by [`CacheWriter::write_source_text`].
+    fn read_source_text(&mut self) -> JsResult<SourceText> {
+        match self.read_u8()? {
+            SOURCE_REF => {
+                let index = self.read_usize()?;
+                self.sources
+                    .get(index)
+                    .cloned()
+                    .ok_or_else(|| invalid_cache().into())
+            }
+            SOURCE_NEW => {
+                let len = self.read_len(2)?;
+                let mut source_text = boa_ast::SourceText::with_capacity(len);
+                for unit in self.read_slice(len * 2)?.chunks_exact(2) {
+                    source_text.collect_code_point(u16::from_le_bytes([unit[0], unit[1]]).into());
+                }
+                let source_text = SourceText::new(source_text);
+                self.sources.push(source_text.clone());
+                Ok(source_text)
+            }
+            _ => Err(invalid_cache().into()),
+        }
+    }
+
+    /// Reads a scope written by [`CacheWriter::write_scope`].
+    pub(crate) fn read_scope(&mut self) -> JsResult<Scope> {
+        match self.read_u8()? {
+            SCOPE_GLOBAL => Ok(self.global_scope.clone()),
+            SCOPE_REF => {
+                let index = self.read_usize()?;
+                self.scopes
+                    .get(index)
+                    .cloned()
+                    .ok_or_else(|| invalid_cache().into())
+            }
+            SCOPE_NEW => {
+                let outer = self.read_scope()?;
+                let unique_id = self.read_u32()?;
+                let index = self.read_u32()?;
+                let function = self.read_bool()?;
+                let this_escaped = self.read_bool()?;
+
+                let len = self.read_len(12)?;
+                let mut bindings = Vec::with_capacity(len);
+                for _ in 0..len {
+                    bindings.push(Binding {
+                        name: self.read_string()?,
+                        index: self.read_u32()?,
+                        mutable: self.read_bool()?,
+                        lex: self.read_bool()?,
+                        strict: self.read_bool()?,
+                        escapes: self.read_bool()?,
+                        accessed: self.read_bool()?,
+                    });
+                }
+
+                let scope =
+                    Scope::from_parts(outer, unique_id, index, function, this_escaped, bindings);
+                self.scopes.push(scope.clone());
+                Ok(scope)
+            }
+            _ => Err(invalid_cache().into()),
+        }
+    }
+
+    /// Reads a binding locator written by [`CacheWriter::write_locator`].
+    ///
+    /// Bindings of the global scope are resolved by name, since their indices depend on the
+    /// declarations of the realm.
+    pub(crate) fn read_locator(&mut self) -> JsResult<BindingLocator> {
+        let name = self.read_string()?;
+        let scope = match self.read_u8()? {
+            0 => BindingLocatorScope::GlobalObject,
+            1 => BindingLocatorScope::GlobalDeclarative,
+            2 => BindingLocatorScope::Stack(self.read_u32()?),
+            _ => return Err(invalid_cache().into()),
+        };
+        let binding_index = self.read_u32()?;
+        let unique_scope_id = self.read_u32()?;
+
+        if let BindingLocatorScope::GlobalDeclarative = scope {
+            return self.global_scope.get_binding(&name).ok_or_else(|| {
+                JsNativeError::error()
+                    .with_message(format!(
+                        "bytecode cache refers to the undeclared global binding `{}`",
+                        name.to_std_string_escaped()
+                    ))
+                    .into()
+            });
+        }
+
+        Ok(BindingLocator::from_parts(
+            name,
+            scope,
+            binding_index,
+            unique_scope_id,
+        ))
+    }
+
+    /// Reads a [`CodeBlock`] written by [`CacheWriter::write_code_block`].
+    pub(crate) fn read_code_block(&mut self) -> JsResult<CodeBlock> {
+        let name = self.read_string()?;
+        let flags = CodeBlockFlags::from_bits_truncate(self.read_u16()?);
+        let length = self.read_u32()?;
+        let parameter_length = self.read_u32()?;
+        let register_count = self.read_u32()?;
+        let this_mode = match self.read_u8()? {
+            0 => ThisMode::Lexical,
+            1 => ThisMode::Strict,
+            2 => ThisMode::Global,
+            _ => return Err(invalid_cache().into()),
+        };
+
+        let len = self.read_len(1)?;
+        let mut mapped_arguments_binding_indices = ThinVec::with_capacity(len);
+        for _ in 0..len {
+            let index = if self.read_bool()? {
+                Some(self.read_u32()?)
+            } else {
+                None
+            };
+            mapped_arguments_binding_indices.push(index);
+        }
+
+        let bytecode = ByteCode {
+            bytecode: self.read_bytes()?.into(),
+        };
+
+        let len = self.read_len(1)?;
+        let mut constants = ThinVec::with_capacity(len);
+        for _ in 0..len {
+            let constant = match self.read_u8()? {
+                CONSTANT_STRING => Constant::String(self.read_string()?),
+                CONSTANT_FUNCTION => Constant::Function(Gc::new(self.read_code_block()?)),
+                CONSTANT_BIGINT => {
+                    let digits =
+                        std::str::from_utf8(self.read_bytes()?).map_err(|_| invalid_cache())?;
+                    Constant::BigInt(
+                        JsBigInt::from_string_radix(digits, 16).ok_or_else(invalid_cache)?,
+                    )
+                }
+                CONSTANT_SCOPE => Constant::Scope(self.read_scope()?),
+                _ => return Err(invalid_cache().into()),
+            };
+            constants.push(constant);
+        }
+
+        let len = self.read_len(17)?;
+        let mut bindings = Vec::with_capacity(len);
+        for _ in 0..len {
+            bindings.push(self.read_locator()?);
+        }
+
+        let len = self.read_len(12)?;
+        let mut handlers = ThinVec::with_capacity(len);
+        for _ in 0..len {
+            handlers.push(Handler {
+                start: self.read_u32()?,
+                end: self.read_u32()?,
+                environment_count: self.read_u32()?,
+            });
+        }
+
+        let len = self.read_len(8)?;
+        let mut ic = Vec::with_capacity(len);
+        for _ in 0..len {
+            ic.push(InlineCache::new(self.read_string()?));
+        }
+
+        let source_text_spanned = match self.read_u8()? {
+            SOURCE_TEXT_NONE => SpannedSourceText::new_empty(),
+            SOURCE_TEXT_ONLY => SpannedSourceText::new_source_only(self.read_source_text()?),
+            SOURCE_TEXT_SPANNED => {
+                let source_text = self.read_source_text()?;
+                let start = self.read_usize()?;
+                let end = self.read_usize()?;
+                if start > end || end > source_text.code_points().len() {
+                    return Err(invalid_cache().into());
+                }
+                let span = LinearSpan::new(LinearPosition::new(start), LinearPosition::new(end));
+                SpannedSourceText::new(source_text, Some(span))
+            }
+            _ => return Err(invalid_cache().into()),
+        };
+
+        let len = self.read_len(12)?;
+        let mut entries = Vec::with_capacity(len);
+        for _ in 0..len {
+            let pc = self.read_u32()?;
+            let position = match (self.read_u32()?, self.read_u32()?) {
+                (0, 0) => None,
+                (0, _) | (_, 0) => return Err(invalid_cache().into()),
+                (line, column) => Some(Position::new(line, column)),
+            };
+            if entries
+                .last()
+                .is_some_and(|last: &SourceMapEntry| last.pc >= pc)
+            {
+                return Err(invalid_cache().into());
+            }
+            entries.push(SourceMapEntry { pc, position });
+        }
+
+        Ok(CodeBlock {
+            name,
+            flags: Cell::new(flags),
+            length,
+            parameter_length,
+            register_count,
+            this_mode,
+            mapped_arguments_binding_indices,
+            bytecode,
+            constants,
+            bindings: bindings.into_boxed_slice(),
+            handlers,
+            ic: ic.into_boxed_slice(),
+            source_text_spanned,
+            source_map: SourceMap::new(entries.into_boxed_slice()),
+        })
+    }
+}
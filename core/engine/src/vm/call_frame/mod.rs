@@ -3,14 +3,18 @@
 //! This module will provides everything needed to implement the `CallFrame`
 
 use crate::{
-    builtins::iterable::IteratorRecord, environments::EnvironmentStack, realm::Realm,
-    vm::CodeBlock, JsValue,
+    builtins::{disposable_stack::DisposeCapability, iterable::IteratorRecord},
+    environments::EnvironmentStack,
+    realm::Realm,
+    vm::CodeBlock,
+    JsValue,
 };
-use boa_ast::scope::BindingLocator;
+use boa_ast::{scope::BindingLocator, Position};
 use boa_gc::{Finalize, Gc, Trace};
+use std::path::Path;
 use thin_vec::ThinVec;
 
-use super::ActiveRunnable;
+use super::{ActiveRunnable, SourceLocation};
 
 bitflags::bitflags! {
     /// Flags associated with a [`CallFrame`].
@@ -48,6 +52,9 @@ pub struct CallFrame {
     // Iterators and their `[[Done]]` flags that must be closed when an abrupt completion is thrown.
     pub(crate) iterators: ThinVec<IteratorRecord>,
 
+    // The dispose capabilities of the scopes containing `using` declarations that are being evaluated.
+    pub(crate) dispose_capabilities: ThinVec<DisposeCapability>,
+
     // The stack of bindings being updated.
     // SAFETY: Nothing in `BindingLocator` requires tracing, so this is safe.
     #[unsafe_ignore_trace]
@@ -78,6 +85,29 @@ impl CallFrame {
     pub const fn code_block(&self) -> &Gc<CodeBlock> {
         &self.code_block
     }
+
+    /// Gets the position in the source code of the instruction currently executed by this
+    /// call frame, if it is known.
+    #[must_use]
+    pub fn position(&self) -> Option<Position> {
+        // Note: -1 because the `pc` is incremented before executing an instruction.
+        self.code_block.find_position(self.pc.saturating_sub(1))
+    }
+
+    /// Gets the path of the script or module executed by this call frame, if any.
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        match self.active_runnable.as_ref()? {
+            ActiveRunnable::Script(script) => script.path(),
+            ActiveRunnable::Module(module) => module.path(),
+        }
+    }
+
+    /// Gets the [`SourceLocation`] of the instruction currently executed by this call frame.
+    #[must_use]
+    pub fn location(&self) -> SourceLocation {
+        SourceLocation::new(self.path().map(Path::to_path_buf), self.position())
+    }
 }
 
 /// ---- `CallFrame` creation methods ----
@@ -103,6 +133,7 @@ impl CallFrame {
             env_fp: 0,
             argument_count: 0,
             iterators: ThinVec::new(),
+            dispose_capabilities: ThinVec::new(),
             binding_stack: Vec::new(),
             code_block,
             loop_iteration_count: 0,
@@ -11,7 +11,10 @@ use crate::{
     Context, JsBigInt, JsString, JsValue, SpannedSourceText,
 };
 use bitflags::bitflags;
-use boa_ast::scope::{BindingLocator, Scope};
+use boa_ast::{
+    scope::{BindingLocator, Scope},
+    Position,
+};
 use boa_gc::{empty_trace, Finalize, Gc, Trace};
 use boa_profiler::Profiler;
 use std::{cell::Cell, fmt::Display, fmt::Write as _};
@@ -19,6 +22,7 @@ use thin_vec::ThinVec;
 
 use super::{
     opcode::{ByteCode, Instruction, InstructionIterator},
+    source_map::SourceMap,
     InlineCache,
 };
 
@@ -154,6 +158,10 @@ pub struct CodeBlock {
 
     /// source text of the code block
     pub(crate) source_text_spanned: SpannedSourceText,
+
+    /// Mapping from bytecode offsets to positions in the source code.
+    #[unsafe_ignore_trace]
+    pub(crate) source_map: SourceMap,
 }
 
 /// ---- `CodeBlock` public API ----
@@ -177,6 +185,7 @@ impl CodeBlock {
             handlers: ThinVec::default(),
             ic: Box::default(),
             source_text_spanned: SpannedSourceText::new_empty(),
+            source_map: SourceMap::default(),
         }
     }
 
@@ -277,6 +286,13 @@ impl CodeBlock {
             .find(|(_, handler)| handler.contains(pc))
     }
 
+    /// Find the position in the source code of the instruction containing the program
+    /// counter (`pc`), if it is known.
+    #[inline]
+    pub(crate) fn find_position(&self, pc: u32) -> Option<Position> {
+        self.source_map.find(pc)
+    }
+
     /// Get the [`JsString`] constant from the [`CodeBlock`].
     ///
     /// # Panics
@@ -713,11 +729,13 @@ impl CodeBlock {
             | Instruction::Pos { value }
             | Instruction::Neg { value }
             | Instruction::IsObject { value }
-            | Instruction::ImportCall { value }
             | Instruction::BindThisValue { value }
             | Instruction::BitNot { value } => {
                 format!("value:{value}")
             }
+            Instruction::ImportCall { value, options } => {
+                format!("value:{value}, options:{options}")
+            }
             Instruction::PushClassField {
                 object,
                 name_index,
@@ -783,6 +801,68 @@ impl CodeBlock {
             } => {
                 format!("configurable:{configurable}, name_index:{name_index}")
             }
+            Instruction::CreateClassDecorations { dst, class } => {
+                format!("dst:{dst}, class:{class}")
+            }
+            Instruction::CreateDecorationTarget {
+                dst,
+                decorations,
+                key,
+                flags,
+            } => {
+                format!("dst:{dst}, decorations:{decorations}, key:{key}, flags:{flags}")
+            }
+            Instruction::ApplyDecorator {
+                value,
+                target,
+                decorator,
+            } => {
+                format!("value:{value}, target:{target}, decorator:{decorator}")
+            }
+            Instruction::CreateAutoAccessor {
+                dst,
+                class,
+                target,
+                name_index,
+            } => {
+                format!("dst:{dst}, class:{class}, target:{target}, name_index:{name_index}")
+            }
+            Instruction::DefineDecoratedElement {
+                object,
+                target,
+                value,
+            } => {
+                format!("object:{object}, target:{target}, value:{value}")
+            }
+            Instruction::SetClassFieldInitializers { class, target } => {
+                format!("class:{class}, target:{target}")
+            }
+            Instruction::ApplyFieldInitializers {
+                value,
+                receiver,
+                target,
+            } => {
+                format!("value:{value}, receiver:{receiver}, target:{target}")
+            }
+            Instruction::PushClassInitializers { decorations } => {
+                format!("decorations:{decorations}")
+            }
+            Instruction::CallClassInitializers {
+                decorations,
+                receiver,
+                is_class,
+            } => {
+                format!("decorations:{decorations}, receiver:{receiver}, is_class:{is_class}")
+            }
+            Instruction::AddDisposableResource { value }
+            | Instruction::AddAsyncDisposableResource { value } => format!("value:{value}"),
+            Instruction::DisposeResources { value, done } => {
+                format!("value:{value}, done:{done}")
+            }
+            Instruction::RecordDisposeError { error } => format!("error:{error}"),
+            Instruction::PopDisposeScope { error, has_error } => {
+                format!("error:{error}, has_error:{has_error}")
+            }
             Instruction::PushPrivateEnvironment {
                 class,
                 name_indices,
@@ -827,6 +907,7 @@ impl CodeBlock {
             | Instruction::CallSpread
             | Instruction::NewSpread
             | Instruction::SuperCallSpread
+            | Instruction::PushDisposeScope
             | Instruction::PopPrivateEnvironment => String::new(),
             Instruction::Reserved1
             | Instruction::Reserved2
@@ -875,22 +956,7 @@ impl CodeBlock {
             | Instruction::Reserved45
             | Instruction::Reserved46
             | Instruction::Reserved47
-            | Instruction::Reserved48
-            | Instruction::Reserved49
-            | Instruction::Reserved50
-            | Instruction::Reserved51
-            | Instruction::Reserved52
-            | Instruction::Reserved53
-            | Instruction::Reserved54
-            | Instruction::Reserved55
-            | Instruction::Reserved56
-            | Instruction::Reserved57
-            | Instruction::Reserved58
-            | Instruction::Reserved59
-            | Instruction::Reserved60
-            | Instruction::Reserved61
-            | Instruction::Reserved62
-            | Instruction::Reserved63 => unreachable!("Reserved opcodes are unreachable"),
+            | Instruction::Reserved48 => unreachable!("Reserved opcodes are unreachable"),
         }
     }
 }
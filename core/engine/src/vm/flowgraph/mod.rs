@@ -442,7 +442,22 @@ impl CodeBlock {
                 | Instruction::CreateMappedArgumentsObject { .. }
                 | Instruction::CreateUnmappedArgumentsObject { .. }
                 | Instruction::CreateGlobalFunctionBinding { .. }
-                | Instruction::CreateGlobalVarBinding { .. } => {
+                | Instruction::CreateGlobalVarBinding { .. }
+                | Instruction::CreateClassDecorations { .. }
+                | Instruction::CreateDecorationTarget { .. }
+                | Instruction::ApplyDecorator { .. }
+                | Instruction::CreateAutoAccessor { .. }
+                | Instruction::DefineDecoratedElement { .. }
+                | Instruction::SetClassFieldInitializers { .. }
+                | Instruction::ApplyFieldInitializers { .. }
+                | Instruction::PushClassInitializers { .. }
+                | Instruction::CallClassInitializers { .. }
+                | Instruction::PushDisposeScope
+                | Instruction::AddDisposableResource { .. }
+                | Instruction::AddAsyncDisposableResource { .. }
+                | Instruction::DisposeResources { .. }
+                | Instruction::RecordDisposeError { .. }
+                | Instruction::PopDisposeScope { .. } => {
                     graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
                     graph.add_edge(previous_pc, pc, None, Color::None, EdgeStyle::Line);
                 }
@@ -496,22 +511,7 @@ impl CodeBlock {
                 | Instruction::Reserved45
                 | Instruction::Reserved46
                 | Instruction::Reserved47
-                | Instruction::Reserved48
-                | Instruction::Reserved49
-                | Instruction::Reserved50
-                | Instruction::Reserved51
-                | Instruction::Reserved52
-                | Instruction::Reserved53
-                | Instruction::Reserved54
-                | Instruction::Reserved55
-                | Instruction::Reserved56
-                | Instruction::Reserved57
-                | Instruction::Reserved58
-                | Instruction::Reserved59
-                | Instruction::Reserved60
-                | Instruction::Reserved61
-                | Instruction::Reserved62
-                | Instruction::Reserved63 => unreachable!("Reserved opcodes are unreachable"),
+                | Instruction::Reserved48 => unreachable!("Reserved opcodes are unreachable"),
             }
         }
 
@@ -0,0 +1,285 @@
+//! Execution budgets and interrupts of the virtual machine.
+
+use std::{
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+    thread::{self, Thread},
+};
+
+use crate::{Context, JsNativeError, JsResult};
+
+/// The reason an [`InterruptHandler`] was called.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterruptReason {
+    /// The fuel of the context was exhausted.
+    ///
+    /// See [`Context::set_fuel`].
+    FuelExhausted,
+
+    /// An interrupt was requested with [`InterruptHandle::request_interrupt`].
+    Requested,
+}
+
+/// The action the virtual machine takes after calling an [`InterruptHandler`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterruptAction {
+    /// Continue the execution.
+    ///
+    /// If the handler was called because the fuel of the context was exhausted, it must give it
+    /// more fuel with [`Context::set_fuel`], otherwise the execution throws an uncatchable
+    /// [`RuntimeLimit`][crate::JsNativeErrorKind::RuntimeLimit] error.
+    Continue,
+
+    /// Pause the execution until [`InterruptHandle::resume`] or [`InterruptHandle::terminate`] is
+    /// called from another thread.
+    ///
+    /// The thread of the context is blocked while the execution is paused, so another thread must
+    /// hold an [`InterruptHandle`] of the context to resume it. Otherwise, the execution is
+    /// terminated instead.
+    Pause,
+
+    /// Terminate the execution, as if [`InterruptHandle::terminate`] was called.
+    Terminate,
+}
+
+/// A host hook that is called by the virtual machine when the fuel of a [`Context`] is exhausted,
+/// or when an interrupt is requested with an [`InterruptHandle`].
+///
+/// The handler is called between two instructions, so it can safely use the context to inspect
+/// or change the state of the realm, or to give more fuel to the context.
+///
+/// This trait is implemented for all closures with the signature of
+/// [`InterruptHandler::interrupt`].
+pub trait InterruptHandler {
+    /// Handles an interrupt of the execution of `context`, returning the action the virtual
+    /// machine must take.
+    fn interrupt(&self, reason: InterruptReason, context: &mut Context) -> InterruptAction;
+}
+
+impl<F> InterruptHandler for F
+where
+    F: Fn(InterruptReason, &mut Context) -> InterruptAction,
+{
+    fn interrupt(&self, reason: InterruptReason, context: &mut Context) -> InterruptAction {
+        self(reason, context)
+    }
+}
+
+/// The execution of the context must be terminated.
+const TERMINATE: u8 = 1;
+/// The interrupt handler of the context must be called.
+const INTERRUPT: u8 = 1 << 1;
+/// The execution of the context must be paused.
+const PAUSE: u8 = 1 << 2;
+
+#[derive(Debug, Default)]
+struct InterruptState {
+    flags: AtomicU8,
+
+    /// The thread that is waiting for the execution to be resumed.
+    paused: Mutex<Option<Thread>>,
+}
+
+/// A thread-safe handle to request the termination, the pause or an interrupt of the execution
+/// of a [`Context`].
+///
+/// The requests are handled by the virtual machine before executing the next instruction, so they
+/// also stop code that never returns to the host, like infinite loops.
+///
+/// A handle is obtained with [`Context::interrupt_handle`], and can be cloned and sent to other
+/// threads.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{Context, Source};
+/// let mut context = Context::default();
+/// let handle = context.interrupt_handle();
+///
+/// let watchdog = std::thread::spawn(move || {
+///     std::thread::sleep(std::time::Duration::from_millis(50));
+///     handle.terminate();
+/// });
+///
+/// let result = context.eval(Source::from_bytes("while (true) {}"));
+/// assert!(result.unwrap_err().as_native().unwrap().is_terminated());
+/// watchdog.join().unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct InterruptHandle {
+    state: Arc<InterruptState>,
+}
+
+impl InterruptHandle {
+    /// Requests the termination of the execution, which throws an uncatchable
+    /// [`Terminated`][crate::JsNativeErrorKind::Terminated] error.
+    ///
+    /// The termination is sticky: all the code executed afterwards is terminated too, until
+    /// [`InterruptHandle::cancel_termination`] is called.
+    pub fn terminate(&self) {
+        self.state.flags.fetch_or(TERMINATE, Ordering::SeqCst);
+        self.unpark();
+    }
+
+    /// Cancels a termination requested with [`InterruptHandle::terminate`], so the context can
+    /// execute code again.
+    pub fn cancel_termination(&self) {
+        self.state.flags.fetch_and(!TERMINATE, Ordering::SeqCst);
+    }
+
+    /// Checks if the termination of the execution was requested.
+    #[must_use]
+    pub fn is_terminating(&self) -> bool {
+        self.state.flags.load(Ordering::SeqCst) & TERMINATE != 0
+    }
+
+    /// Requests a call to the [`InterruptHandler`] of the context, which decides if the execution
+    /// continues, pauses or terminates.
+    ///
+    /// The request is ignored if the context doesn't have an interrupt handler.
+    pub fn request_interrupt(&self) {
+        self.state.flags.fetch_or(INTERRUPT, Ordering::SeqCst);
+    }
+
+    /// Pauses the execution, blocking the thread of the context until
+    /// [`InterruptHandle::resume`] or [`InterruptHandle::terminate`] is called.
+    ///
+    /// Since the thread of the context is blocked, the execution can only be resumed from another
+    /// thread. Pausing a context on the thread that runs it, without sending a handle to another
+    /// thread first, blocks that thread forever. If no other handle is left when the execution
+    /// is paused, the execution is terminated instead of blocking.
+    pub fn pause(&self) {
+        self.state.flags.fetch_or(PAUSE, Ordering::SeqCst);
+    }
+
+    /// Resumes an execution paused by [`InterruptHandle::pause`] or by an [`InterruptHandler`].
+    pub fn resume(&self) {
+        self.state.flags.fetch_and(!PAUSE, Ordering::SeqCst);
+        self.unpark();
+    }
+
+    /// Checks if the execution is paused, or will be paused before the next instruction.
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.state.flags.load(Ordering::SeqCst) & PAUSE != 0
+    }
+
+    /// Checks if any request is pending.
+    #[inline]
+    pub(crate) fn is_pending(&self) -> bool {
+        self.state.flags.load(Ordering::Relaxed) != 0
+    }
+
+    /// Takes the pending interrupt request, if any.
+    fn take_interrupt_request(&self) -> bool {
+        self.state.flags.fetch_and(!INTERRUPT, Ordering::SeqCst) & INTERRUPT != 0
+    }
+
+    /// Blocks the current thread while the execution is paused and not terminated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an uncatchable `Terminated` error if the execution is paused and the handle of the
+    /// context is the only one left, since nothing could resume it.
+    fn wait_while_paused(&self) -> JsResult<()> {
+        // The thread must be registered before checking the flags, so a concurrent call to
+        // `resume` either clears the flag before the check or unparks the thread.
+        *self
+            .state
+            .paused
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(thread::current());
+
+        let mut result = Ok(());
+        while self.state.flags.load(Ordering::SeqCst) & (PAUSE | TERMINATE) == PAUSE {
+            if Arc::strong_count(&self.state) == 1 {
+                // The pause is cleared, so the context can still be used after the error.
+                self.state.flags.fetch_and(!PAUSE, Ordering::SeqCst);
+                result = Err(JsNativeError::terminated()
+                    .with_message(
+                        "cannot pause the execution, since there is no other handle to resume it",
+                    )
+                    .into());
+                break;
+            }
+            thread::park();
+        }
+
+        *self
+            .state
+            .paused
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = None;
+        result
+    }
+
+    fn unpark(&self) {
+        if let Some(thread) = &*self
+            .state
+            .paused
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+        {
+            thread.unpark();
+        }
+    }
+}
+
+impl Context {
+    /// Consumes one unit of fuel and handles the pending interrupts, before executing an
+    /// instruction.
+    #[inline]
+    pub(crate) fn check_interrupts(&mut self) -> JsResult<()> {
+        match &mut self.vm.fuel {
+            Some(0) => {}
+            Some(fuel) if !self.vm.interrupt.is_pending() => {
+                *fuel -= 1;
+                return Ok(());
+            }
+            None if !self.vm.interrupt.is_pending() => return Ok(()),
+            _ => {}
+        }
+        self.handle_interrupts()
+    }
+
+    #[cold]
+    fn handle_interrupts(&mut self) -> JsResult<()> {
+        if self.vm.fuel == Some(0) {
+            self.call_interrupt_handler(InterruptReason::FuelExhausted);
+        }
+        if self.vm.interrupt.take_interrupt_request() {
+            self.call_interrupt_handler(InterruptReason::Requested);
+        }
+
+        self.vm.interrupt.wait_while_paused()?;
+        if self.vm.interrupt.is_terminating() {
+            return Err(JsNativeError::terminated()
+                .with_message("execution was terminated")
+                .into());
+        }
+
+        match &mut self.vm.fuel {
+            Some(0) => Err(JsNativeError::runtime_limit()
+                .with_message("exceeded the execution budget")
+                .into()),
+            Some(fuel) => {
+                *fuel -= 1;
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn call_interrupt_handler(&mut self, reason: InterruptReason) {
+        let Some(handler) = self.interrupt_handler.clone() else {
+            return;
+        };
+        match handler.interrupt(reason, self) {
+            InterruptAction::Continue => {}
+            InterruptAction::Pause => self.vm.interrupt.pause(),
+            InterruptAction::Terminate => self.vm.interrupt.terminate(),
+        }
+    }
+}
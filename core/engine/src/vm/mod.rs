@@ -26,6 +26,7 @@ use std::fmt::Write as _;
 pub(crate) use opcode::{Instruction, InstructionIterator, Opcode};
 
 pub(crate) use {
+    cache::{CacheKind, CacheReader, CacheWriter},
     call_frame::CallFrameFlags,
     code_block::{
         create_function_object, create_function_object_fast, CodeBlockFlags, Constant, Handler,
@@ -34,17 +35,26 @@ pub(crate) use {
     inline_cache::InlineCache,
 };
 
+pub(crate) use source_map::{SourceMap, SourceMapEntry};
+
+pub use interrupt::{InterruptAction, InterruptHandle, InterruptHandler, InterruptReason};
 pub use runtime_limits::RuntimeLimits;
 pub use {
+    backtrace::StackFrame,
     call_frame::{CallFrame, GeneratorResumeKind},
     code_block::CodeBlock,
+    source_map::SourceLocation,
 };
 
+mod backtrace;
+mod cache;
 mod call_frame;
 mod code_block;
 mod completion_record;
 mod inline_cache;
+mod interrupt;
 mod runtime_limits;
+mod source_map;
 
 pub(crate) mod opcode;
 
@@ -84,6 +94,13 @@ pub struct Vm {
     pub(crate) environments: EnvironmentStack,
     pub(crate) runtime_limits: RuntimeLimits,
 
+    /// The number of instructions that can be executed before the interrupt handler is called,
+    /// or [`None`] if the execution is not limited.
+    pub(crate) fuel: Option<u64>,
+
+    /// The requests to terminate, pause or interrupt the execution.
+    pub(crate) interrupt: InterruptHandle,
+
     /// This is used to assign a native (rust) function as the active function,
     /// because we don't push a frame for them.
     pub(crate) native_active_function: Option<JsObject>,
@@ -425,6 +442,8 @@ impl Vm {
             environments: EnvironmentStack::new(realm.environment().clone()),
             pending_exception: None,
             runtime_limits: RuntimeLimits::default(),
+            fuel: None,
+            interrupt: InterruptHandle::default(),
             native_active_function: None,
             realm,
             #[cfg(feature = "trace")]
@@ -657,6 +676,13 @@ impl Context {
             self.instructions_remaining -= 1;
         }
 
+        if let Err(err) = self
+            .check_interrupts()
+            .and_then(|()| self.check_heap_size())
+        {
+            return self.handle_error(err);
+        }
+
         #[cfg(feature = "trace")]
         if self.vm.trace || self.vm.frame().code_block.traceable() {
             self.trace_execute_instruction(f, opcode)
@@ -693,6 +719,15 @@ impl Context {
             return ControlFlow::Break(CompletionRecord::Throw(err));
         }
 
+        // A native error gets a `stack` property when it is converted into an error object, so its
+        // backtrace must be captured where it is thrown. Other errors only capture it once they
+        // leave the frame, in `handle_thow`.
+        let err = if err.as_native().is_some() && err.backtrace().is_none() {
+            err.with_backtrace(self.capture_backtrace())
+        } else {
+            err
+        };
+
         // Note: -1 because we increment after fetching the opcode.
         let pc = self.vm.frame().pc.saturating_sub(1);
         if self.vm.handle_exception_at(pc) {
@@ -733,6 +768,17 @@ impl Context {
     }
 
     fn handle_thow(&mut self) -> ControlFlow<CompletionRecord> {
+        // The backtrace is only captured once the error leaves the frame that threw it, so errors
+        // caught in the same frame don't pay for it.
+        if let Some(err) = self.vm.pending_exception.take() {
+            let err = if err.is_catchable() && err.backtrace().is_none() {
+                err.with_backtrace(self.capture_backtrace())
+            } else {
+                err
+            };
+            self.vm.pending_exception = Some(err);
+        }
+
         let mut env_fp = self.vm.frame().env_fp;
         if self.vm.frame().exit_early() {
             self.vm.environments.truncate(env_fp as usize);
@@ -861,6 +907,58 @@ impl Context {
 
         Ok(())
     }
+
+    /// Checks if allocating `bytes` bytes would exceed the heap size limit.
+    ///
+    /// The heap size includes the values allocated by the garbage collector and the strings,
+    /// which are reference counted outside of it. Before throwing, this collects the unreachable
+    /// values of the heap, which also frees the strings they own, and calls
+    /// [`HostHooks::near_heap_limit`], which can raise the limit.
+    ///
+    /// [`HostHooks::near_heap_limit`]: crate::context::HostHooks::near_heap_limit
+    pub(crate) fn check_heap_limit(&mut self, bytes: usize) -> JsResult<()> {
+        fn heap_size() -> usize {
+            boa_gc::bytes_allocated().saturating_add(boa_string::bytes_allocated())
+        }
+
+        let limit = self.vm.runtime_limits.heap_size_limit();
+        if limit == usize::MAX || heap_size().saturating_add(bytes) <= limit {
+            return Ok(());
+        }
+
+        boa_gc::force_collect();
+        let used = heap_size().saturating_add(bytes);
+        if used <= limit {
+            return Ok(());
+        }
+
+        let limit = self.host_hooks().near_heap_limit(used, limit, self);
+        self.vm.runtime_limits.set_heap_size_limit(limit);
+        if used <= limit {
+            return Ok(());
+        }
+
+        Err(JsNativeError::range()
+            .with_message("out of memory: exceeded the heap size limit")
+            .into())
+    }
+
+    /// Checks if the values allocated by the previous instructions exceeded the heap size limit.
+    ///
+    /// Objects, environments and the other values of the garbage collector are not checked when
+    /// they are allocated, so the heap size is checked before executing every instruction.
+    #[inline]
+    fn check_heap_size(&mut self) -> JsResult<()> {
+        if self.vm.runtime_limits.heap_size_limit() == usize::MAX {
+            return Ok(());
+        }
+        self.check_heap_limit(0)
+    }
+
+    /// Checks if allocating a string of `len` code units would exceed the heap size limit.
+    pub(crate) fn check_string_allocation(&mut self, len: usize) -> JsResult<()> {
+        self.check_heap_limit(len.saturating_mul(size_of::<u16>()))
+    }
 }
 
 /// Yields once to the executor.
@@ -1,11 +1,15 @@
 use super::VaryingOperand;
 use crate::{
-    builtins::{promise::PromiseCapability, Promise},
+    builtins::promise::PromiseCapability,
     error::JsNativeError,
-    module::{ModuleKind, Referrer},
-    object::FunctionObjectBuilder,
+    js_string,
+    module::{
+        load_dynamic_import, unsupported_import_attribute, ImportAttribute, ModuleRequest, Referrer,
+    },
+    object::internal_methods::InternalMethodContext,
+    property::PropertyKey,
     vm::opcode::Operation,
-    Context, JsObject, JsResult, JsValue, NativeFunction,
+    Context, JsObject, JsResult, JsValue,
 };
 
 /// `CallEval` implements the Opcode Operation for `Opcode::CallEval`
@@ -255,7 +259,10 @@ pub(crate) struct ImportCall;
 
 impl ImportCall {
     #[inline(always)]
-    pub(super) fn operation(value: VaryingOperand, context: &mut Context) -> JsResult<()> {
+    pub(super) fn operation(
+        (value, options): (VaryingOperand, VaryingOperand),
+        context: &mut Context,
+    ) -> JsResult<()> {
         // Import Calls
         // Runtime Semantics: Evaluation
         // https://tc39.es/ecma262/#sec-import-call-runtime-semantics-evaluation
@@ -266,11 +273,18 @@ impl ImportCall {
             .get_active_script_or_module()
             .map_or_else(|| Referrer::Realm(context.realm().clone()), Into::into);
 
-        // 3. Let argRef be ? Evaluation of AssignmentExpression.
-        // 4. Let specifier be ? GetValue(argRef).
+        // 3. Let specifierRef be ? Evaluation of specifierExpression.
+        // 4. Let specifier be ? GetValue(specifierRef).
         let arg = context.vm.get_register(value.into()).clone();
 
-        // 5. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        // 5. If optionsExpression is present, then
+        //     a. Let optionsRef be ? Evaluation of optionsExpression.
+        //     b. Let options be ? GetValue(optionsRef).
+        // 6. Else,
+        //     a. Let options be undefined.
+        let options = context.vm.get_register(options.into()).clone();
+
+        // 7. Let promiseCapability be ! NewPromiseCapability(%Promise%).
         let cap = PromiseCapability::new(
             &context.intrinsics().constructors().promise().constructor(),
             context,
@@ -278,183 +292,125 @@ impl ImportCall {
         .expect("operation cannot fail for the %Promise% intrinsic");
         let promise = cap.promise().clone();
 
-        // 6. Let specifierString be Completion(ToString(specifier)).
-        match arg.to_string(context) {
-            // 7. IfAbruptRejectPromise(specifierString, promiseCapability).
+        let request = (|| -> JsResult<ModuleRequest> {
+            // 8. Let specifierString be Completion(ToString(specifier)).
+            // 9. IfAbruptRejectPromise(specifierString, promiseCapability).
+            let specifier = arg.to_string(context)?;
+
+            // 10. Let attributes be a new empty List.
+            // 11. If options is not undefined, then
+            let attributes = Self::import_attributes(&options, context)?;
+
+            // 12. Let moduleRequest be a new ModuleRequest Record { [[Specifier]]: specifierString, [[Attributes]]: attributes }.
+            Ok(ModuleRequest::new(specifier, attributes))
+        })();
+
+        match request {
             Err(err) => {
                 let err = err.to_opaque(context);
                 cap.reject().call(&JsValue::undefined(), &[err], context)?;
             }
-            // 8. Perform HostLoadImportedModule(referrer, specifierString, empty, promiseCapability).
-            Ok(specifier) => context.module_loader().load_imported_module(
-                referrer.clone(),
-                specifier.clone(),
-                Box::new(move |completion, context| {
-                    // `ContinueDynamicImport ( promiseCapability, moduleCompletion )`
-                    // https://tc39.es/ecma262/#sec-ContinueDynamicImport
-
-                    // `FinishLoadingImportedModule ( referrer, specifier, payload, result )`
-                    // https://tc39.es/ecma262/#sec-FinishLoadingImportedModule
-                    let module = match completion {
-                        // 1. If result is a normal completion, then
-                        Ok(m) => {
-                            match referrer {
-                                Referrer::Module(module) => {
-                                    let ModuleKind::SourceText(src) = module.kind() else {
-                                        panic!("referrer cannot be a synthetic module");
-                                    };
-
-                                    let mut loaded_modules = src.loaded_modules().borrow_mut();
-
-                                    //     a. If referrer.[[LoadedModules]] contains a Record whose [[Specifier]] is specifier, then
-                                    //     b. Else,
-                                    //         i. Append the Record { [[Specifier]]: specifier, [[Module]]: result.[[Value]] } to referrer.[[LoadedModules]].
-                                    let entry = loaded_modules
-                                        .entry(specifier)
-                                        .or_insert_with(|| m.clone());
-
-                                    //         i. Assert: That Record's [[Module]] is result.[[Value]].
-                                    debug_assert_eq!(&m, entry);
-
-                                    // Same steps apply to referrers below
-                                }
-                                Referrer::Realm(realm) => {
-                                    let mut loaded_modules = realm.loaded_modules().borrow_mut();
-                                    let entry = loaded_modules
-                                        .entry(specifier)
-                                        .or_insert_with(|| m.clone());
-                                    debug_assert_eq!(&m, entry);
-                                }
-                                Referrer::Script(script) => {
-                                    let mut loaded_modules = script.loaded_modules().borrow_mut();
-                                    let entry = loaded_modules
-                                        .entry(specifier)
-                                        .or_insert_with(|| m.clone());
-                                    debug_assert_eq!(&m, entry);
-                                }
-                            }
-
-                            m
-                        }
-                        // 1. If moduleCompletion is an abrupt completion, then
-                        Err(err) => {
-                            // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « moduleCompletion.[[Value]] »).
-                            let err = err.to_opaque(context);
-                            cap.reject()
-                                .call(&JsValue::undefined(), &[err], context)
-                                .expect("default `reject` function cannot throw");
-
-                            // b. Return unused.
-                            return;
-                        }
-                    };
-
-                    // 2. Let module be moduleCompletion.[[Value]].
-                    // 3. Let loadPromise be module.LoadRequestedModules().
-                    let load = module.load(context);
-
-                    // 4. Let rejectedClosure be a new Abstract Closure with parameters (reason) that captures promiseCapability and performs the following steps when called:
-                    // 5. Let onRejected be CreateBuiltinFunction(rejectedClosure, 1, "", « »).
-                    let on_rejected = FunctionObjectBuilder::new(
-                        context.realm(),
-                        NativeFunction::from_copy_closure_with_captures(
-                            |_, args, cap, context| {
-                                //     a. Perform ! Call(promiseCapability.[[Reject]], undefined, « reason »).
-                                cap.reject()
-                                    .call(&JsValue::undefined(), args, context)
-                                    .expect("default `reject` function cannot throw");
-
-                                //     b. Return unused.
-                                Ok(JsValue::undefined())
-                            },
-                            cap.clone(),
-                        ),
-                    )
-                    .build();
-
-                    // 6. Let linkAndEvaluateClosure be a new Abstract Closure with no parameters that captures module, promiseCapability, and onRejected and performs the following steps when called:
-                    // 7. Let linkAndEvaluate be CreateBuiltinFunction(linkAndEvaluateClosure, 0, "", « »).
-                    let link_evaluate = FunctionObjectBuilder::new(
-                        context.realm(),
-                        NativeFunction::from_copy_closure_with_captures(
-                            |_, _, (module, cap, on_rejected), context| {
-                                // a. Let link be Completion(module.Link()).
-                                // b. If link is an abrupt completion, then
-                                if let Err(e) = module.link(context) {
-                                    // i. Perform ! Call(promiseCapability.[[Reject]], undefined, « link.[[Value]] »).
-                                    let e = e.to_opaque(context);
-                                    cap.reject()
-                                        .call(&JsValue::undefined(), &[e], context)
-                                        .expect("default `reject` function cannot throw");
-                                    // ii. Return unused.
-                                    return Ok(JsValue::undefined());
-                                }
-
-                                // c. Let evaluatePromise be module.Evaluate().
-                                let evaluate = module.evaluate(context);
-
-                                // d. Let fulfilledClosure be a new Abstract Closure with no parameters that captures module and promiseCapability and performs the following steps when called:
-                                // e. Let onFulfilled be CreateBuiltinFunction(fulfilledClosure, 0, "", « »).
-                                let fulfill = FunctionObjectBuilder::new(
-                                    context.realm(),
-                                    NativeFunction::from_copy_closure_with_captures(
-                                        |_, _, (module, cap), context| {
-                                            // i. Let namespace be GetModuleNamespace(module).
-                                            let namespace = module.namespace(context);
-
-                                            // ii. Perform ! Call(promiseCapability.[[Resolve]], undefined, « namespace »).
-                                            cap.resolve()
-                                                .call(
-                                                    &JsValue::undefined(),
-                                                    &[namespace.into()],
-                                                    context,
-                                                )
-                                                .expect("default `resolve` function cannot throw");
-
-                                            // iii. Return unused.
-                                            Ok(JsValue::undefined())
-                                        },
-                                        (module.clone(), cap.clone()),
-                                    ),
-                                )
-                                .build();
-
-                                // f. Perform PerformPromiseThen(evaluatePromise, onFulfilled, onRejected).
-                                Promise::perform_promise_then(
-                                    &evaluate,
-                                    Some(fulfill),
-                                    Some(on_rejected.clone()),
-                                    None,
-                                    context,
-                                );
-
-                                // g. Return unused.
-                                Ok(JsValue::undefined())
-                            },
-                            (module.clone(), cap.clone(), on_rejected.clone()),
-                        ),
-                    )
-                    .build();
-
-                    // 8. Perform PerformPromiseThen(loadPromise, linkAndEvaluate, onRejected).
-                    Promise::perform_promise_then(
-                        &load,
-                        Some(link_evaluate),
-                        Some(on_rejected),
-                        None,
-                        context,
-                    );
-
-                    // 9. Return unused.
-                }),
-                context,
-            ),
+            // 13. Perform HostLoadImportedModule(referrer, moduleRequest, empty, promiseCapability).
+            Ok(request) => load_dynamic_import(referrer, request, cap, context),
         }
 
-        // 9. Return promiseCapability.[[Promise]].
+        // 14. Return promiseCapability.[[Promise]].
         context.vm.set_register(value.into(), promise.into());
         Ok(())
     }
+
+    /// Gets the import attributes from the `with` property of the options of an import call.
+    fn import_attributes(
+        options: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Box<[ImportAttribute]>> {
+        if options.is_undefined() {
+            return Ok(Box::default());
+        }
+
+        // a. If options is not an Object, then
+        //     i. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+        //     ii. Return promiseCapability.[[Promise]].
+        let Some(options) = options.as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message("the options of an import call must be an object")
+                .into());
+        };
+
+        // b. Let attributesObj be Completion(Get(options, "with")).
+        // c. IfAbruptRejectPromise(attributesObj, promiseCapability).
+        let attributes_obj = options.get(js_string!("with"), context)?;
+
+        // d. If attributesObj is not undefined, then
+        if attributes_obj.is_undefined() {
+            return Ok(Box::default());
+        }
+
+        //     i. If attributesObj is not an Object, then
+        //         1. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+        //         2. Return promiseCapability.[[Promise]].
+        let Some(attributes_obj) = attributes_obj.as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message("the `with` option of an import call must be an object")
+                .into());
+        };
+
+        //     ii. Let entries be Completion(EnumerableOwnProperties(attributesObj, key+value)).
+        //     iii. IfAbruptRejectPromise(entries, promiseCapability).
+        //     iv. For each element entry of entries, do
+        let mut attributes = Vec::new();
+        let keys =
+            attributes_obj.__own_property_keys__(&mut InternalMethodContext::new(context))?;
+        for key in keys {
+            let key_str = match &key {
+                PropertyKey::String(s) => s.clone(),
+                PropertyKey::Index(i) => i.get().to_string().into(),
+                PropertyKey::Symbol(_) => continue,
+            };
+            let desc = attributes_obj
+                .__get_own_property__(&key, &mut InternalMethodContext::new(context))?;
+            if !desc.is_some_and(|desc| desc.expect_enumerable()) {
+                continue;
+            }
+
+            // 1. Let key be ! Get(entry, "0").
+            // 2. Let value be ! Get(entry, "1").
+            let value = attributes_obj.get(key, context)?;
+
+            // 3. If key is a String, then
+            //     a. If value is not a String, then
+            //         i. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+            //         ii. Return promiseCapability.[[Promise]].
+            let Some(value) = value.as_string() else {
+                return Err(JsNativeError::typ()
+                    .with_message(format!(
+                        "the value of the import attribute `{}` must be a string",
+                        key_str.to_std_string_escaped()
+                    ))
+                    .into());
+            };
+
+            //     b. Append the ImportAttribute Record { [[Key]]: key, [[Value]]: value } to attributes.
+            attributes.push(ImportAttribute::new(key_str, value.clone()));
+        }
+
+        // e. If AllImportAttributesSupported(attributes) is false, then
+        //     i. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+        //     ii. Return promiseCapability.[[Promise]].
+        if let Some(key) = unsupported_import_attribute(&attributes) {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "unsupported import attribute `{}`",
+                    key.to_std_string_escaped()
+                ))
+                .into());
+        }
+
+        // f. Sort attributes according to the lexicographic order of their [[Key]] field, treating
+        //    the value of each such field as a sequence of UTF-16 code unit values.
+        //    NOTE: This is done by `ModuleRequest::new`.
+        Ok(attributes.into_boxed_slice())
+    }
 }
 
 impl Operation for ImportCall {
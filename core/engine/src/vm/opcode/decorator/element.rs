@@ -0,0 +1,234 @@
+use super::{DecorationKind, DecorationName, DecorationTarget};
+use crate::{
+    builtins::function::{set_function_name, OrdinaryFunction},
+    js_str, js_string,
+    native_function::NativeFunction,
+    object::{
+        internal_methods::InternalMethodContext, FunctionObjectBuilder, JsObject,
+        ObjectInitializer, PrivateElement,
+    },
+    property::{Attribute, PropertyDescriptor, PropertyKey},
+    vm::opcode::{Operation, VaryingOperand},
+    Context, JsArgs, JsNativeError, JsResult, JsValue,
+};
+
+/// `CreateAutoAccessor` implements the Opcode Operation for `Opcode::CreateAutoAccessor`
+///
+/// Operation:
+///  - Create the getter and setter of an `accessor` class field, which read and write the
+///    private storage field of the accessor.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CreateAutoAccessor;
+
+impl CreateAutoAccessor {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (dst, class, target, index): (
+            VaryingOperand,
+            VaryingOperand,
+            VaryingOperand,
+            VaryingOperand,
+        ),
+        context: &mut Context,
+    ) {
+        let class = context.vm.get_register(class.into()).clone();
+        let target = context.vm.get_register(target.into()).clone();
+        let description = context
+            .vm
+            .frame()
+            .code_block()
+            .constant_string(index.into());
+
+        let class = class.as_object().expect("class must be object");
+        let storage = class.private_name(description);
+
+        let name = match &target
+            .as_object()
+            .and_then(JsObject::downcast_ref::<DecorationTarget>)
+            .expect("decoration target must be a `DecorationTarget` object")
+            .name
+        {
+            DecorationName::Public(key) => key.clone(),
+            DecorationName::Private(name) => js_string!(js_str!("#"), name.description()).into(),
+            DecorationName::Class(_) => unreachable!(),
+        };
+
+        let getter = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |this, _, storage, context| {
+                    let object = this.as_object().ok_or_else(|| {
+                        JsNativeError::typ()
+                            .with_message("auto-accessor getter called on a non-object")
+                    })?;
+                    object.private_get(storage, context)
+                },
+                storage.clone(),
+            ),
+        )
+        .length(0)
+        .build();
+        set_function_name(&getter, &name, Some(js_str!("get")), context);
+
+        let setter = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |this, args, storage, context| {
+                    let object = this.as_object().ok_or_else(|| {
+                        JsNativeError::typ()
+                            .with_message("auto-accessor setter called on a non-object")
+                    })?;
+                    object.private_set(storage, args.get_or_undefined(0).clone(), context)?;
+                    Ok(JsValue::undefined())
+                },
+                storage,
+            ),
+        )
+        .length(1)
+        .build();
+        set_function_name(&setter, &name, Some(js_str!("set")), context);
+
+        let accessor = ObjectInitializer::new(context)
+            .property(js_string!("get"), getter, Attribute::all())
+            .property(js_string!("set"), setter, Attribute::all())
+            .build();
+        context.vm.set_register(dst.into(), accessor.into());
+    }
+}
+
+impl Operation for CreateAutoAccessor {
+    const NAME: &'static str = "CreateAutoAccessor";
+    const INSTRUCTION: &'static str = "INST - CreateAutoAccessor";
+    const COST: u8 = 6;
+}
+
+/// `DefineDecoratedElement` implements the Opcode Operation for `Opcode::DefineDecoratedElement`
+///
+/// Operation:
+///  - Define a decorated method, getter, setter or accessor on a class or class prototype.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DefineDecoratedElement;
+
+impl DefineDecoratedElement {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (object, target, value): (VaryingOperand, VaryingOperand, VaryingOperand),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let object = context.vm.get_register(object.into()).clone();
+        let target = context.vm.get_register(target.into()).clone();
+        let value = context.vm.get_register(value.into()).clone();
+
+        let object = object.as_object().expect("class must be object");
+        let (kind, is_static, name) = {
+            let target = target
+                .as_object()
+                .and_then(JsObject::downcast_ref::<DecorationTarget>)
+                .expect("decoration target must be a `DecorationTarget` object");
+            (target.kind, target.is_static, target.name.clone())
+        };
+
+        let (get, set) = if kind == DecorationKind::Accessor {
+            let accessor = value.as_object().expect("auto-accessor must be an object");
+            (
+                accessor.get(js_string!("get"), context)?,
+                accessor.get(js_string!("set"), context)?,
+            )
+        } else {
+            (JsValue::undefined(), JsValue::undefined())
+        };
+
+        match name {
+            DecorationName::Public(key) => {
+                let descriptor = match kind {
+                    DecorationKind::Method => PropertyDescriptor::builder()
+                        .value(value)
+                        .writable(true)
+                        .enumerable(false)
+                        .configurable(true)
+                        .build(),
+                    DecorationKind::Getter => PropertyDescriptor::builder()
+                        .get(value)
+                        .maybe_set(existing_accessor(object, &key, false, context)?)
+                        .enumerable(false)
+                        .configurable(true)
+                        .build(),
+                    DecorationKind::Setter => PropertyDescriptor::builder()
+                        .maybe_get(existing_accessor(object, &key, true, context)?)
+                        .set(value)
+                        .enumerable(false)
+                        .configurable(true)
+                        .build(),
+                    DecorationKind::Accessor => PropertyDescriptor::builder()
+                        .get(get)
+                        .set(set)
+                        .enumerable(false)
+                        .configurable(true)
+                        .build(),
+                    DecorationKind::Class | DecorationKind::Field => unreachable!(),
+                };
+                object.__define_own_property__(
+                    &key,
+                    descriptor,
+                    &mut InternalMethodContext::new(context),
+                )?;
+            }
+            DecorationName::Private(name) => {
+                let element = match kind {
+                    DecorationKind::Method => PrivateElement::Method(
+                        value.as_object().expect("method must be callable").clone(),
+                    ),
+                    DecorationKind::Getter => PrivateElement::Accessor {
+                        getter: value.as_object().cloned(),
+                        setter: None,
+                    },
+                    DecorationKind::Setter => PrivateElement::Accessor {
+                        getter: None,
+                        setter: value.as_object().cloned(),
+                    },
+                    DecorationKind::Accessor => PrivateElement::Accessor {
+                        getter: get.as_object().cloned(),
+                        setter: set.as_object().cloned(),
+                    },
+                    DecorationKind::Class | DecorationKind::Field => unreachable!(),
+                };
+
+                if is_static {
+                    object.borrow_mut().append_private_element(name, element);
+                } else {
+                    object
+                        .downcast_mut::<OrdinaryFunction>()
+                        .expect("class must be function object")
+                        .push_private_method(name, element);
+                }
+            }
+            DecorationName::Class(_) => unreachable!(),
+        }
+
+        Ok(())
+    }
+}
+
+impl Operation for DefineDecoratedElement {
+    const NAME: &'static str = "DefineDecoratedElement";
+    const INSTRUCTION: &'static str = "INST - DefineDecoratedElement";
+    const COST: u8 = 6;
+}
+
+/// Gets the other half of an existing accessor property, so that defining a getter does not
+/// remove a previously defined setter and vice versa.
+fn existing_accessor(
+    object: &JsObject,
+    key: &PropertyKey,
+    getter: bool,
+    context: &mut Context,
+) -> JsResult<Option<JsValue>> {
+    let descriptor = object.__get_own_property__(key, &mut InternalMethodContext::new(context))?;
+    Ok(descriptor.and_then(|descriptor| {
+        if getter {
+            descriptor.get().cloned()
+        } else {
+            descriptor.set().cloned()
+        }
+    }))
+}
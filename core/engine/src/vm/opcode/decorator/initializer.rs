@@ -0,0 +1,199 @@
+use super::{ClassDecorations, DecorationTarget};
+use crate::{
+    builtins::function::{set_function_name, ClassFieldDefinition, OrdinaryFunction},
+    native_function::NativeFunction,
+    object::{FunctionObjectBuilder, JsFunction, JsObject},
+    vm::opcode::{Operation, VaryingOperand},
+    Context, JsResult, JsValue,
+};
+use thin_vec::ThinVec;
+
+/// Gets the initializers returned by the field or accessor decorators of a decoration target.
+fn field_initializers(target: &JsValue) -> ThinVec<JsObject> {
+    target
+        .as_object()
+        .and_then(JsObject::downcast_ref::<DecorationTarget>)
+        .expect("decoration target must be a `DecorationTarget` object")
+        .field_initializers
+        .clone()
+}
+
+/// `SetClassFieldInitializers` implements the Opcode Operation for `Opcode::SetClassFieldInitializers`
+///
+/// Operation:
+///  - Attach the initializers returned by field decorators to the last field pushed to a class.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SetClassFieldInitializers;
+
+impl SetClassFieldInitializers {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (class, target): (VaryingOperand, VaryingOperand),
+        context: &mut Context,
+    ) {
+        let class = context.vm.get_register(class.into()).clone();
+        let target = context.vm.get_register(target.into()).clone();
+
+        let initializers = field_initializers(&target);
+        if initializers.is_empty() {
+            return;
+        }
+
+        let class = class.as_object().expect("class must be function object");
+        let mut class = class
+            .downcast_mut::<OrdinaryFunction>()
+            .expect("class must be function object");
+        let field = class
+            .last_field_mut()
+            .expect("decorated field must have been pushed to the class");
+
+        // The anonymous function name of a public field must be set on the value returned by its
+        // initializer, before the decorator initializers observe it.
+        let (initializer, function_name) = match field {
+            ClassFieldDefinition::Public(_, initializer, function_name) => {
+                (initializer, function_name.take().map(JsValue::from))
+            }
+            ClassFieldDefinition::Private(_, initializer) => (initializer, None),
+        };
+
+        let wrapped = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |this, _, (initializer, function_name, initializers), context| {
+                    let mut value = initializer.call(this, &[], context)?;
+                    if let (Some(name), Some(function)) = (function_name, value.as_object()) {
+                        let name = name.to_property_key(context)?;
+                        set_function_name(function, &name, None, context);
+                    }
+                    for initializer in initializers {
+                        value = initializer.call(this, &[value], context)?;
+                    }
+                    Ok(value)
+                },
+                (initializer.clone(), function_name, initializers),
+            ),
+        )
+        .build();
+
+        *initializer = wrapped;
+    }
+}
+
+impl Operation for SetClassFieldInitializers {
+    const NAME: &'static str = "SetClassFieldInitializers";
+    const INSTRUCTION: &'static str = "INST - SetClassFieldInitializers";
+    const COST: u8 = 4;
+}
+
+/// `ApplyFieldInitializers` implements the Opcode Operation for `Opcode::ApplyFieldInitializers`
+///
+/// Operation:
+///  - Pass the initial value of a static field through the initializers returned by its
+///    decorators.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ApplyFieldInitializers;
+
+impl ApplyFieldInitializers {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (value, receiver, target): (VaryingOperand, VaryingOperand, VaryingOperand),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let mut result = context.vm.get_register(value.into()).clone();
+        let receiver = context.vm.get_register(receiver.into()).clone();
+        let target = context.vm.get_register(target.into()).clone();
+
+        for initializer in field_initializers(&target) {
+            result = initializer.call(&receiver, &[result], context)?;
+        }
+
+        context.vm.set_register(value.into(), result);
+        Ok(())
+    }
+}
+
+impl Operation for ApplyFieldInitializers {
+    const NAME: &'static str = "ApplyFieldInitializers";
+    const INSTRUCTION: &'static str = "INST - ApplyFieldInitializers";
+    const COST: u8 = 4;
+}
+
+/// `PushClassInitializers` implements the Opcode Operation for `Opcode::PushClassInitializers`
+///
+/// Operation:
+///  - Move the instance initializers added by element decorators to the `[[Initializers]]`
+///    internal slot of the class.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PushClassInitializers;
+
+impl PushClassInitializers {
+    #[inline(always)]
+    pub(crate) fn operation(decorations: VaryingOperand, context: &mut Context) {
+        let decorations = context.vm.get_register(decorations.into()).clone();
+        let decorations = decorations
+            .as_object()
+            .expect("class decorations must be object");
+        let mut decorations = decorations
+            .downcast_mut::<ClassDecorations>()
+            .expect("class decorations must be a `ClassDecorations` object");
+
+        let initializers = std::mem::take(&mut decorations.instance_initializers);
+        decorations
+            .class
+            .downcast_mut::<OrdinaryFunction>()
+            .expect("class must be function object")
+            .push_initializers(
+                initializers
+                    .into_iter()
+                    .map(JsFunction::from_object_unchecked),
+            );
+    }
+}
+
+impl Operation for PushClassInitializers {
+    const NAME: &'static str = "PushClassInitializers";
+    const INSTRUCTION: &'static str = "INST - PushClassInitializers";
+    const COST: u8 = 3;
+}
+
+/// `CallClassInitializers` implements the Opcode Operation for `Opcode::CallClassInitializers`
+///
+/// Operation:
+///  - Call the static initializers, or the class initializers if `is_class` is set, added by
+///    decorators with the given receiver.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CallClassInitializers;
+
+impl CallClassInitializers {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (decorations, receiver, is_class): (VaryingOperand, VaryingOperand, VaryingOperand),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let decorations = context.vm.get_register(decorations.into()).clone();
+        let receiver = context.vm.get_register(receiver.into()).clone();
+
+        let initializers = {
+            let decorations = decorations
+                .as_object()
+                .and_then(JsObject::downcast_ref::<ClassDecorations>)
+                .expect("class decorations must be a `ClassDecorations` object");
+            if u32::from(is_class) != 0 {
+                decorations.class_initializers.clone()
+            } else {
+                decorations.static_initializers.clone()
+            }
+        };
+
+        for initializer in initializers {
+            initializer.call(&receiver, &[], context)?;
+        }
+        Ok(())
+    }
+}
+
+impl Operation for CallClassInitializers {
+    const NAME: &'static str = "CallClassInitializers";
+    const INSTRUCTION: &'static str = "INST - CallClassInitializers";
+    const COST: u8 = 4;
+}
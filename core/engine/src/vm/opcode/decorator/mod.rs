@@ -0,0 +1,529 @@
+//! This module implements the opcodes used to evaluate class and class element decorators.
+//!
+//! More information:
+//!  - [ECMAScript proposal][spec]
+//!
+//! [spec]: https://arai-a.github.io/ecma262-compare/?pr=2417
+
+use crate::{
+    js_str, js_string,
+    native_function::NativeFunction,
+    object::{JsObject, ObjectInitializer, PrivateName},
+    property::{Attribute, PropertyKey},
+    vm::opcode::{Operation, VaryingOperand},
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+use boa_gc::{Finalize, Gc, GcRefCell, Trace};
+use thin_vec::ThinVec;
+
+pub(crate) mod element;
+pub(crate) mod initializer;
+
+pub(crate) use element::*;
+pub(crate) use initializer::*;
+
+/// The kind of value a decorator is applied to.
+///
+/// The kind is encoded in the lower bits of the `flags` operand of
+/// [`CreateDecorationTarget`], together with the [`DecorationKind::STATIC`] and
+/// [`DecorationKind::PRIVATE`] bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DecorationKind {
+    Class,
+    Method,
+    Getter,
+    Setter,
+    Field,
+    Accessor,
+}
+
+impl DecorationKind {
+    /// Flag set when the decorated element is static.
+    pub(crate) const STATIC: u32 = 0b0_1000;
+
+    /// Flag set when the decorated element has a private name.
+    pub(crate) const PRIVATE: u32 = 0b1_0000;
+
+    /// Encodes the kind and the element placement into the `flags` operand.
+    pub(crate) const fn to_flags(self, is_static: bool, is_private: bool) -> u32 {
+        let mut flags = self as u32;
+        if is_static {
+            flags |= Self::STATIC;
+        }
+        if is_private {
+            flags |= Self::PRIVATE;
+        }
+        flags
+    }
+
+    const fn from_flags(flags: u32) -> Self {
+        match flags & 0b0111 {
+            0 => Self::Class,
+            1 => Self::Method,
+            2 => Self::Getter,
+            3 => Self::Setter,
+            4 => Self::Field,
+            5 => Self::Accessor,
+            _ => unreachable!(),
+        }
+    }
+
+    fn name(self) -> JsString {
+        match self {
+            Self::Class => js_string!("class"),
+            Self::Method => js_string!("method"),
+            Self::Getter => js_string!("getter"),
+            Self::Setter => js_string!("setter"),
+            Self::Field => js_string!("field"),
+            Self::Accessor => js_string!("accessor"),
+        }
+    }
+}
+
+/// The name of a decorated value.
+#[derive(Debug, Clone)]
+pub(crate) enum DecorationName {
+    /// The name of a decorated class, or `undefined` for anonymous classes.
+    Class(JsValue),
+
+    /// The property key of a public class element.
+    Public(PropertyKey),
+
+    /// The private name of a private class element.
+    Private(PrivateName),
+}
+
+/// The extra initializers registered with `addInitializer` while decorating a class.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct ClassDecorations {
+    /// The undecorated class constructor.
+    class: JsObject,
+
+    /// Initializers that run on every instance, before its fields are defined.
+    instance_initializers: ThinVec<JsObject>,
+
+    /// Initializers that run on the class, before its static fields are defined.
+    static_initializers: ThinVec<JsObject>,
+
+    /// Initializers that run on the decorated class once it is fully defined.
+    class_initializers: ThinVec<JsObject>,
+}
+
+/// The class or class element currently being decorated.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct DecorationTarget {
+    /// The [`ClassDecorations`] of the class that contains this element.
+    decorations: JsObject,
+
+    #[unsafe_ignore_trace]
+    kind: DecorationKind,
+
+    is_static: bool,
+
+    #[unsafe_ignore_trace]
+    name: DecorationName,
+
+    /// The initializers returned by field decorators, and the `init` functions returned by
+    /// accessor decorators.
+    field_initializers: ThinVec<JsObject>,
+}
+
+impl DecorationTarget {
+    /// Returns the display name of the target, as exposed by `context.name`.
+    fn name_value(&self) -> JsValue {
+        match &self.name {
+            DecorationName::Class(name) => name.clone(),
+            DecorationName::Public(key) => key.clone().into(),
+            DecorationName::Private(name) => js_string!(js_str!("#"), name.description()).into(),
+        }
+    }
+}
+
+/// `CreateClassDecorations` implements the Opcode Operation for `Opcode::CreateClassDecorations`
+///
+/// Operation:
+///  - Create the record holding the extra initializers of a decorated class.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CreateClassDecorations;
+
+impl CreateClassDecorations {
+    #[inline(always)]
+    pub(crate) fn operation((dst, class): (VaryingOperand, VaryingOperand), context: &mut Context) {
+        let class = context.vm.get_register(class.into());
+        let class = class.as_object().expect("class must be object").clone();
+
+        let decorations = JsObject::from_proto_and_data(
+            None,
+            ClassDecorations {
+                class,
+                instance_initializers: ThinVec::new(),
+                static_initializers: ThinVec::new(),
+                class_initializers: ThinVec::new(),
+            },
+        );
+        context.vm.set_register(dst.into(), decorations.into());
+    }
+}
+
+impl Operation for CreateClassDecorations {
+    const NAME: &'static str = "CreateClassDecorations";
+    const INSTRUCTION: &'static str = "INST - CreateClassDecorations";
+    const COST: u8 = 3;
+}
+
+/// `CreateDecorationTarget` implements the Opcode Operation for `Opcode::CreateDecorationTarget`
+///
+/// Operation:
+///  - Create the target of the decorators of a class or class element.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CreateDecorationTarget;
+
+impl CreateDecorationTarget {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (dst, decorations, key, flags): (
+            VaryingOperand,
+            VaryingOperand,
+            VaryingOperand,
+            VaryingOperand,
+        ),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let decorations = context.vm.get_register(decorations.into()).clone();
+        let key = context.vm.get_register(key.into()).clone();
+        let flags = u32::from(flags);
+
+        let decorations = decorations
+            .as_object()
+            .expect("class decorations must be object")
+            .clone();
+        let kind = DecorationKind::from_flags(flags);
+
+        let name = if kind == DecorationKind::Class {
+            DecorationName::Class(key)
+        } else if flags & DecorationKind::PRIVATE != 0 {
+            let description = key
+                .as_string()
+                .expect("private name description must be a string")
+                .clone();
+            let class = decorations
+                .downcast_ref::<ClassDecorations>()
+                .expect("class decorations must be a `ClassDecorations` object")
+                .class
+                .clone();
+            DecorationName::Private(class.private_name(description))
+        } else {
+            DecorationName::Public(key.to_property_key(context)?)
+        };
+
+        let target = JsObject::from_proto_and_data(
+            None,
+            DecorationTarget {
+                decorations,
+                kind,
+                is_static: flags & DecorationKind::STATIC != 0,
+                name,
+                field_initializers: ThinVec::new(),
+            },
+        );
+        context.vm.set_register(dst.into(), target.into());
+        Ok(())
+    }
+}
+
+impl Operation for CreateDecorationTarget {
+    const NAME: &'static str = "CreateDecorationTarget";
+    const INSTRUCTION: &'static str = "INST - CreateDecorationTarget";
+    const COST: u8 = 4;
+}
+
+/// `ApplyDecorator` implements the Opcode Operation for `Opcode::ApplyDecorator`
+///
+/// Operation:
+///  - Call a decorator with the decorated value and its context object, and replace the value
+///    with the result of the decorator.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ApplyDecorator;
+
+impl ApplyDecorator {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (value, target, decorator): (VaryingOperand, VaryingOperand, VaryingOperand),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let current = context.vm.get_register(value.into()).clone();
+        let target = context.vm.get_register(target.into()).clone();
+        let decorator = context.vm.get_register(decorator.into()).clone();
+
+        let target = target
+            .as_object()
+            .expect("decoration target must be object")
+            .clone();
+        let kind = target
+            .downcast_ref::<DecorationTarget>()
+            .expect("decoration target must be a `DecorationTarget` object")
+            .kind;
+
+        // The `addInitializer` function of the context object can only be called while the
+        // decorator is running.
+        let finished = Gc::new(GcRefCell::new(false));
+        let decorator_context = create_decorator_context(&target, &finished, context);
+
+        let argument = if kind == DecorationKind::Field {
+            JsValue::undefined()
+        } else {
+            current.clone()
+        };
+        let result = decorator.call(
+            &JsValue::undefined(),
+            &[argument, decorator_context.into()],
+            context,
+        );
+        *finished.borrow_mut() = true;
+        let result = result?;
+
+        if result.is_undefined() {
+            return Ok(());
+        }
+
+        match kind {
+            DecorationKind::Class
+            | DecorationKind::Method
+            | DecorationKind::Getter
+            | DecorationKind::Setter => {
+                if !result.is_callable() {
+                    return Err(JsNativeError::typ()
+                        .with_message("decorator must return a function or undefined")
+                        .into());
+                }
+                context.vm.set_register(value.into(), result);
+            }
+            DecorationKind::Field => {
+                let initializer = result.as_callable().ok_or_else(|| {
+                    JsNativeError::typ()
+                        .with_message("field decorator must return a function or undefined")
+                })?;
+                target
+                    .downcast_mut::<DecorationTarget>()
+                    .expect("decoration target must be a `DecorationTarget` object")
+                    .field_initializers
+                    .push(initializer.clone());
+            }
+            DecorationKind::Accessor => {
+                let result = result.as_object().ok_or_else(|| {
+                    JsNativeError::typ()
+                        .with_message("accessor decorator must return an object or undefined")
+                })?;
+                let current = current
+                    .as_object()
+                    .expect("auto-accessor must be an object");
+
+                let mut get = current.get(js_string!("get"), context)?;
+                let mut set = current.get(js_string!("set"), context)?;
+
+                let new_get = result.get(js_string!("get"), context)?;
+                if !new_get.is_undefined() {
+                    if !new_get.is_callable() {
+                        return Err(JsNativeError::typ()
+                            .with_message("accessor decorator `get` must be a function")
+                            .into());
+                    }
+                    get = new_get;
+                }
+
+                let new_set = result.get(js_string!("set"), context)?;
+                if !new_set.is_undefined() {
+                    if !new_set.is_callable() {
+                        return Err(JsNativeError::typ()
+                            .with_message("accessor decorator `set` must be a function")
+                            .into());
+                    }
+                    set = new_set;
+                }
+
+                let init = result.get(js_string!("init"), context)?;
+                if !init.is_undefined() {
+                    let init = init.as_callable().ok_or_else(|| {
+                        JsNativeError::typ()
+                            .with_message("accessor decorator `init` must be a function")
+                    })?;
+                    target
+                        .downcast_mut::<DecorationTarget>()
+                        .expect("decoration target must be a `DecorationTarget` object")
+                        .field_initializers
+                        .push(init.clone());
+                }
+
+                let accessor = ObjectInitializer::new(context)
+                    .property(js_string!("get"), get, Attribute::all())
+                    .property(js_string!("set"), set, Attribute::all())
+                    .build();
+                context.vm.set_register(value.into(), accessor.into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Operation for ApplyDecorator {
+    const NAME: &'static str = "ApplyDecorator";
+    const INSTRUCTION: &'static str = "INST - ApplyDecorator";
+    const COST: u8 = 8;
+}
+
+/// Creates the context object that is passed as the second argument to a decorator.
+fn create_decorator_context(
+    target: &JsObject,
+    finished: &Gc<GcRefCell<bool>>,
+    context: &mut Context,
+) -> JsObject {
+    let (kind, is_static, is_private, name) = {
+        let target = target
+            .downcast_ref::<DecorationTarget>()
+            .expect("decoration target must be a `DecorationTarget` object");
+        (
+            target.kind,
+            target.is_static,
+            matches!(target.name, DecorationName::Private(_)),
+            target.name_value(),
+        )
+    };
+
+    let add_initializer = NativeFunction::from_copy_closure_with_captures(
+        |_, args, (finished, target), _| {
+            // 1. If decorationState.[[Finished]] is true, throw a TypeError exception.
+            if *finished.borrow() {
+                return Err(JsNativeError::typ()
+                    .with_message("cannot add an initializer after the decorator has finished")
+                    .into());
+            }
+
+            // 2. If IsCallable(initializer) is false, throw a TypeError exception.
+            let initializer = args.get_or_undefined(0).as_callable().ok_or_else(|| {
+                JsNativeError::typ().with_message("initializer must be a function")
+            })?;
+
+            // 3. Append initializer to the extra initializers of the decorated element.
+            let target = target
+                .downcast_ref::<DecorationTarget>()
+                .expect("decoration target must be a `DecorationTarget` object");
+            let mut decorations = target
+                .decorations
+                .downcast_mut::<ClassDecorations>()
+                .expect("class decorations must be a `ClassDecorations` object");
+            let initializers = match (target.kind, target.is_static) {
+                (DecorationKind::Class, _) => &mut decorations.class_initializers,
+                (_, true) => &mut decorations.static_initializers,
+                (_, false) => &mut decorations.instance_initializers,
+            };
+            initializers.push(initializer.clone());
+
+            Ok(JsValue::undefined())
+        },
+        (finished.clone(), target.clone()),
+    );
+
+    if kind == DecorationKind::Class {
+        return ObjectInitializer::new(context)
+            .property(js_string!("kind"), kind.name(), Attribute::all())
+            .property(js_string!("name"), name, Attribute::all())
+            .function(add_initializer, js_string!("addInitializer"), 1)
+            .build();
+    }
+
+    let access = create_access_object(target, kind, context);
+    ObjectInitializer::new(context)
+        .property(js_string!("kind"), kind.name(), Attribute::all())
+        .property(js_string!("name"), name, Attribute::all())
+        .property(js_string!("access"), access, Attribute::all())
+        .property(js_string!("static"), is_static, Attribute::all())
+        .property(js_string!("private"), is_private, Attribute::all())
+        .function(add_initializer, js_string!("addInitializer"), 1)
+        .build()
+}
+
+/// Creates the `access` object of a decorator context, which can be used to access the
+/// decorated element on any object.
+fn create_access_object(
+    target: &JsObject,
+    kind: DecorationKind,
+    context: &mut Context,
+) -> JsObject {
+    let has = NativeFunction::from_copy_closure_with_captures(
+        |_, args, target, context| {
+            let object = access_receiver(args)?;
+            match target_name(target) {
+                DecorationName::Public(key) => Ok(object.has_property(key, context)?.into()),
+                DecorationName::Private(name) => Ok(object
+                    .private_element_find(&name, true, true)
+                    .is_some()
+                    .into()),
+                DecorationName::Class(_) => unreachable!(),
+            }
+        },
+        target.clone(),
+    );
+
+    let mut access = ObjectInitializer::new(context);
+
+    if kind != DecorationKind::Setter {
+        let get = NativeFunction::from_copy_closure_with_captures(
+            |_, args, target, context| {
+                let object = access_receiver(args)?;
+                match target_name(target) {
+                    DecorationName::Public(key) => object.get(key, context),
+                    DecorationName::Private(name) => object.private_get(&name, context),
+                    DecorationName::Class(_) => unreachable!(),
+                }
+            },
+            target.clone(),
+        );
+        access.function(get, js_string!("get"), 1);
+    }
+
+    if matches!(
+        kind,
+        DecorationKind::Setter | DecorationKind::Field | DecorationKind::Accessor
+    ) {
+        let set = NativeFunction::from_copy_closure_with_captures(
+            |_, args, target, context| {
+                let object = access_receiver(args)?;
+                let value = args.get_or_undefined(1).clone();
+                match target_name(target) {
+                    DecorationName::Public(key) => {
+                        object.set(key, value, true, context)?;
+                    }
+                    DecorationName::Private(name) => object.private_set(&name, value, context)?,
+                    DecorationName::Class(_) => unreachable!(),
+                }
+                Ok(JsValue::undefined())
+            },
+            target.clone(),
+        );
+        access.function(set, js_string!("set"), 2);
+    }
+
+    access.function(has, js_string!("has"), 1).build()
+}
+
+/// Gets the object an access function operates on.
+fn access_receiver(args: &[JsValue]) -> JsResult<JsObject> {
+    args.get_or_undefined(0)
+        .as_object()
+        .cloned()
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("decorator access functions must be called with an object")
+                .into()
+        })
+}
+
+/// Gets the name of a decoration target.
+fn target_name(target: &JsObject) -> DecorationName {
+    target
+        .downcast_ref::<DecorationTarget>()
+        .expect("decoration target must be a `DecorationTarget` object")
+        .name
+        .clone()
+}
@@ -0,0 +1,196 @@
+use crate::{
+    builtins::disposable_stack::{DisposableResource, DisposeCapability, DisposeHint},
+    vm::opcode::{Operation, VaryingOperand},
+    Context, JsError, JsResult,
+};
+
+/// `PushDisposeScope` implements the Opcode Operation for `Opcode::PushDisposeScope`
+///
+/// Operation:
+///  - Push a new dispose capability for the `using` declarations of a scope.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PushDisposeScope;
+
+impl PushDisposeScope {
+    #[inline(always)]
+    pub(crate) fn operation((): (), context: &mut Context) {
+        context
+            .vm
+            .frame_mut()
+            .dispose_capabilities
+            .push(DisposeCapability::default());
+    }
+}
+
+impl Operation for PushDisposeScope {
+    const NAME: &'static str = "PushDisposeScope";
+    const INSTRUCTION: &'static str = "INST - PushDisposeScope";
+    const COST: u8 = 1;
+}
+
+fn add_disposable_resource(
+    value: VaryingOperand,
+    hint: DisposeHint,
+    context: &mut Context,
+) -> JsResult<()> {
+    let value = context.vm.get_register(value.into()).clone();
+    if let Some(resource) = DisposableResource::create(value, hint, None, context)? {
+        context
+            .vm
+            .frame_mut()
+            .dispose_capabilities
+            .last_mut()
+            .expect("there should be a dispose capability")
+            .add_resource(resource);
+    }
+    Ok(())
+}
+
+/// `AddDisposableResource` implements the Opcode Operation for `Opcode::AddDisposableResource`
+///
+/// Operation:
+///  - Add the value of a `using` declaration to the current dispose capability.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AddDisposableResource;
+
+impl AddDisposableResource {
+    #[inline(always)]
+    pub(crate) fn operation(value: VaryingOperand, context: &mut Context) -> JsResult<()> {
+        add_disposable_resource(value, DisposeHint::Sync, context)
+    }
+}
+
+impl Operation for AddDisposableResource {
+    const NAME: &'static str = "AddDisposableResource";
+    const INSTRUCTION: &'static str = "INST - AddDisposableResource";
+    const COST: u8 = 4;
+}
+
+/// `AddAsyncDisposableResource` implements the Opcode Operation for `Opcode::AddAsyncDisposableResource`
+///
+/// Operation:
+///  - Add the value of an `await using` declaration to the current dispose capability.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AddAsyncDisposableResource;
+
+impl AddAsyncDisposableResource {
+    #[inline(always)]
+    pub(crate) fn operation(value: VaryingOperand, context: &mut Context) -> JsResult<()> {
+        add_disposable_resource(value, DisposeHint::Async, context)
+    }
+}
+
+impl Operation for AddAsyncDisposableResource {
+    const NAME: &'static str = "AddAsyncDisposableResource";
+    const INSTRUCTION: &'static str = "INST - AddAsyncDisposableResource";
+    const COST: u8 = 4;
+}
+
+/// `DisposeResources` implements the Opcode Operation for `Opcode::DisposeResources`
+///
+/// Operation:
+///  - Dispose the resources of the current dispose capability until a value must be awaited.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DisposeResources;
+
+impl DisposeResources {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (value, done): (VaryingOperand, VaryingOperand),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let mut capability = context
+            .vm
+            .frame_mut()
+            .dispose_capabilities
+            .pop()
+            .expect("there should be a dispose capability");
+
+        let step = capability.dispose_step(context)?;
+
+        context.vm.frame_mut().dispose_capabilities.push(capability);
+
+        context.vm.set_register(done.into(), step.is_none().into());
+        if let Some(awaited) = step {
+            context.vm.set_register(value.into(), awaited);
+        }
+
+        Ok(())
+    }
+}
+
+impl Operation for DisposeResources {
+    const NAME: &'static str = "DisposeResources";
+    const INSTRUCTION: &'static str = "INST - DisposeResources";
+    const COST: u8 = 8;
+}
+
+/// `RecordDisposeError` implements the Opcode Operation for `Opcode::RecordDisposeError`
+///
+/// Operation:
+///  - Record the error thrown while awaiting the result of an async dispose method.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RecordDisposeError;
+
+impl RecordDisposeError {
+    #[inline(always)]
+    pub(crate) fn operation(error: VaryingOperand, context: &mut Context) {
+        let error = JsError::from_opaque(context.vm.get_register(error.into()).clone());
+        context
+            .vm
+            .frame_mut()
+            .dispose_capabilities
+            .last_mut()
+            .expect("there should be a dispose capability")
+            .record_error(error);
+    }
+}
+
+impl Operation for RecordDisposeError {
+    const NAME: &'static str = "RecordDisposeError";
+    const INSTRUCTION: &'static str = "INST - RecordDisposeError";
+    const COST: u8 = 1;
+}
+
+/// `PopDisposeScope` implements the Opcode Operation for `Opcode::PopDisposeScope`
+///
+/// Operation:
+///  - Pop the current dispose capability, combining the errors thrown while disposing its
+///    resources with the completion of the scope.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PopDisposeScope;
+
+impl PopDisposeScope {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (error, has_error): (VaryingOperand, VaryingOperand),
+        context: &mut Context,
+    ) {
+        let capability = context
+            .vm
+            .frame_mut()
+            .dispose_capabilities
+            .pop()
+            .expect("there should be a dispose capability");
+
+        let completion = if context.vm.get_register(has_error.into()).to_boolean() {
+            Err(JsError::from_opaque(
+                context.vm.get_register(error.into()).clone(),
+            ))
+        } else {
+            Ok(())
+        };
+
+        if let Err(err) = capability.into_completion(completion, context) {
+            let err = err.to_opaque(context);
+            context.vm.set_register(error.into(), err);
+            context.vm.set_register(has_error.into(), true.into());
+        }
+    }
+}
+
+impl Operation for PopDisposeScope {
+    const NAME: &'static str = "PopDisposeScope";
+    const INSTRUCTION: &'static str = "INST - PopDisposeScope";
+    const COST: u8 = 2;
+}
@@ -18,8 +18,10 @@ mod call;
 mod concat;
 mod control_flow;
 mod copy;
+mod decorator;
 mod define;
 mod delete;
+mod dispose;
 mod environment;
 mod generator;
 mod get;
@@ -52,10 +54,14 @@ pub(crate) use control_flow::*;
 #[doc(inline)]
 pub(crate) use copy::*;
 #[doc(inline)]
+pub(crate) use decorator::*;
+#[doc(inline)]
 pub(crate) use define::*;
 #[doc(inline)]
 pub(crate) use delete::*;
 #[doc(inline)]
+pub(crate) use dispose::*;
+#[doc(inline)]
 pub(crate) use environment::*;
 #[doc(inline)]
 pub(crate) use generator::*;
@@ -1658,9 +1664,9 @@ generate_opcodes! {
     /// Dynamically import a module.
     ///
     /// - Registers:
-    ///   - Input: value
+    ///   - Input: value, options
     ///   - Output: value
-    ImportCall { value: VaryingOperand },
+    ImportCall { value: VaryingOperand, options: VaryingOperand },
 
     /// Pop the two values of the stack, strict equal compares the two values,
     /// if true jumps to address, otherwise push the second pop'ed value.
@@ -2093,6 +2099,111 @@ generate_opcodes! {
     /// [spec]: https://tc39.es/ecma262/#sec-createglobalvarbinding
     CreateGlobalVarBinding { configurable: VaryingOperand, name_index: VaryingOperand },
 
+    /// Create the record holding the extra initializers of a decorated class.
+    ///
+    /// - Registers:
+    ///   - Input: class
+    ///   - Output: dst
+    CreateClassDecorations { dst: VaryingOperand, class: VaryingOperand },
+
+    /// Create the target of the decorators of a class or class element.
+    ///
+    /// The key is the property key of a public element, the description of a private name, or
+    /// the name of a decorated class.
+    ///
+    /// - Operands:
+    ///   - flags: `VaryingOperand`
+    /// - Registers:
+    ///   - Input: decorations, key
+    ///   - Output: dst
+    CreateDecorationTarget { dst: VaryingOperand, decorations: VaryingOperand, key: VaryingOperand, flags: VaryingOperand },
+
+    /// Apply a decorator to a class or class element.
+    ///
+    /// - Registers:
+    ///   - Input: value, target, decorator
+    ///   - Output: value
+    ApplyDecorator { value: VaryingOperand, target: VaryingOperand, decorator: VaryingOperand },
+
+    /// Create the getter and setter of an `accessor` class field.
+    ///
+    /// - Operands:
+    ///   - name_index: `VaryingOperand`
+    /// - Registers:
+    ///   - Input: class, target
+    ///   - Output: dst
+    CreateAutoAccessor { dst: VaryingOperand, class: VaryingOperand, target: VaryingOperand, name_index: VaryingOperand },
+
+    /// Define a decorated method, getter, setter or accessor.
+    ///
+    /// - Registers:
+    ///   - Input: object, target, value
+    DefineDecoratedElement { object: VaryingOperand, target: VaryingOperand, value: VaryingOperand },
+
+    /// Attach the initializers returned by field decorators to the last field of a class.
+    ///
+    /// - Registers:
+    ///   - Input: class, target
+    SetClassFieldInitializers { class: VaryingOperand, target: VaryingOperand },
+
+    /// Pass the value of a static field through the initializers returned by its decorators.
+    ///
+    /// - Registers:
+    ///   - Input: value, receiver, target
+    ///   - Output: value
+    ApplyFieldInitializers { value: VaryingOperand, receiver: VaryingOperand, target: VaryingOperand },
+
+    /// Move the instance initializers added by decorators to the class.
+    ///
+    /// - Registers:
+    ///   - Input: decorations
+    PushClassInitializers { decorations: VaryingOperand },
+
+    /// Call the static or class initializers added by decorators.
+    ///
+    /// - Operands:
+    ///   - is_class: `bool`
+    /// - Registers:
+    ///   - Input: decorations, receiver
+    CallClassInitializers { decorations: VaryingOperand, receiver: VaryingOperand, is_class: VaryingOperand },
+
+    /// Push a new dispose capability for the `using` declarations of a scope.
+    PushDisposeScope,
+
+    /// Add the value of a `using` declaration to the current dispose capability.
+    ///
+    /// - Registers:
+    ///   - Input: value
+    AddDisposableResource { value: VaryingOperand },
+
+    /// Add the value of an `await using` declaration to the current dispose capability.
+    ///
+    /// - Registers:
+    ///   - Input: value
+    AddAsyncDisposableResource { value: VaryingOperand },
+
+    /// Dispose the resources of the current dispose capability, in reverse order.
+    ///
+    /// Stops early when a value must be awaited before continuing, storing it in `value` and
+    /// setting `done` to `false`. Errors thrown by dispose methods are recorded in the capability.
+    ///
+    /// - Registers:
+    ///   - Output: value, done
+    DisposeResources { value: VaryingOperand, done: VaryingOperand },
+
+    /// Record an error thrown while awaiting the result of an async dispose method.
+    ///
+    /// - Registers:
+    ///   - Input: error
+    RecordDisposeError { error: VaryingOperand },
+
+    /// Pop the current dispose capability, combining the errors recorded while disposing its
+    /// resources with the completion of the scope.
+    ///
+    /// - Registers:
+    ///   - Input: error, has_error
+    ///   - Output: error, has_error
+    PopDisposeScope { error: VaryingOperand, has_error: VaryingOperand },
     /// Reserved [`Opcode`].
     Reserved1 => Reserved,
     /// Reserved [`Opcode`].
@@ -2189,34 +2300,4 @@ generate_opcodes! {
     Reserved47 => Reserved,
     /// Reserved [`Opcode`].
     Reserved48 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved49 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved50 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved51 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved52 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved53 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved54 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved55 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved56 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved57 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved58 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved59 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved60 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved61 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved62 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved63 => Reserved,
 }
@@ -9,6 +9,9 @@ pub struct RuntimeLimits {
 
     /// Max function recursion limit
     resursion: usize,
+
+    /// Max heap size in bytes before an error is thrown.
+    heap_size: usize,
 }
 
 impl Default for RuntimeLimits {
@@ -18,6 +21,7 @@ impl Default for RuntimeLimits {
             loop_iteration: u64::MAX,
             resursion: 512,
             stack_size: 1024 * 10,
+            heap_size: usize::MAX,
         }
     }
 }
@@ -75,4 +79,39 @@ impl RuntimeLimits {
     pub fn set_recursion_limit(&mut self, value: usize) {
         self.resursion = value;
     }
+
+    /// Get the heap size limit in bytes.
+    ///
+    /// The heap size is approximated by the bytes allocated by the garbage collector and by the
+    /// strings, plus the size of the string, array, map or set that is being allocated or grown.
+    /// It is checked before allocating strings and growing collections, and before executing
+    /// every instruction, which catches the objects allocated by the previous ones. If the limit
+    /// is exceeded, a `RangeError` is thrown.
+    ///
+    /// Since the garbage collector and the strings are shared by all the contexts of a thread,
+    /// the heap size includes the memory allocated by all of them.
+    ///
+    /// The limit value [`usize::MAX`] means that there is no limit.
+    #[inline]
+    #[must_use]
+    pub const fn heap_size_limit(&self) -> usize {
+        self.heap_size
+    }
+
+    /// Set the heap size limit in bytes.
+    ///
+    /// Since the garbage collector and the strings are shared by all the contexts of a thread,
+    /// the limit applies to the memory allocated by all of them.
+    ///
+    /// Setting the limit to [`usize::MAX`] means that there is no limit.
+    #[inline]
+    pub fn set_heap_size_limit(&mut self, value: usize) {
+        self.heap_size = value;
+    }
+
+    /// Disable heap size limit.
+    #[inline]
+    pub fn disable_heap_size_limit(&mut self) {
+        self.heap_size = usize::MAX;
+    }
 }
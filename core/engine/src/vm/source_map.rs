@@ -0,0 +1,99 @@
+//! Source maps of compiled code.
+//!
+//! This module contains the [`SourceMap`], which maps the bytecode offsets of a [`CodeBlock`]
+//! back to positions in the source code, and the [`SourceLocation`] reported for thrown errors.
+//!
+//! [`CodeBlock`]: super::CodeBlock
+
+use boa_ast::Position;
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+/// An entry of the [`SourceMap`].
+///
+/// Marks that every instruction starting at `pc`, up to the `pc` of the next entry, was
+/// compiled from the node that starts at `position`, or from no node with a known position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SourceMapEntry {
+    pub(crate) pc: u32,
+    pub(crate) position: Option<Position>,
+}
+
+/// A compact table mapping bytecode offsets (`pc`) to [`Position`]s in the source code.
+///
+/// Only the offsets where the position changes are stored, sorted by `pc`.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SourceMap {
+    entries: Box<[SourceMapEntry]>,
+}
+
+impl SourceMap {
+    /// Creates a new `SourceMap` from entries sorted by `pc`.
+    pub(crate) fn new(entries: Box<[SourceMapEntry]>) -> Self {
+        debug_assert!(entries.windows(2).all(|w| w[0].pc < w[1].pc));
+        Self { entries }
+    }
+
+    /// Returns the entries of the source map, sorted by `pc`.
+    pub(crate) fn entries(&self) -> &[SourceMapEntry] {
+        &self.entries
+    }
+
+    /// Finds the position in the source code of the instruction containing `pc`.
+    pub(crate) fn find(&self, pc: u32) -> Option<Position> {
+        let index = self.entries.partition_point(|entry| entry.pc <= pc);
+        index
+            .checked_sub(1)
+            .and_then(|index| self.entries[index].position)
+    }
+}
+
+/// The location in the source code where an error was thrown.
+///
+/// Locations are captured by the VM when an error is thrown from JavaScript code, and
+/// can be retrieved with [`JsError::location`] or [`JsNativeError::location`].
+///
+/// [`JsError::location`]: crate::JsError::location
+/// [`JsNativeError::location`]: crate::JsNativeError::location
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    path: Option<PathBuf>,
+    position: Option<Position>,
+}
+
+impl SourceLocation {
+    /// Creates a new `SourceLocation`.
+    #[must_use]
+    pub const fn new(path: Option<PathBuf>, position: Option<Position>) -> Self {
+        Self { path, position }
+    }
+
+    /// Gets the path of the script or module where the error was thrown, if any.
+    #[inline]
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Gets the line and column where the error was thrown, if known.
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        self.position
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "{}", path.display())?,
+            None => f.write_str("<anonymous>")?,
+        }
+        if let Some(position) = self.position {
+            write!(f, ":{position}")?;
+        }
+        Ok(())
+    }
+}
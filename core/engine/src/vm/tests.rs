@@ -1,6 +1,9 @@
 use crate::{
-    js_string, property::Attribute, run_test_actions, Context, JsNativeErrorKind, JsValue,
-    TestAction,
+    js_string,
+    property::Attribute,
+    run_test_actions,
+    vm::{InterruptAction, InterruptReason, SourceLocation},
+    Context, JsNativeError, JsNativeErrorKind, JsString, JsValue, TestAction,
 };
 use boa_macros::js_str;
 use boa_parser::Source;
@@ -315,6 +318,278 @@ fn recursion_runtime_limit() {
     ]);
 }
 
+#[test]
+fn fuel_runtime_limit() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            context.set_fuel(Some(1000));
+        }),
+        TestAction::assert_eq("1 + 1", JsValue::new(2)),
+        TestAction::inspect_context(|context| {
+            assert!(context.fuel().is_some_and(|fuel| fuel < 1000));
+        }),
+        TestAction::assert_native_error(
+            "while (true) { }",
+            JsNativeErrorKind::RuntimeLimit,
+            "exceeded the execution budget",
+        ),
+        // The error cannot be caught.
+        TestAction::assert_native_error(
+            "try { while (true) { } } catch { }",
+            JsNativeErrorKind::RuntimeLimit,
+            "exceeded the execution budget",
+        ),
+        TestAction::inspect_context(|context| {
+            context.set_fuel(None);
+        }),
+        TestAction::assert_eq("1 + 1", JsValue::new(2)),
+    ]);
+}
+
+#[test]
+fn fuel_interrupt_handler() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            let refuels = std::rc::Rc::new(std::cell::Cell::new(0));
+            context.set_interrupt_handler(std::rc::Rc::new(
+                move |reason: InterruptReason, context: &mut Context| {
+                    assert_eq!(reason, InterruptReason::FuelExhausted);
+                    refuels.set(refuels.get() + 1);
+                    if refuels.get() > 3 {
+                        return InterruptAction::Terminate;
+                    }
+                    context.set_fuel(Some(100));
+                    InterruptAction::Continue
+                },
+            ));
+            context.set_fuel(Some(100));
+        }),
+        TestAction::assert_eq(
+            "let sum = 0; for (let i = 0; i < 10; i++) { sum += i; } sum",
+            JsValue::new(45),
+        ),
+        TestAction::assert_native_error(
+            "while (true) { }",
+            JsNativeErrorKind::Terminated,
+            "execution was terminated",
+        ),
+        // The termination is sticky until it is canceled.
+        TestAction::assert_native_error(
+            "1 + 1",
+            JsNativeErrorKind::Terminated,
+            "execution was terminated",
+        ),
+        TestAction::inspect_context(|context| {
+            context.interrupt_handle().cancel_termination();
+            context.set_fuel(None);
+        }),
+        TestAction::assert_eq("1 + 1", JsValue::new(2)),
+    ]);
+}
+
+#[test]
+fn interrupt_handle() {
+    let context = &mut Context::default();
+    let handle = context.interrupt_handle();
+
+    // Interrupts are ignored if there is no handler.
+    handle.request_interrupt();
+    assert_eq!(
+        context.eval(Source::from_bytes("1 + 1")).unwrap(),
+        JsValue::new(2)
+    );
+
+    context.set_interrupt_handler(std::rc::Rc::new(
+        |reason: InterruptReason, context: &mut Context| {
+            assert_eq!(reason, InterruptReason::Requested);
+            context
+                .global_object()
+                .set(js_string!("interrupted"), true, false, context)
+                .unwrap();
+            InterruptAction::Continue
+        },
+    ));
+    handle.request_interrupt();
+    assert_eq!(
+        context.eval(Source::from_bytes("interrupted")).unwrap(),
+        JsValue::new(true)
+    );
+
+    // A paused execution can be resumed or terminated from another thread.
+    let thread = {
+        let handle = handle.clone();
+        std::thread::spawn(move || {
+            while !handle.is_paused() {
+                std::thread::yield_now();
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            handle.resume();
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            handle.terminate();
+        })
+    };
+    handle.pause();
+    let err = context
+        .eval(Source::from_bytes("let i = 0; while (true) { i++ }"))
+        .unwrap_err();
+    assert!(err.as_native().is_some_and(JsNativeError::is_terminated));
+    thread.join().unwrap();
+}
+
+#[test]
+fn pause_without_handle() {
+    let context = &mut Context::default();
+    context.set_interrupt_handler(std::rc::Rc::new(|_: InterruptReason, _: &mut Context| {
+        InterruptAction::Pause
+    }));
+    context.interrupt_handle().request_interrupt();
+    let err = context
+        .eval(Source::from_bytes("1 + 1"))
+        .unwrap_err()
+        .as_native()
+        .cloned()
+        .unwrap();
+    assert!(err.is_terminated());
+    assert_eq!(
+        err.message(),
+        "cannot pause the execution, since there is no other handle to resume it"
+    );
+
+    // The context can still be used afterwards.
+    assert_eq!(
+        context.eval(Source::from_bytes("1 + 1")).unwrap(),
+        JsValue::new(2)
+    );
+}
+
+#[test]
+fn heap_runtime_limit() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            let limit =
+                boa_gc::bytes_allocated() + boa_string::bytes_allocated() + 16 * 1024 * 1024;
+            context.runtime_limits_mut().set_heap_size_limit(limit);
+        }),
+        TestAction::assert_native_error(
+            "let s = 'x'; while (true) { s += s; }",
+            JsNativeErrorKind::Range,
+            "out of memory: exceeded the heap size limit",
+        ),
+        // The error can be caught, and the context is still usable afterwards.
+        TestAction::assert_eq(
+            indoc! {r#"
+                const array = [];
+                try {
+                    while (true) { array.push(0); }
+                } catch (e) {
+                    e instanceof RangeError && array.length > 0;
+                }
+            "#},
+            JsValue::new(true),
+        ),
+        // Elements stored past the end of an array make it sparse, so they are not charged as
+        // dense storage.
+        TestAction::assert_eq(
+            "const sparse = []; sparse[4294967294] = 1; sparse.length === 4294967295",
+            JsValue::new(true),
+        ),
+        TestAction::assert_eq("'a'.repeat(3)", js_str!("aaa")),
+        TestAction::assert_native_error(
+            "'a'.repeat(2 ** 30)",
+            JsNativeErrorKind::Range,
+            "out of memory: exceeded the heap size limit",
+        ),
+    ]);
+}
+
+#[test]
+fn heap_limit_counts_objects() {
+    for code in [
+        "let o = null; for (;;) o = { next: o };",
+        "let f = null; for (;;) { const g = f; f = () => g; }",
+        "const map = new Map(); for (let i = 0; ; i++) map.set(i, i);",
+        "const set = new Set(); for (let i = 0; ; i++) set.add(i);",
+    ] {
+        run_test_actions([
+            TestAction::inspect_context(|context| {
+                let limit =
+                    boa_gc::bytes_allocated() + boa_string::bytes_allocated() + 16 * 1024 * 1024;
+                context.runtime_limits_mut().set_heap_size_limit(limit);
+            }),
+            TestAction::assert_native_error(
+                code,
+                JsNativeErrorKind::Range,
+                "out of memory: exceeded the heap size limit",
+            ),
+        ]);
+    }
+}
+
+#[test]
+fn heap_limit_counts_strings() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            let limit = boa_gc::bytes_allocated() + boa_string::bytes_allocated() + 7 * 512 * 1024;
+            context.runtime_limits_mut().set_heap_size_limit(limit);
+        }),
+        // Every string is below the limit on its own, but the live strings are not.
+        TestAction::assert_native_error(
+            indoc! {r#"
+                const a = 'a'.repeat(1024 * 1024);
+                const b = 'b'.repeat(1024 * 1024);
+                const c = 'c'.repeat(1024 * 1024);
+            "#},
+            JsNativeErrorKind::Range,
+            "out of memory: exceeded the heap size limit",
+        ),
+    ]);
+}
+
+#[test]
+fn near_heap_limit_hook() {
+    use crate::context::{ContextBuilder, HostHooks};
+    use std::{cell::Cell, rc::Rc};
+
+    #[derive(Default)]
+    struct Hooks {
+        calls: Cell<usize>,
+    }
+
+    impl HostHooks for Hooks {
+        fn near_heap_limit(&self, used: usize, limit: usize, _context: &mut Context) -> usize {
+            self.calls.set(self.calls.get() + 1);
+            // Only raise the limit the first time.
+            if self.calls.get() == 1 {
+                used
+            } else {
+                limit
+            }
+        }
+    }
+
+    let hooks = Rc::new(Hooks::default());
+    let context = &mut ContextBuilder::new()
+        .host_hooks(hooks.clone())
+        .build()
+        .unwrap();
+    let limit = boa_gc::bytes_allocated() + 1024 * 1024;
+    context.runtime_limits_mut().set_heap_size_limit(limit);
+
+    assert_eq!(
+        context
+            .eval(Source::from_bytes("'a'.repeat(1024 * 1024).length"))
+            .unwrap(),
+        JsValue::new(1024 * 1024)
+    );
+    assert_eq!(hooks.calls.get(), 1);
+    assert!(context.runtime_limits().heap_size_limit() > limit);
+
+    assert!(context
+        .eval(Source::from_bytes("'a'.repeat(16 * 1024 * 1024)"))
+        .is_err());
+    assert_eq!(hooks.calls.get(), 2);
+}
+
 #[test]
 fn arguments_object_constructor_valid_index() {
     run_test_actions([TestAction::assert_eq(
@@ -419,6 +694,145 @@ fn cross_context_funtion_call() {
     assert_eq!(result, Ok(JsValue::new(100)));
 }
 
+#[test]
+fn thrown_error_location() {
+    let context = &mut Context::default();
+    let error = context
+        .eval(Source::from_bytes(indoc! {r#"
+            function f() {
+                throw new Error("oops");
+            }
+            f();
+        "#}))
+        .unwrap_err();
+
+    let location = error.location().unwrap();
+    assert!(location.path().is_none());
+    let position = location.position().unwrap();
+    assert_eq!(position.line_number(), 2);
+    assert_eq!(position.column_number(), 5);
+    assert_eq!(location.to_string(), "<anonymous>:2:5");
+}
+
+#[test]
+fn native_error_location() {
+    let context = &mut Context::default();
+    let error = context
+        .eval(Source::from_bytes(indoc! {r#"
+            let a = undefined;
+
+            a.foo();
+        "#}))
+        .unwrap_err();
+
+    let native = error.as_native().unwrap();
+    assert_eq!(native.kind, JsNativeErrorKind::Type);
+    let position = native
+        .location()
+        .and_then(SourceLocation::position)
+        .unwrap();
+    assert_eq!(position.line_number(), 3);
+    assert_eq!(position.column_number(), 1);
+}
+
+#[test]
+fn statement_error_location() {
+    let context = &mut Context::default();
+    let error = context
+        .eval(Source::from_bytes(indoc! {r#"
+            let a = null;
+            if (true) {
+                a.foo = 1;
+            }
+        "#}))
+        .unwrap_err();
+
+    let position = error
+        .as_native()
+        .unwrap()
+        .location()
+        .and_then(SourceLocation::position)
+        .unwrap();
+    assert_eq!(position.line_number(), 3);
+    assert_eq!(position.column_number(), 5);
+
+    let error = context
+        .eval(Source::from_bytes(indoc! {r#"
+            let b = 1;
+
+            const c = b.foo.bar;
+        "#}))
+        .unwrap_err();
+
+    let position = error
+        .as_native()
+        .unwrap()
+        .location()
+        .and_then(SourceLocation::position)
+        .unwrap();
+    assert_eq!(position.line_number(), 3);
+    assert_eq!(position.column_number(), 1);
+}
+
+#[test]
+fn thrown_error_backtrace() {
+    let context = &mut Context::default();
+    let error = context
+        .eval(Source::from_bytes(indoc! {r#"
+            function inner() {
+                throw new Error("oops");
+            }
+            function outer() { [0].map(function () { inner(); }); }
+            outer();
+        "#}))
+        .unwrap_err();
+
+    let backtrace = error.backtrace().unwrap();
+    let names: Vec<_> = backtrace
+        .iter()
+        .map(|frame| frame.function_name().map(JsString::to_std_string_escaped))
+        .collect();
+    assert_eq!(
+        names,
+        [
+            Some("inner".to_owned()),
+            None,
+            Some("outer".to_owned()),
+            None
+        ]
+    );
+    let lines: Vec<_> = backtrace
+        .iter()
+        .map(|frame| frame.location().position().unwrap().line_number())
+        .collect();
+    assert_eq!(lines, [2, 4, 4, 5]);
+    assert_eq!(error.location(), Some(backtrace[0].location()));
+}
+
+#[test]
+fn rethrown_error_backtrace() {
+    let context = &mut Context::default();
+    let error = context
+        .eval(Source::from_bytes(indoc! {r#"
+            function f() {
+                try {
+                    throw new Error("oops");
+                } finally {
+                    globalThis.done = true;
+                }
+            }
+            f();
+        "#}))
+        .unwrap_err();
+
+    let backtrace = error.backtrace().unwrap();
+    let names: Vec<_> = backtrace
+        .iter()
+        .map(|frame| frame.function_name().map(JsString::to_std_string_escaped))
+        .collect();
+    assert_eq!(names, [Some("f".to_owned()), None]);
+}
+
 // See: https://github.com/boa-dev/boa/issues/1848
 #[test]
 fn long_object_chain_gc_trace_stack_overflow() {
@@ -0,0 +1,142 @@
+#![allow(unused_crate_dependencies, missing_docs)]
+
+use boa_engine::builtins::promise::PromiseState;
+use boa_engine::{js_string, Context, JsValue, Module, Script, Source};
+
+const SCRIPT: &str = r"
+    let counter = 0;
+    const base = 10n;
+    function add(a, b = 1) {
+        counter += 1;
+        return a + b;
+    }
+    class Point {
+        #x;
+        constructor(x) { this.#x = x; }
+        get x() { return this.#x; }
+    }
+    var result;
+    try {
+        result = [1, 2, 3].map((x) => add(x, new Point(x).x)).join();
+    } catch (e) {
+        result = e;
+    }
+    `${result}:${counter}:${base * 2n}:${'cached'}`;
+";
+
+#[test]
+fn script_cache_round_trip() {
+    let cache = {
+        let context = &mut Context::default();
+        let script = Script::parse(Source::from_bytes(SCRIPT), None, context).unwrap();
+        let cache = script.to_cache(context).unwrap();
+        assert_eq!(
+            script.evaluate(context).unwrap(),
+            JsValue::from(js_string!("2,4,6:3:20:cached"))
+        );
+        cache
+    };
+
+    let context = &mut Context::default();
+    let script = Script::from_cache(&cache, Source::from_bytes(SCRIPT), None, context).unwrap();
+    assert_eq!(
+        script.evaluate(context).unwrap(),
+        JsValue::from(js_string!("2,4,6:3:20:cached"))
+    );
+
+    // The global lexical bindings of the script are created when it is loaded.
+    let counter = Script::parse(Source::from_bytes("counter"), None, context).unwrap();
+    assert_eq!(counter.evaluate(context).unwrap(), JsValue::new(3));
+
+    // A cache can also be created from a script loaded from a cache.
+    assert_eq!(script.to_cache(context).unwrap(), cache);
+}
+
+#[test]
+fn script_cache_mismatch() {
+    let context = &mut Context::default();
+    let script = Script::parse(Source::from_bytes("1 + 1"), None, context).unwrap();
+    let cache = script.to_cache(context).unwrap();
+
+    let context = &mut Context::default();
+    assert!(Script::from_cache(&cache, Source::from_bytes("1 + 2"), None, context).is_err());
+    assert!(Script::from_cache(&cache[1..], Source::from_bytes("1 + 1"), None, context).is_err());
+    assert!(Module::from_cache(&cache, Source::from_bytes("1 + 1"), None, context).is_err());
+}
+
+#[test]
+fn script_cache_corrupted() {
+    let context = &mut Context::default();
+    let script = Script::parse(Source::from_bytes(SCRIPT), None, context).unwrap();
+    let cache = script.to_cache(context).unwrap();
+
+    let context = &mut Context::default();
+    let truncated = &cache[..cache.len() - 1];
+    assert!(Script::from_cache(truncated, Source::from_bytes(SCRIPT), None, context).is_err());
+
+    for index in (cache.len() / 2..cache.len()).step_by(7) {
+        let mut corrupted = cache.clone();
+        corrupted[index] ^= 0xFF;
+        assert!(Script::from_cache(&corrupted, Source::from_bytes(SCRIPT), None, context).is_err());
+    }
+}
+
+#[test]
+fn script_cache_redeclaration() {
+    let context = &mut Context::default();
+    let script = Script::parse(Source::from_bytes("let x = 1;"), None, context).unwrap();
+    let cache = script.to_cache(context).unwrap();
+
+    let context = &mut Context::default();
+    Script::parse(Source::from_bytes("let x = 2;"), None, context)
+        .unwrap()
+        .evaluate(context)
+        .unwrap();
+    assert!(Script::from_cache(&cache, Source::from_bytes("let x = 1;"), None, context).is_err());
+}
+
+const MODULE: &str = r"
+    let calls = 0;
+    export function double(x) {
+        calls += 1;
+        return helper(x) * 2;
+    }
+    function helper(x) { return x; }
+    export const value = await Promise.resolve(double(21));
+    export { calls };
+    export default class {}
+";
+
+#[test]
+fn module_cache_round_trip() {
+    let cache = {
+        let context = &mut Context::default();
+        let module = Module::parse(Source::from_bytes(MODULE), None, context).unwrap();
+        module.to_cache(context).unwrap()
+    };
+
+    let context = &mut Context::default();
+    let module = Module::from_cache(&cache, Source::from_bytes(MODULE), None, context).unwrap();
+    let promise = module.load_link_evaluate(context);
+    context.run_jobs().unwrap();
+
+    match promise.state() {
+        PromiseState::Fulfilled(v) => assert!(v.is_undefined()),
+        PromiseState::Pending => panic!("module didn't finish evaluating"),
+        PromiseState::Rejected(e) => panic!("Unexpected error: {}", e.display()),
+    }
+
+    let namespace = module.namespace(context);
+    assert_eq!(
+        namespace.get(js_string!("value"), context).unwrap(),
+        JsValue::new(42)
+    );
+    assert_eq!(
+        namespace.get(js_string!("calls"), context).unwrap(),
+        JsValue::new(1)
+    );
+    assert!(namespace
+        .get(js_string!("default"), context)
+        .unwrap()
+        .is_callable());
+}
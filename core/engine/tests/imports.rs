@@ -45,3 +45,66 @@ fn subdirectories() {
         }
     }
 }
+
+fn load_module(source: &[u8]) -> (boa_engine::Module, PromiseState, Context) {
+    let assets_dir =
+        PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("tests/assets");
+
+    let loader = Rc::new(SimpleModuleLoader::new(assets_dir).unwrap());
+    let mut context = Context::builder().module_loader(loader).build().unwrap();
+
+    let module = boa_engine::Module::parse(Source::from_bytes(source), None, &mut context).unwrap();
+    let result = module.load_link_evaluate(&mut context);
+    context.run_jobs().unwrap();
+
+    (module, result.state(), context)
+}
+
+/// Test that requests with the same specifier but different import attributes load different
+/// modules.
+#[test]
+fn same_specifier_with_different_attributes() {
+    let (module, state, mut context) = load_module(
+        br#"
+        import * as js from "string.json";
+        import json from "string.json" with { type: "json" };
+        export const result = typeof js + " " + json;
+        "#,
+    );
+
+    if let PromiseState::Rejected(reason) = state {
+        panic!("Module failed to load: {}", reason.display());
+    }
+    let result = module
+        .namespace(&mut context)
+        .get(js_string!("result"), &mut context)
+        .unwrap();
+    assert_eq!(result, js_string!("object json").into());
+}
+
+/// Test that a JSON module cannot be imported without the `type: "json"` attribute.
+#[test]
+fn json_module_requires_type() {
+    let (module, state, mut context) =
+        load_module(br#"export { default as data } from "data.json" with { type: "json" };"#);
+    if let PromiseState::Rejected(reason) = state {
+        panic!("Module failed to load: {}", reason.display());
+    }
+    let answer = module
+        .namespace(&mut context)
+        .get(js_string!("data"), &mut context)
+        .unwrap()
+        .as_object()
+        .unwrap()
+        .get(js_string!("answer"), &mut context)
+        .unwrap();
+    assert_eq!(answer, JsValue::from(42));
+
+    let (_, state, _) = load_module(
+        br#"
+        import json from "data.json" with { type: "json" };
+        import js from "data.json";
+        "#,
+    );
+    assert!(matches!(state, PromiseState::Rejected(_)));
+}
@@ -3,7 +3,7 @@
 use std::rc::Rc;
 
 use boa_engine::builtins::promise::PromiseState;
-use boa_engine::module::{ModuleLoader, Referrer};
+use boa_engine::module::{ModuleLoader, ModuleRequest, ModuleType, Referrer};
 use boa_engine::{js_string, Context, JsResult, JsString, Module, Source};
 
 #[test]
@@ -13,11 +13,11 @@ fn test_json_module_from_str() {
         fn load_imported_module(
             &self,
             _referrer: Referrer,
-            specifier: JsString,
+            request: ModuleRequest,
             finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
             context: &mut Context,
         ) {
-            assert_eq!(specifier.to_std_string_escaped(), "basic");
+            assert_eq!(request.specifier().to_std_string_escaped(), "basic");
 
             finish_load(
                 Ok(Module::parse_json(self.0.clone(), context).unwrap()),
@@ -63,3 +63,85 @@ fn test_json_module_from_str() {
         json_string
     );
 }
+
+#[test]
+fn test_json_module_with_import_attributes() {
+    struct TestModuleLoader(JsString);
+    impl ModuleLoader for TestModuleLoader {
+        fn load_imported_module(
+            &self,
+            _referrer: Referrer,
+            request: ModuleRequest,
+            finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
+            context: &mut Context,
+        ) {
+            let module = match request.module_type() {
+                Ok(ModuleType::Json) => Module::parse_json(self.0.clone(), context),
+                Ok(ModuleType::JavaScript) => Module::parse(
+                    Source::from_bytes(b"export default 'javascript';"),
+                    None,
+                    context,
+                ),
+                Err(err) => Err(err),
+            };
+            finish_load(module, context);
+        }
+    }
+
+    let json_string = js_string!(r#"{"key":"value"}"#);
+    let mut context = Context::builder()
+        .module_loader(Rc::new(TestModuleLoader(json_string)))
+        .build()
+        .unwrap();
+
+    let source = Source::from_bytes(
+        b"
+        import json from 'data.json' with { type: 'json' };
+        import js from 'data.js';
+        export const key = json.key;
+        export { js };
+        export const dynamic = import('other.json', { with: { type: 'json' } })
+            .then((ns) => ns.default.key);
+        export const css = import('style.css', { with: { type: 'css' } })
+            .catch((e) => e.name);
+        export const unsupported = import('other.json', { with: { kind: 'json' } })
+            .catch((e) => e.name);
+    ",
+    );
+
+    let module = Module::parse(source, None, &mut context).unwrap();
+    let promise = module.load_link_evaluate(&mut context);
+    context.run_jobs().unwrap();
+
+    if let PromiseState::Rejected(e) = promise.state() {
+        panic!("Unexpected error: {:?}", e.to_string(&mut context).unwrap());
+    }
+
+    let namespace = module.namespace(&mut context);
+    let mut get = |name: JsString| {
+        let value = namespace.get(name, &mut context).unwrap();
+        match value.as_promise() {
+            Some(promise) => match promise.state() {
+                PromiseState::Fulfilled(v) => v,
+                state => panic!("unexpected promise state: {state:?}"),
+            },
+            None => value,
+        }
+    };
+
+    assert_eq!(get(js_string!("key")), js_string!("value").into());
+    assert_eq!(get(js_string!("js")), js_string!("javascript").into());
+    assert_eq!(get(js_string!("dynamic")), js_string!("value").into());
+    assert_eq!(get(js_string!("css")), js_string!("TypeError").into());
+    assert_eq!(
+        get(js_string!("unsupported")),
+        js_string!("TypeError").into()
+    );
+}
+
+#[test]
+fn test_unsupported_import_attribute() {
+    let mut context = Context::default();
+    let source = Source::from_bytes(b"import json from 'data.json' with { kind: 'json' };");
+    assert!(Module::parse(source, None, &mut context).is_err());
+}
@@ -0,0 +1,238 @@
+#![allow(unused_crate_dependencies, missing_docs)]
+
+use boa_engine::{
+    context::ContextBuilder,
+    js_string,
+    native_function::NativeFunction,
+    object::{FunctionObjectBuilder, ObjectInitializer},
+    property::Attribute,
+    snapshot::{HostObjects, Snapshot},
+    Context, JsNativeErrorKind, JsObject, JsString, JsValue, Source,
+};
+
+const SETUP: &str = r"
+    var greeting = 'Hello';
+    let counter = 0;
+    const symbol = Symbol('tag');
+    function increment() {
+        counter += 1;
+        return counter;
+    }
+    class Counter {
+        #count = 0;
+        static created = 0;
+        constructor() { Counter.created += 1; }
+        next() { return ++this.#count; }
+        static #secret() { return 'secret'; }
+        static reveal() { return Counter.#secret(); }
+    }
+    const makeAdder = (a) => (b) => a + b;
+    var addTen = makeAdder(10);
+    var shared = { symbol, nested: { list: [1, 2.5, 'three', 4n] } };
+    shared.self = shared;
+    var counterInstance = new Counter();
+    counterInstance.next();
+    Array.prototype.sum = function () { return this.reduce((a, b) => a + b, 0); };
+    var map = new Map([[1, 'one'], [shared, 'shared']]);
+    var set = new Set(['a', 'b']);
+    var date = new Date(0);
+    var regexp = /b+/g;
+    var error = new RangeError('out of range');
+    var bound = increment.bind(null);
+";
+
+fn snapshot() -> Snapshot {
+    let context = &mut Context::default();
+    context.eval(Source::from_bytes(SETUP)).unwrap();
+    context.snapshot().unwrap()
+}
+
+fn eval(context: &mut Context, code: &str) -> JsValue {
+    context.eval(Source::from_bytes(code)).unwrap()
+}
+
+#[test]
+fn snapshot_round_trip() {
+    let snapshot = snapshot();
+    let context = &mut ContextBuilder::new().snapshot(snapshot).build().unwrap();
+
+    assert_eq!(
+        eval(context, "greeting"),
+        JsValue::from(js_string!("Hello"))
+    );
+    assert_eq!(eval(context, "increment()"), JsValue::new(1));
+    assert_eq!(eval(context, "bound()"), JsValue::new(2));
+    assert_eq!(eval(context, "counter"), JsValue::new(2));
+    assert_eq!(eval(context, "addTen(5)"), JsValue::new(15));
+    assert_eq!(
+        eval(
+            context,
+            "shared.self === shared && shared.symbol === symbol"
+        ),
+        JsValue::new(true)
+    );
+    assert_eq!(
+        eval(context, "shared.nested.list.join()"),
+        JsValue::from(js_string!("1,2.5,three,4"))
+    );
+
+    // Private names are still shared between the restored class and its instances.
+    assert_eq!(eval(context, "counterInstance.next()"), JsValue::new(2));
+    assert_eq!(eval(context, "new Counter().next()"), JsValue::new(1));
+    assert_eq!(eval(context, "Counter.created"), JsValue::new(2));
+    assert_eq!(
+        eval(context, "Counter.reveal()"),
+        JsValue::from(js_string!("secret"))
+    );
+    assert_eq!(
+        eval(context, "counterInstance instanceof Counter"),
+        JsValue::new(true)
+    );
+
+    // Const bindings stay immutable.
+    let err = context.eval(Source::from_bytes("symbol = 1")).unwrap_err();
+    assert!(matches!(
+        err.try_native(context).unwrap().kind,
+        JsNativeErrorKind::Type
+    ));
+}
+
+#[test]
+fn snapshot_builtins() {
+    let snapshot = snapshot();
+    let context = &mut ContextBuilder::new().snapshot(snapshot).build().unwrap();
+
+    assert_eq!(eval(context, "[1, 2, 3].sum()"), JsValue::new(6));
+    assert_eq!(
+        eval(context, "map.get(1)"),
+        JsValue::from(js_string!("one"))
+    );
+    assert_eq!(
+        eval(context, "map.get(shared)"),
+        JsValue::from(js_string!("shared"))
+    );
+    assert_eq!(eval(context, "set.has('b') && set.size"), JsValue::new(2));
+    assert_eq!(eval(context, "date.getTime()"), JsValue::new(0));
+    assert_eq!(
+        eval(context, "'abbbc'.match(regexp)[0]"),
+        JsValue::from(js_string!("bbb"))
+    );
+    assert_eq!(
+        eval(context, "error instanceof RangeError && error.message"),
+        JsValue::from(js_string!("out of range"))
+    );
+}
+
+#[test]
+fn snapshot_unsupported_objects() {
+    let context = &mut Context::default();
+    context
+        .eval(Source::from_bytes("var promise = Promise.resolve(1);"))
+        .unwrap();
+    let err = context.snapshot().unwrap_err();
+    assert!(matches!(
+        err.as_native().map(|err| &err.kind),
+        Some(JsNativeErrorKind::Type)
+    ));
+
+    for code in [
+        "var proxy = new Proxy({}, {});",
+        "var generator = (function* () {})();",
+    ] {
+        let context = &mut Context::default();
+        context.eval(Source::from_bytes(code)).unwrap();
+        assert!(context.snapshot().is_err(), "{code}");
+    }
+
+    let context = &mut Context::default();
+    context
+        .register_global_builtin_callable(
+            js_string!("host"),
+            0,
+            NativeFunction::from_fn_ptr(|_, _, _| Ok(JsValue::undefined())),
+        )
+        .unwrap();
+    assert!(context.snapshot().is_err());
+
+    // Host properties that are not functions can be stored.
+    let context = &mut Context::default();
+    context
+        .register_global_property(js_string!("answer"), 42, Attribute::all())
+        .unwrap();
+    let snapshot = context.snapshot().unwrap();
+    let context = &mut ContextBuilder::new().snapshot(snapshot).build().unwrap();
+    assert_eq!(eval(context, "answer"), JsValue::new(42));
+}
+
+#[test]
+fn snapshot_invalid_bytes() {
+    let invalid = Snapshot::from_bytes(vec![1, 2, 3]);
+    assert!(ContextBuilder::new().snapshot(invalid).build().is_err());
+
+    let mut bytes = snapshot().into_bytes();
+    bytes.pop();
+    assert!(ContextBuilder::new()
+        .snapshot(Snapshot::from_bytes(bytes))
+        .build()
+        .is_err());
+}
+
+#[test]
+fn snapshot_host_objects() {
+    fn host_objects(context: &mut Context, prefix: &'static str) -> (JsObject, HostObjects) {
+        let log = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure(move |_, args, context| {
+                let message = args.first().cloned().unwrap_or_default();
+                let message = message.to_string(context)?.to_std_string_escaped();
+                Ok(JsValue::from(JsString::from(format!("{prefix}{message}"))))
+            }),
+        )
+        .name(js_string!("log"))
+        .build();
+        let console = ObjectInitializer::new(context)
+            .property(js_string!("log"), log.clone(), Attribute::all())
+            .build();
+
+        let mut host_objects = HostObjects::new();
+        host_objects.insert(js_string!("console"), console.clone());
+        host_objects.insert(js_string!("log"), log.into());
+        (console, host_objects)
+    }
+
+    let context = &mut Context::default();
+    let (console, host) = host_objects(context, "created: ");
+    context
+        .register_global_property(js_string!("console"), console, Attribute::all())
+        .unwrap();
+    context
+        .eval(Source::from_bytes(
+            "var log = console.log; function greet(name) { return console.log(`Hello, ${name}!`); }",
+        ))
+        .unwrap();
+
+    // The host objects are only stored by their identifier.
+    assert!(context.snapshot().is_err());
+    let snapshot = context.snapshot_with(&host).unwrap();
+
+    let context = &mut Context::default();
+    assert!(context
+        .restore_snapshot(&snapshot, &HostObjects::new())
+        .is_err());
+
+    let context = &mut Context::default();
+    let (console, host) = host_objects(context, "restored: ");
+    context.restore_snapshot(&snapshot, &host).unwrap();
+    assert_eq!(
+        eval(context, "greet('snapshot')"),
+        JsValue::from(js_string!("restored: Hello, snapshot!"))
+    );
+    assert_eq!(eval(context, "log === console.log"), JsValue::new(true));
+    assert_eq!(
+        context
+            .global_object()
+            .get(js_string!("console"), context)
+            .unwrap(),
+        JsValue::from(console)
+    );
+}
@@ -124,6 +124,10 @@ pub(crate) trait ErasedEphemeronBox {
     /// "successfully traced".
     unsafe fn trace(&self, tracer: &mut Tracer) -> bool;
 
+    /// Enqueues the `EphemeronBox`'s key, if it still has one, to keep it alive during a minor
+    /// collection.
+    unsafe fn trace_key(&self, tracer: &mut Tracer);
+
     fn trace_non_roots(&self);
 
     /// Runs the finalization logic of the `EphemeronBox`'s held value, if the key is still live,
@@ -163,6 +167,14 @@ impl<K: Trace + ?Sized, V: Trace> ErasedEphemeronBox for EphemeronBox<K, V> {
         is_key_marked
     }
 
+    unsafe fn trace_key(&self, tracer: &mut Tracer) {
+        // SAFETY: the garbage collector ensures the ephemeron doesn't mutate until
+        // finalization.
+        if let Some(key) = unsafe { self.key_ptr() } {
+            tracer.enqueue(key.cast());
+        }
+    }
+
     fn trace_non_roots(&self) {
         // SAFETY: Tracing always executes before collecting, meaning this cannot cause
         // use after free.
@@ -1,14 +1,16 @@
 use std::{cell::Cell, fmt};
 
 const MARK_MASK: u32 = 1 << (u32::BITS - 1);
-const NON_ROOTS_MASK: u32 = !MARK_MASK;
+const OLD_MASK: u32 = 1 << (u32::BITS - 2);
+const NON_ROOTS_MASK: u32 = !(MARK_MASK | OLD_MASK);
 const NON_ROOTS_MAX: u32 = NON_ROOTS_MASK;
 
 /// The `Gcheader` contains the `GcBox`'s and `EphemeronBox`'s current state for the `Collector`'s
 /// Mark/Sweep as well as a pointer to the next node in the heap.
 ///
 /// `ref_count` is the number of Gc instances, and `non_root_count` is the number of
-/// Gc instances in the heap. `non_root_count` also includes Mark Flag bit.
+/// Gc instances in the heap. `non_root_count` also includes Mark Flag bit and the Old Flag bit,
+/// which is set on the nodes that were promoted to the old generation.
 ///
 /// The next node is set by the `Allocator` during initialization and by the
 /// `Collector` during the sweep phase.
@@ -37,9 +39,17 @@ impl GcHeader {
     }
 
     /// Increments [`GcHeader`]'s non-roots count.
+    ///
+    /// The count of the nodes in the old generation is not incremented, since minor collections
+    /// only need the roots of the young generation. Full collections demote all the nodes before
+    /// counting their non-roots.
     pub(crate) fn inc_non_root_count(&self) {
         let non_root_count = self.non_root_count.get();
 
+        if non_root_count & OLD_MASK != 0 {
+            return;
+        }
+
         if (non_root_count & NON_ROOTS_MASK) < NON_ROOTS_MAX {
             self.non_root_count.set(non_root_count.wrapping_add(1));
         } else {
@@ -91,12 +101,30 @@ impl GcHeader {
         self.non_root_count
             .set(self.non_root_count.get() & !MARK_MASK);
     }
+
+    /// Returns a bool for whether [`GcHeader`]'s old bit is 1.
+    pub(crate) fn is_old(&self) -> bool {
+        self.non_root_count.get() & OLD_MASK != 0
+    }
+
+    /// Sets [`GcHeader`]'s old bit to 1, moving the node to the old generation.
+    pub(crate) fn promote(&self) {
+        self.non_root_count
+            .set(self.non_root_count.get() | OLD_MASK);
+    }
+
+    /// Sets [`GcHeader`]'s old bit to 0, moving the node back to the young generation.
+    pub(crate) fn demote(&self) {
+        self.non_root_count
+            .set(self.non_root_count.get() & !OLD_MASK);
+    }
 }
 
 impl fmt::Debug for GcHeader {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("GcHeader")
             .field("marked", &self.is_marked())
+            .field("old", &self.is_old())
             .field("ref_count", &self.ref_count.get())
             .field("non_root_count", &self.non_root_count())
             .finish_non_exhaustive()
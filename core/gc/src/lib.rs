@@ -3,6 +3,10 @@
 //! # Crate Overview
 //! **`boa_gc`** is a mark-sweep garbage collector that implements a [`Trace`] and [`Finalize`] trait
 //! for garbage collected values.
+//!
+//! The garbage collector can optionally allocate new values in a young generation, which is
+//! collected by minor collections that don't trace the rest of the heap. See [`GcConfig`] for the
+//! configuration of the collector, and [`GcStatistics`] for the pause times of the collections.
 #![doc = include_str!("../ABOUT.md")]
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/boa-dev/boa/main/assets/logo_black.svg",
@@ -30,6 +34,7 @@ use std::{
     cell::{Cell, RefCell},
     mem,
     ptr::NonNull,
+    time::Duration,
 };
 
 pub use crate::trace::{Finalize, Trace, Tracer};
@@ -47,35 +52,119 @@ thread_local!(static BOA_GC: RefCell<BoaGc> = RefCell::new( BoaGc {
     config: GcConfig::default(),
     runtime: GcRuntimeData::default(),
     strongs: Vec::default(),
+    young_start: 0,
     weaks: Vec::default(),
     weak_maps: Vec::default(),
 }));
 
-#[derive(Debug, Clone, Copy)]
-struct GcConfig {
-    /// The threshold at which the garbage collector will trigger a collection.
-    threshold: usize,
-    /// The percentage of used space at which the garbage collector will trigger a collection.
-    used_space_percentage: usize,
+/// The configuration of the garbage collector of the current thread.
+///
+/// The configuration is obtained with [`config`] and changed with [`set_config`].
+///
+/// # Generational collection
+///
+/// If [`GcConfig::generational`] is enabled, the values that survive a collection are promoted
+/// to an old generation. Then, after [`GcConfig::young_threshold`] bytes are allocated, a minor
+/// collection drops the unreachable values that were allocated since the last collection, without
+/// tracing the old generation. This makes most pauses much shorter for programs that allocate a
+/// lot of short-lived values. The old generation is only collected by full collections, which
+/// run after [`GcConfig::threshold`] bytes are allocated.
+///
+/// The values of the young generation that are referenced by the old generation are found with
+/// the reference counts of the [`Gc`] pointers, so no write barriers are needed. Values of the old
+/// generation and values referenced by an [`Ephemeron`] are always kept alive by minor
+/// collections.
+///
+/// # Examples
+///
+/// ```
+/// let mut config = boa_gc::config();
+/// config.generational = true;
+/// config.young_threshold = 512 * 1024;
+/// boa_gc::set_config(config);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct GcConfig {
+    /// The number of allocated bytes at which the garbage collector runs a full collection.
+    ///
+    /// The threshold is increased after a collection if the bytes that are still allocated are
+    /// above [`GcConfig::used_space_percentage`] of the threshold.
+    ///
+    /// Defaults to 1MB.
+    pub threshold: usize,
+
+    /// The percentage of the threshold that can be used after a full collection before the
+    /// threshold is increased.
+    ///
+    /// Defaults to 70.
+    pub used_space_percentage: usize,
+
+    /// Whether the garbage collector runs minor collections of the young generation.
+    ///
+    /// Defaults to `false`.
+    pub generational: bool,
+
+    /// The number of bytes allocated in the young generation at which the garbage collector runs
+    /// a minor collection, if [`GcConfig::generational`] is enabled.
+    ///
+    /// Defaults to 256KB.
+    pub young_threshold: usize,
 }
 
 // Setting the defaults to an arbitrary value currently.
-//
-// TODO: Add a configure later
 impl Default for GcConfig {
     fn default() -> Self {
         Self {
             // Start at 1MB, the nursary size for V8 is ~1-8MB and SM can be up to 16MB
             threshold: 1_048_576,
             used_space_percentage: 70,
+            generational: false,
+            young_threshold: 262_144,
         }
     }
 }
 
+/// Statistics of the collections run by the garbage collector of the current thread.
+///
+/// The statistics are obtained with [`statistics`]. The pause times are not measured on
+/// `wasm32-unknown-unknown`, where they are always zero.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct GcStatistics {
+    /// The number of full collections.
+    pub collections: usize,
+
+    /// The number of minor collections of the young generation.
+    pub minor_collections: usize,
+
+    /// The duration of the last collection.
+    pub last_pause: Duration,
+
+    /// The duration of the longest collection.
+    pub max_pause: Duration,
+
+    /// The sum of the durations of all the collections.
+    pub total_pause: Duration,
+}
+
 #[derive(Default, Debug, Clone, Copy)]
 struct GcRuntimeData {
     collections: usize,
+    minor_collections: usize,
     bytes_allocated: usize,
+    young_bytes_allocated: usize,
+    last_pause: Duration,
+    max_pause: Duration,
+    total_pause: Duration,
+}
+
+impl GcRuntimeData {
+    fn record_pause(&mut self, pause: Duration) {
+        self.last_pause = pause;
+        self.max_pause = self.max_pause.max(pause);
+        self.total_pause += pause;
+    }
 }
 
 #[derive(Debug)]
@@ -83,6 +172,9 @@ struct BoaGc {
     config: GcConfig,
     runtime: GcRuntimeData,
     strongs: Vec<GcErasedPointer>,
+    /// The index of the first node of the young generation in `strongs`. All the nodes before it
+    /// are in the old generation.
+    young_start: usize,
     weaks: Vec<EphemeronPointer>,
     weak_maps: Vec<ErasedWeakMapBoxPointer>,
 }
@@ -142,6 +234,7 @@ impl Allocator {
 
             gc.strongs.push(erased);
             gc.runtime.bytes_allocated += element_size;
+            gc.runtime.young_bytes_allocated += element_size;
 
             ptr
         })
@@ -192,7 +285,7 @@ impl Allocator {
 
     fn manage_state(gc: &mut BoaGc) {
         if gc.runtime.bytes_allocated > gc.config.threshold {
-            Collector::collect(gc);
+            Collector::run(gc, Collector::collect);
 
             // Post collection check
             // If the allocated bytes are still above the threshold, increase the threshold.
@@ -202,6 +295,10 @@ impl Allocator {
                 gc.config.threshold =
                     gc.runtime.bytes_allocated / gc.config.used_space_percentage * 100;
             }
+        } else if gc.config.generational
+            && gc.runtime.young_bytes_allocated > gc.config.young_threshold
+        {
+            Collector::run(gc, Collector::collect_young);
         }
     }
 }
@@ -223,14 +320,29 @@ struct Unreachables {
 /// A better approach in a more concurrent structure may be to reorder.
 ///
 /// Mark -> Sweep -> Finalize
+///
+/// Minor collections run the same phases, but only on the nodes of the young generation.
 struct Collector;
 
 impl Collector {
+    /// Runs a collection, recording its pause time.
+    fn run(gc: &mut BoaGc, collect: fn(&mut BoaGc)) {
+        let pause = measure(|| collect(gc));
+        gc.runtime.record_pause(pause);
+    }
+
     /// Run a collection on the full heap.
     fn collect(gc: &mut BoaGc) {
         let _timer = Profiler::global().start_event("Gc Full Collection", "gc");
         gc.runtime.collections += 1;
 
+        // Demote the old generation, since a full collection needs the non-roots count of all the
+        // nodes.
+        for node in &gc.strongs[..gc.young_start] {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            unsafe { node.as_ref() }.header.demote();
+        }
+
         Self::trace_non_roots(gc);
 
         let mut tracer = Tracer::new();
@@ -256,9 +368,18 @@ impl Collector {
                 &mut gc.strongs,
                 &mut gc.weaks,
                 &mut gc.runtime.bytes_allocated,
+                gc.config.generational,
             );
         }
 
+        // All the surviving nodes were promoted to the old generation.
+        gc.young_start = if gc.config.generational {
+            gc.strongs.len()
+        } else {
+            0
+        };
+        gc.runtime.young_bytes_allocated = 0;
+
         // Weak maps have to be cleared after the sweep, since the process dereferences GcBoxes.
         gc.weak_maps.retain(|w| {
             // SAFETY: The caller must ensure the validity of every node of `heap_start`.
@@ -283,6 +404,105 @@ impl Collector {
         gc.weak_maps.shrink_to(gc.weak_maps.len() >> 2);
     }
 
+    /// Run a collection on the young generation.
+    ///
+    /// The non-roots count of the old nodes is not incremented, so the young nodes referenced by
+    /// old nodes are roots of the collection. The old nodes are never traced, and are only
+    /// collected by a full collection.
+    fn collect_young(gc: &mut BoaGc) {
+        let _timer = Profiler::global().start_event("Gc Minor Collection", "gc");
+        gc.runtime.minor_collections += 1;
+
+        let young_start = gc.young_start;
+
+        for node in &gc.strongs[young_start..] {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            let trace_non_roots_fn = unsafe { node.as_ref() }.trace_non_roots_fn();
+
+            // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
+            unsafe {
+                trace_non_roots_fn(*node);
+            }
+        }
+
+        let mut tracer = Tracer::new();
+
+        // Ephemerons are only processed by full collections, so the young keys of the ephemerons
+        // are kept alive until they are promoted.
+        for eph in &gc.weaks {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            let eph_ref = unsafe { eph.as_ref() };
+
+            // SAFETY: the garbage collector ensures `eph_ref` always points to valid data.
+            unsafe {
+                eph_ref.trace_key(&mut tracer);
+                tracer.trace_until_empty();
+            }
+        }
+
+        let unreachables = Self::mark_young(&mut tracer, &gc.strongs[young_start..]);
+
+        if !unreachables.is_empty() {
+            // SAFETY: All passed pointers are valid, since we won't deallocate until `Self::sweep_strongs`.
+            unsafe {
+                Self::finalize(Unreachables {
+                    strong: unreachables,
+                    weak: Vec::new(),
+                });
+            }
+
+            let _final_unreachables = Self::mark_young(&mut tracer, &gc.strongs[young_start..]);
+        }
+
+        let mut young = gc.strongs.split_off(young_start);
+
+        // SAFETY: All the nodes of the young generation are valid per the invariants of our GC.
+        unsafe {
+            Self::sweep_strongs(&mut young, &mut gc.runtime.bytes_allocated, true);
+        }
+
+        gc.strongs.append(&mut young);
+        gc.young_start = gc.strongs.len();
+        gc.runtime.young_bytes_allocated = 0;
+
+        // Tracing the young nodes can mark ephemerons and count their non-roots, which must be
+        // reset for the next full collection.
+        for eph in &gc.weaks {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            let header = unsafe { eph.as_ref() }.header();
+            header.unmark();
+            header.reset_non_root_count();
+        }
+    }
+
+    /// Marks the nodes of the young generation reachable from its roots, returning the
+    /// unreachable nodes.
+    fn mark_young(tracer: &mut Tracer, young: &[GcErasedPointer]) -> Vec<GcErasedPointer> {
+        let _timer = Profiler::global().start_event("Gc Minor Marking", "gc");
+
+        let mut dead = Vec::new();
+        for node in young {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            let node_ref = unsafe { node.as_ref() };
+            if node_ref.is_rooted() {
+                tracer.enqueue(*node);
+
+                // SAFETY: all nodes must be valid as this phase cannot drop any node.
+                unsafe {
+                    tracer.trace_until_empty();
+                }
+            } else if !node_ref.is_marked() {
+                dead.push(*node);
+            }
+        }
+
+        dead.retain_mut(|node| {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            unsafe { !node.as_ref().is_marked() }
+        });
+        dead
+    }
+
     fn trace_non_roots(gc: &BoaGc) {
         // Count all the handles located in GC heap.
         // Then, we can find whether there is a reference from other places, and they are the roots.
@@ -456,16 +676,59 @@ impl Collector {
         strong: &mut Vec<GcErasedPointer>,
         weak: &mut Vec<EphemeronPointer>,
         total_allocated: &mut usize,
+        promote: bool,
     ) {
+        // SAFETY: The caller must ensure the validity of every node of `strong`.
+        unsafe {
+            Self::sweep_strongs(strong, total_allocated, promote);
+        }
+
         let _timer = Profiler::global().start_event("Gc Sweeping", "gc");
         let _guard = DropGuard::new();
 
-        strong.retain(|node| {
+        weak.retain(|eph| {
             // SAFETY: The caller must ensure the validity of every node of `heap_start`.
+            let eph_ref = unsafe { eph.as_ref() };
+            let header = eph_ref.header();
+            if header.is_marked() {
+                header.unmark();
+                header.reset_non_root_count();
+
+                true
+            } else {
+                // SAFETY: The algorithm ensures only unmarked/unreachable pointers are dropped.
+                // The caller must ensure all pointers were allocated by `Box::into_raw(Box::new(..))`.
+                let unmarked_eph = unsafe { Box::from_raw(eph.as_ptr()) };
+                let unallocated_bytes = size_of_val(&*unmarked_eph);
+                *total_allocated -= unallocated_bytes;
+
+                false
+            }
+        });
+    }
+
+    /// # Safety
+    ///
+    /// - Providing a list with invalid pointers will result in Undefined Behaviour.
+    /// - Providing a list of pointers that weren't allocated by `Box::into_raw(Box::new(..))`
+    ///   will result in Undefined Behaviour.
+    unsafe fn sweep_strongs(
+        strong: &mut Vec<GcErasedPointer>,
+        total_allocated: &mut usize,
+        promote: bool,
+    ) {
+        let _timer = Profiler::global().start_event("Gc Sweeping", "gc");
+        let _guard = DropGuard::new();
+
+        strong.retain(|node| {
+            // SAFETY: The caller must ensure the validity of every node of `strong`.
             let node_ref = unsafe { node.as_ref() };
             if node_ref.is_marked() {
                 node_ref.header.unmark();
                 node_ref.reset_non_root_count();
+                if promote {
+                    node_ref.header.promote();
+                }
 
                 true
             } else {
@@ -483,26 +746,6 @@ impl Collector {
                 false
             }
         });
-
-        weak.retain(|eph| {
-            // SAFETY: The caller must ensure the validity of every node of `heap_start`.
-            let eph_ref = unsafe { eph.as_ref() };
-            let header = eph_ref.header();
-            if header.is_marked() {
-                header.unmark();
-                header.reset_non_root_count();
-
-                true
-            } else {
-                // SAFETY: The algorithm ensures only unmarked/unreachable pointers are dropped.
-                // The caller must ensure all pointers were allocated by `Box::into_raw(Box::new(..))`.
-                let unmarked_eph = unsafe { Box::from_raw(eph.as_ptr()) };
-                let unallocated_bytes = size_of_val(&*unmarked_eph);
-                *total_allocated -= unallocated_bytes;
-
-                false
-            }
-        });
     }
 
     // Clean up the heap when BoaGc is dropped
@@ -540,17 +783,89 @@ impl Collector {
     }
 }
 
+/// Measures the duration of a collection.
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+fn measure(collect: impl FnOnce()) -> Duration {
+    let start = std::time::Instant::now();
+    collect();
+    start.elapsed()
+}
+
+/// Runs a collection without measuring its duration, since `Instant::now` panics on
+/// `wasm32-unknown-unknown`.
+#[cfg(all(target_family = "wasm", target_os = "unknown"))]
+fn measure(collect: impl FnOnce()) -> Duration {
+    collect();
+    Duration::ZERO
+}
+
 /// Forcefully runs a garbage collection of all unaccessible nodes.
 pub fn force_collect() {
     BOA_GC.with(|current| {
         let mut gc = current.borrow_mut();
 
         if gc.runtime.bytes_allocated > 0 {
-            Collector::collect(&mut gc);
+            Collector::run(&mut gc, Collector::collect);
+        }
+    });
+}
+
+/// Forcefully runs a minor garbage collection of the unaccessible nodes of the young generation.
+///
+/// This can be used to collect the short-lived values of a program when it is idle, even if
+/// [`GcConfig::generational`] is disabled. In that case, the surviving values are promoted to the
+/// old generation until the next full collection.
+pub fn force_collect_young() {
+    BOA_GC.with(|current| {
+        let mut gc = current.borrow_mut();
+
+        if gc.young_start < gc.strongs.len() {
+            Collector::run(&mut gc, Collector::collect_young);
         }
     });
 }
 
+/// Returns the configuration of the garbage collector of this thread.
+#[must_use]
+pub fn config() -> GcConfig {
+    BOA_GC.with(|current| current.borrow().config)
+}
+
+/// Sets the configuration of the garbage collector of this thread.
+///
+/// # Panics
+///
+/// Panics if [`GcConfig::used_space_percentage`] is zero.
+pub fn set_config(config: GcConfig) {
+    assert!(
+        config.used_space_percentage > 0,
+        "the used space percentage must not be zero"
+    );
+    BOA_GC.with(|current| current.borrow_mut().config = config);
+}
+
+/// Returns the statistics of the collections run by the garbage collector of this thread.
+#[must_use]
+pub fn statistics() -> GcStatistics {
+    BOA_GC.with(|current| {
+        let runtime = current.borrow().runtime;
+        GcStatistics {
+            collections: runtime.collections,
+            minor_collections: runtime.minor_collections,
+            last_pause: runtime.last_pause,
+            max_pause: runtime.max_pause,
+            total_pause: runtime.total_pause,
+        }
+    })
+}
+
+/// Returns the number of bytes currently allocated by the garbage collector of this thread,
+/// including the values that are unreachable but were not collected yet.
+#[must_use]
+pub fn bytes_allocated() -> usize {
+    BOA_GC.with(|current| current.borrow().runtime.bytes_allocated)
+}
+
 #[cfg(test)]
 mod test;
 
@@ -1,7 +1,7 @@
 use boa_macros::{Finalize, Trace};
 
 use super::{run_test, Harness};
-use crate::{force_collect, Gc, GcBox, GcRefCell};
+use crate::{bytes_allocated, force_collect, Gc, GcBox, GcRefCell};
 
 #[test]
 fn gc_basic_cell_allocation() {
@@ -32,6 +32,20 @@ fn gc_basic_pointer_alloc() {
     });
 }
 
+#[test]
+fn gc_bytes_allocated() {
+    run_test(|| {
+        assert_eq!(bytes_allocated(), 0);
+
+        let gc = Gc::new([0u64; 16]);
+        assert!(bytes_allocated() >= size_of::<[u64; 16]>());
+
+        drop(gc);
+        force_collect();
+        assert_eq!(bytes_allocated(), 0);
+    });
+}
+
 #[test]
 // Takes too long to finish in miri
 #[cfg_attr(miri, ignore)]
@@ -0,0 +1,146 @@
+use super::{run_test, Harness};
+use crate::{
+    config, force_collect, force_collect_young, set_config, statistics, Gc, GcBox, GcConfig,
+    GcRefCell, WeakGc,
+};
+
+fn enable_generational() {
+    let mut new_config = config();
+    new_config.generational = true;
+    set_config(new_config);
+}
+
+#[test]
+fn gc_config() {
+    run_test(|| {
+        assert_eq!(config(), GcConfig::default());
+        assert!(!config().generational);
+
+        let mut new_config = config();
+        new_config.generational = true;
+        new_config.young_threshold = 1024;
+        set_config(new_config);
+        assert_eq!(config(), new_config);
+    });
+}
+
+#[test]
+fn gc_minor_collection() {
+    run_test(|| {
+        enable_generational();
+
+        let live = Gc::new(1_u32);
+        let dead = Gc::new(2_u32);
+        drop(dead);
+
+        force_collect_young();
+        Harness::assert_collections(0);
+        assert_eq!(statistics().minor_collections, 1);
+        Harness::assert_exact_bytes_allocated(size_of::<GcBox<u32>>());
+        assert_eq!(*live, 1);
+
+        // The promoted value is only collected by a full collection.
+        drop(live);
+        force_collect_young();
+        Harness::assert_bytes_allocated();
+
+        force_collect();
+        Harness::assert_collections(1);
+        Harness::assert_empty_gc();
+    });
+}
+
+#[test]
+fn gc_minor_collection_old_to_young() {
+    run_test(|| {
+        enable_generational();
+
+        let old = Gc::new(GcRefCell::new(None::<Gc<u32>>));
+        force_collect();
+
+        *old.borrow_mut() = Some(Gc::new(5));
+        force_collect_young();
+        assert_eq!(old.borrow().as_deref(), Some(&5));
+
+        // The young value is still collected when it is only referenced by dead young values.
+        *old.borrow_mut() = None;
+        force_collect();
+        Harness::assert_exact_bytes_allocated(size_of::<GcBox<GcRefCell<Option<Gc<u32>>>>>());
+
+        let young = Gc::new(GcRefCell::new(None::<Gc<u32>>));
+        *young.borrow_mut() = Some(Gc::new(6));
+        drop(young);
+        force_collect_young();
+        Harness::assert_exact_bytes_allocated(size_of::<GcBox<GcRefCell<Option<Gc<u32>>>>>());
+
+        drop(old);
+        force_collect();
+        Harness::assert_empty_gc();
+    });
+}
+
+#[test]
+fn gc_minor_collection_young_to_old() {
+    run_test(|| {
+        enable_generational();
+
+        let old = Gc::new(7_u32);
+        force_collect();
+
+        let young = Gc::new(old.clone());
+        drop(old);
+        force_collect_young();
+        assert_eq!(**young, 7);
+
+        // Dropping the young value releases its reference to the old value.
+        drop(young);
+        force_collect_young();
+        force_collect();
+        Harness::assert_empty_gc();
+    });
+}
+
+#[test]
+fn gc_minor_collection_weak() {
+    run_test(|| {
+        enable_generational();
+
+        let young = Gc::new(3_u32);
+        let weak = WeakGc::new(&young);
+        drop(young);
+
+        // Weakly referenced young values are kept alive until they are promoted.
+        force_collect_young();
+        assert_eq!(weak.upgrade().as_deref(), Some(&3));
+
+        force_collect();
+        assert!(weak.upgrade().is_none());
+    });
+}
+
+#[test]
+fn gc_automatic_minor_collections() {
+    run_test(|| {
+        let mut new_config = config();
+        new_config.generational = true;
+        new_config.young_threshold = 1024;
+        set_config(new_config);
+
+        let root = Gc::new(GcRefCell::new(Vec::<Gc<u32>>::new()));
+        for i in 0..1_000_u32 {
+            let value = Gc::new(i);
+            if i % 10 == 0 {
+                root.borrow_mut().push(value);
+            }
+        }
+
+        let stats = statistics();
+        Harness::assert_collections(0);
+        assert!(stats.minor_collections > 0);
+        assert!(stats.last_pause <= stats.max_pause);
+        assert!(stats.max_pause <= stats.total_pause);
+
+        let values: Vec<u32> = root.borrow().iter().map(|value| **value).collect();
+        assert_eq!(values, (0..1_000).step_by(10).collect::<Vec<u32>>());
+    });
+}
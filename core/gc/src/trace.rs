@@ -36,13 +36,16 @@ impl Tracer {
 
     /// Traces through all the queued nodes until the queue is empty.
     ///
+    /// The nodes in the old generation are skipped, since they are only collected by full
+    /// collections, which demote all the nodes before tracing.
+    ///
     /// # Safety
     ///
     /// All the pointers inside of the queue must point to valid memory.
     pub(crate) unsafe fn trace_until_empty(&mut self) {
         while let Some(node) = self.queue.pop_front() {
             let node_ref = unsafe { node.as_ref() };
-            if node_ref.is_marked() {
+            if node_ref.is_marked() || node_ref.header.is_old() {
                 continue;
             }
             node_ref.header.mark();
@@ -117,6 +117,7 @@ static PROVIDER: Lazy<LocaleFallbackProvider<MultiForkByMarkerProvider<LazyBuffe
             provider_from_icu_crate!(icu_collator),
             provider_from_icu_crate!(icu_datetime),
             provider_from_icu_crate!(icu_decimal),
+            provider_from_icu_crate!(icu_experimental),
             provider_from_icu_crate!(icu_list),
             provider_from_icu_crate!(icu_locale),
             provider_from_icu_crate!(icu_normalizer),
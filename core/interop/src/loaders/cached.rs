@@ -1,6 +1,6 @@
 //! A module loader that caches modules once they're resolved.
-use boa_engine::module::{resolve_module_specifier, ModuleLoader, Referrer};
-use boa_engine::{Context, JsError, JsNativeError, JsResult, JsString, Module};
+use boa_engine::module::{resolve_module_specifier, ModuleLoader, ModuleRequest, Referrer};
+use boa_engine::{Context, JsError, JsNativeError, JsResult, Module};
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -39,31 +39,32 @@ where
     fn load_imported_module(
         &self,
         referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
-        let path = match resolve_module_specifier(None, &specifier, referrer.path(), context) {
-            Ok(path) => path,
-            Err(err) => {
-                finish_load(
-                    Err(JsError::from_native(
-                        JsNativeError::typ()
-                            .with_message("could not resolve module specifier")
-                            .with_cause(err),
-                    )),
-                    context,
-                );
-                return;
-            }
-        };
+        let path =
+            match resolve_module_specifier(None, request.specifier(), referrer.path(), context) {
+                Ok(path) => path,
+                Err(err) => {
+                    finish_load(
+                        Err(JsError::from_native(
+                            JsNativeError::typ()
+                                .with_message("could not resolve module specifier")
+                                .with_cause(err),
+                        )),
+                        context,
+                    );
+                    return;
+                }
+            };
 
         if let Some(module) = self.cache.borrow().get(&path).cloned() {
             finish_load(Ok(module), context);
         } else {
             self.inner.load_imported_module(
                 referrer,
-                specifier,
+                request,
                 {
                     let cache = self.cache.clone();
                     Box::new(move |result: JsResult<Module>, context| {
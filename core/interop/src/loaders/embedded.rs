@@ -5,7 +5,7 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::path::Path;
 
-use boa_engine::module::{ModuleLoader, Referrer};
+use boa_engine::module::{ModuleLoader, ModuleRequest, ModuleType, Referrer};
 use boa_engine::{Context, JsNativeError, JsResult, JsString, Module, Source};
 
 /// Create a module loader that embeds files from the filesystem at build
@@ -15,7 +15,8 @@ use boa_engine::{Context, JsNativeError, JsResult, JsString, Module, Source};
 /// changed by specifying the `max_size` parameter.
 ///
 /// The embedded module will only contain files that have the `.js`, `.mjs`,
-/// or `.cjs` extension.
+/// or `.cjs` extension. Files imported with `type: "json"` are loaded as
+/// JSON modules.
 #[macro_export]
 macro_rules! embed_module {
     ($($x: expr),*) => {
@@ -36,7 +37,7 @@ impl EmbeddedModuleEntry {
         Self::Source(compress_type, path, source)
     }
 
-    fn cache(&mut self, context: &mut Context) -> JsResult<&Module> {
+    fn cache(&mut self, module_type: ModuleType, context: &mut Context) -> JsResult<&Module> {
         if let Self::Source(compress, path, source) = self {
             let mut bytes: &[u8] = match compress {
                 CompressType::None => source,
@@ -45,9 +46,17 @@ impl EmbeddedModuleEntry {
                 CompressType::Lz4 => &lz4_flex::decompress_size_prepended(source)
                     .map_err(|e| boa_engine::js_error!("Could not decompress module: {}", e))?,
             };
-            let path = path.to_std_string_escaped();
-            let source = Source::from_reader(&mut bytes, Some(Path::new(&path)));
-            match Module::parse(source, None, context) {
+            let module = match module_type {
+                ModuleType::JavaScript => {
+                    let path = path.to_std_string_escaped();
+                    let source = Source::from_reader(&mut bytes, Some(Path::new(&path)));
+                    Module::parse(source, None, context)
+                }
+                ModuleType::Json => std::str::from_utf8(bytes)
+                    .map_err(|e| boa_engine::js_error!("Could not decode module: {}", e))
+                    .and_then(|json| Module::parse_json(JsString::from(json), context)),
+            };
+            match module {
                 Ok(module) => {
                     *self = Self::Module(module);
                 }
@@ -129,16 +138,21 @@ impl ModuleLoader for EmbeddedModuleLoader {
     fn load_imported_module(
         &self,
         referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
-        let Ok(specifier_path) = boa_engine::module::resolve_module_specifier(
-            None,
-            &specifier,
-            referrer.path(),
-            context,
-        ) else {
+        let specifier = request.specifier();
+        let module_type = match request.module_type() {
+            Ok(module_type) => module_type,
+            Err(err) => {
+                finish_load(Err(err), context);
+                return;
+            }
+        };
+        let Ok(specifier_path) =
+            boa_engine::module::resolve_module_specifier(None, specifier, referrer.path(), context)
+        else {
             let err = JsNativeError::typ().with_message(format!(
                 "could not resolve module specifier `{}`",
                 specifier.to_std_string_escaped()
@@ -152,7 +166,7 @@ impl ModuleLoader for EmbeddedModuleLoader {
             .get(&JsString::from(specifier_path.to_string_lossy().as_ref()))
         {
             let mut embedded = module.borrow_mut();
-            let module = embedded.cache(context);
+            let module = embedded.cache(module_type, context);
 
             finish_load(module.cloned(), context);
         } else {
@@ -1,6 +1,8 @@
 //! This module contains types that help create custom module loaders from functions.
-use boa_engine::module::{resolve_module_specifier, ModuleLoader, Referrer};
-use boa_engine::{Context, JsError, JsNativeError, JsResult, JsString, Module, Source};
+use boa_engine::module::{
+    resolve_module_specifier, ModuleLoader, ModuleRequest, ModuleType, Referrer,
+};
+use boa_engine::{js_string, Context, JsError, JsNativeError, JsResult, JsString, Module, Source};
 use std::io::Cursor;
 
 /// Create a [`ModuleLoader`] from a function that takes a referrer and a path,
@@ -52,11 +54,11 @@ where
     fn load_imported_module(
         &self,
         referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
-        finish_load((self.factory)(&referrer, &specifier), context);
+        finish_load((self.factory)(&referrer, request.specifier()), context);
     }
 }
 
@@ -107,23 +109,36 @@ where
     fn load_imported_module(
         &self,
         referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
-        match resolve_module_specifier(None, &specifier, referrer.path(), context) {
+        let module_type = match request.module_type() {
+            Ok(module_type) => module_type,
+            Err(e) => {
+                finish_load(Err(e), context);
+                return;
+            }
+        };
+        match resolve_module_specifier(None, request.specifier(), referrer.path(), context) {
             Err(e) => finish_load(Err(e), context),
             Ok(p) => {
-                let m = match self.0(&p.to_string_lossy()) {
-                    Some(source) => Ok(Source::from_reader(
-                        Cursor::new(source.into_bytes()),
-                        Some(&p),
-                    )),
-                    None => Err(JsError::from_native(
+                let Some(source) = self.0(&p.to_string_lossy()) else {
+                    let err = JsError::from_native(
                         JsNativeError::error().with_message("Module not found"),
-                    )),
+                    );
+                    finish_load(Err(err), context);
+                    return;
+                };
+                let module = match module_type {
+                    ModuleType::JavaScript => Module::parse(
+                        Source::from_reader(Cursor::new(source.into_bytes()), Some(&p)),
+                        None,
+                        context,
+                    ),
+                    ModuleType::Json => Module::parse_json(js_string!(source), context),
                 };
-                finish_load(m.and_then(|s| Module::parse(s, None, context)), context);
+                finish_load(module, context);
             }
         }
     }
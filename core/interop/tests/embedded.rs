@@ -83,3 +83,42 @@ fn compressed_lz4() {
 
     load_module_and_test(&module_loader);
 }
+
+#[test]
+fn json_module() {
+    #[cfg(target_family = "unix")]
+    let module_loader = Rc::new(embed_module!("tests/embedded/"));
+    #[cfg(target_family = "windows")]
+    let module_loader = Rc::new(embed_module!("tests\\embedded\\"));
+
+    let mut context = Context::builder()
+        .module_loader(module_loader)
+        .build()
+        .unwrap();
+
+    let module = Module::parse(
+        Source::from_bytes(
+            b"import data from '/data.json' with { type: 'json' }; export const value = data.value;",
+        ),
+        None,
+        &mut context,
+    )
+    .expect("failed to parse module");
+    let promise = module.load_link_evaluate(&mut context);
+    context.run_jobs().unwrap();
+
+    match promise.state() {
+        PromiseState::Fulfilled(_) => {
+            let value = module
+                .namespace(&mut context)
+                .get(js_string!("value"), &mut context)
+                .unwrap();
+            assert_eq!(value.as_number(), Some(42.));
+        }
+        PromiseState::Rejected(err) => panic!(
+            "promise was not fulfilled: {:?}",
+            err.to_string(&mut context)
+        ),
+        PromiseState::Pending => panic!("Promise was not settled"),
+    }
+}
@@ -109,6 +109,21 @@ impl Error {
         }
     }
 
+    /// Gets the span of the source code where the error occurred, if any.
+    ///
+    /// The errors that only have a position return an empty span at that position.
+    #[must_use]
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            Self::Expected { span, .. } | Self::Unexpected { span, .. } => Some(*span),
+            Self::General { position, .. }
+            | Self::Lex {
+                err: LexError::Syntax(_, position),
+            } => Some(Span::new(*position, *position)),
+            Self::AbruptEnd | Self::Lex { .. } => None,
+        }
+    }
+
     /// Creates an `Expected` parsing error.
     pub(crate) fn expected<E, F>(expected: E, found: F, span: Span, context: &'static str) -> Self
     where
@@ -16,7 +16,25 @@ use boa_profiler::Profiler;
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-comments
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Lexical_grammar
-pub(super) struct SingleLineComment;
+#[derive(Debug)]
+pub(super) struct SingleLineComment {
+    text: Option<String>,
+}
+
+impl SingleLineComment {
+    /// Creates a new single line comment lexer, which records the text of the comment if
+    /// `record` is `true`.
+    pub(super) fn new(record: bool) -> Self {
+        Self {
+            text: record.then(String::new),
+        }
+    }
+
+    /// Takes the recorded text of the comment.
+    pub(super) fn into_text(self) -> Option<String> {
+        self.text
+    }
+}
 
 impl<R> Tokenizer<R> for SingleLineComment {
     fn lex(
@@ -35,7 +53,7 @@ impl<R> Tokenizer<R> for SingleLineComment {
             let tried_ch = char::try_from(ch);
             match tried_ch {
                 Ok(c) if c == '\r' || c == '\n' || c == '\u{2028}' || c == '\u{2029}' => break,
-                _ => {}
+                _ => push_char(&mut self.text, ch),
             }
             cursor.next_char().expect("Comment character vanished");
         }
@@ -57,7 +75,25 @@ impl<R> Tokenizer<R> for SingleLineComment {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-comments
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Lexical_grammar
-pub(super) struct MultiLineComment;
+#[derive(Debug)]
+pub(super) struct MultiLineComment {
+    text: Option<String>,
+}
+
+impl MultiLineComment {
+    /// Creates a new multi-line comment lexer, which records the text of the comment if
+    /// `record` is `true`.
+    pub(super) fn new(record: bool) -> Self {
+        Self {
+            text: record.then(String::new),
+        }
+    }
+
+    /// Takes the recorded text of the comment.
+    pub(super) fn into_text(self) -> Option<String> {
+        self.text
+    }
+}
 
 impl<R> Tokenizer<R> for MultiLineComment {
     fn lex(
@@ -91,6 +127,7 @@ impl<R> Tokenizer<R> for MultiLineComment {
                 }
                 _ => {}
             }
+            push_char(&mut self.text, ch);
         }
 
         Err(Error::syntax(
@@ -106,7 +143,25 @@ impl<R> Tokenizer<R> for MultiLineComment {
 ///  - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-ecmascript-language-lexical-grammar
-pub(super) struct HashbangComment;
+#[derive(Debug)]
+pub(super) struct HashbangComment {
+    text: Option<String>,
+}
+
+impl HashbangComment {
+    /// Creates a new hashbang comment lexer, which records the text of the comment if
+    /// `record` is `true`.
+    pub(super) fn new(record: bool) -> Self {
+        Self {
+            text: record.then(String::new),
+        }
+    }
+
+    /// Takes the recorded text of the comment.
+    pub(super) fn into_text(self) -> Option<String> {
+        self.text
+    }
+}
 
 impl<R> Tokenizer<R> for HashbangComment {
     fn lex(
@@ -120,11 +175,13 @@ impl<R> Tokenizer<R> for HashbangComment {
     {
         let _timer = Profiler::global().start_event("Hashbang", "Lexing");
 
+        // Consume the '!', since only the '#' is consumed.
+        let _next = cursor.next_char()?;
         while let Some(ch) = cursor.next_char()? {
             let tried_ch = char::try_from(ch);
             match tried_ch {
                 Ok(c) if c == '\r' || c == '\n' || c == '\u{2028}' || c == '\u{2029}' => break,
-                _ => {}
+                _ => push_char(&mut self.text, ch),
             }
         }
 
@@ -135,3 +192,10 @@ impl<R> Tokenizer<R> for HashbangComment {
         ))
     }
 }
+
+/// Appends the code point `ch` to the recorded text of a comment, if it is being recorded.
+fn push_char(text: &mut Option<String>, ch: u32) {
+    if let Some(text) = text {
+        text.push(char::from_u32(ch).unwrap_or(char::REPLACEMENT_CHARACTER));
+    }
+}
@@ -42,7 +42,10 @@ use self::{
     template::TemplateLiteral,
 };
 use crate::source::{ReadChar, UTF8Input};
-use boa_ast::{PositionGroup, Punctuator};
+use boa_ast::{
+    comment::{Comment, CommentKind},
+    PositionGroup, Punctuator,
+};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 
@@ -68,6 +71,7 @@ trait Tokenizer<R> {
 pub struct Lexer<R> {
     cursor: Cursor<R>,
     goal_symbol: InputElement,
+    comments: Option<Vec<Comment>>,
 }
 
 impl<R> Lexer<R> {
@@ -109,9 +113,46 @@ impl<R> Lexer<R> {
         Self {
             cursor: Cursor::new(reader),
             goal_symbol: InputElement::default(),
+            comments: None,
         }
     }
 
+    /// Sets whether the lexer records the comments of the source.
+    pub(super) fn set_record_comments(&mut self, record: bool) {
+        self.comments = record.then(Vec::new);
+    }
+
+    /// Takes the comments recorded by the lexer, in source order.
+    pub(super) fn take_comments(&mut self) -> Box<[Comment]> {
+        self.comments
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+            .into_boxed_slice()
+    }
+
+    /// Records a comment, if the lexer records comments.
+    fn record_comment(&mut self, kind: CommentKind, text: Option<String>, token: &Token) {
+        if let (Some(comments), Some(text)) = (&mut self.comments, text) {
+            comments.push(Comment::new(kind, text, token.span()));
+        }
+    }
+
+    /// Lexes a single line comment, with its start already consumed.
+    fn lex_single_line_comment(
+        &mut self,
+        start: PositionGroup,
+        interner: &mut Interner,
+    ) -> Result<Token, Error>
+    where
+        R: ReadChar,
+    {
+        let mut comment = SingleLineComment::new(self.comments.is_some());
+        let token = comment.lex(&mut self.cursor, start, interner)?;
+        self.record_comment(CommentKind::SingleLine, comment.into_text(), &token);
+        Ok(token)
+    }
+
     /// Handles lexing of a token starting '/' with the '/' already being consumed.
     /// This could be a divide symbol or the start of a regex.
     ///
@@ -137,12 +178,15 @@ impl<R> Lexer<R> {
                 // /
                 (0x002F, false) => {
                     self.cursor.next_char()?.expect("/ token vanished"); // Consume the '/'
-                    SingleLineComment.lex(&mut self.cursor, start, interner)
+                    self.lex_single_line_comment(start, interner)
                 }
                 // *
                 (0x002A, false) => {
                     self.cursor.next_char()?.expect("* token vanished"); // Consume the '*'
-                    MultiLineComment.lex(&mut self.cursor, start, interner)
+                    let mut comment = MultiLineComment::new(self.comments.is_some());
+                    let token = comment.lex(&mut self.cursor, start, interner)?;
+                    self.record_comment(CommentKind::MultiLine, comment.into_text(), &token);
+                    Ok(token)
                 }
                 (ch, init_with_eq) => {
                     match self.get_goal() {
@@ -206,7 +250,7 @@ impl<R> Lexer<R> {
             let _next = self.cursor.next_char();
 
             let start = self.cursor.pos_group();
-            SingleLineComment.lex(&mut self.cursor, start, interner)?;
+            self.lex_single_line_comment(start, interner)?;
         }
 
         Ok(())
@@ -234,7 +278,10 @@ impl<R> Lexer<R> {
         if self.get_goal() == InputElement::HashbangOrRegExp {
             self.set_goal(InputElement::RegExp);
             if next_ch == 0x23 && self.cursor.peek_char()? == Some(0x21) {
-                let _token = HashbangComment.lex(&mut self.cursor, start, interner);
+                let mut comment = HashbangComment::new(self.comments.is_some());
+                if let Ok(token) = comment.lex(&mut self.cursor, start, interner) {
+                    self.record_comment(CommentKind::Hashbang, comment.into_text(), &token);
+                }
                 return self.next(interner);
             }
         }
@@ -319,6 +366,11 @@ impl<R> Lexer<R> {
                     start,
                     self.cursor.pos_group(),
                 )),
+                '@' => Ok(Token::new_by_position_group(
+                    Punctuator::At.into(),
+                    start,
+                    self.cursor.pos_group(),
+                )),
                 '#' => PrivateIdentifier::new().lex(&mut self.cursor, start, interner),
                 '/' => self.lex_slash_token(start, interner, false),
                 #[cfg(feature = "annex-b")]
@@ -330,7 +382,7 @@ impl<R> Lexer<R> {
                     let _next = self.cursor.next_char();
                     let _next = self.cursor.next_char();
                     let start = self.cursor.pos_group();
-                    SingleLineComment.lex(&mut self.cursor, start, interner)
+                    self.lex_single_line_comment(start, interner)
                 }
                 #[allow(clippy::cast_possible_truncation)]
                 '=' | '*' | '+' | '-' | '%' | '|' | '&' | '^' | '<' | '>' | '!' | '~' | '?' => {
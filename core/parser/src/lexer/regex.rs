@@ -7,6 +7,7 @@ use boa_ast::{Position, PositionGroup};
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
 use regress::{Flags, Regex};
+use rustc_hash::FxHashMap;
 use std::fmt::{Display, Write};
 use std::str::{self, FromStr};
 
@@ -142,7 +143,7 @@ impl<R> Tokenizer<R> for RegexLiteral {
             }
         }
 
-        if let Err(error) = Regex::from_unicode(body.into_iter(), flags_str) {
+        if let Err(error) = RegExpMatcher::new(&body, flags_str) {
             return Err(Error::syntax(
                 format!("Invalid regular expression literal: {error}"),
                 start_pos,
@@ -276,3 +277,379 @@ impl From<RegExpFlags> for Flags {
         }
     }
 }
+
+/// A regular expression pattern compiled with `regress`.
+///
+/// `regress` rejects patterns that define the same capturing group name more than once, so
+/// patterns with [duplicate named groups][proposal] are rewritten before compiling them:
+///
+/// - Every group after the first one of a duplicated name is renamed to a unique name.
+/// - Every named backreference is replaced with numbered backreferences to each one of the
+///   groups with that name. Since at most one of those groups can participate in a match, and
+///   backreferences to groups that didn't participate always match the empty string, this
+///   matches exactly what the original backreference would have matched.
+///
+/// Named backreferences are replaced even without duplicate names, since `regress` resolves
+/// them to the wrong group when they follow non-capturing groups or lookarounds.
+///
+/// [proposal]: https://github.com/tc39/proposal-duplicate-named-capturing-groups
+#[derive(Debug, Clone)]
+pub struct RegExpMatcher {
+    regex: Regex,
+    renamed_groups: Box<[(Box<str>, Box<str>)]>,
+}
+
+impl RegExpMatcher {
+    /// Compiles the regular expression `pattern`, given as a list of code points, with `flags`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `pattern` is not a valid pattern, or if two groups with the same name
+    /// could both participate in a match.
+    pub fn new<F>(pattern: &[u32], flags: F) -> Result<Self, regress::Error>
+    where
+        F: Into<Flags>,
+    {
+        let flags = flags.into();
+        let Some(rewrite) = GroupScanner::new(pattern, flags.unicode_sets).rewrite()? else {
+            return Ok(Self {
+                regex: Regex::from_unicode(pattern.iter().copied(), flags)?,
+                renamed_groups: Box::default(),
+            });
+        };
+
+        Ok(Self {
+            regex: Regex::from_unicode(rewrite.pattern.into_iter(), flags)?,
+            renamed_groups: rewrite.renamed_groups.into_boxed_slice(),
+        })
+    }
+
+    /// Gets the compiled `regress` regular expression.
+    #[must_use]
+    pub const fn regex(&self) -> &Regex {
+        &self.regex
+    }
+
+    /// Gets the name that a named group had in the original pattern, given the name of the group
+    /// in the compiled regular expression.
+    #[must_use]
+    pub fn group_name<'a>(&'a self, name: &'a str) -> &'a str {
+        self.renamed_groups
+            .iter()
+            .find(|(renamed, _)| &**renamed == name)
+            .map_or(name, |(_, original)| original)
+    }
+}
+
+/// A named capturing group of a pattern.
+#[derive(Debug)]
+struct NamedGroup {
+    /// The (unescaped) name of the group.
+    name: String,
+    /// The range of the name in the pattern, without the angle brackets.
+    range: std::ops::Range<usize>,
+    /// The index of the capturing group.
+    capture: usize,
+    /// The disjunctions that contain the group, as pairs of the disjunction and the index of
+    /// the alternative containing the group.
+    alternatives: Vec<(usize, usize)>,
+}
+
+impl NamedGroup {
+    /// Abstract operation `MightBothParticipate ( x, y )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-mightbothparticipate
+    fn might_both_participate(&self, other: &Self) -> bool {
+        // 1. Assert: x and y have the same enclosing Pattern.
+        // 2. If the enclosing Pattern contains a Disjunction :: Alternative | Disjunction Parse Node
+        //    such that either x is contained within the Alternative and y is contained within the
+        //    derived Disjunction, or x is contained within the derived Disjunction and y is
+        //    contained within the Alternative, return false.
+        for (x, y) in self.alternatives.iter().zip(&other.alternatives) {
+            if x.0 != y.0 {
+                break;
+            }
+            if x.1 != y.1 {
+                return false;
+            }
+        }
+
+        // 3. Return true.
+        true
+    }
+}
+
+/// The result of rewriting a pattern with duplicate named groups.
+#[derive(Debug)]
+struct RewrittenPattern {
+    pattern: Vec<u32>,
+    renamed_groups: Vec<(Box<str>, Box<str>)>,
+}
+
+/// A scanner that finds the named groups and named backreferences of a pattern.
+///
+/// This doesn't validate the pattern; if it finds anything unexpected it stops and leaves the
+/// pattern untouched, so that `regress` can report the error.
+#[derive(Debug)]
+struct GroupScanner<'a> {
+    pattern: &'a [u32],
+    unicode_sets: bool,
+    position: usize,
+}
+
+impl<'a> GroupScanner<'a> {
+    const fn new(pattern: &'a [u32], unicode_sets: bool) -> Self {
+        Self {
+            pattern,
+            unicode_sets,
+            position: 0,
+        }
+    }
+
+    /// Returns the code point at `position`, if it's an ASCII character.
+    fn ascii_at(&self, position: usize) -> Option<u8> {
+        self.pattern
+            .get(position)
+            .and_then(|&c| u8::try_from(c).ok())
+            .filter(u8::is_ascii)
+    }
+
+    /// Rewrites the pattern if it has duplicate named groups.
+    fn rewrite(mut self) -> Result<Option<RewrittenPattern>, regress::Error> {
+        let Some((groups, backreferences)) = self.scan() else {
+            return Ok(None);
+        };
+
+        let mut renamed_groups = Vec::new();
+        let mut names = FxHashMap::<&str, Vec<usize>>::default();
+        for (index, group) in groups.iter().enumerate() {
+            let duplicates = names.entry(group.name.as_str()).or_default();
+            for &duplicate in &*duplicates {
+                if groups[duplicate].might_both_participate(group) {
+                    return Err(regress::Error {
+                        text: "Duplicate capture group name".into(),
+                    });
+                }
+            }
+            duplicates.push(index);
+        }
+
+        if names.len() == groups.len() && backreferences.is_empty() {
+            return Ok(None);
+        }
+
+        // Collect the replacements of the pattern, ordered by their position in the pattern.
+        let mut replacements = Vec::new();
+        for indices in names.values().filter(|indices| indices.len() > 1) {
+            let original = &groups[indices[0]].name;
+            let mut suffix = 0;
+            for &index in &indices[1..] {
+                let renamed = loop {
+                    suffix += 1;
+                    let renamed = format!("{original}${suffix}");
+                    if !names.contains_key(renamed.as_str()) {
+                        break renamed;
+                    }
+                };
+                replacements.push((groups[index].range.clone(), renamed.clone()));
+                renamed_groups.push((renamed.into_boxed_str(), original.clone().into_boxed_str()));
+            }
+        }
+        for (name, range) in backreferences {
+            let Some(indices) = names.get(name.as_str()) else {
+                continue;
+            };
+            let mut replacement = String::from("(?:");
+            for &index in indices {
+                replacement.push('\\');
+                replacement.push_str(&groups[index].capture.to_string());
+            }
+            replacement.push(')');
+            replacements.push((range, replacement));
+        }
+        replacements.sort_by_key(|(range, _)| range.start);
+
+        let mut pattern = Vec::with_capacity(self.pattern.len());
+        let mut position = 0;
+        for (range, replacement) in replacements {
+            pattern.extend_from_slice(&self.pattern[position..range.start]);
+            pattern.extend(replacement.chars().map(u32::from));
+            position = range.end;
+        }
+        pattern.extend_from_slice(&self.pattern[position..]);
+
+        Ok(Some(RewrittenPattern {
+            pattern,
+            renamed_groups,
+        }))
+    }
+
+    /// Finds the named groups of the pattern, and the names and ranges of its named
+    /// backreferences.
+    #[allow(clippy::type_complexity)]
+    fn scan(&mut self) -> Option<(Vec<NamedGroup>, Vec<(String, std::ops::Range<usize>)>)> {
+        let mut groups = Vec::new();
+        let mut backreferences = Vec::new();
+
+        // The stack of disjunctions containing the current position, and the index of the
+        // current alternative of each one of them.
+        let mut alternatives = vec![(0, 0)];
+        let mut disjunctions = 0;
+        let mut captures = 0;
+
+        while self.position < self.pattern.len() {
+            match self.ascii_at(self.position) {
+                Some(b'\\') => {
+                    if self.ascii_at(self.position + 1) == Some(b'k')
+                        && self.ascii_at(self.position + 2) == Some(b'<')
+                    {
+                        let start = self.position;
+                        self.position += 3;
+                        let (name, _) = self.group_name()?;
+                        backreferences.push((name, start..self.position));
+                    } else {
+                        self.position += 2;
+                    }
+                }
+                Some(b'[') => self.skip_class(),
+                Some(b'(') => {
+                    if self.ascii_at(self.position + 1) == Some(b'?')
+                        && self.ascii_at(self.position + 2) == Some(b'<')
+                        && !matches!(self.ascii_at(self.position + 3), Some(b'=' | b'!'))
+                    {
+                        self.position += 3;
+                        let (name, range) = self.group_name()?;
+                        captures += 1;
+                        groups.push(NamedGroup {
+                            name,
+                            range,
+                            capture: captures,
+                            alternatives: alternatives.clone(),
+                        });
+                    } else {
+                        if self.ascii_at(self.position + 1) != Some(b'?') {
+                            captures += 1;
+                        }
+                        self.position += 1;
+                    }
+                    disjunctions += 1;
+                    alternatives.push((disjunctions, 0));
+                }
+                Some(b')') => {
+                    if alternatives.len() == 1 {
+                        return None;
+                    }
+                    alternatives.pop();
+                    self.position += 1;
+                }
+                Some(b'|') => {
+                    if let Some((_, alternative)) = alternatives.last_mut() {
+                        *alternative += 1;
+                    }
+                    self.position += 1;
+                }
+                _ => self.position += 1,
+            }
+        }
+
+        Some((groups, backreferences))
+    }
+
+    /// Skips the character class at the current position.
+    fn skip_class(&mut self) {
+        let mut depth = 0;
+        while self.position < self.pattern.len() {
+            match self.ascii_at(self.position) {
+                Some(b'\\') => self.position += 1,
+                Some(b'[') if depth == 0 || self.unicode_sets => depth += 1,
+                Some(b']') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        self.position += 1;
+                        return;
+                    }
+                }
+                _ => {}
+            }
+            self.position += 1;
+        }
+    }
+
+    /// Consumes the group name at the current position and its closing angle bracket,
+    /// returning the unescaped name and its range in the pattern.
+    fn group_name(&mut self) -> Option<(String, std::ops::Range<usize>)> {
+        let start = self.position;
+        let mut name = String::new();
+        loop {
+            match self.ascii_at(self.position) {
+                Some(b'>') => break,
+                Some(b'\\') => {
+                    self.position += 1;
+                    let mut code_point = self.unicode_escape()?;
+                    if (0xD800..=0xDBFF).contains(&code_point)
+                        && self.ascii_at(self.position) == Some(b'\\')
+                    {
+                        let position = self.position;
+                        self.position += 1;
+                        match self.unicode_escape() {
+                            Some(low @ 0xDC00..=0xDFFF) => {
+                                code_point =
+                                    0x1_0000 + ((code_point - 0xD800) << 10) + (low - 0xDC00);
+                            }
+                            _ => self.position = position,
+                        }
+                    }
+                    name.push(char::from_u32(code_point)?);
+                }
+                _ => {
+                    name.push(char::from_u32(*self.pattern.get(self.position)?)?);
+                    self.position += 1;
+                }
+            }
+        }
+        let end = self.position;
+        self.position += 1;
+        Some((name, start..end))
+    }
+
+    /// Consumes a `u` escape sequence after a backslash, returning its code point.
+    fn unicode_escape(&mut self) -> Option<u32> {
+        if self.ascii_at(self.position) != Some(b'u') {
+            return None;
+        }
+        self.position += 1;
+
+        let braced = self.ascii_at(self.position) == Some(b'{');
+        if braced {
+            self.position += 1;
+        }
+
+        let mut code_point = 0u32;
+        let mut digits = 0;
+        while let Some(digit) = self
+            .ascii_at(self.position)
+            .and_then(|c| char::from(c).to_digit(16))
+        {
+            code_point = code_point.checked_mul(16)?.checked_add(digit)?;
+            digits += 1;
+            self.position += 1;
+            if !braced && digits == 4 {
+                break;
+            }
+        }
+
+        if braced {
+            if digits == 0 || self.ascii_at(self.position) != Some(b'}') {
+                return None;
+            }
+            self.position += 1;
+        } else if digits != 4 {
+            return None;
+        }
+
+        Some(code_point)
+    }
+}
@@ -3,10 +3,10 @@
 use crate::lexer::{
     template::TemplateString,
     token::{ContainsEscapeSequence, EscapeSequence, Numeric},
-    Cursor, Error, Interner, Lexer, Punctuator, TokenKind,
+    Cursor, Error, InputElement, Interner, Lexer, Punctuator, TokenKind,
 };
 use crate::source::ReadChar;
-use boa_ast::{Keyword, Position, Span};
+use boa_ast::{comment::CommentKind, Keyword, Position, Span};
 use boa_interner::Sym;
 use boa_macros::utf16;
 use std::str;
@@ -778,6 +778,26 @@ fn regex_literal_flags_err() {
         .expect_err("Lexer did not handle regex literal with error");
 }
 
+#[test]
+fn regex_literal_duplicate_named_groups() {
+    let mut lexer = Lexer::from(&br"/(?<a>x)|(?<a>y)/"[..]);
+    let interner = &mut Interner::default();
+
+    let expected = [TokenKind::regular_expression_literal(
+        interner.get_or_intern_static("(?<a>x)|(?<a>y)", utf16!("(?<a>x)|(?<a>y)")),
+        Sym::EMPTY_STRING,
+    )];
+
+    expect_tokens(&mut lexer, &expected, interner);
+
+    let mut lexer = Lexer::from(&br"/(?<a>x)(?<a>y)/"[..]);
+    let interner = &mut Interner::default();
+
+    lexer
+        .next(interner)
+        .expect_err("Lexer did not handle duplicate named groups with error");
+}
+
 #[test]
 fn addition_no_spaces() {
     let mut lexer = Lexer::from(&b"1+1"[..]);
@@ -1197,3 +1217,47 @@ mod carriage_return {
         expect_tokens_with_lines(3, "-\r\n\n\r3");
     }
 }
+
+#[test]
+fn check_recorded_comments() {
+    let s = "#!/usr/bin/env boa\n// single\nvar /* multi\nline */ x;";
+    let mut lexer = Lexer::from(s.as_bytes());
+    lexer.set_goal(InputElement::HashbangOrRegExp);
+    lexer.set_record_comments(true);
+    let interner = &mut Interner::default();
+
+    while lexer.next(interner).unwrap().is_some() {}
+
+    let comments = lexer.take_comments();
+    let comments = comments
+        .iter()
+        .map(|comment| (comment.kind(), comment.text(), comment.span()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        comments,
+        [
+            (
+                CommentKind::Hashbang,
+                "/usr/bin/env boa",
+                span((1, 1), (2, 1))
+            ),
+            (CommentKind::SingleLine, " single", span((2, 1), (2, 10))),
+            (
+                CommentKind::MultiLine,
+                " multi\nline ",
+                span((3, 5), (4, 8))
+            ),
+        ]
+    );
+}
+
+#[test]
+fn check_comments_not_recorded_by_default() {
+    let s = "// single\n/* multi */";
+    let mut lexer = Lexer::from(s.as_bytes());
+    let interner = &mut Interner::default();
+
+    while lexer.next(interner).unwrap().is_some() {}
+
+    assert!(lexer.take_comments().is_empty());
+}
@@ -2,9 +2,8 @@ use crate::{
     lexer::{InputElement, Lexer, Token, TokenKind},
     parser::ParseResult,
     source::{ReadChar, UTF8Input},
-    Error,
 };
-use boa_ast::{LinearPosition, PositionGroup};
+use boa_ast::{comment::Comment, LinearPosition, Position, PositionGroup};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 
@@ -31,6 +30,8 @@ pub(super) struct BufferedLexer<R> {
     read_index: usize,
     write_index: usize,
     last_linear_pos: LinearPosition,
+    /// The end of the last token that was consumed, ignoring line terminators.
+    last_pos: Position,
 }
 
 impl<R> From<Lexer<R>> for BufferedLexer<R>
@@ -54,6 +55,7 @@ where
             read_index: 0,
             write_index: 0,
             last_linear_pos: LinearPosition::default(),
+            last_pos: Position::new(1, 1),
         }
     }
 }
@@ -93,9 +95,9 @@ where
     ) -> ParseResult<Token> {
         let _timer = Profiler::global().start_event("cursor::lex_regex()", "Parsing");
         self.set_goal(InputElement::RegExp);
-        self.lexer
-            .lex_slash_token(start, interner, init_with_eq)
-            .map_err(Into::into)
+        let token = self.lexer.lex_slash_token(start, interner, init_with_eq)?;
+        self.last_pos = token.span().end();
+        Ok(token)
     }
 
     /// Lexes the next tokens as template middle or template tail assuming that the starting
@@ -105,9 +107,9 @@ where
         start: PositionGroup,
         interner: &mut Interner,
     ) -> ParseResult<Token> {
-        self.lexer
-            .lex_template(start, interner)
-            .map_err(Error::from)
+        let token = self.lexer.lex_template(start, interner)?;
+        self.last_pos = token.span().end();
+        Ok(token)
     }
 
     pub(super) const fn strict(&self) -> bool {
@@ -126,6 +128,14 @@ where
         self.lexer.set_module(module);
     }
 
+    pub(super) fn set_record_comments(&mut self, record: bool) {
+        self.lexer.set_record_comments(record);
+    }
+
+    pub(super) fn take_comments(&mut self) -> Box<[Comment]> {
+        self.lexer.take_comments()
+    }
+
     /// Fills the peeking buffer with the next token.
     ///
     /// It will not fill two line terminators one after the other.
@@ -206,6 +216,9 @@ where
 
             if let Some(tok) = &tok {
                 self.last_linear_pos = tok.linear_span().end();
+                if tok.kind() != &TokenKind::LineTerminator {
+                    self.last_pos = tok.span().end();
+                }
             }
 
             Ok(tok)
@@ -278,6 +291,12 @@ where
         self.last_linear_pos
     }
 
+    /// Gets the end position of the last consumed token that is not a line terminator.
+    #[inline]
+    pub(super) const fn last_pos(&self) -> Position {
+        self.last_pos
+    }
+
     pub(super) fn take_source(&mut self) -> boa_ast::SourceText {
         self.lexer.take_source()
     }
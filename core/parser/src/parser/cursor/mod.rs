@@ -7,7 +7,9 @@ use crate::{
     source::ReadChar,
     Error,
 };
-use boa_ast::{LinearPosition, PositionGroup, Punctuator};
+use boa_ast::{
+    comment::Comment, LinearPosition, Position, PositionGroup, Punctuator, Span, Spanned,
+};
 use boa_interner::Interner;
 use buffered_lexer::BufferedLexer;
 
@@ -37,6 +39,9 @@ pub(super) struct Cursor<R> {
 
     /// Tracks the number of tagged templates that are currently being parsed.
     tagged_templates_count: u32,
+
+    /// The errors recorded by a recovering parse, or `None` if the parse stops at the first error.
+    errors: Option<Vec<Error>>,
 }
 
 impl<R> Cursor<R>
@@ -51,6 +56,86 @@ where
             json_parse: false,
             identifier: 0,
             tagged_templates_count: 0,
+            errors: None,
+        }
+    }
+
+    /// Sets if the parse recovers from errors instead of stopping at the first one.
+    pub(super) fn set_recovering(&mut self, recovering: bool) {
+        self.errors = recovering.then(Vec::new);
+    }
+
+    /// Takes the errors recorded by a recovering parse.
+    pub(super) fn take_errors(&mut self) -> Vec<Error> {
+        self.errors.as_mut().map(std::mem::take).unwrap_or_default()
+    }
+
+    /// Records `error` if the parse is recovering, or returns it otherwise.
+    pub(super) fn report(&mut self, error: Error) -> ParseResult<()> {
+        let Some(errors) = &mut self.errors else {
+            return Err(error);
+        };
+
+        // An abrupt end is reported by every construct that is still open at the end of the
+        // input, so only the innermost one is recorded.
+        if !(matches!(error, Error::AbruptEnd) && matches!(errors.last(), Some(Error::AbruptEnd))) {
+            errors.push(error);
+        }
+        Ok(())
+    }
+
+    /// Records `error` and skips the tokens of the statement or declaration starting at `start`,
+    /// so a recovering parse can continue with the next one.
+    ///
+    /// The tokens are skipped until a `;` or a token that starts a new line is found outside of
+    /// any parentheses, brackets or braces, or until one of the `break_nodes` or the end of the
+    /// input is found. At least one token is skipped if the statement didn't consume any token.
+    ///
+    /// Returns the error if the parse is not recovering, or if the lexer can't skip an invalid
+    /// token.
+    pub(super) fn recover(
+        &mut self,
+        error: Error,
+        start: LinearPosition,
+        break_nodes: &[TokenKind],
+        interner: &mut Interner,
+    ) -> ParseResult<()> {
+        self.report(error)?;
+
+        let mut depth = 0_usize;
+        loop {
+            let pos = self.linear_pos();
+            match self.peek(0, interner).map(|token| token.is_some()) {
+                Ok(true) => {}
+                Ok(false) => return Ok(()),
+                // Invalid tokens are skipped by the lexer, unless it is stuck.
+                Err(error) if self.linear_pos() != pos => {
+                    self.report(error)?;
+                    continue;
+                }
+                Err(error) => return Err(error),
+            }
+            let new_line = self.peek_is_line_terminator(0, interner)? == Some(true);
+            let token = self.peek(0, interner).or_abrupt()?;
+            let consumed = token.linear_span().start() != start;
+
+            match token.kind() {
+                TokenKind::Punctuator(
+                    Punctuator::OpenBlock | Punctuator::OpenParen | Punctuator::OpenBracket,
+                ) => depth += 1,
+                kind if depth == 0 && break_nodes.contains(kind) => return Ok(()),
+                TokenKind::Punctuator(Punctuator::Semicolon) if depth == 0 => {
+                    self.advance(interner);
+                    return Ok(());
+                }
+                TokenKind::Punctuator(
+                    Punctuator::CloseBlock | Punctuator::CloseParen | Punctuator::CloseBracket,
+                ) => depth = depth.saturating_sub(1),
+                _ if depth == 0 && new_line && consumed => return Ok(()),
+                _ => {}
+            }
+
+            self.advance(interner);
         }
     }
 
@@ -64,6 +149,16 @@ where
         self.buffered_lexer.module()
     }
 
+    /// Sets whether the cursor records the comments of the source.
+    pub(super) fn set_record_comments(&mut self, record: bool) {
+        self.buffered_lexer.set_record_comments(record);
+    }
+
+    /// Takes the comments recorded by the cursor, in source order.
+    pub(super) fn take_comments(&mut self) -> Box<[Comment]> {
+        self.buffered_lexer.take_comments()
+    }
+
     pub(super) fn set_goal(&mut self, elm: InputElement) {
         self.buffered_lexer.set_goal(elm);
     }
@@ -311,6 +406,29 @@ where
         self.buffered_lexer.linear_pos()
     }
 
+    /// Gets the start position of the next token, which is where the node parsed next starts.
+    ///
+    /// At the end of the input, this is the end of the last consumed token.
+    pub(super) fn next_start(&mut self, interner: &mut Interner) -> ParseResult<Position> {
+        let last = self.buffered_lexer.last_pos();
+        Ok(self
+            .peek(0, interner)?
+            .map_or(last, |token| token.span().start()))
+    }
+
+    /// Sets the span of `node` from `start` to the end of the last consumed token, unless the
+    /// node already has a span.
+    ///
+    /// This way, a parser that returns the node produced by another parser keeps its span, like
+    /// an expression statement keeps the span of its expression.
+    pub(super) fn spanned<N: Spanned>(&self, mut node: N, start: Position) -> N {
+        if node.span().is_none() {
+            let end = self.buffered_lexer.last_pos().max(start);
+            node.set_span(Span::new(start, end));
+        }
+        node
+    }
+
     pub(super) fn take_source(&mut self) -> boa_ast::SourceText {
         self.buffered_lexer.take_source()
     }
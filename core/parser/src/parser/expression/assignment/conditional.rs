@@ -62,6 +62,7 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("ConditionalExpression", "Parsing");
+        let start = cursor.next_start(interner)?;
         let lhs = ShortCircuitExpression::new(self.allow_in, self.allow_yield, self.allow_await)
             .parse(cursor, interner)?;
 
@@ -76,7 +77,8 @@ where
                 let else_clause =
                     AssignmentExpression::new(self.allow_in, self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?;
-                return Ok(Conditional::new(lhs, then_clause, else_clause).into());
+                let conditional = Conditional::new(lhs, then_clause, else_clause);
+                return Ok(cursor.spanned(conditional, start).into());
             }
         }
 
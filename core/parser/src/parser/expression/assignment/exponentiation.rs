@@ -75,17 +75,15 @@ where
             _ => {}
         }
 
+        let start = cursor.next_start(interner)?;
         let lhs =
             UpdateExpression::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
         if let Some(tok) = cursor.peek(0, interner)? {
             if tok.kind() == &TokenKind::Punctuator(Punctuator::Exp) {
                 cursor.advance(interner);
-                return Ok(Binary::new(
-                    ArithmeticOp::Exp.into(),
-                    lhs,
-                    self.parse(cursor, interner)?,
-                )
-                .into());
+                let binary =
+                    Binary::new(ArithmeticOp::Exp.into(), lhs, self.parse(cursor, interner)?);
+                return Ok(cursor.spanned(binary, start).into());
             }
         }
         Ok(lhs)
@@ -89,11 +89,13 @@ where
         let _timer = Profiler::global().start_event("AssignmentExpression", "Parsing");
         cursor.set_goal(InputElement::RegExp);
 
+        let start = cursor.next_start(interner)?;
         match cursor.peek(0, interner).or_abrupt()?.kind() {
             // [+Yield]YieldExpression[?In, ?Await]
             TokenKind::Keyword((Keyword::Yield, _)) if self.allow_yield.0 => {
-                return YieldExpression::new(self.allow_in, self.allow_await)
-                    .parse(cursor, interner)
+                let expr = YieldExpression::new(self.allow_in, self.allow_await)
+                    .parse(cursor, interner)?;
+                return Ok(cursor.spanned(expr, start));
             }
             // ArrowFunction[?In, ?Yield, ?Await] -> ArrowParameters[?Yield, ?Await] -> BindingIdentifier[?Yield, ?Await]
             TokenKind::IdentifierName(_)
@@ -109,13 +111,10 @@ where
                 };
                 if let Some(tok) = cursor.peek_no_skip_line_term(skip_n, interner)? {
                     if tok.kind() == &TokenKind::Punctuator(Punctuator::Arrow) {
-                        return ArrowFunction::new(
-                            self.allow_in,
-                            self.allow_yield,
-                            self.allow_await,
-                        )
-                        .parse(cursor, interner)
-                        .map(Expression::ArrowFunction);
+                        let arrow =
+                            ArrowFunction::new(self.allow_in, self.allow_yield, self.allow_await)
+                                .parse(cursor, interner)?;
+                        return Ok(cursor.spanned(arrow, start).into());
                     }
                 }
             }
@@ -144,9 +143,9 @@ where
                             TokenKind::Punctuator(Punctuator::Arrow)
                         )))
                 {
-                    return Ok(AsyncArrowFunction::new(self.allow_in, self.allow_yield)
-                        .parse(cursor, interner)?
-                        .into());
+                    let arrow = AsyncArrowFunction::new(self.allow_in, self.allow_yield)
+                        .parse(cursor, interner)?;
+                    return Ok(cursor.spanned(arrow, start).into());
                 }
             }
             _ => {}
@@ -221,7 +220,8 @@ where
             let linear_pos_end = body.linear_pos_end();
             let span = start_linear_span.union(linear_pos_end);
 
-            return Ok(boa_ast::function::ArrowFunction::new(None, parameters, body, span).into());
+            let arrow = boa_ast::function::ArrowFunction::new(None, parameters, body, span);
+            return Ok(cursor.spanned(arrow, start).into());
         }
 
         // Review if we are trying to assign to an invalid left hand side expression.
@@ -242,7 +242,8 @@ where
                         if let Some(ident) = lhs_name {
                             expr.set_anonymous_function_definition_name(&ident);
                         }
-                        lhs = Assign::new(AssignOp::Assign, target, expr).into();
+                        let assign = Assign::new(AssignOp::Assign, target, expr);
+                        lhs = cursor.spanned(assign, start).into();
                     } else {
                         return Err(Error::lex(LexError::Syntax(
                             "Invalid left-hand side in assignment".into(),
@@ -266,7 +267,8 @@ where
                                 rhs.set_anonymous_function_definition_name(&ident);
                             }
                         }
-                        lhs = Assign::new(assignop, target, rhs).into();
+                        let assign = Assign::new(assignop, target, rhs);
+                        lhs = cursor.spanned(assign, start).into();
                     } else {
                         return Err(Error::lex(LexError::Syntax(
                             "Invalid left-hand side in assignment".into(),
@@ -54,6 +54,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("YieldExpression", "Parsing");
 
+        let start = cursor.next_start(interner)?;
         cursor.expect(
             TokenKind::Keyword((Keyword::Yield, false)),
             "yield expression",
@@ -64,7 +65,7 @@ where
             cursor.peek_is_line_terminator(0, interner)?,
             Some(true) | None
         ) {
-            return Ok(Yield::new(None, false).into());
+            return Ok(cursor.spanned(Yield::new(None, false), start).into());
         }
 
         let token = cursor.peek(0, interner).or_abrupt()?;
@@ -73,7 +74,7 @@ where
                 cursor.advance(interner);
                 let expr = AssignmentExpression::new(self.allow_in, true, self.allow_await)
                     .parse(cursor, interner)?;
-                Ok(Yield::new(Some(expr), true).into())
+                Ok(cursor.spanned(Yield::new(Some(expr), true), start).into())
             }
             TokenKind::IdentifierName(_)
             | TokenKind::Punctuator(
@@ -112,9 +113,9 @@ where
             | TokenKind::TemplateMiddle(_) => {
                 let expr = AssignmentExpression::new(self.allow_in, true, self.allow_await)
                     .parse(cursor, interner)?;
-                Ok(Yield::new(Some(expr), false).into())
+                Ok(cursor.spanned(Yield::new(Some(expr), false), start).into())
             }
-            _ => Ok(Yield::new(None, false).into()),
+            _ => Ok(cursor.spanned(Yield::new(None, false), start).into()),
         }
     }
 }
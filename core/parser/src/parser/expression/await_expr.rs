@@ -48,12 +48,13 @@ where
     type Output = Await;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let start = cursor.next_start(interner)?;
         cursor.expect(
             TokenKind::Keyword((Keyword::Await, false)),
             "Await expression parsing",
             interner,
         )?;
         let expr = UnaryExpression::new(self.allow_yield, true).parse(cursor, interner)?;
-        Ok(expr.into())
+        Ok(cursor.spanned(Await::from(expr), start))
     }
 }
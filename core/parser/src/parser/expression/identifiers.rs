@@ -11,7 +11,7 @@ use crate::{
     source::ReadChar,
     Error,
 };
-use boa_ast::expression::Identifier as AstIdentifier;
+use boa_ast::{expression::Identifier as AstIdentifier, Spanned};
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
 
@@ -205,6 +205,6 @@ where
             ));
         }
 
-        Ok(AstIdentifier::new(ident))
+        Ok(AstIdentifier::new(ident).with_span(tok.span()))
     }
 }
@@ -24,7 +24,7 @@ use boa_ast::{
         access::{PrivatePropertyAccess, SimplePropertyAccess},
         Call,
     },
-    Punctuator,
+    Position, Punctuator,
 };
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
@@ -40,14 +40,18 @@ pub(super) struct CallExpression {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     first_member_expr: ast::Expression,
+    position: Position,
 }
 
 impl CallExpression {
     /// Creates a new `CallExpression` parser.
+    ///
+    /// `position` is the start of `first_member_expr` in the source code.
     pub(super) fn new<Y, A>(
         allow_yield: Y,
         allow_await: A,
         first_member_expr: ast::Expression,
+        position: Position,
     ) -> Self
     where
         Y: Into<AllowYield>,
@@ -57,6 +61,7 @@ impl CallExpression {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
             first_member_expr,
+            position,
         }
     }
 }
@@ -75,7 +80,9 @@ where
         let lhs = if token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
             let args =
                 Arguments::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
-            Call::new(self.first_member_expr, args).into()
+            Call::new(self.first_member_expr, args)
+                .with_position(self.position)
+                .into()
         } else {
             let next_token = cursor.next(interner)?.expect("token vanished");
             return Err(Error::expected(
@@ -86,7 +93,8 @@ where
             ));
         };
 
-        CallExpressionTail::new(self.allow_yield, self.allow_await, lhs).parse(cursor, interner)
+        CallExpressionTail::new(self.allow_yield, self.allow_await, lhs, self.position)
+            .parse(cursor, interner)
     }
 }
 
@@ -96,11 +104,19 @@ pub(super) struct CallExpressionTail {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     call: ast::Expression,
+    position: Position,
 }
 
 impl CallExpressionTail {
     /// Creates a new `CallExpressionTail` parser.
-    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, call: ast::Expression) -> Self
+    ///
+    /// `position` is the start of `call` in the source code.
+    pub(super) fn new<Y, A>(
+        allow_yield: Y,
+        allow_await: A,
+        call: ast::Expression,
+        position: Position,
+    ) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -109,6 +125,7 @@ impl CallExpressionTail {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
             call,
+            position,
         }
     }
 }
@@ -120,7 +137,12 @@ where
     type Output = ast::Expression;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
-        let mut lhs = self.call;
+        let start = self.position;
+        let mut lhs = cursor.spanned(self.call, start);
+
+        // The position reported for a call is the start of the property name being called,
+        // which points closer to the failing call in long chains than the start of the chain.
+        let mut position = self.position;
 
         while let Some(tok) = cursor.peek(0, interner)? {
             let token = tok.clone();
@@ -128,12 +150,14 @@ where
                 TokenKind::Punctuator(Punctuator::OpenParen) => {
                     let args = Arguments::new(self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?;
-                    lhs = ast::Expression::from(Call::new(lhs, args));
+                    lhs = ast::Expression::from(Call::new(lhs, args).with_position(position));
                 }
                 TokenKind::Punctuator(Punctuator::Dot) => {
                     cursor.advance(interner);
 
-                    let access = match cursor.next(interner).or_abrupt()?.kind() {
+                    let name = cursor.next(interner).or_abrupt()?;
+                    position = name.span().start();
+                    let access = match name.kind() {
                         TokenKind::IdentifierName((name, _)) => {
                             SimplePropertyAccess::new(lhs, *name).into()
                         }
@@ -165,6 +189,7 @@ where
                     lhs = ast::Expression::PropertyAccess(access);
                 }
                 TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    position = token.span().start();
                     cursor.advance(interner);
                     let idx = Expression::new(true, self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?;
@@ -184,6 +209,7 @@ where
                 }
                 _ => break,
             }
+            lhs = cursor.spanned(lhs, start);
         }
 
         Ok(lhs)
@@ -26,7 +26,7 @@ use boa_ast::{
         },
         Call, New,
     },
-    Keyword, Punctuator,
+    Keyword, Punctuator, SpanIgnoreEq,
 };
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
@@ -114,7 +114,7 @@ where
                     ));
                 }
 
-                ast::Expression::ImportMeta
+                ast::Expression::ImportMeta(SpanIgnoreEq::default())
             }
             TokenKind::Keyword((Keyword::New, false)) => {
                 cursor.advance(interner);
@@ -129,7 +129,7 @@ where
                             ));
                         }
                         TokenKind::IdentifierName((Sym::TARGET, ContainsEscapeSequence(false))) => {
-                            ast::Expression::NewTarget
+                            ast::Expression::NewTarget(SpanIgnoreEq::default())
                         }
                         _ => {
                             return Err(Error::general(
@@ -149,7 +149,7 @@ where
                         }
                         _ => Box::new([]),
                     };
-                    let call_node = Call::new(lhs_inner, args);
+                    let call_node = Call::new(lhs_inner, args).with_position(position);
 
                     ast::Expression::from(New::from(call_node))
                 };
@@ -211,6 +211,7 @@ where
             _ => PrimaryExpression::new(self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?,
         };
+        lhs = cursor.spanned(lhs, position);
 
         cursor.set_goal(InputElement::TemplateTail);
 
@@ -276,6 +277,7 @@ where
                 }
                 _ => break,
             }
+            lhs = cursor.spanned(lhs, position);
         }
 
         Ok(lhs)
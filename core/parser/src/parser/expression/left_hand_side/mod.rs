@@ -21,14 +21,13 @@ use crate::{
     parser::{
         expression::{
             left_hand_side::{
-                arguments::Arguments,
                 call::{CallExpression, CallExpressionTail},
                 member::MemberExpression,
                 optional::OptionalExpression,
             },
             AssignmentExpression,
         },
-        AllowAwait, AllowYield, Cursor, ParseResult, TokenParser,
+        AllowAwait, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
     },
     source::ReadChar,
     Error,
@@ -40,6 +39,8 @@ use boa_ast::{
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 
+pub(in crate::parser) use arguments::Arguments;
+
 /// Parses a left hand side expression.
 ///
 /// More information:
@@ -116,11 +117,14 @@ where
 
         cursor.set_goal(InputElement::TemplateTail);
 
+        let position = cursor.peek(0, interner).or_abrupt()?.span().start();
+
         let mut lhs = if is_keyword_call(Keyword::Super, cursor, interner)? {
             cursor.advance(interner);
             let args =
                 Arguments::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
-            SuperCall::new(args).into()
+            let call = SuperCall::new(args).with_position(position);
+            cursor.spanned(call, position).into()
         } else if is_keyword_call(Keyword::Import, cursor, interner)? {
             // `import`
             cursor.advance(interner);
@@ -130,6 +134,19 @@ where
             let arg = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
 
+            // `,` AssignmentExpression[opt] `,`[opt]
+            let mut options = None;
+            if cursor.next_if(Punctuator::Comma, interner)?.is_some()
+                && cursor.peek(0, interner).or_abrupt()?.kind()
+                    != &TokenKind::Punctuator(Punctuator::CloseParen)
+            {
+                options = Some(
+                    AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)?,
+                );
+                cursor.next_if(Punctuator::Comma, interner)?;
+            }
+
             cursor.expect(
                 TokenKind::Punctuator(Punctuator::CloseParen),
                 "import call",
@@ -139,7 +156,8 @@ where
             CallExpressionTail::new(
                 self.allow_yield,
                 self.allow_await,
-                ImportCall::new(arg).into(),
+                ImportCall::new(arg, options).into(),
+                position,
             )
             .parse(cursor, interner)?
         } else {
@@ -147,8 +165,9 @@ where
                 .parse(cursor, interner)?;
             if let Some(tok) = cursor.peek(0, interner)? {
                 if tok.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
-                    member = CallExpression::new(self.allow_yield, self.allow_await, member)
-                        .parse(cursor, interner)?;
+                    member =
+                        CallExpression::new(self.allow_yield, self.allow_await, member, position)
+                            .parse(cursor, interner)?;
                 }
             }
             member
@@ -156,9 +175,9 @@ where
 
         if let Some(tok) = cursor.peek(0, interner)? {
             if tok.kind() == &TokenKind::Punctuator(Punctuator::Optional) {
-                lhs = OptionalExpression::new(self.allow_yield, self.allow_await, lhs)
-                    .parse(cursor, interner)?
-                    .into();
+                let optional = OptionalExpression::new(self.allow_yield, self.allow_await, lhs)
+                    .parse(cursor, interner)?;
+                lhs = cursor.spanned(optional, position).into();
             }
         }
 
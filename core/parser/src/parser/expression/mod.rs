@@ -45,8 +45,8 @@ use boa_profiler::Profiler;
 
 pub(super) use self::{assignment::AssignmentExpression, primary::Initializer};
 pub(in crate::parser) use {
-    identifiers::{BindingIdentifier, LabelIdentifier},
-    left_hand_side::LeftHandSideExpression,
+    identifiers::{BindingIdentifier, IdentifierReference, LabelIdentifier},
+    left_hand_side::{Arguments, LeftHandSideExpression},
     primary::object_initializer::{
         AsyncGeneratorMethod, AsyncMethod, GeneratorMethod, PropertyName,
     },
@@ -84,16 +84,18 @@ macro_rules! expression {
                     cursor.set_goal($goal.unwrap());
                 }
 
+                let start = cursor.next_start(interner)?;
                 let mut lhs = $lower::new($( self.$low_param ),*).parse(cursor, interner)?;
                 while let Some(tok) = cursor.peek(0, interner)? {
                     match *tok.kind() {
                         TokenKind::Punctuator(op) if $( op == $op )||* => {
                             cursor.advance(interner);
-                            lhs = Binary::new(
+                            let binary = Binary::new(
                                 op.as_binary_op().expect("Could not get binary operation."),
                                 lhs,
                                 $lower::new($( self.$low_param ),*).parse(cursor, interner)?
-                            ).into();
+                            );
+                            lhs = cursor.spanned(binary, start).into();
                         }
                         _ => break
                     }
@@ -145,6 +147,9 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("Expression", "Parsing");
 
+        // The first token must be lexed with the goal symbol of `AssignmentExpression`.
+        cursor.set_goal(InputElement::RegExp);
+        let start = cursor.next_start(interner)?;
         let mut lhs = AssignmentExpression::new(self.allow_in, self.allow_yield, self.allow_await)
             .parse(cursor, interner)?;
         while let Some(tok) = cursor.peek(0, interner)? {
@@ -164,7 +169,7 @@ where
 
                     cursor.advance(interner);
 
-                    lhs = Binary::new(
+                    let binary = Binary::new(
                         Punctuator::Comma
                             .as_binary_op()
                             .expect("Could not get binary operation."),
@@ -175,8 +180,8 @@ where
                             self.allow_await,
                         )
                         .parse(cursor, interner)?,
-                    )
-                    .into();
+                    );
+                    lhs = cursor.spanned(binary, start).into();
                 }
                 _ => break,
             }
@@ -254,6 +259,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("ShortCircuitExpression", "Parsing");
 
+        let start = cursor.next_start(interner)?;
         let mut current_node =
             BitwiseORExpression::new(self.allow_in, self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
@@ -275,8 +281,8 @@ where
                         BitwiseORExpression::new(self.allow_in, self.allow_yield, self.allow_await)
                             .parse(cursor, interner)?;
 
-                    current_node =
-                        Binary::new(BinaryOp::Logical(LogicalOp::And), current_node, rhs).into();
+                    let binary = Binary::new(BinaryOp::Logical(LogicalOp::And), current_node, rhs);
+                    current_node = cursor.spanned(binary, start).into();
                 }
                 TokenKind::Punctuator(Punctuator::BoolOr) => {
                     if previous == PreviousExpr::Coalesce {
@@ -295,8 +301,8 @@ where
                         PreviousExpr::Logical,
                     )
                     .parse(cursor, interner)?;
-                    current_node =
-                        Binary::new(BinaryOp::Logical(LogicalOp::Or), current_node, rhs).into();
+                    let binary = Binary::new(BinaryOp::Logical(LogicalOp::Or), current_node, rhs);
+                    current_node = cursor.spanned(binary, start).into();
                 }
                 TokenKind::Punctuator(Punctuator::Coalesce) => {
                     if previous == PreviousExpr::Logical {
@@ -312,9 +318,9 @@ where
                     let rhs =
                         BitwiseORExpression::new(self.allow_in, self.allow_yield, self.allow_await)
                             .parse(cursor, interner)?;
-                    current_node =
-                        Binary::new(BinaryOp::Logical(LogicalOp::Coalesce), current_node, rhs)
-                            .into();
+                    let binary =
+                        Binary::new(BinaryOp::Logical(LogicalOp::Coalesce), current_node, rhs);
+                    current_node = cursor.spanned(binary, start).into();
                 }
                 _ => break,
             }
@@ -524,6 +530,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("Relation Expression", "Parsing");
 
+        let start = cursor.next_start(interner)?;
         if self.allow_in.0 {
             let token = cursor.peek(0, interner).or_abrupt()?;
             if let TokenKind::PrivateIdentifier(identifier) = token.kind() {
@@ -543,7 +550,8 @@ where
                         let rhs = ShiftExpression::new(self.allow_yield, self.allow_await)
                             .parse(cursor, interner)?;
 
-                        return Ok(BinaryInPrivate::new(PrivateName::new(identifier), rhs).into());
+                        let binary = BinaryInPrivate::new(PrivateName::new(identifier), rhs);
+                        return Ok(cursor.spanned(binary, start).into());
                     }
                     _ => {}
                 }
@@ -562,13 +570,13 @@ where
                         || op == Punctuator::GreaterThanOrEq =>
                 {
                     cursor.advance(interner);
-                    lhs = Binary::new(
+                    let binary = Binary::new(
                         op.as_binary_op().expect("Could not get binary operation."),
                         lhs,
                         ShiftExpression::new(self.allow_yield, self.allow_await)
                             .parse(cursor, interner)?,
-                    )
-                    .into();
+                    );
+                    lhs = cursor.spanned(binary, start).into();
                 }
                 TokenKind::Keyword((Keyword::InstanceOf | Keyword::In, true)) => {
                     return Err(Error::general(
@@ -581,13 +589,13 @@ where
                         || (op == Keyword::In && self.allow_in == AllowIn(true)) =>
                 {
                     cursor.advance(interner);
-                    lhs = Binary::new(
+                    let binary = Binary::new(
                         op.as_binary_op().expect("Could not get binary operation."),
                         lhs,
                         ShiftExpression::new(self.allow_yield, self.allow_await)
                             .parse(cursor, interner)?,
-                    )
-                    .into();
+                    );
+                    lhs = cursor.spanned(binary, start).into();
                 }
                 _ => break,
             }
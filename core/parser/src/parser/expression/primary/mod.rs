@@ -36,7 +36,7 @@ use crate::{
             identifiers::IdentifierReference, primary::template::TemplateLiteral,
             BindingIdentifier, Expression,
         },
-        statement::{ArrayBindingPattern, ObjectBindingPattern},
+        statement::{ArrayBindingPattern, DecoratorList, ObjectBindingPattern},
         AllowAwait, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
     },
     source::ReadChar,
@@ -47,14 +47,14 @@ use boa_ast::{
     self as ast,
     declaration::Variable,
     expression::{
-        literal::{self, Literal, TemplateElement},
+        literal::{self, Literal, LiteralKind, TemplateElement},
         operator::{assign::AssignTarget, binary::BinaryOp},
         Identifier, Parenthesized,
     },
     function::{FormalParameter, FormalParameterList},
     operations::{contains, ContainsSymbol},
     pattern::{ArrayPatternElement, ObjectPatternElement, Pattern},
-    Keyword, Punctuator, Span,
+    Keyword, Punctuator, Span, SpanIgnoreEq,
 };
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
@@ -103,7 +103,7 @@ where
         let tok = cursor.peek(0, interner).or_abrupt()?;
         let tok_position = tok.span().start();
 
-        match tok.kind() {
+        let expr = match tok.kind() {
             TokenKind::Keyword((Keyword::This, true))
             | TokenKind::BooleanLiteral((_, ContainsEscapeSequence(true)))
             | TokenKind::NullLiteral(ContainsEscapeSequence(true)) => Err(Error::general(
@@ -112,7 +112,7 @@ where
             )),
             TokenKind::Keyword((Keyword::This, false)) => {
                 cursor.advance(interner);
-                Ok(ast::Expression::This)
+                Ok(ast::Expression::This(SpanIgnoreEq::default()))
             }
             TokenKind::Keyword((Keyword::Function, _)) => {
                 let next_token = cursor.peek(1, interner).or_abrupt()?;
@@ -132,9 +132,21 @@ where
                     .parse(cursor, interner)
                     .map(Into::into)
             }
+            TokenKind::Punctuator(Punctuator::At) => {
+                let decorators = DecoratorList::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?;
+                cursor.expect(
+                    (Keyword::Class, false),
+                    "decorated class expression",
+                    interner,
+                )?;
+                ClassExpression::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)
+                    .map(|class| class.with_decorators(decorators).into())
+            }
             TokenKind::Keyword((Keyword::Debugger, _)) => {
                 cursor.advance(interner);
-                Ok(ast::Expression::Debugger)
+                Ok(ast::Expression::Debugger(SpanIgnoreEq::default()))
             }
             TokenKind::Keyword((Keyword::Async, contain_escaped_char)) => {
                 let contain_escaped_char = *contain_escaped_char;
@@ -198,7 +210,7 @@ where
             }
             TokenKind::NullLiteral(_) => {
                 cursor.advance(interner);
-                Ok(Literal::Null.into())
+                Ok(Literal::from(LiteralKind::Null).into())
             }
             TokenKind::IdentifierName(_)
             | TokenKind::Keyword((
@@ -283,7 +295,9 @@ where
                 tok.span(),
                 "primary expression",
             )),
-        }
+        }?;
+
+        Ok(cursor.spanned(expr, tok_position))
     }
 }
 
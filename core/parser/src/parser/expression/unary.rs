@@ -65,7 +65,7 @@ where
 
         let tok = cursor.peek(0, interner).or_abrupt()?;
         let token_start = tok.span().start();
-        match tok.kind() {
+        let expr = match tok.kind() {
             TokenKind::Keyword((Keyword::Delete | Keyword::Void | Keyword::TypeOf, true)) => Err(
                 Error::general("Keyword must not contain escaped characters", token_start),
             ),
@@ -126,6 +126,8 @@ where
                 Ok((AwaitExpression::new(self.allow_yield).parse(cursor, interner)?).into())
             }
             _ => UpdateExpression::new(self.allow_yield, self.allow_await).parse(cursor, interner),
-        }
+        }?;
+
+        Ok(cursor.spanned(expr, token_start))
     }
 }
@@ -107,7 +107,11 @@ where
                             position,
                         )))
                     },
-                    |target| Ok(Update::new(UpdateOp::IncrementPre, target).into()),
+                    |target| {
+                        Ok(cursor
+                            .spanned(Update::new(UpdateOp::IncrementPre, target), position)
+                            .into())
+                    },
                 );
             }
             TokenKind::Punctuator(Punctuator::Dec) => {
@@ -126,7 +130,11 @@ where
                             position,
                         )))
                     },
-                    |target| Ok(Update::new(UpdateOp::DecrementPre, target).into()),
+                    |target| {
+                        Ok(cursor
+                            .spanned(Update::new(UpdateOp::DecrementPre, target), position)
+                            .into())
+                    },
                 );
             }
             _ => {}
@@ -155,7 +163,11 @@ where
                                 token_start,
                             )))
                         },
-                        |target| Ok(Update::new(UpdateOp::IncrementPost, target).into()),
+                        |target| {
+                            Ok(cursor
+                                .spanned(Update::new(UpdateOp::IncrementPost, target), position)
+                                .into())
+                        },
                     );
                 }
                 TokenKind::Punctuator(Punctuator::Dec) => {
@@ -171,7 +183,11 @@ where
                                 token_start,
                             )))
                         },
-                        |target| Ok(Update::new(UpdateOp::DecrementPost, target).into()),
+                        |target| {
+                            Ok(cursor
+                                .spanned(Update::new(UpdateOp::DecrementPost, target), position)
+                                .into())
+                        },
                     );
                 }
                 _ => {}
@@ -27,7 +27,7 @@ use boa_ast::{
         lexically_declared_names, var_declared_names, ContainsSymbol,
     },
     scope::Scope,
-    Position, StatementList,
+    Declaration, Position, StatementList, StatementListItem,
 };
 use boa_interner::Interner;
 use rustc_hash::FxHashSet;
@@ -221,6 +221,80 @@ impl<'a, R: ReadChar> Parser<'a, R> {
         Ok((module, source))
     }
 
+    /// Parse the full input as a [ECMAScript Script][spec] into the boa AST representation,
+    /// recovering from syntax errors.
+    ///
+    /// Instead of stopping at the first syntax error, the parser records it and skips the rest of
+    /// the statement or declaration that contains it, replacing it with a
+    /// [`Statement::Error`][boa_ast::Statement::Error] placeholder. This is done for the
+    /// statement lists of blocks and functions too, so the returned `Script` contains all the
+    /// valid statements of the input, and the returned errors contain all the syntax errors that
+    /// were found, in source order.
+    ///
+    /// A `Script` with errors must not be compiled, since its placeholders throw a `SyntaxError`
+    /// when they are executed.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-Script
+    pub fn parse_script_recovering(
+        &mut self,
+        scope: &Scope,
+        interner: &mut Interner,
+    ) -> (boa_ast::Script, Vec<Error>) {
+        self.cursor.set_goal(InputElement::HashbangOrRegExp);
+        self.cursor.set_recovering(true);
+        let result = ScriptParser::new(false).parse(&mut self.cursor, interner);
+        let mut errors = self.cursor.take_errors();
+        self.cursor.set_recovering(false);
+
+        let mut ast = match result {
+            Ok((ast, _)) => ast,
+            Err(error) => {
+                errors.push(error);
+                boa_ast::Script::default()
+            }
+        };
+        if !ast.analyze_scope(scope, interner) {
+            errors.push(Error::general(
+                "invalid scope analysis",
+                Position::new(1, 1),
+            ));
+        }
+        (ast, errors)
+    }
+
+    /// Parse the full input as an [ECMAScript Module][spec] into the boa AST representation,
+    /// recovering from syntax errors.
+    ///
+    /// See [`Parser::parse_script_recovering`] for more information.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-Module
+    pub fn parse_module_recovering(
+        &mut self,
+        scope: &Scope,
+        interner: &mut Interner,
+    ) -> (boa_ast::Module, Vec<Error>) {
+        self.cursor.set_goal(InputElement::HashbangOrRegExp);
+        self.cursor.set_recovering(true);
+        let result = ModuleParser.parse(&mut self.cursor, interner);
+        let mut errors = self.cursor.take_errors();
+        self.cursor.set_recovering(false);
+
+        let mut module = match result {
+            Ok((module, _)) => module,
+            Err(error) => {
+                errors.push(error);
+                boa_ast::Module::default()
+            }
+        };
+        if !module.analyze_scope(scope, interner) {
+            errors.push(Error::general(
+                "invalid scope analysis",
+                Position::new(1, 1),
+            ));
+        }
+        (module, errors)
+    }
+
     /// [`19.2.1.1 PerformEval ( x, strictCaller, direct )`][spec]
     ///
     /// Parses the source text input of an `eval` call.
@@ -296,6 +370,17 @@ impl<R> Parser<'_, R> {
     {
         self.cursor.set_identifier(identifier);
     }
+
+    /// Set the parser to record the comments of the source.
+    ///
+    /// The recorded comments are attached to the parsed [`Script`][boa_ast::Script] or
+    /// [`Module`][boa_ast::Module], and can be retrieved with their `comments` method.
+    pub fn set_record_comments(&mut self)
+    where
+        R: ReadChar,
+    {
+        self.cursor.set_record_comments(true);
+    }
 }
 
 /// Parses a full script.
@@ -326,29 +411,30 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let stmts =
             ScriptBody::new(true, cursor.strict(), self.direct_eval).parse(cursor, interner)?;
-        let script = boa_ast::Script::new(stmts);
+        let mut script = boa_ast::Script::new(stmts);
 
         // It is a Syntax Error if the LexicallyDeclaredNames of ScriptBody contains any duplicate entries.
         let mut lexical_names = FxHashSet::default();
         for name in lexically_declared_names(&script) {
             if !lexical_names.insert(name) {
-                return Err(Error::general(
+                cursor.report(Error::general(
                     "lexical name declared multiple times",
                     Position::new(1, 1),
-                ));
+                ))?;
             }
         }
 
         // It is a Syntax Error if any element of the LexicallyDeclaredNames of ScriptBody also occurs in the VarDeclaredNames of ScriptBody.
         for name in var_declared_names(&script) {
             if lexical_names.contains(&name) {
-                return Err(Error::general(
+                cursor.report(Error::general(
                     "lexical name declared multiple times",
                     Position::new(1, 1),
-                ));
+                ))?;
             }
         }
 
+        script.set_comments(cursor.take_comments());
         let source = cursor.take_source();
         Ok((script, source))
     }
@@ -401,41 +487,54 @@ where
             // code that is being processed by a direct eval.
             // Additional early error rules for super within direct eval are defined in 19.2.1.1.
             if contains(&body, ContainsSymbol::Super) {
-                return Err(Error::general("invalid super usage", Position::new(1, 1)));
+                cursor.report(Error::general("invalid super usage", Position::new(1, 1)))?;
             }
             // It is a Syntax Error if StatementList Contains NewTarget unless the source text containing NewTarget
             // is eval code that is being processed by a direct eval.
             // Additional early error rules for NewTarget in direct eval are defined in 19.2.1.1.
             if contains(&body, ContainsSymbol::NewTarget) {
-                return Err(Error::general(
+                cursor.report(Error::general(
                     "invalid new.target usage",
                     Position::new(1, 1),
-                ));
+                ))?;
             }
 
             // It is a Syntax Error if AllPrivateIdentifiersValid of StatementList with
             // argument « » is false unless the source text containing ScriptBody is
             // eval code that is being processed by a direct eval.
             if !all_private_identifiers_valid(&body, Vec::new()) {
-                return Err(Error::general(
+                cursor.report(Error::general(
                     "invalid private identifier usage",
                     Position::new(1, 1),
-                ));
+                ))?;
             }
         }
 
         if let Err(error) = check_labels(&body) {
-            return Err(Error::lex(LexError::Syntax(
+            cursor.report(Error::lex(LexError::Syntax(
                 error.message(interner).into(),
                 Position::new(1, 1),
-            )));
+            )))?;
+        }
+
+        // It is a Syntax Error if the goal symbol is Script and UsingDeclaration or
+        // AwaitUsingDeclaration is not contained, either directly or indirectly, within a Block,
+        // ForStatement, ForInOfStatement, FunctionBody, GeneratorBody, AsyncGeneratorBody,
+        // AsyncFunctionBody, ClassStaticBlockBody, or ClassBody.
+        if body.statements().iter().any(|item| {
+            matches!(item, StatementListItem::Declaration(Declaration::Lexical(decl)) if decl.is_using())
+        }) {
+            cursor.report(Error::lex(LexError::Syntax(
+                "using declarations are not allowed at the top level of a script".into(),
+                Position::new(1, 1),
+            )))?;
         }
 
         if contains_invalid_object_literal(&body) {
-            return Err(Error::lex(LexError::Syntax(
+            cursor.report(Error::lex(LexError::Syntax(
                 "invalid object literal in script statement list".into(),
                 Position::new(1, 1),
-            )));
+            )))?;
         }
 
         Ok(body)
@@ -460,19 +559,19 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         cursor.set_module();
 
-        let module = boa_ast::Module::new(ModuleItemList.parse(cursor, interner)?);
+        let mut module = boa_ast::Module::new(ModuleItemList.parse(cursor, interner)?);
 
         // It is a Syntax Error if the LexicallyDeclaredNames of ModuleItemList contains any duplicate entries.
         let mut bindings = FxHashSet::default();
         for name in lexically_declared_names(&module) {
             if !bindings.insert(name) {
-                return Err(Error::general(
+                cursor.report(Error::general(
                     format!(
                         "lexical name `{}` declared multiple times",
                         interner.resolve_expect(name.sym())
                     ),
                     Position::new(1, 1),
-                ));
+                ))?;
             }
         }
 
@@ -480,13 +579,13 @@ where
         // VarDeclaredNames of ModuleItemList.
         for name in var_declared_names(&module) {
             if !bindings.insert(name) {
-                return Err(Error::general(
+                cursor.report(Error::general(
                     format!(
                         "lexical name `{}` declared multiple times",
                         interner.resolve_expect(name.sym())
                     ),
                     Position::new(1, 1),
-                ));
+                ))?;
             }
         }
 
@@ -495,13 +594,13 @@ where
             let mut exported_names = FxHashSet::default();
             for name in module.items().exported_names() {
                 if !exported_names.insert(name) {
-                    return Err(Error::general(
+                    cursor.report(Error::general(
                         format!(
                             "exported name `{}` declared multiple times",
                             interner.resolve_expect(name)
                         ),
                         Position::new(1, 1),
-                    ));
+                    ))?;
                 }
             }
         }
@@ -510,50 +609,51 @@ where
         // the VarDeclaredNames of ModuleItemList, or the LexicallyDeclaredNames of ModuleItemList.
         for name in module.items().exported_bindings() {
             if !bindings.contains(&name) {
-                return Err(Error::general(
+                cursor.report(Error::general(
                     format!(
                         "could not find the exported binding `{}` in the declared names of the module",
                         interner.resolve_expect(name.sym())
                     ),
                     Position::new(1, 1),
-                ));
+                ))?;
             }
         }
 
         // It is a Syntax Error if ModuleItemList Contains super.
         if contains(&module, ContainsSymbol::Super) {
-            return Err(Error::general(
+            cursor.report(Error::general(
                 "module cannot contain `super` on the top-level",
                 Position::new(1, 1),
-            ));
+            ))?;
         }
 
         // It is a Syntax Error if ModuleItemList Contains NewTarget.
         if contains(&module, ContainsSymbol::NewTarget) {
-            return Err(Error::general(
+            cursor.report(Error::general(
                 "module cannot contain `new.target` on the top-level",
                 Position::new(1, 1),
-            ));
+            ))?;
         }
 
         // It is a Syntax Error if ContainsDuplicateLabels of ModuleItemList with argument « » is true.
         // It is a Syntax Error if ContainsUndefinedBreakTarget of ModuleItemList with argument « » is true.
         // It is a Syntax Error if ContainsUndefinedContinueTarget of ModuleItemList with arguments « » and « » is true.
-        check_labels(&module).map_err(|error| {
-            Error::lex(LexError::Syntax(
+        if let Err(error) = check_labels(&module) {
+            cursor.report(Error::lex(LexError::Syntax(
                 error.message(interner).into(),
                 Position::new(1, 1),
-            ))
-        })?;
+            )))?;
+        }
 
         // It is a Syntax Error if AllPrivateIdentifiersValid of ModuleItemList with argument « » is false.
         if !all_private_identifiers_valid(&module, Vec::new()) {
-            return Err(Error::general(
+            cursor.report(Error::general(
                 "invalid private identifier usage",
                 Position::new(1, 1),
-            ));
+            ))?;
         }
 
+        module.set_comments(cursor.take_comments());
         let source = cursor.take_source();
         Ok((module, source))
     }
@@ -5,8 +5,9 @@ use crate::{
     lexer::{token::ContainsEscapeSequence, Error as LexError, TokenKind},
     parser::{
         expression::{
-            AssignmentExpression, AsyncGeneratorMethod, AsyncMethod, BindingIdentifier,
-            GeneratorMethod, LeftHandSideExpression, PropertyName,
+            self, Arguments, AssignmentExpression, AsyncGeneratorMethod, AsyncMethod,
+            BindingIdentifier, GeneratorMethod, IdentifierReference, LeftHandSideExpression,
+            PropertyName,
         },
         function::{FunctionBody, UniqueFormalParameters, FUNCTION_BREAK_TOKENS},
         statement::StatementList,
@@ -24,11 +25,14 @@ use ast::{
 };
 use boa_ast::{
     self as ast,
-    expression::Identifier,
+    expression::{
+        access::{PrivatePropertyAccess, SimplePropertyAccess},
+        Call, Identifier, Parenthesized,
+    },
     function::{
         self, ClassDeclaration as ClassDeclarationNode, ClassElementName, ClassFieldDefinition,
-        ClassMethodDefinition, FormalParameterList, FunctionExpression, PrivateFieldDefinition,
-        StaticBlockBody,
+        ClassMethodDefinition, Decorator as DecoratorNode, FormalParameterList, FunctionExpression,
+        PrivateFieldDefinition, StaticBlockBody,
     },
     operations::{contains, contains_arguments, ContainsSymbol},
     Expression, Keyword, Punctuator,
@@ -75,6 +79,8 @@ where
     type Output = ClassDeclarationNode;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let decorators =
+            DecoratorList::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
         cursor.expect((Keyword::Class, false), "class declaration", interner)?;
         let strict = cursor.strict();
         cursor.set_strict(true);
@@ -100,12 +106,10 @@ where
         let (super_ref, constructor, elements) =
             ClassTail::new(name, self.allow_yield, self.allow_await).parse(cursor, interner)?;
 
-        Ok(ClassDeclarationNode::new(
-            name,
-            super_ref,
-            constructor,
-            elements.into_boxed_slice(),
-        ))
+        Ok(
+            ClassDeclarationNode::new(name, super_ref, constructor, elements.into_boxed_slice())
+                .with_decorators(decorators),
+        )
     }
 }
 
@@ -247,6 +251,146 @@ where
     }
 }
 
+/// `DecoratorList` parsing.
+///
+/// Parses all the decorators preceding a class or a class element, returning an empty list if
+/// there are none.
+///
+/// More information:
+///  - [Decorators proposal][spec]
+///
+/// [spec]: https://arai-a.github.io/ecma262-compare/?pr=2417#prod-DecoratorList
+#[derive(Debug, Clone, Copy)]
+pub(in crate::parser) struct DecoratorList {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl DecoratorList {
+    /// Creates a new `DecoratorList` parser.
+    pub(in crate::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for DecoratorList
+where
+    R: ReadChar,
+{
+    type Output = Box<[DecoratorNode]>;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let mut decorators = Vec::new();
+        while cursor.next_if(Punctuator::At, interner)?.is_some() {
+            decorators
+                .push(Decorator::new(self.allow_yield, self.allow_await).parse(cursor, interner)?);
+        }
+        Ok(decorators.into_boxed_slice())
+    }
+}
+
+/// `Decorator` parsing.
+///
+/// Parses the expression of a decorator, after the leading `@` has been consumed.
+///
+/// More information:
+///  - [Decorators proposal][spec]
+///
+/// [spec]: https://arai-a.github.io/ecma262-compare/?pr=2417#prod-Decorator
+#[derive(Debug, Clone, Copy)]
+struct Decorator {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl Decorator {
+    /// Creates a new `Decorator` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for Decorator
+where
+    R: ReadChar,
+{
+    type Output = DecoratorNode;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        // DecoratorParenthesizedExpression : ( Expression )
+        if cursor.next_if(Punctuator::OpenParen, interner)?.is_some() {
+            let expression = expression::Expression::new(true, self.allow_yield, self.allow_await)
+                .parse(cursor, interner)?;
+            cursor.expect(Punctuator::CloseParen, "decorator", interner)?;
+            return Ok(DecoratorNode::new(Parenthesized::new(expression).into()));
+        }
+
+        // DecoratorMemberExpression :
+        //     IdentifierReference
+        //     DecoratorMemberExpression . IdentifierName
+        //     DecoratorMemberExpression . PrivateIdentifier
+        let mut decorator: Expression =
+            IdentifierReference::new(self.allow_yield, self.allow_await)
+                .parse(cursor, interner)?
+                .into();
+        while cursor.next_if(Punctuator::Dot, interner)?.is_some() {
+            let token = cursor.next(interner).or_abrupt()?;
+            let access = match token.kind() {
+                TokenKind::IdentifierName((name, _)) => {
+                    SimplePropertyAccess::new(decorator, *name).into()
+                }
+                TokenKind::Keyword((kw, _)) => {
+                    SimplePropertyAccess::new(decorator, kw.to_sym()).into()
+                }
+                TokenKind::BooleanLiteral((true, _)) => {
+                    SimplePropertyAccess::new(decorator, Sym::TRUE).into()
+                }
+                TokenKind::BooleanLiteral((false, _)) => {
+                    SimplePropertyAccess::new(decorator, Sym::FALSE).into()
+                }
+                TokenKind::NullLiteral(_) => SimplePropertyAccess::new(decorator, Sym::NULL).into(),
+                TokenKind::PrivateIdentifier(name) => {
+                    PrivatePropertyAccess::new(decorator, PrivateName::new(*name)).into()
+                }
+                _ => {
+                    return Err(Error::expected(
+                        ["identifier".to_owned()],
+                        token.to_string(interner),
+                        token.span(),
+                        "decorator",
+                    ));
+                }
+            };
+            decorator = Expression::PropertyAccess(access);
+        }
+
+        // DecoratorCallExpression : DecoratorMemberExpression Arguments
+        if cursor.peek(0, interner).or_abrupt()?.kind()
+            == &TokenKind::Punctuator(Punctuator::OpenParen)
+        {
+            let args =
+                Arguments::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
+            decorator = Call::new(decorator, args).into();
+        }
+
+        Ok(DecoratorNode::new(decorator))
+    }
+}
+
 /// `ClassBody` parsing.
 ///
 /// More information:
@@ -522,7 +666,21 @@ where
     type Output = (Option<FunctionExpression>, Option<function::ClassElement>);
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let decorators =
+            DecoratorList::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
+
         let token = cursor.peek(0, interner).or_abrupt()?;
+        if !decorators.is_empty()
+            && matches!(
+                token.kind(),
+                TokenKind::Punctuator(Punctuator::Semicolon | Punctuator::CloseBlock)
+            )
+        {
+            return Err(Error::general(
+                "decorators must be followed by a class element",
+                token.span().start(),
+            ));
+        }
         let r#static = match token.kind() {
             TokenKind::Punctuator(Punctuator::Semicolon) => {
                 cursor.advance(interner);
@@ -567,6 +725,23 @@ where
             )
         );
 
+        // `accessor` is only a keyword if it is followed by a class element name on the same line.
+        let is_accessor = matches!(
+            cursor.peek(0, interner).or_abrupt()?.kind(),
+            TokenKind::IdentifierName((Sym::ACCESSOR, _))
+        ) && !cursor.peek_is_line_terminator(1, interner).or_abrupt()?
+            && matches!(
+                cursor.peek(1, interner).or_abrupt()?.kind(),
+                TokenKind::IdentifierName(_)
+                    | TokenKind::StringLiteral(_)
+                    | TokenKind::NumericLiteral(_)
+                    | TokenKind::Keyword(_)
+                    | TokenKind::NullLiteral(_)
+                    | TokenKind::BooleanLiteral(_)
+                    | TokenKind::PrivateIdentifier(_)
+                    | TokenKind::Punctuator(Punctuator::OpenBracket)
+            );
+
         let token = cursor.peek(0, interner).or_abrupt()?;
         let start_linear_span = token.linear_span();
         let start_linear_pos = start_linear_span.start();
@@ -574,6 +749,12 @@ where
         let position = token.span().start();
         let element = match token.kind() {
             TokenKind::IdentifierName((Sym::CONSTRUCTOR, _)) if !r#static => {
+                if !decorators.is_empty() {
+                    return Err(Error::general(
+                        "decorators cannot be applied to a class constructor",
+                        position,
+                    ));
+                }
                 cursor.advance(interner);
                 let strict = cursor.strict();
                 cursor.set_strict(true);
@@ -603,6 +784,12 @@ where
                 ));
             }
             TokenKind::Punctuator(Punctuator::OpenBlock) if r#static => {
+                if !decorators.is_empty() {
+                    return Err(Error::general(
+                        "decorators cannot be applied to a class static block",
+                        position,
+                    ));
+                }
                 cursor.advance(interner);
                 let statement_list = if cursor
                     .next_if(TokenKind::Punctuator(Punctuator::CloseBlock), interner)?
@@ -687,6 +874,103 @@ where
                 };
                 function::ClassElement::StaticBlock(StaticBlockBody::new(statement_list.into()))
             }
+            TokenKind::IdentifierName((Sym::ACCESSOR, ContainsEscapeSequence(true)))
+                if is_accessor =>
+            {
+                return Err(Error::general(
+                    "keyword must not contain escaped characters",
+                    token.span().start(),
+                ))
+            }
+            TokenKind::IdentifierName((Sym::ACCESSOR, ContainsEscapeSequence(false)))
+                if is_accessor =>
+            {
+                cursor.advance(interner);
+                let token = cursor.peek(0, interner).or_abrupt()?;
+                let name_position = token.span().start();
+                let name = match token.kind() {
+                    TokenKind::PrivateIdentifier(Sym::CONSTRUCTOR) => {
+                        return Err(Error::general(
+                            "class constructor may not be a private method",
+                            name_position,
+                        ))
+                    }
+                    TokenKind::PrivateIdentifier(name) => {
+                        let name = *name;
+                        cursor.advance(interner);
+                        ClassElementName::PrivateName(PrivateName::new(name))
+                    }
+                    _ => ClassElementName::PropertyName(
+                        PropertyName::new(self.allow_yield, self.allow_await)
+                            .parse(cursor, interner)?,
+                    ),
+                };
+                if let ClassElementName::PropertyName(name) = &name {
+                    if let Some(name) = name.literal() {
+                        if r#static {
+                            if [Sym::CONSTRUCTOR, Sym::PROTOTYPE].contains(&name) {
+                                return Err(Error::general(
+                                    "class may not have static field definitions named 'constructor' or 'prototype'",
+                                    name_position,
+                                ));
+                            }
+                        } else if name == Sym::CONSTRUCTOR {
+                            return Err(Error::general(
+                                "class may not have field definitions named 'constructor'",
+                                name_position,
+                            ));
+                        }
+                    }
+                }
+
+                let strict = cursor.strict();
+                cursor.set_strict(true);
+                let initializer = if cursor.next_if(Punctuator::Assign, interner)?.is_some() {
+                    let mut rhs =
+                        AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                            .parse(cursor, interner)?;
+                    let function_name = match &name {
+                        ClassElementName::PropertyName(name) => name.literal(),
+                        ClassElementName::PrivateName(name) => Some(
+                            interner.get_or_intern(
+                                [
+                                    utf16!("#"),
+                                    interner.resolve_expect(name.description()).utf16(),
+                                ]
+                                .concat()
+                                .as_slice(),
+                            ),
+                        ),
+                    };
+                    if let Some(function_name) = function_name {
+                        rhs.set_anonymous_function_definition_name(&Identifier::new(function_name));
+                    }
+                    Some(rhs)
+                } else {
+                    None
+                };
+                cursor.expect_semicolon("expected semicolon", interner)?;
+                cursor.set_strict(strict);
+
+                match name {
+                    ClassElementName::PropertyName(name) => {
+                        let field = ClassFieldDefinition::new_accessor(name, initializer);
+                        if r#static {
+                            function::ClassElement::StaticFieldDefinition(field)
+                        } else {
+                            function::ClassElement::FieldDefinition(field)
+                        }
+                    }
+                    ClassElementName::PrivateName(name) => {
+                        let field = PrivateFieldDefinition::new_accessor(name, initializer);
+                        if r#static {
+                            function::ClassElement::PrivateStaticFieldDefinition(field)
+                        } else {
+                            function::ClassElement::PrivateFieldDefinition(field)
+                        }
+                    }
+                }
+            }
             TokenKind::Punctuator(Punctuator::Mul) => {
                 let token = cursor.peek(1, interner).or_abrupt()?;
                 let name_position = token.span().start();
@@ -1306,6 +1590,33 @@ where
             _ => {}
         }
 
+        if decorators.is_empty() {
+            return Ok((None, Some(element)));
+        }
+
+        let element = match element {
+            function::ClassElement::MethodDefinition(m) => {
+                function::ClassElement::MethodDefinition(m.with_decorators(decorators))
+            }
+            function::ClassElement::FieldDefinition(field) => {
+                function::ClassElement::FieldDefinition(field.with_decorators(decorators))
+            }
+            function::ClassElement::StaticFieldDefinition(field) => {
+                function::ClassElement::StaticFieldDefinition(field.with_decorators(decorators))
+            }
+            function::ClassElement::PrivateFieldDefinition(field) => {
+                function::ClassElement::PrivateFieldDefinition(field.with_decorators(decorators))
+            }
+            function::ClassElement::PrivateStaticFieldDefinition(field) => {
+                function::ClassElement::PrivateStaticFieldDefinition(
+                    field.with_decorators(decorators),
+                )
+            }
+            function::ClassElement::StaticBlock(_) => {
+                unreachable!("static blocks cannot be decorated")
+            }
+        };
+
         Ok((None, Some(element)))
     }
 }
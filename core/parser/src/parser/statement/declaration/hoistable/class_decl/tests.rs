@@ -1,4 +1,4 @@
-use crate::parser::tests::check_script_parser;
+use crate::parser::tests::{check_invalid_script, check_script_parser};
 use boa_ast::{
     declaration::{LexicalDeclaration, Variable, VariableList},
     expression::{
@@ -7,11 +7,11 @@ use boa_ast::{
         Call, Identifier,
     },
     function::{
-        ClassDeclaration, ClassElement, ClassFieldDefinition, ClassMethodDefinition,
-        FormalParameterList, FunctionBody, FunctionExpression,
+        ClassDeclaration, ClassElement, ClassElementName, ClassFieldDefinition,
+        ClassMethodDefinition, Decorator, FormalParameterList, FunctionBody, FunctionExpression,
     },
     property::{MethodDefinitionKind, PropertyName},
-    Declaration, Expression, Statement, StatementListItem,
+    Declaration, Expression, SpanIgnoreEq, Statement, StatementListItem,
 };
 use boa_interner::Interner;
 use boa_macros::utf16;
@@ -104,7 +104,7 @@ fn check_new_target_with_property_access() {
 
     let new_target = Expression::PropertyAccess(
         SimplePropertyAccess::new(
-            Expression::NewTarget,
+            Expression::NewTarget(SpanIgnoreEq::default()),
             interner.get_or_intern_static("name", utf16!("name")),
         )
         .into(),
@@ -175,3 +175,77 @@ fn check_new_target_with_property_access() {
         interner,
     );
 }
+
+#[test]
+fn check_decorators() {
+    let interner = &mut Interner::default();
+
+    let dec = Decorator::new(
+        Identifier::from(interner.get_or_intern_static("dec", utf16!("dec"))).into(),
+    );
+    let log = Decorator::new(Expression::Call(Call::new(
+        PropertyAccess::Simple(SimplePropertyAccess::new(
+            Identifier::from(interner.get_or_intern_static("console", utf16!("console"))).into(),
+            interner.get_or_intern_static("log", utf16!("log")),
+        ))
+        .into(),
+        [Literal::from(1).into()].into(),
+    )));
+
+    let elements = vec![
+        ClassElement::MethodDefinition(
+            ClassMethodDefinition::new(
+                ClassElementName::PropertyName(PropertyName::Literal(
+                    interner.get_or_intern_static("m", utf16!("m")),
+                )),
+                FormalParameterList::default(),
+                FunctionBody::default(),
+                MethodDefinitionKind::Ordinary,
+                false,
+                boa_ast::LinearPosition::default(),
+            )
+            .with_decorators([dec.clone(), log.clone()].into()),
+        ),
+        ClassElement::FieldDefinition(
+            ClassFieldDefinition::new_accessor(
+                PropertyName::Literal(interner.get_or_intern_static("x", utf16!("x"))),
+                Some(Literal::from(1).into()),
+            )
+            .with_decorators([dec.clone()].into()),
+        ),
+        ClassElement::FieldDefinition(ClassFieldDefinition::new(
+            PropertyName::Literal(interner.get_or_intern_static("accessor", utf16!("accessor"))),
+            None,
+        )),
+    ];
+
+    check_script_parser(
+        "@dec
+        class A {
+            @dec @console.log(1) m() { }
+            @dec accessor x = 1;
+            accessor
+        }
+        ",
+        [Declaration::ClassDeclaration(
+            ClassDeclaration::new(
+                interner.get_or_intern_static("A", utf16!("A")).into(),
+                None,
+                None,
+                elements.into(),
+            )
+            .with_decorators([dec].into()),
+        )
+        .into()],
+        interner,
+    );
+}
+
+#[test]
+fn check_invalid_decorators() {
+    check_invalid_script("class A { @dec constructor() {} }");
+    check_invalid_script("class A { @dec static {} }");
+    check_invalid_script("class A { @dec }");
+    check_invalid_script("@dec let x = 1;");
+    check_invalid_script("@dec() function f() {}");
+}
@@ -81,8 +81,9 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("HoistableDeclaration", "Parsing");
         let tok = cursor.peek(0, interner).or_abrupt()?;
+        let start = tok.span().start();
 
-        match tok.kind() {
+        let declaration = match tok.kind() {
             TokenKind::Keyword((Keyword::Function | Keyword::Async | Keyword::Class, true)) => {
                 Err(Error::general(
                     "Keyword must not contain escaped characters",
@@ -117,13 +118,15 @@ where
                         .map(Declaration::from)
                 }
             }
-            TokenKind::Keyword((Keyword::Class, false)) => {
+            TokenKind::Keyword((Keyword::Class, false)) | TokenKind::Punctuator(Punctuator::At) => {
                 ClassDeclaration::new(self.allow_yield, self.allow_await, false)
                     .parse(cursor, interner)
                     .map(Declaration::from)
             }
             _ => unreachable!("unknown token found: {:?}", tok),
-        }
+        }?;
+
+        Ok(cursor.spanned(declaration, start))
     }
 }
 
@@ -13,14 +13,17 @@ use crate::{
     lexer::TokenKind,
     parser::{
         cursor::Cursor,
-        statement::{declaration::FromClause, BindingIdentifier},
+        statement::{
+            declaration::{FromClause, WithClause},
+            BindingIdentifier,
+        },
         Error, OrAbrupt, ParseResult, TokenParser,
     },
     source::ReadChar,
 };
 use boa_ast::{
     declaration::{
-        ImportDeclaration as AstImportDeclaration, ImportKind,
+        ImportAttribute, ImportDeclaration as AstImportDeclaration, ImportKind,
         ImportSpecifier as AstImportSpecifier, ModuleSpecifier,
     },
     expression::Identifier,
@@ -78,6 +81,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("ImportDeclaration", "Parsing");
 
+        let start = cursor.next_start(interner)?;
         cursor.expect((Keyword::Import, false), "import declaration", interner)?;
 
         let tok = cursor.peek(0, interner).or_abrupt()?;
@@ -87,13 +91,16 @@ where
                 let module_identifier = *module_identifier;
 
                 cursor.advance(interner);
+                let attributes = WithClause::new("import declaration").parse(cursor, interner)?;
                 cursor.expect_semicolon("import declaration", interner)?;
 
-                return Ok(AstImportDeclaration::new(
+                let import = AstImportDeclaration::new(
                     None,
                     ImportKind::DefaultOrUnnamed,
                     ModuleSpecifier::new(module_identifier),
-                ));
+                    attributes,
+                );
+                return Ok(cursor.spanned(import, start));
             }
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
                 let list = NamedImports.parse(cursor, interner)?;
@@ -155,8 +162,11 @@ where
         };
 
         let module_identifier = FromClause::new("import declaration").parse(cursor, interner)?;
+        let attributes = WithClause::new("import declaration").parse(cursor, interner)?;
+        cursor.expect_semicolon("import declaration", interner)?;
 
-        Ok(import_clause.with_specifier(module_identifier))
+        let import = import_clause.with_specifier(module_identifier, attributes);
+        Ok(cursor.spanned(import, start))
     }
 }
 
@@ -261,16 +271,33 @@ enum ImportClause {
 impl ImportClause {
     #[inline]
     #[allow(clippy::missing_const_for_fn)]
-    fn with_specifier(self, specifier: ModuleSpecifier) -> AstImportDeclaration {
+    fn with_specifier(
+        self,
+        specifier: ModuleSpecifier,
+        attributes: Box<[ImportAttribute]>,
+    ) -> AstImportDeclaration {
         match self {
-            Self::Namespace(default, binding) => {
-                AstImportDeclaration::new(default, ImportKind::Namespaced { binding }, specifier)
-            }
+            Self::Namespace(default, binding) => AstImportDeclaration::new(
+                default,
+                ImportKind::Namespaced { binding },
+                specifier,
+                attributes,
+            ),
             Self::ImportList(default, names) => {
                 if names.is_empty() {
-                    AstImportDeclaration::new(default, ImportKind::DefaultOrUnnamed, specifier)
+                    AstImportDeclaration::new(
+                        default,
+                        ImportKind::DefaultOrUnnamed,
+                        specifier,
+                        attributes,
+                    )
                 } else {
-                    AstImportDeclaration::new(default, ImportKind::Named { names }, specifier)
+                    AstImportDeclaration::new(
+                        default,
+                        ImportKind::Named { names },
+                        specifier,
+                        attributes,
+                    )
                 }
             }
         }
@@ -1,14 +1,16 @@
 //! Lexical declaration parsing.
 //!
-//! This parses `let` and `const` declarations.
+//! This parses `let`, `const`, `using` and `await using` declarations.
 //!
 //! More information:
 //!  - [ECMAScript specification][spec]
+//!  - [Explicit Resource Management proposal][proposal]
 //!
 //! [spec]: https://tc39.es/ecma262/#sec-let-and-const-declarations
+//! [proposal]: https://tc39.es/proposal-explicit-resource-management/#sec-let-const-using-and-await-using-declarations
 
 use crate::{
-    lexer::{Error as LexError, Token, TokenKind},
+    lexer::{token::ContainsEscapeSequence, Error as LexError, Token, TokenKind},
     parser::{
         cursor::{Cursor, SemicolonResult},
         expression::Initializer,
@@ -69,33 +71,46 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("LexicalDeclaration", "Parsing");
         let tok = cursor.next(interner).or_abrupt()?;
+        let start = tok.span().start();
 
-        let lexical_declaration = match tok.kind() {
+        let kind = match tok.kind() {
             TokenKind::Keyword((Keyword::Const | Keyword::Let, true)) => {
                 return Err(Error::general(
                     "Keyword must not contain escaped characters",
                     tok.span().start(),
                 ))
             }
-            TokenKind::Keyword((Keyword::Const, false)) => BindingList::new(
-                self.allow_in,
-                self.allow_yield,
-                self.allow_await,
-                true,
-                self.loop_init,
-            )
-            .parse(cursor, interner)?,
-            TokenKind::Keyword((Keyword::Let, false)) => BindingList::new(
-                self.allow_in,
-                self.allow_yield,
-                self.allow_await,
-                false,
-                self.loop_init,
-            )
-            .parse(cursor, interner)?,
+            TokenKind::Keyword((Keyword::Const, false)) => BindingListKind::Const,
+            TokenKind::Keyword((Keyword::Let, false)) => BindingListKind::Let,
+            TokenKind::IdentifierName((Sym::USING, ContainsEscapeSequence(false))) => {
+                BindingListKind::Using
+            }
+            TokenKind::Keyword((Keyword::Await, false)) if self.allow_await.0 => {
+                cursor.expect(
+                    TokenKind::identifier(Sym::USING),
+                    "await using declaration",
+                    interner,
+                )?;
+                BindingListKind::AwaitUsing
+            }
+            TokenKind::Keyword((Keyword::Await, false)) => {
+                return Err(Error::general(
+                    "`await using` declarations are only valid in async functions and modules",
+                    tok.span().start(),
+                ))
+            }
             _ => unreachable!("unknown token found: {:?}", tok),
         };
 
+        let lexical_declaration = BindingList::new(
+            self.allow_in,
+            self.allow_yield,
+            self.allow_await,
+            kind,
+            self.loop_init,
+        )
+        .parse(cursor, interner)?;
+
         if !self.loop_init {
             cursor.expect_semicolon("lexical declaration", interner)?;
         }
@@ -119,7 +134,7 @@ where
             }
         }
 
-        Ok(lexical_declaration)
+        Ok(cursor.spanned(lexical_declaration, start))
     }
 }
 
@@ -138,10 +153,74 @@ pub(crate) fn allowed_token_after_let(token: Option<&Token>) -> bool {
     )
 }
 
+/// Checks if the next tokens start a `using` or an `await using` declaration.
+///
+/// Both `using` and `await` must be directly followed by the next token of the declaration,
+/// without any line terminator in between.
+pub(in crate::parser) fn is_using_declaration<R>(
+    cursor: &mut Cursor<R>,
+    interner: &mut Interner,
+) -> ParseResult<bool>
+where
+    R: ReadChar,
+{
+    // The current token might be preceded by a line terminator.
+    let mut skip_n = usize::from(
+        cursor
+            .peek_is_line_terminator(0, interner)?
+            .unwrap_or(false),
+    );
+
+    match cursor
+        .peek_no_skip_line_term(skip_n, interner)?
+        .map(Token::kind)
+    {
+        Some(TokenKind::IdentifierName((Sym::USING, ContainsEscapeSequence(false)))) => {}
+        Some(TokenKind::Keyword((Keyword::Await, false))) => {
+            skip_n += 1;
+            if !matches!(
+                cursor
+                    .peek_no_skip_line_term(skip_n, interner)?
+                    .map(Token::kind),
+                Some(TokenKind::IdentifierName((
+                    Sym::USING,
+                    ContainsEscapeSequence(false)
+                )))
+            ) {
+                return Ok(false);
+            }
+        }
+        _ => return Ok(false),
+    }
+
+    Ok(matches!(
+        cursor
+            .peek_no_skip_line_term(skip_n + 1, interner)?
+            .map(Token::kind),
+        Some(
+            TokenKind::IdentifierName(_)
+                | TokenKind::Keyword((
+                    Keyword::Yield | Keyword::Let | Keyword::Async | Keyword::Of,
+                    _
+                ))
+        )
+    ))
+}
+
+/// The kind of lexical declaration parsed by a [`BindingList`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BindingListKind {
+    Let,
+    Const,
+    Using,
+    AwaitUsing,
+}
+
 /// Parses a binding list.
 ///
-/// It will return an error if a `const` declaration is being parsed and there is no
-/// initializer.
+/// It will return an error if a `const`, `using` or `await using` declaration is being parsed
+/// and there is no initializer, or if a `using` or `await using` declaration contains a binding
+/// pattern.
 ///
 /// More information:
 ///  - [ECMAScript specification][spec]
@@ -152,7 +231,7 @@ struct BindingList {
     allow_in: AllowIn,
     allow_yield: AllowYield,
     allow_await: AllowAwait,
-    is_const: bool,
+    kind: BindingListKind,
     loop_init: bool,
 }
 
@@ -162,7 +241,7 @@ impl BindingList {
         allow_in: I,
         allow_yield: Y,
         allow_await: A,
-        is_const: bool,
+        kind: BindingListKind,
         loop_init: bool,
     ) -> Self
     where
@@ -174,7 +253,7 @@ impl BindingList {
             allow_in: allow_in.into(),
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
-            is_const,
+            kind,
             loop_init,
         }
     }
@@ -193,24 +272,40 @@ where
         // Const and Let signatures are slightly different, Const needs definitions, Lets don't
         let mut decls = Vec::new();
 
-        loop {
-            let decl = LexicalBinding::new(self.allow_in, self.allow_yield, self.allow_await)
-                .parse(cursor, interner)?;
-
-            if self.is_const {
-                let init_is_some = decl.init().is_some();
+        let is_using = matches!(
+            self.kind,
+            BindingListKind::Using | BindingListKind::AwaitUsing
+        );
 
-                if init_is_some || self.loop_init {
-                    decls.push(decl);
-                } else {
-                    let next = cursor.next(interner).or_abrupt()?;
+        loop {
+            if is_using {
+                let peek_token = cursor.peek(0, interner).or_abrupt()?;
+                if matches!(
+                    peek_token.kind(),
+                    TokenKind::Punctuator(Punctuator::OpenBlock | Punctuator::OpenBracket)
+                ) {
                     return Err(Error::general(
-                        "Expected initializer for const declaration",
-                        next.span().start(),
+                        "binding patterns are not allowed in using declarations",
+                        peek_token.span().start(),
                     ));
                 }
-            } else {
+            }
+
+            let decl = LexicalBinding::new(self.allow_in, self.allow_yield, self.allow_await)
+                .parse(cursor, interner)?;
+
+            if self.kind == BindingListKind::Let || decl.init().is_some() || self.loop_init {
                 decls.push(decl);
+            } else {
+                let next = cursor.next(interner).or_abrupt()?;
+                return Err(Error::general(
+                    if is_using {
+                        "Expected initializer for using declaration"
+                    } else {
+                        "Expected initializer for const declaration"
+                    },
+                    next.span().start(),
+                ));
             }
 
             match cursor.peek_semicolon(interner)? {
@@ -253,11 +348,12 @@ where
             .try_into()
             .expect("`LexicalBinding` must return at least one variable");
 
-        if self.is_const {
-            Ok(ast::declaration::LexicalDeclaration::Const(decls))
-        } else {
-            Ok(ast::declaration::LexicalDeclaration::Let(decls))
-        }
+        Ok(match self.kind {
+            BindingListKind::Let => ast::declaration::LexicalDeclaration::Let(decls),
+            BindingListKind::Const => ast::declaration::LexicalDeclaration::Const(decls),
+            BindingListKind::Using => ast::declaration::LexicalDeclaration::Using(decls),
+            BindingListKind::AwaitUsing => ast::declaration::LexicalDeclaration::AwaitUsing(decls),
+        })
     }
 }
 
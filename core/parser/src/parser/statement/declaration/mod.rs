@@ -17,10 +17,11 @@ mod tests;
 pub(in crate::parser) use self::{
     export::ExportDeclaration,
     hoistable::{
-        class_decl::ClassTail, ClassDeclaration, FunctionDeclaration, HoistableDeclaration,
+        class_decl::{ClassTail, DecoratorList},
+        ClassDeclaration, FunctionDeclaration, HoistableDeclaration,
     },
     import::ImportDeclaration,
-    lexical::{allowed_token_after_let, LexicalDeclaration},
+    lexical::{allowed_token_after_let, is_using_declaration, LexicalDeclaration},
 };
 use crate::{
     lexer::TokenKind,
@@ -28,7 +29,7 @@ use crate::{
     source::ReadChar,
     Error,
 };
-use boa_ast::{self as ast, Keyword};
+use boa_ast::{self as ast, Keyword, Punctuator};
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
 
@@ -68,9 +69,11 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("Declaration", "Parsing");
         let tok = cursor.peek(0, interner).or_abrupt()?;
+        let start = tok.span().start();
 
-        match tok.kind() {
-            TokenKind::Keyword((Keyword::Function | Keyword::Async | Keyword::Class, _)) => {
+        let declaration = match tok.kind() {
+            TokenKind::Keyword((Keyword::Function | Keyword::Async | Keyword::Class, _))
+            | TokenKind::Punctuator(Punctuator::At) => {
                 HoistableDeclaration::new(self.allow_yield, self.allow_await, false)
                     .parse(cursor, interner)
             }
@@ -91,7 +94,9 @@ where
                 tok.span(),
                 "export declaration",
             )),
-        }
+        }?;
+
+        Ok(cursor.spanned(declaration, start))
     }
 }
 
@@ -139,3 +144,107 @@ where
         Ok((*from).into())
     }
 }
+
+/// Parses a `with` clause.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-WithClause
+#[derive(Debug, Clone, Copy)]
+struct WithClause {
+    context: &'static str,
+}
+
+impl WithClause {
+    /// Creates a new `with` clause parser
+    #[inline]
+    const fn new(context: &'static str) -> Self {
+        Self { context }
+    }
+}
+
+impl<R> TokenParser<R> for WithClause
+where
+    R: ReadChar,
+{
+    type Output = Box<[ast::declaration::ImportAttribute]>;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let _timer = Profiler::global().start_event("WithClause", "Parsing");
+
+        if cursor.next_if((Keyword::With, false), interner)?.is_none() {
+            return Ok(Box::default());
+        }
+
+        cursor.expect(Punctuator::OpenBlock, self.context, interner)?;
+
+        let mut attributes: Vec<ast::declaration::ImportAttribute> = Vec::new();
+        loop {
+            let tok = cursor.next(interner).or_abrupt()?;
+            let key = match tok.kind() {
+                TokenKind::Punctuator(Punctuator::CloseBlock) => break,
+                TokenKind::IdentifierName((name, _)) | TokenKind::StringLiteral((name, _)) => *name,
+                TokenKind::Keyword((kw, _)) => kw.to_sym(),
+                TokenKind::BooleanLiteral((true, _)) => Sym::TRUE,
+                TokenKind::BooleanLiteral((false, _)) => Sym::FALSE,
+                TokenKind::NullLiteral(_) => Sym::NULL,
+                _ => {
+                    return Err(Error::expected(
+                        [
+                            Punctuator::CloseBlock.to_string(),
+                            "identifier".to_owned(),
+                            "string literal".to_owned(),
+                        ],
+                        tok.to_string(interner),
+                        tok.span(),
+                        self.context,
+                    ))
+                }
+            };
+            let key_position = tok.span().start();
+
+            if attributes.iter().any(|attribute| attribute.key() == key) {
+                return Err(Error::general(
+                    format!(
+                        "duplicate import attribute `{}`",
+                        interner.resolve_expect(key)
+                    ),
+                    key_position,
+                ));
+            }
+
+            cursor.expect(Punctuator::Colon, self.context, interner)?;
+
+            let tok = cursor.next(interner).or_abrupt()?;
+            let TokenKind::StringLiteral((value, _)) = tok.kind() else {
+                return Err(Error::expected(
+                    ["string literal".to_owned()],
+                    tok.to_string(interner),
+                    tok.span(),
+                    self.context,
+                ));
+            };
+            attributes.push(ast::declaration::ImportAttribute::new(key, *value));
+
+            let tok = cursor.next(interner).or_abrupt()?;
+            match tok.kind() {
+                TokenKind::Punctuator(Punctuator::Comma) => {}
+                TokenKind::Punctuator(Punctuator::CloseBlock) => break,
+                _ => {
+                    return Err(Error::expected(
+                        [
+                            Punctuator::Comma.to_string(),
+                            Punctuator::CloseBlock.to_string(),
+                        ],
+                        tok.to_string(interner),
+                        tok.span(),
+                        self.context,
+                    ))
+                }
+            }
+        }
+
+        Ok(attributes.into_boxed_slice())
+    }
+}
@@ -1,9 +1,12 @@
-use crate::parser::tests::{check_invalid_script, check_module_parser, check_script_parser};
+use crate::parser::tests::{
+    check_invalid_module, check_invalid_script, check_module_parser, check_script_parser,
+};
 use boa_ast::{
     declaration::{
-        ExportDeclaration, ExportSpecifier, LexicalDeclaration, VarDeclaration, Variable,
+        ExportDeclaration, ExportSpecifier, ImportAttribute, ImportDeclaration, ImportKind,
+        LexicalDeclaration, ReExportKind, VarDeclaration, Variable,
     },
-    expression::literal::Literal,
+    expression::literal::{Literal, LiteralKind},
     Declaration, ModuleItem, Statement,
 };
 use boa_interner::{Interner, Sym};
@@ -378,7 +381,7 @@ fn module_export_reserved() {
                 Declaration::Lexical(LexicalDeclaration::Const(
                     vec![Variable::from_identifier(
                         val.into(),
-                        Some(Literal::Null.into()),
+                        Some(LiteralKind::Null.into()),
                     )]
                     .try_into()
                     .unwrap(),
@@ -397,3 +400,106 @@ fn module_export_reserved() {
         interner,
     );
 }
+
+/// Checks import declarations and re-exports with a `with` clause.
+#[test]
+fn module_import_attributes() {
+    let interner = &mut Interner::default();
+    let data = interner.get_or_intern_static("data", utf16!("data"));
+    let json = interner.get_or_intern_static("./data.json", utf16!("./data.json"));
+    let type_ = interner.get_or_intern_static("type", utf16!("type"));
+    let json_type = interner.get_or_intern_static("json", utf16!("json"));
+    let other = interner.get_or_intern_static("other", utf16!("other"));
+    let value = interner.get_or_intern_static("value", utf16!("value"));
+    check_module_parser(
+        r#"
+            import data from "./data.json" with { type: "json" };
+            import "./data.json" with { "type": "json", other: "value", };
+            export * from "./data.json" with {};
+        "#,
+        vec![
+            ModuleItem::ImportDeclaration(ImportDeclaration::new(
+                Some(data.into()),
+                ImportKind::DefaultOrUnnamed,
+                json.into(),
+                vec![ImportAttribute::new(type_, json_type)].into(),
+            )),
+            ModuleItem::ImportDeclaration(ImportDeclaration::new(
+                None,
+                ImportKind::DefaultOrUnnamed,
+                json.into(),
+                vec![
+                    ImportAttribute::new(type_, json_type),
+                    ImportAttribute::new(other, value),
+                ]
+                .into(),
+            )),
+            ModuleItem::ExportDeclaration(ExportDeclaration::ReExport {
+                kind: ReExportKind::Namespaced { name: None },
+                specifier: json.into(),
+                attributes: Box::default(),
+            }),
+        ],
+        interner,
+    );
+}
+
+/// Checks `with` clause early errors.
+#[test]
+fn module_import_attributes_early_errors() {
+    check_invalid_module(r#"import a from "a.json" with { type: "json", type: "json" };"#);
+    check_invalid_module(r#"import a from "a.json" with { type: json };"#);
+    check_invalid_module(r#"import a from "a.json" with { type: "json" "a": "b" };"#);
+    check_invalid_module(r#"import a from "a.json" with type;"#);
+    check_invalid_module(r#"export * from "a.json" with { 0: "json" };"#);
+}
+
+/// Checks `using` and `await using` declarations parsing.
+#[test]
+fn using_declaration() {
+    let interner = &mut Interner::default();
+    let a = interner.get_or_intern_static("a", utf16!("a"));
+    let b = interner.get_or_intern_static("b", utf16!("b"));
+    check_module_parser(
+        "using a = null; await using b = null;",
+        vec![
+            ModuleItem::StatementListItem(
+                Declaration::Lexical(LexicalDeclaration::Using(
+                    vec![Variable::from_identifier(
+                        a.into(),
+                        Some(LiteralKind::Null.into()),
+                    )]
+                    .try_into()
+                    .unwrap(),
+                ))
+                .into(),
+            ),
+            ModuleItem::StatementListItem(
+                Declaration::Lexical(LexicalDeclaration::AwaitUsing(
+                    vec![Variable::from_identifier(
+                        b.into(),
+                        Some(LiteralKind::Null.into()),
+                    )]
+                    .try_into()
+                    .unwrap(),
+                ))
+                .into(),
+            ),
+        ],
+        interner,
+    );
+}
+
+/// Checks `using` declarations early errors.
+#[test]
+fn using_declaration_early_errors() {
+    check_invalid_script("using a = null;");
+    check_invalid_script("{ using a; }");
+    // `using [a] = null` is an assignment to the `using[a]` member, so only object patterns fail.
+    check_invalid_script("{ using {a} = null; }");
+    check_invalid_script("{ using let = null; }");
+    check_invalid_script("{ await using a = null; }");
+    check_invalid_script("for (using a in {}) {}");
+    check_invalid_script("switch (0) { case 0: using a = null; }");
+    check_invalid_module("export using a = null;");
+}
@@ -12,7 +12,7 @@ use crate::{
     parser::{
         expression::{AssignmentExpression, Expression},
         statement::{
-            declaration::{allowed_token_after_let, LexicalDeclaration},
+            declaration::{allowed_token_after_let, is_using_declaration, LexicalDeclaration},
             variable::VariableDeclarationList,
             Statement,
         },
@@ -135,6 +135,31 @@ where
                     .parse(cursor, interner)?
                     .into(),
             ),
+            // `for (using of ...)` always uses `using` as an identifier, except for the
+            // `for (using of = ...; ...)` declaration.
+            TokenKind::IdentifierName((Sym::USING, _))
+                if is_using_declaration(cursor, interner)?
+                    && (!matches!(
+                        cursor.peek(1, interner).or_abrupt()?.kind(),
+                        TokenKind::Keyword((Keyword::Of, _))
+                    ) || matches!(
+                        cursor.peek(2, interner).or_abrupt()?.kind(),
+                        TokenKind::Punctuator(Punctuator::Assign)
+                    )) =>
+            {
+                Some(
+                    LexicalDeclaration::new(false, self.allow_yield, self.allow_await, true)
+                        .parse(cursor, interner)?
+                        .into(),
+                )
+            }
+            TokenKind::Keyword((Keyword::Await, _)) if is_using_declaration(cursor, interner)? => {
+                Some(
+                    LexicalDeclaration::new(false, self.allow_yield, self.allow_await, true)
+                        .parse(cursor, interner)?
+                        .into(),
+                )
+            }
             TokenKind::Keyword((Keyword::Async, false)) if !r#await => {
                 if matches!(
                     cursor.peek(1, interner).or_abrupt()?.kind(),
@@ -225,7 +250,10 @@ where
                 // Checks are only applicable to lexical bindings.
                 if matches!(
                     &init,
-                    IterableLoopInitializer::Const(_) | IterableLoopInitializer::Let(_)
+                    IterableLoopInitializer::Const(_)
+                        | IterableLoopInitializer::Let(_)
+                        | IterableLoopInitializer::Using(_)
+                        | IterableLoopInitializer::AwaitUsing(_)
                 ) {
                     // It is a Syntax Error if the BoundNames of ForDeclaration contains "let".
                     // It is a Syntax Error if any element of the BoundNames of ForDeclaration also occurs in the VarDeclaredNames of Statement.
@@ -263,15 +291,22 @@ where
         };
 
         if let Some(ForLoopInitializer::Lexical(initializer)) = &init {
-            if let ast::declaration::LexicalDeclaration::Const(list) = initializer.declaration() {
-                for decl in list.as_ref() {
-                    if decl.init().is_none() {
-                        return Err(Error::general(
-                            "Expected initializer for const declaration",
-                            position,
-                        ));
-                    }
-                }
+            let declaration = initializer.declaration();
+            if declaration.is_const()
+                && declaration
+                    .variable_list()
+                    .as_ref()
+                    .iter()
+                    .any(|decl| decl.init().is_none())
+            {
+                return Err(Error::general(
+                    if declaration.is_using() {
+                        "Expected initializer for using declaration"
+                    } else {
+                        "Expected initializer for const declaration"
+                    },
+                    position,
+                ));
             }
         }
 
@@ -380,6 +415,14 @@ fn initializer_to_iterable_loop_initializer(
                 ))),
             }
         }
+        ForLoopInitializer::Lexical(initializer)
+            if in_loop && initializer.declaration().is_using() =>
+        {
+            Err(Error::lex(LexError::Syntax(
+                "using declarations are not allowed in the head of a for-in loop".into(),
+                position,
+            )))
+        }
         ForLoopInitializer::Lexical(initializer) => {
             match initializer.declaration().variable_list().as_ref() {
                 [decl] => {
@@ -397,6 +440,12 @@ fn initializer_to_iterable_loop_initializer(
                         ast::declaration::LexicalDeclaration::Let(_) => {
                             IterableLoopInitializer::Let(decl.binding().clone())
                         }
+                        ast::declaration::LexicalDeclaration::Using(_) => {
+                            IterableLoopInitializer::Using(decl.binding().clone())
+                        }
+                        ast::declaration::LexicalDeclaration::AwaitUsing(_) => {
+                            IterableLoopInitializer::AwaitUsing(decl.binding().clone())
+                        }
                     })
                 }
                 _ => Err(Error::lex(LexError::Syntax(
@@ -257,7 +257,7 @@ fn do_while_spaces() {
                 PSEUDO_LINEAR_POS,
             ))
             .into(),
-            Literal::Bool(true).into(),
+            Literal::from(true).into(),
         ))
         .into()],
         &mut Interner::default(),
@@ -26,7 +26,10 @@ use self::{
     block::BlockStatement,
     break_stm::BreakStatement,
     continue_stm::ContinueStatement,
-    declaration::{allowed_token_after_let, Declaration, ExportDeclaration, ImportDeclaration},
+    declaration::{
+        allowed_token_after_let, is_using_declaration, ClassDeclaration, Declaration,
+        ExportDeclaration, ImportDeclaration, LexicalDeclaration,
+    },
     expression::ExpressionStatement,
     if_stm::IfStatement,
     iteration::{DoWhileStatement, ForStatement, WhileStatement},
@@ -53,14 +56,15 @@ use ast::{
 };
 use boa_ast::{
     self as ast,
+    expression::literal::LiteralKind,
     pattern::{ArrayPattern, ArrayPatternElement, ObjectPatternElement},
-    Keyword, Punctuator,
+    Keyword, Punctuator, SpanIgnoreEq,
 };
-use boa_interner::Interner;
+use boa_interner::{Interner, Sym};
 use boa_macros::utf16;
 use boa_profiler::Profiler;
 
-pub(in crate::parser) use declaration::ClassTail;
+pub(in crate::parser) use declaration::{ClassTail, DecoratorList};
 
 /// Statement parsing.
 ///
@@ -121,8 +125,9 @@ where
         let _timer = Profiler::global().start_event("Statement", "Parsing");
         // TODO: add BreakableStatement and divide Whiles, fors and so on to another place.
         let tok = cursor.peek(0, interner).or_abrupt()?;
+        let start = tok.span().start();
 
-        match tok.kind() {
+        let statement = match tok.kind() {
             TokenKind::Keyword((Keyword::With, _)) => {
                 WithStatement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor, interner)
@@ -198,7 +203,7 @@ where
             TokenKind::Punctuator(Punctuator::Semicolon) => {
                 // parse the EmptyStatement
                 cursor.advance(interner);
-                Ok(ast::Statement::Empty)
+                Ok(ast::Statement::Empty(SpanIgnoreEq::default()))
             }
             TokenKind::IdentifierName(_)
             | TokenKind::Keyword((Keyword::Await | Keyword::Yield, _)) => {
@@ -224,7 +229,9 @@ where
             _ => {
                 ExpressionStatement::new(self.allow_yield, self.allow_await).parse(cursor, interner)
             }
-        }
+        }?;
+
+        Ok(cursor.spanned(statement, start))
     }
 }
 
@@ -297,8 +304,18 @@ where
         let mut linear_pos_end = cursor.linear_pos();
 
         loop {
-            let peek_token = cursor.peek(0, interner)?;
+            let peek_token = match cursor.peek(0, interner) {
+                Ok(peek_token) => peek_token,
+                Err(error) => {
+                    let start = cursor.linear_pos();
+                    cursor.recover(error, start, self.break_nodes, interner)?;
+                    items.push(ast::Statement::Error(SpanIgnoreEq::default()).into());
+                    continue;
+                }
+            };
+            let mut start = linear_pos_end;
             if let Some(peek_token) = peek_token {
+                start = peek_token.linear_span().start();
                 linear_pos_end = peek_token.linear_span().end();
             }
 
@@ -314,15 +331,31 @@ where
             }
 
             let item =
-                StatementListItem::new(self.allow_yield, self.allow_await, self.allow_return)
-                    .parse(cursor, interner)?;
+                match StatementListItem::new(self.allow_yield, self.allow_await, self.allow_return)
+                    .parse(cursor, interner)
+                {
+                    Ok(item) => item,
+                    Err(error) => {
+                        // Replace the statement with a placeholder if the parse is recovering.
+                        cursor.recover(error, start, self.break_nodes, interner)?;
+                        ast::Statement::Error(SpanIgnoreEq::default()).into()
+                    }
+                };
 
             if directive_prologues {
-                match &item {
+                let directive = match &item {
                     ast::StatementListItem::Statement(ast::Statement::Expression(
-                        ast::Expression::Literal(ast::expression::literal::Literal::String(string)),
-                    )) if !strict => {
-                        if interner.resolve_expect(*string).join(
+                        ast::Expression::Literal(literal),
+                    )) => match literal.kind() {
+                        LiteralKind::String(string) => Some(*string),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+
+                match directive {
+                    Some(string) if !strict => {
+                        if interner.resolve_expect(string).join(
                             |s| s == "use strict",
                             |g| g == utf16!("use strict"),
                             true,
@@ -336,29 +369,25 @@ where
 
                             for (position, escape) in std::mem::take(&mut directives_stack) {
                                 if escape.contains(EscapeSequence::LEGACY_OCTAL) {
-                                    return Err(Error::general(
+                                    cursor.report(Error::general(
                                         "legacy octal escape sequences are not allowed in strict mode",
                                         position,
-                                    ));
+                                    ))?;
                                 }
 
                                 if escape.contains(EscapeSequence::NON_OCTAL_DECIMAL) {
-                                    return Err(Error::general(
+                                    cursor.report(Error::general(
                                         "decimal escape sequences are not allowed in strict mode",
                                         position,
-                                    ));
+                                    ))?;
                                 }
                             }
                         }
                     }
-                    ast::StatementListItem::Statement(ast::Statement::Expression(
-                        ast::Expression::Literal(ast::expression::literal::Literal::String(
-                            _string,
-                        )),
-                    )) => {
+                    Some(_) => {
                         // TODO: should store directives in some place
                     }
-                    _ => {
+                    None => {
                         directive_prologues = false;
                         directives_stack.clear();
                     }
@@ -416,9 +445,11 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("StatementListItem", "Parsing");
         let tok = cursor.peek(0, interner).or_abrupt()?;
+        let start = tok.span().start();
 
-        match tok.kind().clone() {
-            TokenKind::Keyword((Keyword::Function | Keyword::Class | Keyword::Const, _)) => {
+        let item = match tok.kind().clone() {
+            TokenKind::Keyword((Keyword::Function | Keyword::Class | Keyword::Const, _))
+            | TokenKind::Punctuator(Punctuator::At) => {
                 Declaration::new(self.allow_yield, self.allow_await)
                     .parse(cursor, interner)
                     .map(ast::StatementListItem::from)
@@ -430,6 +461,14 @@ where
                     .parse(cursor, interner)
                     .map(ast::StatementListItem::from)
             }
+            TokenKind::IdentifierName((Sym::USING, _))
+            | TokenKind::Keyword((Keyword::Await, _))
+                if is_using_declaration(cursor, interner)? =>
+            {
+                LexicalDeclaration::new(true, self.allow_yield, self.allow_await, false)
+                    .parse(cursor, interner)
+                    .map(|decl| ast::StatementListItem::Declaration(decl.into()))
+            }
             TokenKind::Keyword((Keyword::Async, false)) => {
                 let skip_n = if cursor.peek_is_line_terminator(0, interner).or_abrupt()? {
                     2
@@ -454,7 +493,9 @@ where
             _ => Statement::new(self.allow_yield, self.allow_await, self.allow_return)
                 .parse(cursor, interner)
                 .map(ast::StatementListItem::from),
-        }
+        }?;
+
+        Ok(cursor.spanned(item, start))
     }
 }
 
@@ -911,21 +952,43 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let mut list = Vec::new();
-        while cursor.peek(0, interner)?.is_some() {
-            let item = ModuleItem.parse(cursor, interner)?;
+        loop {
+            let start = match cursor.peek(0, interner) {
+                Ok(Some(token)) => token.linear_span().start(),
+                Ok(None) => break,
+                Err(error) => {
+                    let start = cursor.linear_pos();
+                    cursor.recover(error, start, &[], interner)?;
+                    list.push(boa_ast::ModuleItem::StatementListItem(
+                        ast::Statement::Error(SpanIgnoreEq::default()).into(),
+                    ));
+                    continue;
+                }
+            };
+
+            let item = match ModuleItem.parse(cursor, interner) {
+                Ok(item) => item,
+                Err(error) => {
+                    // Replace the item with a placeholder if the parse is recovering.
+                    cursor.recover(error, start, &[], interner)?;
+                    boa_ast::ModuleItem::StatementListItem(
+                        ast::Statement::Error(SpanIgnoreEq::default()).into(),
+                    )
+                }
+            };
 
             if let Err(error) = check_labels(&item) {
-                return Err(Error::lex(LexError::Syntax(
+                cursor.report(Error::lex(LexError::Syntax(
                     error.message(interner).into(),
                     Position::new(1, 1),
-                )));
+                )))?;
             }
 
             if contains_invalid_object_literal(&item) {
-                return Err(Error::lex(LexError::Syntax(
+                cursor.report(Error::lex(LexError::Syntax(
                     "invalid object literal in module item list".into(),
                     Position::new(1, 1),
-                )));
+                )))?;
             }
 
             list.push(item);
@@ -935,10 +998,10 @@ where
 
         // It is a Syntax Error if AllPrivateIdentifiersValid of ModuleItemList with argument « » is false.
         if !all_private_identifiers_valid(&list, Vec::new()) {
-            return Err(Error::general(
+            cursor.report(Error::general(
                 "invalid private identifier usage",
                 Position::new(1, 1),
-            ));
+            ))?;
         }
 
         Ok(list)
@@ -966,6 +1029,45 @@ where
             TokenKind::Keyword((Keyword::Export, false)) => ExportDeclaration
                 .parse(cursor, interner)
                 .map(Self::Output::ExportDeclaration),
+            TokenKind::Punctuator(Punctuator::At) => {
+                // Decorators can also precede the `export` keyword of an exported class.
+                let position = tok.span().start();
+                let decorators = DecoratorList::new(false, true).parse(cursor, interner)?;
+                if cursor.peek(0, interner).or_abrupt()?.kind()
+                    != &TokenKind::Keyword((Keyword::Export, false))
+                {
+                    let class = ClassDeclaration::new(false, true, false)
+                        .parse(cursor, interner)?
+                        .with_decorators(decorators);
+                    return Ok(Self::Output::StatementListItem(
+                        ast::Declaration::from(class).into(),
+                    ));
+                }
+
+                let export = match ExportDeclaration.parse(cursor, interner)? {
+                    ast::declaration::ExportDeclaration::Declaration(
+                        ast::Declaration::ClassDeclaration(class),
+                    ) if class.decorators().is_empty() => {
+                        ast::declaration::ExportDeclaration::Declaration(
+                            ast::Declaration::ClassDeclaration(class.with_decorators(decorators)),
+                        )
+                    }
+                    ast::declaration::ExportDeclaration::DefaultClassDeclaration(class)
+                        if class.decorators().is_empty() =>
+                    {
+                        ast::declaration::ExportDeclaration::DefaultClassDeclaration(
+                            class.with_decorators(decorators),
+                        )
+                    }
+                    _ => {
+                        return Err(Error::general(
+                            "decorators must be followed by a single class declaration",
+                            position,
+                        ))
+                    }
+                };
+                Ok(Self::Output::ExportDeclaration(export))
+            }
             TokenKind::Keyword((Keyword::Import, false)) => {
                 if ImportDeclaration::test(cursor, interner)? {
                     ImportDeclaration
@@ -75,6 +75,24 @@ where
         let cases = CaseBlock::new(self.allow_yield, self.allow_await, self.allow_return)
             .parse(cursor, interner)?;
 
+        // It is a Syntax Error if a UsingDeclaration or AwaitUsingDeclaration is directly
+        // contained in the StatementList of a CaseClause or DefaultClause.
+        let has_using_declaration = cases.iter().any(|case| {
+            case.body().statements().iter().any(|item| {
+                matches!(
+                    item,
+                    ast::StatementListItem::Declaration(ast::Declaration::Lexical(decl))
+                        if decl.is_using()
+                )
+            })
+        });
+        if has_using_declaration {
+            return Err(Error::general(
+                "using declarations are not allowed directly in switch case clauses",
+                position,
+            ));
+        }
+
         let switch = Switch::new(condition, cases);
 
         // It is a Syntax Error if the LexicallyDeclaredNames of CaseBlock contains any duplicate
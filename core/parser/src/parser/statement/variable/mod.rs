@@ -56,6 +56,7 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("VariableStatement", "Parsing");
+        let start = cursor.next_start(interner)?;
         cursor.expect((Keyword::Var, false), "variable statement", interner)?;
 
         let decl_list = VariableDeclarationList::new(true, self.allow_yield, self.allow_await)
@@ -63,7 +64,7 @@ where
 
         cursor.expect_semicolon("variable statement", interner)?;
 
-        Ok(decl_list)
+        Ok(cursor.spanned(decl_list, start))
     }
 }
 
@@ -0,0 +1,305 @@
+//! Tests for the code generator of `boa_ast`, which need the parser to read the generated code
+//! back.
+
+use crate::{Parser, Source};
+use boa_ast::{
+    codegen::{generate_module, generate_script, CodegenMode},
+    scope::Scope,
+    Module, Script,
+};
+use boa_interner::{Interner, ToInternedString};
+
+fn parse_script(source: &str, interner: &mut Interner) -> Script {
+    Parser::new(Source::from_bytes(source))
+        .parse_script(&Scope::new_global(), interner)
+        .unwrap_or_else(|err| panic!("failed to parse `{source}`: {err}"))
+}
+
+fn parse_module(source: &str, interner: &mut Interner) -> Module {
+    Parser::new(Source::from_bytes(source))
+        .parse_module(&Scope::new_global(), interner)
+        .unwrap_or_else(|err| panic!("failed to parse `{source}`: {err}"))
+}
+
+/// Checks that the pretty output of `source` parses back to the same script, and that the
+/// minified output parses and is stable when minified again. Returns the minified output.
+#[track_caller]
+fn check_script(source: &str) -> String {
+    let interner = &mut Interner::default();
+    let script = parse_script(source, interner);
+
+    let pretty = generate_script(&script, interner, CodegenMode::Pretty);
+    assert_eq!(
+        parse_script(&pretty, interner),
+        script,
+        "pretty output:\n{pretty}"
+    );
+
+    let minified = generate_script(&script, interner, CodegenMode::Minified);
+    let reparsed = parse_script(&minified, interner);
+    assert_eq!(
+        generate_script(&reparsed, interner, CodegenMode::Minified),
+        minified
+    );
+    minified
+}
+
+/// Same as [`check_script`], for modules.
+#[track_caller]
+fn check_module(source: &str) -> String {
+    let interner = &mut Interner::default();
+    let module = parse_module(source, interner);
+
+    let pretty = generate_module(&module, interner, CodegenMode::Pretty);
+    assert_eq!(
+        parse_module(&pretty, interner),
+        module,
+        "pretty output:\n{pretty}"
+    );
+
+    let minified = generate_module(&module, interner, CodegenMode::Minified);
+    let reparsed = parse_module(&minified, interner);
+    assert_eq!(
+        generate_module(&reparsed, interner, CodegenMode::Minified),
+        minified
+    );
+    minified
+}
+
+#[test]
+fn round_trip() {
+    check_script(
+        r#"
+        "use strict";
+        var a = 1, b = [1, , 2, ...c], d = { e, f: 2, [g]: 3, 4: h, "i j": 5, ...k };
+        let { l, m: [n = 1, , ...o], ...p } = q;
+        const r = `s${t}u${`v`}`, w = x`y${z}\n`;
+        function* gen(a, b = 1, ...c) { yield* a; yield; }
+        async function af() { await a; for await (const b of c) {} }
+        class A extends B {
+            #a = 1;
+            static b;
+            accessor c = 2;
+            constructor(a) { super(a); }
+            get d() { return this.#a; }
+            set d(v) { this.#a = v; }
+            static *e() {}
+            async f() {}
+            static { init(); }
+            g() { return #a in this; }
+        }
+        label: for (let i = 0; i < 10; i++) {
+            if (i % 2) continue label; else break;
+        }
+        for (const k in o) {}
+        for (var v of [1, 2]) {}
+        do { a--; } while (a > 0);
+        while (false);
+        switch (a) { case 1: b(); break; default: c(); }
+        try { a(); } catch ({ message }) { b(message); } finally { c(); }
+        try { a(); } catch { b(); }
+        throw new Error("a" + 'b' + "\u2028\0\x01");
+        a = b ? c : d ? e : f;
+        a ??= b?.c?.[d]?.(e);
+        new a.b(c);
+        x = typeof void delete a.b;
+        y = /ab+c/gi.test(z), 1e21, 0.5, 123.456, 0x10, 10n;
+        "#,
+    );
+}
+
+#[test]
+fn round_trip_module() {
+    check_module(
+        r#"
+        import a, * as b from "b";
+        import { c, d as e, "f g" as h } from "c";
+        import i from "i.json" with { type: "json" };
+        import "side-effect";
+        export * from "j";
+        export * as k from "k";
+        export { l as m, n } from "l";
+        export { a as q, c };
+        export var o = 1;
+        export class P {}
+        export default function () { return a; }
+        "#,
+    );
+}
+
+#[test]
+fn round_trip_comments() {
+    let source = "// lead\nlet a = 1;\n/* tail */\n";
+    let interner = &mut Interner::default();
+    let mut parser = Parser::new(Source::from_bytes(source));
+    parser.set_record_comments();
+    let script = parser
+        .parse_script(&Scope::new_global(), interner)
+        .expect("failed to parse");
+
+    assert_eq!(
+        generate_script(&script, interner, CodegenMode::Pretty),
+        source
+    );
+    assert_eq!(
+        generate_script(&script, interner, CodegenMode::Minified),
+        "let a=1;"
+    );
+}
+
+#[test]
+fn module_comments() {
+    let source = "// lead\nimport a from \"a\";\n/** doc */\nexport function f() {\n    // body\n    return a;\n}\n/* tail */\n";
+    let interner = &mut Interner::default();
+    let mut parser = Parser::new(Source::from_bytes(source));
+    parser.set_record_comments();
+    let module = parser
+        .parse_module(&Scope::new_global(), interner)
+        .expect("failed to parse");
+
+    assert_eq!(module.to_interned_string(interner), source);
+}
+
+#[test]
+fn hashbang() {
+    let source = "#!/usr/bin/env boa\n// comment\nf();\n";
+    let interner = &mut Interner::default();
+    let mut parser = Parser::new(Source::from_bytes(source));
+    parser.set_record_comments();
+    let script = parser
+        .parse_script(&Scope::new_global(), interner)
+        .expect("failed to parse");
+
+    assert_eq!(
+        generate_script(&script, interner, CodegenMode::Minified),
+        "#!/usr/bin/env boa\nf();"
+    );
+}
+
+#[test]
+fn precedence() {
+    for (source, expected) in [
+        ("(a + b) * c;", "(a+b)*c;"),
+        ("a + (b * c);", "a+b*c;"),
+        ("a - (b - c);", "a-(b-c);"),
+        ("(a - b) - c;", "a-b-c;"),
+        ("(-a) ** b;", "(-a)**b;"),
+        ("a ** (b ** c);", "a**b**c;"),
+        ("(a ** b) ** c;", "(a**b)**c;"),
+        ("a - -b;", "a- -b;"),
+        ("a + +b;", "a+ +b;"),
+        ("a ?? (b || c);", "a??(b||c);"),
+        ("(a ?? b) || c;", "(a??b)||c;"),
+        ("(a, b) ? c : d;", "(a,b)?c:d;"),
+        ("x = (y, z);", "x=(y,z);"),
+        ("(x = y) + z;", "(x=y)+z;"),
+        ("new (a())();", "new(a())();"),
+        ("new (a.b().c);", "new(a.b().c)();"),
+        ("(new a).b;", "new a().b;"),
+        ("(a?.b).c;", "(a?.b).c;"),
+        ("(1).toString();", "1 .toString();"),
+        ("for (var i = (a in b); ;);", "for(var i=(a in b);;);"),
+        ("(async () => {})();", "(async()=>{})();"),
+    ] {
+        assert_eq!(check_script(source), expected, "`{source}`");
+    }
+}
+
+#[test]
+fn ambiguous_statement_start() {
+    for (source, expected) in [
+        ("(function () {})();", "(function(){}());"),
+        ("(class {}).name;", "(class{}.name);"),
+        ("({}).x = 1;", "({}.x=1);"),
+        ("({ a } = b);", "({a}=b);"),
+        ("(let)[0] = 1;", "(let[0]=1);"),
+        ("x = () => ({});", "x=()=>({});"),
+        ("x = () => ({}).y;", "x=()=>({}.y);"),
+    ] {
+        assert_eq!(check_script(source), expected, "`{source}`");
+    }
+}
+
+#[test]
+fn automatic_semicolon_insertion() {
+    for (source, expected) in [
+        ("let a = 1\nlet b = 2", "let a=1;let b=2;"),
+        ("a\n++b", "a;++b;"),
+        ("function f() { return\n1 }", "function f(){return;1}"),
+        ("if (a) { b() } else c()", "if(a){b()}else c();"),
+        ("do a(); while (b) c()", "do a();while(b);c();"),
+        ("class A { a\n b() {} }", "class A{a;b(){}}"),
+    ] {
+        assert_eq!(check_script(source), expected, "`{source}`");
+    }
+}
+
+#[test]
+fn numbers_and_strings() {
+    for (source, expected) in [
+        (
+            "x = [1000, 0.5, 1e21, 0.000001, 1e-7, 255];",
+            "x=[1e3,.5,1e21,1e-6,1e-7,255];",
+        ),
+        ("x = 'a\"b';", "x='a\"b';"),
+        ("x = \"a'b\";", "x=\"a'b\";"),
+        ("x = '\\0' + '\\x001';", "x=\"\\0\"+\"\\x001\";"),
+    ] {
+        assert_eq!(check_script(source), expected, "`{source}`");
+    }
+}
+
+#[test]
+fn minified_renames_locals() {
+    for (source, expected) in [
+        (
+            "function add(x, y) { return x + y; }",
+            "function add(a,b){return a+b}",
+        ),
+        (
+            "let f = function (x) { return x * 2; };",
+            "let f=function(a){return a*2};",
+        ),
+        (
+            "function f(x) { return function (y) { return x + y + a; }; }",
+            "function f(b){return function(c){return b+c+a}}",
+        ),
+        (
+            "function f(x) { let { y } = x; return { y }; }",
+            "function f(a){let{y:b}=a;return{y:b}}",
+        ),
+        ("(value => value)(1);", "(a=>a)(1);"),
+    ] {
+        assert_eq!(check_script(source), expected, "`{source}`");
+    }
+}
+
+#[test]
+fn minified_keeps_dynamic_scopes() {
+    for (source, expected) in [
+        (
+            "function f(x) { eval(\"x\"); return x; }",
+            "function f(x){eval(\"x\");return x}",
+        ),
+        (
+            "function f(x) { with (o) { return x; } }",
+            "function f(x){with(o){return x}}",
+        ),
+    ] {
+        assert_eq!(check_script(source), expected, "`{source}`");
+    }
+}
+
+#[test]
+fn minified_module() {
+    assert_eq!(
+        check_module(
+            r#"
+            import { a as b } from "m";
+            export const c = b + 1;
+            export default function () { return c; }
+            "#,
+        ),
+        "import{a as b}from\"m\";export const c=b+1;export default function(){return c}"
+    );
+}
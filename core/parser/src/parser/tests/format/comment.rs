@@ -0,0 +1,30 @@
+use crate::parser::tests::format::test_formatting_with_comments;
+
+#[test]
+fn leading_comments() {
+    test_formatting_with_comments(
+        r#"
+        // The answer.
+        let answer = 42;
+        /** Logs the answer. */
+        function log() {
+            // Inside the body.
+            console.log(answer);
+        }
+        "#,
+    );
+}
+
+#[test]
+fn trailing_comments() {
+    test_formatting_with_comments(
+        r#"
+        if (true) {
+            /* before */
+            call();
+        }
+        /* at the end */
+        // of the script
+        "#,
+    );
+}
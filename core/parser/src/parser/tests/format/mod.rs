@@ -1,3 +1,4 @@
+mod comment;
 mod declaration;
 mod expression;
 mod function;
@@ -14,6 +15,18 @@ mod statement;
 /// level.
 #[cfg(test)]
 fn test_formatting(source: &'static str) {
+    check_formatting(source, false);
+}
+
+/// Same as [`test_formatting`], but records the comments of the source code, so that they are
+/// part of the formatted code.
+#[cfg(test)]
+fn test_formatting_with_comments(source: &'static str) {
+    check_formatting(source, true);
+}
+
+#[cfg(test)]
+fn check_formatting(source: &'static str, record_comments: bool) {
     // Remove preceding newline.
 
     use crate::{Parser, Source};
@@ -33,7 +46,11 @@ fn test_formatting(source: &'static str) {
         .join("\n");
     let source = Source::from_bytes(source);
     let interner = &mut Interner::default();
-    let result = Parser::new(source)
+    let mut parser = Parser::new(source);
+    if record_comments {
+        parser.set_record_comments();
+    }
+    let result = parser
         .parse_script(&Scope::new_global(), interner)
         .expect("parsing failed")
         .to_interned_string(interner);
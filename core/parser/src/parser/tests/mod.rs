@@ -1,5 +1,6 @@
 //! Tests for the parser.
 
+mod codegen;
 mod format;
 
 use std::convert::TryInto;
@@ -24,8 +25,8 @@ use boa_ast::{
     },
     scope::Scope,
     statement::{If, Return},
-    Expression, LinearPosition, LinearSpan, Module, ModuleItem, ModuleItemList, Script, Statement,
-    StatementList, StatementListItem,
+    Expression, LinearPosition, LinearSpan, Module, ModuleItem, ModuleItemList, Position, Script,
+    Span, SpanIgnoreEq, Spanned, Statement, StatementList, StatementListItem,
 };
 use boa_interner::Interner;
 use boa_macros::utf16;
@@ -75,6 +76,14 @@ pub(super) fn check_invalid_script(js: &str) {
         .is_err());
 }
 
+/// Checks that the given javascript string creates a parse error when parsed as a module.
+#[track_caller]
+pub(super) fn check_invalid_module(js: &str) {
+    assert!(Parser::new(Source::from_bytes(js))
+        .parse_module(&Scope::new_global(), &mut Interner::default())
+        .is_err());
+}
+
 /// Should be parsed as `new Class().method()` instead of `new (Class().method())`
 #[test]
 fn check_construct_call_precedence() {
@@ -204,13 +213,13 @@ fn ambigous_regex_divide_expression() {
             RelationalOp::StrictEqual.into(),
             Binary::new(
                 ArithmeticOp::Div.into(),
-                Literal::Int(1).into(),
+                Literal::from(1).into(),
                 Identifier::new(interner.get_or_intern_static("a", utf16!("a"))).into(),
             )
             .into(),
             Binary::new(
                 ArithmeticOp::Div.into(),
-                Literal::Int(1).into(),
+                Literal::from(1).into(),
                 Identifier::new(interner.get_or_intern_static("b", utf16!("b"))).into(),
             )
             .into(),
@@ -233,20 +242,20 @@ fn two_divisions_in_expression() {
             Binary::new(
                 RelationalOp::StrictNotEqual.into(),
                 Identifier::new(a).into(),
-                Literal::Int(0).into(),
+                Literal::from(0).into(),
             )
             .into(),
             Binary::new(
                 RelationalOp::StrictEqual.into(),
                 Binary::new(
                     ArithmeticOp::Div.into(),
-                    Literal::Int(1).into(),
+                    Literal::from(1).into(),
                     Identifier::new(a).into(),
                 )
                 .into(),
                 Binary::new(
                     ArithmeticOp::Div.into(),
-                    Literal::Int(1).into(),
+                    Literal::from(1).into(),
                     Identifier::new(interner.get_or_intern_static("b", utf16!("b"))).into(),
                 )
                 .into(),
@@ -272,7 +281,7 @@ fn comment_semi_colon_insertion() {
             Declaration::Lexical(LexicalDeclaration::Let(
                 vec![Variable::from_identifier(
                     interner.get_or_intern_static("a", utf16!("a")).into(),
-                    Some(Literal::Int(10).into()),
+                    Some(Literal::from(10).into()),
                 )]
                 .try_into()
                 .unwrap(),
@@ -281,7 +290,7 @@ fn comment_semi_colon_insertion() {
             Declaration::Lexical(LexicalDeclaration::Let(
                 vec![Variable::from_identifier(
                     interner.get_or_intern_static("b", utf16!("b")).into(),
-                    Some(Literal::Int(20).into()),
+                    Some(Literal::from(20).into()),
                 )]
                 .try_into()
                 .unwrap(),
@@ -308,7 +317,7 @@ fn multiline_comment_semi_colon_insertion() {
             Declaration::Lexical(LexicalDeclaration::Let(
                 vec![Variable::from_identifier(
                     interner.get_or_intern_static("a", utf16!("a")).into(),
-                    Some(Literal::Int(10).into()),
+                    Some(Literal::from(10).into()),
                 )]
                 .try_into()
                 .unwrap(),
@@ -317,7 +326,7 @@ fn multiline_comment_semi_colon_insertion() {
             Declaration::Lexical(LexicalDeclaration::Let(
                 vec![Variable::from_identifier(
                     interner.get_or_intern_static("b", utf16!("b")).into(),
-                    Some(Literal::Int(20).into()),
+                    Some(Literal::from(20).into()),
                 )]
                 .try_into()
                 .unwrap(),
@@ -341,7 +350,7 @@ fn multiline_comment_no_lineterminator() {
             Declaration::Lexical(LexicalDeclaration::Let(
                 vec![Variable::from_identifier(
                     interner.get_or_intern_static("a", utf16!("a")).into(),
-                    Some(Literal::Int(10).into()),
+                    Some(Literal::from(10).into()),
                 )]
                 .try_into()
                 .unwrap(),
@@ -350,7 +359,7 @@ fn multiline_comment_no_lineterminator() {
             Declaration::Lexical(LexicalDeclaration::Let(
                 vec![Variable::from_identifier(
                     interner.get_or_intern_static("b", utf16!("b")).into(),
-                    Some(Literal::Int(20).into()),
+                    Some(Literal::from(20).into()),
                 )]
                 .try_into()
                 .unwrap(),
@@ -377,7 +386,7 @@ fn assignment_line_terminator() {
             Declaration::Lexical(LexicalDeclaration::Let(
                 vec![Variable::from_identifier(
                     interner.get_or_intern_static("a", utf16!("a")).into(),
-                    Some(Literal::Int(3).into()),
+                    Some(Literal::from(3).into()),
                 )]
                 .try_into()
                 .unwrap(),
@@ -414,7 +423,7 @@ fn assignment_multiline_terminator() {
             Declaration::Lexical(LexicalDeclaration::Let(
                 vec![Variable::from_identifier(
                     a.into(),
-                    Some(Literal::Int(3).into()),
+                    Some(Literal::from(3).into()),
                 )]
                 .try_into()
                 .unwrap(),
@@ -556,8 +565,8 @@ fn empty_statement() {
             if(a) ;
         ",
         vec![
-            Statement::Empty.into(),
-            Statement::Empty.into(),
+            Statement::Empty(SpanIgnoreEq::default()).into(),
+            Statement::Empty(SpanIgnoreEq::default()).into(),
             Statement::Var(VarDeclaration(
                 vec![Variable::from_identifier(
                     a.into(),
@@ -567,7 +576,12 @@ fn empty_statement() {
                 .unwrap(),
             ))
             .into(),
-            Statement::If(If::new(Identifier::new(a).into(), Statement::Empty, None)).into(),
+            Statement::If(If::new(
+                Identifier::new(a).into(),
+                Statement::Empty(SpanIgnoreEq::default()),
+                None,
+            ))
+            .into(),
         ],
         interner,
     );
@@ -589,9 +603,9 @@ fn empty_statement_ends_directive_prologues() {
             let public = 5;
         "#,
         vec![
-            Statement::Expression(Expression::from(Literal::String(a))).into(),
-            Statement::Empty.into(),
-            Statement::Expression(Expression::from(Literal::String(use_strict))).into(),
+            Statement::Expression(Expression::from(Literal::from(a))).into(),
+            Statement::Empty(SpanIgnoreEq::default()).into(),
+            Statement::Expression(Expression::from(Literal::from(use_strict))).into(),
             Declaration::Lexical(LexicalDeclaration::Let(
                 vec![Variable::from_identifier(
                     public,
@@ -659,3 +673,151 @@ fn stress_test_operations() {
         .parse_script(&Scope::new_global(), &mut Interner::default())
         .is_ok());
 }
+
+#[test]
+fn recovering_script() {
+    let interner = &mut Interner::default();
+    let (script, errors) = Parser::new(Source::from_bytes(
+        "let a = 1;\nlet b = ;\nfunction f() {\n    return 1 +;\n    a;\n}\nlet c = 3;",
+    ))
+    .parse_script_recovering(&Scope::new_global(), interner);
+
+    assert_eq!(errors.len(), 2);
+    let lines: Vec<_> = errors
+        .iter()
+        .map(|error| {
+            error
+                .span()
+                .expect("error should have a span")
+                .start()
+                .line_number()
+        })
+        .collect();
+    assert_eq!(lines, [2, 4]);
+
+    let statements = script.statements().statements();
+    assert_eq!(statements.len(), 4);
+    assert_eq!(
+        statements[1],
+        Statement::Error(SpanIgnoreEq::default()).into()
+    );
+    let StatementListItem::Declaration(Declaration::FunctionDeclaration(function)) = &statements[2]
+    else {
+        panic!("expected a function declaration");
+    };
+    let body = function.body().statements();
+    assert_eq!(body.len(), 2);
+    assert_eq!(body[0], Statement::Error(SpanIgnoreEq::default()).into());
+}
+
+#[test]
+fn recovering_script_without_errors() {
+    let src = "let a = 1;\nfunction f() { return a; }";
+    let interner = &mut Interner::default();
+    let (script, errors) = Parser::new(Source::from_bytes(src))
+        .parse_script_recovering(&Scope::new_global(), interner);
+
+    assert!(errors.is_empty());
+    assert_eq!(
+        script,
+        Parser::new(Source::from_bytes(src))
+            .parse_script(&Scope::new_global(), interner)
+            .expect("failed to parse")
+    );
+}
+
+#[test]
+fn recovering_script_abrupt_end() {
+    let (script, errors) = Parser::new(Source::from_bytes("let a;\nfunction f() {\n  if (a) {"))
+        .parse_script_recovering(&Scope::new_global(), &mut Interner::default());
+
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0], crate::Error::AbruptEnd));
+    assert_eq!(script.statements().statements().len(), 2);
+}
+
+#[test]
+fn recovering_module() {
+    let (module, errors) = Parser::new(Source::from_bytes(
+        "export const = 1;\nexport const b = 2;\nlet c = );\nlet d = 4;",
+    ))
+    .parse_module_recovering(&Scope::new_global(), &mut Interner::default());
+
+    assert_eq!(errors.len(), 2);
+    let items = module.items().items();
+    assert_eq!(items.len(), 4);
+    assert_eq!(
+        items[0],
+        ModuleItem::StatementListItem(Statement::Error(SpanIgnoreEq::default()).into())
+    );
+    assert_eq!(
+        items[2],
+        ModuleItem::StatementListItem(Statement::Error(SpanIgnoreEq::default()).into())
+    );
+}
+
+/// Creates a span from `(line, column)` pairs.
+fn span(start: (u32, u32), end: (u32, u32)) -> Span {
+    Span::new(Position::new(start.0, start.1), Position::new(end.0, end.1))
+}
+
+#[test]
+fn spans_of_statements_and_expressions() {
+    let (script, errors) = Parser::new(Source::from_bytes(
+        "let x = a + b * c;\nif (x) {\n  f(x).y;\n}",
+    ))
+    .parse_script_recovering(&Scope::new_global(), &mut Interner::default());
+    assert!(errors.is_empty());
+
+    let statements = script.statements().statements();
+    assert_eq!(statements[0].span(), Some(span((1, 1), (1, 19))));
+    assert_eq!(statements[1].span(), Some(span((2, 1), (4, 2))));
+
+    let StatementListItem::Declaration(Declaration::Lexical(declaration)) = &statements[0] else {
+        panic!("expected a lexical declaration");
+    };
+    let variable = &declaration.variable_list().as_ref()[0];
+    let Some(Expression::Binary(binary)) = variable.init() else {
+        panic!("expected a binary expression");
+    };
+    assert_eq!(binary.span(), Some(span((1, 9), (1, 18))));
+    assert_eq!(binary.lhs().span(), Some(span((1, 9), (1, 10))));
+    assert_eq!(binary.rhs().span(), Some(span((1, 13), (1, 18))));
+
+    let StatementListItem::Statement(Statement::If(r#if)) = &statements[1] else {
+        panic!("expected an if statement");
+    };
+    assert_eq!(r#if.cond().span(), Some(span((2, 5), (2, 6))));
+    let Statement::Block(block) = r#if.body() else {
+        panic!("expected a block statement");
+    };
+    let access = &block.statement_list().statements()[0];
+    assert_eq!(access.span(), Some(span((3, 3), (3, 9))));
+    let StatementListItem::Statement(Statement::Expression(Expression::PropertyAccess(access))) =
+        access
+    else {
+        panic!("expected a property access");
+    };
+    let boa_ast::expression::access::PropertyAccess::Simple(access) = access else {
+        panic!("expected a simple property access");
+    };
+    assert_eq!(access.target().span(), Some(span((3, 3), (3, 7))));
+}
+
+#[test]
+fn spans_ignored_by_equality() {
+    let interner = &mut Interner::default();
+    let parse = |src: &str, interner: &mut Interner| {
+        Parser::new(Source::from_bytes(src))
+            .parse_script(&Scope::new_global(), interner)
+            .expect("failed to parse")
+    };
+
+    let compact = parse("a+b;", interner);
+    let spaced = parse("\n  a  +  b ;", interner);
+    assert_eq!(compact, spaced);
+    assert_ne!(
+        compact.statements().statements()[0].span(),
+        spaced.statements().statements()[0].span()
+    );
+}
@@ -1,5 +1,7 @@
 //! Boa parser input source types.
 
+use crate::{error::ParseResult, lexer::Error as LexError};
+use boa_ast::SourceText;
 use std::{
     fs::File,
     io::{self, BufReader, Read},
@@ -134,6 +136,27 @@ impl<'path, R> Source<'path, R> {
     }
 }
 
+impl<R: ReadChar> Source<'_, R> {
+    /// Reads the remaining input of this [`Source`] into a [`SourceText`], without parsing it.
+    ///
+    /// The code points are collected the same way the parser collects them, so the resulting
+    /// source text is equal to the one returned by [`Parser::parse_script_with_source`] for the
+    /// same input.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` on any invalid read of the input.
+    ///
+    /// [`Parser::parse_script_with_source`]: crate::Parser::parse_script_with_source
+    pub fn read_source_text(&mut self) -> ParseResult<SourceText> {
+        let mut source_text = SourceText::default();
+        while let Some(ch) = self.reader.next_char().map_err(LexError::from)? {
+            source_text.collect_code_point(ch);
+        }
+        Ok(source_text)
+    }
+}
+
 /// This trait is used to abstract over the different types of input readers.
 pub trait ReadChar {
     /// Retrieves the next unicode code point. Returns `None` if the end of the input is reached.
@@ -1,5 +1,6 @@
 use crate::{
-    alloc_overflow, JsStr, JsStrVariant, JsString, RawJsString, RefCount, TaggedLen, DATA_OFFSET,
+    alloc_overflow, track_allocation, JsStr, JsStrVariant, JsString, RawJsString, RefCount,
+    TaggedLen, DATA_OFFSET,
 };
 use tag_ptr::Tagged;
 
@@ -362,10 +363,11 @@ impl<D: Copy> JsStringBuilder<D> {
         let len = self.len();
 
         // Shrink to fit the length.
+        let layout = Self::new_layout(len);
         if len != self.capacity() {
-            let layout = Self::new_layout(self.len());
             self.allocate_inner(layout);
         }
+        track_allocation(layout);
 
         let inner = self.inner;
 
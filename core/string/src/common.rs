@@ -89,7 +89,9 @@ impl StaticJsStrings {
         (EMPTY_STRING, ""),
         (LENGTH, "length"),
         // Symbols
+        (SYMBOL_ASYNC_DISPOSE, "Symbol.asyncDispose"),
         (SYMBOL_ASYNC_ITERATOR, "Symbol.asyncIterator"),
+        (SYMBOL_DISPOSE, "Symbol.dispose"),
         (SYMBOL_HAS_INSTANCE, "Symbol.hasInstance"),
         (SYMBOL_IS_CONCAT_SPREADABLE, "Symbol.isConcatSpreadable"),
         (SYMBOL_ITERATOR, "Symbol.iterator"),
@@ -102,7 +104,9 @@ impl StaticJsStrings {
         (SYMBOL_TO_PRIMITIVE, "Symbol.toPrimitive"),
         (SYMBOL_TO_STRING_TAG, "Symbol.toStringTag"),
         (SYMBOL_UNSCOPABLES, "Symbol.unscopables"),
+        (FN_SYMBOL_ASYNC_DISPOSE, "[Symbol.asyncDispose]"),
         (FN_SYMBOL_ASYNC_ITERATOR, "[Symbol.asyncIterator]"),
+        (FN_SYMBOL_DISPOSE, "[Symbol.dispose]"),
         (FN_SYMBOL_HAS_INSTANCE, "[Symbol.hasInstance]"),
         (FN_SYMBOL_IS_CONCAT_SPREADABLE, "[Symbol.isConcatSpreadable]"),
         (FN_SYMBOL_ITERATOR, "[Symbol.iterator]"),
@@ -119,6 +123,7 @@ impl StaticJsStrings {
         (ARRAY, "Array"),
         (ARRAY_BUFFER, "ArrayBuffer"),
         (SHARED_ARRAY_BUFFER, "SharedArrayBuffer"),
+        (ASYNC_DISPOSABLE_STACK, "AsyncDisposableStack"),
         (ASYNC_FUNCTION, "AsyncFunction"),
         (ASYNC_GENERATOR, "AsyncGenerator"),
         (ASYNC_GENERATOR_FUNCTION, "AsyncGeneratorFunction"),
@@ -127,11 +132,13 @@ impl StaticJsStrings {
         (BOOLEAN, "Boolean"),
         (DATA_VIEW, "DataView"),
         (DATE, "Date"),
+        (DISPOSABLE_STACK, "DisposableStack"),
         (ERROR, "Error"),
         (AGGREGATE_ERROR, "AggregateError"),
         (EVAL_ERROR, "EvalError"),
         (RANGE_ERROR, "RangeError"),
         (REFERENCE_ERROR, "ReferenceError"),
+        (SUPPRESSED_ERROR, "SuppressedError"),
         (SYNTAX_ERROR, "SyntaxError"),
         (TYPE_ERROR, "TypeError"),
         (URI_ERROR, "URIError"),
@@ -153,6 +160,9 @@ impl StaticJsStrings {
         (MATH, "Math"),
         (NUMBER, "Number"),
         (NUMBER_FORMAT, "NumberFormat"),
+        (RELATIVE_TIME_FORMAT, "RelativeTimeFormat"),
+        (DISPLAY_NAMES, "DisplayNames"),
+        (DURATION_FORMAT, "DurationFormat"),
         (IS_FINITE, "isFinite"),
         (IS_NAN, "isNaN"),
         (PARSE_INT, "parseInt"),
@@ -175,15 +185,19 @@ impl StaticJsStrings {
         (UINT32_ARRAY, "Uint32Array"),
         (BIG_INT64_ARRAY, "BigInt64Array"),
         (BIG_UINT64_ARRAY, "BigUint64Array"),
+        (FLOAT16_ARRAY, "Float16Array"),
         (FLOAT32_ARRAY, "Float32Array"),
         (FLOAT64_ARRAY, "Float64Array"),
         (ENCODE_URI, "encodeURI"),
         (ENCODE_URI_COMPONENT, "encodeURIComponent"),
         (DECODE_URI, "decodeURI"),
         (DECODE_URI_COMPONENT, "decodeURIComponent"),
+        (ITERATOR, "Iterator"),
         (WEAK_REF, "WeakRef"),
         (WEAK_MAP, "WeakMap"),
         (WEAK_SET, "WeakSet"),
+        (FINALIZATION_REGISTRY, "FinalizationRegistry"),
+        (SHADOW_REALM, "ShadowRealm"),
         (TEMPORAL, "Temporal"),
         (NOW_TAG, "Temporal.Now"),
         (INSTANT_TAG, "Temporal.Instant"),
@@ -233,8 +247,12 @@ static RAW_STATICS_CACHE: LazyLock<FxHashMap<JsStr<'static>, usize>> = LazyLock:
 const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("".as_bytes()),
     // Well known symbols
+    JsStr::latin1("Symbol.asyncDispose".as_bytes()),
+    JsStr::latin1("[Symbol.asyncDispose]".as_bytes()),
     JsStr::latin1("Symbol.asyncIterator".as_bytes()),
     JsStr::latin1("[Symbol.asyncIterator]".as_bytes()),
+    JsStr::latin1("Symbol.dispose".as_bytes()),
+    JsStr::latin1("[Symbol.dispose]".as_bytes()),
     JsStr::latin1("Symbol.hasInstance".as_bytes()),
     JsStr::latin1("[Symbol.hasInstance]".as_bytes()),
     JsStr::latin1("Symbol.isConcatSpreadable".as_bytes()),
@@ -265,6 +283,7 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("Array".as_bytes()),
     JsStr::latin1("ArrayBuffer".as_bytes()),
     JsStr::latin1("SharedArrayBuffer".as_bytes()),
+    JsStr::latin1("AsyncDisposableStack".as_bytes()),
     JsStr::latin1("AsyncFunction".as_bytes()),
     JsStr::latin1("AsyncGenerator".as_bytes()),
     JsStr::latin1("AsyncGeneratorFunction".as_bytes()),
@@ -273,11 +292,13 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("Boolean".as_bytes()),
     JsStr::latin1("DataView".as_bytes()),
     JsStr::latin1("Date".as_bytes()),
+    JsStr::latin1("DisposableStack".as_bytes()),
     JsStr::latin1("Error".as_bytes()),
     JsStr::latin1("AggregateError".as_bytes()),
     JsStr::latin1("EvalError".as_bytes()),
     JsStr::latin1("RangeError".as_bytes()),
     JsStr::latin1("ReferenceError".as_bytes()),
+    JsStr::latin1("SuppressedError".as_bytes()),
     JsStr::latin1("SyntaxError".as_bytes()),
     JsStr::latin1("TypeError".as_bytes()),
     JsStr::latin1("URIError".as_bytes()),
@@ -299,6 +320,9 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("Math".as_bytes()),
     JsStr::latin1("Number".as_bytes()),
     JsStr::latin1("NumberFormat".as_bytes()),
+    JsStr::latin1("RelativeTimeFormat".as_bytes()),
+    JsStr::latin1("DisplayNames".as_bytes()),
+    JsStr::latin1("DurationFormat".as_bytes()),
     JsStr::latin1("isFinite".as_bytes()),
     JsStr::latin1("isNaN".as_bytes()),
     JsStr::latin1("parseInt".as_bytes()),
@@ -321,15 +345,19 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("Uint32Array".as_bytes()),
     JsStr::latin1("BigInt64Array".as_bytes()),
     JsStr::latin1("BigUint64Array".as_bytes()),
+    JsStr::latin1("Float16Array".as_bytes()),
     JsStr::latin1("Float32Array".as_bytes()),
     JsStr::latin1("Float64Array".as_bytes()),
     JsStr::latin1("encodeURI".as_bytes()),
     JsStr::latin1("encodeURIComponent".as_bytes()),
     JsStr::latin1("decodeURI".as_bytes()),
     JsStr::latin1("decodeURIComponent".as_bytes()),
+    JsStr::latin1("Iterator".as_bytes()),
     JsStr::latin1("WeakRef".as_bytes()),
     JsStr::latin1("WeakMap".as_bytes()),
     JsStr::latin1("WeakSet".as_bytes()),
+    JsStr::latin1("FinalizationRegistry".as_bytes()),
+    JsStr::latin1("ShadowRealm".as_bytes()),
     JsStr::latin1("Temporal".as_bytes()),
     JsStr::latin1("Temporal.Now".as_bytes()),
     JsStr::latin1("Temporal.Instant".as_bytes()),
@@ -617,6 +645,8 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     // JSON object
     JsStr::latin1("parse".as_bytes()),
     JsStr::latin1("stringify".as_bytes()),
+    JsStr::latin1("rawJSON".as_bytes()),
+    JsStr::latin1("isRawJSON".as_bytes()),
     // Promise object
     JsStr::latin1("promise".as_bytes()),
     JsStr::latin1("resolve".as_bytes()),
@@ -636,6 +666,11 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("Map Iterator".as_bytes()),
     JsStr::latin1("For In Iterator".as_bytes()),
     JsStr::latin1("RegExp String Iterator".as_bytes()),
+    JsStr::latin1("Iterator Helper".as_bytes()),
+    // Iterator helpers
+    JsStr::latin1("take".as_bytes()),
+    JsStr::latin1("drop".as_bytes()),
+    JsStr::latin1("toArray".as_bytes()),
     // Iterator result object
     JsStr::latin1("done".as_bytes()),
     // Math object
@@ -662,6 +697,7 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("exp".as_bytes()),
     JsStr::latin1("expm1".as_bytes()),
     JsStr::latin1("floor".as_bytes()),
+    JsStr::latin1("f16round".as_bytes()),
     JsStr::latin1("fround".as_bytes()),
     JsStr::latin1("hypot".as_bytes()),
     JsStr::latin1("imul".as_bytes()),
@@ -693,9 +729,16 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("get byteOffset".as_bytes()),
     JsStr::latin1("get size".as_bytes()),
     JsStr::latin1("get length".as_bytes()),
+    JsStr::latin1("fromBase64".as_bytes()),
+    JsStr::latin1("fromHex".as_bytes()),
+    JsStr::latin1("setFromBase64".as_bytes()),
+    JsStr::latin1("setFromHex".as_bytes()),
+    JsStr::latin1("toBase64".as_bytes()),
+    JsStr::latin1("toHex".as_bytes()),
     // DataView object
     JsStr::latin1("getBigInt64".as_bytes()),
     JsStr::latin1("getBigUint64".as_bytes()),
+    JsStr::latin1("getFloat16".as_bytes()),
     JsStr::latin1("getFloat32".as_bytes()),
     JsStr::latin1("getFloat64".as_bytes()),
     JsStr::latin1("getInt8".as_bytes()),
@@ -706,6 +749,7 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("getUint32".as_bytes()),
     JsStr::latin1("setBigInt64".as_bytes()),
     JsStr::latin1("setBigUint64".as_bytes()),
+    JsStr::latin1("setFloat16".as_bytes()),
     JsStr::latin1("setFloat32".as_bytes()),
     JsStr::latin1("setFloat64".as_bytes()),
     JsStr::latin1("setInt8".as_bytes()),
@@ -716,6 +760,13 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("setUint32".as_bytes()),
     // WeakRef object
     JsStr::latin1("deref".as_bytes()),
+    // FinalizationRegistry object
+    JsStr::latin1("register".as_bytes()),
+    JsStr::latin1("unregister".as_bytes()),
+    JsStr::latin1("cleanupSome".as_bytes()),
+    // ShadowRealm object
+    JsStr::latin1("evaluate".as_bytes()),
+    JsStr::latin1("importValue".as_bytes()),
     // Atomic object
     JsStr::latin1("and".as_bytes()),
     JsStr::latin1("compareExchange".as_bytes()),
@@ -737,6 +788,7 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("Intl.ListFormat".as_bytes()),
     JsStr::latin1("format".as_bytes()),
     JsStr::latin1("formatToParts".as_bytes()),
+    JsStr::latin1("formatRange".as_bytes()),
     JsStr::latin1("get baseName".as_bytes()),
     JsStr::latin1("get calendar".as_bytes()),
     JsStr::latin1("get caseFirst".as_bytes()),
@@ -766,6 +818,9 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("Segmenter String Iterator".as_bytes()),
     JsStr::latin1("Intl.PluralRules".as_bytes()),
     JsStr::latin1("select".as_bytes()),
+    JsStr::latin1("Intl.RelativeTimeFormat".as_bytes()),
+    JsStr::latin1("Intl.DisplayNames".as_bytes()),
+    JsStr::latin1("Intl.DurationFormat".as_bytes()),
     // Temporal object
     JsStr::latin1("get Id".as_bytes()),
     JsStr::latin1("getOffsetNanosecondsFor".as_bytes()),
@@ -48,6 +48,30 @@ fn alloc_overflow() -> ! {
     panic!("detected overflow during string allocation")
 }
 
+thread_local! {
+    /// The number of bytes used by the heap allocated strings of this thread.
+    static BYTES_ALLOCATED: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Records that a string with the given `layout` was allocated.
+fn track_allocation(layout: Layout) {
+    BYTES_ALLOCATED.with(|bytes| bytes.set(bytes.get().saturating_add(layout.size())));
+}
+
+/// Records that a string with the given `layout` was deallocated.
+fn track_deallocation(layout: Layout) {
+    BYTES_ALLOCATED.with(|bytes| bytes.set(bytes.get().saturating_sub(layout.size())));
+}
+
+/// Returns the number of bytes currently used by the heap allocated strings of this thread.
+///
+/// Static strings are not included, and a string shared by many [`JsString`]s is only counted
+/// once. The buffers of the string builders are counted once they are built into a string.
+#[must_use]
+pub fn bytes_allocated() -> usize {
+    BYTES_ALLOCATED.with(Cell::get)
+}
+
 /// Helper function to check if a `char` is trimmable.
 pub(crate) const fn is_trimmable_whitespace(c: char) -> bool {
     // The rust implementation of `trim` does not regard the same characters whitespace as ecma standard does
@@ -699,6 +723,7 @@ impl JsString {
         // we should abort, since an allocation error is pretty unrecoverable for us
         // right now.
         let inner = NonNull::new(inner).ok_or(Some(layout))?;
+        track_allocation(layout);
 
         // SAFETY:
         // `NonNull` verified for us that the pointer returned by `alloc` is valid,
@@ -891,6 +916,8 @@ impl Drop for JsString {
                 }
             };
 
+            track_deallocation(layout);
+
             // SAFETY:
             // If refcount is 0 and we call drop, that means this is the last `JsString` which
             // points to this memory allocation, so deallocating it is safe.
@@ -3,8 +3,8 @@
 use std::hash::{BuildHasher, BuildHasherDefault, Hash};
 
 use crate::{
-    CommonJsStringBuilder, JsStr, JsString, Latin1JsStringBuilder, StaticJsString, StaticJsStrings,
-    Utf16JsStringBuilder,
+    bytes_allocated, CommonJsStringBuilder, JsStr, JsString, Latin1JsStringBuilder, StaticJsString,
+    StaticJsStrings, Utf16JsStringBuilder,
 };
 
 use rustc_hash::FxHasher;
@@ -471,3 +471,27 @@ fn common_js_string_builder() {
         "Déjà vu2024年5月21日🎹"
     );
 }
+
+#[test]
+fn bytes_allocated_tracks_live_strings() {
+    let before = bytes_allocated();
+
+    let string = JsString::from("Hello, world!");
+    let allocated = bytes_allocated();
+    assert!(allocated > before);
+
+    // Clones share the same allocation.
+    let clone = string.clone();
+    assert_eq!(bytes_allocated(), allocated);
+    drop(string);
+    assert_eq!(bytes_allocated(), allocated);
+    drop(clone);
+    assert_eq!(bytes_allocated(), before);
+
+    let mut builder = Utf16JsStringBuilder::new();
+    builder.extend_from_slice(&ascii_to_utf16(b"Hello"));
+    let string = builder.build();
+    assert!(bytes_allocated() > before);
+    drop(string);
+    assert_eq!(bytes_allocated(), before);
+}
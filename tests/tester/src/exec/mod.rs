@@ -25,6 +25,10 @@ use std::{cell::RefCell, eprintln, path::Path, rc::Rc};
 
 use self::js262::WorkerHandles;
 
+/// The heap size limit of the contexts that run the tests, so that tests that allocate unbounded
+/// memory throw an out of memory error instead of exhausting the memory of the machine.
+const HEAP_SIZE_LIMIT: usize = 1024 * 1024 * 1024;
+
 impl TestSuite {
     /// Runs the test suite.
     pub(crate) fn run(
@@ -535,6 +539,9 @@ impl Test {
             .expect("cannot fail with default global object");
 
         context.set_optimizer_options(optimizer_options);
+        context
+            .runtime_limits_mut()
+            .set_heap_size_limit(HEAP_SIZE_LIMIT);
 
         // Register the print() function.
         register_print_fn(&mut context, async_result.clone());
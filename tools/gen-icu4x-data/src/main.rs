@@ -18,6 +18,7 @@ const SERVICES: &[(&str, &[DataMarkerInfo])] = &[
     ("icu_collator", icu_collator::provider::MARKERS),
     ("icu_datetime", icu_datetime::provider::MARKERS),
     ("icu_decimal", icu_decimal::provider::MARKERS),
+    ("icu_experimental", icu_experimental::provider::MARKERS),
     ("icu_list", icu_list::provider::MARKERS),
     ("icu_locale", icu_locale::provider::MARKERS),
     ("icu_normalizer", icu_normalizer::provider::MARKERS),